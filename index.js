@@ -9,6 +9,8 @@ const OpenSeaService = require('./src/blockchain/opensea');
 const MagicEdenService = require('./src/blockchain/magiceden');
 const HeliusService = require('./src/blockchain/helius');
 const MagicEdenOrdinalsService = require('./src/blockchain/magicEdenOrdinalsService');
+const HiroOrdinalsService = require('./src/blockchain/hiroOrdinalsService');
+const AlchemyNotifyService = require('./src/blockchain/alchemyNotify');
 const BotCommands = require('./src/bot/commands');
 const WebhookHandlers = require('./src/webhooks/handlers');
 const TokenTracker = require('./src/services/tokenTracker');
@@ -17,6 +19,14 @@ const SecureTrendingService = require('./src/services/secureTrendingService');
 const ChannelService = require('./src/services/channelService');
 const ChainManager = require('./src/services/chainManager');
 const BitcoinOrdinalsPoller = require('./src/services/bitcoinOrdinalsPoller');
+const SessionStore = require('./src/services/sessionStore');
+const { createDedupStore } = require('./src/services/dedupStore');
+const NotificationOutbox = require('./src/services/notificationOutbox');
+const TelegramSender = require('./src/services/telegramSender');
+const OutboundWebhookService = require('./src/services/outboundWebhookService');
+const ChatSettingsService = require('./src/services/chatSettingsService');
+const MessageTemplateService = require('./src/services/messageTemplateService');
+const WalletWatchService = require('./src/services/walletWatchService');
 
 class MintyRushBot {
   constructor() {
@@ -51,6 +61,15 @@ class MintyRushBot {
       await this.services.db.initialize();
       logger.info('Database initialized');
 
+      // Restore conversation state of unfinished bot flows from before the last restart
+      try {
+        this.services.sessionStore = new SessionStore(this.services.db);
+        await this.services.sessionStore.initialize();
+      } catch (error) {
+        logger.warn('Session store not available, bot flows will not survive restarts:', error.message);
+        this.services.sessionStore = null;
+      }
+
       // Initialize ChainManager for multi-chain support
       this.services.chainManager = new ChainManager(this.services.db);
       await this.services.chainManager.initialize();
@@ -91,6 +110,16 @@ class MintyRushBot {
         this.services.magicEdenOrdinals = null;
       }
 
+      // Initialize Hiro Chainhook service (for Bitcoin Ordinals inscription transfers)
+      try {
+        this.services.hiroOrdinals = new HiroOrdinalsService(this.services.magicEdenOrdinals);
+        await this.services.hiroOrdinals.initialize();
+        logger.info('₿ Hiro Chainhook service initialized');
+      } catch (error) {
+        logger.warn('Hiro Chainhook service not available:', error.message);
+        this.services.hiroOrdinals = null;
+      }
+
 
       try {
         this.services.trending = new TrendingService(this.services.db);
@@ -133,16 +162,21 @@ class MintyRushBot {
         this.services.magicEden,
         this.services.helius,
         this.services.magicEdenOrdinals,
-        null // bitcoinPoller will be set later
+        null, // bitcoinPoller will be set later
+        this.services.hiroOrdinals
       );
       await this.services.tokenTracker.initialize();
       logger.info('Token tracker initialized with OpenSea + Magic Eden + Bitcoin Ordinals support');
 
+      // One rate limiter for every outgoing message, so alerts and broadcasts share Telegram's limits
+      this.services.telegramSender = new TelegramSender(this.bot);
+
       this.services.channelService = new ChannelService(
         this.services.db,
         this.bot,
         this.services.trending,
-        this.services.secureTrending
+        this.services.secureTrending,
+        this.services.telegramSender
       );
       await this.services.channelService.initialize();
       logger.info('Channel service initialized');
@@ -150,6 +184,32 @@ class MintyRushBot {
 
       this.setupExpressMiddleware();
 
+      // Shared event dedup store, so restarts and replicas don't re-send alerts
+      this.services.dedupStore = createDedupStore(this.services.db);
+
+      // Alerts are queued here and delivered with retries by the outbox worker
+      this.services.outbox = new NotificationOutbox(this.services.db, this.bot, {
+        sender: this.services.telegramSender
+      });
+
+      // Signed copies of each activity for endpoints registered by group admins
+      this.services.outboundWebhooks = new OutboundWebhookService(this.services.db);
+
+      // Per-chat alert preferences, shared by the alert path and the settings commands
+      this.services.chatSettings = new ChatSettingsService(this.services.db);
+
+      // Custom alert layouts edited by group admins with /templates
+      this.services.messageTemplates = new MessageTemplateService(this.services.db);
+
+      // Wallet watchlists, delivered by Alchemy address activity, Helius and Hiro webhooks
+      this.services.walletWatch = new WalletWatchService(this.services.db, {
+        chainManager: this.services.chainManager,
+        helius: this.services.helius,
+        alchemy: new AlchemyNotifyService(),
+        hiro: this.services.hiroOrdinals,
+        onBitcoinWatchChange: () => this.services.tokenTracker.syncHiroPredicates()
+      });
+
       // Setup webhook handlers before initializing Bitcoin Ordinals poller
       const webhookHandlers = new WebhookHandlers(
         this.services.db,
@@ -161,7 +221,13 @@ class MintyRushBot {
         this.services.magicEden,
         this.services.helius,
         this.services.magicEdenOrdinals,
-        null // Hiro removed
+        this.services.hiroOrdinals,
+        this.services.dedupStore,
+        this.services.outbox,
+        this.services.outboundWebhooks,
+        this.services.chatSettings,
+        this.services.messageTemplates,
+        this.services.walletWatch
       );
 
       // Connect webhook handlers to token tracker
@@ -169,7 +235,7 @@ class MintyRushBot {
         this.services.tokenTracker.setWebhookHandlers(webhookHandlers);
       }
 
-      // Initialize Bitcoin Ordinals Poller (replaces Hiro webhooks)
+      // Initialize Bitcoin Ordinals Poller (sales and listings; transfers come from the Hiro chainhook)
       try {
         this.services.bitcoinPoller = new BitcoinOrdinalsPoller(
           this.services.db,
@@ -189,6 +255,24 @@ class MintyRushBot {
       // Store webhook handlers reference
       this.webhookHandlers = webhookHandlers;
 
+      // Post the alerts held for quiet hours and digest chats when they are due
+      webhookHandlers.digests.start();
+
+      // Deliver sales once their sweep window has closed
+      webhookHandlers.sweeps.start();
+
+      // Poll floor and volume of collections with /stats_alert rules
+      webhookHandlers.statsAlerts.start();
+
+      // Offline price history: candles from PRICE_FIXTURES_DIR
+      await webhookHandlers.priceHistory.start();
+
+      try {
+        await this.services.walletWatch.initialize();
+      } catch (error) {
+        logger.warn('Wallet watchlists not synced:', error.message);
+      }
+
 
       this.setupWebhookRoutes();
 
@@ -199,7 +283,13 @@ class MintyRushBot {
         this.services.trending,
         this.services.channelService,
         this.services.secureTrending,
-        this.services.chainManager
+        this.services.chainManager,
+        this.services.sessionStore,
+        this.services.outboundWebhooks,
+        this.services.chatSettings,
+        this.services.messageTemplates,
+        this.services.walletWatch,
+        webhookHandlers.statsAlerts
       );
       await botCommands.setupCommands(this.bot);
       logger.info('Bot commands setup completed');
@@ -223,6 +313,13 @@ class MintyRushBot {
       // Don't wait for bot launch - continue initialization
       logger.info('📱 Telegram bot launching in background...');
 
+      // Deliver queued alerts, including any left over from before a restart
+      this.services.outbox.start();
+
+      // Process acknowledged webhook payloads, including any stored before a restart
+      webhookHandlers.inbox.start();
+      this.services.outboundWebhooks.start();
+
       // Start Bitcoin Ordinals Poller immediately
       if (this.services.bitcoinPoller) {
         this.services.bitcoinPoller.start();
@@ -310,6 +407,7 @@ class MintyRushBot {
 
     this.app.post('/webhook/alchemy', webhookHandlers.handleAlchemyWebhook.bind(webhookHandlers));
     this.app.post('/webhook/helius', webhookHandlers.handleHeliusWebhook.bind(webhookHandlers));
+    this.app.post('/webhook/hiro', webhookHandlers.handleHiroChainhook.bind(webhookHandlers));
 
     this.app.get('/health', webhookHandlers.handleHealthCheck.bind(webhookHandlers));
 
@@ -470,6 +568,40 @@ class MintyRushBot {
         await this.services.magicEdenOrdinals.disconnect();
       }
 
+      // Unprocessed webhook payloads, sales waiting in a sweep window and held digest events
+      // stay in the database until the next start
+      if (this.webhookHandlers) {
+        await this.webhookHandlers.inbox.stop();
+        await this.webhookHandlers.sweeps.stop();
+        await this.webhookHandlers.digests.stop();
+        await this.webhookHandlers.statsAlerts.stop();
+      }
+
+      // Let the outbox finish the deliveries in flight; the rest stays queued
+      if (this.services.outbox) {
+        await this.services.outbox.stop();
+      }
+
+      // Stop outbound webhook deliveries; pending ones are retried after the restart
+      if (this.services.outboundWebhooks) {
+        await this.services.outboundWebhooks.stop();
+      }
+
+      // Drop sends still waiting for a rate limit slot
+      if (this.services.telegramSender) {
+        this.services.telegramSender.stop();
+      }
+
+      // Stop dedup cleanup timer
+      if (this.services.dedupStore) {
+        this.services.dedupStore.close();
+      }
+
+      // Flush pending session writes before the database goes away
+      if (this.services.sessionStore) {
+        await this.services.sessionStore.close();
+      }
+
       // Close database connection
       if (this.services.db) {
         await this.services.db.close();
@@ -497,13 +629,31 @@ class MintyRushBot {
         botStatus,
         channelsCount,
         tokensCount,
-        trendingCount
+        trendingCount,
+        outboxStats,
+        outboundWebhookStats,
+        digestStats,
+        sweepStats,
+        inboxStats,
+        walletWatchStats,
+        statsAlertStats,
+        rarityStats,
+        priceHistoryStats
       ] = await Promise.allSettled([
         this.checkDatabaseStatus(),
         this.checkBotStatus(),
         this.getChannelsCount(),
         this.getTokensCount(),
-        this.getTrendingCount()
+        this.getTrendingCount(),
+        this.services.outbox.getStats(),
+        this.services.outboundWebhooks.getStats(),
+        this.webhookHandlers ? this.webhookHandlers.digests.getStats() : null,
+        this.webhookHandlers ? this.webhookHandlers.sweeps.getStats() : null,
+        this.webhookHandlers ? this.webhookHandlers.inbox.getStats() : null,
+        this.services.walletWatch.getStats(),
+        this.webhookHandlers ? this.webhookHandlers.statsAlerts.getStats() : null,
+        this.webhookHandlers ? this.webhookHandlers.rarity.getStats() : null,
+        this.webhookHandlers ? this.webhookHandlers.priceHistory.getStats() : null
       ]);
 
       return {
@@ -515,7 +665,18 @@ class MintyRushBot {
           channels: channelsCount.status === 'fulfilled' ? channelsCount.value : 0,
           tokens: tokensCount.status === 'fulfilled' ? tokensCount.value : 0,
           trending: trendingCount.status === 'fulfilled' ? trendingCount.value : 0
-        }
+        },
+        outbox: outboxStats.status === 'fulfilled' ? outboxStats.value : 'error',
+        outboundWebhooks: outboundWebhookStats.status === 'fulfilled' ? outboundWebhookStats.value : 'error',
+        telegramSender: this.services.telegramSender.getStats(),
+        sweeps: sweepStats.status === 'fulfilled' ? sweepStats.value : 'error',
+        webhookInbox: inboxStats.status === 'fulfilled' ? inboxStats.value : 'error',
+        saleAttribution: this.webhookHandlers ? this.webhookHandlers.saleAttribution.getStats() : null,
+        digests: digestStats.status === 'fulfilled' ? digestStats.value : 'error',
+        walletWatch: walletWatchStats.status === 'fulfilled' ? walletWatchStats.value : 'error',
+        statsAlerts: statsAlertStats.status === 'fulfilled' ? statsAlertStats.value : 'error',
+        rarity: rarityStats.status === 'fulfilled' ? rarityStats.value : 'error',
+        priceHistory: priceHistoryStats.status === 'fulfilled' ? priceHistoryStats.value : 'error'
       };
     } catch (error) {
       logger.error('Error getting system status:', error);
@@ -0,0 +1,99 @@
+#!/usr/bin/env node
+/**
+ * Alchemy Webhook Signing Keys
+ *
+ * Usage:
+ *   node scripts/alchemyWebhookKeys.js list                                   Show webhooks and key status
+ *   node scripts/alchemyWebhookKeys.js assign <webhookId> <contract> [chain]  Bind a tracked token to a webhook
+ *   node scripts/alchemyWebhookKeys.js set-key <webhookId> <signingKey>       Set or rotate a webhook's signing key
+ *
+ * After a rotation the previous key is still accepted for ALCHEMY_KEY_ROTATION_GRACE_HOURS (default 24).
+ */
+
+// Load environment variables
+require('dotenv').config();
+
+const Database = require('../src/database/db');
+
+function printUsage() {
+  console.log('\nUsage: node scripts/alchemyWebhookKeys.js <list|assign <webhookId> <contract> [chain]|set-key <webhookId> <signingKey>>');
+}
+
+function mask(key) {
+  return key ? `${key.slice(0, 6)}…${key.slice(-4)}` : 'none';
+}
+
+async function main() {
+  const [command = 'list', ...args] = process.argv.slice(2);
+
+  if (!['list', 'assign', 'set-key'].includes(command)) {
+    console.error(`❌ Error: Unknown command "${command}"`);
+    printUsage();
+    process.exit(1);
+  }
+
+  const db = new Database();
+
+  try {
+    await db.initialize();
+
+    if (command === 'list') {
+      const rows = await db.all(`
+        SELECT webhook_id, contract_address, chain_name, token_name,
+               webhook_signing_key, webhook_previous_signing_key, webhook_key_rotated_at
+        FROM tracked_tokens
+        WHERE webhook_id IS NOT NULL
+        ORDER BY webhook_id, id
+      `);
+
+      if (rows.length === 0) {
+        console.log('\n❌ No tracked tokens are bound to an Alchemy webhook.\n');
+        return;
+      }
+
+      console.log('\n🔑 Alchemy webhooks:\n');
+      rows.forEach(row => {
+        const rotatedAt = row.webhook_key_rotated_at ? new Date(row.webhook_key_rotated_at).toLocaleString() : 'never';
+        console.log(`  ${row.webhook_id} → ${row.token_name || 'Unknown'} (${row.contract_address} on ${row.chain_name})`);
+        console.log(`     Key: ${mask(row.webhook_signing_key)}, previous: ${mask(row.webhook_previous_signing_key)}, rotated: ${rotatedAt}`);
+      });
+      console.log('');
+    } else if (command === 'assign') {
+      const [webhookId, contractAddress, chainName = 'ethereum'] = args;
+      if (!webhookId || !contractAddress) {
+        printUsage();
+        process.exit(1);
+      }
+
+      const { updated } = await db.assignWebhookToToken(contractAddress, chainName, webhookId);
+      if (updated === 0) {
+        console.error(`❌ Error: ${contractAddress} on ${chainName} is not a tracked token`);
+        process.exit(1);
+      }
+      console.log(`\n✅ ${contractAddress} (${chainName}) bound to webhook ${webhookId}\n`);
+    } else if (command === 'set-key') {
+      const [webhookId, signingKey] = args;
+      if (!webhookId || !signingKey) {
+        printUsage();
+        process.exit(1);
+      }
+
+      const { updated } = await db.setWebhookSigningKey(webhookId, signingKey);
+      if (updated === 0) {
+        console.error(`❌ Error: No tracked tokens are bound to webhook ${webhookId} (run "assign" first)`);
+        process.exit(1);
+      }
+      console.log(`\n✅ Signing key set for webhook ${webhookId} (${updated} token(s)); the previous key stays valid during the grace period\n`);
+    }
+  } catch (error) {
+    console.error('\n❌ Error:', error.message);
+    process.exit(1);
+  } finally {
+    await db.close();
+  }
+}
+
+main().catch(error => {
+  console.error('Fatal error:', error);
+  process.exit(1);
+});
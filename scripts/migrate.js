@@ -0,0 +1,90 @@
+#!/usr/bin/env node
+/**
+ * Schema Migrations
+ *
+ * Usage:
+ *   node scripts/migrate.js status          Show applied and pending migrations
+ *   node scripts/migrate.js pending         List pending migrations only
+ *   node scripts/migrate.js up [version]    Apply pending migrations (up to version)
+ *   node scripts/migrate.js down [steps]    Roll back the last N migrations (default 1)
+ */
+
+// Load environment variables
+require('dotenv').config();
+
+const Database = require('../src/database/db');
+
+function printUsage() {
+  console.log('\nUsage: node scripts/migrate.js <status|pending|up [version]|down [steps]>');
+}
+
+function formatMigration(migration) {
+  const id = `${String(migration.version).padStart(3, '0')}_${migration.name}`;
+  if (!migration.applied) {
+    return `  ⏳ ${id} (pending)`;
+  }
+  const appliedAt = migration.appliedAt ? new Date(migration.appliedAt).toLocaleString() : 'unknown';
+  const warning = migration.checksumMismatch ? ' ⚠️  CHECKSUM MISMATCH' : '';
+  return `  ✅ ${id} (applied ${appliedAt})${warning}`;
+}
+
+async function main() {
+  const command = process.argv[2] || 'status';
+  const arg = process.argv[3];
+
+  if (!['status', 'pending', 'up', 'down'].includes(command)) {
+    console.error(`❌ Error: Unknown command "${command}"`);
+    printUsage();
+    process.exit(1);
+  }
+
+  // Base tables are still created, but migrations only run when asked for here
+  const db = new Database({ autoMigrate: false });
+
+  try {
+    await db.initialize();
+
+    if (command === 'status') {
+      const { migrations, missing } = await db.migrator.status();
+      console.log('\n📋 Schema migrations:\n');
+      migrations.forEach(m => console.log(formatMigration(m)));
+      missing.forEach(row => console.log(`  ❓ ${row.version}_${row.name} (applied, but file is missing)`));
+      console.log('');
+    } else if (command === 'pending') {
+      const pending = await db.migrator.pending();
+      if (pending.length === 0) {
+        console.log('\n✅ No pending migrations\n');
+      } else {
+        console.log(`\n⏳ ${pending.length} pending migration(s):\n`);
+        pending.forEach(m => console.log(formatMigration(m)));
+        console.log('');
+      }
+    } else if (command === 'up') {
+      const target = arg ? parseInt(arg, 10) : null;
+      if (arg && isNaN(target)) {
+        console.error('❌ Error: Target version must be a number');
+        process.exit(1);
+      }
+      const applied = await db.migrator.migrate(target);
+      console.log(`\n✅ Applied ${applied.length} migration(s)\n`);
+    } else if (command === 'down') {
+      const steps = arg ? parseInt(arg, 10) : 1;
+      if (isNaN(steps) || steps < 1) {
+        console.error('❌ Error: Steps must be a positive number');
+        process.exit(1);
+      }
+      const rolledBack = await db.migrator.rollback(steps);
+      console.log(`\n✅ Rolled back ${rolledBack.length} migration(s)\n`);
+    }
+  } catch (error) {
+    console.error('\n❌ Error:', error.message);
+    process.exit(1);
+  } finally {
+    await db.close();
+  }
+}
+
+main().catch(error => {
+  console.error('Fatal error:', error);
+  process.exit(1);
+});
@@ -0,0 +1,93 @@
+#!/usr/bin/env node
+/**
+ * Notification Outbox
+ *
+ * Usage:
+ *   node scripts/outbox.js status           Count queued, sent and dead-lettered messages
+ *   node scripts/outbox.js dead [limit]     List dead-lettered messages (default 20)
+ *   node scripts/outbox.js retry <id|all>   Requeue a dead-lettered message, or all of them
+ *
+ * Requeued messages are delivered by the running bot's outbox worker.
+ */
+
+// Load environment variables
+require('dotenv').config();
+
+const Database = require('../src/database/db');
+const NotificationOutbox = require('../src/services/notificationOutbox');
+
+function printUsage() {
+  console.log('\nUsage: node scripts/outbox.js <status|dead [limit]|retry <id|all>>');
+}
+
+async function main() {
+  const command = process.argv[2] || 'status';
+  const arg = process.argv[3];
+
+  if (!['status', 'dead', 'retry'].includes(command)) {
+    console.error(`❌ Error: Unknown command "${command}"`);
+    printUsage();
+    process.exit(1);
+  }
+
+  const db = new Database();
+  // No bot: this script only inspects and requeues, the running bot delivers
+  const outbox = new NotificationOutbox(db, null);
+
+  try {
+    await db.initialize();
+
+    if (command === 'status') {
+      const stats = await outbox.getStats();
+      console.log('\n📬 Notification outbox:\n');
+      console.log(`  ⏳ Pending: ${stats.pending}`);
+      console.log(`  📤 Sending: ${stats.sending}`);
+      console.log(`  ✅ Sent:    ${stats.sent}`);
+      console.log(`  💀 Dead:    ${stats.dead}\n`);
+    } else if (command === 'dead') {
+      const limit = arg ? parseInt(arg, 10) : 20;
+      if (isNaN(limit) || limit < 1) {
+        console.error('❌ Error: Limit must be a positive number');
+        process.exit(1);
+      }
+
+      const rows = await outbox.getDeadLetters(limit);
+      if (rows.length === 0) {
+        console.log('\n✅ No dead-lettered messages\n');
+        return;
+      }
+
+      console.log(`\n💀 ${rows.length} dead-lettered message(s):\n`);
+      rows.forEach(row => {
+        const recipient = row.recipient_type ? ` (${row.recipient_type} ${row.recipient_id})` : '';
+        console.log(`  #${row.id} ${row.method} → chat ${row.chat_id}${recipient}, ${row.attempts} attempt(s), queued ${new Date(row.created_at).toLocaleString()}`);
+        console.log(`     ${row.last_error || 'No error recorded'}`);
+      });
+      console.log('');
+    } else if (command === 'retry') {
+      if (!arg) {
+        printUsage();
+        process.exit(1);
+      }
+
+      const id = arg === 'all' ? null : parseInt(arg, 10);
+      if (arg !== 'all' && isNaN(id)) {
+        console.error('❌ Error: Expected a message id or "all"');
+        process.exit(1);
+      }
+
+      const requeued = await outbox.requeueDeadLetters(id);
+      console.log(`\n✅ Requeued ${requeued} message(s)\n`);
+    }
+  } catch (error) {
+    console.error('\n❌ Error:', error.message);
+    process.exit(1);
+  } finally {
+    await db.close();
+  }
+}
+
+main().catch(error => {
+  console.error('Fatal error:', error);
+  process.exit(1);
+});
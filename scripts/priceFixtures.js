@@ -0,0 +1,97 @@
+#!/usr/bin/env node
+/**
+ * Price History Fixtures
+ *
+ * Usage:
+ *   node scripts/priceFixtures.js load <file|dir>...                Store the candles of JSON fixtures
+ *   node scripts/priceFixtures.js fetch <asset> <since> [until]     Fetch hourly candles from the price APIs and store them
+ *   node scripts/priceFixtures.js export <asset> <since> [until]    Print stored candles as a fixture
+ *
+ * Assets are CoinGecko IDs (ethereum, solana, bitcoin, avalanche-2, apecoin, ...). Times are
+ * ISO dates or relative (6h, 30d); until defaults to now. A fixture is
+ * { "asset": "ethereum", "candles": [["2025-01-10T12:00:00Z", open, high, low, close], ...] }
+ * or an array of those.
+ *
+ * Example (prepare an offline store):
+ *   node scripts/priceFixtures.js fetch ethereum 90d
+ *   node scripts/priceFixtures.js export ethereum 90d > fixtures/prices/ethereum.json
+ *   PRICE_HISTORY_OFFLINE=true node scripts/priceFixtures.js load fixtures/prices
+ */
+
+// Load environment variables
+require('dotenv').config();
+
+const fs = require('fs');
+const Database = require('../src/database/db');
+const PriceHistoryService = require('../src/services/priceHistoryService');
+
+const RELATIVE_TIME_PATTERN = /^(\d+)([mhd])$/;
+const UNIT_MS = { m: 60 * 1000, h: 60 * 60 * 1000, d: 24 * 60 * 60 * 1000 };
+
+function printUsage() {
+  console.log('\nUsage: node scripts/priceFixtures.js <load <file|dir>...|fetch <asset> <since> [until]|export <asset> <since> [until]>');
+}
+
+function parseTime(value) {
+  const relative = value.match(RELATIVE_TIME_PATTERN);
+  const date = relative
+    ? new Date(Date.now() - parseInt(relative[1], 10) * UNIT_MS[relative[2]])
+    : new Date(value);
+
+  if (isNaN(date.getTime())) {
+    throw new Error(`Invalid time "${value}"`);
+  }
+  return date.getTime();
+}
+
+async function main() {
+  const [command, ...args] = process.argv.slice(2);
+
+  if (!['load', 'fetch', 'export'].includes(command) || args.length === 0 || (command !== 'load' && args.length < 2)) {
+    if (command) console.error(`❌ Error: Unknown command or missing arguments "${[command, ...args].join(' ')}"`);
+    printUsage();
+    process.exit(1);
+  }
+
+  const db = new Database();
+  const priceHistory = new PriceHistoryService(db);
+
+  try {
+    await db.initialize();
+
+    if (command === 'load') {
+      let total = 0;
+      for (const target of args) {
+        total += fs.statSync(target).isDirectory()
+          ? await priceHistory.loadFixtureDir(target)
+          : await priceHistory.loadFixtureFile(target);
+      }
+      console.log(`\n✅ Stored ${total} candle(s)\n`);
+      return;
+    }
+
+    const [asset, since, until] = args;
+    const from = parseTime(since);
+    const to = until ? parseTime(until) : Date.now();
+
+    if (command === 'fetch') {
+      const { candles, source } = await priceHistory.fetchCandles(asset, from, to);
+      const count = await priceHistory.storeCandles(asset, candles, source);
+      console.log(`\n✅ Stored ${count} ${asset} candle(s) from ${source}\n`);
+    } else {
+      const fixture = await priceHistory.exportFixture(asset, from, to);
+      console.error(`📈 ${fixture.candles.length} ${asset} candle(s)`);
+      console.log(JSON.stringify(fixture, null, 2));
+    }
+  } catch (error) {
+    console.error('\n❌ Error:', error.message);
+    process.exit(1);
+  } finally {
+    await db.close();
+  }
+}
+
+main().catch(error => {
+  console.error('Fatal error:', error);
+  process.exit(1);
+});
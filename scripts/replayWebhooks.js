@@ -0,0 +1,446 @@
+#!/usr/bin/env node
+/**
+ * Replay Logged Webhooks
+ *
+ * Re-feeds Alchemy and Helius payloads stored in webhook_logs through the webhook
+ * handlers, e.g. to reproduce a bug or to recover alerts lost during an outage.
+ * Runs as a dry run by default: messages are rendered and printed, nothing is queued
+ * and nothing is written to the database. Dry runs don't read receipts, block times or
+ * floors, value events with stored price candles only, and refuse every statement that
+ * isn't a SELECT. With --send, messages are queued in the notification outbox and
+ * delivered by the running bot's outbox worker.
+ *
+ * Usage:
+ *   node scripts/replayWebhooks.js [options]
+ *
+ * Options:
+ *   --type <alchemy|helius>   Only replay this webhook type (default: both)
+ *   --since <time>            Logged at or after (ISO date, or relative: 30m, 6h, 2d)
+ *   --until <time>            Logged before (same formats as --since)
+ *   --errors                  Only payloads that were logged with an error message
+ *   --failed                  Only payloads that were never processed successfully
+ *   --id <id>                 Only this webhook_logs row
+ *   --limit <n>               Maximum number of log rows to read (default 100)
+ *   --dry-run                 Render messages without sending (default)
+ *   --send                    Queue the messages for delivery to Telegram
+ *   --respect-dedup           Skip events already recorded in the shared dedup store
+ *   --include-rejected        Also replay payloads whose signature was rejected
+ *
+ * Examples:
+ *   node scripts/replayWebhooks.js --type helius --since 6h --failed
+ *   node scripts/replayWebhooks.js --since 2025-01-10T12:00:00Z --until 2025-01-10T14:00:00Z --send
+ */
+
+// Load environment variables
+require('dotenv').config();
+
+const Database = require('../src/database/db');
+const ChainManager = require('../src/services/chainManager');
+const TrendingService = require('../src/services/trendingService');
+const SecureTrendingService = require('../src/services/secureTrendingService');
+const MagicEdenService = require('../src/blockchain/magiceden');
+const HeliusService = require('../src/blockchain/helius');
+const WebhookHandlers = require('../src/webhooks/handlers');
+const SweepAggregator = require('../src/services/sweepAggregator');
+const NotificationOutbox = require('../src/services/notificationOutbox');
+const { DedupStore, DatabaseDedupBackend, MemoryDedupBackend, createDedupStore } = require('../src/services/dedupStore');
+
+const WEBHOOK_TYPES = ['alchemy', 'helius'];
+const RELATIVE_TIME_PATTERN = /^(\d+)([mhd])$/;
+const UNIT_MS = { m: 60 * 1000, h: 60 * 60 * 1000, d: 24 * 60 * 60 * 1000 };
+const READ_ONLY_SQL = /^\s*SELECT\b/i;
+
+function printUsage() {
+  console.log('\nUsage: node scripts/replayWebhooks.js [--type alchemy|helius] [--since <time>] [--until <time>] [--errors] [--failed] [--id <id>] [--limit <n>] [--dry-run|--send]');
+}
+
+function parseTime(value, flag) {
+  const relative = value.match(RELATIVE_TIME_PATTERN);
+  const date = relative
+    ? new Date(Date.now() - parseInt(relative[1], 10) * UNIT_MS[relative[2]])
+    : new Date(value);
+
+  if (isNaN(date.getTime())) {
+    throw new Error(`Invalid ${flag} value "${value}"`);
+  }
+  return date;
+}
+
+function parseArgs(argv) {
+  const options = {
+    type: null,
+    since: null,
+    until: null,
+    errors: false,
+    failed: false,
+    id: null,
+    limit: 100,
+    send: false,
+    respectDedup: false,
+    includeRejected: false
+  };
+
+  for (let i = 0; i < argv.length; i++) {
+    const arg = argv[i];
+    const next = () => {
+      if (argv[i + 1] === undefined) throw new Error(`${arg} requires a value`);
+      return argv[++i];
+    };
+
+    if (arg === '--type') {
+      options.type = next().toLowerCase();
+      if (!WEBHOOK_TYPES.includes(options.type)) {
+        throw new Error(`Unsupported --type "${options.type}" (expected ${WEBHOOK_TYPES.join(' or ')})`);
+      }
+    } else if (arg === '--since') {
+      options.since = parseTime(next(), arg);
+    } else if (arg === '--until') {
+      options.until = parseTime(next(), arg);
+    } else if (arg === '--errors') {
+      options.errors = true;
+    } else if (arg === '--failed') {
+      options.failed = true;
+    } else if (arg === '--id') {
+      options.id = parseInt(next(), 10);
+      if (isNaN(options.id)) throw new Error('--id must be a number');
+    } else if (arg === '--limit') {
+      options.limit = parseInt(next(), 10);
+      if (isNaN(options.limit) || options.limit < 1) throw new Error('--limit must be a positive number');
+    } else if (arg === '--dry-run') {
+      options.send = false;
+    } else if (arg === '--send') {
+      options.send = true;
+    } else if (arg === '--respect-dedup') {
+      options.respectDedup = true;
+    } else if (arg === '--include-rejected') {
+      options.includeRejected = true;
+    } else {
+      throw new Error(`Unknown option "${arg}"`);
+    }
+  }
+
+  return options;
+}
+
+async function loadLogs(db, options) {
+  const conditions = [];
+  const params = [];
+  const addCondition = (sql, value) => {
+    params.push(value);
+    conditions.push(sql.replace('?', `$${params.length}`));
+  };
+
+  if (options.id) addCondition('id = ?', options.id);
+  if (options.type) {
+    addCondition('webhook_type = ?', options.type);
+  } else {
+    conditions.push(`webhook_type IN (${WEBHOOK_TYPES.map(type => `'${type}'`).join(', ')})`);
+  }
+  if (options.since) addCondition('created_at >= ?', options.since);
+  if (options.until) addCondition('created_at < ?', options.until);
+  if (options.errors) conditions.push('error_message IS NOT NULL');
+
+  params.push(options.limit);
+  const rows = await db.all(
+    `SELECT id, webhook_type, payload, processed, error_message, created_at
+     FROM webhook_logs
+     WHERE ${conditions.join(' AND ')}
+     ORDER BY created_at ASC, id ASC
+     LIMIT $${params.length}`,
+    params
+  );
+
+  // Handlers log each payload before and after processing, so collapse rows with the same
+  // payload into one replay and remember whether any of them was processed successfully
+  const byPayload = new Map();
+  for (const row of rows) {
+    const key = `${row.webhook_type}:${row.payload}`;
+    const entry = byPayload.get(key) || { ...row, anyProcessed: false, rejected: false };
+    entry.anyProcessed = entry.anyProcessed || !!row.processed;
+    entry.rejected = entry.rejected || (row.error_message || '').startsWith('Signature rejected');
+    entry.error_message = row.error_message || entry.error_message;
+    byPayload.set(key, entry);
+  }
+
+  return Array.from(byPayload.values()).filter(entry =>
+    (!options.failed || !entry.anyProcessed) && (options.includeRejected || !entry.rejected)
+  );
+}
+
+/**
+ * Make a database refuse writes for a dry run: any statement other than a SELECT throws, so
+ * a service that would write fails its entry instead of changing the database
+ */
+function makeReadOnly(db) {
+  const { adapter } = db;
+  const assertReadOnly = (sql) => {
+    if (!READ_ONLY_SQL.test(sql)) {
+      throw new Error(`Dry run: refusing to write to the database (${sql.trim().split(/\s+/).slice(0, 3).join(' ')} ...)`);
+    }
+  };
+
+  const query = adapter.query.bind(adapter);
+  adapter.query = async (text, params) => {
+    assertReadOnly(text);
+    return query(text, params);
+  };
+  // No transaction is opened: its statements could only be reads
+  adapter.transaction = async (work) => work(adapter.query);
+}
+
+/**
+ * Dedup backend for dry runs that honour the shared store: keys recorded by the bot are
+ * read from processed_events, the replay's own claims are only kept in memory
+ */
+function createDryRunDedupBackend(db) {
+  const stored = new DatabaseDedupBackend(db);
+  const local = new MemoryDedupBackend();
+  return {
+    name: 'database (read-only)',
+    claim: async (key, source, expiresAt) => !await stored.has(key) && local.claim(key, source, expiresAt),
+    has: async (key) => await stored.has(key) || local.has(key),
+    cleanup: () => local.cleanup()
+  };
+}
+
+/**
+ * Stand-ins for the services a routed event would use to fetch from external APIs or to
+ * write: receipts, block times and floors aren't read, prices come from stored candles only
+ * and rarity from stored snapshots, and no snapshot or stats alert can be started
+ */
+function useDryRunServices(handlers) {
+  const { rarity } = handlers;
+  handlers.saleAttribution = {
+    attribute: async () => false,
+    stampBlockTime: async () => {},
+    getStats: () => ({})
+  };
+  handlers.priceHistory.offline = true;
+  handlers.rarity = {
+    getItemRarity: (tokenId, nftId) => rarity.getItemRarity(tokenId, nftId),
+    getItemTraits: (tokenId, nftId) => rarity.getItemTraits(tokenId, nftId)
+  };
+  handlers.collectionStats = {
+    getStats: async () => null,
+    getFloorPrice: async () => null
+  };
+  handlers.statsAlerts = {
+    start: () => {},
+    stop: async () => {},
+    getStats: async () => ({})
+  };
+}
+
+/**
+ * Outbox stand-in for dry runs: records every queued message instead of storing it
+ */
+function createDryRunOutbox(rendered) {
+  return {
+    enqueue: async ({ chatId, method, photo, text, extra = {} }) => {
+      rendered.push({ method, chatId, text: extra.caption || text || '', image: photo });
+      return { id: null };
+    }
+  };
+}
+
+/**
+ * Digest stand-in for dry runs: chats in quiet hours or digest mode are still recognised,
+ * but held events are only recorded instead of being written to digest_events
+ */
+function createDryRunDigests(digests, rendered) {
+  return {
+    getHoldReason: (chatId) => digests.getHoldReason(chatId),
+    hold: async (target, token, event, reason) => {
+      rendered.push({ method: `held for ${reason} digest`, chatId: target.chatId, text: `${event.type} ${event.tokenId || ''}`.trim() });
+    }
+  };
+}
+
+/**
+ * Sweep batches kept in memory for a replay, so replayed sales never mix with the sales the
+ * running bot holds in pending_sweep_sales. Batches are delivered after each entry.
+ */
+function createReplaySweeps(handlers) {
+  const batches = new Map();
+  return {
+    windowMs: handlers.sweeps.windowMs,
+    add: async (token, event) => {
+      const key = SweepAggregator.getKey(token, event);
+      if (!batches.has(key)) batches.set(key, { token, events: [] });
+      batches.get(key).events.push(event);
+    },
+    flushAll: async () => {
+      const pending = [...batches.values()];
+      batches.clear();
+      for (const { token, events } of pending) {
+        await handlers.deliverSaleBatch(token, events);
+      }
+    }
+  };
+}
+
+async function replayEntry(handlers, entry) {
+  const payload = JSON.parse(entry.payload);
+
+  if (entry.webhook_type === 'alchemy') {
+    if (payload.type !== 'NFT_ACTIVITY') {
+      return { replayed: 0, skipped: `unsupported Alchemy payload type ${payload.type}` };
+    }
+    const processed = await handlers.handleNFTActivity(payload);
+    return { replayed: processed ? 1 : 0 };
+  }
+
+  const transactions = (Array.isArray(payload) ? payload : [payload]).filter(tx => ['NFT_SALE', 'NFT_LISTING'].includes(tx.type));
+  let replayed = 0;
+  for (const transaction of transactions) {
+    const processed = transaction.type === 'NFT_SALE'
+      ? await handlers.handleHeliusNFTSale(transaction)
+      : await handlers.handleHeliusNFTListing(transaction);
+    if (processed) replayed++;
+  }
+  return { replayed, skipped: transactions.length === 0 ? 'no NFT_SALE or NFT_LISTING transactions' : null };
+}
+
+/**
+ * Replay logged webhooks through a fresh set of handlers
+ * @param {Database} db - Initialized database; made read-only for dry runs
+ * @param {Array<Object>} entries - Entries from loadLogs
+ * @param {Object} options - Options from parseArgs
+ * @param {Object} services - { chainManager, magicEden, secureTrending, helius }
+ * @param {Function} print - Writes one line of the report
+ * @returns {Promise<{replayed: number, rendered: Array<Object>}>}
+ */
+async function replayLogs(db, entries, options, services, print = console.log) {
+  const rendered = [];
+  if (!options.send) {
+    // Handlers log every payload and event; a dry run only prints them
+    db.logNFTActivity = async () => ({ id: null });
+    db.logWebhook = async () => ({ id: null });
+    makeReadOnly(db);
+  }
+
+  // Original deliveries already claimed their dedup keys, so by default the replay
+  // only deduplicates within itself
+  let dedupStore;
+  if (!options.respectDedup) {
+    dedupStore = new DedupStore(new MemoryDedupBackend());
+  } else {
+    dedupStore = options.send ? createDedupStore(db) : new DedupStore(createDryRunDedupBackend(db));
+  }
+
+  try {
+    // The outbox worker isn't started here: queued messages are delivered by the running bot
+    const outbox = options.send ? new NotificationOutbox(db, null) : createDryRunOutbox(rendered);
+    const handlers = new WebhookHandlers(
+      db,
+      null, // messages only go through the outbox
+
+      new TrendingService(db),
+      services.secureTrending || null,
+      null, // OpenSea streaming is not needed for replays
+      services.chainManager,
+      services.magicEden || null,
+      services.helius || null,
+      null,
+      null,
+      dedupStore,
+      outbox
+    );
+    handlers.sweeps = createReplaySweeps(handlers);
+    if (!options.send) {
+      handlers.digests = createDryRunDigests(handlers.digests, rendered);
+      useDryRunServices(handlers);
+    }
+
+    print(`\n🔁 Replaying ${entries.length} logged webhook(s) ${options.send ? '(queueing for delivery)' : '(dry run)'}\n`);
+
+    let totalReplayed = 0;
+    for (const entry of entries) {
+      const createdAt = new Date(entry.created_at).toLocaleString();
+      const errorNote = entry.error_message ? ` - logged error: ${entry.error_message}` : '';
+      print(`📥 #${entry.id} ${entry.webhook_type} (${createdAt})${errorNote}`);
+
+      const messageCountBefore = rendered.length;
+      try {
+        const { replayed, skipped } = await replayEntry(handlers, entry);
+        // Sales wait for their sweep window; render them with the entry they came from
+        await handlers.sweeps.flushAll();
+        totalReplayed += replayed;
+        print(skipped ? `   ⏭️ Skipped: ${skipped}` : `   ✅ ${replayed} event(s) replayed`);
+      } catch (error) {
+        print(`   ❌ Replay failed: ${error.message}`);
+      }
+
+      rendered.slice(messageCountBefore).forEach(message => {
+        print(`   📨 ${message.method} → chat ${message.chatId}${message.image ? ` (image: ${message.image})` : ''}`);
+        print(message.text.split('\n').map(line => `      ${line}`).join('\n'));
+      });
+      print('');
+    }
+
+    return { replayed: totalReplayed, rendered };
+  } finally {
+    dedupStore.close();
+  }
+}
+
+async function main() {
+  let options;
+  try {
+    options = parseArgs(process.argv.slice(2));
+  } catch (error) {
+    console.error(`❌ Error: ${error.message}`);
+    printUsage();
+    process.exit(1);
+  }
+
+  const db = new Database();
+
+  try {
+    await db.initialize();
+
+    const entries = await loadLogs(db, options);
+    if (entries.length === 0) {
+      console.log('\n❌ No logged webhooks match these filters.\n');
+      return;
+    }
+
+    const chainManager = new ChainManager(db);
+    await chainManager.initialize();
+
+    const magicEden = new MagicEdenService();
+    await magicEden.initialize().catch(error => console.warn(`⚠️  Magic Eden unavailable: ${error.message}`));
+
+    let secureTrending = new SecureTrendingService(db, chainManager);
+    await secureTrending.initialize().catch(error => {
+      console.warn(`⚠️  Secure trending unavailable: ${error.message}`);
+      secureTrending = null;
+    });
+
+    const { replayed, rendered } = await replayLogs(db, entries, options, {
+      chainManager,
+      magicEden,
+      secureTrending,
+      helius: new HeliusService()
+    });
+
+    const deliveryNote = options.send ? 'messages queued in the notification outbox' : `${rendered.length} message(s) rendered, nothing queued`;
+    console.log(`✅ Done: ${replayed} event(s) replayed, ${deliveryNote}\n`);
+  } catch (error) {
+    console.error('\n❌ Error:', error.message);
+    process.exitCode = 1;
+  } finally {
+    await db.close();
+    // Services started above keep timers running; don't wait for them
+    process.exit();
+  }
+}
+
+if (require.main === module) {
+  main().catch(error => {
+    console.error('Fatal error:', error);
+    process.exit(1);
+  });
+}
+
+module.exports = { parseArgs, loadLogs, replayLogs };
@@ -0,0 +1,124 @@
+const axios = require('axios');
+const logger = require('../services/logger');
+
+/**
+ * Alchemy Notify API, used for the ADDRESS_ACTIVITY webhooks of watched EVM wallets.
+ * Requests are authenticated with ALCHEMY_AUTH_TOKEN (the dashboard auth token, not the API key).
+ */
+class AlchemyNotifyService {
+  constructor() {
+    this.authToken = process.env.ALCHEMY_AUTH_TOKEN;
+    this.apiBaseUrl = 'https://dashboard.alchemy.com/api';
+  }
+
+  isConfigured() {
+    return !!this.authToken;
+  }
+
+  get headers() {
+    return {
+      'X-Alchemy-Token': this.authToken,
+      'Content-Type': 'application/json'
+    };
+  }
+
+  /**
+   * Create an ADDRESS_ACTIVITY webhook
+   * @param {string} network - Alchemy network, e.g. ETH_MAINNET
+   * @param {string} webhookURL - The URL to receive webhook notifications
+   * @param {Array<string>} addresses - Addresses to monitor
+   * @returns {Promise<Object>} Result with webhook ID and signing key
+   */
+  async createAddressWebhook(network, webhookURL, addresses) {
+    try {
+      logger.info(`Creating Alchemy address activity webhook on ${network}...`);
+
+      const response = await axios.post(`${this.apiBaseUrl}/create-webhook`, {
+        network,
+        webhook_type: 'ADDRESS_ACTIVITY',
+        webhook_url: webhookURL,
+        addresses
+      }, {
+        timeout: 15000,
+        headers: this.headers
+      });
+
+      const webhook = response.data?.data;
+      if (!webhook?.id) {
+        throw new Error('Failed to create webhook - no webhook ID returned');
+      }
+
+      logger.info(`✅ Alchemy webhook created successfully: ${webhook.id} (${addresses.length} address(es))`);
+      return {
+        success: true,
+        webhookId: webhook.id,
+        signingKey: webhook.signing_key || null
+      };
+    } catch (error) {
+      logger.error(`Failed to create Alchemy webhook on ${network}:`, error.response?.data || error.message);
+      return {
+        success: false,
+        error: error.response?.data?.message || error.message
+      };
+    }
+  }
+
+  /**
+   * Replace the address list of an ADDRESS_ACTIVITY webhook
+   * @param {string} webhookId - The webhook ID
+   * @param {Array<string>} addresses - Complete list of addresses to monitor
+   * @returns {Promise<Object>} Update result
+   */
+  async replaceWebhookAddresses(webhookId, addresses) {
+    try {
+      await axios.put(`${this.apiBaseUrl}/update-webhook-addresses`, {
+        webhook_id: webhookId,
+        addresses
+      }, {
+        timeout: 15000,
+        headers: this.headers
+      });
+
+      logger.info(`✅ Alchemy webhook ${webhookId} now monitors ${addresses.length} address(es)`);
+      return { success: true, webhookId };
+    } catch (error) {
+      logger.error(`Failed to update Alchemy webhook ${webhookId}:`, error.response?.data || error.message);
+      return {
+        success: false,
+        notFound: error.response?.status === 404,
+        error: error.response?.data?.message || error.message
+      };
+    }
+  }
+
+  /**
+   * Delete a webhook by ID
+   * @param {string} webhookId - The webhook ID to delete
+   * @returns {Promise<Object>} Deletion result
+   */
+  async deleteWebhook(webhookId) {
+    try {
+      await axios.delete(`${this.apiBaseUrl}/delete-webhook`, {
+        params: { webhook_id: webhookId },
+        timeout: 10000,
+        headers: this.headers
+      });
+
+      logger.info(`✅ Alchemy webhook deleted successfully: ${webhookId}`);
+      return { success: true, webhookId };
+    } catch (error) {
+      if (error.response?.status === 404) {
+        logger.warn(`Alchemy webhook ${webhookId} not found (may have been already deleted)`);
+        return { success: true, webhookId, note: 'Webhook not found (already deleted)' };
+      }
+
+      logger.error(`Failed to delete Alchemy webhook ${webhookId}:`, error.response?.data || error.message);
+      return {
+        success: false,
+        error: error.response?.data?.message || error.message
+      };
+    }
+  }
+}
+
+module.exports = AlchemyNotifyService;
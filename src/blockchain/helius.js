@@ -5,6 +5,7 @@ class HeliusService {
   constructor() {
     this.apiKey = process.env.HELIUS_API_KEY;
     this.apiBaseUrl = 'https://api.helius.xyz/v0';
+    this.rpcUrl = 'https://mainnet.helius-rpc.com';
     this.webhookAuthToken = process.env.HELIUS_WEBHOOK_AUTH_TOKEN;
     this.magicEdenProgram = 'M2mx93ekt1fmXSVkTrUL9xVFHkmME8HTUi5Cyc5aF7K'; // Magic Eden v2 program
     this.webhooks = new Map(); // Track active webhooks by collection/mint
@@ -35,22 +36,23 @@ class HeliusService {
   }
 
   /**
-   * Create a webhook for monitoring Magic Eden NFT sales
+   * Create a webhook for monitoring Magic Eden NFT sales (or other transaction types)
    * @param {string} webhookURL - The URL to receive webhook notifications
    * @param {Array<string>} accountAddresses - Solana addresses to monitor (optional, defaults to Magic Eden program)
    * @param {string} webhookName - Optional name for the webhook
+   * @param {Array<string>} transactionTypes - Enhanced transaction types to deliver (defaults to NFT_SALE)
    * @returns {Promise<Object>} Webhook creation result with webhook ID
    */
-  async createWebhook(webhookURL, accountAddresses = null, webhookName = null) {
+  async createWebhook(webhookURL, accountAddresses = null, webhookName = null, transactionTypes = ['NFT_SALE']) {
     try {
-      logger.info(`Creating Helius webhook for Magic Eden sales...`);
+      logger.info(`Creating Helius webhook for ${transactionTypes.join(', ')}...`);
 
       // Default to monitoring Magic Eden program for all NFT sales
       const addresses = accountAddresses || [this.magicEdenProgram];
 
       const payload = {
         webhookURL: webhookURL,
-        transactionTypes: ['NFT_SALE'],
+        transactionTypes,
         accountAddresses: addresses,
         webhookType: 'enhanced', // Enhanced webhooks provide parsed, human-readable data
         authHeader: this.webhookAuthToken
@@ -76,6 +78,7 @@ class HeliusService {
           webhookId,
           webhookURL,
           accountAddresses: addresses,
+          transactionTypes,
           createdAt: new Date().toISOString()
         });
 
@@ -187,11 +190,31 @@ class HeliusService {
       logger.error(`Failed to update Helius webhook ${webhookId}:`, error.response?.data || error.message);
       return {
         success: false,
+        notFound: error.response?.status === 404,
         error: error.response?.data?.error || error.message
       };
     }
   }
 
+  /**
+   * Replace the monitored addresses of a webhook. Helius replaces the whole definition
+   * on update, so the other fields are sent again.
+   * @param {string} webhookId - The webhook ID to update
+   * @param {string} webhookURL - The URL to receive webhook notifications
+   * @param {Array<string>} accountAddresses - Complete list of addresses to monitor
+   * @param {Array<string>} transactionTypes - Enhanced transaction types to deliver
+   * @returns {Promise<Object>} Update result
+   */
+  async setWebhookAddresses(webhookId, webhookURL, accountAddresses, transactionTypes) {
+    return this.updateWebhook(webhookId, {
+      webhookURL,
+      transactionTypes,
+      accountAddresses,
+      webhookType: 'enhanced',
+      authHeader: this.webhookAuthToken
+    });
+  }
+
   /**
    * List all webhooks for this account
    * @returns {Promise<Array>} Array of webhooks
@@ -329,6 +352,36 @@ class HeliusService {
     }
   }
 
+  /**
+   * Parse Helius enhanced webhook payload for NFT listings (below-floor snipe alerts)
+   * @param {Object} transaction - The transaction object from Helius webhook
+   * @returns {Object|null} Parsed listing data
+   */
+  parseNFTListingEvent(transaction) {
+    if (transaction.type !== 'NFT_LISTING') {
+      return null;
+    }
+
+    const nftEvent = transaction.events?.nft;
+    if (!nftEvent) {
+      logger.warn('NFT listing event missing NFT data');
+      return null;
+    }
+
+    return {
+      type: 'NFT_LISTING',
+      signature: transaction.signature,
+      timestamp: transaction.timestamp,
+      slot: transaction.slot,
+      amount: nftEvent.amount, // List price in lamports
+      amountSol: nftEvent.amount ? (nftEvent.amount / 1e9).toFixed(4) : '0',
+      seller: nftEvent.seller,
+      nfts: nftEvent.nfts || [],
+      mintAddress: nftEvent.nfts?.[0]?.mint || null,
+      source: nftEvent.source || 'MAGIC_EDEN'
+    };
+  }
+
   /**
    * Create webhook for specific NFT collection monitoring
    * @param {string} collectionSymbol - Magic Eden collection symbol
@@ -338,16 +391,72 @@ class HeliusService {
   async createCollectionWebhook(collectionSymbol, webhookURL) {
     try {
       // For collection-wide monitoring, we use the Magic Eden program address
-      // Helius will send all NFT sales on Magic Eden, and we filter by collection in our handler
+      // Helius will send all NFT sales and listings on Magic Eden, and we filter by collection in our handler
       const webhookName = `Magic Eden - ${collectionSymbol}`;
 
-      return await this.createWebhook(webhookURL, [this.magicEdenProgram], webhookName);
+      return await this.createWebhook(webhookURL, [this.magicEdenProgram], webhookName, ['NFT_SALE', 'NFT_LISTING']);
     } catch (error) {
       logger.error(`Failed to create collection webhook for ${collectionSymbol}:`, error);
       return { success: false, error: error.message };
     }
   }
 
+  /**
+   * Call a Digital Asset Standard (DAS) method on the Helius RPC
+   * @param {string} method - e.g. getAsset, getAssetsByGroup
+   * @param {Object} params
+   * @returns {Promise<Object|null>} JSON-RPC result, null on error
+   */
+  async callDas(method, params) {
+    try {
+      const response = await axios.post(this.rpcUrl, {
+        jsonrpc: '2.0',
+        id: method,
+        method,
+        params
+      }, {
+        params: { 'api-key': this.apiKey },
+        timeout: 30000,
+        headers: { 'Content-Type': 'application/json' }
+      });
+
+      if (response.data?.error) {
+        throw new Error(response.data.error.message || JSON.stringify(response.data.error));
+      }
+      return response.data?.result || null;
+    } catch (error) {
+      logger.error(`Helius ${method} failed:`, error.response?.data || error.message);
+      return null;
+    }
+  }
+
+  /**
+   * Verified collection address of an NFT
+   * @param {string} mintAddress - Any NFT of the collection
+   * @returns {Promise<string|null>} Collection address, null if the NFT has none
+   */
+  async getCollectionAddress(mintAddress) {
+    const asset = await this.callDas('getAsset', { id: mintAddress });
+    return asset?.grouping?.find(group => group.group_key === 'collection')?.group_value || null;
+  }
+
+  /**
+   * One page of the NFTs in a collection, with their metadata (rarity snapshots)
+   * @param {string} collectionAddress - Verified collection address
+   * @param {number} page - 1-based page number
+   * @param {number} limit - Items per page (max 1000)
+   * @returns {Promise<Array|null>} DAS assets, null on error
+   */
+  async getAssetsByCollection(collectionAddress, page = 1, limit = 1000) {
+    const result = await this.callDas('getAssetsByGroup', {
+      groupKey: 'collection',
+      groupValue: collectionAddress,
+      page,
+      limit
+    });
+    return result ? result.items || [] : null;
+  }
+
   /**
    * Get connection status
    * @returns {Object} Connection status
@@ -0,0 +1,520 @@
+const axios = require('axios');
+const crypto = require('crypto');
+const logger = require('../services/logger');
+
+// Every predicate this bot registers is named with this prefix; others on the account are left alone
+const PREDICATE_PREFIX = 'minttechbot-ordinals-';
+const WALLETS_PREDICATE_NAME = `${PREDICATE_PREFIX}wallets`;
+const COLLECTION_CACHE_SIZE = 5000;
+// Inscriptions per page of the Hiro Ordinals API (its maximum)
+const ORDINALS_PAGE_SIZE = 60;
+// Tracked collections' inscription IDs are reloaded from Magic Eden this often (failed loads sooner)
+const COLLECTION_INDEX_TTL_MS = 6 * 60 * 60 * 1000;
+const COLLECTION_INDEX_RETRY_MS = 10 * 60 * 1000;
+const COLLECTION_INDEX_PAGE_SIZE = 100;
+
+class HiroOrdinalsService {
+  constructor(magicEdenOrdinalsService = null) {
+    this.apiKey = process.env.HIRO_API_KEY;
+    this.platformBaseUrl = process.env.HIRO_PLATFORM_API_URL || 'https://api.platform.hiro.so';
+    this.chainhookAuthToken = process.env.HIRO_CHAINHOOK_AUTH_TOKEN;
+    this.network = process.env.HIRO_NETWORK || 'mainnet';
+    this.webhookURL = process.env.WEBHOOK_URL ? `${process.env.WEBHOOK_URL.replace(/\/$/, '')}/webhook/hiro` : null;
+    this.ordinalsApiUrl = process.env.HIRO_ORDINALS_API_URL || 'https://api.hiro.so/ordinals/v1';
+    this.magicEdenOrdinals = magicEdenOrdinalsService;
+    this.predicates = new Map(); // predicate name -> { uuid, url }
+    this.collectionCache = new Map(); // inscription ID -> Magic Eden collection symbol (or null)
+    this.collectionIndex = new Map(); // tracked collection symbol -> { ids: Set, expiresAt }
+    this.isConnected = false;
+  }
+
+  async initialize() {
+    try {
+      if (!this.chainhookAuthToken) {
+        throw new Error('HIRO_CHAINHOOK_AUTH_TOKEN not found in environment variables');
+      }
+
+      if (this.canManagePredicates()) {
+        // Pick up the predicates registered by a previous run
+        this.predicates = await this.findPredicates();
+      } else {
+        logger.warn('HIRO_API_KEY or WEBHOOK_URL not set - chainhook predicates must be managed manually');
+      }
+
+      this.isConnected = true;
+      logger.info('₿ Hiro Chainhook service initialized successfully');
+      return true;
+    } catch (error) {
+      logger.error('Failed to initialize Hiro Chainhook service:', error.message);
+      this.isConnected = false;
+      throw error;
+    }
+  }
+
+  canManagePredicates() {
+    return !!(this.apiKey && this.webhookURL);
+  }
+
+  get chainhooksUrl() {
+    return `${this.platformBaseUrl}/v1/ext/${this.apiKey}/chainhooks`;
+  }
+
+  /**
+   * Name of the predicate of a tracked collection, or of the watched wallets
+   * @param {{collection?: string, wallets?: boolean}} target
+   */
+  getPredicateName({ collection = null, wallets = false } = {}) {
+    return wallets ? WALLETS_PREDICATE_NAME : `${PREDICATE_PREFIX}collection-${collection}`;
+  }
+
+  /**
+   * URL a predicate posts to: /webhook/hiro with the collection (or the wallets) it serves,
+   * so each delivery is only matched against its own target
+   */
+  getPredicateUrl({ collection = null, wallets = false } = {}) {
+    const query = wallets ? 'scope=wallets' : `collection=${encodeURIComponent(collection)}`;
+    return `${this.webhookURL}?${query}`;
+  }
+
+  /**
+   * Build the chainhook predicate that posts inscription transfers to /webhook/hiro. Hiro's
+   * ordinals scope only offers the whole inscription feed, so the target is carried in the URL.
+   * @param {{collection?: string, wallets?: boolean}} target
+   * @returns {Object} Predicate definition
+   */
+  buildPredicate(target) {
+    return {
+      uuid: crypto.randomUUID(),
+      name: this.getPredicateName(target),
+      version: 1,
+      chain: 'bitcoin',
+      networks: {
+        [this.network]: {
+          if_this: {
+            scope: 'ordinals_protocol',
+            operation: 'inscription_feed'
+          },
+          then_that: {
+            http_post: {
+              url: this.getPredicateUrl(target),
+              authorization_header: `Bearer ${this.chainhookAuthToken}`
+            }
+          }
+        }
+      }
+    };
+  }
+
+  /**
+   * List chainhooks registered on the Hiro Platform
+   * @returns {Promise<Array>} Chainhooks
+   */
+  async listPredicates() {
+    const response = await axios.get(this.chainhooksUrl, { timeout: 10000 });
+    return Array.isArray(response.data) ? response.data : [];
+  }
+
+  /**
+   * This bot's predicates, by name
+   * @returns {Promise<Map<string, {uuid: string, url: string|null}>>}
+   */
+  async findPredicates() {
+    const predicates = new Map();
+    for (const chainhook of await this.listPredicates()) {
+      const definition = chainhook.definition || chainhook;
+      if (!definition.name?.startsWith(PREDICATE_PREFIX)) continue;
+
+      predicates.set(definition.name, {
+        uuid: chainhook.uuid || definition.uuid,
+        url: definition.networks?.[this.network]?.then_that?.http_post?.url || null
+      });
+    }
+    return predicates;
+  }
+
+  /**
+   * Register one predicate per tracked collection (and one for the watched wallets), delete
+   * the predicates of collections no longer tracked, and replace those pointing at an old
+   * WEBHOOK_URL
+   * @param {Object} targets
+   * @param {Array<string>} targets.collections - Magic Eden symbols of the tracked collections
+   * @param {boolean} targets.wallets - Whether any Bitcoin wallet is watched
+   * @returns {Promise<Object>} { success, created: [names], deleted: [names], errors: [messages] }
+   */
+  async syncPredicates({ collections = [], wallets = false } = {}) {
+    if (!this.canManagePredicates()) {
+      return { success: false, error: 'Predicate management disabled (HIRO_API_KEY or WEBHOOK_URL missing)' };
+    }
+
+    const wanted = new Map();
+    for (const collection of new Set(collections)) {
+      wanted.set(this.getPredicateName({ collection }), { collection });
+    }
+    if (wallets) {
+      wanted.set(WALLETS_PREDICATE_NAME, { wallets: true });
+    }
+
+    const result = { success: true, created: [], deleted: [], errors: [] };
+    try {
+      this.predicates = await this.findPredicates();
+    } catch (error) {
+      logger.error('Failed to list Hiro chainhook predicates:', error.response?.data || error.message);
+      return { success: false, error: error.response?.data?.message || error.message };
+    }
+
+    for (const [name, existing] of [...this.predicates]) {
+      const target = wanted.get(name);
+      if (target && existing.url === this.getPredicateUrl(target)) continue;
+
+      if (target) {
+        // WEBHOOK_URL changed since the predicate was registered
+        logger.info(`Hiro predicate ${name} points at ${existing.url}, replacing it`);
+      }
+      const deletion = await this.deletePredicate(name);
+      if (deletion.success) {
+        if (!target) result.deleted.push(name);
+      } else {
+        result.errors.push(`${name}: ${deletion.error}`);
+      }
+    }
+
+    for (const [name, target] of wanted) {
+      if (this.predicates.has(name)) continue;
+
+      const creation = await this.registerPredicate(target);
+      if (creation.success) {
+        result.created.push(name);
+      } else {
+        result.errors.push(`${name}: ${creation.error}`);
+      }
+    }
+
+    result.success = result.errors.length === 0;
+    return result;
+  }
+
+  /**
+   * Register the predicate of one collection (or of the watched wallets)
+   * @param {{collection?: string, wallets?: boolean}} target
+   * @returns {Promise<Object>} Result with predicate UUID
+   */
+  async registerPredicate(target) {
+    const predicate = this.buildPredicate(target);
+    try {
+      const response = await axios.post(this.chainhooksUrl, predicate, {
+        timeout: 15000,
+        headers: { 'Content-Type': 'application/json' }
+      });
+
+      const uuid = response.data?.chainhookUuid || predicate.uuid;
+      this.predicates.set(predicate.name, { uuid, url: this.getPredicateUrl(target) });
+      logger.info(`✅ Hiro chainhook predicate ${predicate.name} registered: ${uuid}`);
+      return { success: true, predicateUuid: uuid };
+    } catch (error) {
+      logger.error(`Failed to register Hiro chainhook predicate ${predicate.name}:`, error.response?.data || error.message);
+      return { success: false, error: error.response?.data?.message || error.message };
+    }
+  }
+
+  /**
+   * Delete one of this bot's predicates
+   * @param {string} name - Predicate name
+   * @returns {Promise<Object>} Deletion result
+   */
+  async deletePredicate(name) {
+    if (!this.canManagePredicates()) {
+      return { success: false, error: 'Predicate management disabled (HIRO_API_KEY or WEBHOOK_URL missing)' };
+    }
+
+    const uuid = this.predicates.get(name)?.uuid;
+    if (!uuid) {
+      return { success: true, note: 'No predicate registered' };
+    }
+
+    try {
+      await axios.delete(`${this.chainhooksUrl}/${uuid}`, { timeout: 10000 });
+      logger.info(`✅ Hiro chainhook predicate ${name} deleted: ${uuid}`);
+      this.predicates.delete(name);
+      return { success: true, predicateUuid: uuid };
+    } catch (error) {
+      if (error.response?.status === 404) {
+        logger.warn(`Hiro predicate ${name} (${uuid}) not found (may have been already deleted)`);
+        this.predicates.delete(name);
+        return { success: true, predicateUuid: uuid, note: 'Predicate not found (already deleted)' };
+      }
+
+      logger.error(`Failed to delete Hiro chainhook predicate ${name}:`, error.response?.data || error.message);
+      return { success: false, error: error.response?.data?.message || error.message };
+    }
+  }
+
+  /**
+   * Verify the bearer token on incoming chainhook requests
+   * @param {string} authHeader - Authorization header from the request
+   * @returns {boolean} True if valid
+   */
+  verifyChainhookAuth(authHeader) {
+    if (!authHeader || !this.chainhookAuthToken) {
+      logger.warn('Missing auth header or chainhook token');
+      return false;
+    }
+
+    const match = authHeader.match(/^Bearer\s+(.+)$/i);
+    if (!match) {
+      logger.warn('Chainhook auth header is not a bearer token');
+      return false;
+    }
+
+    const received = Buffer.from(match[1].trim());
+    const expected = Buffer.from(this.chainhookAuthToken);
+    const isValid = received.length === expected.length && crypto.timingSafeEqual(received, expected);
+    if (!isValid) {
+      logger.warn('Invalid chainhook bearer token');
+    }
+
+    return isValid;
+  }
+
+  /**
+   * Collect inscription transfers from a chainhook payload
+   * @param {Object} payload - Chainhook request body
+   * @returns {Array<Object>} { block_identifier, transaction_identifier, operation } per transfer
+   */
+  extractInscriptionTransfers(payload) {
+    const transfers = [];
+
+    for (const block of payload.apply || []) {
+      for (const transaction of block.transactions || []) {
+        for (const operation of transaction.metadata?.ordinal_operations || []) {
+          if (operation.inscription_transferred) {
+            transfers.push({
+              block_identifier: block.block_identifier,
+              timestamp: block.timestamp,
+              transaction_identifier: transaction.transaction_identifier,
+              operation: operation.inscription_transferred
+            });
+          }
+        }
+      }
+    }
+
+    return transfers;
+  }
+
+  /**
+   * Collect inscription reveals (new inscriptions) from a chainhook payload
+   * @param {Object} payload - Chainhook request body
+   * @returns {Array<Object>} { block_identifier, transaction_identifier, operation } per reveal
+   */
+  extractInscriptionReveals(payload) {
+    const reveals = [];
+
+    for (const block of payload.apply || []) {
+      for (const transaction of block.transactions || []) {
+        for (const operation of transaction.metadata?.ordinal_operations || []) {
+          if (operation.inscription_revealed) {
+            reveals.push({
+              block_identifier: block.block_identifier,
+              timestamp: block.timestamp,
+              transaction_identifier: transaction.transaction_identifier,
+              operation: operation.inscription_revealed
+            });
+          }
+        }
+      }
+    }
+
+    return reveals;
+  }
+
+  /**
+   * Parse one inscription transfer collected by extractInscriptionTransfers
+   * @param {Object} event - Transfer event
+   * @returns {Object|null} Parsed transfer data
+   */
+  parseInscriptionTransfer(event) {
+    try {
+      const operation = event.operation;
+      const txid = (event.transaction_identifier?.hash || '').replace(/^0x/, '');
+      if (!operation || !txid) {
+        return null;
+      }
+
+      const inscriptionId = operation.inscription_id || null;
+      if (!inscriptionId) {
+        logger.debug(`Inscription transfer in ${txid} has no inscription ID, skipping`);
+        return null;
+      }
+
+      // satpoint is "<txid>:<vout>:<offset>"; the previous owner is only known by outpoint
+      const [previousTxid, previousVout] = (operation.satpoint_pre_transfer || '').split(':');
+      const [nextTxid, nextVout] = (operation.satpoint_post_transfer || '').split(':');
+      const destination = operation.destination || {};
+
+      return {
+        inscription_id: inscriptionId,
+        inscription_number: operation.inscription_number ?? null,
+        ordinal_number: operation.ordinal_number ?? null,
+        txid,
+        sender: previousTxid ? `${previousTxid}:${previousVout}` : null,
+        recipient: destination.type === 'transferred' ? destination.value : null,
+        recipient_output: nextTxid ? `${nextTxid}:${nextVout}` : null,
+        destination_type: destination.type || null,
+        block_height: event.block_identifier?.index ?? null,
+        timestamp: event.timestamp || null,
+        collection_symbol: operation.collection_symbol || null
+      };
+    } catch (error) {
+      logger.error('Error parsing Hiro inscription transfer:', error);
+      return null;
+    }
+  }
+
+  /**
+   * Parse one inscription reveal collected by extractInscriptionReveals
+   * @param {Object} event - Reveal event
+   * @returns {Object|null} Parsed reveal data
+   */
+  parseInscriptionReveal(event) {
+    const operation = event.operation;
+    const txid = (event.transaction_identifier?.hash || '').replace(/^0x/, '');
+    if (!operation?.inscription_id || !txid) {
+      return null;
+    }
+
+    const [outputTxid, outputVout] = (operation.satpoint_post_inscription || '').split(':');
+    return {
+      inscription_id: operation.inscription_id,
+      inscription_number: operation.inscription_number ?? null,
+      txid,
+      inscriber: operation.inscriber_address || null,
+      output: outputTxid ? `${outputTxid}:${outputVout}` : null,
+      block_height: event.block_identifier?.index ?? null,
+      timestamp: event.timestamp || null
+    };
+  }
+
+  /**
+   * Outputs currently holding an address's inscriptions, from the Hiro Ordinals API
+   * @param {string} address - Bitcoin address
+   * @param {number} limit - Most inscriptions to look at
+   * @returns {Promise<Array<string>>} "<txid>:<vout>" outputs
+   */
+  async getAddressInscriptionOutputs(address, limit = 1000) {
+    const outputs = new Set();
+
+    for (let offset = 0; offset < limit; offset += ORDINALS_PAGE_SIZE) {
+      const response = await axios.get(`${this.ordinalsApiUrl}/inscriptions`, {
+        params: { address, limit: ORDINALS_PAGE_SIZE, offset },
+        headers: this.apiKey ? { 'x-api-key': this.apiKey } : {},
+        timeout: 10000
+      });
+
+      const results = response.data?.results || [];
+      results.forEach(inscription => inscription.output && outputs.add(inscription.output));
+      if (results.length < ORDINALS_PAGE_SIZE) break;
+    }
+
+    return [...outputs];
+  }
+
+  /**
+   * Load the inscription IDs of the tracked collections that aren't indexed yet or are due
+   * a reload, and forget collections that are no longer tracked. Feed transfers are then
+   * matched in memory instead of with a metadata lookup per inscription.
+   * @param {Array<string>} symbols - Magic Eden symbols of the tracked collections
+   */
+  async refreshCollectionIndex(symbols) {
+    for (const symbol of this.collectionIndex.keys()) {
+      if (!symbols.includes(symbol)) this.collectionIndex.delete(symbol);
+    }
+
+    for (const symbol of symbols) {
+      const indexed = this.collectionIndex.get(symbol);
+      if (indexed && indexed.expiresAt > Date.now()) continue;
+
+      const ids = await this.fetchCollectionInscriptionIds(symbol);
+      if (ids) {
+        this.collectionIndex.set(symbol, { ids, expiresAt: Date.now() + COLLECTION_INDEX_TTL_MS });
+        logger.info(`₿ Indexed ${ids.size} inscription(s) of ${symbol}`);
+      } else {
+        // Keep matching against the previous list until a reload succeeds
+        this.collectionIndex.set(symbol, { ids: indexed?.ids || new Set(), expiresAt: Date.now() + COLLECTION_INDEX_RETRY_MS });
+      }
+    }
+  }
+
+  /**
+   * All inscription IDs of a Magic Eden collection
+   * @returns {Promise<Set<string>|null>} Null when a page failed
+   */
+  async fetchCollectionInscriptionIds(symbol) {
+    if (!this.magicEdenOrdinals) return null;
+
+    const ids = new Set();
+    for (let offset = 0; ; offset += COLLECTION_INDEX_PAGE_SIZE) {
+      const tokens = await this.magicEdenOrdinals.getCollectionTokens(symbol, offset, COLLECTION_INDEX_PAGE_SIZE);
+      if (!tokens) {
+        logger.warn(`Could not index the inscriptions of ${symbol} (page at ${offset} failed)`);
+        return null;
+      }
+      tokens.forEach(inscription => inscription.id && ids.add(inscription.id));
+      if (tokens.length < COLLECTION_INDEX_PAGE_SIZE) break;
+    }
+    return ids;
+  }
+
+  /**
+   * Tracked collection an inscription belongs to, from the index built by refreshCollectionIndex
+   * @param {string} inscriptionId
+   * @param {Array<string>|null} symbols - Only look in these collections
+   * @returns {string|null} Collection symbol
+   */
+  findIndexedCollection(inscriptionId, symbols = null) {
+    for (const [symbol, { ids }] of this.collectionIndex) {
+      if ((!symbols || symbols.includes(symbol)) && ids.has(inscriptionId)) return symbol;
+    }
+    return null;
+  }
+
+  /**
+   * Look up the Magic Eden collection an inscription belongs to
+   * @param {string} inscriptionId - Inscription ID
+   * @returns {Promise<string|null>} Collection symbol or null
+   */
+  async resolveCollectionSymbol(inscriptionId) {
+    if (this.collectionCache.has(inscriptionId)) {
+      return this.collectionCache.get(inscriptionId);
+    }
+    if (!this.magicEdenOrdinals) {
+      return null;
+    }
+
+    const metadata = await this.magicEdenOrdinals.getInscriptionMetadata(inscriptionId);
+    const collectionSymbol = metadata?.collectionSymbol || metadata?.collection?.symbol || null;
+
+    // Collection membership doesn't change, keep a bounded cache
+    if (this.collectionCache.size >= COLLECTION_CACHE_SIZE) {
+      this.collectionCache.delete(this.collectionCache.keys().next().value);
+    }
+    this.collectionCache.set(inscriptionId, collectionSymbol);
+    return collectionSymbol;
+  }
+
+  /**
+   * Get connection status
+   * @returns {Object} Connection status
+   */
+  getConnectionStatus() {
+    return {
+      isConnected: this.isConnected,
+      service: 'Hiro Chainhook',
+      blockchain: 'Bitcoin',
+      predicates: [...this.predicates.keys()],
+      managesPredicates: this.canManagePredicates(),
+      indexedCollections: this.collectionIndex.size
+    };
+  }
+}
+
+module.exports = HiroOrdinalsService;
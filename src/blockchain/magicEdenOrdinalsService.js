@@ -101,6 +101,61 @@ class MagicEdenOrdinalsService {
     }
   }
 
+  /**
+   * Floor, listings and lifetime volume of a collection (collection stats alerts)
+   * @param {string} collectionSymbol - Magic Eden collection symbol
+   * @returns {Promise<Object|null>} { floorPrice, totalListed, totalVolume } in BTC, null if unavailable
+   */
+  async getCollectionStats(collectionSymbol) {
+    try {
+      const response = await axios.get(`${this.apiBaseUrl}/stat`, {
+        params: { collectionSymbol },
+        timeout: 10000,
+        headers: {
+          'Authorization': `Bearer ${this.apiKey}`,
+          'Accept': 'application/json'
+        }
+      });
+
+      const stats = response.data;
+      if (!stats) return null;
+      const toBTC = (sats) => sats != null && sats !== '' ? Number(sats) / 1e8 : null;
+      return {
+        floorPrice: toBTC(stats.floorPrice),
+        totalListed: stats.totalListed != null ? Number(stats.totalListed) : null,
+        totalVolume: toBTC(stats.totalVolume)
+      };
+    } catch (error) {
+      logger.error(`Failed to get stats for Ordinals collection ${collectionSymbol}:`, error.message);
+      return null;
+    }
+  }
+
+  /**
+   * One page of the inscriptions in a collection, with their attributes (rarity snapshots)
+   * @param {string} collectionSymbol - Magic Eden collection symbol
+   * @param {number} offset - Items to skip
+   * @param {number} limit - Items per page (max 100)
+   * @returns {Promise<Array|null>} Tokens ({ id, meta: { attributes } }), null on error
+   */
+  async getCollectionTokens(collectionSymbol, offset = 0, limit = 100) {
+    try {
+      const response = await axios.get(`${this.apiBaseUrl}/tokens`, {
+        params: { collectionSymbol, offset, limit, sortBy: 'inscriptionNumberAsc' },
+        timeout: 15000,
+        headers: {
+          'Authorization': `Bearer ${this.apiKey}`,
+          'Accept': 'application/json'
+        }
+      });
+
+      return response.data?.tokens || [];
+    } catch (error) {
+      logger.error(`Failed to get tokens of Ordinals collection ${collectionSymbol}:`, error.message);
+      return null;
+    }
+  }
+
   /**
    * Get collection activities (transfers, sales, listings)
    * @param {string} collectionSymbol - Magic Eden collection symbol
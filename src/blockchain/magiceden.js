@@ -166,6 +166,60 @@ class MagicEdenService {
     }
   }
 
+  /**
+   * Unrounded floor, listings and lifetime volume of a collection (collection stats alerts)
+   * @param {string} collectionSymbol - Magic Eden collection symbol
+   * @returns {Promise<Object|null>} { floorPrice, listedCount, volumeAll } in SOL, null if unavailable
+   */
+  async getCollectionStats(collectionSymbol) {
+    try {
+      const url = `${this.apiBaseUrl}/collections/${collectionSymbol}/stats`;
+      const response = await axios.get(url, {
+        timeout: 10000,
+        headers: {
+          'Accept': 'application/json',
+          'Authorization': `Bearer ${this.apiKey}`
+        }
+      });
+
+      const stats = response.data;
+      if (!stats) return null;
+      return {
+        floorPrice: stats.floorPrice != null ? stats.floorPrice / 1e9 : null,
+        listedCount: stats.listedCount ?? null,
+        volumeAll: stats.volumeAll != null ? stats.volumeAll / 1e9 : null
+      };
+    } catch (error) {
+      logger.error(`Failed to get stats for ${collectionSymbol}:`, error.message);
+      return null;
+    }
+  }
+
+  /**
+   * Lowest listings of a collection
+   * @param {string} collectionSymbol - Magic Eden collection symbol
+   * @param {number} limit - Number of listings to fetch
+   * @returns {Promise<Array>} Listings ({ tokenMint, price, ... })
+   */
+  async getCollectionListings(collectionSymbol, limit = 20) {
+    try {
+      const url = `${this.apiBaseUrl}/collections/${collectionSymbol}/listings`;
+      const response = await axios.get(url, {
+        params: { limit },
+        timeout: 10000,
+        headers: {
+          'Accept': 'application/json',
+          'Authorization': `Bearer ${this.apiKey}`
+        }
+      });
+
+      return response.data || [];
+    } catch (error) {
+      logger.error(`Failed to get listings for ${collectionSymbol}:`, error.message);
+      return [];
+    }
+  }
+
   /**
    * Get collection activities (sales, listings)
    * @param {string} collectionSymbol - Magic Eden collection symbol
@@ -3,18 +3,29 @@ const logger = require('../services/logger');
 const { ethers } = require('ethers');
 const addresses = require('../config/addresses');
 const helpers = require('./helpers');
+const subscriptionFilters = require('../services/subscriptionFilters');
+const MessageTemplateService = require('../services/messageTemplateService');
+const { parseStatsAlertRule, formatWindow } = require('../services/statsAlertService');
+const { t, SUPPORTED_LANGUAGES, DEFAULT_LANGUAGE, isSupportedLanguage, resolveLanguage } = require('../i18n');
 
 class BotCommands {
-  constructor(database, tokenTracker, trendingService, channelService, secureTrendingService = null, chainManager = null) {
+  constructor(database, tokenTracker, trendingService, channelService, secureTrendingService = null, chainManager = null, sessionStore = null, outboundWebhooks = null, chatSettings = null, messageTemplates = null, walletWatch = null, statsAlerts = null) {
     this.db = database;
     this.tokenTracker = tokenTracker;
     this.trending = trendingService;
     this.secureTrending = secureTrendingService;
     this.channels = channelService;
     this.chainManager = chainManager;
+    this.sessionStore = sessionStore;
+    this.outboundWebhooks = outboundWebhooks;
+    this.chatSettings = chatSettings;
+    this.messageTemplates = messageTemplates;
+    this.walletWatch = walletWatch;
+    this.statsAlerts = statsAlerts;
 
-    this.userStates = new Map();
-    this.userSessions = new Map(); // Store user session data for multi-step flows
+    // Persisted through the session store when available, so flows survive restarts
+    this.userStates = sessionStore ? sessionStore.getMap('user_states') : new Map();
+    this.userSessions = sessionStore ? sessionStore.getMap('user_sessions') : new Map(); // Store user session data for multi-step flows
 
     // Original states
     this.STATE_EXPECTING_CONTEXT_SELECTION = 'expecting_context_selection';
@@ -42,8 +53,13 @@ class BotCommands {
     this.STATE_IMAGE_CHAIN_SELECT = 'image_chain_select';
     this.STATE_IMAGE_CONTRACT_INPUT = 'image_contract_input';
     this.STATE_EXPECTING_GROUP_LINK = 'expecting_group_link';
+    this.STATE_EXPECTING_ALERT_MIN_PRICE = 'expecting_alert_min_price';
+    this.STATE_EXPECTING_ALERT_MARKETPLACES = 'expecting_alert_marketplaces';
+    this.STATE_EXPECTING_ALERT_SNIPE = 'expecting_alert_snipe';
+    this.STATE_EXPECTING_ALERT_TRAITS = 'expecting_alert_traits';
+    this.STATE_EXPECTING_TEMPLATE = 'expecting_template';
 
-    this.pendingPayments = new Map();
+    this.pendingPayments = sessionStore ? sessionStore.getMap('pending_payments') : new Map();
   }
 
   // Session data management for multi-step flows
@@ -74,10 +90,28 @@ class BotCommands {
       await handler();
     } catch (error) {
       logger.error(`Error in ${commandName}:`, error);
-      await ctx.reply('❌ An error occurred. Please try again.');
+      await ctx.reply(t(await this.getLanguage(ctx), 'common.error'));
     }
   }
 
+  /**
+   * Language of the chat a command or button comes from: the chat's saved choice,
+   * otherwise the user's Telegram language in private chats and English elsewhere
+   * @returns {Promise<string>} Supported language code
+   */
+  async getLanguage(ctx) {
+    const chatId = ctx.chat?.id;
+    if (this.chatSettings && chatId != null) {
+      try {
+        const { language } = await this.chatSettings.get(chatId);
+        if (language) return language;
+      } catch (error) {
+        logger.debug(`Could not load language of chat ${chatId}:`, error.message);
+      }
+    }
+    return ctx.chat?.type === 'private' ? resolveLanguage(ctx.from?.language_code) : DEFAULT_LANGUAGE;
+  }
+
   // Helper to send/edit menu messages with fallback
   async sendOrEditMenu(ctx, message, keyboard) {
     try {
@@ -202,6 +236,34 @@ class BotCommands {
     this.userStates.delete(userId.toString());
   }
 
+  /**
+   * Remind a returning user about a flow they didn't finish (e.g. before a restart)
+   * @param {Object} ctx - Telegram context
+   */
+  async sendResumeReminder(ctx) {
+    const userId = ctx.from.id.toString();
+    const flowState = this.getFlowState(userId);
+    const expectingTxHash = [
+      this.STATE_EXPECTING_TX_HASH,
+      this.STATE_EXPECTING_FOOTER_TX_HASH,
+      this.STATE_EXPECTING_IMAGE_TX_HASH,
+      this.STATE_EXPECTING_VALIDATION_TX_HASH
+    ].includes(flowState);
+
+    const lang = await this.getLanguage(ctx);
+    if (expectingTxHash) {
+      return ctx.reply(t(lang, 'resume.tx_hash'));
+    }
+
+    if (this.pendingPayments.has(userId)) {
+      return ctx.reply(t(lang, 'resume.payment'));
+    }
+
+    if (flowState) {
+      return ctx.reply(t(lang, 'resume.step'));
+    }
+  }
+
   // ============================================================================
   // GROUP MANAGEMENT HELPERS
   // ============================================================================
@@ -242,18 +304,19 @@ class BotCommands {
    * @returns {Promise<string>} Context label (e.g., "Private" or "GroupName")
    */
   async resolveContextLabel(chatId, ctx, userTelegramId) {
+    const lang = await this.getLanguage(ctx);
     try {
       // Fetch group/channel title
       try {
         const chat = await ctx.telegram.getChat(chatId);
-        return chat.title || 'Group';
+        return chat.title || t(lang, 'common.group');
       } catch (error) {
         logger.warn(`Failed to resolve context for chat_id ${chatId}:`, error);
-        return 'Group';
+        return t(lang, 'common.group');
       }
     } catch (error) {
       logger.error('Error resolving context label:', error);
-      return 'Unknown';
+      return t(lang, 'common.unknown');
     }
   }
 
@@ -357,10 +420,12 @@ class BotCommands {
       this.clearUserState(user.id, 'group_title');
       logger.debug(`[STARTMINTY] Cleared stale group context for fresh start`);
 
-      const welcomeMessage = helpers.formatWelcomeMessage();
-      const keyboard = helpers.buildMainMenuKeyboard();
+      const lang = await this.getLanguage(ctx);
+      const welcomeMessage = helpers.formatWelcomeMessage(lang);
+      const keyboard = helpers.buildMainMenuKeyboard(lang);
 
       await ctx.replyWithHTML(welcomeMessage, keyboard);
+      await this.sendResumeReminder(ctx);
       logger.info(`New user started bot: ${user.id} (${user.username})`);
     });
 
@@ -371,6 +436,7 @@ class BotCommands {
 
       // Check for start parameters (deep links)
       const startPayload = ctx.startPayload;
+      const lang = await this.getLanguage(ctx);
 
       logger.info(`[BOT_START] User ${user.id} (${user.username}) started bot with payload: ${startPayload || 'NONE'}`);
 
@@ -395,16 +461,16 @@ class BotCommands {
             logger.info(`[BOT_START] Stored pending group setup session for user ${user.id}`);
 
             // Show normal welcome message
-            const welcomeMessage = helpers.formatWelcomeMessage();
-            const keyboard = helpers.buildMainMenuKeyboard();
+            const welcomeMessage = helpers.formatWelcomeMessage(lang);
+            const keyboard = helpers.buildMainMenuKeyboard(lang);
             return await ctx.replyWithHTML(welcomeMessage, keyboard);
           } else {
             logger.warn(`[BOT_START] User ${ctx.from.id} is NOT admin in group ${groupContext.group_chat_id}`);
-            return ctx.reply('⚠️ You are no longer an admin in that group.');
+            return ctx.reply(t(lang, 'start.not_admin'));
           }
         } else {
           logger.warn(`[BOT_START] Invalid setup token: ${setupToken}`);
-          return ctx.reply('❌ Invalid or expired setup link. Please run /startminty in the group again.');
+          return ctx.reply(t(lang, 'start.invalid_setup'));
         }
       }
 
@@ -422,22 +488,24 @@ class BotCommands {
       this.clearUserState(ctx.from.id, 'group_title');
       logger.debug(`[BOT_START] Cleared stale group context for fresh start`);
 
-      const welcomeMessage = helpers.formatWelcomeMessage();
-      const keyboard = helpers.buildMainMenuKeyboard();
+      const welcomeMessage = helpers.formatWelcomeMessage(lang);
+      const keyboard = helpers.buildMainMenuKeyboard(lang);
 
       await ctx.replyWithHTML(welcomeMessage, keyboard);
+      await this.sendResumeReminder(ctx);
     });
 
     bot.help(async (ctx) => {
-      const helpMessage = helpers.formatHelpMessage();
+      const helpMessage = helpers.formatHelpMessage(await this.getLanguage(ctx));
       await ctx.replyWithHTML(helpMessage);
     });
 
     // Manual transaction validation command
     bot.command('validate', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         if (!this.secureTrending) {
-          return ctx.reply('❌ Validation service not available.');
+          return ctx.reply(t(lang, 'service.validation_unavailable'));
         }
 
         // Trigger the verify trending button flow instead of text-based command
@@ -445,14 +513,15 @@ class BotCommands {
 
       } catch (error) {
         logger.error('Error in validate command:', error);
-        await ctx.reply('❌ An error occurred. Please try again.');
+        await ctx.reply(t(lang, 'common.error'));
       }
     });
 
     bot.command('buy_image', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         if (!this.secureTrending) {
-          return ctx.reply('❌ Image fee system not available.');
+          return ctx.reply(t(lang, 'service.image_unavailable'));
         }
 
         // Trigger the buy image button flow instead of text-based command
@@ -460,14 +529,15 @@ class BotCommands {
 
       } catch (error) {
         logger.error('Error in buy_image command:', error);
-        ctx.reply('⚠️ An error occurred. Please try again.');
+        ctx.reply(t(lang, 'common.error'));
       }
     });
 
     bot.command('validate_image', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         if (!this.secureTrending) {
-          return ctx.reply('❌ Image fee system not available.');
+          return ctx.reply(t(lang, 'service.image_unavailable'));
         }
 
         // Trigger the verify image button flow instead of text-based command
@@ -475,14 +545,15 @@ class BotCommands {
 
       } catch (error) {
         logger.error('Error in validate_image command:', error);
-        await ctx.reply('❌ An error occurred. Please try again.');
+        await ctx.reply(t(lang, 'common.error'));
       }
     });
 
     bot.command('buy_footer', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         if (!this.secureTrending) {
-          return ctx.reply('❌ Footer ad system not available.');
+          return ctx.reply(t(lang, 'service.footer_unavailable'));
         }
 
         // Initialize session for footer payment flow
@@ -492,14 +563,15 @@ class BotCommands {
 
       } catch (error) {
         logger.error('Error in buy_footer command:', error);
-        ctx.reply('⚠️ An error occurred. Please try again.');
+        ctx.reply(t(lang, 'common.error'));
       }
     });
 
     bot.command('validate_footer', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         if (!this.secureTrending) {
-          return ctx.reply('❌ Footer ad system not available.');
+          return ctx.reply(t(lang, 'service.footer_unavailable'));
         }
 
         // Trigger the verify footer button flow instead of text-based command
@@ -507,7 +579,7 @@ class BotCommands {
 
       } catch (error) {
         logger.error('Error in validate_footer command:', error);
-        await ctx.reply('❌ An error occurred. Please try again.');
+        await ctx.reply(t(lang, 'common.error'));
       }
     });
 
@@ -520,17 +592,18 @@ class BotCommands {
 
 
     bot.command('my_tokens', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         const user = await this.db.getUser(ctx.from.id.toString());
         if (!user) {
-          return ctx.reply('Please start the bot first with /startminty');
+          return ctx.reply(t(lang, 'common.start_first'));
         }
 
         // Use same context-aware logic as menu button
         return this.showMyTokens(ctx);
       } catch (error) {
         logger.error('Error in my_tokens command:', error);
-        ctx.reply('❌ Error retrieving your NFTs. Please try again.');
+        ctx.reply(t(lang, 'tokens.load_error'));
       }
     });
 
@@ -541,7 +614,7 @@ class BotCommands {
         return this.showMyTokens(ctx);
       } catch (error) {
         logger.error('Error in remove_token command:', error);
-        ctx.reply('❌ Error loading your NFTs. Please try again.');
+        ctx.reply(t(await this.getLanguage(ctx), 'tokens.load_error'));
       }
     });
 
@@ -558,17 +631,18 @@ class BotCommands {
 
 
     bot.command('add_channel', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         const chatId = ctx.chat.id.toString();
         const chatType = ctx.chat.type;
 
         if (chatType !== 'channel' && chatType !== 'supergroup') {
-          return ctx.reply('❌ This command only works in channels or groups. Add me to a channel first, then use this command there.');
+          return ctx.reply(t(lang, 'channel_cmd.add_wrong_chat'));
         }
         const channelTitle = ctx.chat.title || 'Unknown Channel';
         const telegramId = ctx.from?.id;
         if (!telegramId) {
-          return ctx.reply('❌ Unable to identify user. Please try again.');
+          return ctx.reply(t(lang, 'channel_cmd.unknown_user'));
         }
 
         // Create user and get database ID
@@ -580,54 +654,72 @@ class BotCommands {
         const userId = userResult.id;
 
         if (!userId) {
-          return ctx.reply('❌ Error setting up user. Please try again.');
+          return ctx.reply(t(lang, 'channel_cmd.user_error'));
         }
 
         const result = await this.channels.addChannel(chatId, channelTitle, userId);
         await ctx.reply(result.message);
       } catch (error) {
         logger.error('Error in add_channel command:', error);
-        ctx.reply('❌ Error adding channel. Please try again.');
+        ctx.reply(t(lang, 'channel_cmd.add_error'));
       }
     });
 
     bot.command('channel_settings', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         const chatId = ctx.chat.id.toString();
         const chatType = ctx.chat.type;
 
         if (chatType !== 'channel' && chatType !== 'supergroup') {
-          return ctx.reply('❌ This command only works in channels or groups.');
+          return ctx.reply(t(lang, 'channel_cmd.settings_wrong_chat'));
         }
         await this.channels.handleChannelSettingsCommand(ctx, chatId);
       } catch (error) {
         logger.error('Error in channel_settings command:', error);
-        ctx.reply('❌ Error retrieving channel settings. Please try again.');
+        ctx.reply(t(lang, 'channel_cmd.settings_error'));
       }
     });
 
     bot.command('get_chat_id', async (ctx) => {
+      const lang = await this.getLanguage(ctx);
       try {
         const chatId = ctx.chat.id;
         const chatType = ctx.chat.type;
-        const chatTitle = ctx.chat.title || ctx.chat.first_name || 'Unknown';
-        const message = `
-🆔 *Chat Information*
-
-**Chat ID:** \`${chatId}\`
-**Type:** ${chatType}
-**Title:** ${chatTitle}
-
-*Use this chat ID to configure notifications in your bot settings.*
-        `;
+        const chatTitle = ctx.chat.title || ctx.chat.first_name || t(lang, 'common.unknown');
+        const message = t(lang, 'chat_id.message', { chatId, chatType, chatTitle });
         await ctx.replyWithMarkdown(message);
       } catch (error) {
         logger.error('Error in get_chat_id command:', error);
-        ctx.reply('❌ Error getting chat information.');
+        ctx.reply(t(lang, 'chat_id.error'));
       }
     });
 
 
+    // Outbound webhooks (group admins only)
+    bot.command('webhook_add', async (ctx) => this.handleWebhookAdd(ctx));
+    bot.command('webhooks', async (ctx) => this.showGroupWebhooks(ctx));
+    bot.command('webhook_remove', async (ctx) => this.handleWebhookRemove(ctx));
+    bot.command('webhook_secret', async (ctx) => this.handleWebhookSecret(ctx));
+
+    // Per-chat alert settings (group admins, or anyone in their private chat)
+    bot.command('sweep_mode', async (ctx) => this.handleSweepMode(ctx));
+    bot.command('quiet_hours', async (ctx) => this.handleQuietHours(ctx));
+    bot.command('digest', async (ctx) => this.handleDigest(ctx));
+    bot.command('templates', async (ctx) => this.showTemplatesMenu(ctx));
+    bot.command('language', async (ctx) => this.handleLanguage(ctx));
+
+    // Wallet watchlists (group admins, or anyone in their private chat)
+    bot.command('watch_wallet', async (ctx) => this.handleWatchWallet(ctx));
+    bot.command('unwatch_wallet', async (ctx) => this.handleUnwatchWallet(ctx));
+    bot.command('wallets', async (ctx) => this.showWatchedWallets(ctx));
+
+    // Floor and volume alerts on the collections tracked in a chat
+    bot.command('stats_alert', async (ctx) => this.handleStatsAlert(ctx));
+    bot.command('stats_alerts', async (ctx) => this.showStatsAlerts(ctx));
+    bot.command('stats_alert_remove', async (ctx) => this.handleStatsAlertRemove(ctx));
+
+
     bot.on('callback_query', async (ctx) => {
       const data = ctx.callbackQuery.data;
       logger.info(`[CALLBACK_DEBUG] Received callback: ${data}`);
@@ -644,6 +736,19 @@ class BotCommands {
         logger.info(`[STATE] Cleared contract expectation for user ${ctx.from.id} - clicked: ${data}`);
       }
 
+      // Leaving the alert settings menu drops a pending min price / marketplace / snipe / trait prompt
+      if (this.isAlertFilterPrompt(userState) &&
+          !data.startsWith('alf_') && !data.startsWith('alerts_')) {
+        this.clearFlowState(ctx.from.id);
+        this.clearUserState(ctx.from.id, 'alert_filter_subscription');
+      }
+
+      // Leaving the template editor drops a pending template prompt
+      if (userState === this.STATE_EXPECTING_TEMPLATE && !data.startsWith('tpl_')) {
+        this.clearFlowState(ctx.from.id);
+        this.clearUserState(ctx.from.id, 'template_edit');
+      }
+
       try {
         if (data === 'view_trending') {
           await ctx.answerCbQuery();
@@ -678,18 +783,18 @@ class BotCommands {
                 logger.info(`[ADD_TOKEN] Auto-detected group: ${groupContext.group_title} (${groupContext.group_chat_id})`);
 
                 // Show chain selection with group indicator and escape hatch
+                const lang = await this.getLanguage(ctx);
                 if (!this.chainManager) {
-                  return ctx.reply('❌ Chain manager not available');
+                  return ctx.reply(t(lang, 'service.chain_manager_unavailable'));
                 }
 
-                const message = `🎯 <b>Adding NFT to:</b> ${groupContext.group_title}\n\n` +
-                  `Please select the blockchain chain:`;
+                const message = t(lang, 'add_token.adding_to', { group: groupContext.group_title });
 
                 // Use dynamic chain manager for all chains
                 const chainKeyboard = this.chainManager.getChainSelectionKeyboard();
                 chainKeyboard.push([
-                  { text: '🔄 Switch Group', callback_data: 'switch_group_context' },
-                  { text: '🏠 Main Menu', callback_data: 'main_menu' }
+                  { text: t(lang, 'add_token.switch_group'), callback_data: 'switch_group_context' },
+                  { text: t(lang, 'common.main_menu'), callback_data: 'main_menu' }
                 ]);
 
                 const keyboard = Markup.inlineKeyboard(chainKeyboard);
@@ -727,17 +832,17 @@ class BotCommands {
 
             if (isStillAdmin) {
               // Show chain selection with existing group context
+              const lang = await this.getLanguage(ctx);
               if (!this.chainManager) {
-                return ctx.reply('❌ Chain manager not available');
+                return ctx.reply(t(lang, 'service.chain_manager_unavailable'));
               }
 
-              const message = `🎯 <b>Adding NFT to:</b> ${existingGroupTitle || 'Group'}\n\n` +
-                `Please select the blockchain chain:`;
+              const message = t(lang, 'add_token.adding_to', { group: existingGroupTitle || t(lang, 'common.group') });
 
               const chainKeyboard = this.chainManager.getChainSelectionKeyboard();
               chainKeyboard.push([
-                { text: '🔄 Switch Group', callback_data: 'switch_group_context' },
-                { text: '🏠 Main Menu', callback_data: 'main_menu' }
+                { text: t(lang, 'add_token.switch_group'), callback_data: 'switch_group_context' },
+                { text: t(lang, 'common.main_menu'), callback_data: 'main_menu' }
               ]);
 
               const keyboard = Markup.inlineKeyboard(chainKeyboard);
@@ -755,13 +860,14 @@ class BotCommands {
           // Check if user is already in a group - skip context selection
           const chatType = ctx.chat.type;
           if (chatType === 'group' || chatType === 'supergroup') {
+            const lang = await this.getLanguage(ctx);
             const groupChatId = ctx.chat.id.toString();
-            const groupTitle = ctx.chat.title || 'this group';
+            const groupTitle = ctx.chat.title || t(lang, 'group_start.this_group');
 
             // Verify user is admin
             const isAdmin = await this.isUserGroupAdmin(ctx.from.id, groupChatId, ctx);
             if (!isAdmin) {
-              return ctx.reply('⚠️ Only group admins can add NFT tracking.');
+              return ctx.reply(t(lang, 'add_token.admins_only'));
             }
 
             // Set group as target for token addition
@@ -772,13 +878,13 @@ class BotCommands {
 
             // Show chain selection immediately
             if (!this.chainManager) {
-              return ctx.reply('❌ Chain manager not available');
+              return ctx.reply(t(lang, 'service.chain_manager_unavailable'));
             }
 
-            const message = `🎯 <b>Adding NFT to:</b> ${groupTitle}\n\nPlease select the blockchain chain:`;
+            const message = t(lang, 'add_token.adding_to', { group: groupTitle });
             const chainKeyboard = this.chainManager.getChainSelectionKeyboard();
             chainKeyboard.push([
-              { text: '◀️ Back', callback_data: 'menu_tokens' }
+              { text: t(lang, 'common.back'), callback_data: 'menu_tokens' }
             ]);
 
             const keyboard = Markup.inlineKeyboard(chainKeyboard);
@@ -817,10 +923,11 @@ class BotCommands {
           const chatId = parts.join('_'); // Rest is chat_id
 
           // Get group title
-          let groupTitle = 'Group';
+          const lang = await this.getLanguage(ctx);
+          let groupTitle = t(lang, 'common.group');
           try {
             const chat = await ctx.telegram.getChat(chatId);
-            groupTitle = chat.title || 'Group';
+            groupTitle = chat.title || groupTitle;
           } catch (error) {
             logger.warn(`Failed to get chat title for ${chatId}:`, error);
           }
@@ -832,17 +939,17 @@ class BotCommands {
 
           // Show chain selection
           if (!this.chainManager) {
-            return ctx.reply('❌ Chain manager not available');
+            return ctx.reply(t(lang, 'service.chain_manager_unavailable'));
           }
 
           const chainKeyboard = this.chainManager.getChainSelectionKeyboard();
           chainKeyboard.push([{
-            text: '◀️ Back to Context Selection',
+            text: t(lang, 'add_token.back_to_context'),
             callback_data: 'add_token_start'
           }]);
           this.setFlowState(ctx.from.id, this.STATE_EXPECTING_CHAIN_FOR_CONTRACT);
 
-          const message = `🔗 <b>Select Blockchain Network</b>\n\n👥 <b>Adding to: ${groupTitle}</b>\n\nChoose the blockchain where your NFT collection exists:`;
+          const message = t(lang, 'add_token.select_network', { group: groupTitle });
           const keyboard = Markup.inlineKeyboard(chainKeyboard);
           return this.sendOrEditMenu(ctx, message, keyboard);
         }
@@ -903,6 +1010,14 @@ class BotCommands {
           await ctx.answerCbQuery();
           return this.showVerifyMenu(ctx);
         }
+        if (data === 'menu_language') {
+          await ctx.answerCbQuery();
+          return this.showLanguageMenu(ctx);
+        }
+        if (data.startsWith('lang_')) {
+          await ctx.answerCbQuery();
+          return this.handleLanguageSelection(ctx, data.replace('lang_', ''));
+        }
         if (data === 'main_menu') {
           await ctx.answerCbQuery();
 
@@ -935,23 +1050,24 @@ class BotCommands {
 
           logger.info(`[CHAIN_SELECT] User ${ctx.from.id} selected chain: ${chainName}`);
           logger.info(`[CHAIN_SELECT] User state: ${userState || 'NONE'}`);
+          const lang = await this.getLanguage(ctx);
 
           if (userState === this.STATE_EXPECTING_CHAIN_FOR_CONTRACT) {
             // User selected chain for adding a contract
             if (chainName === 'all') {
               try {
-                return ctx.editMessageText('❌ Please select a specific blockchain network for adding tokens.');
+                return ctx.editMessageText(t(lang, 'chain.select_specific'));
               } catch (error) {
-                return ctx.reply('❌ Please select a specific blockchain network for adding tokens.');
+                return ctx.reply(t(lang, 'chain.select_specific'));
               }
             }
 
             const chainConfig = this.chainManager.getChain(chainName);
             if (!chainConfig) {
               try {
-                return ctx.editMessageText('❌ Invalid blockchain network selected.');
+                return ctx.editMessageText(t(lang, 'chain.invalid'));
               } catch (error) {
-                return ctx.reply('❌ Invalid blockchain network selected.');
+                return ctx.reply(t(lang, 'chain.invalid'));
               }
             }
 
@@ -965,15 +1081,15 @@ class BotCommands {
             // Customize message based on chain type
             let message;
             if (chainName === 'solana') {
-              message = `◎ <b>Solana</b> selected!\n\n📝 Please enter the collection symbol:\n\nExample: <code>mad_lads</code>\n\n💡 The collection must exist on Magic Eden marketplace.`;
+              message = t(lang, 'add_token.prompt_solana');
             } else if (chainName === 'bitcoin') {
-              message = `₿ <b>Bitcoin</b> selected!\n\n📝 Please enter the collection name:\n\nExample: <code>bitcoin-puppets</code> or <code>NodeMonkes</code>\n\n💡 The collection must exist on Magic Eden Ordinals marketplace.`;
+              message = t(lang, 'add_token.prompt_bitcoin');
             } else {
-              message = `🔗 <b>${chainConfig.displayName}</b> selected!\n\n📝 Please enter the NFT contract address to track on ${chainConfig.displayName}:\n\n💡 Make sure the contract exists on ${chainConfig.displayName} network.`;
+              message = t(lang, 'add_token.prompt_evm', { chain: chainConfig.displayName });
             }
 
             const keyboard = Markup.inlineKeyboard([
-              [Markup.button.callback('◀️ Back to Chain Selection', 'back_to_chain_selection')]
+              [Markup.button.callback(t(lang, 'add_token.back_to_chains'), 'back_to_chain_selection')]
             ]);
 
             const sentMessage = await this.sendOrEditMenu(ctx, message, keyboard);
@@ -993,9 +1109,9 @@ class BotCommands {
             const chainConfig = this.chainManager.getChain(chainName);
             if (!chainConfig) {
               try {
-                return ctx.editMessageText('❌ Invalid blockchain network selected.');
+                return ctx.editMessageText(t(lang, 'chain.invalid'));
               } catch (error) {
-                return ctx.reply('❌ Invalid blockchain network selected.');
+                return ctx.reply(t(lang, 'chain.invalid'));
               }
             }
 
@@ -1004,9 +1120,9 @@ class BotCommands {
 
           } else {
             try {
-              return ctx.editMessageText('❌ No active chain selection process found.');
+              return ctx.editMessageText(t(lang, 'chain.no_selection'));
             } catch (error) {
-              return ctx.reply('❌ No active chain selection process found.');
+              return ctx.reply(t(lang, 'chain.no_selection'));
             }
           }
         }
@@ -1071,31 +1187,32 @@ class BotCommands {
         }
         if (data === 'channel_add') {
           await ctx.answerCbQuery();
+          const lang = await this.getLanguage(ctx);
+          const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'channels_menu.back'), 'menu_channels')]]);
           try {
-            return ctx.editMessageText('💡 <b>Add Bot to Channel</b>\n\n1. Add this bot to your channel as an admin\n2. Use the command: <code>/add_channel</code> in the channel\n3. Configure notifications with <code>/channel_settings</code>', {
+            return ctx.editMessageText(t(lang, 'channels_menu.add_help'), {
               parse_mode: 'HTML',
-              reply_markup: Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Channels Menu', 'menu_channels')]])
+              reply_markup: keyboard.reply_markup
             });
           } catch (error) {
-            return ctx.reply('💡 <b>Add Bot to Channel</b>\n\n1. Add this bot to your channel as an admin\n2. Use the command: <code>/add_channel</code> in the channel\n3. Configure notifications with <code>/channel_settings</code>', {
-              parse_mode: 'HTML',
-              reply_markup: Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Channels Menu', 'menu_channels')]])
-            });
+            return ctx.replyWithHTML(t(lang, 'channels_menu.add_help'), keyboard);
           }
         }
         if (data === 'get_chat_id') {
           await ctx.answerCbQuery();
+          const lang = await this.getLanguage(ctx);
           const chatId = ctx.chat.id;
           const chatType = ctx.chat.type;
-          const chatTitle = ctx.chat.title || ctx.chat.first_name || 'Unknown';
-          const message = `🆔 <b>Chat Information</b>\n\n<b>Chat ID:</b> <code>${chatId}</code>\n<b>Type:</b> ${chatType}\n<b>Title:</b> ${chatTitle}\n\n<i>Use this chat ID to configure notifications in your bot settings.</i>`;
+          const chatTitle = ctx.chat.title || ctx.chat.first_name || t(lang, 'common.unknown');
+          const message = t(lang, 'chat_id.message_html', { chatId, chatType, chatTitle });
+          const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'channels_menu.back'), 'menu_channels')]]);
           try {
             return ctx.editMessageText(message, {
               parse_mode: 'HTML',
-              reply_markup: Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Channels Menu', 'menu_channels')]])
+              reply_markup: keyboard.reply_markup
             });
           } catch (error) {
-            return ctx.replyWithHTML(message, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Channels Menu', 'menu_channels')]]));
+            return ctx.replyWithHTML(message, keyboard);
           }
         }
         if (data === 'verify_trending') {
@@ -1117,10 +1234,11 @@ class BotCommands {
           const duration = parseInt(data.replace('image_duration_', ''));
           const session = this.getUserSession(ctx.from.id);
           if (!session || session.flow !== 'image_payment') {
+            const lang = await this.getLanguage(ctx);
             try {
-              return ctx.editMessageText('❌ Session expired. Please try again.');
+              return ctx.editMessageText(t(lang, 'common.session_expired'));
             } catch (error) {
-              return ctx.reply('❌ Session expired. Please try again.');
+              return ctx.reply(t(lang, 'common.session_expired'));
             }
           }
 
@@ -1138,10 +1256,11 @@ class BotCommands {
           const duration = parseInt(data.replace('token_image_duration_', ''));
           const session = this.getUserSession(ctx.from.id);
           if (!session || session.flow !== 'image_payment' || !session.contractAddress) {
+            const lang = await this.getLanguage(ctx);
             try {
-              return ctx.editMessageText('❌ Session expired. Please start again.');
+              return ctx.editMessageText(t(lang, 'common.session_expired'));
             } catch (error) {
-              return ctx.reply('❌ Session expired. Please start again.');
+              return ctx.reply(t(lang, 'common.session_expired'));
             }
           }
 
@@ -1160,10 +1279,11 @@ class BotCommands {
           const duration = parseInt(data.replace('footer_duration_', ''));
           const session = this.getUserSession(ctx.from.id);
           if (!session || session.flow !== 'footer_payment' || !session.chain) {
+            const lang = await this.getLanguage(ctx);
             try {
-              return ctx.editMessageText('❌ Session expired. Please try again.');
+              return ctx.editMessageText(t(lang, 'common.session_expired'));
             } catch (error) {
-              return ctx.reply('❌ Session expired. Please try again.');
+              return ctx.reply(t(lang, 'common.session_expired'));
             }
           }
 
@@ -1228,7 +1348,7 @@ class BotCommands {
           // Auto-detect chain from selected token (like image payment flow)
           const token = await this.db.get('SELECT * FROM tracked_tokens WHERE id = $1', [tokenId]);
           if (!token) {
-            return ctx.reply('❌ NFT collection not found.');
+            return ctx.reply(t(await this.getLanguage(ctx), 'token.not_found'));
           }
           const autoDetectedChain = token.chain_name || 'ethereum';
 
@@ -1267,7 +1387,7 @@ class BotCommands {
           const chain = session.pendingTrendingChain;
 
           if (!tokenId) {
-            return ctx.reply('❌ Session expired. Please start again.');
+            return ctx.reply(t(await this.getLanguage(ctx), 'common.session_expired'));
           }
 
           // Clear state and group link session
@@ -1321,10 +1441,11 @@ class BotCommands {
 
           const session = this.getUserSession(ctx.from.id);
           if (!session || session.flow !== `${paymentType}_payment`) {
+            const lang = await this.getLanguage(ctx);
             try {
-              return ctx.editMessageText('❌ Session expired. Please try again.');
+              return ctx.editMessageText(t(lang, 'common.session_expired'));
             } catch (error) {
-              return ctx.reply('❌ Session expired. Please try again.');
+              return ctx.reply(t(lang, 'common.session_expired'));
             }
           }
 
@@ -1403,21 +1524,23 @@ class BotCommands {
           await ctx.answerCbQuery();
           const session = this.getUserSession(ctx.from.id);
           if (!session || session.flow !== 'image_payment') {
+            const lang = await this.getLanguage(ctx);
             try {
-              return ctx.editMessageText('⚠️ Session expired. Please start again.');
+              return ctx.editMessageText(t(lang, 'common.session_expired'));
             } catch (error) {
-              return ctx.reply('⚠️ Session expired. Please start again.');
+              return ctx.reply(t(lang, 'common.session_expired'));
             }
           }
 
           // Set state to expect transaction hash
           this.setFlowState(ctx.from.id, this.STATE_EXPECTING_IMAGE_TX_HASH);
 
+          const lang = await this.getLanguage(ctx);
           const chainConfig = session?.chain && this.chainManager ? this.chainManager.getChain(session.chain) : null;
-          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : 'the selected blockchain';
+          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : t(lang, 'payment.selected_chain');
 
-          const message = `📝 <b>Submit Transaction Hash</b>\n\nPlease send me your ${chainDisplay} transaction hash for the image fee payment.\n\n<i>Example: 0xabc123456789def...</i>\n\n`;
-          const keyboard = Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_images')]]);
+          const message = t(lang, 'payment.submit_tx.image', { chain: chainDisplay });
+          const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_images')]]);
           try {
             return ctx.editMessageText(message, {
               parse_mode: 'HTML',
@@ -1433,26 +1556,28 @@ class BotCommands {
           await ctx.answerCbQuery();
           const session = this.getUserSession(ctx.from.id);
           if (!session || session.flow !== 'image_payment' || !session.contractAddress) {
+            const lang = await this.getLanguage(ctx);
             try {
-              return ctx.editMessageText('⚠️ Session expired. Please start again.');
+              return ctx.editMessageText(t(lang, 'common.session_expired'));
             } catch (error) {
-              return ctx.reply('⚠️ Session expired. Please start again.');
+              return ctx.reply(t(lang, 'common.session_expired'));
             }
           }
 
           // Set state to expect transaction hash
           this.setFlowState(ctx.from.id, this.STATE_EXPECTING_IMAGE_TX_HASH);
 
+          const lang = await this.getLanguage(ctx);
           const chainConfig = session?.chain && this.chainManager ? this.chainManager.getChain(session.chain) : null;
-          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : 'the selected blockchain';
+          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : t(lang, 'payment.selected_chain');
 
-          const message = `📝 <b>Submit Transaction Hash</b>\n\n` +
-            `🎯 <b>NFT:</b> ${session.tokenName} (${session.tokenSymbol})\n` +
-            `🔗 <b>Chain:</b> ${chainDisplay}\n\n` +
-            `Please send me your transaction hash for the image fee payment.\n\n` +
-            `<i>Example: 0xabc123456789def...</i>\n\n`;
+          const message = t(lang, 'payment.submit_tx.image_token', {
+            name: session.tokenName,
+            symbol: session.tokenSymbol,
+            chain: chainDisplay
+          });
 
-          const keyboard = Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_images')]]);
+          const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_images')]]);
           try {
             return ctx.editMessageText(message, {
               parse_mode: 'HTML',
@@ -1467,21 +1592,23 @@ class BotCommands {
           await ctx.answerCbQuery();
           const session = this.getUserSession(ctx.from.id);
           if (!session || session.flow !== 'footer_payment') {
+            const lang = await this.getLanguage(ctx);
             try {
-              return ctx.editMessageText('⚠️ Session expired. Please start again.');
+              return ctx.editMessageText(t(lang, 'common.session_expired'));
             } catch (error) {
-              return ctx.reply('⚠️ Session expired. Please start again.');
+              return ctx.reply(t(lang, 'common.session_expired'));
             }
           }
 
           // Set state to expect transaction hash
           this.setFlowState(ctx.from.id, this.STATE_EXPECTING_FOOTER_TX_HASH);
 
+          const lang = await this.getLanguage(ctx);
           const chainConfig = session?.chain && this.chainManager ? this.chainManager.getChain(session.chain) : null;
-          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : 'the selected blockchain';
+          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : t(lang, 'payment.selected_chain');
 
-          const message = `📝 <b>Submit Transaction Hash</b>\n\nPlease send me your ${chainDisplay} transaction hash for the footer payment.\n\n<i>Example: 0xabc123456789def...</i>\n\n`;
-          const keyboard = Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]]);
+          const message = t(lang, 'payment.submit_tx.footer', { chain: chainDisplay });
+          const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]]);
           return ctx.replyWithHTML(message, keyboard);
         }
 
@@ -1490,26 +1617,26 @@ class BotCommands {
           await ctx.answerCbQuery();
           this.setFlowState(ctx.from.id, this.STATE_EXPECTING_FOOTER_TX_HASH);
 
+          const lang = await this.getLanguage(ctx);
           const session = this.getUserSession(ctx.from.id);
           const chainConfig = session?.chain && this.chainManager ? this.chainManager.getChain(session.chain) : null;
-          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : 'the selected blockchain';
+          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : t(lang, 'payment.selected_chain');
 
-          const message = `📝 <b>Submit Transaction Hash</b>\n\nPlease send me your ${chainDisplay} transaction hash for the footer payment.\n\n<i>Example: 0xabc123456789def...</i>\n\n`;
-          const keyboard = Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]]);
+          const message = t(lang, 'payment.submit_tx.footer', { chain: chainDisplay });
+          const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]]);
           return ctx.replyWithHTML(message, keyboard);
         }
         if (data === 'submit_image_tx') {
           await ctx.answerCbQuery();
           this.setFlowState(ctx.from.id, this.STATE_EXPECTING_IMAGE_TX_HASH);
 
+          const lang = await this.getLanguage(ctx);
           const session = this.getUserSession(ctx.from.id);
           const chainConfig = session?.chain && this.chainManager ? this.chainManager.getChain(session.chain) : null;
-          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : 'the selected blockchain';
+          const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : t(lang, 'payment.selected_chain');
 
-          return ctx.reply(`📝 <b>Submit Transaction Hash</b>\n\nPlease send me your ${chainDisplay} transaction hash for the image fee payment.\n\n<i>Example: 0xabc123456789def...</i>\n\n`, {
-            parse_mode: 'HTML',
-            reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_images')]])
-          });
+          return ctx.replyWithHTML(t(lang, 'payment.submit_tx.image', { chain: chainDisplay }),
+            Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_images')]]));
         }
 
         // enter_contract handler removed - dead code from old fallback flow
@@ -1528,6 +1655,22 @@ class BotCommands {
           return;
         }
 
+        // Per-subscription alert filters
+        if (data.startsWith('alerts_')) {
+          await ctx.answerCbQuery();
+          return this.showSubscriptionAlerts(ctx, parseInt(data.replace('alerts_', ''), 10));
+        }
+        if (data.startsWith('alf_')) {
+          await ctx.answerCbQuery();
+          return this.handleAlertFilterAction(ctx, data);
+        }
+
+        // Custom alert templates: tpl_menu, tpl_<v|e|p|r>_<eventType>
+        if (data.startsWith('tpl_')) {
+          await ctx.answerCbQuery();
+          return this.handleTemplateAction(ctx, data);
+        }
+
         if (data.startsWith('stats_')) {
           const tokenId = data.replace('stats_', '');
           await this.showTokenStats(ctx, tokenId);
@@ -1582,22 +1725,11 @@ class BotCommands {
           const currencySymbol = chainConfig ? chainConfig.currencySymbol : 'ETH';
           const contractAddress = chainConfig ? chainConfig.paymentContract : addresses.ethereum.paymentContract;
 
-          const message = `📝 **Submit Transaction Hash**
-
-Please send your ${chainDisplay} transaction hash now.
-
-The transaction hash should:
-• Start with 0x
-• Be 66 characters long
-• Be from a transaction sent to: \`${contractAddress}\`
-
-Example: \`0x1234567890abcdef...\`
+          const lang = await this.getLanguage(ctx);
+          const message = t(lang, 'payment.submit_tx.trending', { chain: chainDisplay, address: contractAddress });
 
-`;
-
-          return ctx.replyWithMarkdown(message, {
-            reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_verify')]])
-          });
+          return ctx.replyWithMarkdown(message,
+            Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_verify')]]));
         }
 
 
@@ -1620,7 +1752,7 @@ Example: \`0x1234567890abcdef...\`
           const page = parseInt(data.replace('channel_page_', ''));
           const user = await this.db.getUser(ctx.from.id.toString());
           if (!user) {
-            return ctx.reply('Please start the bot first with /startminty');
+            return ctx.reply(t(await this.getLanguage(ctx), 'common.start_first'));
           }
           const channels = await this.db.getChannelsByUser(user.id);
           return this.showChannelSelectionMenu(ctx, channels, page);
@@ -1664,6 +1796,34 @@ Example: \`0x1234567890abcdef...\`
           }
         }
 
+        // Toggle aggregated/individual sweep alerts
+        if (data.startsWith('channel_sweep_mode_')) {
+          await ctx.answerCbQuery();
+          const channelChatId = data.replace('channel_sweep_mode_', '');
+          if (!this.chatSettings) {
+            return ctx.reply(t(await this.getLanguage(ctx), 'settings.unavailable'));
+          }
+
+          const currentMode = await this.chatSettings.getSweepMode(channelChatId);
+          await this.chatSettings.setSweepMode(channelChatId, currentMode === 'aggregated' ? 'individual' : 'aggregated');
+          return this.showChannelSettingsForChannel(ctx, channelChatId);
+        }
+
+        if (data.startsWith('channel_language_')) {
+          await ctx.answerCbQuery();
+          const channelChatId = data.replace('channel_language_', '');
+          if (!this.chatSettings) {
+            return ctx.reply(t(await this.getLanguage(ctx), 'settings.unavailable'));
+          }
+
+          // Cycles through the supported languages, like the alert and sweep modes
+          const codes = Object.keys(SUPPORTED_LANGUAGES);
+          const { language } = await this.chatSettings.get(channelChatId);
+          const next = codes[(codes.indexOf(language || DEFAULT_LANGUAGE) + 1) % codes.length];
+          await this.chatSettings.setLanguage(channelChatId, next);
+          return this.showChannelSettingsForChannel(ctx, channelChatId);
+        }
+
         // Add NFTs to channel
         if (data.startsWith('channel_add_nft_')) {
           await ctx.answerCbQuery();
@@ -1680,16 +1840,16 @@ Example: \`0x1234567890abcdef...\`
           this.setUserState(ctx.from.id, 'group_title', settings.settings.channel_title);
 
           // Start chain selection flow
+          const lang = await this.getLanguage(ctx);
           if (!this.chainManager) {
-            return ctx.reply('❌ Chain manager not available');
+            return ctx.reply(t(lang, 'service.chain_manager_unavailable'));
           }
 
-          const message = `🎯 <b>Adding NFT to:</b> ${settings.settings.channel_title}\n\n` +
-            `Please select the blockchain chain:`;
+          const message = t(lang, 'add_token.adding_to', { group: settings.settings.channel_title });
 
           const chainKeyboard = this.chainManager.getChainSelectionKeyboard();
           chainKeyboard.push([
-            { text: '◀️ Back', callback_data: `channel_select_${channelChatId}` }
+            { text: t(lang, 'common.back'), callback_data: `channel_select_${channelChatId}` }
           ]);
 
           const keyboard = Markup.inlineKeyboard(chainKeyboard);
@@ -1715,22 +1875,13 @@ Example: \`0x1234567890abcdef...\`
         }
         if (data === 'help_contact') {
           await ctx.answerCbQuery();
-          const contactMessage = `📞 <b>Help & Contact</b>
-
-🌐 <b>Web:</b> https://www.candycodex.com
-
-📧 <b>Mail:</b> support@candycodex.com
-
-💬 <b>Telegram:</b> @CandyCodex
-
-<i>Need help? Feel free to reach out through any of these channels!</i>`;
-          return ctx.replyWithHTML(contactMessage);
+          return ctx.replyWithHTML(t(await this.getLanguage(ctx), 'contact.message'));
         }
 
-        await ctx.answerCbQuery('Feature coming soon!');
+        await ctx.answerCbQuery(t(await this.getLanguage(ctx), 'common.coming_soon'));
       } catch (error) {
         logger.error('Error handling callback query:', error);
-        await ctx.answerCbQuery('❌ Error processing request');
+        await ctx.answerCbQuery(t(await this.getLanguage(ctx), 'common.request_error'));
       }
     });
 
@@ -1750,8 +1901,9 @@ Example: \`0x1234567890abcdef...\`
       if (chatType === 'group' || chatType === 'supergroup') {
         // If user is expecting a contract, always process their message (no reply required)
         const isExpectingContract = userState === this.STATE_EXPECTING_CONTRACT;
+        const isExpectingAlertFilter = this.isAlertFilterPrompt(userState) || userState === this.STATE_EXPECTING_TEMPLATE;
 
-        if (!isExpectingContract && !this.shouldRespondInGroup(ctx)) {
+        if (!isExpectingContract && !isExpectingAlertFilter && !this.shouldRespondInGroup(ctx)) {
           // Only ignore if user is NOT expecting input AND message is not a reply/mention
           logger.debug(`[TEXT_HANDLER] Ignoring message - user not expecting input and not reply/mention`);
           return;
@@ -1771,7 +1923,10 @@ Example: \`0x1234567890abcdef...\`
       // Handle cancel command first
       if (text.toLowerCase() === 'cancel' || text.toLowerCase() === '/cancel') {
         this.clearUserState(userId);
-        ctx.reply('✅ Operation cancelled.');
+        this.clearFlowState(userId);
+        this.clearUserSession(userId);
+        this.pendingPayments.delete(userIdStr);
+        ctx.reply(t(await this.getLanguage(ctx), 'common.cancelled'));
         return;
       }
 
@@ -1783,7 +1938,7 @@ Example: \`0x1234567890abcdef...\`
         if (text.toLowerCase() === 'cancel') {
           this.clearUserState(ctx.from.id);
           this.pendingPayments.delete(ctx.from.id.toString());
-          ctx.reply('✅ Transaction submission cancelled.');
+          ctx.reply(t(await this.getLanguage(ctx), 'payment.tx_cancelled'));
           return;
         }
         await this.handleTransactionHash(ctx, text);
@@ -1831,6 +1986,12 @@ Example: \`0x1234567890abcdef...\`
       } else if (userState === this.STATE_FOOTER_CONTRACT_INPUT) {
         await this.handleEnhancedFooterContract(ctx, text);
         return;
+      } else if (this.isAlertFilterPrompt(userState)) {
+        await this.handleAlertFilterInput(ctx, text, userState);
+        return;
+      } else if (userState === this.STATE_EXPECTING_TEMPLATE) {
+        await this.handleTemplateInput(ctx, text);
+        return;
       }
 
       // Magic Eden collection symbol or URL (only when in STATE_EXPECTING_CONTRACT)
@@ -1874,6 +2035,7 @@ Example: \`0x1234567890abcdef...\`
         if (!command) return;
 
         logger.info(`Processing channel command: ${command} in channel ${ctx.chat.id}`);
+        const lang = await this.getLanguage(ctx);
 
 
         switch (command) {
@@ -1881,9 +2043,9 @@ Example: \`0x1234567890abcdef...\`
             const chatId = ctx.chat.id.toString();
             const chatType = ctx.chat.type;
             if (chatType !== 'channel' && chatType !== 'supergroup') {
-              return ctx.reply('❌ This command only works in channels or groups. Add me to a channel first, then use this command there.');
+              return ctx.reply(t(lang, 'channel_cmd.add_channel_only'));
             }
-            const channelTitle = ctx.chat.title || 'Unknown Channel';
+            const channelTitle = ctx.chat.title || t(lang, 'channel_cmd.unknown_channel');
 
             let userId = null;
             let username = null;
@@ -1923,196 +2085,1131 @@ Example: \`0x1234567890abcdef...\`
               dbUserId = userResult.id;
               logger.info(`Ensured user ${userId} exists in database with DB ID ${dbUserId}`);
 
-              if (!dbUserId) {
-                logger.error(`createUser returned null ID for user ${userId}`);
-                return ctx.reply('❌ Error setting up user. Please try again.');
-              }
-            } catch (error) {
-              logger.error(`Error ensuring user ${userId} exists:`, error);
-              return ctx.reply('❌ Error setting up user. Please try again.');
-            }
+              if (!dbUserId) {
+                logger.error(`createUser returned null ID for user ${userId}`);
+                return ctx.reply(t(lang, 'channel_cmd.setup_error'));
+              }
+            } catch (error) {
+              logger.error(`Error ensuring user ${userId} exists:`, error);
+              return ctx.reply(t(lang, 'channel_cmd.setup_error'));
+            }
+
+            const result = await this.channels.addChannel(chatId, channelTitle, dbUserId);
+            await ctx.reply(result.message);
+            break;
+
+          case 'channel_settings':
+            const settingsChatId = ctx.chat.id.toString();
+            const settingsChatType = ctx.chat.type;
+            if (settingsChatType !== 'channel' && settingsChatType !== 'supergroup') {
+              return ctx.reply(t(lang, 'channel_cmd.channel_only'));
+            }
+            await this.channels.handleChannelSettingsCommand(ctx, settingsChatId);
+            break;
+
+          case 'quiet_hours':
+            await this.handleQuietHours(ctx);
+            break;
+
+          case 'digest':
+            await this.handleDigest(ctx);
+            break;
+
+          case 'language':
+            await this.handleLanguage(ctx);
+            break;
+
+          case 'watch_wallet':
+            await this.handleWatchWallet(ctx);
+            break;
+
+          case 'unwatch_wallet':
+            await this.handleUnwatchWallet(ctx);
+            break;
+
+          case 'wallets':
+            await this.showWatchedWallets(ctx);
+            break;
+
+          case 'stats_alert':
+            await this.handleStatsAlert(ctx);
+            break;
+
+          case 'stats_alerts':
+            await this.showStatsAlerts(ctx);
+            break;
+
+          case 'stats_alert_remove':
+            await this.handleStatsAlertRemove(ctx);
+            break;
+
+          case 'get_chat_id':
+            await ctx.reply(t(lang, 'chat_id.message_plain', {
+              chatId: ctx.chat.id,
+              chatType: ctx.chat.type,
+              chatTitle: ctx.chat.title || 'N/A'
+            }));
+            break;
+
+          case 'help':
+            await ctx.replyWithHTML(t(lang, 'help.message'));
+            break;
+
+          default:
+            logger.debug(`Unhandled channel command: ${command}`);
+            break;
+        }
+
+      } catch (error) {
+        logger.error('Error handling channel post:', error);
+        try {
+          await ctx.reply(t(await this.getLanguage(ctx), 'channel_cmd.command_error'));
+        } catch (replyError) {
+          logger.error('Failed to send error message for channel post:', replyError);
+        }
+      }
+    });
+
+    // Track when bot is added/removed from groups
+    bot.on('my_chat_member', async (ctx) => {
+      try {
+        const chat = ctx.chat;
+        const newStatus = ctx.myChatMember.new_chat_member.status;
+        const oldStatus = ctx.myChatMember.old_chat_member.status;
+
+        // Only track groups and channels
+        if (chat.type !== 'group' && chat.type !== 'supergroup' && chat.type !== 'channel') {
+          return;
+        }
+
+        const groupChatId = chat.id.toString();
+        const groupTitle = chat.title || 'Unknown Group';
+        const groupType = chat.type;
+
+        logger.info(`[BOT_GROUPS] Status change in ${groupTitle} (${groupChatId}): ${oldStatus} -> ${newStatus}`);
+
+        // Bot was added or promoted
+        if ((oldStatus === 'left' || oldStatus === 'kicked') && (newStatus === 'member' || newStatus === 'administrator')) {
+          await this.db.upsertBotGroup(groupChatId, groupTitle, newStatus, groupType);
+          logger.info(`[BOT_GROUPS] Bot added to ${groupTitle} (${groupChatId}) as ${newStatus}`);
+        }
+        // Bot status changed (member <-> admin)
+        else if ((oldStatus === 'member' || oldStatus === 'administrator') && (newStatus === 'member' || newStatus === 'administrator')) {
+          await this.db.upsertBotGroup(groupChatId, groupTitle, newStatus, groupType);
+          logger.info(`[BOT_GROUPS] Bot status updated in ${groupTitle} (${groupChatId}): ${newStatus}`);
+        }
+        // Bot was removed or kicked
+        else if (newStatus === 'left' || newStatus === 'kicked') {
+          await this.db.upsertBotGroup(groupChatId, groupTitle, newStatus, groupType);
+          logger.info(`[BOT_GROUPS] Bot removed from ${groupTitle} (${groupChatId})`);
+        }
+      } catch (error) {
+        logger.error('[BOT_GROUPS] Error tracking chat member update:', error);
+      }
+    });
+
+    // Update group last_seen on any message activity
+    bot.use(async (ctx, next) => {
+      try {
+        const chat = ctx.chat;
+        if (chat && (chat.type === 'group' || chat.type === 'supergroup' || chat.type === 'channel')) {
+          const groupChatId = chat.id.toString();
+          const groupTitle = chat.title || null;
+
+          // Silently update last_seen timestamp (don't block if it fails)
+          this.db.touchBotGroup(groupChatId, groupTitle).catch(err => {
+            logger.debug(`[BOT_GROUPS] Could not touch group ${groupChatId}: ${err.message}`);
+          });
+        }
+      } catch (error) {
+        logger.debug('[BOT_GROUPS] Error in activity tracking middleware:', error);
+      }
+      return next();
+    });
+
+    logger.info('Bot commands setup completed');
+  }
+
+  // ============================================================================
+  // GROUP SETUP METHODS
+  // ============================================================================
+
+  /**
+   * Handle /startminty command in group context
+   * Generates deep link for private configuration
+   */
+  async handleGroupStart(ctx) {
+    try {
+      const groupId = ctx.chat.id.toString();
+      const lang = await this.getLanguage(ctx);
+      const groupTitle = ctx.chat.title || t(lang, 'group_start.this_group');
+      const userId = ctx.from.id;
+
+      logger.info(`[GROUP_START] User ${userId} (${ctx.from.username}) ran /startminty in group ${groupId} (${groupTitle})`);
+
+      // Ensure user exists in database
+      await this.db.createUser(ctx.from.id.toString(), ctx.from.username, ctx.from.first_name);
+      const user = await this.db.getUser(ctx.from.id.toString());
+
+      // Generate setup token for public setup
+      const crypto = require('crypto');
+      const setupToken = crypto.randomBytes(16).toString('hex');
+
+      // Save group context for public setup
+      await this.db.createGroupContext(groupId, groupTitle, setupToken, user.id);
+
+      // Also track in bot_groups table and mark as setup
+      try {
+        await this.db.upsertBotGroup(groupId, groupTitle, 'member', ctx.chat.type);
+        await this.db.markGroupAsSetup(groupId);
+        logger.info(`[BOT_GROUPS] Tracked and marked ${groupTitle} (${groupId}) as set up via /startminty`);
+      } catch (error) {
+        logger.debug('[BOT_GROUPS] Could not track group in bot_groups:', error.message);
+      }
+
+      // Show message with two buttons
+      const message = t(lang, 'group_start.message', { group: helpers.escapeHtml(groupTitle) });
+
+      // Create deep link for private setup with setup token for auto-detection
+      const botUsername = ctx.botInfo.username;
+      const deepLink = `https://t.me/${botUsername}?start=setup_${setupToken}`;
+
+      const keyboard = Markup.inlineKeyboard([
+        [Markup.button.callback(t(lang, 'group_start.setup_chat'), `public_config_${setupToken}`)],
+        [Markup.button.url(t(lang, 'group_start.setup_bot'), deepLink)]
+      ]);
+
+      await ctx.replyWithHTML(message, keyboard);
+      logger.info(`[GROUP_START] ✅ Setup options shown to group ${groupId}`);
+    } catch (error) {
+      logger.error('[GROUP_START] ❌ Error:', error);
+      await ctx.reply(t(await this.getLanguage(ctx), 'common.error'));
+    }
+  }
+
+  /**
+   * Check that a webhook command runs in a group and was sent by one of its admins
+   * @returns {Promise<boolean>} True if the command may proceed (otherwise a reply was sent)
+   */
+  async ensureGroupAdminForWebhooks(ctx) {
+    const lang = await this.getLanguage(ctx);
+    if (!this.outboundWebhooks) {
+      await ctx.reply(t(lang, 'webhooks.unavailable'));
+      return false;
+    }
+    if (ctx.chat.type !== 'group' && ctx.chat.type !== 'supergroup') {
+      await ctx.reply(t(lang, 'webhooks.group_only'));
+      return false;
+    }
+
+    try {
+      const member = await ctx.getChatMember(ctx.from.id);
+      if (member.status === 'creator' || member.status === 'administrator') {
+        return true;
+      }
+    } catch (error) {
+      logger.warn(`[WEBHOOKS] Could not check admin status of ${ctx.from.id} in ${ctx.chat.id}:`, error.message);
+    }
+
+    await ctx.reply(t(lang, 'webhooks.admins_only'));
+    return false;
+  }
+
+  getCommandArgs(ctx) {
+    return (ctx.message?.text || ctx.channelPost?.text || '').trim().split(/\s+/).slice(1);
+  }
+
+  /**
+   * Send an endpoint secret to the admin privately, never to the group
+   */
+  async sendWebhookSecret(ctx, webhookId, secret) {
+    try {
+      const lang = await this.getLanguage(ctx);
+      await ctx.telegram.sendMessage(ctx.from.id,
+        t(lang, 'webhooks.secret_dm', {
+          id: webhookId,
+          chat: helpers.escapeHtml(ctx.chat.title || ctx.chat.id),
+          secret
+        }),
+        { parse_mode: 'HTML' }
+      );
+      return true;
+    } catch (error) {
+      logger.warn(`[WEBHOOKS] Could not DM secret to ${ctx.from.id}:`, error.message);
+      return false;
+    }
+  }
+
+  async handleWebhookAdd(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!await this.ensureGroupAdminForWebhooks(ctx)) return;
+
+      const [identifier, url, eventTypesInput] = this.getCommandArgs(ctx);
+      if (!identifier || !url) {
+        return ctx.replyWithHTML(t(lang, 'webhooks.add_usage'));
+      }
+
+      const token = await this.outboundWebhooks.findChatToken(ctx.chat.id, identifier);
+      if (!token) {
+        return ctx.reply(t(lang, 'webhooks.not_tracked'));
+      }
+
+      const parsedTypes = this.outboundWebhooks.parseEventTypes(eventTypesInput);
+      if (!parsedTypes.isValid) {
+        return ctx.reply(`❌ ${t(lang, parsedTypes.reason, parsedTypes.params)}`);
+      }
+
+      const user = await this.db.getUser(ctx.from.id.toString());
+      const { id, secret } = await this.outboundWebhooks.register({
+        tokenId: token.id,
+        chatId: ctx.chat.id,
+        userId: user?.id || null,
+        url,
+        eventTypes: parsedTypes.eventTypes
+      });
+
+      const secretSent = await this.sendWebhookSecret(ctx, id, secret);
+      const events = parsedTypes.eventTypes ? parsedTypes.eventTypes.join(', ') : t(lang, 'webhooks.all_events');
+      await ctx.reply(
+        `${t(lang, 'webhooks.registered', { id, name: token.token_name || token.contract_address, events })}\n\n` +
+        (secretSent ? t(lang, 'webhooks.secret_sent') : t(lang, 'webhooks.secret_not_sent', { id }))
+      );
+    } catch (error) {
+      logger.error('Error in webhook_add command:', error);
+      ctx.reply(t(lang, 'webhooks.register_error', { error: error.message }));
+    }
+  }
+
+  async showGroupWebhooks(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!await this.ensureGroupAdminForWebhooks(ctx)) return;
+
+      const webhooks = await this.outboundWebhooks.listForChat(ctx.chat.id);
+      if (webhooks.length === 0) {
+        return ctx.reply(t(lang, 'webhooks.list_empty'));
+      }
+
+      let message = `${t(lang, 'webhooks.list_title')}\n\n`;
+      for (const webhook of webhooks) {
+        const status = webhook.is_active ? '🟢' : '🔴';
+        message += `${status} <b>#${webhook.id}</b> ${helpers.escapeHtml(webhook.token_name || webhook.contract_address)}\n`;
+        message += `   <code>${helpers.escapeHtml(webhook.url)}</code>\n`;
+        message += `   ${t(lang, 'webhooks.events', { events: webhook.event_types || t(lang, 'webhooks.all_events') })}`;
+        if (webhook.failure_count > 0) {
+          message += t(lang, 'webhooks.failures', {
+            count: webhook.failure_count,
+            error: helpers.escapeHtml(webhook.last_error || t(lang, 'common.unknown'))
+          });
+        }
+        message += '\n\n';
+      }
+      message += t(lang, 'webhooks.list_footer');
+
+      await ctx.replyWithHTML(message, { disable_web_page_preview: true });
+    } catch (error) {
+      logger.error('Error in webhooks command:', error);
+      ctx.reply(t(lang, 'webhooks.load_error'));
+    }
+  }
+
+  async handleWebhookRemove(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!await this.ensureGroupAdminForWebhooks(ctx)) return;
+
+      const webhookId = parseInt(this.getCommandArgs(ctx)[0], 10);
+      if (isNaN(webhookId)) {
+        return ctx.reply(t(lang, 'webhooks.remove_usage'));
+      }
+
+      const removed = await this.outboundWebhooks.remove(webhookId, ctx.chat.id);
+      await ctx.reply(t(lang, removed ? 'webhooks.removed' : 'webhooks.not_found', { id: webhookId }));
+    } catch (error) {
+      logger.error('Error in webhook_remove command:', error);
+      ctx.reply(t(lang, 'webhooks.remove_error'));
+    }
+  }
+
+  async handleWebhookSecret(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!await this.ensureGroupAdminForWebhooks(ctx)) return;
+
+      const webhookId = parseInt(this.getCommandArgs(ctx)[0], 10);
+      if (isNaN(webhookId)) {
+        return ctx.reply(t(lang, 'webhooks.secret_usage'));
+      }
+
+      if (!await this.outboundWebhooks.belongsToChat(webhookId, ctx.chat.id)) {
+        return ctx.reply(t(lang, 'webhooks.not_found', { id: webhookId }));
+      }
+
+      // The old secret stays in use unless the new one reached the admin
+      const secret = this.outboundWebhooks.generateSecret();
+      if (!await this.sendWebhookSecret(ctx, webhookId, secret)) {
+        return ctx.reply(t(lang, 'webhooks.secret_unchanged', { id: webhookId }));
+      }
+      if (!await this.outboundWebhooks.rotateSecret(webhookId, ctx.chat.id, secret)) {
+        return ctx.reply(t(lang, 'webhooks.not_found', { id: webhookId }));
+      }
+
+      await ctx.reply(t(lang, 'webhooks.secret_rotated', { id: webhookId }));
+    } catch (error) {
+      logger.error('Error in webhook_secret command:', error);
+      ctx.reply(t(lang, 'webhooks.secret_error'));
+    }
+  }
+
+  /**
+   * Check that chat settings may be changed from here: in a private chat by its user,
+   * in a group by one of its admins, in a channel by a post (only admins can post)
+   * @returns {Promise<boolean>} True if the command may proceed (otherwise a reply was sent)
+   */
+  async ensureChatSettingsAccess(ctx) {
+    const lang = await this.getLanguage(ctx);
+    if (!this.chatSettings) {
+      await ctx.reply(t(lang, 'settings.unavailable'));
+      return false;
+    }
+    if (ctx.chat.type === 'private' || (ctx.chat.type === 'channel' && ctx.channelPost)) {
+      return true;
+    }
+    if (ctx.chat.type !== 'group' && ctx.chat.type !== 'supergroup') {
+      await ctx.reply(t(lang, 'settings.channel_private_only'));
+      return false;
+    }
+    if (await this.isUserGroupAdmin(ctx.from.id, ctx.chat.id, ctx)) {
+      return true;
+    }
+
+    await ctx.reply(t(lang, 'settings.admins_only'));
+    return false;
+  }
+
+  async handleSweepMode(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      const { SWEEP_MODES } = require('../services/chatSettingsService');
+      const [mode] = this.getCommandArgs(ctx).map(arg => arg.toLowerCase());
+      if (!mode) {
+        const currentMode = await this.chatSettings.getSweepMode(ctx.chat.id);
+        return ctx.replyWithHTML(t(lang, 'sweep.status', { mode: currentMode }));
+      }
+
+      if (!SWEEP_MODES.includes(mode)) {
+        return ctx.reply(t(lang, 'sweep.unknown_mode', { mode, modes: SWEEP_MODES.join(', ') }));
+      }
+
+      await this.chatSettings.setSweepMode(ctx.chat.id, mode);
+      await ctx.reply(t(lang, `sweep.saved.${mode}`));
+    } catch (error) {
+      logger.error('Error in sweep_mode command:', error);
+      ctx.reply(t(lang, 'sweep.error'));
+    }
+  }
+
+  /**
+   * Quiet hours and digest state of a chat, as HTML lines
+   */
+  async describeDigestSettings(chatId, lang = DEFAULT_LANGUAGE) {
+    const { isQuietTime, formatMinutes } = require('../services/digestService');
+    const settings = await this.chatSettings.get(chatId);
+
+    const quietHours = settings.quiet_hours_start != null && settings.quiet_hours_end != null
+      ? `${formatMinutes(settings.quiet_hours_start)}-${formatMinutes(settings.quiet_hours_end)} (${settings.timezone})`
+      : t(lang, 'quiet.off');
+    const digest = settings.digest_interval_minutes
+      ? t(lang, 'digest.every', { minutes: settings.digest_interval_minutes })
+      : t(lang, 'digest.off');
+
+    let text = t(lang, 'quiet.summary', { quiet: quietHours, digest });
+    if (isQuietTime(settings)) {
+      text += `\n\n${t(lang, 'quiet.active_now')}`;
+    }
+    return text;
+  }
+
+  /**
+   * /quiet_hours <start> <end> [timezone] | off - hold alerts during a daily window and
+   * post them as one summary when it ends
+   */
+  async handleQuietHours(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      const { parseTimeOfDay } = require('../services/digestService');
+      const { isValidTimezone } = require('../services/chatSettingsService');
+      // "23:00-07:00" is accepted as well as "23:00 07:00"
+      const args = this.getCommandArgs(ctx).join(' ').replace(/(\d)\s*-\s*(\d)/, '$1 $2').split(/\s+/).filter(Boolean);
+
+      if (args.length === 0) {
+        return ctx.replyWithHTML(`${await this.describeDigestSettings(ctx.chat.id, lang)}\n\n${t(lang, 'quiet.help')}`);
+      }
+
+      if (['off', 'none', 'disable'].includes(args[0].toLowerCase())) {
+        await this.chatSettings.setQuietHours(ctx.chat.id, null, null);
+        return ctx.reply(t(lang, 'quiet.turned_off'));
+      }
+
+      const start = parseTimeOfDay(args[0]);
+      const end = parseTimeOfDay(args[1]);
+      if (start === null || end === null) {
+        return ctx.reply(t(lang, 'quiet.invalid_times'));
+      }
+      if (start === end) {
+        return ctx.reply(t(lang, 'quiet.same_times'));
+      }
+
+      const timezone = args[2] || null;
+      if (timezone && !isValidTimezone(timezone)) {
+        return ctx.reply(t(lang, 'quiet.unknown_timezone', { timezone }));
+      }
+
+      await this.chatSettings.setQuietHours(ctx.chat.id, start, end, timezone);
+      await ctx.replyWithHTML(`${t(lang, 'quiet.saved')}\n\n${await this.describeDigestSettings(ctx.chat.id, lang)}`);
+    } catch (error) {
+      logger.error('Error in quiet_hours command:', error);
+      ctx.reply(t(lang, 'quiet.error'));
+    }
+  }
+
+  /**
+   * /digest <interval> | off - post alerts as a periodic summary instead of one by one
+   */
+  async handleDigest(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      const { MAX_DIGEST_INTERVAL_MINUTES } = require('../services/chatSettingsService');
+      const input = this.getCommandArgs(ctx).join('').toLowerCase();
+
+      if (!input) {
+        return ctx.replyWithHTML(`${await this.describeDigestSettings(ctx.chat.id, lang)}\n\n${t(lang, 'digest.help')}`);
+      }
+
+      if (['off', 'none', 'disable', 'live'].includes(input)) {
+        await this.chatSettings.setDigestInterval(ctx.chat.id, null);
+        return ctx.reply(t(lang, 'digest.turned_off'));
+      }
+
+      const match = input.match(/^(\d+)(m|min|mins|minutes|h|hr|hrs|hours)?$/);
+      const minutes = match ? parseInt(match[1], 10) * (match[2] && match[2].startsWith('h') ? 60 : 1) : 0;
+      if (!minutes || minutes < 5 || minutes > MAX_DIGEST_INTERVAL_MINUTES) {
+        return ctx.reply(t(lang, 'digest.invalid_interval', { maxHours: MAX_DIGEST_INTERVAL_MINUTES / 60 }));
+      }
+
+      await this.chatSettings.setDigestInterval(ctx.chat.id, minutes);
+      await ctx.replyWithHTML(`${t(lang, 'digest.saved')}\n\n${await this.describeDigestSettings(ctx.chat.id, lang)}`);
+    } catch (error) {
+      logger.error('Error in digest command:', error);
+      ctx.reply(t(lang, 'digest.error'));
+    }
+  }
+
+  /**
+   * /language [code] - language of this chat's menus and alerts; without a code shows the picker
+   */
+  async handleLanguage(ctx) {
+    const [code] = this.getCommandArgs(ctx).map(arg => arg.toLowerCase());
+    if (!code) {
+      return this.showLanguageMenu(ctx);
+    }
+    return this.handleLanguageSelection(ctx, code);
+  }
+
+  async showLanguageMenu(ctx) {
+    const lang = await this.getLanguage(ctx);
+    const buttons = Object.entries(SUPPORTED_LANGUAGES).map(([code, name]) =>
+      Markup.button.callback(`${code === lang ? '✅ ' : ''}${name}`, `lang_${code}`));
+
+    const keyboard = Markup.inlineKeyboard([
+      buttons.slice(0, 2),
+      buttons.slice(2),
+      helpers.buildBackToMainButton(lang)
+    ]);
+    return this.sendOrEditMenu(ctx, t(lang, 'language.menu', { language: SUPPORTED_LANGUAGES[lang] }), keyboard);
+  }
+
+  async handleLanguageSelection(ctx, code) {
+    try {
+      if (!isSupportedLanguage(code)) {
+        return ctx.reply(t(await this.getLanguage(ctx), 'language.unknown', {
+          code,
+          codes: Object.keys(SUPPORTED_LANGUAGES).join(', ')
+        }));
+      }
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      await this.chatSettings.setLanguage(ctx.chat.id, code);
+      const saved = t(code, 'language.saved', { language: SUPPORTED_LANGUAGES[code] });
+
+      // Buttons redraw the main menu in the new language; commands just confirm
+      if (ctx.callbackQuery) {
+        return this.sendOrEditMenu(ctx, `${saved}\n\n${helpers.formatWelcomeMessage(code)}`, helpers.buildMainMenuKeyboard(code));
+      }
+      return ctx.reply(saved);
+    } catch (error) {
+      logger.error('Error in language command:', error);
+      ctx.reply(t(await this.getLanguage(ctx), 'language.error'));
+    }
+  }
+
+  /**
+   * /templates - custom alert layouts of this chat, one per event type
+   */
+  async showTemplatesMenu(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!this.messageTemplates) {
+        return ctx.reply(t(lang, 'templates.unavailable'));
+      }
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      const templates = await this.messageTemplates.getTemplates(ctx.chat.id);
+      const lines = MessageTemplateService.TEMPLATE_EVENT_TYPES.map(type =>
+        `• <b>${type}</b>: ${t(lang, templates[type] ? 'templates.custom' : 'templates.default')}`);
+
+      const message = t(lang, 'templates.menu', { lines: lines.join('\n') });
+
+      const buttons = MessageTemplateService.TEMPLATE_EVENT_TYPES.map(type =>
+        Markup.button.callback(`${templates[type] ? '✏️ ' : ''}${type}`, `tpl_v_${type}`));
+      const keyboard = Markup.inlineKeyboard([buttons.slice(0, 3), buttons.slice(3)]);
+
+      return this.sendOrEditMenu(ctx, message, keyboard);
+    } catch (error) {
+      logger.error('Error showing templates menu:', error);
+      ctx.reply(t(lang, 'templates.load_error'));
+    }
+  }
+
+  /**
+   * Template of one event type with edit, preview and reset buttons
+   */
+  async showTemplateEditor(ctx, eventType) {
+    const lang = await this.getLanguage(ctx);
+    const custom = await this.messageTemplates.get(ctx.chat.id, eventType);
+    const template = custom || MessageTemplateService.DEFAULT_TEMPLATES[eventType];
+
+    const message = t(lang, 'templates.editor', {
+      type: eventType,
+      state: t(lang, custom ? 'templates.state.custom' : 'templates.state.default'),
+      template: helpers.escapeHtml(template)
+    });
+
+    const rows = [
+      [Markup.button.callback(t(lang, 'templates.edit'), `tpl_e_${eventType}`), Markup.button.callback(t(lang, 'templates.preview'), `tpl_p_${eventType}`)]
+    ];
+    if (custom) {
+      rows.push([Markup.button.callback(t(lang, 'templates.reset'), `tpl_r_${eventType}`)]);
+    }
+    rows.push([Markup.button.callback(t(lang, 'templates.back'), 'tpl_menu')]);
+
+    return this.sendOrEditMenu(ctx, message, Markup.inlineKeyboard(rows));
+  }
+
+  /**
+   * Render a template with sample values, the way an alert would look
+   */
+  async sendTemplatePreview(ctx, eventType, template) {
+    const { ACTIVITY_LABELS } = require('../webhooks/activityEvent');
+    const lang = await this.getLanguage(ctx);
+    const preview = MessageTemplateService.renderTemplate(template, {
+      ...MessageTemplateService.SAMPLE_VALUES,
+      emoji: ACTIVITY_LABELS[eventType].emoji
+    });
+    const footer = `\n${t(lang, 'alert.powered_by', { link: '<a href="https://mint.candycodex.com/">Candy Codex</a>' })}`;
+
+    let note = '';
+    if (preview.length + footer.length > MessageTemplateService.MAX_CAPTION_LENGTH) {
+      note = `\n\n${t(lang, 'templates.caption_warning')}`;
+    }
+    return ctx.reply(`${preview}${footer}${note}`, { parse_mode: 'HTML', disable_web_page_preview: true });
+  }
+
+  async handleTemplateAction(ctx, data) {
+    if (!this.messageTemplates) {
+      return ctx.reply(t(await this.getLanguage(ctx), 'templates.unavailable'));
+    }
+    if (data === 'tpl_menu') {
+      return this.showTemplatesMenu(ctx);
+    }
+    if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+    const [, action, eventType] = data.split('_');
+    if (!MessageTemplateService.TEMPLATE_EVENT_TYPES.includes(eventType)) {
+      return this.showTemplatesMenu(ctx);
+    }
+    if (action === 'v') {
+      return this.showTemplateEditor(ctx, eventType);
+    }
+
+    if (action === 'e') {
+      this.setFlowState(ctx.from.id, this.STATE_EXPECTING_TEMPLATE);
+      this.setUserState(ctx.from.id, 'template_edit', `${ctx.chat.id}:${eventType}`);
+      const placeholders = Object.entries(MessageTemplateService.TEMPLATE_PLACEHOLDERS)
+        .map(([name, description]) => `<code>{${name}}</code> - ${helpers.escapeHtml(description)}`);
+      return ctx.replyWithHTML(t(await this.getLanguage(ctx), 'templates.edit_prompt', {
+        type: eventType,
+        placeholders: placeholders.join('\n')
+      }));
+    }
+
+    if (action === 'p') {
+      const template = await this.messageTemplates.get(ctx.chat.id, eventType) || MessageTemplateService.DEFAULT_TEMPLATES[eventType];
+      return this.sendTemplatePreview(ctx, eventType, template);
+    }
+
+    if (action === 'r') {
+      await this.messageTemplates.reset(ctx.chat.id, eventType);
+    }
+    return this.showTemplateEditor(ctx, eventType);
+  }
+
+  async handleTemplateInput(ctx, text) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      const [chatId, eventType] = String(this.getUserState(ctx.from.id, 'template_edit') || '').split(':');
+      if (!eventType || chatId !== String(ctx.chat.id)) {
+        this.clearFlowState(ctx.from.id);
+        return ctx.reply(t(lang, 'templates.wrong_chat'));
+      }
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      const validation = MessageTemplateService.validateTemplate(text);
+      if (!validation.isValid) {
+        return ctx.reply(t(lang, 'templates.invalid', { reason: t(lang, validation.reason, validation.params) }));
+      }
+
+      await this.messageTemplates.set(ctx.chat.id, eventType, text, ctx.from.id);
+      this.clearFlowState(ctx.from.id);
+      this.clearUserState(ctx.from.id, 'template_edit');
+
+      await ctx.reply(t(lang, 'templates.saved', { type: eventType }));
+      return this.sendTemplatePreview(ctx, eventType, text);
+    } catch (error) {
+      logger.error('Error saving template:', error);
+      ctx.reply(t(lang, 'templates.save_error'));
+    }
+  }
+
+  getChainDisplayName(chainName) {
+    return this.chainManager?.getChain(chainName)?.displayName || chainName;
+  }
+
+  /**
+   * /watch_wallet <address> [chain] [label] - alert on every NFT the wallet buys, sells,
+   * mints or moves, in any collection
+   */
+  async handleWatchWallet(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!this.walletWatch) {
+        return ctx.reply(t(lang, 'wallet.unavailable'));
+      }
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      const { parseWalletAddress } = require('../services/walletWatchService');
+      const [address, ...rest] = this.getCommandArgs(ctx);
+      if (!address) {
+        return ctx.replyWithHTML(t(lang, 'wallet.usage'));
+      }
+
+      // The argument after the address is a chain when it names one, otherwise it starts the label
+      let chainName = null;
+      if (rest.length > 0 && this.chainManager?.getChain(rest[0].toLowerCase())) {
+        chainName = rest.shift().toLowerCase();
+      }
+
+      const wallet = parseWalletAddress(address, chainName);
+      if (!wallet) {
+        return ctx.reply(t(lang, 'wallet.invalid_address'));
+      }
+      const chain = this.getChainDisplayName(wallet.chain);
+      if (!this.walletWatch.isChainAvailable(wallet.chain)) {
+        return ctx.reply(t(lang, 'wallet.chain_unavailable', { chain }));
+      }
+
+      const result = await this.walletWatch.addWallet(
+        { chatId: ctx.chat.id, chatType: ctx.chat.type, addedBy: ctx.from?.id },
+        wallet,
+        rest.join(' ')
+      );
+
+      if (result.status === 'limit') {
+        return ctx.reply(t(lang, 'wallet.limit', { limit: result.limit }));
+      }
+      if (result.status === 'updated') {
+        return ctx.replyWithHTML(t(lang, 'wallet.updated', { address: wallet.address }));
+      }
+      await ctx.replyWithHTML(t(lang, result.synced ? 'wallet.saved' : 'wallet.sync_failed', { address: wallet.address, chain }));
+    } catch (error) {
+      logger.error('Error in watch_wallet command:', error);
+      ctx.reply(t(lang, 'wallet.error'));
+    }
+  }
+
+  /**
+   * /unwatch_wallet <address> - stop watching a wallet on every chain it is watched on
+   */
+  async handleUnwatchWallet(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!this.walletWatch) {
+        return ctx.reply(t(lang, 'wallet.unavailable'));
+      }
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
+
+      const [address] = this.getCommandArgs(ctx);
+      if (!address) {
+        return ctx.replyWithHTML(t(lang, 'wallet.unwatch_usage'));
+      }
+
+      const removed = await this.walletWatch.removeWallet(ctx.chat.id, address);
+      await ctx.replyWithHTML(t(lang, removed > 0 ? 'wallet.removed' : 'wallet.not_found', { address: helpers.escapeHtml(address) }));
+    } catch (error) {
+      logger.error('Error in unwatch_wallet command:', error);
+      ctx.reply(t(lang, 'wallet.error'));
+    }
+  }
+
+  async showWatchedWallets(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!this.walletWatch) {
+        return ctx.reply(t(lang, 'wallet.unavailable'));
+      }
 
-            const result = await this.channels.addChannel(chatId, channelTitle, dbUserId);
-            await ctx.reply(result.message);
-            break;
+      const wallets = await this.walletWatch.listWallets(ctx.chat.id);
+      if (wallets.length === 0) {
+        return ctx.replyWithHTML(t(lang, 'wallet.list_empty'));
+      }
 
-          case 'channel_settings':
-            const settingsChatId = ctx.chat.id.toString();
-            const settingsChatType = ctx.chat.type;
-            if (settingsChatType !== 'channel' && settingsChatType !== 'supergroup') {
-              return ctx.reply('❌ This command only works in channels or groups.');
-            }
-            await this.channels.handleChannelSettingsCommand(ctx, settingsChatId);
-            break;
+      const lines = wallets.map(wallet => {
+        const label = wallet.label ? `<b>${helpers.escapeHtml(wallet.label)}</b> ` : '';
+        return `• ${label}<code>${wallet.address}</code> (${this.getChainDisplayName(wallet.chain_name)})`;
+      });
+      await ctx.replyWithHTML(
+        `${t(lang, 'wallet.list_title', { count: wallets.length, limit: this.walletWatch.walletLimit })}\n\n` +
+        `${lines.join('\n')}\n\n${t(lang, 'wallet.list_footer')}`
+      );
+    } catch (error) {
+      logger.error('Error in wallets command:', error);
+      ctx.reply(t(lang, 'wallet.error'));
+    }
+  }
 
-          case 'get_chat_id':
-            const chatInfo = {
-              id: ctx.chat.id,
-              type: ctx.chat.type,
-              title: ctx.chat.title || 'N/A'
-            };
-            await ctx.reply(`📋 Chat Info:\nID: ${chatInfo.id}\nType: ${chatInfo.type}\nTitle: ${chatInfo.title}`);
-            break;
+  /**
+   * Find a collection tracked in this chat by contract address, slug or name
+   * @returns {Promise<Object|null>} Tracked token row
+   */
+  async findChatTrackedToken(chatId, query) {
+    const needle = String(query || '').toLowerCase();
+    const tokens = await this.db.getGroupTrackedTokens(chatId.toString());
+    return tokens.find(token => [token.contract_address, token.collection_slug, token.token_name]
+      .some(value => value && value.toLowerCase() === needle)) || null;
+  }
 
-          case 'help':
-            const helpMessage = `📋 <b>Minty RushBot Commands</b>
-
-🎯 <b>NFT Management:</b>
-• /add_token - Add NFT collection to track
-• /remove_token - Remove tracked NFT  
-• /my_tokens - View your tracked NFTs
-
-💰 <b>Trending &amp; Boost:</b>
-• /trending - View trending collections
-• /buy_trending - Boost NFT trending
-• /validate &lt;txhash&gt; - Validate trending payment
-• /buy_image &lt;contract&gt; - Pay fee for real NFT images
-• /validate_image &lt;contract&gt; &lt;txhash&gt; - Validate image fee
-• /buy_footer &lt;contract&gt; - Pay fee for footer advertisement
-• /validate_footer &lt;contract&gt; &lt;txhash&gt; &lt;link&gt; - Validate footer ad
-
-📺 <b>Channel Commands:</b>
-• /add_channel - Add bot to channel
-• /channel_settings - Configure channel alerts
-
-• /startminty - Welcome message
-• /help - Show this help
-
-Simple and focused - boost your NFTs easily! 🚀`;
-            await ctx.replyWithHTML(helpMessage);
-            break;
+  describeStatsAlertRule(rule, lang = DEFAULT_LANGUAGE) {
+    const metric = t(lang, `stats_alert.metric.${rule.metric}`);
+    if (rule.condition === 'change') {
+      return t(lang, 'stats_alert.rule.change', { metric, threshold: parseFloat(rule.threshold), window: formatWindow(rule.window_minutes) });
+    }
+    const currency = this.chainManager?.getCurrencySymbol(rule.chain_name) || '';
+    return t(lang, `stats_alert.rule.${rule.condition}`, { metric, threshold: `${parseFloat(rule.threshold)} ${currency}`.trim() });
+  }
 
-          default:
-            logger.debug(`Unhandled channel command: ${command}`);
-            break;
-        }
+  /**
+   * /stats_alert <collection> <floor|volume> <below|above|change> <value> [window] - alert
+   * when a tracked collection's floor or 24h volume crosses a threshold or moves by a percentage
+   */
+  async handleStatsAlert(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!this.statsAlerts) {
+        return ctx.reply(t(lang, 'stats_alert.unavailable'));
+      }
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
 
-      } catch (error) {
-        logger.error('Error handling channel post:', error);
-        try {
-          await ctx.reply('❌ An error occurred processing the command. Please try again.');
-        } catch (replyError) {
-          logger.error('Failed to send error message for channel post:', replyError);
+      const args = this.getCommandArgs(ctx);
+      if (args.length < 4) {
+        return ctx.replyWithHTML(t(lang, 'stats_alert.usage'));
+      }
+
+      // Names may contain spaces, so the rule is read from the end: "... floor change 10% 1h"
+      let rule = null;
+      let collectionArgs = null;
+      for (const ruleLength of [4, 3]) {
+        if (args.length <= ruleLength) continue;
+        rule = parseStatsAlertRule(args.slice(-ruleLength));
+        if (rule) {
+          collectionArgs = args.slice(0, -ruleLength);
+          break;
         }
       }
-    });
+      if (!rule) {
+        return ctx.replyWithHTML(t(lang, 'stats_alert.invalid_rule'));
+      }
 
-    // Track when bot is added/removed from groups
-    bot.on('my_chat_member', async (ctx) => {
-      try {
-        const chat = ctx.chat;
-        const newStatus = ctx.myChatMember.new_chat_member.status;
-        const oldStatus = ctx.myChatMember.old_chat_member.status;
+      const collection = collectionArgs.join(' ');
+      const token = await this.findChatTrackedToken(ctx.chat.id, collection);
+      if (!token) {
+        return ctx.replyWithHTML(t(lang, 'stats_alert.not_tracked', { collection: helpers.escapeHtml(collection) }));
+      }
 
-        // Only track groups and channels
-        if (chat.type !== 'group' && chat.type !== 'supergroup' && chat.type !== 'channel') {
-          return;
-        }
+      const result = await this.statsAlerts.addRule(
+        { chatId: ctx.chat.id, chatType: ctx.chat.type, createdBy: ctx.from?.id },
+        token,
+        rule
+      );
+      if (result.status === 'limit') {
+        return ctx.reply(t(lang, 'stats_alert.limit', { limit: result.limit }));
+      }
 
-        const groupChatId = chat.id.toString();
-        const groupTitle = chat.title || 'Unknown Group';
-        const groupType = chat.type;
+      const description = this.describeStatsAlertRule({ ...rule, window_minutes: rule.windowMinutes, chain_name: token.chain_name }, lang);
+      await ctx.replyWithHTML(t(lang, 'stats_alert.saved', {
+        id: result.id,
+        collection: helpers.escapeHtml(token.token_name || token.collection_slug || token.contract_address),
+        rule: helpers.escapeHtml(description)
+      }));
+    } catch (error) {
+      logger.error('Error in stats_alert command:', error);
+      ctx.reply(t(lang, 'stats_alert.error'));
+    }
+  }
 
-        logger.info(`[BOT_GROUPS] Status change in ${groupTitle} (${groupChatId}): ${oldStatus} -> ${newStatus}`);
+  async showStatsAlerts(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!this.statsAlerts) {
+        return ctx.reply(t(lang, 'stats_alert.unavailable'));
+      }
 
-        // Bot was added or promoted
-        if ((oldStatus === 'left' || oldStatus === 'kicked') && (newStatus === 'member' || newStatus === 'administrator')) {
-          await this.db.upsertBotGroup(groupChatId, groupTitle, newStatus, groupType);
-          logger.info(`[BOT_GROUPS] Bot added to ${groupTitle} (${groupChatId}) as ${newStatus}`);
-        }
-        // Bot status changed (member <-> admin)
-        else if ((oldStatus === 'member' || oldStatus === 'administrator') && (newStatus === 'member' || newStatus === 'administrator')) {
-          await this.db.upsertBotGroup(groupChatId, groupTitle, newStatus, groupType);
-          logger.info(`[BOT_GROUPS] Bot status updated in ${groupTitle} (${groupChatId}): ${newStatus}`);
-        }
-        // Bot was removed or kicked
-        else if (newStatus === 'left' || newStatus === 'kicked') {
-          await this.db.upsertBotGroup(groupChatId, groupTitle, newStatus, groupType);
-          logger.info(`[BOT_GROUPS] Bot removed from ${groupTitle} (${groupChatId})`);
-        }
-      } catch (error) {
-        logger.error('[BOT_GROUPS] Error tracking chat member update:', error);
+      const rules = await this.statsAlerts.listRules(ctx.chat.id);
+      if (rules.length === 0) {
+        return ctx.replyWithHTML(t(lang, 'stats_alert.list_empty'));
       }
-    });
 
-    // Update group last_seen on any message activity
-    bot.use(async (ctx, next) => {
-      try {
-        const chat = ctx.chat;
-        if (chat && (chat.type === 'group' || chat.type === 'supergroup' || chat.type === 'channel')) {
-          const groupChatId = chat.id.toString();
-          const groupTitle = chat.title || null;
+      const lines = rules.map(rule => {
+        const collection = helpers.escapeHtml(rule.token_name || rule.collection_slug || '');
+        return `<code>#${rule.id}</code> <b>${collection}</b> - ${helpers.escapeHtml(this.describeStatsAlertRule(rule, lang))}`;
+      });
+      await ctx.replyWithHTML(
+        `${t(lang, 'stats_alert.list_title', { count: rules.length, limit: this.statsAlerts.ruleLimit })}\n\n` +
+        `${lines.join('\n')}\n\n${t(lang, 'stats_alert.list_footer')}`
+      );
+    } catch (error) {
+      logger.error('Error in stats_alerts command:', error);
+      ctx.reply(t(lang, 'stats_alert.error'));
+    }
+  }
 
-          // Silently update last_seen timestamp (don't block if it fails)
-          this.db.touchBotGroup(groupChatId, groupTitle).catch(err => {
-            logger.debug(`[BOT_GROUPS] Could not touch group ${groupChatId}: ${err.message}`);
-          });
-        }
-      } catch (error) {
-        logger.debug('[BOT_GROUPS] Error in activity tracking middleware:', error);
+  async handleStatsAlertRemove(ctx) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      if (!this.statsAlerts) {
+        return ctx.reply(t(lang, 'stats_alert.unavailable'));
       }
-      return next();
-    });
+      if (!await this.ensureChatSettingsAccess(ctx)) return;
 
-    logger.info('Bot commands setup completed');
+      const ruleId = parseInt(String(this.getCommandArgs(ctx)[0] || '').replace(/^#/, ''), 10);
+      if (!ruleId) {
+        return ctx.replyWithHTML(t(lang, 'stats_alert.remove_usage'));
+      }
+
+      const removed = await this.statsAlerts.removeRule(ctx.chat.id, ruleId);
+      await ctx.reply(t(lang, removed ? 'stats_alert.removed' : 'stats_alert.not_found', { id: ruleId }));
+    } catch (error) {
+      logger.error('Error in stats_alert_remove command:', error);
+      ctx.reply(t(lang, 'stats_alert.error'));
+    }
   }
 
-  // ============================================================================
-  // GROUP SETUP METHODS
-  // ============================================================================
+  isAlertFilterPrompt(state) {
+    return [this.STATE_EXPECTING_ALERT_MIN_PRICE, this.STATE_EXPECTING_ALERT_MARKETPLACES, this.STATE_EXPECTING_ALERT_SNIPE,
+      this.STATE_EXPECTING_ALERT_TRAITS].includes(state);
+  }
 
   /**
-   * Handle /startminty command in group context
-   * Generates deep link for private configuration
+   * Make sure the subscription's collection has a rarity snapshot to check trait filters
+   * against. Runs in the background; a fresh snapshot is left alone.
    */
-  async handleGroupStart(ctx) {
-    try {
-      const groupId = ctx.chat.id.toString();
-      const groupTitle = ctx.chat.title || 'this group';
-      const userId = ctx.from.id;
+  requestTraitIndex(subscription) {
+    const rarity = this.tokenTracker?.webhookHandlers?.rarity;
+    if (!rarity) return;
+
+    const token = {
+      id: subscription.token_id,
+      token_name: subscription.token_name,
+      contract_address: subscription.contract_address,
+      chain_name: subscription.chain_name,
+      collection_slug: subscription.collection_slug
+    };
+    rarity.refreshIfStale(token).catch(error => {
+      logger.warn(`Could not index the traits of ${token.contract_address}: ${error.message}`);
+    });
+  }
 
-      logger.info(`[GROUP_START] User ${userId} (${ctx.from.username}) ran /startminty in group ${groupId} (${groupTitle})`);
+  /**
+   * Load one of the user's own subscriptions with its filters
+   * @returns {Promise<Object|null>} Subscription row with token fields and `filters`
+   */
+  async loadUserSubscription(ctx, subscriptionId) {
+    const user = await this.db.getUser(ctx.from.id.toString());
+    if (!user || isNaN(subscriptionId)) return null;
 
-      // Ensure user exists in database
-      await this.db.createUser(ctx.from.id.toString(), ctx.from.username, ctx.from.first_name);
-      const user = await this.db.getUser(ctx.from.id.toString());
+    const subscription = await this.db.getUserSubscription(subscriptionId, user.id);
+    if (!subscription) return null;
 
-      // Generate setup token for public setup
-      const crypto = require('crypto');
-      const setupToken = crypto.randomBytes(16).toString('hex');
+    return { ...subscription, filters: subscriptionFilters.fromSubscriptionRow(subscription) };
+  }
 
-      // Save group context for public setup
-      await this.db.createGroupContext(groupId, groupTitle, setupToken, user.id);
+  async showSubscriptionAlerts(ctx, subscriptionId) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      const subscription = await this.loadUserSubscription(ctx, subscriptionId);
+      if (!subscription) {
+        return ctx.reply(t(lang, 'filters.not_found'));
+      }
+
+      const { filters } = subscription;
+      const currency = this.chainManager?.getCurrencySymbol(subscription.chain_name) || 'ETH';
+      const contextLabel = await this.resolveContextLabel(subscription.chat_id, ctx, ctx.from.id.toString());
+      const selectedTypes = filters?.eventTypes || subscriptionFilters.FILTER_EVENT_TYPES;
+      const summary = subscriptionFilters.describeFilters(filters, currency, lang);
+
+      let message = `${t(lang, 'filters.title', { name: helpers.escapeHtml(subscription.token_name || subscription.contract_address) })}\n`;
+      message += `📍 ${helpers.escapeHtml(contextLabel)}\n\n`;
+      message += summary.length > 0
+        ? summary.map(line => `• ${helpers.escapeHtml(line)}`).join('\n')
+        : t(lang, 'filters.none');
+      message += `\n\n${t(lang, 'filters.tap_hint')}`;
+
+      const typeButtons = subscriptionFilters.FILTER_EVENT_TYPES.map(type =>
+        Markup.button.callback(`${selectedTypes.includes(type) ? '✅' : '❌'} ${t(lang, `filters.type.${type}`)}`, `alf_t_${subscriptionId}_${type}`));
+
+      const minPriceLabel = filters?.minPrice
+        ? (filters.minPrice.unit === 'usd' ? `$${filters.minPrice.amount}` : `${filters.minPrice.amount} ${currency}`)
+        : t(lang, 'filters.value.none');
+      const marketplaceLabel = filters?.marketplaces
+        ? subscriptionFilters.describeMarketplaces(filters.marketplaces, lang)
+        : t(lang, 'filters.value.all');
+      const snipeLabel = filters?.snipe
+        ? (filters.snipe.discount > 0 ? `≥${filters.snipe.discount}%` : t(lang, 'filters.value.below_floor'))
+        : t(lang, 'filters.value.off');
+      const traitLabel = filters?.traits
+        ? filters.traits.map(subscriptionFilters.formatTraitFilter).join('; ')
+        : t(lang, 'filters.value.all');
 
-      // Also track in bot_groups table and mark as setup
-      try {
-        await this.db.upsertBotGroup(groupId, groupTitle, 'member', ctx.chat.type);
-        await this.db.markGroupAsSetup(groupId);
-        logger.info(`[BOT_GROUPS] Tracked and marked ${groupTitle} (${groupId}) as set up via /startminty`);
-      } catch (error) {
-        logger.debug('[BOT_GROUPS] Could not track group in bot_groups:', error.message);
-      }
+      const keyboard = Markup.inlineKeyboard([
+        typeButtons.slice(0, 3),
+        typeButtons.slice(3),
+        [Markup.button.callback(t(lang, 'filters.min_price', { value: minPriceLabel }), `alf_p_${subscriptionId}`)],
+        [Markup.button.callback(t(lang, 'filters.marketplaces', { value: marketplaceLabel }).slice(0, 60), `alf_m_${subscriptionId}`)],
+        [Markup.button.callback(t(lang, filters?.ignoreBurns ? 'filters.burns.hidden' : 'filters.burns.shown'), `alf_b_${subscriptionId}`)],
+        [Markup.button.callback(t(lang, 'filters.snipe', { value: snipeLabel }), `alf_s_${subscriptionId}`)],
+        [Markup.button.callback(t(lang, 'filters.traits', { value: traitLabel }).slice(0, 60), `alf_a_${subscriptionId}`)],
+        [Markup.button.callback(t(lang, 'filters.reset'), `alf_r_${subscriptionId}`)],
+        [Markup.button.callback(t(lang, 'filters.back'), 'my_tokens')]
+      ]);
 
-      // Show message with two buttons
-      const message = `🎉 <b>Welcome to Minty Rush!</b>
+      return this.sendOrEditMenu(ctx, message, keyboard);
+    } catch (error) {
+      logger.error('Error showing alert settings:', error);
+      ctx.reply(t(lang, 'filters.load_error'));
+    }
+  }
 
-To configure bot for <b>${groupTitle}</b>
-select an option below:`;
+  /**
+   * Buttons of the alert settings menu: alf_<action>_<subscriptionId>[_<eventType>]
+   */
+  async handleAlertFilterAction(ctx, data) {
+    const [, action, id, eventType] = data.split('_');
+    const subscriptionId = parseInt(id, 10);
+    const subscription = await this.loadUserSubscription(ctx, subscriptionId);
+    if (!subscription) {
+      return ctx.reply(t(await this.getLanguage(ctx), 'filters.not_found'));
+    }
 
-      // Create deep link for private setup with setup token for auto-detection
-      const botUsername = ctx.botInfo.username;
-      const deepLink = `https://t.me/${botUsername}?start=setup_${setupToken}`;
+    const filters = subscription.filters || { eventTypes: null, minPrice: null, marketplaces: null, ignoreBurns: false, snipe: null, traits: null };
+
+    const prompts = {
+      p: { state: this.STATE_EXPECTING_ALERT_MIN_PRICE, key: 'filters.min_price_prompt' },
+      m: { state: this.STATE_EXPECTING_ALERT_MARKETPLACES, key: 'filters.marketplaces_prompt' },
+      s: { state: this.STATE_EXPECTING_ALERT_SNIPE, key: 'filters.snipe_prompt' },
+      a: { state: this.STATE_EXPECTING_ALERT_TRAITS, key: 'filters.traits_prompt' }
+    };
+    if (prompts[action]) {
+      this.setFlowState(ctx.from.id, prompts[action].state);
+      this.setUserState(ctx.from.id, 'alert_filter_subscription', subscriptionId);
+      return ctx.replyWithHTML(t(await this.getLanguage(ctx), prompts[action].key));
+    }
 
-      const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('💬 Setup in TG Chat', `public_config_${setupToken}`)],
-        [Markup.button.url('🤖 Setup inside BOT', deepLink)]
-      ]);
+    if (action === 't' && subscriptionFilters.FILTER_EVENT_TYPES.includes(eventType)) {
+      const selected = new Set(filters.eventTypes || subscriptionFilters.FILTER_EVENT_TYPES);
+      if (selected.has(eventType)) {
+        selected.delete(eventType);
+      } else {
+        selected.add(eventType);
+      }
+      const eventTypes = subscriptionFilters.FILTER_EVENT_TYPES.filter(type => selected.has(type));
+      filters.eventTypes = eventTypes.length === subscriptionFilters.FILTER_EVENT_TYPES.length ? null : eventTypes;
+    } else if (action === 'b') {
+      filters.ignoreBurns = !filters.ignoreBurns;
+    } else if (action === 'r') {
+      Object.assign(filters, { eventTypes: null, minPrice: null, marketplaces: null, ignoreBurns: false, snipe: null, traits: null });
+    } else {
+      return this.showSubscriptionAlerts(ctx, subscriptionId);
+    }
 
-      await ctx.replyWithHTML(message, keyboard);
-      logger.info(`[GROUP_START] ✅ Setup options shown to group ${groupId}`);
+    await this.db.updateSubscriptionFilters(subscriptionId, subscriptionFilters.toSubscriptionColumns(filters));
+    return this.showSubscriptionAlerts(ctx, subscriptionId);
+  }
+
+  async handleAlertFilterInput(ctx, text, state) {
+    const lang = await this.getLanguage(ctx);
+    try {
+      const subscriptionId = this.getUserState(ctx.from.id, 'alert_filter_subscription');
+      const subscription = await this.loadUserSubscription(ctx, subscriptionId);
+      if (!subscription) {
+        this.clearFlowState(ctx.from.id);
+        return ctx.reply(t(lang, 'filters.not_found'));
+      }
+
+      const filters = subscription.filters || { eventTypes: null, minPrice: null, marketplaces: null, ignoreBurns: false, snipe: null, traits: null };
+      if (state === this.STATE_EXPECTING_ALERT_MIN_PRICE) {
+        const parsed = subscriptionFilters.parseMinPrice(text);
+        if (!parsed.isValid) return ctx.reply(`❌ ${t(lang, parsed.reason, parsed.params)}`);
+        filters.minPrice = parsed.minPrice;
+      } else if (state === this.STATE_EXPECTING_ALERT_SNIPE) {
+        const parsed = subscriptionFilters.parseSnipeDiscount(text);
+        if (!parsed.isValid) return ctx.reply(`❌ ${t(lang, parsed.reason, parsed.params)}`);
+        filters.snipe = parsed.snipe;
+      } else if (state === this.STATE_EXPECTING_ALERT_TRAITS) {
+        const parsed = subscriptionFilters.parseTraitFilter(text);
+        if (!parsed.isValid) return ctx.reply(`❌ ${t(lang, parsed.reason, parsed.params)}`);
+        filters.traits = parsed.traits;
+        if (filters.traits) {
+          this.requestTraitIndex(subscription);
+        }
+      } else {
+        const parsed = subscriptionFilters.parseMarketplaceFilter(text);
+        if (!parsed.isValid) return ctx.reply(`❌ ${t(lang, parsed.reason, parsed.params)}`);
+        filters.marketplaces = parsed.marketplaces;
+      }
+
+      await this.db.updateSubscriptionFilters(subscriptionId, subscriptionFilters.toSubscriptionColumns(filters));
+      this.clearFlowState(ctx.from.id);
+      this.clearUserState(ctx.from.id, 'alert_filter_subscription');
+      return this.showSubscriptionAlerts(ctx, subscriptionId);
     } catch (error) {
-      logger.error('[GROUP_START] ❌ Error:', error);
-      await ctx.reply('❌ An error occurred. Please try again.');
+      logger.error('Error saving alert filter:', error);
+      ctx.reply(t(lang, 'filters.save_error'));
     }
   }
 
@@ -2129,10 +3226,11 @@ select an option below:`;
 
       // Validate setup token and get group context
       const groupContext = await this.db.getGroupContextByToken(setupToken);
+      const lang = await this.getLanguage(ctx);
 
       if (!groupContext) {
         logger.warn(`[PUBLIC_CONFIG] Invalid/expired token: ${setupToken}`);
-        return ctx.reply('❌ Invalid or expired setup link. Please run /startminty in the group again.');
+        return ctx.reply(t(lang, 'start.invalid_setup'));
       }
 
       logger.info(`[PUBLIC_CONFIG] Found group context: ${groupContext.group_title} (${groupContext.group_chat_id})`);
@@ -2143,8 +3241,8 @@ select an option below:`;
       await this.db.createUser(ctx.from.id.toString(), ctx.from.username, ctx.from.first_name);
 
       // Show welcome message and main menu in the group (edit the setup message)
-      const welcomeMessage = helpers.formatWelcomeMessage();
-      const keyboard = helpers.buildMainMenuKeyboard();
+      const welcomeMessage = helpers.formatWelcomeMessage(lang);
+      const keyboard = helpers.buildMainMenuKeyboard(lang);
 
       try {
         await ctx.editMessageText(welcomeMessage, {
@@ -2159,16 +3257,17 @@ select an option below:`;
       }
     } catch (error) {
       logger.error('[PUBLIC_CONFIG] ❌ Error:', error);
-      await ctx.reply('❌ An error occurred. Please try again.');
+      await ctx.reply(t(await this.getLanguage(ctx), 'common.error'));
     }
   }
 
 
   async handleContractAddress(ctx, contractAddress) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Get selected chain from user session data
@@ -2178,9 +3277,9 @@ select an option below:`;
       logger.info(`Token addition - Telegram ID: ${ctx.from.id}, Database User ID: ${user.id}, Address: ${contractAddress}, Chain: ${selectedChain}`);
 
       // Customize validation message based on chain
-      let validationMessage = `🔍 Validating and adding on ${chainConfig ? chainConfig.displayName : selectedChain}...`;
+      let validationMessage = t(lang, 'add_token.validating', { chain: chainConfig ? chainConfig.displayName : selectedChain });
       if (selectedChain === 'solana') {
-        validationMessage = `◎ Validating Solana NFT via Magic Eden...`;
+        validationMessage = t(lang, 'add_token.validating_solana');
       }
       const validatingMsg = await ctx.reply(validationMessage);
 
@@ -2217,11 +3316,7 @@ select an option below:`;
 
       // Block private chat tracking - users must add tokens in groups/channels only
       if (ctx.chat.type === 'private' && !configuringGroupId) {
-        return ctx.replyWithHTML(
-          '❌ <b>Private tracking is not available</b>\n\n' +
-          'Please add this bot to a group or channel and use the <b>Setup inside BOT</b> button to track NFTs.\n\n' +
-          'You can still view your tracked NFTs from groups here in DM.'
-        );
+        return ctx.replyWithHTML(t(lang, 'add_token.private_unavailable'));
       }
 
       // Determine chat context
@@ -2271,13 +3366,13 @@ select an option below:`;
           inline_keyboard: [
             [
               {
-                text: 'BOOST YOUR NFT🟢',
+                text: t(lang, 'add_token.boost_button'),
                 callback_data: '/buy_trending'
               }
             ],
             [
               {
-                text: '🏠 Main Menu',
+                text: t(lang, 'common.main_menu'),
                 callback_data: 'main_menu'
               }
             ]
@@ -2304,8 +3399,8 @@ select an option below:`;
             const verifyTokens = await this.db.getUserTrackedTokens(user.id);
             const addedToken = verifyTokens.find(t => t.contract_address.toLowerCase() === contractAddress.toLowerCase());
             if (addedToken) {
-              const successMessage = '✅ Verification: Token is now in your tracking list!';
-              const keyboard = Markup.inlineKeyboard([[Markup.button.callback('👁️ View My NFTs', 'my_tokens')]]);
+              const successMessage = t(lang, 'add_token.verified');
+              const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'tokens_menu.view_mine'), 'my_tokens')]]);
               const verifyMsg = await ctx.replyWithHTML(successMessage, keyboard);
 
               // Auto-delete verification message after 1 minute
@@ -2351,7 +3446,7 @@ select an option below:`;
 
         logger.info(`[LISTENING_MODE] Cleared for user ${ctx.from.id} - validation failed`);
 
-        await ctx.reply(`❌ ${result.message}\n\nIf you think this is a mistake, try again or contact support.`);
+        await ctx.reply(t(lang, 'add_token.failed', { reason: result.message }));
         logger.error(`Failed to add token ${contractAddress} for user ${user.id}: ${result.message}`);
       }
     } catch (error) {
@@ -2385,25 +3480,26 @@ select an option below:`;
 
       logger.info(`[LISTENING_MODE] Cleared for user ${ctx.from.id} - exception occurred`);
 
-      ctx.reply('❌ Error adding token. Please check the NFT address and try again.');
+      ctx.reply(t(lang, 'add_token.error'));
     }
   }
 
   async handleTransactionHash(ctx, txHash) {
+    const lang = await this.getLanguage(ctx);
     try {
       const userId = ctx.from.id.toString();
       const pendingPayment = this.pendingPayments.get(userId);
       if (!pendingPayment) {
         this.clearUserState(userId);
-        return ctx.reply('❌ No pending payment found. Please start the boost process again.');
+        return ctx.reply(t(lang, 'payment.no_pending'));
       }
 
 
       if (!txHash.match(/^0x[a-fA-F0-9]{64}$/)) {
-        return ctx.reply('❌ Invalid transaction hash format. Please send a valid transaction hash (starts with 0x and is 64 characters long).\n\nOr type "cancel" to abort.');
+        return ctx.reply(`${t(lang, 'payment.invalid_tx_hash')}\n\n${t(lang, 'payment.cancel_hint')}`);
       }
 
-      ctx.reply('🔍 Validating your transaction... This may take a few moments.');
+      ctx.reply(t(lang, 'payment.validating'));
 
       // Extract group link from pending payment
       const groupLink = pendingPayment.groupLink || null;
@@ -2437,28 +3533,22 @@ select an option below:`;
       this.pendingPayments.delete(userId);
 
       if (result.success) {
-        const successMessage = `✅ **Payment Confirmed!**
-
-🔥 **${result.tokenName}** is now trending for ${result.duration} hour${result.duration > 1 ? 's' : ''}!
-
-💰 Amount: ${ethers.formatEther(result.amount)} ETH
-📝 Transaction: \`${txHash}\`
-🆔 Payment ID: ${result.paymentId}
-
-Your collection will appear in the trending list and be promoted in channels. Thank you for boosting the ecosystem! 🚀`;
+        const successMessage = t(lang, 'payment.trending_confirmed', {
+          name: result.tokenName,
+          duration: result.duration,
+          count: result.duration,
+          amount: ethers.formatEther(result.amount),
+          txHash,
+          paymentId: result.paymentId
+        });
 
         await ctx.replyWithMarkdown(successMessage);
       } else {
-        const errorMessage = `❌ **Payment Validation Failed**
-
-${result.error}
-
-Please check:
-• Transaction was sent to: \`${process.env.TRENDING_CONTRACT_ADDRESS}\`
-• Exact amount was sent: ${ethers.formatEther(pendingPayment.expectedAmount)} ETH
-• Transaction is confirmed on blockchain
-
-You can try again with a different transaction hash or contact support.`;
+        const errorMessage = t(lang, 'payment.trending_failed', {
+          error: result.error,
+          address: process.env.TRENDING_CONTRACT_ADDRESS,
+          amount: ethers.formatEther(pendingPayment.expectedAmount)
+        });
 
         await ctx.replyWithMarkdown(errorMessage);
       }
@@ -2467,15 +3557,16 @@ You can try again with a different transaction hash or contact support.`;
       logger.error('Error handling transaction hash:', error);
       this.clearUserState(ctx.from.id);
       this.pendingPayments.delete(ctx.from.id.toString());
-      ctx.reply('❌ Error validating transaction. Please try again or contact support.');
+      ctx.reply(t(lang, 'payment.validation_error'));
     }
   }
 
   async handleRemoveToken(ctx, tokenId, explicitChatId = null) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('❌ User not found. Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'remove.user_not_found'));
       }
 
       // Use explicit chatId if provided (for context-aware removal from DMs)
@@ -2485,7 +3576,7 @@ You can try again with a different transaction hash or contact support.`;
       // Get token info for the success message
       const token = await this.db.get('SELECT * FROM tracked_tokens WHERE id = $1', [tokenId]);
       if (!token) {
-        return ctx.reply('❌ NFT not found.');
+        return ctx.reply(t(lang, 'remove.not_found'));
       }
 
       // Check if user has subscription in this chat context
@@ -2495,7 +3586,7 @@ You can try again with a different transaction hash or contact support.`;
       );
 
       if (!subscription) {
-        return ctx.reply('❌ You are not subscribed to this NFT in this chat context.');
+        return ctx.reply(t(lang, 'remove.not_subscribed'));
       }
 
       // Remove subscription from this specific chat context
@@ -2525,14 +3616,13 @@ You can try again with a different transaction hash or contact support.`;
 
         if (!removalResult.success) {
           logger.error(`Failed to remove token via tokenTracker: ${removalResult.message}`);
-          return ctx.reply('❌ Error completing token removal. Please try again.');
+          return ctx.reply(t(lang, 'remove.completion_error'));
         }
 
-        const successMessage = `✅ <b>Token Removed Successfully</b>
-
-🗑️ <b>${token.token_name || 'Unknown Collection'}</b> has been completely removed from tracking.
-
-📮 Contract: <code>${token.contract_address}</code>`;
+        const successMessage = t(lang, 'remove.removed_completely', {
+          name: token.token_name || t(lang, 'tokens.unknown_collection'),
+          address: token.contract_address
+        });
 
         await ctx.replyWithHTML(successMessage);
         logger.info(`Token completely removed via tokenTracker: ${token.contract_address} by user ${user.id}`);
@@ -2541,92 +3631,91 @@ You can try again with a different transaction hash or contact support.`;
         logger.info(`🔄 REMOVE_TOKEN DEBUG: NOT calling tokenTracker.removeToken() - token still has ${subscriptionCount} remaining subscriptions`);
       }
 
-      const contextName = chatId === 'private' ? 'private messages' : `group chat (${chatId})`;
-      const successMessage = `✅ <b>Token Removed Successfully</b>
-
-🗑️ <b>${token.token_name || 'Unknown Collection'}</b> has been removed from your tracking list in ${contextName}.
-
-📮 Contract: <code>${token.contract_address}</code>
-
-You will no longer receive notifications for this NFT in this chat context.`;
+      const contextName = chatId === 'private' ? t(lang, 'remove.context_private') : t(lang, 'remove.context_group', { chatId });
+      const successMessage = t(lang, 'remove.removed_from_chat', {
+        name: token.token_name || t(lang, 'tokens.unknown_collection'),
+        context: contextName,
+        address: token.contract_address
+      });
 
       await ctx.replyWithHTML(successMessage);
       logger.info(`Token subscription removed: ${token.contract_address} by user ${user.id} in chat ${chatId}`);
 
     } catch (error) {
       logger.error('Error removing token:', error);
-      ctx.reply('❌ Error removing token. Please try again.');
+      ctx.reply(t(lang, 'remove.error'));
     }
   }
 
   async toggleTokenNotification(ctx, tokenId) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
 
 
-      await ctx.answerCbQuery('Notification setting updated!');
+      await ctx.answerCbQuery(t(lang, 'tokens.notification_updated'));
 
       return this.showMyTokens(ctx);
     } catch (error) {
       logger.error('Error toggling notification:', error);
-      await ctx.answerCbQuery('❌ Error updating notification setting');
+      await ctx.answerCbQuery(t(lang, 'tokens.notification_error'));
     }
   }
 
   async showTokenStats(ctx, tokenId) {
+    const lang = await this.getLanguage(ctx);
     try {
 
 
-      await ctx.answerCbQuery('Loading statistics...');
-      ctx.reply('📊 Token statistics feature coming soon!');
+      await ctx.answerCbQuery(t(lang, 'stats.loading'));
+      ctx.reply(t(lang, 'stats.coming_soon'));
     } catch (error) {
       logger.error('Error showing token stats:', error);
-      await ctx.answerCbQuery('❌ Error loading statistics');
+      await ctx.answerCbQuery(t(lang, 'stats.error'));
     }
   }
 
   async showTrendingCommand(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       await this.db.expireTrendingPayments();
       const trendingTokens = await this.db.getTrendingTokens();
       if (trendingTokens.length === 0) {
         const keyboard = Markup.inlineKeyboard([
-          [Markup.button.callback('🚀 Buy Trending', 'buy_trending')],
-          [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+          [Markup.button.callback(t(lang, 'trending.buy_button'), 'buy_trending')],
+          [Markup.button.callback(t(lang, 'common.back_main'), 'main_menu')]
         ]);
-        return ctx.replyWithMarkdown(
-          '📊 *No trending NFTs right now*\n\nBe the first to boost your NFT collection!',
-          keyboard
-        );
+        return ctx.replyWithMarkdown(t(lang, 'trending.empty'), keyboard);
       }
 
-      let message = '🔥 *Trending NFT Collections*\n\n';
+      let message = `${t(lang, 'trending.title')}\n\n`;
       const keyboard = [];
 
       trendingTokens.forEach((token, index) => {
         const endTime = new Date(token.trending_end_time);
         const hoursLeft = Math.max(0, Math.ceil((endTime - new Date()) / (1000 * 60 * 60)));
-        message += `${index + 1}. *${token.token_name || 'Unknown Collection'}*\n`;
+        message += `${index + 1}. *${token.token_name || t(lang, 'tokens.unknown_collection')}*\n`;
         message += `   📮 \`${token.contract_address}\`\n`;
-        message += `   ⏱️ ${hoursLeft}h left\n`;
-        message += `   💰 Paid: ${ethers.formatEther(token.payment_amount)} ETH\n\n`;
+        message += `   ${t(lang, 'trending.hours_left', { hours: hoursLeft })}\n`;
+        message += `   ${t(lang, 'trending.paid', { amount: ethers.formatEther(token.payment_amount) })}\n\n`;
         keyboard.push([
-          Markup.button.callback(`📊 ${token.token_name || 'View'} Stats`, `stats_${token.id}`)
+          Markup.button.callback(t(lang, 'trending.stats_button', { name: token.token_name || t(lang, 'trending.view') }), `stats_${token.id}`)
         ]);
       });
 
-      keyboard.push([Markup.button.callback('🚀 Boost Your Token', 'buy_trending')]);
+      keyboard.push([Markup.button.callback(t(lang, 'trending.boost_button'), 'buy_trending')]);
 
       await ctx.replyWithMarkdown(message, Markup.inlineKeyboard(keyboard));
     } catch (error) {
       logger.error('Error in showTrendingCommand:', error);
-      ctx.reply('❌ Error loading trending tokens. Please try again.');
+      ctx.reply(t(lang, 'trending.load_error'));
     }
   }
 
   // Duplicate showMainMenu removed - using the complete version at line 2047
 
   async showPaymentInstructions(ctx, tokenId, duration, isPremium = false, chain = 'ethereum') {
+    const lang = await this.getLanguage(ctx);
     try {
       const telegramId = ctx.from.id.toString();
 
@@ -2649,23 +3738,16 @@ You will no longer receive notifications for this NFT in this chat context.`;
       const trendingService = this.secureTrending || this.trending;
       const instructions = await trendingService.generatePaymentInstructions(tokenId, duration, userId, isPremium, chain);
 
-      let message = `💳 <b>Simple Payment Instructions</b>\n\n`;
-      message += `🔗 Chain: <b>${chainDisplay}</b>\n`;
-      message += `🔥 Collection: ${instructions.tokenName}\n`;
-      message += `📮 Contract: <code>${instructions.tokenAddress}</code>\n`;
-      message += `⏱️ Duration: ${duration} hours\n`;
-      message += `💰 Fee: ${instructions.feeEth} ${currencySymbol}\n\n`;
-      message += `🏦 <b>Payment Address (${truncatedAddress}):</b>\n\n`;
-      message += `<code>${paymentContract}</code>\n\n`;
-      message += `📋 Payment Instructions:\n`;
-      message += `1. Send ${instructions.feeEth} ${currencySymbol} to the payment address above\n`;
-      message += `2. Make sure you're sending from ${chainDisplay} network\n`;
-      message += `3. Tap the address above to copy it\n`;
-      message += `4. Copy your transaction hash after the transfer\n`;
-      message += `5. Submit the transaction hash using the button below\n\n`;
-      message += `✅ Simple Process: Just send a regular ${currencySymbol} transfer - no complex contract calls needed!\n`;
-      message += `⏰ Payment expires in 30 minutes\n\n`;
-      message += `After successful transaction, submit your transaction hash below:`;
+      const message = t(lang, 'trending.instructions', {
+        chain: chainDisplay,
+        name: instructions.tokenName,
+        address: instructions.tokenAddress,
+        duration,
+        fee: instructions.feeEth,
+        symbol: currencySymbol,
+        shortAddress: truncatedAddress,
+        paymentAddress: paymentContract
+      });
 
       // Get group link from session
       const session = this.getUserSession(ctx.from.id);
@@ -2683,23 +3765,24 @@ You will no longer receive notifications for this NFT in this chat context.`;
       });
 
       const keyboard = [
-        [Markup.button.callback('📝 Submit Transaction Hash', `submit_tx_${tokenId}_${duration}_${isPremium ? 'premium' : 'normal'}_${chain}`)],
-        [Markup.button.callback('◀️ Back to Duration', `trending_back_to_duration_${tokenId}_${isPremium ? 'premium' : 'normal'}_${chain}`)],
-        [Markup.button.callback('🏠 Main Menu', 'main_menu')]
+        [Markup.button.callback(t(lang, 'payment.submit_tx_button'), `submit_tx_${tokenId}_${duration}_${isPremium ? 'premium' : 'normal'}_${chain}`)],
+        [Markup.button.callback(t(lang, 'trending.back_to_duration'), `trending_back_to_duration_${tokenId}_${isPremium ? 'premium' : 'normal'}_${chain}`)],
+        [Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')]
       ];
 
       return ctx.replyWithHTML(message, Markup.inlineKeyboard(keyboard));
 
     } catch (error) {
       logger.error('Error showing payment instructions:', error);
-      return ctx.reply('❌ Error loading payment instructions. Please try again.');
+      return ctx.reply(t(lang, 'trending.instructions_error'));
     }
   }
 
   // Menu Navigation Functions
   async showMainMenu(ctx) {
-    const welcomeMessage = helpers.formatWelcomeMessage();
-    const keyboard = helpers.buildMainMenuKeyboard();
+    const lang = await this.getLanguage(ctx);
+    const welcomeMessage = helpers.formatWelcomeMessage(lang);
+    const keyboard = helpers.buildMainMenuKeyboard(lang);
 
     // Check if this is called from a start command (deep link) or callback query
     // If it's from start command, there's no message to edit, so use reply directly
@@ -2723,10 +3806,11 @@ You will no longer receive notifications for this NFT in this chat context.`;
    * Allows user to choose Private or a Group context
    */
   async showContextSelectionMenu(ctx, page = 0) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Get all available groups where bot is member (combines bot_groups + group_contexts)
@@ -2743,7 +3827,7 @@ You will no longer receive notifications for this NFT in this chat context.`;
             logger.info(`[CONTEXT_SELECTION] ${context.group_title}: isAdmin=${isAdmin}`);
             return {
               chat_id: context.group_chat_id,
-              title: context.group_title || 'Group',
+              title: context.group_title || t(lang, 'common.group'),
               isAdmin
             };
           } catch (error) {
@@ -2759,19 +3843,12 @@ You will no longer receive notifications for this NFT in this chat context.`;
 
       // If user has no admin groups, show helpful message
       if (groupsWithTitles.length === 0) {
-        const message = `📭 <b>No Groups Available</b>\n\n` +
-          `You don't have any groups in common with this bot yet, or you're not an admin in those groups.\n\n` +
-          `<b>To get started:</b>\n` +
-          `1️⃣ Add me to your desired Telegram group\n` +
-          `2️⃣ Make sure you are an admin in that group\n` +
-          `3️⃣ Run /startminty in the group to set it up\n` +
-          `4️⃣ Come back here to add NFTs\n\n` +
-          `💡 Use the button below to start adding me to a group!`;
+        const message = t(lang, 'context.no_groups');
 
         const botUsername = ctx.botInfo?.username || (await ctx.telegram.getMe()).username;
         const keyboard = Markup.inlineKeyboard([
-          [Markup.button.url('➕ Add Bot to Group', `https://t.me/${botUsername}?startgroup=true`)],
-          [Markup.button.callback('◀️ Back', 'menu_tokens')]
+          [Markup.button.url(t(lang, 'context.add_to_group'), `https://t.me/${botUsername}?startgroup=true`)],
+          [Markup.button.callback(t(lang, 'common.back'), 'menu_tokens')]
         ]);
 
         return this.sendOrEditMenu(ctx, message, keyboard);
@@ -2813,27 +3890,27 @@ You will no longer receive notifications for this NFT in this chat context.`;
         const paginationRow = [];
 
         if (page > 0) {
-          paginationRow.push(Markup.button.callback('◀️ Prev', `context_page_${page - 1}`));
+          paginationRow.push(Markup.button.callback(t(lang, 'common.previous'), `context_page_${page - 1}`));
         }
 
-        paginationRow.push(Markup.button.callback(`Page ${page + 1}/${totalPages}`, 'noop'));
+        paginationRow.push(Markup.button.callback(t(lang, 'common.page', { page: page + 1, total: totalPages }), 'noop'));
 
         if (page < totalPages - 1) {
-          paginationRow.push(Markup.button.callback('Next ▶️', `context_page_${page + 1}`));
+          paginationRow.push(Markup.button.callback(t(lang, 'common.next'), `context_page_${page + 1}`));
         }
 
         keyboard.push(paginationRow);
       }
 
       // Cancel button
-      keyboard.push([Markup.button.callback('◀️ Back', 'menu_tokens')]);
+      keyboard.push([Markup.button.callback(t(lang, 'common.back'), 'menu_tokens')]);
 
-      const message = `🎯 <b>Where do you want to add this NFT?</b>\n\nChoose context:`;
+      const message = t(lang, 'context.select');
 
       return this.sendOrEditMenu(ctx, message, Markup.inlineKeyboard(keyboard));
     } catch (error) {
       logger.error('Error showing context selection menu:', error);
-      ctx.reply('❌ Error loading contexts. Please try again.');
+      ctx.reply(t(lang, 'context.load_error'));
     }
   }
 
@@ -2844,29 +3921,24 @@ You will no longer receive notifications for this NFT in this chat context.`;
       isAdmin = await this.isUserGroupAdmin(ctx.from.id, ctx.chat.id, ctx);
     }
 
+    const lang = await this.getLanguage(ctx);
     let message;
     let keyboard;
 
     if (isAdmin) {
       // Admins see full management options
-      message = `📊 <b>NFT Management</b>
-
-<b>Manage your NFT collections:</b>`;
+      message = t(lang, 'tokens_menu.admin');
       keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('➕ Add NFT Collection', 'add_token_start'), Markup.button.callback('👁️ View My NFTs', 'my_tokens')],
-        [Markup.button.callback('🗑️ Remove NFT Collection', 'remove_token')],
-        [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+        [Markup.button.callback(t(lang, 'tokens_menu.add'), 'add_token_start'), Markup.button.callback(t(lang, 'tokens_menu.view_mine'), 'my_tokens')],
+        [Markup.button.callback(t(lang, 'tokens_menu.remove'), 'remove_token')],
+        helpers.buildBackToMainButton(lang)
       ]);
     } else {
       // Non-admins only see view option
-      message = `📊 <b>NFT Collections</b>
-
-<b>View tracked NFT collections in this group:</b>
-
-ℹ️ <i>Only admins can add or remove collections</i>`;
+      message = t(lang, 'tokens_menu.viewer');
       keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('👁️ View Tracked NFTs', 'my_tokens')],
-        [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+        [Markup.button.callback(t(lang, 'tokens_menu.view_tracked'), 'my_tokens')],
+        helpers.buildBackToMainButton(lang)
       ]);
     }
 
@@ -2874,22 +3946,22 @@ You will no longer receive notifications for this NFT in this chat context.`;
   }
 
   async showImagesMenu(ctx) {
-    const message = `🖼️ <b>NFT Image Display</b>
-
-<b>Enable real NFT images instead of Candy Codex image:</b>`;
+    const lang = await this.getLanguage(ctx);
+    const message = t(lang, 'images_menu.message');
     const keyboard = Markup.inlineKeyboard([
-      [Markup.button.callback('💳 Display NFT Image', 'buy_image_menu')],
-      [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+      [Markup.button.callback(t(lang, 'images_menu.pay'), 'buy_image_menu')],
+      helpers.buildBackToMainButton(lang)
     ]);
 
     return this.sendOrEditMenu(ctx, message, keyboard);
   }
 
   async showImageTokenSelection(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // In private chat, fetch ALL tokens across all contexts (same as View My NFTs)
@@ -2918,20 +3990,16 @@ You will no longer receive notifications for this NFT in this chat context.`;
         let message, keyboard;
         if (isGroup && !isAdmin) {
           // Non-admin in group with no tracked NFTs
-          message = `🖼️ <b>Select NFT for Image Display</b>\n\n` +
-            `📝 No NFTs are being tracked in this group yet!\n\n` +
-            `ℹ️ Only admins can add NFT collections to track.`;
+          message = `${t(lang, 'images.select_title')}\n\n${t(lang, 'images.none_viewer')}`;
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('◀️ Back to Images Menu', 'menu_images')]
+            [Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images')]
           ]);
         } else {
           // Admin or private chat
-          message = `🖼️ <b>Select NFT for Image Display</b>\n\n` +
-            `📝 You need to add some NFT collections first!\n\n` +
-            `Add your NFTs to track them and enable image display.`;
+          message = `${t(lang, 'images.select_title')}\n\n${t(lang, 'images.none_admin')}`;
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('➕ Add Your First NFT', 'add_token_start')],
-            [Markup.button.callback('◀️ Back to Images Menu', 'menu_images')]
+            [Markup.button.callback(t(lang, 'tokens_menu.add_first'), 'add_token_start')],
+            [Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images')]
           ]);
         }
 
@@ -2954,8 +4022,7 @@ You will no longer receive notifications for this NFT in this chat context.`;
         }
       }
 
-      const message = `🖼️ <b>Select NFT for Image Display</b>\n\n` +
-        `Choose which NFT collection to enable real image display for:`;
+      const message = `${t(lang, 'images.select_title')}\n\n${t(lang, 'images.select_prompt')}`;
 
       const keyboard = [];
 
@@ -2969,7 +4036,7 @@ You will no longer receive notifications for this NFT in this chat context.`;
           const chainConfig = this.chainManager ? this.chainManager.getChain(chainName) : null;
           const chainEmoji = chainConfig ? chainConfig.emoji : '🔷';
 
-          const tokenDisplay = `${chainEmoji} ${token.token_name || 'Unknown'}`;
+          const tokenDisplay = `${chainEmoji} ${token.token_name || t(lang, 'common.unknown')}`;
           const truncatedDisplay = tokenDisplay.length > 25 ? tokenDisplay.substring(0, 22) + '...' : tokenDisplay;
 
           row.push(Markup.button.callback(truncatedDisplay, `image_select_token_${token.id}`));
@@ -2980,8 +4047,8 @@ You will no longer receive notifications for this NFT in this chat context.`;
 
       // Navigation buttons
       keyboard.push([
-        Markup.button.callback('◀️ Back to Images Menu', 'menu_images'),
-        Markup.button.callback('🏠 Main Menu', 'main_menu')
+        Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images'),
+        Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
       ]);
 
       if (isCallback) {
@@ -3003,15 +4070,16 @@ You will no longer receive notifications for this NFT in this chat context.`;
       }
     } catch (error) {
       logger.error('Error showing image token selection:', error);
-      ctx.reply('❌ Error loading your NFTs. Please try again.');
+      ctx.reply(t(lang, 'tokens.load_error'));
     }
   }
 
   async handleImageTokenSelection(ctx, tokenId) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Get token details from database
@@ -3031,7 +4099,7 @@ You will no longer receive notifications for this NFT in this chat context.`;
       const selectedToken = tokens.find(token => token.id.toString() === tokenId.toString());
 
       if (!selectedToken) {
-        return ctx.reply('❌ Token not found. Please try again.');
+        return ctx.reply(t(lang, 'token.not_found_retry'));
       }
 
       // Store token selection in user session
@@ -3048,11 +4116,12 @@ You will no longer receive notifications for this NFT in this chat context.`;
       return this.showImageDurationSelectionWithToken(ctx, selectedToken);
     } catch (error) {
       logger.error('Error handling image token selection:', error);
-      ctx.reply('❌ Error processing token selection. Please try again.');
+      ctx.reply(t(lang, 'token.selection_error'));
     }
   }
 
   async showImageDurationSelectionWithToken(ctx, selectedToken) {
+    const lang = await this.getLanguage(ctx);
     try {
       const chainName = selectedToken.chain_name || 'ethereum';
       const chainConfig = this.chainManager ? this.chainManager.getChain(chainName) : null;
@@ -3070,32 +4139,30 @@ You will no longer receive notifications for this NFT in this chat context.`;
         prices[duration] = this.secureTrending.formatChainAmount(fee, chainNormalized);
       });
 
-      const message = `🎨 <b>Image Fee - Select Duration</b>\n\n` +
-        `🎯 <b>Selected NFT:</b> ${selectedToken.token_name || 'Unknown'} (${selectedToken.token_symbol || 'N/A'})\n` +
-        `🔗 <b>Blockchain:</b> ${chainEmoji} ${chainDisplay}\n\n` +
-        `Choose how long you want NFT images displayed instead of the Candy Codex image:\n\n` +
-        `🔹 <b>30 days</b> - ${prices[30]} ${symbol}\n` +
-        `🔹 <b>60 days</b> - ${prices[60]} ${symbol}\n` +
-        `🔹 <b>90 days</b> - ${prices[90]} ${symbol}\n` +
-        `🔹 <b>180 days</b> - ${prices[180]} ${symbol}\n` +
-        `🔹 <b>365 days</b> - ${prices[365]} ${symbol}\n\n` +
-        `✨ Longer durations offer better value per day!`;
+      const durationLabel = days => t(lang, 'duration.days_price', { days, price: `${prices[days]} ${symbol}` });
+      const message = t(lang, 'images.duration_title', {
+        name: selectedToken.token_name || t(lang, 'common.unknown'),
+        symbol: selectedToken.token_symbol || 'N/A',
+        chain: `${chainEmoji} ${chainDisplay}`
+      }) + '\n\n' +
+        durations.map(days => `🔹 ${durationLabel(days)}`).join('\n') + '\n\n' +
+        t(lang, 'duration.value_hint');
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback(`30 days - ${prices[30]} ${symbol}`, 'token_image_duration_30'),
-          Markup.button.callback(`60 days - ${prices[60]} ${symbol}`, 'token_image_duration_60')
+          Markup.button.callback(durationLabel(30), 'token_image_duration_30'),
+          Markup.button.callback(durationLabel(60), 'token_image_duration_60')
         ],
         [
-          Markup.button.callback(`90 days - ${prices[90]} ${symbol}`, 'token_image_duration_90'),
-          Markup.button.callback(`180 days - ${prices[180]} ${symbol}`, 'token_image_duration_180')
+          Markup.button.callback(durationLabel(90), 'token_image_duration_90'),
+          Markup.button.callback(durationLabel(180), 'token_image_duration_180')
         ],
         [
-          Markup.button.callback(`365 days - ${prices[365]} ${symbol}`, 'token_image_duration_365')
+          Markup.button.callback(durationLabel(365), 'token_image_duration_365')
         ],
         [
-          Markup.button.callback('◀️ Back to Token Selection', 'buy_image_menu'),
-          Markup.button.callback('🏠 Main Menu', 'main_menu')
+          Markup.button.callback(t(lang, 'common.back_to_tokens'), 'buy_image_menu'),
+          Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
         ]
       ]);
 
@@ -3105,15 +4172,16 @@ You will no longer receive notifications for this NFT in this chat context.`;
       await ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error showing image duration selection with token:', error);
-      ctx.reply('❌ Error showing duration options. Please try again.');
+      ctx.reply(t(lang, 'duration.error'));
     }
   }
 
   async showTokenImagePaymentInstructions(ctx, session) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       const { contractAddress, tokenName, tokenSymbol, chain, duration } = session;
@@ -3121,10 +4189,10 @@ You will no longer receive notifications for this NFT in this chat context.`;
       // Check if image fee is already active
       const isActive = await this.secureTrending.isImageFeeActive(contractAddress);
       if (isActive) {
-        return ctx.reply('✅ Image fee is already active for this contract. Actual NFT images are being displayed.', {
+        return ctx.reply(t(lang, 'images.already_active'), {
           reply_markup: Markup.inlineKeyboard([
-            [Markup.button.callback('◀️ Back to Token Selection', 'buy_image_menu')],
-            [Markup.button.callback('🏠 Main Menu', 'main_menu')]
+            [Markup.button.callback(t(lang, 'common.back_to_tokens'), 'buy_image_menu')],
+            [Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')]
           ])
         });
       }
@@ -3133,47 +4201,47 @@ You will no longer receive notifications for this NFT in this chat context.`;
       const chainEmoji = chainConfig ? chainConfig.emoji : '🔷';
       const chainDisplay = chainConfig ? chainConfig.displayName : chain.charAt(0).toUpperCase() + chain.slice(1);
 
-      const durationText = `${duration} days`;
+      const durationText = t(lang, 'duration.days', { days: duration });
 
       const instructions = await this.secureTrending.generateImagePaymentInstructions(contractAddress, user.id, duration, chain);
       const truncatedAddress = this.truncateAddress(instructions.contractAddress);
 
-      const message = `💰 <b>Image Fee Payment Instructions</b>\n\n` +
-        `🎯 <b>Selected NFT:</b> ${tokenName || 'Unknown'} (${tokenSymbol || 'N/A'})\n` +
-        `🔗 <b>Blockchain:</b> ${chainEmoji} ${chainDisplay}\n` +
-        `📮 <b>Contract:</b> <code>${contractAddress}</code>\n` +
-        `📅 <b>Duration:</b> ${durationText}\n` +
-        `💸 <b>Fee:</b> ${instructions.feeEth} ${instructions.symbol}\n\n` +
-        `🏦 <b>Payment Contract (${truncatedAddress}):</b>\n\n` +
-        `<code>${instructions.contractAddress}</code>\n\n` +
-        `📋 <b>Payment Steps:</b>\n` +
-        instructions.instructions.join('\n') + '\n\n' +
-        `After making the payment, click the button below to submit your transaction hash.`;
+      const message = t(lang, 'images.instructions', {
+        name: tokenName || t(lang, 'common.unknown'),
+        symbol: tokenSymbol || 'N/A',
+        chain: `${chainEmoji} ${chainDisplay}`,
+        address: contractAddress,
+        duration: durationText,
+        fee: instructions.feeEth,
+        feeSymbol: instructions.symbol,
+        shortAddress: truncatedAddress,
+        paymentAddress: instructions.contractAddress,
+        steps: instructions.instructions.join('\n')
+      });
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback('📝 Submit Transaction Hash', 'submit_token_image_tx')
+          Markup.button.callback(t(lang, 'payment.submit_tx_button'), 'submit_token_image_tx')
         ],
         [
-          Markup.button.callback('◀️ Back to Duration Selection', 'buy_image_menu'),
-          Markup.button.callback('🏠 Main Menu', 'main_menu')
+          Markup.button.callback(t(lang, 'duration.back'), 'buy_image_menu'),
+          Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
         ]
       ]);
 
       await ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error showing token image payment instructions:', error);
-      ctx.reply('❌ Error generating payment instructions. Please try again.');
+      ctx.reply(t(lang, 'payment.instructions_error'));
     }
   }
 
   async showFooterMenu(ctx) {
-    const message = `🔗 <b>Footer Advertisement</b>
-
-<b>Advertise your Project in notification footers:</b>`;
+    const lang = await this.getLanguage(ctx);
+    const message = t(lang, 'footer_menu.message');
     const keyboard = Markup.inlineKeyboard([
-      [Markup.button.callback('💳 Pay for Footer Ads', 'buy_footer_menu')],
-      [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+      [Markup.button.callback(t(lang, 'footer_menu.pay'), 'buy_footer_menu')],
+      helpers.buildBackToMainButton(lang)
     ]);
 
     // Check if this is called from a start command (deep link) or callback query
@@ -3185,26 +4253,20 @@ You will no longer receive notifications for this NFT in this chat context.`;
   }
 
   async showChannelsMenu(ctx) {
+    const lang = await this.getLanguage(ctx);
     const user = await this.db.getUser(ctx.from.id.toString());
     if (!user) {
-      return ctx.reply('Please start the bot first with /startminty');
+      return ctx.reply(t(lang, 'common.start_first'));
     }
 
     // Get channels added by this user
     const channels = await this.db.getChannelsByUser(user.id);
 
     if (channels.length === 0) {
-      const message = `📺 <b>Channel Management</b>
-
-You haven't added any channels yet.
-
-<b>To get started:</b>
-1️⃣ Add me to your channel as an admin
-2️⃣ Run /add_channel in the channel
-3️⃣ Come back here to configure settings`;
+      const message = t(lang, 'channels_menu.empty');
 
       const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+        helpers.buildBackToMainButton(lang)
       ]);
 
       return this.sendOrEditMenu(ctx, message, keyboard);
@@ -3221,6 +4283,7 @@ You haven't added any channels yet.
     const endIdx = startIdx + channelsPerPage;
     const pageChannels = channels.slice(startIdx, endIdx);
 
+    const lang = await this.getLanguage(ctx);
     const keyboard = [];
 
     // Add channel buttons (2 per row)
@@ -3249,24 +4312,22 @@ You haven't added any channels yet.
       const paginationRow = [];
 
       if (page > 0) {
-        paginationRow.push(Markup.button.callback('◀️ Prev', `channel_page_${page - 1}`));
+        paginationRow.push(Markup.button.callback(t(lang, 'common.previous'), `channel_page_${page - 1}`));
       }
 
-      paginationRow.push(Markup.button.callback(`Page ${page + 1}/${totalPages}`, 'noop'));
+      paginationRow.push(Markup.button.callback(`📄 ${page + 1}/${totalPages}`, 'noop'));
 
       if (page < totalPages - 1) {
-        paginationRow.push(Markup.button.callback('Next ▶️', `channel_page_${page + 1}`));
+        paginationRow.push(Markup.button.callback(t(lang, 'common.next'), `channel_page_${page + 1}`));
       }
 
       keyboard.push(paginationRow);
     }
 
     // Back button
-    keyboard.push([Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]);
+    keyboard.push(helpers.buildBackToMainButton(lang));
 
-    const message = `📺 <b>My Channels</b>
-
-Select a channel to configure:`;
+    const message = t(lang, 'channels_menu.list');
 
     return this.sendOrEditMenu(ctx, message, Markup.inlineKeyboard(keyboard));
   }
@@ -3279,65 +4340,73 @@ Select a channel to configure:`;
     }
 
     const { show_all_activities, trending_tier, channel_title } = settings.settings;
+    const lang = await this.getLanguage(ctx);
 
     // Determine current mode
-    let currentMode, modeIcon, modeDescription;
+    let currentMode, modeIcon;
     if (show_all_activities) {
       currentMode = 'all';
       modeIcon = '📊';
-      modeDescription = '<b>All Activity</b>\nShows real-time alerts for ALL tracked NFTs from any group';
     } else if (trending_tier === 'none') {
       currentMode = 'none';
       modeIcon = '🔕';
-      modeDescription = '<b>No Alerts</b>\nChannel alerts are disabled';
-    } else if (trending_tier === 'normal') {
-      currentMode = 'normal';
-      modeIcon = '🔵';
-      modeDescription = '<b>Standard Trending</b>\nShows scheduled broadcasts for standard tier promotions';
     } else if (trending_tier === 'premium') {
       currentMode = 'premium';
       modeIcon = '⭐';
-      modeDescription = '<b>Premium Trending</b>\nShows scheduled broadcasts for premium tier promotions';
     } else {
-      // This shouldn't happen with migration, but fallback to normal
+      // 'normal', and the fallback for rows from before the tier migration
       currentMode = 'normal';
       modeIcon = '🔵';
-      modeDescription = '<b>Standard Trending</b>\nShows scheduled broadcasts for standard tier promotions';
     }
+    const modeDescription = t(lang, `channel.mode.${currentMode}`);
+
+    const channelSettings = this.chatSettings ? await this.chatSettings.get(channelChatId) : null;
+    const sweepMode = channelSettings?.sweep_mode || 'aggregated';
+    const channelLanguage = SUPPORTED_LANGUAGES[channelSettings?.language || DEFAULT_LANGUAGE];
+    const digestSettings = this.chatSettings
+      ? `\n${await this.describeDigestSettings(channelChatId, lang)}\n${t(lang, 'channel.digest_hint')}\n`
+      : '';
+
+    const message = t(lang, 'channel.settings', {
+      title: channel_title,
+      mode: `${modeIcon} ${modeDescription}`,
+      sweeps: t(lang, `channel.sweeps.${sweepMode}`),
+      language: channelLanguage,
+      digest: digestSettings
+    });
 
-    const message = `⚙️ <b>${channel_title}</b>
-
-<b>Current Alert Mode:</b>
-${modeIcon} ${modeDescription}
-
-Tap the button below to cycle through alert modes:`;
-
-    const keyboard = Markup.inlineKeyboard([
-      [Markup.button.callback(`${modeIcon} Change Alert Mode`, `channel_cycle_mode_${channelChatId}`)],
-      [Markup.button.callback('➕ Add NFTs', `channel_add_nft_${channelChatId}`)],
-      [Markup.button.callback('🔄 Refresh', `channel_select_${channelChatId}`)],
-      [Markup.button.callback('◀️ Back to Channels', 'menu_channels')]
-    ]);
+    const rows = [
+      [Markup.button.callback(t(lang, 'channel.change_mode', { icon: modeIcon }), `channel_cycle_mode_${channelChatId}`)],
+      [Markup.button.callback(t(lang, `channel.sweeps_button.${sweepMode}`), `channel_sweep_mode_${channelChatId}`)]
+    ];
+    if (this.chatSettings) {
+      rows.push([Markup.button.callback(t(lang, 'channel.language_button', { language: channelLanguage }), `channel_language_${channelChatId}`)]);
+    }
+    rows.push(
+      [Markup.button.callback(t(lang, 'channel.add_nfts'), `channel_add_nft_${channelChatId}`)],
+      [Markup.button.callback(t(lang, 'channel.refresh'), `channel_select_${channelChatId}`)],
+      [Markup.button.callback(t(lang, 'channel.back'), 'menu_channels')]
+    );
+    const keyboard = Markup.inlineKeyboard(rows);
 
     return this.sendOrEditMenu(ctx, message, keyboard);
   }
 
   async showVerifyMenu(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
-      const message = `✅ <b>Verify Your Payments</b>\n\n` +
-        `Select the type of payment you want to verify:\n\n`
-        
+      const message = t(lang, 'verify_menu.message');
 
       const keyboard = [
-        [Markup.button.callback('🔍 Verify Trending', 'verify_trending')],
-        [Markup.button.callback('🖼️ Verify Image', 'verify_image')],
-        [Markup.button.callback('🔗 Verify Footer', 'verify_footer')],
-        [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+        [Markup.button.callback(t(lang, 'verify_menu.trending'), 'verify_trending')],
+        [Markup.button.callback(t(lang, 'verify_menu.image'), 'verify_image')],
+        [Markup.button.callback(t(lang, 'verify_menu.footer'), 'verify_footer')],
+        helpers.buildBackToMainButton(lang)
       ];
 
       try {
@@ -3350,15 +4419,16 @@ Tap the button below to cycle through alert modes:`;
       }
     } catch (error) {
       logger.error('Error in showVerifyMenu:', error);
-      ctx.reply('❌ Error loading verification options. Please try again.');
+      ctx.reply(t(lang, 'verify_menu.error'));
     }
   }
 
   async showVerifyTokenSelection(ctx, verificationType) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       const chatId = this.normalizeChatContext(ctx);
@@ -3380,25 +4450,21 @@ Tap the button below to cycle through alert modes:`;
       if (tokens.length === 0) {
         // Check if in group and if user is admin
         const isGroup = ctx.chat.type === 'group' || ctx.chat.type === 'supergroup';
-        const verifyTypeText = verificationType === 'image' ? 'Image Fee' : 'Trending';
+        const verifyTypeText = t(lang, `verify.type.${verificationType === 'image' ? 'image' : 'trending'}`);
 
         let message, keyboard;
         if (isGroup && !isAdmin) {
           // Non-admin in group with no tracked NFTs
-          message = `🔍 <b>Verify ${verifyTypeText} Payment</b>\n\n` +
-            `📝 No NFTs are being tracked in this group yet!\n\n` +
-            `ℹ️ Only admins can add NFT collections to track.`;
+          message = `${t(lang, 'verify.title', { emoji: '🔍', type: verifyTypeText })}\n\n${t(lang, 'images.none_viewer')}`;
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]
+            [Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]
           ]);
         } else {
           // Admin or private chat
-          message = `🔍 <b>Verify ${verifyTypeText} Payment</b>\n\n` +
-            `📝 You need to add some NFT collections first!\n\n` +
-            `Add your NFTs to track them before verifying payments.`;
+          message = `${t(lang, 'verify.title', { emoji: '🔍', type: verifyTypeText })}\n\n${t(lang, 'verify.none_admin')}`;
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('➕ Add Your First NFT', 'add_token_start')],
-            [Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]
+            [Markup.button.callback(t(lang, 'tokens_menu.add_first'), 'add_token_start')],
+            [Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]
           ]);
         }
 
@@ -3412,10 +4478,9 @@ Tap the button below to cycle through alert modes:`;
         }
       }
 
-      const verifyTypeText = verificationType === 'image' ? 'Image Fee' : 'Trending';
+      const verifyTypeText = t(lang, `verify.type.${verificationType === 'image' ? 'image' : 'trending'}`);
       const verifyEmoji = verificationType === 'image' ? '🖼️' : '🔍';
-      const message = `${verifyEmoji} <b>Verify ${verifyTypeText} Payment</b>\n\n` +
-        `Select the NFT collection you made the payment for:`;
+      const message = `${t(lang, 'verify.title', { emoji: verifyEmoji, type: verifyTypeText })}\n\n${t(lang, 'verify.select_prompt')}`;
 
       const keyboard = [];
 
@@ -3429,7 +4494,7 @@ Tap the button below to cycle through alert modes:`;
           const chainConfig = this.chainManager ? this.chainManager.getChain(chainName) : null;
           const chainEmoji = chainConfig ? chainConfig.emoji : '🔷';
 
-          const tokenDisplay = `${chainEmoji} ${token.token_name || 'Unknown'}`;
+          const tokenDisplay = `${chainEmoji} ${token.token_name || t(lang, 'common.unknown')}`;
           const truncatedDisplay = tokenDisplay.length > 25 ? tokenDisplay.substring(0, 22) + '...' : tokenDisplay;
 
           row.push(Markup.button.callback(truncatedDisplay, `verify_${verificationType}_token_${token.id}`));
@@ -3440,8 +4505,8 @@ Tap the button below to cycle through alert modes:`;
 
       // Navigation buttons
       keyboard.push([
-        Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify'),
-        Markup.button.callback('🏠 Main Menu', 'main_menu')
+        Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify'),
+        Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
       ]);
 
       if (isCallback) {
@@ -3454,15 +4519,16 @@ Tap the button below to cycle through alert modes:`;
       }
     } catch (error) {
       logger.error(`Error showing verify token selection for ${verificationType}:`, error);
-      ctx.reply('❌ Error loading your NFTs. Please try again.');
+      ctx.reply(t(lang, 'tokens.load_error'));
     }
   }
 
   async handleVerifyTokenSelection(ctx, tokenId, verificationType) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Get token details from database
@@ -3482,7 +4548,7 @@ Tap the button below to cycle through alert modes:`;
       const selectedToken = tokens.find(token => token.id.toString() === tokenId.toString());
 
       if (!selectedToken) {
-        return ctx.reply('❌ Token not found. Please try again.');
+        return ctx.reply(t(lang, 'token.not_found_retry'));
       }
 
       // Store token selection for verification
@@ -3500,29 +4566,33 @@ Tap the button below to cycle through alert modes:`;
       const chainEmoji = chainConfig ? chainConfig.emoji : '🔷';
       const chainDisplay = chainConfig ? chainConfig.displayName : chainName.charAt(0).toUpperCase() + chainName.slice(1);
 
-      const verifyTypeText = verificationType === 'image' ? 'Image Fee' : 'Trending';
+      const verifyType = verificationType === 'image' ? 'image' : 'trending';
       const verifyEmoji = verificationType === 'image' ? '🖼️' : '🔍';
 
-      const message = `📝 <b>Submit Transaction Hash</b>\n\n` +
-        `${verifyEmoji} <b>Payment Type:</b> ${verifyTypeText}\n` +
-        `🎯 <b>NFT:</b> ${selectedToken.token_name || 'Unknown'} (${selectedToken.token_symbol || 'N/A'})\n` +
-        `${chainEmoji} <b>Blockchain:</b> ${chainDisplay}\n\n` +
-        `Please send me your transaction hash for the ${verifyTypeText.toLowerCase()} payment.\n\n` +
-        `<i>Example: 0xabc123456789def...</i>`;
+      const message = t(lang, 'verify.submit_tx', {
+        emoji: verifyEmoji,
+        type: t(lang, `verify.type.${verifyType}`),
+        name: selectedToken.token_name || t(lang, 'common.unknown'),
+        symbol: selectedToken.token_symbol || 'N/A',
+        chainEmoji,
+        chain: chainDisplay,
+        payment: t(lang, `verify.payment_name.${verifyType}`)
+      });
 
-      const keyboard = Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]);
+      const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]);
       return ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error(`Error handling verify token selection for ${verificationType}:`, error);
-      ctx.reply('❌ Error processing token selection. Please try again.');
+      ctx.reply(t(lang, 'token.selection_error'));
     }
   }
 
   async handleFooterTickerSelection(ctx, footerAdId) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Get footer ad details from database
@@ -3530,7 +4600,7 @@ Tap the button below to cycle through alert modes:`;
       const selectedFooterAd = footerAds.find(ad => ad.id.toString() === footerAdId.toString());
 
       if (!selectedFooterAd) {
-        return ctx.reply('❌ Footer advertisement not found. Please try again.');
+        return ctx.reply(t(lang, 'verify.footer_not_found'));
       }
 
       // Store footer ad selection for verification
@@ -3544,29 +4614,25 @@ Tap the button below to cycle through alert modes:`;
       // Move to transaction hash input
       this.setFlowState(ctx.from.id, this.STATE_EXPECTING_VALIDATION_TX_HASH);
 
-      const message = `📝 <b>Submit Transaction Hash</b>\n\n` +
-        `🔗 <b>Payment Type:</b> Footer Advertisement\n` +
-        `💲 <b>Ticker:</b> ${ticker}\n` +
-        `📎 <b>Link:</b> ${selectedFooterAd.custom_link || 'N/A'}\n\n` +
-        `Please send me your transaction hash for the footer advertisement payment.\n\n` +
-        `<i>Example: 0xabc123456789def...</i>`;
+      const message = t(lang, 'verify.submit_footer_tx', {
+        ticker,
+        link: selectedFooterAd.custom_link || 'N/A'
+      });
 
-      const keyboard = Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]);
+      const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]);
       return ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error handling footer ticker selection:', error);
-      ctx.reply('❌ Error processing ticker selection. Please try again.');
+      ctx.reply(t(lang, 'verify.ticker_error'));
     }
   }
 
   async showVerifyFooterTickerInput(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
-      const message = `🔗 <b>Verify Footer Payment</b>\n\n` +
-        `Please enter the ticker symbol you used when creating your footer advertisement.\n\n` +
-        `💡 <b>Include the $ prefix if you used one</b>\n\n` +
-        `<i>Examples: $PEPE, $BAYC, $AZUKI</i>\n<i>Or without $: PEPE, BAYC, AZUKI</i>`;
+      const message = t(lang, 'verify.footer_ticker_prompt');
 
-      const keyboard = Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]);
+      const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]);
 
       this.setFlowState(ctx.from.id, this.STATE_EXPECTING_VALIDATION_TICKER);
       this.userStates.set(ctx.from.id.toString() + '_validation_type', 'footer');
@@ -3574,15 +4640,16 @@ Tap the button below to cycle through alert modes:`;
       return this.sendOrEditMenu(ctx, message, keyboard);
     } catch (error) {
       logger.error('Error showing footer ticker input:', error);
-      ctx.reply('❌ Error showing ticker input. Please try again.');
+      ctx.reply(t(lang, 'verify.ticker_input_error'));
     }
   }
 
   async showFooterTickerSelection(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Get user's purchased footer ads
@@ -3592,13 +4659,11 @@ Tap the button below to cycle through alert modes:`;
       const isCallback = ctx.callbackQuery && ctx.callbackQuery.message;
 
       if (footerAds.length === 0) {
-        const message = `🔗 <b>Verify Footer Payments</b>\n\n` +
-          `❌ <b>No footer advertisements found!</b>\n\n` +
-          `You haven't purchased any footer ads yet. Purchase a footer advertisement first to verify payments.`;
+        const message = `${t(lang, 'verify.footer_title')}\n\n${t(lang, 'verify.no_footer_ads')}`;
 
         const keyboard = [
-          [Markup.button.callback('💰 Buy Footer Ad', 'buy_footer_menu')],
-          [Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]
+          [Markup.button.callback(t(lang, 'verify.buy_footer'), 'buy_footer_menu')],
+          [Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]
         ];
 
         if (isCallback) {
@@ -3611,9 +4676,7 @@ Tap the button below to cycle through alert modes:`;
         }
       }
 
-      const message = `🔗 <b>Verify Footer Payments</b>\n\n` +
-        `Select which footer advertisement to verify:\n\n` +
-        `💡 Choose from your purchased tickers:`;
+      const message = `${t(lang, 'verify.footer_title')}\n\n${t(lang, 'verify.select_footer')}`;
 
       const keyboard = [];
 
@@ -3625,7 +4688,7 @@ Tap the button below to cycle through alert modes:`;
       }
 
       // Add navigation buttons
-      keyboard.push([Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]);
+      keyboard.push([Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]);
 
       if (isCallback) {
         return ctx.editMessageText(message, {
@@ -3637,7 +4700,7 @@ Tap the button below to cycle through alert modes:`;
       }
     } catch (error) {
       logger.error('Error showing footer ticker selection:', error);
-      ctx.reply('❌ Error loading footer advertisements. Please try again.');
+      ctx.reply(t(lang, 'verify.footer_load_error'));
     }
   }
 
@@ -3681,15 +4744,15 @@ Tap the button below to cycle through alert modes:`;
    * @param {string} contextLabel - Context name
    * @param {Object} chainTokens - Tokens grouped by chain
    * @param {Array} keyboard - Keyboard array to append buttons to
+   * @param {string} lang - Language of the viewer
    * @returns {string} Formatted message section
    */
-  renderContextSection(contextLabel, chainTokens, keyboard) {
+  renderContextSection(contextLabel, chainTokens, keyboard, lang) {
     let section = '';
     const totalTokens = Object.values(chainTokens).reduce((sum, tokens) => sum + tokens.length, 0);
 
-    // Context header with emoji (groups/channels only)
-    const contextEmoji = '👥';
-    section += `${contextEmoji} <b>${contextLabel}</b> (${totalTokens} token${totalTokens !== 1 ? 's' : ''})\n`;
+    // Context header (groups/channels only)
+    section += `${t(lang, 'tokens.context_header', { label: contextLabel, count: totalTokens })}\n`;
 
     // Group by chain within this context
     for (const [chainName, tokens] of Object.entries(chainTokens)) {
@@ -3700,11 +4763,20 @@ Tap the button below to cycle through alert modes:`;
       section += `  🔗 <b>${chainDisplay}</b> (${tokens.length})\n`;
 
       tokens.forEach((token, index) => {
-        section += `     ${index + 1}. <b>${token.token_name || 'Unknown'}</b> (${token.token_symbol || 'N/A'})\n`;
+        section += `     ${index + 1}. <b>${token.token_name || t(lang, 'common.unknown')}</b> (${token.token_symbol || 'N/A'})\n`;
         section += `        📮 <code>${token.contract_address}</code>\n`;
 
+        if (token.subscription_id) {
+          keyboard.push([
+            Markup.button.callback(t(lang, 'tokens.alerts_button', { name: `${token.token_name || token.contract_address.slice(0, 8)} (${contextLabel})` }), `alerts_${token.subscription_id}`)
+          ]);
+        }
+
         // Add context-aware remove button
-        const buttonText = `🗑️ Remove ${token.token_name || token.contract_address.slice(0, 8)}... from ${contextLabel}`;
+        const buttonText = t(lang, 'tokens.remove_from_button', {
+          name: token.token_name || token.contract_address.slice(0, 8),
+          context: contextLabel
+        });
         keyboard.push([
           Markup.button.callback(
             buttonText,
@@ -3724,10 +4796,11 @@ Tap the button below to cycle through alert modes:`;
    * Displays Private tokens + tokens from all groups user is tracking in
    */
   async showMyTokensAllContexts(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Fetch ALL tokens across all contexts
@@ -3736,10 +4809,10 @@ Tap the button below to cycle through alert modes:`;
 
       if (allTokens.length === 0) {
         const keyboard = Markup.inlineKeyboard([
-          [Markup.button.callback('➕ Add Your First NFT', 'add_token_start')],
-          [Markup.button.callback('◀️ Back to NFTs Menu', 'menu_tokens')]
+          [Markup.button.callback(t(lang, 'tokens_menu.add_first'), 'add_token_start')],
+          [Markup.button.callback(t(lang, 'tokens_menu.back'), 'menu_tokens')]
         ]);
-        const message = '🔍 You haven\'t added any tokens yet!\n\nUse the button below to start tracking NFT collections.';
+        const message = t(lang, 'tokens.none_button');
 
         if (ctx.callbackQuery) {
           try {
@@ -3764,19 +4837,19 @@ Tap the button below to cycle through alert modes:`;
       // Build message
       const totalTokens = allTokens.length;
       const contextCount = Object.keys(byContext).length;
-      let message = `🎯 <b>Your Tracked NFTs</b> (${totalTokens} total across ${contextCount} context${contextCount !== 1 ? 's' : ''})\n\n`;
+      let message = `${t(lang, 'tokens.title_all', { total: totalTokens, count: contextCount })}\n\n`;
 
       const keyboard = [];
 
       // Render each context section
       for (const [contextLabel, chainTokens] of Object.entries(byContext)) {
-        message += this.renderContextSection(contextLabel, chainTokens, keyboard);
+        message += this.renderContextSection(contextLabel, chainTokens, keyboard, lang);
       }
 
       // Add action buttons
       keyboard.push([
-        Markup.button.callback('➕ Add More NFTs', 'add_token_start'),
-        Markup.button.callback('◀️ Back to NFTs Menu', 'menu_tokens')
+        Markup.button.callback(t(lang, 'tokens_menu.add_more'), 'add_token_start'),
+        Markup.button.callback(t(lang, 'tokens_menu.back'), 'menu_tokens')
       ]);
 
       // Send or edit message
@@ -3794,15 +4867,16 @@ Tap the button below to cycle through alert modes:`;
       }
     } catch (error) {
       logger.error('Error in showMyTokensAllContexts:', error);
-      ctx.reply('❌ Error retrieving your NFTs. Please try again.');
+      ctx.reply(t(lang, 'tokens.load_error'));
     }
   }
 
   async showMyTokens(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Route to appropriate view based on chat type
@@ -3838,15 +4912,15 @@ Tap the button below to cycle through alert modes:`;
 
         if (isAdmin) {
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('➕ Add Your First NFT', 'add_token_start')],
-            [Markup.button.callback('◀️ Back to NFTs Menu', 'menu_tokens')]
+            [Markup.button.callback(t(lang, 'tokens_menu.add_first'), 'add_token_start')],
+            [Markup.button.callback(t(lang, 'tokens_menu.back'), 'menu_tokens')]
           ]);
-          message = '🔍 You haven\'t added any tokens yet!\n\nUse the button below to start tracking NFT collections.';
+          message = t(lang, 'tokens.none_button');
         } else {
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('◀️ Back to NFTs Menu', 'menu_tokens')]
+            [Markup.button.callback(t(lang, 'tokens_menu.back'), 'menu_tokens')]
           ]);
-          message = '🔍 No NFTs are being tracked in this group yet!\n\nℹ️ Only admins can add NFT collections to track.';
+          message = t(lang, 'tokens.none_group');
         }
 
         // Check if this is a callback query (has callbackQuery) or command
@@ -3877,9 +4951,7 @@ Tap the button below to cycle through alert modes:`;
       });
 
       // Different header for admins vs non-admins
-      let message = isAdmin
-        ? `🎯 <b>Your Tracked NFTs</b> (${tokens.length} total)\n\n`
-        : `🎯 <b>Tracked NFTs in this Group</b> (${tokens.length} total)\n\nℹ️ <i>Read-only view - only admins can manage collections</i>\n\n`;
+      let message = `${t(lang, isAdmin ? 'tokens.title_total' : 'tokens.title_group', { total: tokens.length })}\n\n`;
 
       const keyboard = [];
 
@@ -3892,21 +4964,26 @@ Tap the button below to cycle through alert modes:`;
         message += `🔗 <b>${chainDisplay}</b> (${chainTokens.length})\n`;
 
         chainTokens.forEach((token, index) => {
-          message += `   ${index + 1}. <b>${token.token_name || 'Unknown'}</b> (${token.token_symbol || 'N/A'})\n`;
+          message += `   ${index + 1}. <b>${token.token_name || t(lang, 'common.unknown')}</b> (${token.token_symbol || 'N/A'})\n`;
           message += `      📮 <code>${token.contract_address}</code>\n`;
-          message += `      🟢 Status: Active\n`;
+          message += `      ${t(lang, 'tokens.status_active')}\n`;
 
           // Show tracking status
           if (token.collection_slug || token.chain_name === 'solana' || token.chain_name === 'bitcoin') {
-            message += `      📊 Tracking: ✅ Active\n`;
+            message += `      ${t(lang, 'tokens.tracking_active')}\n`;
           }
           message += '\n';
 
-          // Only show remove button for admins
+          // Only show alert settings and remove buttons for admins
+          if (isAdmin && token.subscription_id) {
+            keyboard.push([
+              Markup.button.callback(t(lang, 'tokens.alerts_button', { name: token.token_name || token.contract_address.slice(0, 8) }), `alerts_${token.subscription_id}`)
+            ]);
+          }
           if (isAdmin) {
             keyboard.push([
               Markup.button.callback(
-                `🗑️ Remove ${token.token_name || token.contract_address.slice(0, 8)}...`,
+                t(lang, 'tokens.remove_button', { name: `${token.token_name || token.contract_address.slice(0, 8)}...` }),
                 `remove_${token.id}`
               )
             ]);
@@ -3917,9 +4994,9 @@ Tap the button below to cycle through alert modes:`;
 
       // Bottom buttons - different for admins vs non-admins
       if (isAdmin) {
-        keyboard.push([Markup.button.callback('➕ Add More NFTs', 'add_token_start'), Markup.button.callback('◀️ Back to NFTs Menu', 'menu_tokens')]);
+        keyboard.push([Markup.button.callback(t(lang, 'tokens_menu.add_more'), 'add_token_start'), Markup.button.callback(t(lang, 'tokens_menu.back'), 'menu_tokens')]);
       } else {
-        keyboard.push([Markup.button.callback('◀️ Back to NFTs Menu', 'menu_tokens')]);
+        keyboard.push([Markup.button.callback(t(lang, 'tokens_menu.back'), 'menu_tokens')]);
       }
 
       // Check if this is a callback query or command
@@ -3938,36 +5015,34 @@ Tap the button below to cycle through alert modes:`;
       }
     } catch (error) {
       logger.error('Error in showMyTokens:', error);
-      ctx.reply('❌ Error retrieving your NFTs. Please try again.');
+      ctx.reply(t(lang, 'tokens.load_error'));
     }
   }
 
   async showBuyTrendingMenu(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
-      const message = `<b>🔥 Buy Trending</b>\n\nSelect trending tier:\n\n` +
-        `<b>🔵 Standard Trending</b>\n` +
-        `Lower cost, shown to standard tier channels\n\n` +
-        `<b>⭐ Premium Trending</b>\n` +
-        `Higher visibility, shown to premium tier channels`;
+      const message = t(lang, 'trending_menu.message');
 
       const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('🔵 Buy Standard Trending', 'buy_trending_normal')],
-        [Markup.button.callback('⭐ Buy Premium Trending', 'buy_trending_premium')],
-        [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')]
+        [Markup.button.callback(t(lang, 'trending_menu.buy_standard'), 'buy_trending_normal')],
+        [Markup.button.callback(t(lang, 'trending_menu.buy_premium'), 'buy_trending_premium')],
+        helpers.buildBackToMainButton(lang)
       ]);
 
       return ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error showing buy trending menu:', error);
-      return ctx.reply('❌ Error loading trending menu. Please try again.');
+      return ctx.reply(t(lang, 'trending_menu.error'));
     }
   }
 
   async showTrendingTypeMenu(ctx, isPremium = false) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       const chatId = this.normalizeChatContext(ctx);
@@ -3997,56 +5072,54 @@ Tap the button below to cycle through alert modes:`;
         let message, keyboard;
         if (isGroup && !isAdmin) {
           // Non-admin in group with no tracked NFTs
-          message = '📝 No NFTs are being tracked in this group yet!\n\nℹ️ Only admins can add NFT collections to track.';
+          message = t(lang, 'images.none_viewer');
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('◀️ Back to Trending Menu', 'menu_trending')]
+            [Markup.button.callback(t(lang, 'trending_menu.back'), 'menu_trending')]
           ]);
         } else {
           // Admin or private chat
-          message = '📝 You need to add some NFT collections first!\n\nUse /add_token to track your first NFT collection.';
+          message = t(lang, 'tokens.none_add_first');
           keyboard = Markup.inlineKeyboard([
-            [Markup.button.callback('➕ Add Your First NFT', 'add_token_start')]
+            [Markup.button.callback(t(lang, 'tokens_menu.add_first'), 'add_token_start')]
           ]);
         }
         return ctx.reply(message, keyboard);
       }
 
-      const trendingType = isPremium ? 'Premium' : 'Normal';
-      const message = `🚀 <b>${trendingType} Trending Boost</b>
-
-Select an NFT collection to boost:`;
+      const message = t(lang, 'trending.boost_title', { tier: t(lang, `trending.tier.${isPremium ? 'premium' : 'normal'}`) });
 
       const keyboard = [];
       tokens.forEach((token, index) => {
         keyboard.push([{
-          text: `${index + 1}. ${token.token_name || 'Unknown Collection'}`,
+          text: `${index + 1}. ${token.token_name || t(lang, 'tokens.unknown_collection')}`,
           callback_data: `trending_${isPremium ? 'premium' : 'normal'}_${token.id}`
         }]);
       });
 
       keyboard.push([{
-        text: '🔄 Back to Buy Trending Menu',
+        text: t(lang, 'trending.back_to_buy'),
         callback_data: 'back_to_buy_trending'
       }]);
 
       return ctx.replyWithHTML(message, { reply_markup: { inline_keyboard: keyboard } });
     } catch (error) {
       logger.error('Error showing trending type menu:', error);
-      return ctx.reply('❌ Error loading your NFTs. Please try again.');
+      return ctx.reply(t(lang, 'tokens.load_error'));
     }
   }
 
   async showTrendingChainSelection(ctx, tokenId, isPremium = false) {
+    const lang = await this.getLanguage(ctx);
     try {
       const token = await this.db.get('SELECT * FROM tracked_tokens WHERE id = $1', [tokenId]);
       if (!token) {
-        return ctx.reply('❌ NFT collection not found.');
+        return ctx.reply(t(lang, 'token.not_found'));
       }
 
-      const trendingType = isPremium ? 'Premium' : 'Normal';
-      const message = `🚀 <b>${trendingType} Trending - ${token.token_name || 'Unknown Collection'}</b>
-
-🔗 Select blockchain network for payment:`;
+      const message = t(lang, 'trending.chain_title', {
+        tier: t(lang, `trending.tier.${isPremium ? 'premium' : 'normal'}`),
+        name: token.token_name || t(lang, 'tokens.unknown_collection')
+      });
 
       const chainOptions = [];
 
@@ -4062,8 +5135,8 @@ Select an NFT collection to boost:`;
 
       // Navigation buttons
       chainOptions.push([
-        Markup.button.callback('◀️ Back to NFT Selection', `buy_trending_${isPremium ? 'premium' : 'normal'}`),
-        Markup.button.callback('🏠 Main Menu', 'main_menu')
+        Markup.button.callback(t(lang, 'trending.back_to_nfts'), `buy_trending_${isPremium ? 'premium' : 'normal'}`),
+        Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
       ]);
 
       const keyboard = Markup.inlineKeyboard(chainOptions);
@@ -4071,30 +5144,27 @@ Select an NFT collection to boost:`;
       return ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error showing trending chain selection:', error);
-      return ctx.reply('❌ Error loading chain options. Please try again.');
+      return ctx.reply(t(lang, 'trending.chain_error'));
     }
   }
 
   async showGroupLinkInput(ctx, token) {
+    const lang = await this.getLanguage(ctx);
     try {
       const userId = ctx.from.id;
       const session = this.getUserSession(userId);
       const tier = session.pendingTrendingTier;
-      const tierBadge = tier === 'premium' ? '⭐ PREMIUM' : '🔵 STANDARD';
-
-      const message = `📎 <b>Group Link (Optional)</b>\n\n` +
-        `<b>Selected:</b> ${token.token_name || token.contract_address}\n` +
-        `<b>Tier:</b> ${tierBadge}\n\n` +
-        `Would you like to include a Telegram group link? This link will appear as a clickable ticker (💬 $${token.token_symbol || 'TOKEN'}) in event notifications.\n\n` +
-        `<b>Accepted formats:</b>\n` +
-        `• Full link: https://t.me/mygroup\n` +
-        `• With @: @mygroup\n` +
-        `• Plain text: mygroup\n\n` +
-        `Send your group link now, or tap Skip to continue without a link.`;
+      const tierBadge = t(lang, `trending.badge.${tier === 'premium' ? 'premium' : 'normal'}`);
+
+      const message = t(lang, 'trending.group_link_prompt', {
+        name: token.token_name || token.contract_address,
+        tier: tierBadge,
+        symbol: token.token_symbol || 'TOKEN'
+      });
 
       const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('⏭️ Skip (No Group Link)', 'trending_skip_group_link')],
-        [Markup.button.callback('◀️ Back', 'buy_trending')]
+        [Markup.button.callback(t(lang, 'trending.skip_group_link'), 'trending_skip_group_link')],
+        [Markup.button.callback(t(lang, 'common.back'), 'buy_trending')]
       ]);
 
       // Set state to expect group link input
@@ -4105,26 +5175,26 @@ Select an NFT collection to boost:`;
       return ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error showing group link input:', error);
-      return ctx.reply('❌ Error loading group link input. Please try again.');
+      return ctx.reply(t(lang, 'trending.group_link_error'));
     }
   }
 
   async showTrendingDurationSelection(ctx, tokenId, isPremium = false, chain = 'ethereum') {
+    const lang = await this.getLanguage(ctx);
     try {
       const token = await this.db.get('SELECT * FROM tracked_tokens WHERE id = $1', [tokenId]);
       if (!token) {
-        return ctx.reply('❌ NFT collection not found.');
+        return ctx.reply(t(lang, 'token.not_found'));
       }
 
-      const trendingType = isPremium ? 'Premium' : 'Normal';
       const chainConfig = this.chainManager ? this.chainManager.getChain(chain) : null;
       const chainDisplay = chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : chain.charAt(0).toUpperCase() + chain.slice(1);
 
-      const message = `🚀 <b>${trendingType} Trending - ${token.token_name || 'Unknown Collection'}</b>
-
-🔗 Chain: <b>${chainDisplay}</b> <i>(auto-detected)</i>
-
-Select trending duration:`;
+      const message = t(lang, 'trending.duration_title', {
+        tier: t(lang, `trending.tier.${isPremium ? 'premium' : 'normal'}`),
+        name: token.token_name || t(lang, 'tokens.unknown_collection'),
+        chain: chainDisplay
+      });
 
       const durations = [6, 12, 18, 24];
       const keyboard = [];
@@ -4136,23 +5206,24 @@ Select trending duration:`;
       durations.forEach(duration => {
         const fee = this.secureTrending.calculateTrendingFee(duration, isPremium, chain);
         const feeFormatted = this.secureTrending.formatChainAmount(fee, chainNormalized);
-        keyboard.push([Markup.button.callback(`${duration}h - ${feeFormatted} ${chainSymbol}`, `trending_duration_${tokenId}_${duration}_${isPremium ? 'premium' : 'normal'}_${chain}`)]);
+        keyboard.push([Markup.button.callback(t(lang, 'trending.duration_button', { hours: duration, price: `${feeFormatted} ${chainSymbol}` }), `trending_duration_${tokenId}_${duration}_${isPremium ? 'premium' : 'normal'}_${chain}`)]);
       });
 
       keyboard.push([
-        Markup.button.callback('🔄 Choose Different Chain', `trending_back_to_chain_${tokenId}_${isPremium ? 'premium' : 'normal'}`),
-        Markup.button.callback('🏠 Main Menu', 'main_menu')
+        Markup.button.callback(t(lang, 'trending.change_chain'), `trending_back_to_chain_${tokenId}_${isPremium ? 'premium' : 'normal'}`),
+        Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
       ]);
 
       return ctx.replyWithHTML(message, Markup.inlineKeyboard(keyboard));
     } catch (error) {
       logger.error('Error showing trending duration selection:', error);
-      return ctx.reply('❌ Error loading trending options. Please try again.');
+      return ctx.reply(t(lang, 'trending.options_error'));
     }
   }
 
   // Button-Driven Flow Handlers
   async handleFooterContract(ctx, contractAddress) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (contractAddress.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4166,18 +5237,18 @@ Select trending duration:`;
 
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Check if user has too many pending operations
       const pendingCount = await this.checkUserPendingOperations(user.id);
       if (pendingCount >= 5) {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ You have too many pending operations. Please complete or cancel existing operations before starting new ones.');
+        return ctx.reply(t(lang, 'common.too_many_pending'));
       }
 
       // Check if contract is already tracked, if not validate and add it
-      ctx.reply('🔍 Validating NFT address...');
+      ctx.reply(t(lang, 'validation.validating_address'));
 
       let token = await this.db.getTrackedToken(contractAddress);
       if (!token) {
@@ -4188,7 +5259,7 @@ Select trending duration:`;
         const result = await this.tokenTracker.addToken(contractAddress, user.id, ctx.from.id.toString(), chatId, chainName);
         if (!result.success) {
           this.clearUserState(ctx.from.id);
-          return ctx.reply(`❌ Contract validation failed: ${result.error}`);
+          return ctx.reply(t(lang, 'validation.contract_failed', { error: result.error }));
         }
         // Get the newly added token
         token = await this.db.getTrackedToken(contractAddress);
@@ -4199,30 +5270,32 @@ Select trending duration:`;
 
       const instructions = await this.secureTrending.generateFooterPaymentInstructions(contractAddress, user.id);
 
-      const message =
-        `💰 <b>Footer Advertisement Payment</b>\n\n` +
-        `🎨 <b>Collection:</b> ${instructions.tokenName || 'Unknown'}\n` +
-        `🎯 <b>Token:</b> ${instructions.tokenSymbol || 'N/A'}\n` +
-        `💸 <b>Fee:</b> ${instructions.feeEth || '1.0'} ${instructions.symbol || 'ETH'}\n` +
-        `⏰ <b>Duration:</b> ${instructions.duration || '30 days'}\n` +
-        `📮 <b>Contract:</b> <code>${instructions.contractAddress || contractAddress}</code>\n\n` +
-        `📋 <b>Payment Steps:</b>\n` +
-        (instructions.instructions || ['Send payment to NFT address']).map((step, i) => `${i + 1}. ${step}`).join('\n') + '\n\n';
+      const steps = instructions.instructions || [t(lang, 'footer.default_step')];
+      const message = t(lang, 'footer.payment', {
+        name: instructions.tokenName || t(lang, 'common.unknown'),
+        symbol: instructions.tokenSymbol || 'N/A',
+        fee: instructions.feeEth || '1.0',
+        feeSymbol: instructions.symbol || 'ETH',
+        duration: instructions.duration || t(lang, 'duration.days', { days: 30 }),
+        address: instructions.contractAddress || contractAddress,
+        steps: steps.map((step, i) => `${i + 1}. ${step}`).join('\n')
+      });
 
       const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('📝 Submit Transaction Hash', 'submit_footer_tx')],
-        [Markup.button.callback('◀️ Back to Footer Menu', 'menu_footer')]
+        [Markup.button.callback(t(lang, 'payment.submit_tx_button'), 'submit_footer_tx')],
+        [Markup.button.callback(t(lang, 'footer_menu.back'), 'menu_footer')]
       ]);
 
       await ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error handling footer contract:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleFooterTxHash(ctx, txHash) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (txHash.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4231,8 +5304,8 @@ Select trending duration:`;
 
       // Validate transaction hash format
       if (!txHash.match(/^0x[a-fA-F0-9]{64}$/)) {
-        const errorMessage = '⚠️ Invalid transaction hash format. Please send a valid transaction hash (starts with 0x and is 64 characters long).';
-        const keyboard = Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]]);
+        const errorMessage = t(lang, 'payment.invalid_tx_hash');
+        const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]]);
         return ctx.replyWithHTML(errorMessage, keyboard);
       }
 
@@ -4262,10 +5335,10 @@ Select trending duration:`;
       if (!contractAddress && !isNewFlow) {
         this.clearUserState(ctx.from.id);
         this.clearUserSession(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
-      await ctx.reply('⏳ Validating your footer advertisement payment...');
+      await ctx.reply(t(lang, 'validation.validating_footer'));
 
       if (isNewFlow) {
         // New flow: validate payment without contract requirement, include chain
@@ -4275,7 +5348,7 @@ Select trending duration:`;
         if (!paymentValidation.success) {
           this.clearUserState(ctx.from.id);
           this.clearUserSession(ctx.from.id);
-          return ctx.reply(`❌ Payment validation failed: ${paymentValidation.error}\n\nPlease ensure you sent the correct amount to the payment contract.`);
+          return ctx.reply(t(lang, 'validation.payment_failed', { error: paymentValidation.error }));
         }
 
         // Footer ad created successfully
@@ -4290,7 +5363,7 @@ Select trending duration:`;
         if (!paymentValidation.success) {
         this.clearUserState(ctx.from.id);
         this.clearUserSession(ctx.from.id);
-        return ctx.reply(`❌ Payment validation failed: ${paymentValidation.error}\n\nPlease ensure you sent the correct amount to the payment contract.`);
+        return ctx.reply(t(lang, 'validation.payment_failed', { error: paymentValidation.error }));
       }
 
       if (isEnhancedFlow) {
@@ -4302,13 +5375,13 @@ Select trending duration:`;
         // Use pre-formatted amount that already includes the correct symbol
         const amountText = session.amountFormatted || '0 ETH';
 
-        return ctx.reply(`✅ <b>Payment Verified!</b>\n\n` +
-          `🎨 Collection: <b>${session.tokenName}</b>\n` +
-          `💰 Amount: ${amountText}\n` +
-          `📅 Duration: <b>${durationDays} days</b>\n\n` +
-          `🔗 <b>Custom Link</b>\n\nNow please send me the custom link you want to display in the footer ads.\n\n<i>Example: https://mytoken.com</i>`, {
+        return ctx.reply(t(lang, 'footer.payment_verified_details', {
+          name: session.tokenName,
+          amount: amountText,
+          duration: t(lang, 'duration.days', { days: durationDays })
+        }), {
           parse_mode: 'HTML',
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]])
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]])
         });
       } else {
         // For old flow, use old session storage method
@@ -4316,16 +5389,16 @@ Select trending duration:`;
         this.userStates.set(userId + '_payment_validated', true);
         this.setFlowState(ctx.from.id, this.STATE_EXPECTING_FOOTER_LINK);
 
-        return ctx.reply('✅ <b>Payment Verified!</b>\n\n🔗 <b>Custom Link</b>\n\nNow please send me the custom link you want to display in the footer ads.\n\n<i>Example: https://mytoken.com</i>\n\n', {
+        return ctx.reply(t(lang, 'footer.payment_verified'), {
           parse_mode: 'HTML',
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]])
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]])
         });
         }
       }
     } catch (error) {
       logger.error('Error handling footer tx hash:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
@@ -4355,6 +5428,7 @@ Select trending duration:`;
   }
 
   async handleGroupLinkInput(ctx, text) {
+    const lang = await this.getLanguage(ctx);
     try {
       const userId = ctx.from.id;
 
@@ -4362,7 +5436,7 @@ Select trending duration:`;
       const { link: groupLink, username: groupUsername } = this.parseGroupLink(text);
 
       if (!groupLink) {
-        return ctx.reply('❌ Invalid link format. Please send a valid Telegram group link or type "cancel" to go back.');
+        return ctx.reply(t(lang, 'trending.group_link_invalid'));
       }
 
       // Store in session
@@ -4378,25 +5452,26 @@ Select trending duration:`;
       const chain = session.pendingTrendingChain;
 
       if (!tokenId) {
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
       // Clear state
       this.clearUserState(userId);
 
       // Confirm and proceed to duration selection
-      await ctx.reply(`✅ Group link saved: ${groupLink}`);
+      await ctx.reply(t(lang, 'trending.group_link_saved', { link: groupLink }));
 
       // Proceed to duration selection
       return this.showTrendingDurationSelection(ctx, tokenId, tier === 'premium', chain);
     } catch (error) {
       logger.error('Error handling group link input:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleFooterLink(ctx, customLink) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (customLink.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4423,19 +5498,19 @@ Select trending duration:`;
       if (!contractAddress || !txHash || !paymentValidated) {
         this.clearUserState(ctx.from.id);
         this.clearUserSession(ctx.from.id);
-        return ctx.reply('❌ Session expired or payment not validated. Please start again.');
+        return ctx.reply(t(lang, 'footer.session_invalid'));
       }
 
       // Validate URL format
       try {
         new URL(customLink);
       } catch (e) {
-        return ctx.reply('❌ Invalid URL format. Please provide a valid URL (e.g., https://mytoken.com).', {
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]])
+        return ctx.reply(t(lang, 'footer.invalid_url'), {
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]])
         });
       }
 
-      await ctx.reply('⏳ Creating your footer advertisement...');
+      await ctx.reply(t(lang, 'footer.creating'));
 
       const user = await this.db.getUser(userId);
       const result = await this.secureTrending.finalizeFooterAd(contractAddress, txHash, customLink, user.id, durationDays);
@@ -4447,18 +5522,19 @@ Select trending duration:`;
       this.userStates.delete(userId + '_payment_validated');
 
       if (result.success) {
-        await ctx.replyWithHTML(`✅ ${result.message}`, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Footer Menu', 'menu_footer')]]));
+        await ctx.replyWithHTML(`✅ ${result.message}`, Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'footer_menu.back'), 'menu_footer')]]));
       } else {
-        await ctx.replyWithHTML(`❌ ${result.error}`, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Footer Menu', 'menu_footer')]]));
+        await ctx.replyWithHTML(`❌ ${result.error}`, Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'footer_menu.back'), 'menu_footer')]]));
       }
     } catch (error) {
       logger.error('Error handling footer link:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleImageContract(ctx, contractAddress) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (contractAddress.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4472,18 +5548,18 @@ Select trending duration:`;
 
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Check if user has too many pending operations
       const pendingCount = await this.checkUserPendingOperations(user.id);
       if (pendingCount >= 5) {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ You have too many pending operations. Please complete or cancel existing operations before starting new ones.');
+        return ctx.reply(t(lang, 'common.too_many_pending'));
       }
 
       // Check if contract is already tracked, if not validate and add it
-      ctx.reply('🔍 Validating NFT address...');
+      ctx.reply(t(lang, 'validation.validating_address'));
 
       let token = await this.db.getTrackedToken(contractAddress);
       if (!token) {
@@ -4494,7 +5570,7 @@ Select trending duration:`;
         const result = await this.tokenTracker.addToken(contractAddress, user.id, ctx.from.id.toString(), chatId, chainName);
         if (!result.success) {
           this.clearUserState(ctx.from.id);
-          return ctx.reply(`❌ Contract validation failed: ${result.error}`);
+          return ctx.reply(t(lang, 'validation.contract_failed', { error: result.error }));
         }
         // Get the newly added token
         token = await this.db.getTrackedToken(contractAddress);
@@ -4503,40 +5579,43 @@ Select trending duration:`;
       // Check if image fee is already active
       const isActive = await this.secureTrending.isImageFeeActive(contractAddress);
       if (isActive) {
-        return ctx.reply('✅ Image fee is already active for this contract. Actual NFT images are being displayed.', {
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Image Spots Menu', 'menu_images')]])
+        return ctx.reply(t(lang, 'images.already_active'), {
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images')]])
         });
       }
 
       const instructions = await this.secureTrending.generateImagePaymentInstructions(contractAddress, user.id);
       const truncatedAddress = this.truncateAddress(instructions.contractAddress);
 
-      const message = `💰 <b>Image Fee Payment Instructions</b>\n\n` +
-        `🎨 Collection: <b>${instructions.tokenName}</b>\n` +
-        `📮 Contract: <code>${instructions.tokenAddress}</code>\n` +
-        `💸 Fee: <b>${instructions.feeEth} ${instructions.symbol}</b> (${instructions.duration} days)\n\n` +
-        `🏦 <b>Payment Address (${truncatedAddress}):</b>\n\n` +
-        `<code>${instructions.contractAddress}</code>\n\n` +
-        `📋 <b>Payment Steps:</b>\n` +
-        instructions.instructions.join('\n') + '\n\n';
+      const message = t(lang, 'images.contract_instructions', {
+        name: instructions.tokenName,
+        address: instructions.tokenAddress,
+        duration: t(lang, 'duration.days', { days: instructions.duration }),
+        fee: instructions.feeEth,
+        feeSymbol: instructions.symbol,
+        shortAddress: truncatedAddress,
+        paymentAddress: instructions.contractAddress,
+        steps: instructions.instructions.join('\n')
+      });
 
       // Store NFT address for later validation
       this.userStates.set(ctx.from.id.toString() + '_image_contract', contractAddress);
 
       const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('📝 Submit Transaction Hash', 'submit_image_tx')],
-        [Markup.button.callback('◀️ Back to Image Spots Menu', 'menu_images')]
+        [Markup.button.callback(t(lang, 'payment.submit_tx_button'), 'submit_image_tx')],
+        [Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images')]
       ]);
 
       await ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error handling image contract:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleImageTxHash(ctx, txHash) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (txHash.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4545,8 +5624,8 @@ Select trending duration:`;
 
       // Validate transaction hash format
       if (!txHash.match(/^0x[a-fA-F0-9]{64}$/)) {
-        return ctx.reply('❌ Invalid transaction hash format. Please send a valid transaction hash (starts with 0x and is 64 characters long).', {
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_images')]])
+        return ctx.reply(t(lang, 'payment.invalid_tx_hash'), {
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_images')]])
         });
       }
 
@@ -4566,10 +5645,10 @@ Select trending duration:`;
       if (!contractAddress) {
         this.clearUserState(ctx.from.id);
         this.clearUserSession(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
-      await ctx.reply('⏳ Validating your image fee transaction...');
+      await ctx.reply(t(lang, 'validation.validating_image'));
 
       const user = await this.db.getUser(userId);
       const chain = session.chain || 'ethereum';
@@ -4581,29 +5660,31 @@ Select trending duration:`;
 
       if (result.success) {
         const symbol = result.symbol || 'ETH';
-        const successMessage = `✅ <b>Image Fee Payment Validated!</b>\n\n` +
-          `🎨 Collection: <b>${result.tokenName}</b>\n` +
-          `📮 Contract: <code>${result.contractAddress}</code>\n` +
-          `💰 Amount: ${result.amountEth} ${symbol}\n` +
-          `📅 Duration: <b>${durationDays} days</b>\n` +
-          `📝 Transaction: <code>${result.txHash}</code>\n` +
-          `👤 Payer: <code>${result.payer}</code>\n\n` +
-          `🖼️ <b>Actual NFT images will now be displayed for this contract for ${durationDays} days!</b>`;
-
-        await ctx.replyWithHTML(successMessage, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Image Spots Menu', 'menu_images')]]));
+        const successMessage = t(lang, 'images.payment_validated', {
+          name: result.tokenName,
+          address: result.contractAddress,
+          amount: result.amountEth,
+          symbol,
+          duration: t(lang, 'duration.days', { days: durationDays }),
+          txHash: result.txHash,
+          payer: result.payer
+        });
+
+        await ctx.replyWithHTML(successMessage, Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images')]]));
       } else {
-        await ctx.reply(`❌ Validation failed: ${result.error}`, {
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Image Spots Menu', 'menu_images')]])
+        await ctx.reply(t(lang, 'validation.failed', { error: result.error }), {
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images')]])
         });
       }
     } catch (error) {
       logger.error('Error handling image tx hash:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleValidationContract(ctx, contractAddress) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (contractAddress.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4617,11 +5698,11 @@ Select trending duration:`;
 
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Check if contract is already tracked, if not validate and add it
-      ctx.reply('🔍 Validating NFT address...');
+      ctx.reply(t(lang, 'validation.validating_address'));
 
       let token = await this.db.getTrackedToken(contractAddress);
       if (!token) {
@@ -4630,7 +5711,7 @@ Select trending duration:`;
         const result = await this.tokenTracker.addToken(contractAddress, user.id, ctx.from.id.toString(), chatId);
         if (!result.success) {
           this.clearUserState(ctx.from.id);
-          return ctx.reply(`❌ Contract validation failed: ${result.error}`);
+          return ctx.reply(t(lang, 'validation.contract_failed', { error: result.error }));
         }
         // Get the newly added token
         token = await this.db.getTrackedToken(contractAddress);
@@ -4643,17 +5724,18 @@ Select trending duration:`;
       this.userStates.set(userId + '_validation_contract', contractAddress);
       this.setFlowState(ctx.from.id, this.STATE_EXPECTING_VALIDATION_TX_HASH);
 
-      const message = `📝 <b>Transaction Hash Required</b>\n\nNow please send me the transaction hash for your ${validationType} payment.\n\n<i>Example: 0xabc123456789def...</i>`;
-      const keyboard = Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Payments', 'cancel_verify')]]);
+      const message = t(lang, 'verify.tx_hash_required', { payment: t(lang, `verify.payment_name.${validationType}`) });
+      const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify.back_to_payments'), 'cancel_verify')]]);
       return ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error handling validation contract:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleValidationTxHash(ctx, txHash) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (txHash.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4662,8 +5744,8 @@ Select trending duration:`;
 
       // Validate transaction hash format
       if (!txHash.match(/^0x[a-fA-F0-9]{64}$/)) {
-        return ctx.reply('❌ Invalid transaction hash format. Please send a valid transaction hash (starts with 0x and is 64 characters long).', {
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_verify')]])
+        return ctx.reply(t(lang, 'payment.invalid_tx_hash'), {
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_verify')]])
         });
       }
 
@@ -4675,12 +5757,12 @@ Select trending duration:`;
 
       if (validationType === 'trending') {
         // For trending, we can validate immediately with chain information
-        await ctx.reply('🔍 Validating your transaction... Please wait.');
+        await ctx.reply(t(lang, 'validation.validating_tx'));
 
         // Get database user ID
         const user = await this.db.getUser(telegramId);
         if (!user) {
-          await ctx.reply('❌ User not found. Please use /start first.');
+          await ctx.reply(t(lang, 'common.user_not_found'));
           return;
         }
 
@@ -4694,28 +5776,29 @@ Select trending duration:`;
         if (result.success) {
           const chain = result.chain || 'ethereum';
           const symbol = this.secureTrending.getChainConfig(chain).symbol;
-          const successMessage = `✅ **Payment Validated Successfully!**\n\n` +
-            `🎯 **${result.tokenName}** trending activated!\n` +
-            `⏱️ Duration: ${result.duration} hours\n` +
-            `💰 Amount: ${result.amountEth} ${symbol}\n` +
-            `🔗 TX: \`${txHash}\`\n\n` +
-            `Your NFT is now trending! 🚀`;
-
-          await ctx.replyWithMarkdown(successMessage, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]));
+          const successMessage = t(lang, 'trending.payment_validated', {
+            name: result.tokenName,
+            hours: result.duration,
+            amount: result.amountEth,
+            symbol,
+            txHash
+          });
+
+          await ctx.replyWithMarkdown(successMessage, Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]));
         } else {
-          await ctx.reply(`❌ **Validation Failed**\n\n${result.error}`, {
+          await ctx.reply(t(lang, 'validation.failed_markdown', { error: result.error }), {
             parse_mode: 'Markdown',
-            reply_markup: Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]])
+            reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]])
           });
         }
       } else if (validationType === 'image') {
         // For image validation, use contract + tx hash
         if (!contractAddress) {
           this.clearUserState(ctx.from.id);
-          return ctx.reply('❌ Session expired. Please start again.');
+          return ctx.reply(t(lang, 'common.session_expired'));
         }
 
-        await ctx.reply('⏳ Validating your image fee transaction...');
+        await ctx.reply(t(lang, 'validation.validating_image'));
 
         const user = await this.db.getUser(telegramId);
         const result = await this.secureTrending.validateImageFeeTransaction(user.id, contractAddress, txHash, null, validationChain);
@@ -4728,18 +5811,20 @@ Select trending duration:`;
 
         if (result.success) {
           const symbol = result.symbol || 'ETH';
-          const successMessage = `✅ <b>Image Fee Payment Validated!</b>\n\n` +
-            `🎨 Collection: <b>${result.tokenName}</b>\n` +
-            `📮 Contract: <code>${result.contractAddress}</code>\n` +
-            `💰 Amount: ${result.amountEth} ${symbol}\n` +
-            `📝 Transaction: <code>${result.txHash}</code>\n` +
-            `👤 Payer: <code>${result.payer}</code>\n\n` +
-            `🖼️ <b>Actual NFT images will now be displayed for this contract for 30 days!</b>`;
-
-          await ctx.replyWithHTML(successMessage, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]));
+          const successMessage = t(lang, 'images.payment_validated', {
+            name: result.tokenName,
+            address: result.contractAddress,
+            amount: result.amountEth,
+            symbol,
+            duration: t(lang, 'duration.days', { days: 30 }),
+            txHash: result.txHash,
+            payer: result.payer
+          });
+
+          await ctx.replyWithHTML(successMessage, Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]));
         } else {
-          await ctx.reply(`❌ Validation failed: ${result.error}`, {
-            reply_markup: Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]])
+          await ctx.reply(t(lang, 'validation.failed', { error: result.error }), {
+            reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]])
           });
         }
       } else if (validationType === 'footer') {
@@ -4747,10 +5832,10 @@ Select trending duration:`;
         const ticker = this.userStates.get(userId + '_validation_ticker');
         if (!ticker) {
           this.clearUserState(ctx.from.id);
-          return ctx.reply('❌ Session expired. Please start again.');
+          return ctx.reply(t(lang, 'common.session_expired'));
         }
 
-        await ctx.reply('⏳ Validating your footer advertisement payment...');
+        await ctx.reply(t(lang, 'validation.validating_footer'));
 
         // Use ticker-based validation without contract requirement
         // For ticker-based verification, we'll let the service determine chain from transaction
@@ -4762,21 +5847,22 @@ Select trending duration:`;
 
         if (result.success) {
           const symbol = result.symbol || 'ETH';
-          await ctx.replyWithHTML(`✅ <b>Footer Payment Validated!</b>\n\n🔗 <b>Ticker:</b> ${ticker}\n💰 <b>Amount:</b> ${result.amountEth || 'N/A'} ${symbol}\n📝 <b>Transaction:</b> <code>${txHash}</code>\n\n📢 Your footer advertisement is now active!`,
-            Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]));
+          await ctx.replyWithHTML(t(lang, 'footer.payment_validated', { ticker, amount: result.amountEth || 'N/A', symbol, txHash }),
+            Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]));
         } else {
-          await ctx.replyWithHTML(`❌ Footer validation failed: ${result.error}`,
-            Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]));
+          await ctx.replyWithHTML(t(lang, 'footer.validation_failed', { error: result.error }),
+            Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]));
         }
       }
     } catch (error) {
       logger.error('Error handling validation tx hash:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleValidationLink(ctx, customLink) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (customLink.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4789,10 +5875,10 @@ Select trending duration:`;
 
       if (!contractAddress || !txHash) {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
-      await ctx.reply('⏳ Validating your footer advertisement transaction...');
+      await ctx.reply(t(lang, 'validation.validating_footer_tx'));
 
       const user = await this.db.getUser(userId);
       const validationChain = this.userStates.get(userId + '_validation_chain') || 'ethereum';
@@ -4805,18 +5891,19 @@ Select trending duration:`;
       this.userStates.delete(userId + '_validation_chain');
 
       if (result.success) {
-        await ctx.replyWithHTML(`✅ ${result.message}`, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]));
+        await ctx.replyWithHTML(`✅ ${result.message}`, Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]));
       } else {
-        await ctx.replyWithHTML(`❌ ${result.error}`, Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]));
+        await ctx.replyWithHTML(`❌ ${result.error}`, Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]));
       }
     } catch (error) {
       logger.error('Error handling validation link:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleValidationTicker(ctx, ticker) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (ticker.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -4833,9 +5920,9 @@ Select trending duration:`;
 
       // Validate ticker format: $SYMBOL (2-10 characters after $)
       if (!cleanTicker.match(/^\$[A-Z0-9]{2,10}$/)) {
-        return ctx.reply('❌ Invalid ticker format. Please enter a valid ticker symbol.\n\n<i>Examples: $PEPE, $BAYC, $AZUKI</i>\n\n💡 Use the same ticker you used when creating your footer ad.', {
+        return ctx.reply(t(lang, 'verify.invalid_ticker'), {
           parse_mode: 'HTML',
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_verify')]])
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_verify')]])
         });
       }
 
@@ -4843,17 +5930,14 @@ Select trending duration:`;
       this.userStates.set(userId + '_validation_ticker', cleanTicker);
       this.setFlowState(ctx.from.id, this.STATE_EXPECTING_VALIDATION_TX_HASH);
 
-      const message = `📝 <b>Submit Transaction Hash</b>\n\n` +
-        `🔗 <b>Footer Ticker:</b> ${cleanTicker}\n\n` +
-        `Please send me your transaction hash for the footer payment.\n\n` +
-        `<i>Example: 0xabc123456789def...</i>`;
+      const message = t(lang, 'verify.submit_ticker_tx', { ticker: cleanTicker });
 
-      const keyboard = Markup.inlineKeyboard([[Markup.button.callback('◀️ Back to Verify Menu', 'menu_verify')]]);
+      const keyboard = Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'verify_menu.back'), 'menu_verify')]]);
       return ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error handling validation ticker:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
@@ -4884,10 +5968,11 @@ Select trending duration:`;
 
   // Helper method to show tokens from all chains
   async showTokensForAllChains(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       const chatId = this.normalizeChatContext(ctx);
@@ -4895,9 +5980,9 @@ Select trending duration:`;
 
       if (tokens.length === 0) {
         const keyboard = Markup.inlineKeyboard([
-          [Markup.button.callback('➕ Add Your First NFT', 'add_token_start')]
+          [Markup.button.callback(t(lang, 'tokens_menu.add_first'), 'add_token_start')]
         ]);
-        return ctx.reply('🔍 You haven\'t added any tokens yet!\n\nUse /add_token to start tracking NFT collections.', keyboard);
+        return ctx.reply(t(lang, 'tokens.none'), keyboard);
       }
 
       // Group tokens by chain
@@ -4910,7 +5995,7 @@ Select trending duration:`;
         tokensByChain[chainName].push(token);
       });
 
-      let message = `🎯 <b>Your Tracked NFTs</b> (${tokens.length} total)\n\n`;
+      let message = t(lang, 'tokens.title_total', { total: tokens.length }) + '\n\n';
       const keyboard = [];
 
       for (const [chainName, chainTokens] of Object.entries(tokensByChain)) {
@@ -4922,19 +6007,19 @@ Select trending duration:`;
         message += `🔗 <b>${chainDisplay}</b> (${chainTokens.length})\n`;
 
         chainTokens.forEach((token, index) => {
-          message += `   ${index + 1}. <b>${token.token_name || 'Unknown'}</b> (${token.token_symbol || 'N/A'})\n`;
+          message += `   ${index + 1}. <b>${token.token_name || t(lang, 'common.unknown')}</b> (${token.token_symbol || 'N/A'})\n`;
           message += `      📮 <code>${token.contract_address}</code>\n`;
-          message += `      🟢 Status: Active\n`;
+          message += `      ${t(lang, 'tokens.status_active')}\n`;
 
           // Show tracking status
           if (token.collection_slug || token.chain_name === 'solana' || token.chain_name === 'bitcoin') {
-            message += `      📊 Tracking: ✅ Active\n`;
+            message += `      ${t(lang, 'tokens.tracking_active')}\n`;
           }
           message += '\n';
 
           keyboard.push([
             Markup.button.callback(
-              `🗑️ Remove ${token.token_name || token.contract_address.slice(0, 8)}...`,
+              t(lang, 'tokens.remove_button', { name: `${token.token_name || token.contract_address.slice(0, 8)}...` }),
               `remove_${token.id}`
             )
           ]);
@@ -4942,21 +6027,22 @@ Select trending duration:`;
         message += '\n';
       }
 
-      keyboard.push([Markup.button.callback('➕ Add More NFTs', 'add_token_start')]);
+      keyboard.push([Markup.button.callback(t(lang, 'tokens_menu.add_more'), 'add_token_start')]);
 
       await ctx.replyWithHTML(message, Markup.inlineKeyboard(keyboard));
     } catch (error) {
       logger.error('Error showing tokens for all chains:', error);
-      ctx.reply('❌ Error retrieving your NFTs. Please try again.');
+      ctx.reply(t(lang, 'tokens.load_error'));
     }
   }
 
   // Helper method to show tokens from a specific chain
   async showTokensForChain(ctx, chainName, chainConfig) {
+    const lang = await this.getLanguage(ctx);
     try {
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       const chatId = this.normalizeChatContext(ctx);
@@ -4965,77 +6051,74 @@ Select trending duration:`;
 
       if (chainTokens.length === 0) {
         const keyboard = Markup.inlineKeyboard([
-          [Markup.button.callback('➕ Add NFT on This Chain', 'add_token_start')],
-          [Markup.button.callback('🌐 View All Chains', 'chain_select_all')]
+          [Markup.button.callback(t(lang, 'tokens_menu.add_on_chain'), 'add_token_start')],
+          [Markup.button.callback(t(lang, 'tokens_menu.view_all_chains'), 'chain_select_all')]
         ]);
         return ctx.reply(
-          `🔍 <b>No NFTs found on ${chainConfig.displayName}</b>\n\nAdd some NFTs to start tracking on this blockchain!`,
+          t(lang, 'tokens.none_on_chain_add', { chain: chainConfig.displayName }),
           { parse_mode: 'HTML', reply_markup: keyboard }
         );
       }
 
       const chainDisplay = `${chainConfig.emoji} ${chainConfig.displayName}`;
-      let message = `🎯 <b>Your ${chainDisplay} Tokens</b> (${chainTokens.length})\n\n`;
+      let message = t(lang, 'tokens.title_chain_count', { chain: chainDisplay, count: chainTokens.length }) + '\n\n';
       const keyboard = [];
 
       chainTokens.forEach((token, index) => {
-        message += `${index + 1}. <b>${token.token_name || 'Unknown'}</b> (${token.token_symbol || 'N/A'})\n`;
+        message += `${index + 1}. <b>${token.token_name || t(lang, 'common.unknown')}</b> (${token.token_symbol || 'N/A'})\n`;
         message += `   📮 <code>${token.contract_address}</code>\n`;
-        message += `   🟢 Status: Active\n`;
+        message += `   ${t(lang, 'tokens.status_active')}\n`;
 
         // Show tracking status
         if (token.collection_slug || token.chain_name === 'solana' || token.chain_name === 'bitcoin') {
-          message += `   📊 Tracking: ✅ Active\n`;
+          message += `   ${t(lang, 'tokens.tracking_active')}\n`;
         }
         message += '\n';
 
         keyboard.push([
           Markup.button.callback(
-            `🗑️ Remove ${token.token_name || token.contract_address.slice(0, 8)}...`,
+            t(lang, 'tokens.remove_button', { name: `${token.token_name || token.contract_address.slice(0, 8)}...` }),
             `remove_${token.id}`
           )
         ]);
       });
 
       keyboard.push([
-        Markup.button.callback('➕ Add More NFTs', 'add_token_start'),
-        Markup.button.callback('🌐 All Chains', 'chain_select_all')
+        Markup.button.callback(t(lang, 'tokens_menu.add_more'), 'add_token_start'),
+        Markup.button.callback(t(lang, 'tokens_menu.all_chains'), 'chain_select_all')
       ]);
 
       await ctx.replyWithHTML(message, Markup.inlineKeyboard(keyboard));
     } catch (error) {
       logger.error('Error showing tokens for chain:', error);
-      ctx.reply('❌ Error retrieving your NFTs. Please try again.');
+      ctx.reply(t(lang, 'tokens.load_error'));
     }
   }
 
   // Duration selection methods for enhanced payment flow
   async showImageDurationSelection(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
-      const message = `🎨 <b>Image Fee - Select Duration</b>\n\n` +
-        `Choose how long you want NFT images displayed instead of the Candy Codex image:\n\n` +
-        `🔹 <b>30 days</b>\n` +
-        `🔹 <b>60 days</b>\n` +
-        `🔹 <b>90 days</b>\n` +
-        `🔹 <b>180 days</b>\n` +
-        `🔹 <b>365 days</b>\n\n` +
-        `✨ Longer durations offer better value per day!\n` +
-        `💡 Pricing will be shown after chain selection.`;
+      const durationLabel = days => t(lang, 'duration.days', { days });
+      const message = t(lang, 'images.duration_menu_title') + '\n\n' +
+        [30, 60, 90, 180, 365].map(days => `🔹 <b>${durationLabel(days)}</b>`).join('\n') + '\n\n' +
+        t(lang, 'duration.value_hint') + '\n' +
+        t(lang, 'duration.pricing_hint');
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback('30 days', 'image_duration_30'),
-          Markup.button.callback('60 days', 'image_duration_60')
+          Markup.button.callback(durationLabel(30), 'image_duration_30'),
+          Markup.button.callback(durationLabel(60), 'image_duration_60')
         ],
         [
-          Markup.button.callback('90 days', 'image_duration_90'),
-          Markup.button.callback('180 days', 'image_duration_180')
+          Markup.button.callback(durationLabel(90), 'image_duration_90'),
+          Markup.button.callback(durationLabel(180), 'image_duration_180')
         ],
         [
-          Markup.button.callback('365 days', 'image_duration_365')
+          Markup.button.callback(durationLabel(365), 'image_duration_365')
         ],
         [
-          Markup.button.callback('◀️ Back to Image Spots Menu', 'menu_images')
+          Markup.button.callback(t(lang, 'images_menu.back'), 'menu_images')
         ]
       ]);
 
@@ -5053,11 +6136,12 @@ Select trending duration:`;
       }
     } catch (error) {
       logger.error('Error showing image duration selection:', error);
-      ctx.reply('❌ Error showing duration options. Please try again.');
+      ctx.reply(t(lang, 'duration.error'));
     }
   }
 
   async showFooterDurationSelection(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       // Get session to determine selected chain
       const session = this.getUserSession(ctx.from.id);
@@ -5084,30 +6168,28 @@ Select trending duration:`;
       };
       const chainEmoji = chainEmojis[chainNormalized] || '🔗';
 
-      const message = `📢 <b>Footer Advertisement - Select Duration</b>\n\n` +
-        `${chainEmoji} <b>Chain:</b> ${selectedChain.charAt(0).toUpperCase() + selectedChain.slice(1)}\n\n` +
-        `Choose how long your footer ad will be displayed:\n\n` +
-        `🔹 <b>30 days</b> - ${fees[30]} ${currencySymbol}\n` +
-        `🔹 <b>60 days</b> - ${fees[60]} ${currencySymbol}\n` +
-        `🔹 <b>90 days</b> - ${fees[90]} ${currencySymbol}\n` +
-        `🔹 <b>180 days</b> - ${fees[180]} ${currencySymbol}\n` +
-        `🔹 <b>365 days</b> - ${fees[365]} ${currencySymbol}\n\n` +
-        `💡 Your ad will appear at the bottom of all token notifications!`;
+      const durationLabel = days => t(lang, 'duration.days_price', { days, price: `${fees[days]} ${currencySymbol}` });
+      const message = t(lang, 'footer.duration_title', {
+        emoji: chainEmoji,
+        chain: selectedChain.charAt(0).toUpperCase() + selectedChain.slice(1)
+      }) + '\n\n' +
+        [30, 60, 90, 180, 365].map(days => `🔹 ${durationLabel(days)}`).join('\n') + '\n\n' +
+        t(lang, 'footer.duration_hint');
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback(`30 days - ${fees[30]} ${currencySymbol}`, 'footer_duration_30'),
-          Markup.button.callback(`60 days - ${fees[60]} ${currencySymbol}`, 'footer_duration_60')
+          Markup.button.callback(durationLabel(30), 'footer_duration_30'),
+          Markup.button.callback(durationLabel(60), 'footer_duration_60')
         ],
         [
-          Markup.button.callback(`90 days - ${fees[90]} ${currencySymbol}`, 'footer_duration_90'),
-          Markup.button.callback(`180 days - ${fees[180]} ${currencySymbol}`, 'footer_duration_180')
+          Markup.button.callback(durationLabel(90), 'footer_duration_90'),
+          Markup.button.callback(durationLabel(180), 'footer_duration_180')
         ],
         [
-          Markup.button.callback(`365 days - ${fees[365]} ${currencySymbol}`, 'footer_duration_365')
+          Markup.button.callback(durationLabel(365), 'footer_duration_365')
         ],
         [
-          Markup.button.callback('◀️ Back to Footer Menu', 'menu_footer')
+          Markup.button.callback(t(lang, 'footer_menu.back'), 'menu_footer')
         ]
       ]);
 
@@ -5117,33 +6199,27 @@ Select trending duration:`;
       await this.sendOrEditMenu(ctx, message, keyboard);
     } catch (error) {
       logger.error('Error showing footer duration selection:', error);
-      ctx.reply('❌ Error showing duration options. Please try again.');
+      ctx.reply(t(lang, 'duration.error'));
     }
   }
 
   async showChainSelection(ctx, paymentType) {
+    const lang = await this.getLanguage(ctx);
     try {
       const session = this.getUserSession(ctx.from.id);
 
       // For footer ads, we select chain FIRST (no session needed yet)
       // For image fees, we select chain AFTER duration (session required)
       if (paymentType === 'image' && !session) {
-        return ctx.reply('❌ Session expired. Please try again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
       let message;
       if (paymentType === 'image') {
-        const durationText = `${session.duration} days`;
-
-        message = `🎨 <b>Image Fee Payment - Select Chain</b>\n\n` +
-          `📅 Duration: <b>${durationText}</b>\n\n` +
-          `🔗 Select the blockchain network:\n` +
-          `💡 Pricing varies by chain and will be shown on the payment screen.`;
+        message = t(lang, 'images.chain_title', { duration: t(lang, 'duration.days', { days: session.duration }) });
       } else {
         // Footer ads - chain selection comes first
-        message = `📢 <b>Footer Ad Payment - Select Chain</b>\n\n` +
-          `🔗 Select the blockchain network for your footer ad payment:\n\n` +
-          `💡 Duration and pricing will be shown on the next step.`;
+        message = t(lang, 'footer.chain_title');
       }
 
       // Build chain options from chainManager - all chains with payment contracts
@@ -5169,11 +6245,11 @@ Select trending duration:`;
       }
 
       // Navigation buttons
-      const backButtonText = paymentType === 'image' ? '◀️ Back to Duration Selection' : '◀️ Back to Footer Menu';
+      const backButtonText = t(lang, paymentType === 'image' ? 'duration.back' : 'footer_menu.back');
       const backButtonCallback = paymentType === 'image' ? 'buy_image_menu' : 'menu_footer';
       chainOptions.push([
         Markup.button.callback(backButtonText, backButtonCallback),
-        Markup.button.callback('🏠 Main Menu', 'main_menu')
+        Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
       ]);
 
       const keyboard = Markup.inlineKeyboard(chainOptions);
@@ -5185,43 +6261,37 @@ Select trending duration:`;
       await this.sendOrEditMenu(ctx, message, keyboard);
     } catch (error) {
       logger.error('Error showing chain selection:', error);
-      ctx.reply('❌ Error showing chain selection. Please try again.');
+      ctx.reply(t(lang, 'payment.chain_error'));
     }
   }
 
   async showContractInput(ctx, paymentType) {
+    const lang = await this.getLanguage(ctx);
     try {
       const session = this.getUserSession(ctx.from.id);
       if (!session) {
-        return ctx.reply('❌ Session expired. Please try again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
-      const durationText = `${session.duration} days`;
+      const durationText = t(lang, 'duration.days', { days: session.duration });
       // Use pre-formatted amount that already includes the correct symbol
       const amountText = session.amountFormatted || 'N/A';
-      const chainText = session.chain || 'Unknown';
-
-      const message = paymentType === 'image' ?
-        `🎨 <b>Image Fee Payment - Enter Contract</b>\n\n` +
-        `📅 Duration: <b>${durationText}</b>\n` +
-        `💰 Amount: <b>${amountText}</b>\n` +
-        `🔗 Chain: <b>${chainText.charAt(0).toUpperCase() + chainText.slice(1)}</b>\n\n` +
-        `📝 Please enter the NFT address:\n\n` +
-        `<i>Example: 0x1234567890abcdef...</i>` :
-        `📢 <b>Footer Ad Payment - Enter Contract</b>\n\n` +
-        `📅 Duration: <b>${durationText}</b>\n` +
-        `💰 Amount: <b>${amountText}</b>\n` +
-        `🔗 Chain: <b>${chainText.charAt(0).toUpperCase() + chainText.slice(1)}</b>\n\n` +
-        `📝 Please enter the NFT address:\n\n` +
-        `<i>Example: 0x1234567890abcdef...</i>`;
+      const chainText = session.chain || t(lang, 'common.unknown');
+
+      const message = t(lang, 'payment.contract_prompt', {
+        title: t(lang, paymentType === 'image' ? 'images.contract_title' : 'footer.contract_title'),
+        duration: durationText,
+        amount: amountText,
+        chain: chainText.charAt(0).toUpperCase() + chainText.slice(1)
+      });
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback('◀️ Back to Chain Selection', paymentType === 'image' ? 'back_to_chain_image' : 'back_to_chain_footer')
+          Markup.button.callback(t(lang, 'payment.back_to_chain'), paymentType === 'image' ? 'back_to_chain_image' : 'back_to_chain_footer')
         ],
         [
-          Markup.button.callback('❌ Cancel', paymentType === 'image' ? 'cancel_images' : 'cancel_footer'),
-          Markup.button.callback('🏠 Main Menu', 'main_menu')
+          Markup.button.callback(t(lang, 'common.cancel'), paymentType === 'image' ? 'cancel_images' : 'cancel_footer'),
+          Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
         ]
       ]);
 
@@ -5232,11 +6302,12 @@ Select trending duration:`;
       await this.sendOrEditMenu(ctx, message, keyboard);
     } catch (error) {
       logger.error('Error showing contract input:', error);
-      ctx.reply('❌ Error showing contract input. Please try again.');
+      ctx.reply(t(lang, 'payment.contract_input_error'));
     }
   }
 
   async handleEnhancedImageContract(ctx, contractAddress) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (contractAddress.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -5252,16 +6323,16 @@ Select trending duration:`;
       const session = this.getUserSession(ctx.from.id);
       if (!session || session.flow !== 'image_payment') {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Validate and track contract if needed
-      ctx.reply('🔍 Validating NFT address...');
+      ctx.reply(t(lang, 'validation.validating_address'));
 
       let token = await this.db.getTrackedToken(contractAddress);
       if (!token) {
@@ -5270,7 +6341,7 @@ Select trending duration:`;
         if (!result.success) {
           this.clearUserState(ctx.from.id);
           this.clearUserSession(ctx.from.id);
-          return ctx.reply(`❌ Contract validation failed: ${result.error}`);
+          return ctx.reply(t(lang, 'validation.contract_failed', { error: result.error }));
         }
         token = await this.db.getTrackedToken(contractAddress);
       }
@@ -5281,54 +6352,56 @@ Select trending duration:`;
       this.setUserSession(ctx.from.id, session);
 
       const chain = session.chain || 'ethereum';
-      const durationText = `${session.duration} days`;
+      const durationText = t(lang, 'duration.days', { days: session.duration });
 
       const instructions = await this.secureTrending.generateImagePaymentInstructions(contractAddress, user.id, session.duration, chain);
       const truncatedAddress = this.truncateAddress(instructions.contractAddress);
 
-      const message = `🎨 <b>Image Fee Payment Instructions</b>\n\n` +
-        `🎨 Collection: <b>${instructions.tokenName}</b>\n` +
-        `📮 Contract: <code>${instructions.tokenAddress}</code>\n` +
-        `📅 Duration: <b>${durationText}</b>\n` +
-        `💸 Fee: <b>${instructions.feeEth} ${instructions.symbol}</b>\n\n` +
-        `🏦 <b>Payment Address (${truncatedAddress}):</b>\n\n` +
-        `<code>${instructions.contractAddress}</code>\n\n` +
-        `📋 <b>Payment Steps:</b>\n` +
-        instructions.instructions.join('\n') + '\n\n' +
-        `After making the payment, click the button below to submit your transaction hash.`;
+      const message = t(lang, 'images.contract_instructions', {
+        name: instructions.tokenName,
+        address: instructions.tokenAddress,
+        duration: durationText,
+        fee: instructions.feeEth,
+        feeSymbol: instructions.symbol,
+        shortAddress: truncatedAddress,
+        paymentAddress: instructions.contractAddress,
+        steps: instructions.instructions.join('\n')
+      });
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback('📝 Submit Transaction Hash', 'submit_enhanced_image_tx')
+          Markup.button.callback(t(lang, 'payment.submit_tx_button'), 'submit_enhanced_image_tx')
         ],
         [
-          Markup.button.callback('◀️ Back to Contract Input', 'back_to_contract_image'),
-          Markup.button.callback('❌ Cancel', 'cancel_images')
+          Markup.button.callback(t(lang, 'payment.back_to_contract'), 'back_to_contract_image'),
+          Markup.button.callback(t(lang, 'common.cancel'), 'cancel_images')
         ]
       ]);
 
       await ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error handling enhanced image contract:', error);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async showLinkInput(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const session = this.getUserSession(ctx.from.id);
-      const chainText = session?.chain ? `🔗 Chain: <b>${session.chain.charAt(0).toUpperCase() + session.chain.slice(1)}</b>\n\n` : '';
+      const chainText = session?.chain
+        ? t(lang, 'payment.chain_line', { chain: session.chain.charAt(0).toUpperCase() + session.chain.slice(1) }) + '\n\n'
+        : '';
 
-      const message = `🔗 <b>Footer Advertisement - Custom Link</b>\n\n` +
+      const message = t(lang, 'footer.link_title') + '\n\n' +
         chainText +
-        `Please send me the custom link you want users to visit when they click your footer ad.\n\n` +
-        `<i>Example: https://mytoken.com or https://twitter.com/myproject</i>`;
+        t(lang, 'footer.link_prompt');
 
       const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('◀️ Back to Chain Selection', 'back_to_chain_footer')],
+        [Markup.button.callback(t(lang, 'payment.back_to_chain'), 'back_to_chain_footer')],
         [
-          Markup.button.callback('❌ Cancel', 'cancel_footer'),
-          Markup.button.callback('🏠 Main Menu', 'main_menu')
+          Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer'),
+          Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
         ]
       ]);
 
@@ -5336,22 +6409,20 @@ Select trending duration:`;
       await this.sendOrEditMenu(ctx, message, keyboard);
     } catch (error) {
       logger.error('Error showing link input:', error);
-      ctx.reply('❌ Error showing link input. Please try again.');
+      ctx.reply(t(lang, 'footer.link_input_error'));
     }
   }
 
   async showTickerInput(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
-      const message = `💰 <b>Footer Advertisement - Ticker Symbol</b>\n\n` +
-        `Please send me the ticker symbol you want to display in the footer.\n\n` +
-        `<i>Examples: $CANDY, $PEPE, $MYTOKEN</i>\n\n` +
-        `⭐️ Your ticker will appear as: <b>⭐️$YOURTICKER</b>`;
+      const message = t(lang, 'footer.ticker_prompt');
 
       const keyboard = Markup.inlineKeyboard([
-        [Markup.button.callback('◀️ Back to Link Input', 'back_to_link_footer')],
+        [Markup.button.callback(t(lang, 'footer.back_to_link'), 'back_to_link_footer')],
         [
-          Markup.button.callback('❌ Cancel', 'cancel_footer'),
-          Markup.button.callback('🏠 Main Menu', 'main_menu')
+          Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer'),
+          Markup.button.callback(t(lang, 'common.main_menu'), 'main_menu')
         ]
       ]);
 
@@ -5359,11 +6430,12 @@ Select trending duration:`;
       await this.sendOrEditMenu(ctx, message, keyboard);
     } catch (error) {
       logger.error('Error showing ticker input:', error);
-      ctx.reply('❌ Error showing ticker input. Please try again.');
+      ctx.reply(t(lang, 'verify.ticker_input_error'));
     }
   }
 
   async handleFooterLinkInput(ctx, customLink) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (customLink.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -5375,15 +6447,15 @@ Select trending duration:`;
       try {
         new URL(customLink);
       } catch (e) {
-        return ctx.reply('❌ Invalid URL format. Please provide a valid URL starting with http:// or https://.\n\n<i>Example: https://mytoken.com</i>', {
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]])
+        return ctx.reply(t(lang, 'footer.invalid_link'), {
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]])
         });
       }
 
       const session = this.getUserSession(ctx.from.id);
       if (!session || session.flow !== 'footer_payment') {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
       // Store link in session and proceed to ticker input
@@ -5394,11 +6466,12 @@ Select trending duration:`;
     } catch (error) {
       logger.error('Error handling footer link input:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleFooterTickerInput(ctx, tickerInput) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (tickerInput.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -5414,16 +6487,16 @@ Select trending duration:`;
 
       // Validate ticker format
       if (!ticker.match(/^\$[A-Z0-9]{1,10}$/)) {
-        return ctx.reply('❌ Invalid ticker format. Please use only letters and numbers, max 10 characters. <b>No spaces allowed.</b>\n\n<i>Examples: $CANDY, $PEPE, $MYTOKEN</i>', {
+        return ctx.reply(t(lang, 'footer.invalid_ticker'), {
           parse_mode: 'HTML',
-          reply_markup: Markup.inlineKeyboard([[Markup.button.callback('❌ Cancel', 'cancel_footer')]])
+          reply_markup: Markup.inlineKeyboard([[Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')]])
         });
       }
 
       const session = this.getUserSession(ctx.from.id);
       if (!session || session.flow !== 'footer_payment') {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
       // Store ticker in session and proceed directly to payment instructions
@@ -5434,11 +6507,12 @@ Select trending duration:`;
     } catch (error) {
       logger.error('Error handling footer ticker input:', error);
       this.clearUserState(ctx.from.id);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async handleEnhancedFooterContract(ctx, contractAddress) {
+    const lang = await this.getLanguage(ctx);
     try {
       if (contractAddress.toLowerCase() === 'cancel') {
         this.clearUserState(ctx.from.id);
@@ -5454,16 +6528,16 @@ Select trending duration:`;
       const session = this.getUserSession(ctx.from.id);
       if (!session || session.flow !== 'footer_payment') {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
-        return ctx.reply('Please start the bot first with /startminty');
+        return ctx.reply(t(lang, 'common.start_first'));
       }
 
       // Validate and track contract if needed
-      ctx.reply('🔍 Validating NFT address...');
+      ctx.reply(t(lang, 'validation.validating_address'));
 
       let token = await this.db.getTrackedToken(contractAddress);
       if (!token) {
@@ -5472,7 +6546,7 @@ Select trending duration:`;
         if (!result.success) {
           this.clearUserState(ctx.from.id);
           this.clearUserSession(ctx.from.id);
-          return ctx.reply(`❌ Contract validation failed: ${result.error}`);
+          return ctx.reply(t(lang, 'validation.contract_failed', { error: result.error }));
         }
         token = await this.db.getTrackedToken(contractAddress);
       }
@@ -5483,57 +6557,58 @@ Select trending duration:`;
       this.setUserSession(ctx.from.id, session);
 
       const chain = session.chain || 'ethereum';
-      const durationText = `${session.duration} days`;
+      const durationText = t(lang, 'duration.days', { days: session.duration });
 
       const instructions = await this.secureTrending.generateFooterPaymentInstructions(contractAddress, user.id, session.duration, chain);
       const truncatedAddress = this.truncateAddress(instructions.contractAddress);
 
-      const message = `📢 <b>Footer Advertisement Payment Instructions</b>\n\n` +
-        `🎨 Collection: <b>${instructions.tokenName}</b>\n` +
-        `📮 Contract: <code>${instructions.tokenAddress}</code>\n` +
-        `🔗 Custom Link: ${session.customLink}\n` +
-        `⭐️ Ticker: <b>${session.tickerSymbol}</b>\n` +
-        `📅 Duration: <b>${durationText}</b>\n` +
-        `💸 Fee: <b>${instructions.feeEth} ${instructions.symbol}</b>\n\n` +
-        `🏦 <b>Payment Address (${truncatedAddress}):</b>\n\n` +
-        `<code>${instructions.contractAddress}</code>\n\n` +
-        `📋 <b>Payment Steps:</b>\n` +
-        instructions.instructions.join('\n') + '\n\n' +
-        `After making the payment, click the button below to submit your transaction hash.`;
+      const message = t(lang, 'footer.contract_instructions', {
+        name: instructions.tokenName,
+        address: instructions.tokenAddress,
+        link: session.customLink,
+        ticker: session.tickerSymbol,
+        duration: durationText,
+        fee: instructions.feeEth,
+        feeSymbol: instructions.symbol,
+        shortAddress: truncatedAddress,
+        paymentAddress: instructions.contractAddress,
+        steps: instructions.instructions.join('\n')
+      });
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback('📝 Submit Transaction Hash', 'submit_enhanced_footer_tx')
+          Markup.button.callback(t(lang, 'payment.submit_tx_button'), 'submit_enhanced_footer_tx')
         ],
         [
-          Markup.button.callback('◀️ Back to Contract Input', 'back_to_contract_footer'),
-          Markup.button.callback('❌ Cancel', 'cancel_footer')
+          Markup.button.callback(t(lang, 'payment.back_to_contract'), 'back_to_contract_footer'),
+          Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')
         ]
       ]);
 
       await ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error handling enhanced footer contract:', error);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
   async showFooterPaymentInstructions(ctx) {
+    const lang = await this.getLanguage(ctx);
     try {
       const session = this.getUserSession(ctx.from.id);
       if (!session || session.flow !== 'footer_payment') {
         this.clearUserState(ctx.from.id);
-        return ctx.reply('❌ Session expired. Please start again.');
+        return ctx.reply(t(lang, 'common.session_expired'));
       }
 
       const user = await this.db.getUser(ctx.from.id.toString());
       if (!user) {
         this.clearUserState(ctx.from.id);
         this.clearUserSession(ctx.from.id);
-        return ctx.reply('❌ Please register first using /start');
+        return ctx.reply(t(lang, 'common.register_first'));
       }
 
-      const durationText = `${session.duration} days`;
+      const durationText = t(lang, 'duration.days', { days: session.duration });
       // Use pre-formatted amount that handles all chain types (Solana lamports, Bitcoin sats, EVM wei)
       // session.amountFormatted already includes the symbol (e.g., "479.4 SOL")
       const amountText = session.amountFormatted || '0 ETH';
@@ -5545,36 +6620,32 @@ Select trending duration:`;
       const chainEmoji = chainConfig ? chainConfig.emoji : '🔷';
       const truncatedAddress = this.truncateAddress(paymentContract);
 
-      const message = `📢 <b>Footer Advertisement Payment Instructions</b>\n\n` +
-        `🔗 Custom Link: ${session.customLink}\n` +
-        `⭐️ Ticker: <b>${session.tickerSymbol}</b>\n` +
-        `📅 Duration: <b>${durationText}</b>\n` +
-        `💸 Fee: <b>${amountText}</b>\n` +
-        `${chainEmoji} Chain: <b>${chainDisplay}</b>\n\n` +
-        `🏦 <b>Payment Address (${truncatedAddress}):</b>\n\n` +
-        `<code>${paymentContract}</code>\n\n` +
-        `📋 <b>Payment Steps:</b>\n` +
-        `1. <b>SEND EXACTLY ${amountText}</b> to the payment address above\n` +
-        `2. Use any ${chainDisplay} wallet on ${chainDisplay.toLowerCase()} network\n` +
-        `3. Tap the address above to copy it\n` +
-        `4. Wait for transaction confirmation\n` +
-        `5. Submit your transaction hash below\n\n` +
-        `After making the payment, click the button below to submit your transaction hash.`;
+      const message = t(lang, 'footer.payment_instructions', {
+        link: session.customLink,
+        ticker: session.tickerSymbol,
+        duration: durationText,
+        amount: amountText,
+        emoji: chainEmoji,
+        chain: chainDisplay,
+        network: chainDisplay.toLowerCase(),
+        shortAddress: truncatedAddress,
+        paymentAddress: paymentContract
+      });
 
       const keyboard = Markup.inlineKeyboard([
         [
-          Markup.button.callback('📝 Submit Transaction Hash', 'submit_enhanced_footer_tx')
+          Markup.button.callback(t(lang, 'payment.submit_tx_button'), 'submit_enhanced_footer_tx')
         ],
         [
-          Markup.button.callback('◀️ Back to Ticker Input', 'back_to_ticker_footer'),
-          Markup.button.callback('❌ Cancel', 'cancel_footer')
+          Markup.button.callback(t(lang, 'footer.back_to_ticker'), 'back_to_ticker_footer'),
+          Markup.button.callback(t(lang, 'common.cancel'), 'cancel_footer')
         ]
       ]);
 
       await ctx.replyWithHTML(message, keyboard);
     } catch (error) {
       logger.error('Error showing footer payment instructions:', error);
-      ctx.reply('❌ An error occurred. Please try again.');
+      ctx.reply(t(lang, 'common.error'));
     }
   }
 
@@ -1,5 +1,6 @@
 const { Markup } = require('telegraf');
 const logger = require('../services/logger');
+const { t, DEFAULT_LANGUAGE } = require('../i18n');
 
 /**
  * Bot Command Helpers
@@ -16,11 +17,12 @@ const logger = require('../services/logger');
  * @param {Object} ctx - Telegram context
  * @param {Error} error - Error object
  * @param {string} context - Context description (e.g., "validate command")
- * @param {Object} options - Options for error handling
+ * @param {Object} options - Options for error handling (lang picks the default message's language)
  */
 async function handleCommandError(ctx, error, context, options = {}) {
   const {
-    userMessage = '❌ An error occurred. Please try again.',
+    lang = DEFAULT_LANGUAGE,
+    userMessage = t(lang, 'common.error'),
     clearState = false,
     clearSession = false,
     userId = null
@@ -50,52 +52,57 @@ async function handleCommandError(ctx, error, context, options = {}) {
 
 /**
  * Build main menu keyboard
+ * @param {string} lang - Language code
  * @returns {Object} Telegraf inline keyboard
  */
-function buildMainMenuKeyboard() {
+function buildMainMenuKeyboard(lang = DEFAULT_LANGUAGE) {
   return Markup.inlineKeyboard([
     [
-      Markup.button.callback('📊 Manage NFTs', 'menu_tokens'),
-      Markup.button.callback('🔥 Trending & Boost', 'menu_trending')
+      Markup.button.callback(t(lang, 'menu.manage_nfts'), 'menu_tokens'),
+      Markup.button.callback(t(lang, 'menu.trending'), 'menu_trending')
     ],
     [
-      Markup.button.callback('🖼️ Display NFT Image', 'menu_images'),
-      Markup.button.callback('🔗 Buy Footer Ads', 'menu_footer')
+      Markup.button.callback(t(lang, 'menu.images'), 'menu_images'),
+      Markup.button.callback(t(lang, 'menu.footer'), 'menu_footer')
     ],
     [
-      Markup.button.callback('📺 Channel Settings', 'menu_channels'),
-      Markup.button.callback('✅ Verify Payments', 'menu_verify')
+      Markup.button.callback(t(lang, 'menu.channels'), 'menu_channels'),
+      Markup.button.callback(t(lang, 'menu.verify'), 'menu_verify')
     ],
     [
-      Markup.button.callback('❓ Help & Contact', 'help_contact')
+      Markup.button.callback(t(lang, 'menu.help'), 'help_contact'),
+      Markup.button.callback(t(lang, 'menu.language'), 'menu_language')
     ]
   ]);
 }
 
 /**
  * Build back to main menu button
+ * @param {string} lang - Language code
  * @returns {Array} Inline keyboard row with back button
  */
-function buildBackToMainButton() {
-  return [Markup.button.callback('◀️ Back to Main Menu', 'main_menu')];
+function buildBackToMainButton(lang = DEFAULT_LANGUAGE) {
+  return [Markup.button.callback(t(lang, 'common.back_main'), 'main_menu')];
 }
 
 /**
  * Build back to menu button (generic)
- * @param {string} menuName - Menu name to return to
+ * @param {string} menuName - Menu name to return to (already translated)
  * @param {string} callbackData - Callback data for button
+ * @param {string} lang - Language code
  * @returns {Array} Inline keyboard row with back button
  */
-function buildBackButton(menuName, callbackData) {
-  return [Markup.button.callback(`◀️ Back to ${menuName}`, callbackData)];
+function buildBackButton(menuName, callbackData, lang = DEFAULT_LANGUAGE) {
+  return [Markup.button.callback(t(lang, 'common.back_to', { menu: menuName }), callbackData)];
 }
 
 /**
  * Build duration selection keyboard
  * @param {string} type - Type of purchase ('trending', 'image', 'footer')
+ * @param {string} lang - Language code
  * @returns {Object} Telegraf inline keyboard
  */
-function buildDurationKeyboard(type) {
+function buildDurationKeyboard(type, lang = DEFAULT_LANGUAGE) {
   const prefix = type === 'trending' ? 'trending_duration' :
                  type === 'image' ? 'image_duration' :
                  'footer_duration';
@@ -104,25 +111,25 @@ function buildDurationKeyboard(type) {
 
   if (type === 'trending') {
     buttons.push([
-      Markup.button.callback('⏱️ 24 hours - 0.005 ETH', `${prefix}_24`),
-      Markup.button.callback('🕐 48 hours - 0.01 ETH', `${prefix}_48`)
+      Markup.button.callback(`⏱️ ${t(lang, 'duration.hours_price', { hours: 24, price: '0.005 ETH' })}`, `${prefix}_24`),
+      Markup.button.callback(`🕐 ${t(lang, 'duration.hours_price', { hours: 48, price: '0.01 ETH' })}`, `${prefix}_48`)
     ]);
     buttons.push([
-      Markup.button.callback('📅 72 hours - 0.015 ETH', `${prefix}_72`),
-      Markup.button.callback('🔥 7 days - 0.05 ETH', `${prefix}_168`)
+      Markup.button.callback(`📅 ${t(lang, 'duration.hours_price', { hours: 72, price: '0.015 ETH' })}`, `${prefix}_72`),
+      Markup.button.callback(`🔥 ${t(lang, 'duration.days_price', { days: 7, price: '0.05 ETH' })}`, `${prefix}_168`)
     ]);
   } else {
     // Image and Footer durations (in days)
     buttons.push([
-      Markup.button.callback('📅 30 days', `${prefix}_30`),
-      Markup.button.callback('📆 60 days', `${prefix}_60`)
+      Markup.button.callback(`📅 ${t(lang, 'duration.days', { days: 30 })}`, `${prefix}_30`),
+      Markup.button.callback(`📆 ${t(lang, 'duration.days', { days: 60 })}`, `${prefix}_60`)
     ]);
     buttons.push([
-      Markup.button.callback('🗓️ 90 days', `${prefix}_90`)
+      Markup.button.callback(`🗓️ ${t(lang, 'duration.days', { days: 90 })}`, `${prefix}_90`)
     ]);
   }
 
-  buttons.push(buildBackToMainButton());
+  buttons.push(buildBackToMainButton(lang));
   return Markup.inlineKeyboard(buttons);
 }
 
@@ -167,9 +174,10 @@ function buildPaymentChainKeyboard(chainManager, type) {
  * @param {Array} tokens - Array of tracked tokens
  * @param {number} page - Current page number
  * @param {number} perPage - Tokens per page
+ * @param {string} lang - Language code
  * @returns {Object} Telegraf inline keyboard
  */
-function buildTokenSelectionKeyboard(tokens, page = 0, perPage = 5) {
+function buildTokenSelectionKeyboard(tokens, page = 0, perPage = 5, lang = DEFAULT_LANGUAGE) {
   const keyboard = [];
   const start = page * perPage;
   const end = Math.min(start + perPage, tokens.length);
@@ -178,10 +186,10 @@ function buildTokenSelectionKeyboard(tokens, page = 0, perPage = 5) {
   // Add token buttons
   pageTokens.forEach(token => {
     const displayName = token.token_name || token.collection_slug ||
-                        (token.contract_address ? `${token.contract_address.substring(0, 8)}...` : 'Unknown');
+                        (token.contract_address ? `${token.contract_address.substring(0, 8)}...` : t(lang, 'common.unknown'));
     keyboard.push([
       Markup.button.callback(
-        `🗑️ Remove ${displayName}`,
+        t(lang, 'tokens.remove_button', { name: displayName }),
         `remove_token_${token.id}`
       )
     ]);
@@ -192,16 +200,16 @@ function buildTokenSelectionKeyboard(tokens, page = 0, perPage = 5) {
   if (totalPages > 1) {
     const paginationRow = [];
     if (page > 0) {
-      paginationRow.push(Markup.button.callback('⬅️ Previous', `tokens_page_${page - 1}`));
+      paginationRow.push(Markup.button.callback(t(lang, 'common.previous'), `tokens_page_${page - 1}`));
     }
     paginationRow.push(Markup.button.callback(`📄 ${page + 1}/${totalPages}`, 'noop'));
     if (page < totalPages - 1) {
-      paginationRow.push(Markup.button.callback('➡️ Next', `tokens_page_${page + 1}`));
+      paginationRow.push(Markup.button.callback(t(lang, 'common.next'), `tokens_page_${page + 1}`));
     }
     keyboard.push(paginationRow);
   }
 
-  keyboard.push(buildBackToMainButton());
+  keyboard.push(buildBackToMainButton(lang));
   return Markup.inlineKeyboard(keyboard);
 }
 
@@ -211,57 +219,29 @@ function buildTokenSelectionKeyboard(tokens, page = 0, perPage = 5) {
 
 /**
  * Format welcome message
+ * @param {string} lang - Language code
  * @returns {string} HTML formatted welcome message
  */
-function formatWelcomeMessage() {
-  return `🚀 <b>Welcome to Minty Rush!</b> 🚀
-
-I help you track NFT collections and get real-time alerts for:
-• New mints and transfers
-• Sales and price updates
-• Trending collections
-• Custom NFT monitoring
-
-<b>Get started by choosing from the menu below:</b>`;
+function formatWelcomeMessage(lang = DEFAULT_LANGUAGE) {
+  return t(lang, 'welcome.message');
 }
 
 /**
  * Format help message
+ * @param {string} lang - Language code
  * @returns {string} HTML formatted help message
  */
-function formatHelpMessage() {
-  return `📋 <b>Minty RushBot Commands</b>
-
-🎯 <b>NFT Management:</b>
-• /add_token - Add NFT collection to track
-• /remove_token - Remove tracked NFT
-• /my_tokens - View your tracked NFTs
-
-💰 <b>Trending &amp; Boost:</b>
-• /trending - View trending collections
-• /buy_trending - Boost NFT trending
-• /validate &lt;txhash&gt; - Validate trending payment
-• /buy_image &lt;contract&gt; - Pay fee for real NFT images
-• /validate_image &lt;contract&gt; &lt;txhash&gt; - Validate image fee
-• /buy_footer &lt;contract&gt; - Pay fee for footer advertisement
-• /validate_footer &lt;contract&gt; &lt;txhash&gt; &lt;link&gt; - Validate footer ad
-
-📺 <b>Channel Commands:</b>
-• /add_channel - Add bot to channel
-• /channel_settings - Configure channel alerts
-
-• /startminty - Welcome message
-• /help - Show this help
-
-Simple and focused - boost your NFTs easily! 🚀`;
+function formatHelpMessage(lang = DEFAULT_LANGUAGE) {
+  return t(lang, 'help.message');
 }
 
 /**
  * Format payment instructions message
  * @param {Object} paymentInfo - Payment information object
+ * @param {string} lang - Language code
  * @returns {string} HTML formatted payment instructions
  */
-function formatPaymentInstructions(paymentInfo) {
+function formatPaymentInstructions(paymentInfo, lang = DEFAULT_LANGUAGE) {
   const {
     chainName,
     chainEmoji,
@@ -273,26 +253,17 @@ function formatPaymentInstructions(paymentInfo) {
     type
   } = paymentInfo;
 
-  const typeDisplay = type === 'trending' ? 'Trending Boost' :
-                      type === 'image' ? 'NFT Image Display' :
-                      'Footer Advertisement';
-
-  return `${chainEmoji} <b>${typeDisplay} Payment Instructions</b>
-
-<b>Network:</b> ${chainDisplay}
-<b>Amount:</b> ${amount} ${symbol}
-<b>Duration:</b> ${duration}
+  const typeDisplay = t(lang, `payment.type.${type === 'trending' || type === 'image' ? type : 'footer'}`);
 
-<b>Send payment to:</b>
-<code>${address}</code>
-
-<b>⚠️ Important:</b>
-• Send EXACTLY ${amount} ${symbol}
-• Use ${chainDisplay} network
-• After payment, use /validate to verify
-• Payment expires in 30 minutes
-
-Need help? Contact @YourSupportBot`;
+  return t(lang, 'payment.instructions', {
+    emoji: chainEmoji,
+    type: typeDisplay,
+    network: chainDisplay,
+    amount,
+    symbol,
+    duration,
+    address
+  });
 }
 
 /**
@@ -309,25 +280,35 @@ function truncateAddress(address, startChars = 6, endChars = 4) {
   return `${address.substring(0, startChars)}...${address.substring(address.length - endChars)}`;
 }
 
+/**
+ * Escape text for HTML parse mode
+ * @param {string} text - Raw text
+ * @returns {string} Escaped text
+ */
+function escapeHtml(text) {
+  return String(text ?? '').replace(/&/g, '&amp;').replace(/</g, '&lt;').replace(/>/g, '&gt;');
+}
+
 /**
  * Format token list message
  * @param {Array} tokens - Array of tracked tokens
  * @param {string} chainName - Chain name (optional)
+ * @param {string} lang - Language code
  * @returns {string} Formatted token list message
  */
-function formatTokenList(tokens, chainName = null) {
+function formatTokenList(tokens, chainName = null, lang = DEFAULT_LANGUAGE) {
   if (tokens.length === 0) {
     return chainName
-      ? `🔍 You haven't added any NFTs on ${chainName} yet!\n\nUse /add_token to start tracking NFT collections.`
-      : `🔍 You haven't added any tokens yet!\n\nUse /add_token to start tracking NFT collections.`;
+      ? t(lang, 'tokens.none_on_chain', { chain: chainName })
+      : t(lang, 'tokens.none');
   }
 
   let message = chainName
-    ? `📊 <b>Your Tracked NFTs on ${chainName}</b>\n\n`
-    : `📊 <b>Your Tracked NFTs</b>\n\n`;
+    ? `${t(lang, 'tokens.title_chain', { chain: chainName })}\n\n`
+    : `${t(lang, 'tokens.title')}\n\n`;
 
   tokens.forEach((token, index) => {
-    const name = token.token_name || token.collection_slug || 'Unknown';
+    const name = token.token_name || token.collection_slug || t(lang, 'common.unknown');
     const address = truncateAddress(token.contract_address);
     const chain = token.chain_name || 'ethereum';
     message += `${index + 1}. <b>${name}</b>\n`;
@@ -461,6 +442,7 @@ module.exports = {
   formatPaymentInstructions,
   formatTokenList,
   truncateAddress,
+  escapeHtml,
 
   // Validation
   validateContractAddress,
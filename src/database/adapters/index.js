@@ -0,0 +1,26 @@
+const PostgresAdapter = require('./postgresAdapter');
+const SqliteAdapter = require('./sqliteAdapter');
+
+/**
+ * Pick the storage backend from the environment.
+ *
+ * DATABASE_CLIENT=postgres|sqlite selects explicitly; otherwise Postgres is used when
+ * DATABASE_URL is set and the embedded SQLite file at DATABASE_PATH when it is not.
+ */
+function createAdapter(env = process.env) {
+  const client = (env.DATABASE_CLIENT || (env.DATABASE_URL ? 'postgres' : 'sqlite')).toLowerCase();
+
+  switch (client) {
+    case 'postgres':
+    case 'postgresql':
+    case 'pg':
+      return new PostgresAdapter(env.DATABASE_URL);
+    case 'sqlite':
+    case 'sqlite3':
+      return new SqliteAdapter(env.DATABASE_PATH || './database.sqlite');
+    default:
+      throw new Error(`Unsupported DATABASE_CLIENT: ${client} (expected postgres or sqlite)`);
+  }
+}
+
+module.exports = { createAdapter, PostgresAdapter, SqliteAdapter };
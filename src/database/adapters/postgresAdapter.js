@@ -0,0 +1,105 @@
+const { Pool } = require('pg');
+const logger = require('../../services/logger');
+
+/**
+ * PostgreSQL storage adapter (Neon / any Postgres reachable via DATABASE_URL)
+ *
+ * SQL throughout the codebase is written in the Postgres dialect, so queries pass
+ * through unchanged.
+ */
+class PostgresAdapter {
+  constructor(connectionString) {
+    this.dialect = 'postgres';
+    this.connectionString = connectionString;
+    this.pool = null;
+  }
+
+  describe() {
+    return this.connectionString?.split('@')[1]?.split('?')[0] || 'database';
+  }
+
+  async connect() {
+    if (!this.connectionString) {
+      throw new Error('DATABASE_URL not found in environment variables');
+    }
+
+    // Create PostgreSQL connection pool with Neon-specific settings
+    this.pool = new Pool({
+      connectionString: this.connectionString,
+      ssl: {
+        rejectUnauthorized: false
+      },
+      max: 10, // Maximum number of clients in pool (reduced for Neon)
+      idleTimeoutMillis: 30000, // Close idle clients after 30 seconds
+      connectionTimeoutMillis: 60000, // Increased timeout for Neon (60 seconds)
+      query_timeout: 60000, // Query timeout
+      keepAlive: true,
+      keepAliveInitialDelayMillis: 10000,
+      statement_timeout: 60000 // Statement timeout
+    });
+
+    try {
+      // Test connection with timeout
+      const connectionPromise = (async () => {
+        const client = await this.pool.connect();
+        try {
+          await client.query('SELECT NOW()');
+        } finally {
+          client.release();
+        }
+      })();
+
+      // Race between connection and timeout
+      const timeoutPromise = new Promise((_, reject) => {
+        setTimeout(() => reject(new Error('Connection timeout')), 60000);
+      });
+
+      await Promise.race([connectionPromise, timeoutPromise]);
+    } catch (error) {
+      // Close any partially created pool
+      await this.close().catch(() => {});
+      throw error;
+    }
+  }
+
+  async query(text, params = []) {
+    const client = await this.pool.connect();
+    try {
+      const result = await client.query(text, params);
+      return { rows: result.rows, rowCount: result.rowCount };
+    } finally {
+      client.release();
+    }
+  }
+
+  async transaction(work) {
+    const client = await this.pool.connect();
+    const query = async (text, params = []) => {
+      const result = await client.query(text, params);
+      return { rows: result.rows, rowCount: result.rowCount };
+    };
+
+    try {
+      await client.query('BEGIN');
+      const result = await work(query);
+      await client.query('COMMIT');
+      return result;
+    } catch (error) {
+      await client.query('ROLLBACK').catch(() => {});
+      throw error;
+    } finally {
+      client.release();
+    }
+  }
+
+  async close() {
+    if (this.pool) {
+      const pool = this.pool;
+      this.pool = null;
+      await pool.end();
+      logger.info('PostgreSQL connection pool closed');
+    }
+  }
+}
+
+module.exports = PostgresAdapter;
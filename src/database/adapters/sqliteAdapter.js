@@ -0,0 +1,181 @@
+const fs = require('fs');
+const path = require('path');
+const logger = require('../../services/logger');
+
+// ISO-8601 with milliseconds, same format as Date.prototype.toISOString(), so
+// timestamps written by SQL defaults and by JS compare correctly as text
+const ISO_NOW = "strftime('%Y-%m-%dT%H:%M:%fZ', 'now')";
+
+/**
+ * Embedded SQLite storage adapter (single file at DATABASE_PATH)
+ *
+ * Queries are written in the Postgres dialect and translated here:
+ * - `$1` placeholders become numbered `?1` parameters
+ * - NOW() becomes an ISO-8601 strftime() expression
+ * - SERIAL / TIMESTAMP WITH TIME ZONE column types are mapped to SQLite types
+ * - ALTER TABLE ... ADD/DROP COLUMN IF [NOT] EXISTS is emulated via PRAGMA table_info
+ * - ALTER TABLE ... ADD/DROP CONSTRAINT is skipped (SQLite only supports constraints
+ *   at CREATE TABLE time, and createTables() already declares the current ones)
+ */
+class SqliteAdapter {
+  constructor(filename) {
+    this.dialect = 'sqlite';
+    this.filename = filename || './database.sqlite';
+    this.db = null;
+    this.transactionLock = null;
+  }
+
+  describe() {
+    return path.resolve(this.filename);
+  }
+
+  async connect() {
+    const sqlite3 = require('sqlite3');
+
+    if (this.filename !== ':memory:') {
+      fs.mkdirSync(path.dirname(path.resolve(this.filename)), { recursive: true });
+    }
+
+    this.db = await new Promise((resolve, reject) => {
+      const db = new sqlite3.Database(this.filename, (error) => {
+        if (error) reject(error);
+        else resolve(db);
+      });
+    });
+
+    this.db.configure('busyTimeout', 10000);
+    await this.execute('PRAGMA journal_mode = WAL');
+    await this.execute('PRAGMA foreign_keys = ON');
+  }
+
+  translate(sql) {
+    return sql
+      .replace(/\bSERIAL PRIMARY KEY\b/gi, 'INTEGER PRIMARY KEY AUTOINCREMENT')
+      .replace(/\bTIMESTAMP WITH TIME ZONE\b/gi, 'TEXT')
+      .replace(/\bDEFAULT NOW\(\)/gi, `DEFAULT (${ISO_NOW})`)
+      .replace(/\bNOW\(\)/gi, ISO_NOW)
+      .replace(/\$(\d+)/g, '?$1');
+  }
+
+  normalizeParams(params) {
+    return params.map(param => {
+      if (param instanceof Date) return param.toISOString();
+      if (param === undefined) return null;
+      return param;
+    });
+  }
+
+  returnsRows(sql) {
+    return /^\s*(SELECT|WITH|PRAGMA|VALUES)\b/i.test(sql) || /\bRETURNING\b/i.test(sql);
+  }
+
+  raw(method, sql, params = []) {
+    return new Promise((resolve, reject) => {
+      if (method === 'all') {
+        this.db.all(sql, params, (error, rows) => {
+          if (error) reject(error);
+          else resolve({ rows, rowCount: rows.length });
+        });
+      } else {
+        this.db.run(sql, params, function (error) {
+          if (error) reject(error);
+          else resolve({ rows: [], rowCount: this.changes });
+        });
+      }
+    });
+  }
+
+  async columnExists(table, column) {
+    const { rows } = await this.raw('all', `PRAGMA table_info(${table})`);
+    return rows.some(row => row.name.toLowerCase() === column.toLowerCase());
+  }
+
+  /**
+   * Emulate Postgres ALTER TABLE forms that SQLite does not support.
+   * Returns null when the statement can be run as-is.
+   */
+  async alterTable(sql) {
+    const match = sql.match(/^\s*ALTER TABLE\s+(\w+)\s+([\s\S]+?)\s*;?\s*$/i);
+    if (!match || !/IF (NOT )?EXISTS|CONSTRAINT|,/i.test(match[2])) {
+      return null;
+    }
+
+    const [, table, body] = match;
+    const clauses = body.split(/,\s*(?=(?:ADD|DROP|RENAME)\b)/i);
+    let rowCount = 0;
+
+    for (const clause of clauses) {
+      const addColumn = clause.match(/^ADD COLUMN\s+(IF NOT EXISTS\s+)?(\w+)\s+([\s\S]+)$/i);
+      const dropColumn = clause.match(/^DROP COLUMN\s+(IF EXISTS\s+)?(\w+)$/i);
+
+      if (/^(ADD|DROP) CONSTRAINT\b/i.test(clause)) {
+        logger.debug(`SQLite: skipping unsupported constraint change on ${table}: ${clause.trim()}`);
+      } else if (addColumn) {
+        const [, ifNotExists, column, definition] = addColumn;
+        if (ifNotExists && await this.columnExists(table, column)) continue;
+        await this.raw('run', `ALTER TABLE ${table} ADD COLUMN ${column} ${definition.trim()}`);
+        rowCount++;
+      } else if (dropColumn) {
+        const [, ifExists, column] = dropColumn;
+        if (ifExists && !await this.columnExists(table, column)) continue;
+        await this.raw('run', `ALTER TABLE ${table} DROP COLUMN ${column}`);
+        rowCount++;
+      } else {
+        await this.raw('run', `ALTER TABLE ${table} ${clause.trim()}`);
+        rowCount++;
+      }
+    }
+
+    return { rows: [], rowCount };
+  }
+
+  async execute(text, params = []) {
+    const sql = this.translate(text);
+
+    const altered = await this.alterTable(sql);
+    if (altered) return altered;
+
+    return this.raw(this.returnsRows(sql) ? 'all' : 'run', sql, this.normalizeParams(params));
+  }
+
+  async query(text, params = []) {
+    // A single connection is shared, so wait for any open transaction to finish
+    while (this.transactionLock) {
+      await this.transactionLock;
+    }
+    return this.execute(text, params);
+  }
+
+  async transaction(work) {
+    while (this.transactionLock) {
+      await this.transactionLock;
+    }
+
+    let release;
+    this.transactionLock = new Promise(resolve => { release = resolve; });
+
+    try {
+      await this.execute('BEGIN IMMEDIATE');
+      const result = await work((text, params = []) => this.execute(text, params));
+      await this.execute('COMMIT');
+      return result;
+    } catch (error) {
+      await this.execute('ROLLBACK').catch(() => {});
+      throw error;
+    } finally {
+      this.transactionLock = null;
+      release();
+    }
+  }
+
+  async close() {
+    if (this.db) {
+      const db = this.db;
+      this.db = null;
+      await new Promise((resolve, reject) => db.close(error => (error ? reject(error) : resolve())));
+      logger.info('SQLite database closed');
+    }
+  }
+}
+
+module.exports = SqliteAdapter;
@@ -1,10 +1,18 @@
-const { Pool } = require('pg');
 const logger = require('../services/logger');
+const Migrator = require('./migrator');
+const { createAdapter } = require('./adapters');
 
 class Database {
-  constructor() {
-    this.pool = null;
-    this.dbUrl = process.env.DATABASE_URL;
+  constructor(options = {}) {
+    // Storage backend: Postgres (DATABASE_URL) or embedded SQLite (DATABASE_PATH)
+    this.adapter = options.adapter || createAdapter();
+    this.autoMigrate = options.autoMigrate ?? process.env.DB_AUTO_MIGRATE !== 'false';
+    this.migrator = new Migrator(this);
+  }
+
+  // SQL dialect of the active storage backend ('postgres' or 'sqlite')
+  get dialect() {
+    return this.adapter.dialect;
   }
 
   async initialize(maxRetries = 3, retryDelayMs = 5000) {
@@ -12,52 +20,17 @@ class Database {
 
     for (let attempt = 1; attempt <= maxRetries; attempt++) {
       try {
-        if (!this.dbUrl) {
-          throw new Error('DATABASE_URL not found in environment variables');
-        }
-
-        const dbHost = this.dbUrl.split('@')[1]?.split('?')[0] || 'database';
+        const target = this.adapter.describe();
 
         if (attempt > 1) {
-          logger.info(`Retry attempt ${attempt}/${maxRetries} for PostgreSQL connection...`);
+          logger.info(`Retry attempt ${attempt}/${maxRetries} for ${this.dialect} connection...`);
         } else {
-          logger.info(`Attempting PostgreSQL connection to: ${dbHost}`);
+          logger.info(`Attempting ${this.dialect} connection to: ${target}`);
         }
 
-        // Create PostgreSQL connection pool with Neon-specific settings
-        this.pool = new Pool({
-          connectionString: this.dbUrl,
-          ssl: {
-            rejectUnauthorized: false
-          },
-          max: 10, // Maximum number of clients in pool (reduced for Neon)
-          idleTimeoutMillis: 30000, // Close idle clients after 30 seconds
-          connectionTimeoutMillis: 60000, // Increased timeout for Neon (60 seconds)
-          query_timeout: 60000, // Query timeout
-          keepAlive: true,
-          keepAliveInitialDelayMillis: 10000,
-          statement_timeout: 60000 // Statement timeout
-        });
+        await this.adapter.connect();
 
-        // Test connection with timeout
-        const connectionPromise = (async () => {
-          const client = await this.pool.connect();
-          try {
-            await client.query('SELECT NOW()');
-            return client;
-          } finally {
-            client.release();
-          }
-        })();
-
-        // Race between connection and timeout
-        const timeoutPromise = new Promise((_, reject) => {
-          setTimeout(() => reject(new Error('Connection timeout')), 60000);
-        });
-
-        await Promise.race([connectionPromise, timeoutPromise]);
-
-        logger.info(`✅ Connected to PostgreSQL database: ${this.dbUrl.split('@')[1]?.split('/')[0] || 'database'}`);
+        logger.info(`✅ Connected to ${this.dialect} database: ${target}`);
 
         await this.createTables();
         return true;
@@ -65,32 +38,28 @@ class Database {
       } catch (error) {
         lastError = error;
 
-        // Close any partially created pool
-        if (this.pool) {
-          try {
-            await this.pool.end();
-            this.pool = null;
-          } catch (closeError) {
-            // Ignore errors when closing failed connection
-          }
-        }
+        // Close any partially opened connection
+        await this.adapter.close().catch(() => {});
+
+        const isNetworkError = this.dialect === 'postgres' &&
+          (error.code === 'ECONNRESET' || error.code === 'ECONNREFUSED' || error.message.includes('timeout'));
 
         if (attempt < maxRetries) {
           logger.warn(`Connection attempt ${attempt} failed: ${error.message}`);
           logger.info(`Waiting ${retryDelayMs}ms before retry...`);
 
           // Special handling for VPN-related errors
-          if (error.code === 'ECONNRESET' || error.code === 'ECONNREFUSED' || error.message.includes('timeout')) {
+          if (isNetworkError) {
             logger.warn('⚠️  Network error detected. If using VPN, ensure split tunneling is configured.');
             logger.warn('⚠️  Run: sudo ./setup-vpn-split-tunnel.sh');
           }
 
           await new Promise(resolve => setTimeout(resolve, retryDelayMs));
         } else {
-          logger.error(`Failed to connect to PostgreSQL database after ${maxRetries} attempts:`, error);
+          logger.error(`Failed to connect to ${this.dialect} database after ${maxRetries} attempts:`, error);
 
           // Provide helpful error message
-          if (error.code === 'ECONNRESET' || error.code === 'ECONNREFUSED') {
+          if (this.dialect === 'postgres' && (error.code === 'ECONNRESET' || error.code === 'ECONNREFUSED')) {
             logger.error('');
             logger.error('━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━');
             logger.error('💡 VPN TROUBLESHOOTING:');
@@ -382,141 +351,14 @@ class Database {
   }
 
   async migrateDatabase() {
-    try {
-      // PostgreSQL doesn't need the same migrations as SQLite since we're creating fresh schema
-      // This method is kept for future migrations
-
-      // Migration: Add last_activity_check column for Bitcoin Ordinals polling
-      try {
-        await this.query(`
-          ALTER TABLE tracked_tokens
-          ADD COLUMN IF NOT EXISTS last_activity_check TIMESTAMP WITH TIME ZONE
-        `);
-        logger.info('✅ Migration: Added last_activity_check column to tracked_tokens');
-      } catch (error) {
-        if (!error.message.includes('already exists')) {
-          logger.warn('Migration warning for last_activity_check column:', error.message);
-        }
-      }
-
-      // Migration: Fix chain_name for existing Bitcoin tokens
-      try {
-        const result = await this.query(`
-          UPDATE tracked_tokens
-          SET chain_name = 'bitcoin'
-          WHERE marketplace = 'magiceden'
-          AND (chain_name IS NULL OR chain_name = '' OR chain_name = 'ethereum')
-          AND (contract_address NOT LIKE '0x%')
-        `);
-        if (result.rowCount > 0) {
-          logger.info(`✅ Migration: Updated chain_name to 'bitcoin' for ${result.rowCount} Bitcoin Ordinals tokens`);
-        }
-      } catch (error) {
-        logger.warn('Migration warning for Bitcoin chain_name fix:', error.message);
-      }
-
-      // Migration: Add tier column to trending_payments
-      try {
-        await this.query(`
-          ALTER TABLE trending_payments
-          ADD COLUMN IF NOT EXISTS tier VARCHAR(50) DEFAULT 'normal'
-        `);
-        logger.info('✅ Migration: Added tier column to trending_payments');
-      } catch (error) {
-        if (!error.message.includes('already exists')) {
-          logger.warn('Migration warning for trending_payments tier column:', error.message);
-        }
-      }
-
-      // Migration: Add trending_tier column to channels
-      try {
-        await this.query(`
-          ALTER TABLE channels
-          ADD COLUMN IF NOT EXISTS trending_tier VARCHAR(50) DEFAULT 'normal'
-        `);
-        logger.info('✅ Migration: Added trending_tier column to channels');
-      } catch (error) {
-        if (!error.message.includes('already exists')) {
-          logger.warn('Migration warning for channels trending_tier column:', error.message);
-        }
-      }
-
-      // Migration: Create index for tier queries
-      try {
-        await this.query(`
-          CREATE INDEX IF NOT EXISTS idx_trending_payments_tier
-          ON trending_payments(tier, is_active, end_time)
-        `);
-        logger.info('✅ Migration: Created index for trending_payments tier queries');
-      } catch (error) {
-        if (!error.message.includes('already exists')) {
-          logger.warn('Migration warning for tier index:', error.message);
-        }
-      }
-
-      // Migration: Update channels trending_tier constraint to include 'none'
-      try {
-        // Drop old constraint if exists
-        await this.query(`
-          ALTER TABLE channels
-          DROP CONSTRAINT IF EXISTS channels_trending_tier_check
-        `);
-        // Add new constraint with 'none' included
-        await this.query(`
-          ALTER TABLE channels
-          ADD CONSTRAINT channels_trending_tier_check
-          CHECK (trending_tier IN ('none', 'normal', 'premium', 'both'))
-        `);
-        logger.info('✅ Migration: Updated channels trending_tier constraint to include none');
-      } catch (error) {
-        if (!error.message.includes('already exists')) {
-          logger.warn('Migration warning for channels tier constraint:', error.message);
-        }
-      }
-
-      // Migration: Add group link columns to trending_payments
-      try {
-        await this.query(`
-          ALTER TABLE trending_payments
-          ADD COLUMN IF NOT EXISTS group_link TEXT,
-          ADD COLUMN IF NOT EXISTS group_username VARCHAR(255)
-        `);
-        logger.info('✅ Migration: Added group link columns to trending_payments');
-      } catch (error) {
-        if (!error.message.includes('already exists')) {
-          logger.warn('Migration warning for group link columns:', error.message);
-        }
-      }
-
-      // Migration: Set 'none' tier channels to 'normal' to enable broadcasts
-      try {
-        const result = await this.query(`
-          UPDATE channels
-          SET trending_tier = 'normal'
-          WHERE trending_tier = 'none'
-        `);
-        if (result.rowCount > 0) {
-          logger.info(`✅ Migration: Set ${result.rowCount} channels from 'none' to 'normal' tier to enable trending broadcasts`);
-        }
-      } catch (error) {
-        logger.warn('Migration warning for channel tier restore:', error.message);
-      }
-
-      // Migration: Convert 'both' tier to 'normal' to prevent duplicate broadcasts
-      try {
-        const result = await this.query(`
-          UPDATE channels
-          SET trending_tier = 'normal'
-          WHERE trending_tier = 'both'
-        `);
-        if (result.rowCount > 0) {
-          logger.info(`✅ Migration: Converted ${result.rowCount} channels from 'both' to 'normal' tier to prevent duplicate broadcasts`);
-        }
-      } catch (error) {
-        logger.warn('Migration warning for both tier conversion:', error.message);
-      }
+    if (!this.autoMigrate) {
+      logger.info('Automatic migrations disabled, skipping (run: npm run migrate)');
+      return;
+    }
 
-      logger.info('Database migration completed');
+    try {
+      const applied = await this.migrator.migrate();
+      logger.info(`Database migration completed (${applied.length} applied)`);
     } catch (error) {
       logger.error('Error during database migration:', error);
       throw error;
@@ -524,13 +366,7 @@ class Database {
   }
 
   async query(text, params = []) {
-    const client = await this.pool.connect();
-    try {
-      const result = await client.query(text, params);
-      return result;
-    } finally {
-      client.release();
-    }
+    return await this.adapter.query(text, params);
   }
 
   async run(sql, params = []) {
@@ -548,6 +384,26 @@ class Database {
     return result.rows;
   }
 
+  /**
+   * Run work on a single connection inside BEGIN/COMMIT, rolling back on error
+   * @param {Function} work - async (tx) => result, where tx exposes query/run/get/all
+   */
+  async transaction(work) {
+    return await this.adapter.transaction(async (query) => {
+      const tx = {
+        dialect: this.dialect,
+        query,
+        run: async (sql, params = []) => {
+          const result = await query(sql, params);
+          return { id: result.rows[0]?.id, changes: result.rowCount };
+        },
+        get: async (sql, params = []) => (await query(sql, params)).rows[0],
+        all: async (sql, params = []) => (await query(sql, params)).rows
+      };
+      return await work(tx);
+    });
+  }
+
   async createUser(telegramId, username, firstName) {
     const sql = `INSERT INTO users (telegram_id, username, first_name)
                  VALUES ($1, $2, $3)
@@ -577,7 +433,7 @@ class Database {
                    token_symbol = EXCLUDED.token_symbol,
                    token_type = EXCLUDED.token_type,
                    total_supply = EXCLUDED.total_supply,
-                   webhook_id = EXCLUDED.webhook_id,
+                   webhook_id = COALESCE(EXCLUDED.webhook_id, tracked_tokens.webhook_id),
                    opensea_subscription_id = EXCLUDED.opensea_subscription_id,
                    helius_webhook_id = EXCLUDED.helius_webhook_id,
                    marketplace = EXCLUDED.marketplace,
@@ -649,7 +505,7 @@ class Database {
   }
 
   async getUserTrackedTokens(userId, chatId, chainName = null) {
-    let sql = `SELECT tt.*, us.notification_enabled
+    let sql = `SELECT tt.*, us.notification_enabled, us.id as subscription_id
                FROM tracked_tokens tt
                JOIN user_subscriptions us ON tt.id = us.token_id
                WHERE us.user_id = $1 AND us.chat_id = $2 AND tt.is_active = true AND (us.notification_enabled = true OR us.notification_enabled IS NULL)`;
@@ -666,10 +522,10 @@ class Database {
   }
 
   async getGroupTrackedTokens(chatId, chainName = null) {
-    let sql = `SELECT DISTINCT ON (tt.id) tt.*, true as notification_enabled
+    let sql = `SELECT tt.*, true as notification_enabled
                FROM tracked_tokens tt
-               JOIN user_subscriptions us ON tt.id = us.token_id
-               WHERE us.chat_id = $1 AND tt.is_active = true`;
+               WHERE tt.is_active = true
+               AND EXISTS (SELECT 1 FROM user_subscriptions us WHERE us.token_id = tt.id AND us.chat_id = $1)`;
 
     const params = [chatId];
 
@@ -678,7 +534,7 @@ class Database {
       params.push(chainName);
     }
 
-    sql += ` ORDER BY tt.id, tt.created_at DESC`;
+    sql += ` ORDER BY tt.id`;
     return await this.all(sql, params);
   }
 
@@ -700,7 +556,7 @@ class Database {
    * Used for DM view to show all tokens (private + all groups)
    */
   async getUserTrackedTokensWithContext(userId) {
-    const sql = `SELECT tt.*, us.notification_enabled, us.chat_id
+    const sql = `SELECT tt.*, us.notification_enabled, us.chat_id, us.id as subscription_id
                  FROM tracked_tokens tt
                  JOIN user_subscriptions us ON tt.id = us.token_id
                  WHERE us.user_id = $1 AND tt.is_active = true
@@ -736,6 +592,40 @@ class Database {
     return { changes: result.rowCount };
   }
 
+  /**
+   * Subscription with its token, only if it belongs to the user
+   */
+  async getUserSubscription(subscriptionId, userId) {
+    const sql = `SELECT us.*, tt.token_name, tt.contract_address, tt.chain_name, tt.collection_slug
+                 FROM user_subscriptions us
+                 JOIN tracked_tokens tt ON tt.id = us.token_id
+                 WHERE us.id = $1 AND us.user_id = $2`;
+    return await this.get(sql, [subscriptionId, userId]);
+  }
+
+  /**
+   * Store alert filters (columns from subscriptionFilters.toSubscriptionColumns)
+   */
+  async updateSubscriptionFilters(subscriptionId, columns) {
+    const sql = `UPDATE user_subscriptions
+                 SET alert_event_types = $2, min_price = $3, min_price_unit = $4,
+                     marketplace_filter_mode = $5, marketplace_filter = $6, ignore_burns = $7,
+                     snipe_discount = $8, trait_filter = $9
+                 WHERE id = $1`;
+    const result = await this.query(sql, [
+      subscriptionId,
+      columns.alert_event_types,
+      columns.min_price,
+      columns.min_price_unit,
+      columns.marketplace_filter_mode,
+      columns.marketplace_filter,
+      columns.ignore_burns,
+      columns.snipe_discount,
+      columns.trait_filter
+    ]);
+    return { changes: result.rowCount };
+  }
+
   async unsubscribeUserFromAllChats(userId, tokenId) {
     const sql = 'DELETE FROM user_subscriptions WHERE user_id = $1 AND token_id = $2';
     const result = await this.query(sql, [userId, tokenId]);
@@ -1171,6 +1061,51 @@ class Database {
     return { id: result.rows[0]?.id };
   }
 
+  async getWebhookSigningKeys(webhookId) {
+    const sql = `SELECT webhook_signing_key, webhook_previous_signing_key, webhook_key_rotated_at
+                 FROM tracked_tokens
+                 WHERE webhook_id = $1 AND webhook_signing_key IS NOT NULL
+                 ORDER BY webhook_key_rotated_at DESC
+                 LIMIT 1`;
+    return await this.get(sql, [webhookId]);
+  }
+
+  /**
+   * Set the signing key for an Alchemy webhook. The current key becomes the previous
+   * key, which stays valid for a grace period so in-flight deliveries aren't rejected.
+   */
+  async setWebhookSigningKey(webhookId, signingKey) {
+    const sql = `UPDATE tracked_tokens
+                 SET webhook_previous_signing_key = webhook_signing_key,
+                     webhook_signing_key = $2,
+                     webhook_key_rotated_at = NOW(),
+                     updated_at = NOW()
+                 WHERE webhook_id = $1`;
+    const result = await this.query(sql, [webhookId, signingKey]);
+    return { updated: result.rowCount };
+  }
+
+  async assignWebhookToToken(contractAddress, chainName, webhookId) {
+    // Tokens sharing a webhook share its keys
+    const keys = await this.getWebhookSigningKeys(webhookId);
+    const sql = `UPDATE tracked_tokens
+                 SET webhook_id = $3,
+                     webhook_signing_key = $4,
+                     webhook_previous_signing_key = $5,
+                     webhook_key_rotated_at = $6,
+                     updated_at = NOW()
+                 WHERE LOWER(contract_address) = LOWER($1) AND chain_name = $2`;
+    const result = await this.query(sql, [
+      contractAddress,
+      chainName,
+      webhookId,
+      keys?.webhook_signing_key || null,
+      keys?.webhook_previous_signing_key || null,
+      keys?.webhook_key_rotated_at || null
+    ]);
+    return { updated: result.rowCount };
+  }
+
   async addImageFeePayment(userId, contractAddress, paymentAmount, transactionHash, payerAddress, durationDays = 30) {
     const endTime = new Date(Date.now() + (durationDays * 24 * 60 * 60 * 1000)).toISOString();
     const sql = `INSERT INTO image_fee_payments
@@ -1410,12 +1345,9 @@ class Database {
 
   async close() {
     try {
-      if (this.pool) {
-        await this.pool.end();
-        logger.info('PostgreSQL connection pool closed');
-      }
+      await this.adapter.close();
     } catch (error) {
-      logger.error('Error closing PostgreSQL pool:', error);
+      logger.error(`Error closing ${this.dialect} database:`, error);
     }
   }
 }
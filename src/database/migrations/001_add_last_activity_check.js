@@ -0,0 +1,13 @@
+// Bitcoin Ordinals poller cursor: last time activities were fetched for a collection
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE tracked_tokens
+      ADD COLUMN IF NOT EXISTS last_activity_check TIMESTAMP WITH TIME ZONE
+    `);
+  },
+
+  async down(db) {
+    await db.query('ALTER TABLE tracked_tokens DROP COLUMN IF EXISTS last_activity_check');
+  }
+};
@@ -0,0 +1,16 @@
+// Ordinals collections added before multi-chain support were stored with chain_name 'ethereum'
+module.exports = {
+  async up(db) {
+    await db.query(`
+      UPDATE tracked_tokens
+      SET chain_name = 'bitcoin'
+      WHERE marketplace = 'magiceden'
+      AND (chain_name IS NULL OR chain_name = '' OR chain_name = 'ethereum')
+      AND (contract_address NOT LIKE '0x%')
+    `);
+  },
+
+  async down() {
+    // Data fix only - the original (wrong) chain names are not recoverable
+  }
+};
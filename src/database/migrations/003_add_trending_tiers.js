@@ -0,0 +1,22 @@
+// Normal/premium trending tiers for payments and channel broadcast preferences
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE trending_payments
+      ADD COLUMN IF NOT EXISTS tier VARCHAR(50) DEFAULT 'normal'
+    `);
+    await db.query(`
+      ALTER TABLE channels
+      ADD COLUMN IF NOT EXISTS trending_tier VARCHAR(50) DEFAULT 'normal'
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_trending_payments_tier
+      ON trending_payments(tier, is_active, end_time)
+    `);
+  },
+
+  async down(db) {
+    // tier columns are part of the base schema in createTables(), only the index is dropped
+    await db.query('DROP INDEX IF EXISTS idx_trending_payments_tier');
+  }
+};
@@ -0,0 +1,21 @@
+// Allow channels to opt out of trending broadcasts with trending_tier = 'none'
+module.exports = {
+  async up(db) {
+    await db.query('ALTER TABLE channels DROP CONSTRAINT IF EXISTS channels_trending_tier_check');
+    await db.query(`
+      ALTER TABLE channels
+      ADD CONSTRAINT channels_trending_tier_check
+      CHECK (trending_tier IN ('none', 'normal', 'premium', 'both'))
+    `);
+  },
+
+  async down(db) {
+    await db.query(`UPDATE channels SET trending_tier = 'normal' WHERE trending_tier = 'none'`);
+    await db.query('ALTER TABLE channels DROP CONSTRAINT IF EXISTS channels_trending_tier_check');
+    await db.query(`
+      ALTER TABLE channels
+      ADD CONSTRAINT channels_trending_tier_check
+      CHECK (trending_tier IN ('normal', 'premium', 'both'))
+    `);
+  }
+};
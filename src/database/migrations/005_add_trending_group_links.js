@@ -0,0 +1,14 @@
+// Group link shown as a clickable ticker in premium trending broadcasts
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE trending_payments
+      ADD COLUMN IF NOT EXISTS group_link TEXT,
+      ADD COLUMN IF NOT EXISTS group_username VARCHAR(255)
+    `);
+  },
+
+  async down() {
+    // group link columns are part of the base schema in createTables()
+  }
+};
@@ -0,0 +1,15 @@
+// 'none' channels never received broadcasts and 'both' channels received duplicates;
+// move both to 'normal'
+module.exports = {
+  async up(db) {
+    await db.query(`
+      UPDATE channels
+      SET trending_tier = 'normal'
+      WHERE trending_tier IN ('none', 'both')
+    `);
+  },
+
+  async down() {
+    // Data fix only - previous tier choices are not recorded
+  }
+};
@@ -0,0 +1,24 @@
+// Persisted conversation state for multi-step bot flows (see services/sessionStore.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS bot_sessions (
+        namespace VARCHAR(50) NOT NULL,
+        session_key VARCHAR(255) NOT NULL,
+        value TEXT NOT NULL,
+        expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+        updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        PRIMARY KEY (namespace, session_key)
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_bot_sessions_expires
+      ON bot_sessions(expires_at)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_bot_sessions_expires');
+    await db.query('DROP TABLE IF EXISTS bot_sessions');
+  }
+};
@@ -0,0 +1,22 @@
+// Shared deduplication keys for incoming webhook/stream events (see services/dedupStore.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS processed_events (
+        event_key VARCHAR(512) PRIMARY KEY,
+        source VARCHAR(50) NOT NULL,
+        expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_processed_events_expires
+      ON processed_events(expires_at)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_processed_events_expires');
+    await db.query('DROP TABLE IF EXISTS processed_events');
+  }
+};
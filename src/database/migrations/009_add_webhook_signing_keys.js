@@ -0,0 +1,25 @@
+// Alchemy webhook signing keys, stored next to tracked_tokens.webhook_id (previous key kept for rotation)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE tracked_tokens
+      ADD COLUMN IF NOT EXISTS webhook_signing_key VARCHAR(255),
+      ADD COLUMN IF NOT EXISTS webhook_previous_signing_key VARCHAR(255),
+      ADD COLUMN IF NOT EXISTS webhook_key_rotated_at TIMESTAMP WITH TIME ZONE
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_tracked_tokens_webhook_id
+      ON tracked_tokens(webhook_id)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_tracked_tokens_webhook_id');
+    await db.query(`
+      ALTER TABLE tracked_tokens
+      DROP COLUMN IF EXISTS webhook_signing_key,
+      DROP COLUMN IF EXISTS webhook_previous_signing_key,
+      DROP COLUMN IF EXISTS webhook_key_rotated_at
+    `);
+  }
+};
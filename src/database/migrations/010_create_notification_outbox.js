@@ -0,0 +1,37 @@
+// Durable outbox for Telegram alerts, delivered by services/notificationOutbox.js
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS notification_outbox (
+        id SERIAL PRIMARY KEY,
+        chat_id VARCHAR(255) NOT NULL,
+        method VARCHAR(50) NOT NULL DEFAULT 'sendPhoto',
+        payload TEXT NOT NULL,
+        recipient_type VARCHAR(20),
+        recipient_id VARCHAR(255),
+        cleanup_files TEXT,
+        status VARCHAR(20) NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'sending', 'sent', 'dead')),
+        attempts INTEGER NOT NULL DEFAULT 0,
+        next_attempt_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        locked_until TIMESTAMP WITH TIME ZONE,
+        last_error TEXT,
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        sent_at TIMESTAMP WITH TIME ZONE
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_notification_outbox_due
+      ON notification_outbox(status, next_attempt_at)
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_notification_outbox_chat
+      ON notification_outbox(chat_id, status, id)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_notification_outbox_chat');
+    await db.query('DROP INDEX IF EXISTS idx_notification_outbox_due');
+    await db.query('DROP TABLE IF EXISTS notification_outbox');
+  }
+};
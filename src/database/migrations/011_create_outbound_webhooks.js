@@ -0,0 +1,55 @@
+// HTTPS endpoints registered by group admins and their signed delivery queue (services/outboundWebhookService.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS outbound_webhooks (
+        id SERIAL PRIMARY KEY,
+        token_id INTEGER NOT NULL REFERENCES tracked_tokens(id) ON DELETE CASCADE,
+        chat_id VARCHAR(255) NOT NULL,
+        created_by_user_id INTEGER,
+        url TEXT NOT NULL,
+        secret VARCHAR(128) NOT NULL,
+        event_types TEXT,
+        is_active BOOLEAN DEFAULT true,
+        failure_count INTEGER NOT NULL DEFAULT 0,
+        last_success_at TIMESTAMP WITH TIME ZONE,
+        last_failure_at TIMESTAMP WITH TIME ZONE,
+        last_error TEXT,
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        UNIQUE(token_id, chat_id, url)
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_outbound_webhooks_token
+      ON outbound_webhooks(token_id, is_active)
+    `);
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS outbound_webhook_deliveries (
+        id SERIAL PRIMARY KEY,
+        webhook_id INTEGER NOT NULL REFERENCES outbound_webhooks(id) ON DELETE CASCADE,
+        event_id VARCHAR(64) NOT NULL,
+        event_type VARCHAR(50) NOT NULL,
+        payload TEXT NOT NULL,
+        status VARCHAR(20) NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'sending', 'delivered', 'dead')),
+        attempts INTEGER NOT NULL DEFAULT 0,
+        next_attempt_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        locked_until TIMESTAMP WITH TIME ZONE,
+        last_status_code INTEGER,
+        last_error TEXT,
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        delivered_at TIMESTAMP WITH TIME ZONE
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_outbound_webhook_deliveries_due
+      ON outbound_webhook_deliveries(status, next_attempt_at)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_outbound_webhook_deliveries_due');
+    await db.query('DROP TABLE IF EXISTS outbound_webhook_deliveries');
+    await db.query('DROP INDEX IF EXISTS idx_outbound_webhooks_token');
+    await db.query('DROP TABLE IF EXISTS outbound_webhooks');
+  }
+};
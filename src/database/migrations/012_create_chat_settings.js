@@ -0,0 +1,17 @@
+// Per-chat alert preferences (services/chatSettingsService.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS chat_settings (
+        chat_id VARCHAR(255) PRIMARY KEY,
+        sweep_mode VARCHAR(20) NOT NULL DEFAULT 'aggregated' CHECK (sweep_mode IN ('aggregated', 'individual')),
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP TABLE IF EXISTS chat_settings');
+  }
+};
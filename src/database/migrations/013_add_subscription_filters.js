@@ -0,0 +1,26 @@
+// Alert filters per subscription: event types, minimum price, marketplaces, burns (services/subscriptionFilters.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE user_subscriptions
+      ADD COLUMN IF NOT EXISTS alert_event_types TEXT,
+      ADD COLUMN IF NOT EXISTS min_price VARCHAR(64),
+      ADD COLUMN IF NOT EXISTS min_price_unit VARCHAR(10),
+      ADD COLUMN IF NOT EXISTS marketplace_filter_mode VARCHAR(10),
+      ADD COLUMN IF NOT EXISTS marketplace_filter TEXT,
+      ADD COLUMN IF NOT EXISTS ignore_burns BOOLEAN DEFAULT false
+    `);
+  },
+
+  async down(db) {
+    await db.query(`
+      ALTER TABLE user_subscriptions
+      DROP COLUMN IF EXISTS alert_event_types,
+      DROP COLUMN IF EXISTS min_price,
+      DROP COLUMN IF EXISTS min_price_unit,
+      DROP COLUMN IF EXISTS marketplace_filter_mode,
+      DROP COLUMN IF EXISTS marketplace_filter,
+      DROP COLUMN IF EXISTS ignore_burns
+    `);
+  }
+};
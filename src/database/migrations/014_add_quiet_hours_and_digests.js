@@ -0,0 +1,44 @@
+// Per-chat quiet hours and digest mode, plus the buffer of held alerts (services/digestService.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE chat_settings
+      ADD COLUMN IF NOT EXISTS quiet_hours_start INTEGER,
+      ADD COLUMN IF NOT EXISTS quiet_hours_end INTEGER,
+      ADD COLUMN IF NOT EXISTS timezone VARCHAR(64) DEFAULT 'UTC',
+      ADD COLUMN IF NOT EXISTS digest_interval_minutes INTEGER
+    `);
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS digest_events (
+        id SERIAL PRIMARY KEY,
+        chat_id VARCHAR(255) NOT NULL,
+        token_id INTEGER NOT NULL REFERENCES tracked_tokens(id) ON DELETE CASCADE,
+        recipient_type VARCHAR(20),
+        recipient_id VARCHAR(255),
+        reason VARCHAR(10) NOT NULL CHECK (reason IN ('quiet', 'digest')),
+        event_type VARCHAR(20) NOT NULL,
+        payload TEXT NOT NULL,
+        usd_value DOUBLE PRECISION,
+        floor_price DOUBLE PRECISION,
+        floor_currency VARCHAR(20),
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_digest_events_chat
+      ON digest_events(chat_id, token_id, id)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_digest_events_chat');
+    await db.query('DROP TABLE IF EXISTS digest_events');
+    await db.query(`
+      ALTER TABLE chat_settings
+      DROP COLUMN IF EXISTS quiet_hours_start,
+      DROP COLUMN IF EXISTS quiet_hours_end,
+      DROP COLUMN IF EXISTS timezone,
+      DROP COLUMN IF EXISTS digest_interval_minutes
+    `);
+  }
+};
@@ -0,0 +1,20 @@
+// Custom alert templates per chat and event type (services/messageTemplateService.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS message_templates (
+        chat_id VARCHAR(255) NOT NULL,
+        event_type VARCHAR(20) NOT NULL,
+        template TEXT NOT NULL,
+        updated_by VARCHAR(255),
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        PRIMARY KEY (chat_id, event_type)
+      )
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP TABLE IF EXISTS message_templates');
+  }
+};
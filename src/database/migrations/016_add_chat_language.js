@@ -0,0 +1,16 @@
+// Language of a chat's menus and alerts (src/i18n), null when never chosen
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE chat_settings
+      ADD COLUMN IF NOT EXISTS language VARCHAR(10)
+    `);
+  },
+
+  async down(db) {
+    await db.query(`
+      ALTER TABLE chat_settings
+      DROP COLUMN IF EXISTS language
+    `);
+  }
+};
@@ -0,0 +1,38 @@
+// Wallet watchlists per chat and the provider webhooks that deliver their activity (services/walletWatchService.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS watched_wallets (
+        id SERIAL PRIMARY KEY,
+        chat_id VARCHAR(255) NOT NULL,
+        chat_type VARCHAR(20),
+        chain_name VARCHAR(50) NOT NULL,
+        address VARCHAR(255) NOT NULL,
+        label VARCHAR(64),
+        added_by VARCHAR(255),
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        UNIQUE(chat_id, chain_name, address)
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_watched_wallets_address
+      ON watched_wallets(chain_name, address)
+    `);
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS wallet_webhooks (
+        provider VARCHAR(20) NOT NULL,
+        network VARCHAR(50) NOT NULL,
+        webhook_id VARCHAR(255) NOT NULL,
+        signing_key TEXT,
+        updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        PRIMARY KEY (provider, network)
+      )
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP TABLE IF EXISTS wallet_webhooks');
+    await db.query('DROP INDEX IF EXISTS idx_watched_wallets_address');
+    await db.query('DROP TABLE IF EXISTS watched_wallets');
+  }
+};
@@ -0,0 +1,76 @@
+// Floor/volume time series and per-chat threshold alerts (services/statsAlertService.js), plus the stats columns written by CollectionStatsService.updateTokenStats
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE tracked_tokens
+      ADD COLUMN IF NOT EXISTS volume_24h VARCHAR(64),
+      ADD COLUMN IF NOT EXISTS floor_price_24h VARCHAR(64),
+      ADD COLUMN IF NOT EXISTS volume_change_24h VARCHAR(64),
+      ADD COLUMN IF NOT EXISTS sales_24h INTEGER,
+      ADD COLUMN IF NOT EXISTS volume_diff_24h VARCHAR(64),
+      ADD COLUMN IF NOT EXISTS sales_diff_24h INTEGER,
+      ADD COLUMN IF NOT EXISTS average_price_24h VARCHAR(64),
+      ADD COLUMN IF NOT EXISTS market_cap VARCHAR(64),
+      ADD COLUMN IF NOT EXISTS floor_price_symbol VARCHAR(20),
+      ADD COLUMN IF NOT EXISTS stats_updated_at TIMESTAMP WITH TIME ZONE
+    `);
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS collection_stats (
+        id SERIAL PRIMARY KEY,
+        token_id INTEGER NOT NULL REFERENCES tracked_tokens(id) ON DELETE CASCADE,
+        source VARCHAR(20) NOT NULL,
+        floor_price DOUBLE PRECISION,
+        volume_24h DOUBLE PRECISION,
+        volume_total DOUBLE PRECISION,
+        sales_24h INTEGER,
+        listed_count INTEGER,
+        currency VARCHAR(20),
+        recorded_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_collection_stats_token
+      ON collection_stats(token_id, recorded_at)
+    `);
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS stats_alerts (
+        id SERIAL PRIMARY KEY,
+        chat_id VARCHAR(255) NOT NULL,
+        chat_type VARCHAR(20),
+        token_id INTEGER NOT NULL REFERENCES tracked_tokens(id) ON DELETE CASCADE,
+        metric VARCHAR(10) NOT NULL CHECK (metric IN ('floor', 'volume')),
+        condition VARCHAR(10) NOT NULL CHECK (condition IN ('below', 'above', 'change')),
+        threshold DOUBLE PRECISION NOT NULL,
+        window_minutes INTEGER,
+        created_by VARCHAR(255),
+        is_triggered BOOLEAN DEFAULT false,
+        last_triggered_at TIMESTAMP WITH TIME ZONE,
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_stats_alerts_token
+      ON stats_alerts(token_id)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_stats_alerts_token');
+    await db.query('DROP TABLE IF EXISTS stats_alerts');
+    await db.query('DROP INDEX IF EXISTS idx_collection_stats_token');
+    await db.query('DROP TABLE IF EXISTS collection_stats');
+    await db.query(`
+      ALTER TABLE tracked_tokens
+      DROP COLUMN IF EXISTS volume_24h,
+      DROP COLUMN IF EXISTS floor_price_24h,
+      DROP COLUMN IF EXISTS volume_change_24h,
+      DROP COLUMN IF EXISTS sales_24h,
+      DROP COLUMN IF EXISTS volume_diff_24h,
+      DROP COLUMN IF EXISTS sales_diff_24h,
+      DROP COLUMN IF EXISTS average_price_24h,
+      DROP COLUMN IF EXISTS market_cap,
+      DROP COLUMN IF EXISTS floor_price_symbol,
+      DROP COLUMN IF EXISTS stats_updated_at
+    `);
+  }
+};
@@ -0,0 +1,16 @@
+// Below-floor listing snipe mode per subscription: minimum discount under the floor in percent, NULL = off (services/subscriptionFilters.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE user_subscriptions
+      ADD COLUMN IF NOT EXISTS snipe_discount DOUBLE PRECISION
+    `);
+  },
+
+  async down(db) {
+    await db.query(`
+      ALTER TABLE user_subscriptions
+      DROP COLUMN IF EXISTS snipe_discount
+    `);
+  }
+};
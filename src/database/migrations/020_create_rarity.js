@@ -0,0 +1,29 @@
+// Rarity snapshots of tracked collections: statistical rank and trait counts per item (services/rarityService.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS collection_rarity (
+        token_id INTEGER PRIMARY KEY REFERENCES tracked_tokens(id) ON DELETE CASCADE,
+        source VARCHAR(20) NOT NULL,
+        item_count INTEGER NOT NULL,
+        trait_type_count INTEGER NOT NULL,
+        refreshed_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS nft_rarity (
+        token_id INTEGER NOT NULL REFERENCES tracked_tokens(id) ON DELETE CASCADE,
+        nft_id VARCHAR(255) NOT NULL,
+        rank INTEGER NOT NULL,
+        score DOUBLE PRECISION NOT NULL,
+        traits TEXT,
+        PRIMARY KEY (token_id, nft_id)
+      )
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP TABLE IF EXISTS nft_rarity');
+    await db.query('DROP TABLE IF EXISTS collection_rarity');
+  }
+};
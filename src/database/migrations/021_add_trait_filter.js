@@ -0,0 +1,16 @@
+// Trait filter per subscription: "Type: Value" entries separated by semicolons, NULL = every item (services/subscriptionFilters.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE user_subscriptions
+      ADD COLUMN IF NOT EXISTS trait_filter TEXT
+    `);
+  },
+
+  async down(db) {
+    await db.query(`
+      ALTER TABLE user_subscriptions
+      DROP COLUMN IF EXISTS trait_filter
+    `);
+  }
+};
@@ -0,0 +1,21 @@
+// Hourly USD price candles per asset (CoinGecko ID) for valuing events at their own time (services/priceHistoryService.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS price_candles (
+        asset VARCHAR(64) NOT NULL,
+        hour TIMESTAMP WITH TIME ZONE NOT NULL,
+        open DOUBLE PRECISION NOT NULL,
+        high DOUBLE PRECISION NOT NULL,
+        low DOUBLE PRECISION NOT NULL,
+        close DOUBLE PRECISION NOT NULL,
+        source VARCHAR(20) NOT NULL,
+        PRIMARY KEY (asset, hour)
+      )
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP TABLE IF EXISTS price_candles');
+  }
+};
@@ -0,0 +1,25 @@
+// Sales waiting for their sweep window to close (services/sweepAggregator.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS pending_sweep_sales (
+        id SERIAL PRIMARY KEY,
+        batch_key VARCHAR(255) NOT NULL,
+        token_id INTEGER NOT NULL REFERENCES tracked_tokens(id) ON DELETE CASCADE,
+        payload TEXT NOT NULL,
+        flush_at TIMESTAMP WITH TIME ZONE NOT NULL,
+        locked_until TIMESTAMP WITH TIME ZONE,
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_pending_sweep_sales_batch
+      ON pending_sweep_sales(batch_key)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_pending_sweep_sales_batch');
+    await db.query('DROP TABLE IF EXISTS pending_sweep_sales');
+  }
+};
@@ -0,0 +1,26 @@
+// Incoming webhook payloads acknowledged but not yet processed (services/webhookInbox.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      CREATE TABLE IF NOT EXISTS webhook_inbox (
+        id SERIAL PRIMARY KEY,
+        source VARCHAR(50) NOT NULL,
+        payload TEXT NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        next_attempt_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+        locked_until TIMESTAMP WITH TIME ZONE,
+        last_error TEXT,
+        created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+    await db.query(`
+      CREATE INDEX IF NOT EXISTS idx_webhook_inbox_due
+      ON webhook_inbox(next_attempt_at, id)
+    `);
+  },
+
+  async down(db) {
+    await db.query('DROP INDEX IF EXISTS idx_webhook_inbox_due');
+    await db.query('DROP TABLE IF EXISTS webhook_inbox');
+  }
+};
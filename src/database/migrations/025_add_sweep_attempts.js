@@ -0,0 +1,16 @@
+// Delivery attempts of a held sweep batch, so a batch that keeps failing is eventually dropped (services/sweepAggregator.js)
+module.exports = {
+  async up(db) {
+    await db.query(`
+      ALTER TABLE pending_sweep_sales
+      ADD COLUMN IF NOT EXISTS attempts INTEGER NOT NULL DEFAULT 0
+    `);
+  },
+
+  async down(db) {
+    await db.query(`
+      ALTER TABLE pending_sweep_sales
+      DROP COLUMN IF EXISTS attempts
+    `);
+  }
+};
@@ -0,0 +1,186 @@
+const fs = require('fs');
+const path = require('path');
+const crypto = require('crypto');
+const logger = require('../services/logger');
+
+const MIGRATIONS_DIR = path.join(__dirname, 'migrations');
+const MIGRATION_FILE_PATTERN = /^(\d+)_([a-z0-9_]+)\.js$/;
+
+/**
+ * Versioned schema migrations
+ *
+ * Each file in src/database/migrations is named `<version>_<name>.js` and exports
+ * async `up(db)` and `down(db)` functions. Applied versions are recorded in
+ * `schema_migrations` together with a checksum of the file, so an edited migration
+ * is detected instead of silently diverging between environments.
+ */
+class Migrator {
+  constructor(database, migrationsDir = MIGRATIONS_DIR) {
+    this.db = database;
+    this.migrationsDir = migrationsDir;
+  }
+
+  async ensureMigrationsTable() {
+    await this.db.query(`
+      CREATE TABLE IF NOT EXISTS schema_migrations (
+        version INTEGER PRIMARY KEY,
+        name VARCHAR(255) NOT NULL,
+        checksum VARCHAR(64) NOT NULL,
+        applied_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+      )
+    `);
+  }
+
+  /**
+   * Load migration files from disk, sorted by version
+   * @returns {Array<{version: number, name: string, checksum: string, up: Function, down: Function}>}
+   */
+  loadMigrations() {
+    const files = fs.readdirSync(this.migrationsDir)
+      .filter(file => MIGRATION_FILE_PATTERN.test(file));
+
+    const migrations = files.map(file => {
+      const [, version, name] = file.match(MIGRATION_FILE_PATTERN);
+      const filePath = path.join(this.migrationsDir, file);
+      const source = fs.readFileSync(filePath, 'utf8');
+      const migration = require(filePath);
+
+      if (typeof migration.up !== 'function' || typeof migration.down !== 'function') {
+        throw new Error(`Migration ${file} must export up() and down() functions`);
+      }
+
+      return {
+        version: parseInt(version, 10),
+        name,
+        file,
+        checksum: crypto.createHash('sha256').update(source).digest('hex'),
+        up: migration.up,
+        down: migration.down
+      };
+    }).sort((a, b) => a.version - b.version);
+
+    for (let i = 1; i < migrations.length; i++) {
+      if (migrations[i].version === migrations[i - 1].version) {
+        throw new Error(`Duplicate migration version ${migrations[i].version}: ${migrations[i - 1].file}, ${migrations[i].file}`);
+      }
+    }
+
+    return migrations;
+  }
+
+  async getAppliedMigrations() {
+    await this.ensureMigrationsTable();
+    const rows = await this.db.all('SELECT version, name, checksum, applied_at FROM schema_migrations ORDER BY version');
+    return rows.map(row => ({ ...row, version: parseInt(row.version, 10) }));
+  }
+
+  /**
+   * Applied and pending migrations, with checksum mismatches and applied versions
+   * whose file no longer exists
+   */
+  async status() {
+    const migrations = this.loadMigrations();
+    const applied = await this.getAppliedMigrations();
+    const appliedByVersion = new Map(applied.map(row => [row.version, row]));
+    const knownVersions = new Set(migrations.map(m => m.version));
+
+    return {
+      migrations: migrations.map(migration => {
+        const record = appliedByVersion.get(migration.version);
+        return {
+          version: migration.version,
+          name: migration.name,
+          applied: !!record,
+          appliedAt: record?.applied_at || null,
+          checksumMismatch: !!record && record.checksum !== migration.checksum
+        };
+      }),
+      missing: applied.filter(row => !knownVersions.has(row.version))
+    };
+  }
+
+  async pending() {
+    const { migrations } = await this.status();
+    return migrations.filter(m => !m.applied);
+  }
+
+  verifyChecksums(migrations, applied) {
+    const byVersion = new Map(migrations.map(m => [m.version, m]));
+    const mismatched = applied.filter(row => {
+      const migration = byVersion.get(row.version);
+      return migration && migration.checksum !== row.checksum;
+    });
+
+    if (mismatched.length > 0) {
+      const list = mismatched.map(row => `${row.version}_${row.name}`).join(', ');
+      throw new Error(`Applied migrations were modified after being run: ${list}. Add a new migration instead of editing an applied one.`);
+    }
+  }
+
+  /**
+   * Apply pending migrations in order, each inside its own transaction
+   * @param {number|null} targetVersion - Stop after this version (all pending if null)
+   * @returns {Promise<Array>} Migrations that were applied
+   */
+  async migrate(targetVersion = null) {
+    const migrations = this.loadMigrations();
+    const applied = await this.getAppliedMigrations();
+    this.verifyChecksums(migrations, applied);
+
+    const appliedVersions = new Set(applied.map(row => row.version));
+    const toApply = migrations.filter(m =>
+      !appliedVersions.has(m.version) && (targetVersion === null || m.version <= targetVersion)
+    );
+
+    if (toApply.length === 0) {
+      logger.info('Database schema is up to date');
+      return [];
+    }
+
+    for (const migration of toApply) {
+      logger.info(`⬆️ Applying migration ${migration.version}_${migration.name}...`);
+      await this.db.transaction(async (tx) => {
+        await migration.up(tx);
+        await tx.query(
+          'INSERT INTO schema_migrations (version, name, checksum) VALUES ($1, $2, $3)',
+          [migration.version, migration.name, migration.checksum]
+        );
+      });
+      logger.info(`✅ Migration ${migration.version}_${migration.name} applied`);
+    }
+
+    return toApply;
+  }
+
+  /**
+   * Roll back the most recently applied migrations
+   * @param {number} steps - Number of migrations to roll back
+   * @returns {Promise<Array>} Migrations that were rolled back
+   */
+  async rollback(steps = 1) {
+    const migrations = this.loadMigrations();
+    const applied = await this.getAppliedMigrations();
+    this.verifyChecksums(migrations, applied);
+
+    const byVersion = new Map(migrations.map(m => [m.version, m]));
+    const toRollback = applied.slice(-steps).reverse();
+
+    for (const record of toRollback) {
+      const migration = byVersion.get(record.version);
+      if (!migration) {
+        throw new Error(`Cannot roll back migration ${record.version}_${record.name}: file not found`);
+      }
+
+      logger.info(`⬇️ Rolling back migration ${migration.version}_${migration.name}...`);
+      await this.db.transaction(async (tx) => {
+        await migration.down(tx);
+        await tx.query('DELETE FROM schema_migrations WHERE version = $1', [migration.version]);
+      });
+      logger.info(`✅ Migration ${migration.version}_${migration.name} rolled back`);
+    }
+
+    return toRollback;
+  }
+}
+
+module.exports = Migrator;
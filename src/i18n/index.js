@@ -0,0 +1,89 @@
+/**
+ * Localization catalog
+ *
+ * Every user-facing string of the bot menus and alerts is looked up here by key.
+ * Locales live in ./locales, one flat object of dotted keys per language. A key
+ * missing from a locale falls back to English, so a partial translation never
+ * shows a raw key.
+ */
+
+const locales = {
+  en: require('./locales/en'),
+  es: require('./locales/es'),
+  ru: require('./locales/ru'),
+  zh: require('./locales/zh')
+};
+
+const DEFAULT_LANGUAGE = 'en';
+
+// Language code -> name shown in the language menu (in its own language)
+const SUPPORTED_LANGUAGES = {
+  en: '🇬🇧 English',
+  es: '🇪🇸 Español',
+  ru: '🇷🇺 Русский',
+  zh: '🇨🇳 中文'
+};
+
+function isSupportedLanguage(code) {
+  return Object.prototype.hasOwnProperty.call(locales, code);
+}
+
+/**
+ * Map a Telegram `language_code` ("es", "pt-br", "zh-hans") to a supported language
+ * @param {string} code
+ * @returns {string} Supported language code, DEFAULT_LANGUAGE when there is none
+ */
+function resolveLanguage(code) {
+  const base = String(code || '').toLowerCase().split(/[-_]/)[0];
+  return isSupportedLanguage(base) ? base : DEFAULT_LANGUAGE;
+}
+
+const pluralRules = {};
+
+/**
+ * CLDR plural category of a count in a language: "one" and "other" in English and
+ * Spanish, "one", "few" and "many" in Russian, always "other" in Chinese
+ */
+function getPluralCategory(lang, count) {
+  if (!pluralRules[lang]) {
+    pluralRules[lang] = new Intl.PluralRules(lang);
+  }
+  return pluralRules[lang].select(count);
+}
+
+function lookup(lang, key, count) {
+  const catalog = locales[lang];
+  if (!catalog) return undefined;
+  if (typeof count === 'number') {
+    const plural = catalog[`${key}_${getPluralCategory(lang, count)}`] ?? catalog[`${key}_other`];
+    if (plural !== undefined) return plural;
+  }
+  return catalog[key];
+}
+
+/**
+ * Translate a key
+ * @param {string} lang - Language code
+ * @param {string} key - Catalog key, e.g. "menu.help"
+ * @param {Object} params - Values for {name} placeholders; a numeric `count` picks
+ *   the form for its plural category (`_one`, `_few`, `_many`, `_other`) when the
+ *   locale has one
+ * @returns {string} Translated text, the English text when the locale lacks the key,
+ *   the key itself when English lacks it too
+ */
+function t(lang, key, params = {}) {
+  const template = lookup(lang, key, params.count) ?? lookup(DEFAULT_LANGUAGE, key, params.count);
+  if (template === undefined) {
+    return key;
+  }
+  return template.replace(/\{(\w+)\}/g, (match, name) =>
+    params[name] !== undefined && params[name] !== null ? String(params[name]) : match);
+}
+
+module.exports = {
+  DEFAULT_LANGUAGE,
+  SUPPORTED_LANGUAGES,
+  isSupportedLanguage,
+  resolveLanguage,
+  t
+};
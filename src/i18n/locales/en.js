@@ -0,0 +1,1070 @@
+// English - the reference catalog; every key used by the bot must exist here
+module.exports = {
+  // Shared
+  'common.error': '❌ An error occurred. Please try again.',
+  'common.start_first': 'Please start the bot first with /startminty',
+  'common.cancelled': '✅ Operation cancelled.',
+  'common.coming_soon': 'Feature coming soon!',
+  'common.request_error': '❌ Error processing request',
+  'common.back_main': '◀️ Back to Main Menu',
+  'common.back_to': '◀️ Back to {menu}',
+  'common.previous': '⬅️ Previous',
+  'common.next': '➡️ Next',
+  'common.unknown': 'Unknown',
+
+  // Welcome, help and main menu
+  'welcome.message': `🚀 <b>Welcome to Minty Rush!</b> 🚀
+
+I help you track NFT collections and get real-time alerts for:
+• New mints and transfers
+• Sales and price updates
+• Trending collections
+• Custom NFT monitoring
+
+<b>Get started by choosing from the menu below:</b>`,
+  'help.message': `📋 <b>Minty RushBot Commands</b>
+
+🎯 <b>NFT Management:</b>
+• /add_token - Add NFT collection to track
+• /remove_token - Remove tracked NFT
+• /my_tokens - View your tracked NFTs
+
+👛 <b>Wallet Watchlists:</b>
+• /watch_wallet &lt;address&gt; [chain] [label] - Alerts for a wallet's NFT activity
+• /unwatch_wallet &lt;address&gt; - Stop watching a wallet
+• /wallets - List this chat's watched wallets
+
+📈 <b>Floor &amp; Volume Alerts:</b>
+• /stats_alert &lt;collection&gt; floor below 0.5 - Alert on floor or 24h volume thresholds
+• /stats_alerts - List this chat's floor and volume alerts
+• /stats_alert_remove &lt;id&gt; - Remove an alert
+
+💰 <b>Trending &amp; Boost:</b>
+• /trending - View trending collections
+• /buy_trending - Boost NFT trending
+• /validate &lt;txhash&gt; - Validate trending payment
+• /buy_image &lt;contract&gt; - Pay fee for real NFT images
+• /validate_image &lt;contract&gt; &lt;txhash&gt; - Validate image fee
+• /buy_footer &lt;contract&gt; - Pay fee for footer advertisement
+• /validate_footer &lt;contract&gt; &lt;txhash&gt; &lt;link&gt; - Validate footer ad
+
+📺 <b>Channel Commands:</b>
+• /add_channel - Add bot to channel
+• /channel_settings - Configure channel alerts
+
+🔗 <b>Webhooks (group admins):</b>
+• /webhook_add &lt;contract&gt; &lt;https-url&gt; - Send activity to your endpoint
+• /webhooks - List this group's webhooks
+• /webhook_remove &lt;id&gt; - Remove a webhook
+• /webhook_secret &lt;id&gt; - Rotate the signing secret
+
+⚙️ <b>Alert Settings:</b>
+• /sweep_mode [aggregated|individual] - One message per sweep or per item
+• /quiet_hours &lt;start&gt; &lt;end&gt; [timezone]|off - Hold alerts and post a summary afterwards
+• /digest &lt;minutes&gt;|off - Post alerts as a periodic summary
+• /templates - Edit, preview and reset alert templates
+• /language [en|es|ru|zh] - Language of menus and alerts
+
+• /startminty - Welcome message
+• /help - Show this help
+
+Simple and focused - boost your NFTs easily! 🚀`,
+  'menu.manage_nfts': '📊 Manage NFTs',
+  'menu.trending': '🔥 Trending & Boost',
+  'menu.images': '🖼️ Display NFT Image',
+  'menu.footer': '🔗 Buy Footer Ads',
+  'menu.channels': '📺 Channel Settings',
+  'menu.verify': '✅ Verify Payments',
+  'menu.help': '❓ Help & Contact',
+  'menu.language': '🌐 Language',
+  'contact.message': `📞 <b>Help & Contact</b>
+
+🌐 <b>Web:</b> https://www.candycodex.com
+
+📧 <b>Mail:</b> support@candycodex.com
+
+💬 <b>Telegram:</b> @CandyCodex
+
+<i>Need help? Feel free to reach out through any of these channels!</i>`,
+
+  // Group setup
+  'group_start.message': `🎉 <b>Welcome to Minty Rush!</b>
+
+To configure bot for <b>{group}</b>
+select an option below:`,
+  'group_start.this_group': 'this group',
+  'group_start.setup_chat': '💬 Setup in TG Chat',
+  'group_start.setup_bot': '🤖 Setup inside BOT',
+  'start.not_admin': '⚠️ You are no longer an admin in that group.',
+  'start.invalid_setup': '❌ Invalid or expired setup link. Please run /startminty in the group again.',
+
+  // Top-level menus
+  'tokens_menu.admin': `📊 <b>NFT Management</b>
+
+<b>Manage your NFT collections:</b>`,
+  'tokens_menu.viewer': `📊 <b>NFT Collections</b>
+
+<b>View tracked NFT collections in this group:</b>
+
+ℹ️ <i>Only admins can add or remove collections</i>`,
+  'tokens_menu.add': '➕ Add NFT Collection',
+  'tokens_menu.view_mine': '👁️ View My NFTs',
+  'tokens_menu.remove': '🗑️ Remove NFT Collection',
+  'tokens_menu.view_tracked': '👁️ View Tracked NFTs',
+  'images_menu.message': `🖼️ <b>NFT Image Display</b>
+
+<b>Enable real NFT images instead of Candy Codex image:</b>`,
+  'images_menu.pay': '💳 Display NFT Image',
+  'footer_menu.message': `🔗 <b>Footer Advertisement</b>
+
+<b>Advertise your Project in notification footers:</b>`,
+  'footer_menu.pay': '💳 Pay for Footer Ads',
+  'channels_menu.empty': `📺 <b>Channel Management</b>
+
+You haven't added any channels yet.
+
+<b>To get started:</b>
+1️⃣ Add me to your channel as an admin
+2️⃣ Run /add_channel in the channel
+3️⃣ Come back here to configure settings`,
+  'channels_menu.list': `📺 <b>My Channels</b>
+
+Select a channel to configure:`,
+  'verify_menu.message': `✅ <b>Verify Your Payments</b>
+
+Select the type of payment you want to verify:
+
+`,
+  'verify_menu.trending': '🔍 Verify Trending',
+  'verify_menu.image': '🖼️ Verify Image',
+  'verify_menu.footer': '🔗 Verify Footer',
+  'verify_menu.error': '❌ Error loading verification options. Please try again.',
+  'trending_menu.message': `<b>🔥 Buy Trending</b>
+
+Select trending tier:
+
+<b>🔵 Standard Trending</b>
+Lower cost, shown to standard tier channels
+
+<b>⭐ Premium Trending</b>
+Higher visibility, shown to premium tier channels`,
+  'trending_menu.buy_standard': '🔵 Buy Standard Trending',
+  'trending_menu.buy_premium': '⭐ Buy Premium Trending',
+  'trending_menu.error': '❌ Error loading trending menu. Please try again.',
+
+  // Channel settings
+  'channel.mode.all': '<b>All Activity</b>\nShows real-time alerts for ALL tracked NFTs from any group',
+  'channel.mode.none': '<b>No Alerts</b>\nChannel alerts are disabled',
+  'channel.mode.normal': '<b>Standard Trending</b>\nShows scheduled broadcasts for standard tier promotions',
+  'channel.mode.premium': '<b>Premium Trending</b>\nShows scheduled broadcasts for premium tier promotions',
+  'channel.sweeps.aggregated': 'One message per sweep',
+  'channel.sweeps.individual': 'One message per item',
+  'channel.digest_hint': '<i>Post /quiet_hours or /digest in the channel to change.</i>',
+  'channel.settings': `⚙️ <b>{title}</b>
+
+<b>Current Alert Mode:</b>
+{mode}
+
+<b>Sweeps:</b> {sweeps}
+<b>Language:</b> {language}
+{digest}
+Tap the button below to cycle through alert modes:`,
+  'channel.change_mode': '{icon} Change Alert Mode',
+  'channel.sweeps_button.aggregated': '🧹 Sweeps: Aggregated',
+  'channel.sweeps_button.individual': '🧹 Sweeps: Individual',
+  'channel.language_button': '🌐 Language: {language}',
+  'channel.add_nfts': '➕ Add NFTs',
+  'channel.refresh': '🔄 Refresh',
+  'channel.back': '◀️ Back to Channels',
+
+  // Chat settings commands
+  'settings.unavailable': '❌ Chat settings are not available.',
+  'settings.channel_private_only': '❌ Channel settings are changed from /channel_settings in a private chat with me.',
+  'settings.admins_only': '❌ Only group admins can change alert settings.',
+  'sweep.status': `🧹 <b>Sweep alerts:</b> {mode}
+
+<b>aggregated</b> - one message per sweep with item count, total and average price and a collage
+<b>individual</b> - one message per item bought
+
+Change with <code>/sweep_mode aggregated</code> or <code>/sweep_mode individual</code>`,
+  'sweep.unknown_mode': '❌ Unknown mode "{mode}". Use: {modes}',
+  'sweep.saved.aggregated': '✅ Sweeps will be posted as one message with a collage.',
+  'sweep.saved.individual': '✅ Each item of a sweep will be posted separately.',
+  'sweep.error': '❌ Error updating sweep mode. Please try again.',
+  'quiet.summary': '🌙 <b>Quiet hours:</b> {quiet}\n📰 <b>Digest:</b> {digest}',
+  'quiet.off': 'off',
+  'quiet.active_now': '<i>Quiet hours are on now: alerts are held for the summary.</i>',
+  'quiet.help': `During quiet hours alerts are held and posted as one summary (sales, volume, top sale, floor change) when they end.
+
+Set with <code>/quiet_hours 23:00 07:00 Europe/Berlin</code> (timezone optional, default UTC)
+Turn off with <code>/quiet_hours off</code>`,
+  'quiet.turned_off': '✅ Quiet hours turned off. Held alerts will be posted shortly.',
+  'quiet.invalid_times': '❌ Use times like 23:00 and 07:00, e.g. /quiet_hours 23:00 07:00 Europe/Berlin',
+  'quiet.same_times': '❌ Quiet hours must start and end at different times.',
+  'quiet.unknown_timezone': '❌ Unknown timezone "{timezone}". Use a name like UTC, Europe/Berlin or America/New_York.',
+  'quiet.saved': '✅ Quiet hours saved.',
+  'quiet.error': '❌ Error updating quiet hours. Please try again.',
+  'digest.every': 'every {minutes} min',
+  'digest.off': 'off (live alerts)',
+  'digest.help': `In digest mode alerts are collected and posted as one summary at a set interval.
+
+Set with <code>/digest 60</code> (minutes) or <code>/digest 4h</code>
+Back to live alerts with <code>/digest off</code>`,
+  'digest.turned_off': '✅ Digest mode turned off. Held alerts will be posted shortly, then alerts go out live.',
+  'digest.invalid_interval': '❌ Use an interval between 5 minutes and {maxHours} hours, e.g. /digest 60 or /digest 4h',
+  'digest.saved': '✅ Digest mode on.',
+  'digest.error': '❌ Error updating digest mode. Please try again.',
+
+  // Alert templates
+  'templates.unavailable': '❌ Message templates are not available.',
+  'templates.menu': `🧩 <b>Alert Templates</b>
+
+Choose how alerts look in this chat. Templates use Telegram HTML and placeholders like <code>{collection}</code> and <code>{price}</code>.
+
+{lines}
+
+Trending alerts, sweeps and digests keep their own layout.`,
+  'templates.custom': '✏️ custom',
+  'templates.default': 'default',
+  'templates.editor': `🧩 <b>{type}</b> template ({state})
+
+<pre>{template}</pre>`,
+  'templates.state.custom': 'custom',
+  'templates.state.default': 'default',
+  'templates.edit': '✏️ Edit',
+  'templates.preview': '👁️ Preview',
+  'templates.reset': '♻️ Reset to default',
+  'templates.back': '◀️ Back to Templates',
+  'templates.caption_warning': '⚠️ With long names this may exceed Telegram\'s 1024-character caption limit.',
+  'templates.edit_prompt': `✏️ Send the new <b>{type}</b> template.
+
+Allowed tags: &lt;b&gt;, &lt;i&gt;, &lt;u&gt;, &lt;s&gt;, &lt;code&gt;, &lt;pre&gt;, &lt;a href="..."&gt;, &lt;blockquote&gt;, &lt;tg-spoiler&gt;. Lines whose placeholders are all empty are left out.
+
+{placeholders}
+
+Type <code>cancel</code> to stop.`,
+  'templates.wrong_chat': '❌ Open /templates again in the chat you want to change.',
+  'templates.invalid': '❌ {reason}\n\nFix the template and send it again, or type cancel.',
+  'templates.error.empty': 'The template is empty.',
+  'templates.error.too_long': 'The template is {length} characters long; the limit is {max}.',
+  'templates.error.unknown_placeholder': 'Unknown placeholder {{name}}.',
+  'templates.error.malformed_tag': 'Unsupported or malformed tag. Write a literal "<" as &lt;.',
+  'templates.error.unsupported_tag': 'Tag <{tag}> is not supported by Telegram.',
+  'templates.error.wrong_close': '</{tag}> closes <{open}>.',
+  'templates.error.unopened_close': '</{tag}> has no opening tag.',
+  'templates.error.link_href': '<a> needs an href="..." attribute.',
+  'templates.error.attribute_placeholder': '{{name}} can\'t be used inside a tag; use {item_url} or {explorer_url} in href.',
+  'templates.error.span_class': '<span> is only supported as <span class="tg-spoiler">.',
+  'templates.error.no_attributes': '<{tag}> takes no attributes.',
+  'templates.error.unclosed': '<{tag}> is never closed.',
+  'templates.error.ampersand': 'Write a literal "&" as &amp;.',
+  'templates.saved': '✅ {type} template saved. Preview:',
+  'templates.load_error': '❌ Error loading templates. Please try again.',
+  'templates.save_error': '❌ Error saving the template. Please try again.',
+
+  // Per-subscription alert filters
+  'filters.not_found': '❌ Subscription not found. Open /my_tokens again.',
+  'filters.title': '🔔 <b>Alert settings: {name}</b>',
+  'filters.none': 'No filters: every sale, listing, offer, mint and transfer is posted.',
+  'filters.tap_hint': 'Tap an event type to turn it on or off.',
+  'filters.type.sale': 'Sales',
+  'filters.type.listing': 'Listings',
+  'filters.type.offer': 'Offers',
+  'filters.type.mint': 'Mints',
+  'filters.type.transfer': 'Transfers',
+  'filters.min_price': '💲 Min price: {value}',
+  'filters.marketplaces': '🏪 Marketplaces: {value}',
+  'filters.value.none': 'none',
+  'filters.value.all': 'all',
+  'filters.value.off': 'off',
+  'filters.value.below_floor': 'below floor',
+  'filters.value.include': 'include {names}',
+  'filters.value.exclude': 'exclude {names}',
+  'filters.burns.hidden': '🔥 Burns: hidden',
+  'filters.burns.shown': '🔥 Burns: shown',
+  'filters.snipe': '🎯 Snipe mode: {value}',
+  'filters.traits': '🧬 Traits: {value}',
+  'filters.reset': '♻️ Reset filters',
+  'filters.back': '◀️ Back to My NFTs',
+  'filters.min_price_prompt': '💲 Send the minimum price for sale, listing and offer alerts:\n\n<code>0.5</code> - in the native currency\n<code>$250</code> - in USD\n<code>off</code> - no minimum\n\nType <code>cancel</code> to stop.',
+  'filters.marketplaces_prompt': '🏪 Send the marketplaces to include or exclude:\n\n<code>include opensea, magic eden</code>\n<code>exclude blur</code>\n<code>off</code> - all marketplaces\n\nType <code>cancel</code> to stop.',
+  'filters.snipe_prompt': '🎯 Snipe mode posts only listings priced under the collection floor. Send how far under:\n\n<code>on</code> - any listing below the floor\n<code>15%</code> - at least 15% under the floor\n<code>off</code> - back to all alerts\n\nType <code>cancel</code> to stop.',
+  'filters.traits_prompt': '🧬 Send the traits to get alerts for. An item needs one of them:\n\n<code>Fur: Gold; Eyes: Laser</code> - trait type and value\n<code>1/1</code> - a value (or trait type) of any type\n<code>off</code> - every item\n\nTraits come from the collection\'s rarity snapshot. Items that aren\'t in it yet are not posted.\n\nType <code>cancel</code> to stop.',
+  'filters.load_error': '❌ Error loading alert settings. Please try again.',
+  'filters.save_error': '❌ Error saving the filter. Please try again.',
+  'filters.summary.events': 'Events: {types}',
+  'filters.summary.min_price': 'Min price: {value}',
+  'filters.summary.marketplaces': 'Marketplaces: {value}',
+  'filters.summary.burns': 'Burns ignored',
+  'filters.summary.snipe_discount': 'Snipe mode: listings {discount}%+ under the floor',
+  'filters.summary.snipe_floor': 'Snipe mode: listings below the floor',
+  'filters.summary.traits': 'Traits: {traits}',
+  'filters.error.min_price': 'Send a number like 0.5 (native currency) or $250 (USD), or "off".',
+  'filters.error.marketplace_mode': 'Start with "include" or "exclude", e.g. "exclude blur" or "include opensea, magic eden".',
+  'filters.error.marketplace_empty': 'Name at least one marketplace.',
+  'filters.error.snipe': 'Send "on" for any listing below the floor, a percentage like 15%, or "off".',
+  'filters.error.traits': 'Send traits like "Fur: Gold; Eyes: Laser" or a value like "1/1", or "off".',
+  'filters.error.too_many_traits': 'Send at most {max} traits.',
+  'filters.error.trait_too_long': 'Each trait can be at most {max} characters.',
+
+  // Language
+  'language.menu': `🌐 <b>Language</b>
+
+Current: {language}
+
+Choose the language of menus and alerts in this chat:`,
+  'language.saved': '✅ Language set to {language}.',
+  'language.unknown': '❌ Unknown language "{code}". Use one of: {codes}',
+  'language.error': '❌ Error updating the language. Please try again.',
+
+  // Wallet watchlists
+  'wallet.usage': `👛 <b>Watch a wallet</b>
+
+<code>/watch_wallet &lt;address&gt; [chain] [label]</code>
+
+Get an alert for every NFT the wallet buys, sells, mints or moves, in any collection. EVM addresses are watched on Ethereum unless a chain is given, e.g. <code>/watch_wallet 0x… base Whale</code>. Solana and Bitcoin addresses are recognized automatically.`,
+  'wallet.invalid_address': '❌ That is not a valid EVM, Solana or Bitcoin address.',
+  'wallet.chain_unavailable': '❌ Wallet watching is not available on {chain}.',
+  'wallet.limit': '❌ This chat already watches {limit} wallets. Remove one with /unwatch_wallet first.',
+  'wallet.saved': '✅ Watching <code>{address}</code> on {chain}.',
+  'wallet.updated': '✅ Label of <code>{address}</code> updated.',
+  'wallet.sync_failed': '⚠️ <code>{address}</code> was saved, but the {chain} webhook could not be updated yet. Alerts start once it is synced.',
+  'wallet.removed': '✅ Stopped watching <code>{address}</code>.',
+  'wallet.not_found': '❌ <code>{address}</code> is not watched in this chat.',
+  'wallet.unwatch_usage': 'Usage: <code>/unwatch_wallet &lt;address&gt;</code>',
+  'wallet.list_empty': '👛 No wallets are watched in this chat yet.\n\nAdd one with <code>/watch_wallet &lt;address&gt; [chain] [label]</code>.',
+  'wallet.list_title': '👛 <b>Watched wallets</b> ({count}/{limit})',
+  'wallet.list_footer': 'Stop watching one with <code>/unwatch_wallet &lt;address&gt;</code>.',
+  'wallet.error': '❌ Error updating the wallet watchlist. Please try again.',
+  'wallet.unavailable': '❌ Wallet watchlists are not available.',
+  'wallet.action.bought': 'bought',
+  'wallet.action.sold': 'sold',
+  'wallet.action.minted': 'minted',
+  'wallet.action.received': 'received',
+  'wallet.action.sent': 'sent',
+  'wallet.action.burned': 'burned',
+
+  // Floor and volume alerts
+  'stats_alert.usage': `📈 <b>Floor and volume alerts</b>
+
+<code>/stats_alert &lt;collection&gt; floor below 0.5</code>
+<code>/stats_alert &lt;collection&gt; floor above 2</code>
+<code>/stats_alert &lt;collection&gt; floor change 10% 1h</code>
+<code>/stats_alert &lt;collection&gt; volume above 100</code>
+
+The collection is the contract address, slug or name of an NFT tracked in this chat. Amounts are in the collection's currency (ETH, SOL, BTC) and volume covers the last 24 hours. A change alert compares with the value one window earlier (default 1h, up to 7d).`,
+  'stats_alert.invalid_rule': '❌ Could not read that rule. Use <code>floor|volume below|above &lt;amount&gt;</code> or <code>floor|volume change &lt;percent&gt; [window]</code>.',
+  'stats_alert.not_tracked': '❌ <b>{collection}</b> is not tracked in this chat. Add it with /add_token first.',
+  'stats_alert.saved': '✅ Alert #{id} saved: <b>{collection}</b> {rule}.',
+  'stats_alert.limit': '❌ This chat already has {limit} floor and volume alerts. Remove one with /stats_alert_remove first.',
+  'stats_alert.list_empty': '📈 No floor or volume alerts in this chat yet.\n\nAdd one with <code>/stats_alert &lt;collection&gt; floor below &lt;amount&gt;</code>.',
+  'stats_alert.list_title': '📈 <b>Floor and volume alerts</b> ({count}/{limit})',
+  'stats_alert.list_footer': 'Remove one with <code>/stats_alert_remove &lt;id&gt;</code>.',
+  'stats_alert.removed': '✅ Alert #{id} removed.',
+  'stats_alert.not_found': '❌ Alert #{id} does not exist in this chat.',
+  'stats_alert.remove_usage': 'Usage: <code>/stats_alert_remove &lt;id&gt;</code>',
+  'stats_alert.error': '❌ Error updating floor and volume alerts. Please try again.',
+  'stats_alert.unavailable': '❌ Floor and volume alerts are not available.',
+  'stats_alert.rule.below': '{metric} below {threshold}',
+  'stats_alert.rule.above': '{metric} above {threshold}',
+  'stats_alert.rule.change': '{metric} moves {threshold}% within {window}',
+  'stats_alert.metric.floor': 'Floor',
+  'stats_alert.metric.volume': '24h volume',
+  'stats_alert.triggered.below': '{emoji} **{collection}**: {metric} dropped below {threshold}',
+  'stats_alert.triggered.above': '{emoji} **{collection}**: {metric} rose above {threshold}',
+  'stats_alert.triggered.change': '{emoji} **{collection}**: {metric} moved {change} in {window}',
+  'stats_alert.previous': '{metric} {window} ago',
+  'stats_alert.sales': '24h sales',
+  'stats_alert.listed': 'Listed',
+
+  // Payments
+  'payment.type.trending': 'Trending Boost',
+  'payment.type.image': 'NFT Image Display',
+  'payment.type.footer': 'Footer Advertisement',
+  'payment.instructions': `{emoji} <b>{type} Payment Instructions</b>
+
+<b>Network:</b> {network}
+<b>Amount:</b> {amount} {symbol}
+<b>Duration:</b> {duration}
+
+<b>Send payment to:</b>
+<code>{address}</code>
+
+<b>⚠️ Important:</b>
+• Send EXACTLY {amount} {symbol}
+• Use {network} network
+• After payment, use /validate to verify
+• Payment expires in 30 minutes
+
+Need help? Contact @YourSupportBot`,
+  'duration.hours_price': '{hours} hours - {price}',
+  'duration.days_price': '{days} days - {price}',
+  'duration.days': '{days} days',
+
+  // Tracked token lists
+  'tokens.none_on_chain': '🔍 You haven\'t added any NFTs on {chain} yet!\n\nUse /add_token to start tracking NFT collections.',
+  'tokens.none': '🔍 You haven\'t added any tokens yet!\n\nUse /add_token to start tracking NFT collections.',
+  'tokens.title_chain': '📊 <b>Your Tracked NFTs on {chain}</b>',
+  'tokens.title': '📊 <b>Your Tracked NFTs</b>',
+  'tokens.remove_button': '🗑️ Remove {name}',
+
+  // Alerts (Telegram Markdown: no unescaped _ * [ ` in translations)
+  'alert.action.sale': '**Buy**',
+  'alert.action.listing': 'Listed',
+  'alert.action.offer': 'Offer Received',
+  'alert.action.mint': 'Mint',
+  'alert.action.transfer': 'Transfer',
+  'alert.action.burn': 'Burn',
+  'alert.price.sale': 'Buy Price',
+  'alert.price.listing': 'List Price',
+  'alert.price.offer': 'Offer Amount',
+  'alert.price.mint': 'Mint Price',
+  'alert.price.transfer': 'Value',
+  'alert.price.burn': 'Value',
+  'alert.trending': 'TRENDING',
+  'alert.candy_minted': 'minted!',
+  'alert.nft': 'NFT',
+  'alert.buyer': 'Buyer',
+  'alert.seller': 'Seller',
+  'alert.bidder': 'Bidder',
+  'alert.from': 'From',
+  'alert.to': 'To',
+  'alert.marketplace': 'Marketplace',
+  'alert.collection': 'Collection',
+  'alert.ca': 'CA',
+  'alert.chain': 'Chain',
+  'alert.view_on': 'View on {name}',
+  'alert.snipe.action': 'Listed below floor',
+  'alert.snipe.discount': 'Under floor',
+  'alert.snipe.floor': 'floor {floor}',
+  'alert.snipe.buy': '🛒 Buy now on {name}',
+  'alert.rank': 'Rank',
+  'alert.rarest_traits': 'Rarest traits',
+  'alert.royalty': 'Royalty',
+  'alert.powered_by': 'Powered by {link}',
+  'alert.boost': 'BOOST YOUR NFT🟢',
+  'alert.sweep': 'Sweep',
+  'alert.items': 'Items',
+  'alert.total': 'Total',
+  'alert.priced': '{count} priced',
+  'alert.avg_price': 'Avg Price',
+  'alert.buyers': 'Buyers',
+  'alert.wallets': '{count} wallets',
+  'alert.nfts': 'NFTs',
+  'alert.more': '+{count} more',
+
+  // Digests
+  'digest.title.quiet': '🌙 **Quiet Hours Digest**',
+  'digest.title.digest': '📰 **Activity Digest**',
+  'digest.summary_one': '{count} event in the last {elapsed}',
+  'digest.summary_other': '{count} events in the last {elapsed}',
+  'digest.hours': '{hours}h',
+  'digest.minutes': '{minutes}m',
+  'digest.sales': 'Sales',
+  'digest.volume': 'Volume',
+  'digest.top_sale': 'Top Sale',
+  'digest.top_sale_item': '{item} for {price}',
+  'digest.floor': 'Floor',
+  'digest.more_collections': '+{count} more collections',
+  'digest.count.listing_one': '{count} listing',
+  'digest.count.listing_other': '{count} listings',
+  'digest.count.offer_one': '{count} offer',
+  'digest.count.offer_other': '{count} offers',
+  'digest.count.mint_one': '{count} mint',
+  'digest.count.mint_other': '{count} mints',
+  'digest.count.transfer_one': '{count} transfer',
+  'digest.count.transfer_other': '{count} transfers',
+  'digest.count.burn_one': '{count} burn',
+  'digest.count.burn_other': '{count} burns',
+
+  // Resume reminders and unavailable services
+  'resume.tx_hash': '⏳ You have an unfinished payment. Reply with your transaction hash to continue, or type "cancel" to start over.',
+  'resume.payment': '⏳ You have an unfinished payment. If you already paid, tap "📝 Submit Transaction Hash" in the payment instructions above to continue.',
+  'resume.step': '⏳ You have an unfinished step. Reply to continue where you left off, or type "cancel" to start over.',
+  'service.validation_unavailable': '❌ Validation service not available.',
+  'service.image_unavailable': '❌ Image fee system not available.',
+  'service.footer_unavailable': '❌ Footer ad system not available.',
+  'service.chain_manager_unavailable': '❌ Chain manager not available',
+  'tokens.load_error': '❌ Error loading your NFTs. Please try again.',
+  'channel_cmd.add_wrong_chat': '❌ This command only works in channels or groups. Add me to a channel first, then use this command there.',
+  'channel_cmd.unknown_user': '❌ Unable to identify user. Please try again.',
+  'channel_cmd.user_error': '❌ Error setting up user. Please try again.',
+  'channel_cmd.add_error': '❌ Error adding channel. Please try again.',
+  'channel_cmd.settings_wrong_chat': '❌ This command only works in channels or groups.',
+  'channel_cmd.settings_error': '❌ Error retrieving channel settings. Please try again.',
+  'chat_id.message': '🆔 *Chat Information*\n\n**Chat ID:** `{chatId}`\n**Type:** {chatType}\n**Title:** {chatTitle}\n\n*Use this chat ID to configure notifications in your bot settings.*',
+  'chat_id.error': '❌ Error getting chat information.',
+
+  // Adding collections
+  'common.group': 'Group',
+  'common.main_menu': '🏠 Main Menu',
+  'common.back': '◀️ Back',
+  'common.cancel': '❌ Cancel',
+  'common.session_expired': '❌ Session expired. Please start again.',
+  'add_token.adding_to': `🎯 <b>Adding NFT to:</b> {group}
+
+Please select the blockchain chain:`,
+  'add_token.select_network': `🔗 <b>Select Blockchain Network</b>
+
+👥 <b>Adding to: {group}</b>
+
+Choose the blockchain where your NFT collection exists:`,
+  'add_token.switch_group': '🔄 Switch Group',
+  'add_token.back_to_context': '◀️ Back to Context Selection',
+  'add_token.back_to_chains': '◀️ Back to Chain Selection',
+  'add_token.admins_only': '⚠️ Only group admins can add NFT tracking.',
+  'add_token.prompt_solana': `◎ <b>Solana</b> selected!
+
+📝 Please enter the collection symbol:
+
+Example: <code>mad_lads</code>
+
+💡 The collection must exist on Magic Eden marketplace.`,
+  'add_token.prompt_bitcoin': `₿ <b>Bitcoin</b> selected!
+
+📝 Please enter the collection name:
+
+Example: <code>bitcoin-puppets</code> or <code>NodeMonkes</code>
+
+💡 The collection must exist on Magic Eden Ordinals marketplace.`,
+  'add_token.prompt_evm': `🔗 <b>{chain}</b> selected!
+
+📝 Please enter the NFT contract address to track on {chain}:
+
+💡 Make sure the contract exists on {chain} network.`,
+  'chain.select_specific': '❌ Please select a specific blockchain network for adding tokens.',
+  'chain.invalid': '❌ Invalid blockchain network selected.',
+  'chain.no_selection': '❌ No active chain selection process found.',
+  'token.not_found': '❌ NFT collection not found.',
+  'channels_menu.add_help': `💡 <b>Add Bot to Channel</b>
+
+1. Add this bot to your channel as an admin
+2. Use the command: <code>/add_channel</code> in the channel
+3. Configure notifications with <code>/channel_settings</code>`,
+  'channels_menu.back': '◀️ Back to Channels Menu',
+  'chat_id.message_html': `🆔 <b>Chat Information</b>
+
+<b>Chat ID:</b> <code>{chatId}</code>
+<b>Type:</b> {chatType}
+<b>Title:</b> {chatTitle}
+
+<i>Use this chat ID to configure notifications in your bot settings.</i>`,
+  'payment.selected_chain': 'the selected blockchain',
+  'payment.submit_tx.image': `📝 <b>Submit Transaction Hash</b>
+
+Please send me your {chain} transaction hash for the image fee payment.
+
+<i>Example: 0xabc123456789def...</i>`,
+  'payment.submit_tx.image_token': `📝 <b>Submit Transaction Hash</b>
+
+🎯 <b>NFT:</b> {name} ({symbol})
+🔗 <b>Chain:</b> {chain}
+
+Please send me your transaction hash for the image fee payment.
+
+<i>Example: 0xabc123456789def...</i>`,
+  'payment.submit_tx.footer': `📝 <b>Submit Transaction Hash</b>
+
+Please send me your {chain} transaction hash for the footer payment.
+
+<i>Example: 0xabc123456789def...</i>`,
+  'payment.submit_tx.trending': '📝 **Submit Transaction Hash**\n\nPlease send your {chain} transaction hash now.\n\nThe transaction hash should:\n• Start with 0x\n• Be 66 characters long\n• Be from a transaction sent to: `{address}`\n\nExample: `0x1234567890abcdef...`',
+
+  // Channel posts and webhooks
+  'payment.tx_cancelled': '✅ Transaction submission cancelled.',
+  'channel_cmd.channel_only': '❌ This command only works in channels or groups.',
+  'channel_cmd.add_channel_only': '❌ This command only works in channels or groups. Add me to a channel first, then use this command there.',
+  'channel_cmd.unknown_channel': 'Unknown Channel',
+  'channel_cmd.setup_error': '❌ Error setting up user. Please try again.',
+  'channel_cmd.command_error': '❌ An error occurred processing the command. Please try again.',
+  'chat_id.message_plain': `📋 Chat Info:
+ID: {chatId}
+Type: {chatType}
+Title: {chatTitle}`,
+  'webhooks.unavailable': '❌ Outbound webhooks are not available.',
+  'webhooks.group_only': '❌ Webhooks are managed per group. Run this command in the group that tracks the collection.',
+  'webhooks.admins_only': '❌ Only group admins can manage webhooks.',
+  'webhooks.secret_dm': `🔑 <b>Signing secret for webhook #{id}</b> ({chat})
+
+<code>{secret}</code>
+
+Verify each request by computing HMAC-SHA256 of <code>&lt;X-MintTech-Timestamp&gt;.&lt;raw body&gt;</code> with this secret and comparing it to the <code>X-MintTech-Signature</code> header (<code>sha256=...</code>).`,
+  'webhooks.add_usage': `Usage: <code>/webhook_add &lt;contract or collection&gt; &lt;https-url&gt; [sale,listing,mint,transfer]</code>
+
+Every matching activity of the collection is POSTed to the URL as signed JSON.`,
+  'webhooks.not_tracked': '❌ This collection is not tracked in this group. Add it with /add_token first.',
+  'webhooks.all_events': 'all',
+  'webhooks.registered': `✅ Webhook #{id} registered for {name}
+📨 Events: {events}`,
+  'webhooks.secret_sent': '🔑 The signing secret was sent to you in a private message.',
+  'webhooks.secret_not_sent': '⚠️ I couldn\'t message you privately. Start a chat with me, then run /webhook_secret {id} here to receive the signing secret.',
+  'webhooks.register_error': '❌ Could not register webhook: {error}',
+  'webhooks.unknown_event_types': 'Unknown event type(s): {types} (expected {expected})',
+  'webhooks.list_empty': 'No webhooks registered in this group. Use /webhook_add to add one.',
+  'webhooks.list_title': '🔗 <b>Webhooks in this group</b>',
+  'webhooks.events': 'Events: {events}',
+  'webhooks.failures': ' · {count} failure(s), last: {error}',
+  'webhooks.list_footer': 'Remove with /webhook_remove &lt;id&gt;, rotate the secret with /webhook_secret &lt;id&gt;.',
+  'webhooks.load_error': '❌ Error loading webhooks. Please try again.',
+  'webhooks.remove_usage': 'Usage: /webhook_remove <id> (see /webhooks)',
+  'webhooks.removed': '✅ Webhook #{id} removed.',
+  'webhooks.not_found': '❌ Webhook #{id} not found in this group.',
+  'webhooks.remove_error': '❌ Error removing webhook. Please try again.',
+  'webhooks.secret_usage': 'Usage: /webhook_secret <id> (see /webhooks)',
+  'webhooks.secret_unchanged': '⚠️ I couldn\'t message you privately, so the secret was not changed. Start a chat with me and run /webhook_secret {id} again.',
+  'webhooks.secret_rotated': '🔑 New signing secret for webhook #{id} sent privately. The old secret no longer works.',
+  'webhooks.secret_error': '❌ Error rotating webhook secret. Please try again.',
+
+  // Adding, removing and boosting collections
+  'add_token.validating': '🔍 Validating and adding on {chain}...',
+  'add_token.validating_solana': '◎ Validating Solana NFT via Magic Eden...',
+  'add_token.private_unavailable': `❌ <b>Private tracking is not available</b>
+
+Please add this bot to a group or channel and use the <b>Setup inside BOT</b> button to track NFTs.
+
+You can still view your tracked NFTs from groups here in DM.`,
+  'add_token.boost_button': 'BOOST YOUR NFT🟢',
+  'add_token.verified': '✅ Verification: Token is now in your tracking list!',
+  'add_token.failed': `❌ {reason}
+
+If you think this is a mistake, try again or contact support.`,
+  'add_token.error': '❌ Error adding token. Please check the NFT address and try again.',
+  'payment.no_pending': '❌ No pending payment found. Please start the boost process again.',
+  'payment.invalid_tx_hash': '❌ Invalid transaction hash format. Please send a valid transaction hash (starts with 0x and is 64 characters long).',
+  'payment.cancel_hint': 'Or type "cancel" to abort.',
+  'payment.validating': '🔍 Validating your transaction... This may take a few moments.',
+  'payment.trending_confirmed': '✅ **Payment Confirmed!**\n\n🔥 **{name}** is now trending for {duration} hours!\n\n💰 Amount: {amount} ETH\n📝 Transaction: `{txHash}`\n🆔 Payment ID: {paymentId}\n\nYour collection will appear in the trending list and be promoted in channels. Thank you for boosting the ecosystem! 🚀',
+  'payment.trending_confirmed_one': '✅ **Payment Confirmed!**\n\n🔥 **{name}** is now trending for {duration} hour!\n\n💰 Amount: {amount} ETH\n📝 Transaction: `{txHash}`\n🆔 Payment ID: {paymentId}\n\nYour collection will appear in the trending list and be promoted in channels. Thank you for boosting the ecosystem! 🚀',
+  'payment.trending_failed': '❌ **Payment Validation Failed**\n\n{error}\n\nPlease check:\n• Transaction was sent to: `{address}`\n• Exact amount was sent: {amount} ETH\n• Transaction is confirmed on blockchain\n\nYou can try again with a different transaction hash or contact support.',
+  'payment.validation_error': '❌ Error validating transaction. Please try again or contact support.',
+  'remove.user_not_found': '❌ User not found. Please start the bot first with /startminty',
+  'remove.not_found': '❌ NFT not found.',
+  'remove.not_subscribed': '❌ You are not subscribed to this NFT in this chat context.',
+  'remove.completion_error': '❌ Error completing token removal. Please try again.',
+  'remove.removed_completely': `✅ <b>Token Removed Successfully</b>
+
+🗑️ <b>{name}</b> has been completely removed from tracking.
+
+📮 Contract: <code>{address}</code>`,
+  'remove.removed_from_chat': `✅ <b>Token Removed Successfully</b>
+
+🗑️ <b>{name}</b> has been removed from your tracking list in {context}.
+
+📮 Contract: <code>{address}</code>
+
+You will no longer receive notifications for this NFT in this chat context.`,
+  'remove.context_private': 'private messages',
+  'remove.context_group': 'group chat ({chatId})',
+  'remove.error': '❌ Error removing token. Please try again.',
+  'tokens.unknown_collection': 'Unknown Collection',
+  'tokens.notification_updated': 'Notification setting updated!',
+  'tokens.notification_error': '❌ Error updating notification setting',
+  'stats.loading': 'Loading statistics...',
+  'stats.coming_soon': '📊 Token statistics feature coming soon!',
+  'stats.error': '❌ Error loading statistics',
+  'trending.buy_button': '🚀 Buy Trending',
+  'trending.empty': `📊 *No trending NFTs right now*
+
+Be the first to boost your NFT collection!`,
+  'trending.title': '🔥 *Trending NFT Collections*',
+  'trending.hours_left': '⏱️ {hours}h left',
+  'trending.paid': '💰 Paid: {amount} ETH',
+  'trending.stats_button': '📊 {name} Stats',
+  'trending.view': 'View',
+  'trending.boost_button': '🚀 Boost Your Token',
+  'trending.load_error': '❌ Error loading trending tokens. Please try again.',
+  'trending.instructions': `💳 <b>Simple Payment Instructions</b>
+
+🔗 Chain: <b>{chain}</b>
+🔥 Collection: {name}
+📮 Contract: <code>{address}</code>
+⏱️ Duration: {duration} hours
+💰 Fee: {fee} {symbol}
+
+🏦 <b>Payment Address ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 Payment Instructions:
+1. Send {fee} {symbol} to the payment address above
+2. Make sure you're sending from {chain} network
+3. Tap the address above to copy it
+4. Copy your transaction hash after the transfer
+5. Submit the transaction hash using the button below
+
+✅ Simple Process: Just send a regular {symbol} transfer - no complex contract calls needed!
+⏰ Payment expires in 30 minutes
+
+After successful transaction, submit your transaction hash below:`,
+  'payment.submit_tx_button': '📝 Submit Transaction Hash',
+  'trending.back_to_duration': '◀️ Back to Duration',
+  'trending.instructions_error': '❌ Error loading payment instructions. Please try again.',
+
+  // Group selection and image fees
+  'context.no_groups': `📭 <b>No Groups Available</b>
+
+You don't have any groups in common with this bot yet, or you're not an admin in those groups.
+
+<b>To get started:</b>
+1️⃣ Add me to your desired Telegram group
+2️⃣ Make sure you are an admin in that group
+3️⃣ Run /startminty in the group to set it up
+4️⃣ Come back here to add NFTs
+
+💡 Use the button below to start adding me to a group!`,
+  'context.add_to_group': '➕ Add Bot to Group',
+  'common.page': 'Page {page}/{total}',
+  'context.select': `🎯 <b>Where do you want to add this NFT?</b>
+
+Choose context:`,
+  'context.load_error': '❌ Error loading contexts. Please try again.',
+  'images.select_title': '🖼️ <b>Select NFT for Image Display</b>',
+  'images.none_viewer': `📝 No NFTs are being tracked in this group yet!
+
+ℹ️ Only admins can add NFT collections to track.`,
+  'images.none_admin': `📝 You need to add some NFT collections first!
+
+Add your NFTs to track them and enable image display.`,
+  'images.select_prompt': 'Choose which NFT collection to enable real image display for:',
+  'images_menu.back': '◀️ Back to Images Menu',
+  'tokens_menu.add_first': '➕ Add Your First NFT',
+  'token.not_found_retry': '❌ Token not found. Please try again.',
+  'token.selection_error': '❌ Error processing token selection. Please try again.',
+  'images.duration_title': `🎨 <b>Image Fee - Select Duration</b>
+
+🎯 <b>Selected NFT:</b> {name} ({symbol})
+🔗 <b>Blockchain:</b> {chain}
+
+Choose how long you want NFT images displayed instead of the Candy Codex image:`,
+  'duration.value_hint': '✨ Longer durations offer better value per day!',
+  'duration.error': '❌ Error showing duration options. Please try again.',
+  'common.back_to_tokens': '◀️ Back to Token Selection',
+  'images.already_active': '✅ Image fee is already active for this contract. Actual NFT images are being displayed.',
+  'images.instructions': `💰 <b>Image Fee Payment Instructions</b>
+
+🎯 <b>Selected NFT:</b> {name} ({symbol})
+🔗 <b>Blockchain:</b> {chain}
+📮 <b>Contract:</b> <code>{address}</code>
+📅 <b>Duration:</b> {duration}
+💸 <b>Fee:</b> {fee} {feeSymbol}
+
+🏦 <b>Payment Contract ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Payment Steps:</b>
+{steps}
+
+After making the payment, click the button below to submit your transaction hash.`,
+  'duration.back': '◀️ Back to Duration Selection',
+  'payment.instructions_error': '❌ Error generating payment instructions. Please try again.',
+
+  // Payment verification
+  'verify.type.image': 'Image Fee',
+  'verify.type.trending': 'Trending',
+  'verify.payment_name.image': 'image fee',
+  'verify.payment_name.trending': 'trending',
+  'verify.title': '{emoji} <b>Verify {type} Payment</b>',
+  'verify.none_admin': `📝 You need to add some NFT collections first!
+
+Add your NFTs to track them before verifying payments.`,
+  'verify.select_prompt': 'Select the NFT collection you made the payment for:',
+  'verify_menu.back': '◀️ Back to Verify Menu',
+  'verify.submit_tx': `📝 <b>Submit Transaction Hash</b>
+
+{emoji} <b>Payment Type:</b> {type}
+🎯 <b>NFT:</b> {name} ({symbol})
+{chainEmoji} <b>Blockchain:</b> {chain}
+
+Please send me your transaction hash for the {payment} payment.
+
+<i>Example: 0xabc123456789def...</i>`,
+  'verify.footer_not_found': '❌ Footer advertisement not found. Please try again.',
+  'verify.submit_footer_tx': `📝 <b>Submit Transaction Hash</b>
+
+🔗 <b>Payment Type:</b> Footer Advertisement
+💲 <b>Ticker:</b> {ticker}
+📎 <b>Link:</b> {link}
+
+Please send me your transaction hash for the footer advertisement payment.
+
+<i>Example: 0xabc123456789def...</i>`,
+  'verify.ticker_error': '❌ Error processing ticker selection. Please try again.',
+  'verify.footer_ticker_prompt': `🔗 <b>Verify Footer Payment</b>
+
+Please enter the ticker symbol you used when creating your footer advertisement.
+
+💡 <b>Include the $ prefix if you used one</b>
+
+<i>Examples: $PEPE, $BAYC, $AZUKI</i>
+<i>Or without $: PEPE, BAYC, AZUKI</i>`,
+  'verify.ticker_input_error': '❌ Error showing ticker input. Please try again.',
+  'verify.footer_title': '🔗 <b>Verify Footer Payments</b>',
+  'verify.no_footer_ads': `❌ <b>No footer advertisements found!</b>
+
+You haven't purchased any footer ads yet. Purchase a footer advertisement first to verify payments.`,
+  'verify.buy_footer': '💰 Buy Footer Ad',
+  'verify.select_footer': `Select which footer advertisement to verify:
+
+💡 Choose from your purchased tickers:`,
+  'verify.footer_load_error': '❌ Error loading footer advertisements. Please try again.',
+
+  // Tracked collections and trending purchases
+  'tokens.context_header': '👥 <b>{label}</b> ({count} tokens)',
+  'tokens.context_header_one': '👥 <b>{label}</b> ({count} token)',
+  'tokens.alerts_button': '🔔 Alerts: {name}',
+  'tokens.remove_from_button': '🗑️ Remove {name}... from {context}',
+  'tokens.none_button': `🔍 You haven't added any tokens yet!
+
+Use the button below to start tracking NFT collections.`,
+  'tokens.none_group': `🔍 No NFTs are being tracked in this group yet!
+
+ℹ️ Only admins can add NFT collections to track.`,
+  'tokens.title_all': '🎯 <b>Your Tracked NFTs</b> ({total} total across {count} contexts)',
+  'tokens.title_all_one': '🎯 <b>Your Tracked NFTs</b> ({total} total across {count} context)',
+  'tokens.title_total': '🎯 <b>Your Tracked NFTs</b> ({total} total)',
+  'tokens.title_group': `🎯 <b>Tracked NFTs in this Group</b> ({total} total)
+
+ℹ️ <i>Read-only view - only admins can manage collections</i>`,
+  'tokens.status_active': '🟢 Status: Active',
+  'tokens.tracking_active': '📊 Tracking: ✅ Active',
+  'tokens_menu.add_more': '➕ Add More NFTs',
+  'tokens_menu.back': '◀️ Back to NFTs Menu',
+  'tokens.none_add_first': `📝 You need to add some NFT collections first!
+
+Use /add_token to track your first NFT collection.`,
+  'trending_menu.back': '◀️ Back to Trending Menu',
+  'trending.tier.premium': 'Premium',
+  'trending.tier.normal': 'Normal',
+  'trending.boost_title': `🚀 <b>{tier} Trending Boost</b>
+
+Select an NFT collection to boost:`,
+  'trending.back_to_buy': '🔄 Back to Buy Trending Menu',
+  'trending.chain_title': `🚀 <b>{tier} Trending - {name}</b>
+
+🔗 Select blockchain network for payment:`,
+  'trending.back_to_nfts': '◀️ Back to NFT Selection',
+  'trending.chain_error': '❌ Error loading chain options. Please try again.',
+  'trending.badge.premium': '⭐ PREMIUM',
+  'trending.badge.normal': '🔵 STANDARD',
+  'trending.group_link_prompt': '📎 <b>Group Link (Optional)</b>\n\n<b>Selected:</b> {name}\n<b>Tier:</b> {tier}\n\nWould you like to include a Telegram group link? This link will appear as a clickable ticker (💬 ${symbol}) in event notifications.\n\n<b>Accepted formats:</b>\n• Full link: https://t.me/mygroup\n• With @: @mygroup\n• Plain text: mygroup\n\nSend your group link now, or tap Skip to continue without a link.',
+  'trending.skip_group_link': '⏭️ Skip (No Group Link)',
+  'trending.group_link_error': '❌ Error loading group link input. Please try again.',
+  'trending.duration_title': `🚀 <b>{tier} Trending - {name}</b>
+
+🔗 Chain: <b>{chain}</b> <i>(auto-detected)</i>
+
+Select trending duration:`,
+  'trending.duration_button': '{hours}h - {price}',
+  'trending.change_chain': '🔄 Choose Different Chain',
+  'trending.options_error': '❌ Error loading trending options. Please try again.',
+
+  // Footer, image and payment validation
+  'common.too_many_pending': '❌ You have too many pending operations. Please complete or cancel existing operations before starting new ones.',
+  'common.user_not_found': '❌ User not found. Please use /start first.',
+  'validation.validating_address': '🔍 Validating NFT address...',
+  'validation.contract_failed': '❌ Contract validation failed: {error}',
+  'validation.validating_tx': '🔍 Validating your transaction... Please wait.',
+  'validation.validating_image': '⏳ Validating your image fee transaction...',
+  'validation.validating_footer': '⏳ Validating your footer advertisement payment...',
+  'validation.validating_footer_tx': '⏳ Validating your footer advertisement transaction...',
+  'validation.payment_failed': `❌ Payment validation failed: {error}
+
+Please ensure you sent the correct amount to the payment contract.`,
+  'validation.failed': '❌ Validation failed: {error}',
+  'validation.failed_markdown': `❌ **Validation Failed**
+
+{error}`,
+  'footer_menu.back': '◀️ Back to Footer Menu',
+  'footer.payment': `💰 <b>Footer Advertisement Payment</b>
+
+🎨 <b>Collection:</b> {name}
+🎯 <b>Token:</b> {symbol}
+💸 <b>Fee:</b> {fee} {feeSymbol}
+⏰ <b>Duration:</b> {duration}
+📮 <b>Contract:</b> <code>{address}</code>
+
+📋 <b>Payment Steps:</b>
+{steps}`,
+  'footer.default_step': 'Send payment to NFT address',
+  'footer.payment_verified_details': `✅ <b>Payment Verified!</b>
+
+🎨 Collection: <b>{name}</b>
+💰 Amount: {amount}
+📅 Duration: <b>{duration}</b>
+
+🔗 <b>Custom Link</b>
+
+Now please send me the custom link you want to display in the footer ads.
+
+<i>Example: https://mytoken.com</i>`,
+  'footer.payment_verified': `✅ <b>Payment Verified!</b>
+
+🔗 <b>Custom Link</b>
+
+Now please send me the custom link you want to display in the footer ads.
+
+<i>Example: https://mytoken.com</i>`,
+  'footer.session_invalid': '❌ Session expired or payment not validated. Please start again.',
+  'footer.invalid_url': '❌ Invalid URL format. Please provide a valid URL (e.g., https://mytoken.com).',
+  'footer.creating': '⏳ Creating your footer advertisement...',
+  'footer.payment_validated': `✅ <b>Footer Payment Validated!</b>
+
+🔗 <b>Ticker:</b> {ticker}
+💰 <b>Amount:</b> {amount} {symbol}
+📝 <b>Transaction:</b> <code>{txHash}</code>
+
+📢 Your footer advertisement is now active!`,
+  'footer.validation_failed': '❌ Footer validation failed: {error}',
+  'trending.group_link_invalid': '❌ Invalid link format. Please send a valid Telegram group link or type "cancel" to go back.',
+  'trending.group_link_saved': '✅ Group link saved: {link}',
+  'images.contract_instructions': `💰 <b>Image Fee Payment Instructions</b>
+
+🎨 Collection: <b>{name}</b>
+📮 Contract: <code>{address}</code>
+📅 Duration: <b>{duration}</b>
+💸 Fee: <b>{fee} {feeSymbol}</b>
+
+🏦 <b>Payment Address ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Payment Steps:</b>
+{steps}
+
+After making the payment, click the button below to submit your transaction hash.`,
+  'images.payment_validated': `✅ <b>Image Fee Payment Validated!</b>
+
+🎨 Collection: <b>{name}</b>
+📮 Contract: <code>{address}</code>
+💰 Amount: {amount} {symbol}
+📅 Duration: <b>{duration}</b>
+📝 Transaction: <code>{txHash}</code>
+👤 Payer: <code>{payer}</code>
+
+🖼️ <b>Actual NFT images will now be displayed for this contract for {duration}!</b>`,
+  'trending.payment_validated': '✅ **Payment Validated Successfully!**\n\n🎯 **{name}** trending activated!\n⏱️ Duration: {hours} hours\n💰 Amount: {amount} {symbol}\n🔗 TX: `{txHash}`\n\nYour NFT is now trending! 🚀',
+  'verify.payment_name.footer': 'footer advertisement',
+  'verify.tx_hash_required': `📝 <b>Transaction Hash Required</b>
+
+Now please send me the transaction hash for your {payment} payment.
+
+<i>Example: 0xabc123456789def...</i>`,
+  'verify.back_to_payments': '◀️ Back to Verify Payments',
+  'verify.invalid_ticker': `❌ Invalid ticker format. Please enter a valid ticker symbol.
+
+<i>Examples: $PEPE, $BAYC, $AZUKI</i>
+
+💡 Use the same ticker you used when creating your footer ad.`,
+  'verify.submit_ticker_tx': `📝 <b>Submit Transaction Hash</b>
+
+🔗 <b>Footer Ticker:</b> {ticker}
+
+Please send me your transaction hash for the footer payment.
+
+<i>Example: 0xabc123456789def...</i>`,
+
+  // Chain lists and footer ad flow
+  'common.register_first': '❌ Please register first using /start',
+  'tokens.none_on_chain_add': `🔍 <b>No NFTs found on {chain}</b>
+
+Add some NFTs to start tracking on this blockchain!`,
+  'tokens.title_chain_count': '🎯 <b>Your {chain} Tokens</b> ({count})',
+  'tokens_menu.add_on_chain': '➕ Add NFT on This Chain',
+  'tokens_menu.view_all_chains': '🌐 View All Chains',
+  'tokens_menu.all_chains': '🌐 All Chains',
+  'images.duration_menu_title': `🎨 <b>Image Fee - Select Duration</b>
+
+Choose how long you want NFT images displayed instead of the Candy Codex image:`,
+  'duration.pricing_hint': '💡 Pricing will be shown after chain selection.',
+  'footer.duration_title': `📢 <b>Footer Advertisement - Select Duration</b>
+
+{emoji} <b>Chain:</b> {chain}
+
+Choose how long your footer ad will be displayed:`,
+  'footer.duration_hint': '💡 Your ad will appear at the bottom of all token notifications!',
+  'images.chain_title': `🎨 <b>Image Fee Payment - Select Chain</b>
+
+📅 Duration: <b>{duration}</b>
+
+🔗 Select the blockchain network:
+💡 Pricing varies by chain and will be shown on the payment screen.`,
+  'footer.chain_title': `📢 <b>Footer Ad Payment - Select Chain</b>
+
+🔗 Select the blockchain network for your footer ad payment:
+
+💡 Duration and pricing will be shown on the next step.`,
+  'payment.chain_error': '❌ Error showing chain selection. Please try again.',
+  'images.contract_title': '🎨 <b>Image Fee Payment - Enter Contract</b>',
+  'footer.contract_title': '📢 <b>Footer Ad Payment - Enter Contract</b>',
+  'payment.contract_prompt': `{title}
+
+📅 Duration: <b>{duration}</b>
+💰 Amount: <b>{amount}</b>
+🔗 Chain: <b>{chain}</b>
+
+📝 Please enter the NFT address:
+
+<i>Example: 0x1234567890abcdef...</i>`,
+  'payment.back_to_chain': '◀️ Back to Chain Selection',
+  'payment.back_to_contract': '◀️ Back to Contract Input',
+  'payment.contract_input_error': '❌ Error showing contract input. Please try again.',
+  'footer.link_title': '🔗 <b>Footer Advertisement - Custom Link</b>',
+  'payment.chain_line': '🔗 Chain: <b>{chain}</b>',
+  'footer.link_prompt': `Please send me the custom link you want users to visit when they click your footer ad.
+
+<i>Example: https://mytoken.com or https://twitter.com/myproject</i>`,
+  'footer.link_input_error': '❌ Error showing link input. Please try again.',
+  'footer.ticker_prompt': `💰 <b>Footer Advertisement - Ticker Symbol</b>
+
+Please send me the ticker symbol you want to display in the footer.
+
+<i>Examples: $CANDY, $PEPE, $MYTOKEN</i>
+
+⭐️ Your ticker will appear as: <b>⭐️$YOURTICKER</b>`,
+  'footer.back_to_link': '◀️ Back to Link Input',
+  'footer.back_to_ticker': '◀️ Back to Ticker Input',
+  'footer.invalid_link': `❌ Invalid URL format. Please provide a valid URL starting with http:// or https://.
+
+<i>Example: https://mytoken.com</i>`,
+  'footer.invalid_ticker': `❌ Invalid ticker format. Please use only letters and numbers, max 10 characters. <b>No spaces allowed.</b>
+
+<i>Examples: $CANDY, $PEPE, $MYTOKEN</i>`,
+  'footer.contract_instructions': `📢 <b>Footer Advertisement Payment Instructions</b>
+
+🎨 Collection: <b>{name}</b>
+📮 Contract: <code>{address}</code>
+🔗 Custom Link: {link}
+⭐️ Ticker: <b>{ticker}</b>
+📅 Duration: <b>{duration}</b>
+💸 Fee: <b>{fee} {feeSymbol}</b>
+
+🏦 <b>Payment Address ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Payment Steps:</b>
+{steps}
+
+After making the payment, click the button below to submit your transaction hash.`,
+  'footer.payment_instructions': `📢 <b>Footer Advertisement Payment Instructions</b>
+
+🔗 Custom Link: {link}
+⭐️ Ticker: <b>{ticker}</b>
+📅 Duration: <b>{duration}</b>
+💸 Fee: <b>{amount}</b>
+{emoji} Chain: <b>{chain}</b>
+
+🏦 <b>Payment Address ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Payment Steps:</b>
+1. <b>SEND EXACTLY {amount}</b> to the payment address above
+2. Use any {chain} wallet on {network} network
+3. Tap the address above to copy it
+4. Wait for transaction confirmation
+5. Submit your transaction hash below
+
+After making the payment, click the button below to submit your transaction hash.`
+};
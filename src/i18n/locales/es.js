@@ -0,0 +1,1070 @@
+// Spanish
+module.exports = {
+  // Shared
+  'common.error': '❌ Se produjo un error. Inténtalo de nuevo.',
+  'common.start_first': 'Primero inicia el bot con /startminty',
+  'common.cancelled': '✅ Operación cancelada.',
+  'common.coming_soon': '¡Función disponible pronto!',
+  'common.request_error': '❌ Error al procesar la solicitud',
+  'common.back_main': '◀️ Volver al menú principal',
+  'common.back_to': '◀️ Volver a {menu}',
+  'common.previous': '⬅️ Anterior',
+  'common.next': '➡️ Siguiente',
+  'common.unknown': 'Desconocido',
+
+  // Welcome, help and main menu
+  'welcome.message': `🚀 <b>¡Bienvenido a Minty Rush!</b> 🚀
+
+Te ayudo a seguir colecciones NFT y recibir alertas en tiempo real de:
+• Nuevos mints y transferencias
+• Ventas y cambios de precio
+• Colecciones en tendencia
+• Seguimiento personalizado de NFT
+
+<b>Empieza eligiendo una opción del menú:</b>`,
+  'help.message': `📋 <b>Comandos de Minty RushBot</b>
+
+🎯 <b>Gestión de NFT:</b>
+• /add_token - Añadir una colección NFT para seguir
+• /remove_token - Dejar de seguir un NFT
+• /my_tokens - Ver tus NFT seguidos
+
+👛 <b>Carteras vigiladas:</b>
+• /watch_wallet &lt;address&gt; [chain] [label] - Alertas de la actividad NFT de una cartera
+• /unwatch_wallet &lt;address&gt; - Dejar de vigilar una cartera
+• /wallets - Ver las carteras vigiladas en este chat
+
+📈 <b>Alertas de suelo y volumen:</b>
+• /stats_alert &lt;collection&gt; floor below 0.5 - Alertas por umbrales de suelo o volumen 24h
+• /stats_alerts - Ver las alertas de suelo y volumen de este chat
+• /stats_alert_remove &lt;id&gt; - Eliminar una alerta
+
+💰 <b>Tendencias y Boost:</b>
+• /trending - Ver colecciones en tendencia
+• /buy_trending - Impulsar un NFT en tendencias
+• /validate &lt;txhash&gt; - Validar el pago de tendencia
+• /buy_image &lt;contract&gt; - Pagar la tarifa de imágenes reales del NFT
+• /validate_image &lt;contract&gt; &lt;txhash&gt; - Validar la tarifa de imagen
+• /buy_footer &lt;contract&gt; - Pagar un anuncio en el pie de las alertas
+• /validate_footer &lt;contract&gt; &lt;txhash&gt; &lt;link&gt; - Validar el anuncio del pie
+
+📺 <b>Comandos de canal:</b>
+• /add_channel - Añadir el bot a un canal
+• /channel_settings - Configurar las alertas del canal
+
+🔗 <b>Webhooks (administradores del grupo):</b>
+• /webhook_add &lt;contract&gt; &lt;https-url&gt; - Enviar la actividad a tu endpoint
+• /webhooks - Ver los webhooks de este grupo
+• /webhook_remove &lt;id&gt; - Eliminar un webhook
+• /webhook_secret &lt;id&gt; - Renovar el secreto de firma
+
+⚙️ <b>Ajustes de alertas:</b>
+• /sweep_mode [aggregated|individual] - Un mensaje por barrido o por artículo
+• /quiet_hours &lt;inicio&gt; &lt;fin&gt; [zona horaria]|off - Retener alertas y publicar un resumen después
+• /digest &lt;minutos&gt;|off - Publicar las alertas como resumen periódico
+• /templates - Editar, previsualizar y restablecer plantillas de alertas
+• /language [en|es|ru|zh] - Idioma de menús y alertas
+
+• /startminty - Mensaje de bienvenida
+• /help - Mostrar esta ayuda
+
+Simple y directo: ¡impulsa tus NFT fácilmente! 🚀`,
+  'menu.manage_nfts': '📊 Gestionar NFT',
+  'menu.trending': '🔥 Tendencias y Boost',
+  'menu.images': '🖼️ Mostrar imagen NFT',
+  'menu.footer': '🔗 Anuncios en el pie',
+  'menu.channels': '📺 Ajustes de canal',
+  'menu.verify': '✅ Verificar pagos',
+  'menu.help': '❓ Ayuda y contacto',
+  'menu.language': '🌐 Idioma',
+  'contact.message': `📞 <b>Ayuda y contacto</b>
+
+🌐 <b>Web:</b> https://www.candycodex.com
+
+📧 <b>Correo:</b> support@candycodex.com
+
+💬 <b>Telegram:</b> @CandyCodex
+
+<i>¿Necesitas ayuda? ¡Escríbenos por cualquiera de estos canales!</i>`,
+
+  // Group setup
+  'group_start.message': `🎉 <b>¡Bienvenido a Minty Rush!</b>
+
+Para configurar el bot en <b>{group}</b>
+elige una opción:`,
+  'group_start.this_group': 'este grupo',
+  'group_start.setup_chat': '💬 Configurar en el chat',
+  'group_start.setup_bot': '🤖 Configurar en el BOT',
+  'start.not_admin': '⚠️ Ya no eres administrador de ese grupo.',
+  'start.invalid_setup': '❌ Enlace de configuración no válido o caducado. Ejecuta /startminty en el grupo de nuevo.',
+
+  // Top-level menus
+  'tokens_menu.admin': `📊 <b>Gestión de NFT</b>
+
+<b>Gestiona tus colecciones NFT:</b>`,
+  'tokens_menu.viewer': `📊 <b>Colecciones NFT</b>
+
+<b>Colecciones NFT seguidas en este grupo:</b>
+
+ℹ️ <i>Solo los administradores pueden añadir o quitar colecciones</i>`,
+  'tokens_menu.add': '➕ Añadir colección NFT',
+  'tokens_menu.view_mine': '👁️ Ver mis NFT',
+  'tokens_menu.remove': '🗑️ Quitar colección NFT',
+  'tokens_menu.view_tracked': '👁️ Ver NFT seguidos',
+  'images_menu.message': `🖼️ <b>Imagen del NFT</b>
+
+<b>Muestra las imágenes reales del NFT en lugar de la imagen de Candy Codex:</b>`,
+  'images_menu.pay': '💳 Mostrar imagen NFT',
+  'footer_menu.message': `🔗 <b>Anuncio en el pie</b>
+
+<b>Anuncia tu proyecto en el pie de las alertas:</b>`,
+  'footer_menu.pay': '💳 Pagar anuncio en el pie',
+  'channels_menu.empty': `📺 <b>Gestión de canales</b>
+
+Todavía no has añadido ningún canal.
+
+<b>Para empezar:</b>
+1️⃣ Añádeme a tu canal como administrador
+2️⃣ Ejecuta /add_channel en el canal
+3️⃣ Vuelve aquí para configurarlo`,
+  'channels_menu.list': `📺 <b>Mis canales</b>
+
+Elige un canal para configurarlo:`,
+  'verify_menu.message': `✅ <b>Verifica tus pagos</b>
+
+Elige el tipo de pago que quieres verificar:
+
+`,
+  'verify_menu.trending': '🔍 Verificar tendencia',
+  'verify_menu.image': '🖼️ Verificar imagen',
+  'verify_menu.footer': '🔗 Verificar pie',
+  'verify_menu.error': '❌ Error al cargar las opciones de verificación. Inténtalo de nuevo.',
+  'trending_menu.message': `<b>🔥 Comprar tendencia</b>
+
+Elige el nivel:
+
+<b>🔵 Tendencia estándar</b>
+Más económica, se muestra en los canales de nivel estándar
+
+<b>⭐ Tendencia premium</b>
+Más visibilidad, se muestra en los canales de nivel premium`,
+  'trending_menu.buy_standard': '🔵 Comprar tendencia estándar',
+  'trending_menu.buy_premium': '⭐ Comprar tendencia premium',
+  'trending_menu.error': '❌ Error al cargar el menú de tendencias. Inténtalo de nuevo.',
+
+  // Channel settings
+  'channel.mode.all': '<b>Toda la actividad</b>\nAlertas en tiempo real de TODOS los NFT seguidos en cualquier grupo',
+  'channel.mode.none': '<b>Sin alertas</b>\nLas alertas del canal están desactivadas',
+  'channel.mode.normal': '<b>Tendencia estándar</b>\nPublicaciones programadas de las promociones de nivel estándar',
+  'channel.mode.premium': '<b>Tendencia premium</b>\nPublicaciones programadas de las promociones de nivel premium',
+  'channel.sweeps.aggregated': 'Un mensaje por barrido',
+  'channel.sweeps.individual': 'Un mensaje por artículo',
+  'channel.digest_hint': '<i>Publica /quiet_hours o /digest en el canal para cambiarlo.</i>',
+  'channel.settings': `⚙️ <b>{title}</b>
+
+<b>Modo de alertas actual:</b>
+{mode}
+
+<b>Barridos:</b> {sweeps}
+<b>Idioma:</b> {language}
+{digest}
+Pulsa el botón para cambiar de modo de alertas:`,
+  'channel.change_mode': '{icon} Cambiar modo de alertas',
+  'channel.sweeps_button.aggregated': '🧹 Barridos: agrupados',
+  'channel.sweeps_button.individual': '🧹 Barridos: individuales',
+  'channel.language_button': '🌐 Idioma: {language}',
+  'channel.add_nfts': '➕ Añadir NFT',
+  'channel.refresh': '🔄 Actualizar',
+  'channel.back': '◀️ Volver a canales',
+
+  // Chat settings commands
+  'settings.unavailable': '❌ Los ajustes del chat no están disponibles.',
+  'settings.channel_private_only': '❌ Los ajustes de un canal se cambian con /channel_settings en un chat privado conmigo.',
+  'settings.admins_only': '❌ Solo los administradores del grupo pueden cambiar los ajustes de alertas.',
+  'sweep.status': `🧹 <b>Alertas de barridos:</b> {mode}
+
+<b>aggregated</b> - un mensaje por barrido con número de artículos, total, precio medio y un collage
+<b>individual</b> - un mensaje por artículo comprado
+
+Cámbialo con <code>/sweep_mode aggregated</code> o <code>/sweep_mode individual</code>`,
+  'sweep.unknown_mode': '❌ Modo desconocido "{mode}". Usa: {modes}',
+  'sweep.saved.aggregated': '✅ Los barridos se publicarán en un solo mensaje con collage.',
+  'sweep.saved.individual': '✅ Cada artículo de un barrido se publicará por separado.',
+  'sweep.error': '❌ Error al cambiar el modo de barridos. Inténtalo de nuevo.',
+  'quiet.summary': '🌙 <b>Horas de silencio:</b> {quiet}\n📰 <b>Resumen:</b> {digest}',
+  'quiet.off': 'desactivadas',
+  'quiet.active_now': '<i>Las horas de silencio están activas: las alertas se guardan para el resumen.</i>',
+  'quiet.help': `Durante las horas de silencio las alertas se retienen y se publican en un solo resumen (ventas, volumen, mejor venta, cambio del floor) al terminar.
+
+Configúralas con <code>/quiet_hours 23:00 07:00 Europe/Madrid</code> (zona horaria opcional, UTC por defecto)
+Desactívalas con <code>/quiet_hours off</code>`,
+  'quiet.turned_off': '✅ Horas de silencio desactivadas. Las alertas retenidas se publicarán en breve.',
+  'quiet.invalid_times': '❌ Usa horas como 23:00 y 07:00, p. ej. /quiet_hours 23:00 07:00 Europe/Madrid',
+  'quiet.same_times': '❌ Las horas de silencio deben empezar y terminar a horas distintas.',
+  'quiet.unknown_timezone': '❌ Zona horaria desconocida "{timezone}". Usa un nombre como UTC, Europe/Madrid o America/Mexico_City.',
+  'quiet.saved': '✅ Horas de silencio guardadas.',
+  'quiet.error': '❌ Error al cambiar las horas de silencio. Inténtalo de nuevo.',
+  'digest.every': 'cada {minutes} min',
+  'digest.off': 'desactivado (alertas en vivo)',
+  'digest.help': `En modo resumen las alertas se acumulan y se publican en un solo mensaje cada cierto intervalo.
+
+Configúralo con <code>/digest 60</code> (minutos) o <code>/digest 4h</code>
+Vuelve a las alertas en vivo con <code>/digest off</code>`,
+  'digest.turned_off': '✅ Modo resumen desactivado. Las alertas retenidas se publicarán en breve y después llegarán en vivo.',
+  'digest.invalid_interval': '❌ Usa un intervalo de entre 5 minutos y {maxHours} horas, p. ej. /digest 60 o /digest 4h',
+  'digest.saved': '✅ Modo resumen activado.',
+  'digest.error': '❌ Error al cambiar el modo resumen. Inténtalo de nuevo.',
+
+  // Alert templates
+  'templates.unavailable': '❌ Las plantillas de mensajes no están disponibles.',
+  'templates.menu': `🧩 <b>Plantillas de alertas</b>
+
+Elige cómo se ven las alertas en este chat. Las plantillas usan HTML de Telegram y marcadores como <code>{collection}</code> y <code>{price}</code>.
+
+{lines}
+
+Las alertas de tendencia, los barridos y los resúmenes mantienen su propio formato.`,
+  'templates.custom': '✏️ personalizada',
+  'templates.default': 'predeterminada',
+  'templates.editor': `🧩 Plantilla <b>{type}</b> ({state})
+
+<pre>{template}</pre>`,
+  'templates.state.custom': 'personalizada',
+  'templates.state.default': 'predeterminada',
+  'templates.edit': '✏️ Editar',
+  'templates.preview': '👁️ Vista previa',
+  'templates.reset': '♻️ Restablecer',
+  'templates.back': '◀️ Volver a plantillas',
+  'templates.caption_warning': '⚠️ Con nombres largos podría superar el límite de 1024 caracteres de Telegram.',
+  'templates.edit_prompt': `✏️ Envía la nueva plantilla <b>{type}</b>.
+
+Etiquetas permitidas: &lt;b&gt;, &lt;i&gt;, &lt;u&gt;, &lt;s&gt;, &lt;code&gt;, &lt;pre&gt;, &lt;a href="..."&gt;, &lt;blockquote&gt;, &lt;tg-spoiler&gt;. Las líneas cuyos marcadores estén todos vacíos se omiten.
+
+{placeholders}
+
+Escribe <code>cancel</code> para salir.`,
+  'templates.wrong_chat': '❌ Abre /templates de nuevo en el chat que quieres cambiar.',
+  'templates.invalid': '❌ {reason}\n\nCorrige la plantilla y envíala de nuevo, o escribe cancel.',
+  'templates.error.empty': 'La plantilla está vacía.',
+  'templates.error.too_long': 'La plantilla tiene {length} caracteres; el límite es {max}.',
+  'templates.error.unknown_placeholder': 'Marcador desconocido {{name}}.',
+  'templates.error.malformed_tag': 'Etiqueta no admitida o mal formada. Escribe un "<" literal como &lt;.',
+  'templates.error.unsupported_tag': 'Telegram no admite la etiqueta <{tag}>.',
+  'templates.error.wrong_close': '</{tag}> cierra <{open}>.',
+  'templates.error.unopened_close': '</{tag}> no tiene etiqueta de apertura.',
+  'templates.error.link_href': '<a> necesita un atributo href="...".',
+  'templates.error.attribute_placeholder': '{{name}} no se puede usar dentro de una etiqueta; usa {item_url} o {explorer_url} en href.',
+  'templates.error.span_class': '<span> solo se admite como <span class="tg-spoiler">.',
+  'templates.error.no_attributes': '<{tag}> no admite atributos.',
+  'templates.error.unclosed': '<{tag}> nunca se cierra.',
+  'templates.error.ampersand': 'Escribe un "&" literal como &amp;.',
+  'templates.saved': '✅ Plantilla {type} guardada. Vista previa:',
+  'templates.load_error': '❌ Error al cargar las plantillas. Inténtalo de nuevo.',
+  'templates.save_error': '❌ Error al guardar la plantilla. Inténtalo de nuevo.',
+
+  // Per-subscription alert filters
+  'filters.not_found': '❌ Suscripción no encontrada. Abre /my_tokens de nuevo.',
+  'filters.title': '🔔 <b>Ajustes de alertas: {name}</b>',
+  'filters.none': 'Sin filtros: se publican todas las ventas, listados, ofertas, mints y transferencias.',
+  'filters.tap_hint': 'Pulsa un tipo de evento para activarlo o desactivarlo.',
+  'filters.type.sale': 'Ventas',
+  'filters.type.listing': 'Listados',
+  'filters.type.offer': 'Ofertas',
+  'filters.type.mint': 'Mints',
+  'filters.type.transfer': 'Transferencias',
+  'filters.min_price': '💲 Precio mínimo: {value}',
+  'filters.marketplaces': '🏪 Marketplaces: {value}',
+  'filters.value.none': 'ninguno',
+  'filters.value.all': 'todos',
+  'filters.value.off': 'no',
+  'filters.value.below_floor': 'bajo el suelo',
+  'filters.value.include': 'incluir {names}',
+  'filters.value.exclude': 'excluir {names}',
+  'filters.burns.hidden': '🔥 Quemas: ocultas',
+  'filters.burns.shown': '🔥 Quemas: visibles',
+  'filters.snipe': '🎯 Modo snipe: {value}',
+  'filters.traits': '🧬 Rasgos: {value}',
+  'filters.reset': '♻️ Restablecer filtros',
+  'filters.back': '◀️ Volver a mis NFT',
+  'filters.min_price_prompt': '💲 Envía el precio mínimo para las alertas de ventas, listados y ofertas:\n\n<code>0.5</code> - en la moneda nativa\n<code>$250</code> - en USD\n<code>off</code> - sin mínimo\n\nEscribe <code>cancel</code> para salir.',
+  'filters.marketplaces_prompt': '🏪 Envía los marketplaces que quieres incluir o excluir:\n\n<code>include opensea, magic eden</code>\n<code>exclude blur</code>\n<code>off</code> - todos los marketplaces\n\nEscribe <code>cancel</code> para salir.',
+  'filters.snipe_prompt': '🎯 El modo snipe solo publica listados con precio por debajo del suelo de la colección. Envía cuánto por debajo:\n\n<code>on</code> - cualquier listado bajo el suelo\n<code>15%</code> - al menos un 15% bajo el suelo\n<code>off</code> - volver a todas las alertas\n\nEscribe <code>cancel</code> para salir.',
+  'filters.traits_prompt': '🧬 Envía los rasgos de los que quieres alertas. El ítem debe tener uno de ellos:\n\n<code>Fur: Gold; Eyes: Laser</code> - tipo y valor del rasgo\n<code>1/1</code> - un valor (o tipo de rasgo) de cualquier tipo\n<code>off</code> - todos los ítems\n\nLos rasgos vienen del snapshot de rareza de la colección. Los ítems que aún no están en él no se publican.\n\nEscribe <code>cancel</code> para salir.',
+  'filters.load_error': '❌ Error al cargar los ajustes de alertas. Inténtalo de nuevo.',
+  'filters.save_error': '❌ Error al guardar el filtro. Inténtalo de nuevo.',
+  'filters.summary.events': 'Eventos: {types}',
+  'filters.summary.min_price': 'Precio mínimo: {value}',
+  'filters.summary.marketplaces': 'Marketplaces: {value}',
+  'filters.summary.burns': 'Quemas ignoradas',
+  'filters.summary.snipe_discount': 'Modo snipe: listados {discount}%+ bajo el suelo',
+  'filters.summary.snipe_floor': 'Modo snipe: listados bajo el suelo',
+  'filters.summary.traits': 'Rasgos: {traits}',
+  'filters.error.min_price': 'Envía un número como 0.5 (moneda nativa) o $250 (USD), u "off".',
+  'filters.error.marketplace_mode': 'Empieza con "include" o "exclude", p. ej. "exclude blur" o "include opensea, magic eden".',
+  'filters.error.marketplace_empty': 'Indica al menos un marketplace.',
+  'filters.error.snipe': 'Envía "on" para cualquier listado bajo el suelo, un porcentaje como 15%, u "off".',
+  'filters.error.traits': 'Envía rasgos como "Fur: Gold; Eyes: Laser" o un valor como "1/1", u "off".',
+  'filters.error.too_many_traits': 'Envía como máximo {max} rasgos.',
+  'filters.error.trait_too_long': 'Cada rasgo puede tener como máximo {max} caracteres.',
+
+  // Language
+  'language.menu': `🌐 <b>Idioma</b>
+
+Actual: {language}
+
+Elige el idioma de los menús y las alertas en este chat:`,
+  'language.saved': '✅ Idioma cambiado a {language}.',
+  'language.unknown': '❌ Idioma desconocido "{code}". Usa uno de: {codes}',
+  'language.error': '❌ Error al cambiar el idioma. Inténtalo de nuevo.',
+
+  // Wallet watchlists
+  'wallet.usage': `👛 <b>Vigilar una cartera</b>
+
+<code>/watch_wallet &lt;address&gt; [chain] [label]</code>
+
+Recibe una alerta por cada NFT que la cartera compre, venda, mintee o mueva, en cualquier colección. Las direcciones EVM se vigilan en Ethereum salvo que indiques una red, p. ej. <code>/watch_wallet 0x… base Ballena</code>. Las direcciones de Solana y Bitcoin se reconocen automáticamente.`,
+  'wallet.invalid_address': '❌ No es una dirección EVM, Solana o Bitcoin válida.',
+  'wallet.chain_unavailable': '❌ La vigilancia de carteras no está disponible en {chain}.',
+  'wallet.limit': '❌ Este chat ya vigila {limit} carteras. Quita una con /unwatch_wallet primero.',
+  'wallet.saved': '✅ Vigilando <code>{address}</code> en {chain}.',
+  'wallet.updated': '✅ Etiqueta de <code>{address}</code> actualizada.',
+  'wallet.sync_failed': '⚠️ <code>{address}</code> se ha guardado, pero el webhook de {chain} aún no se pudo actualizar. Las alertas empezarán cuando se sincronice.',
+  'wallet.removed': '✅ Ya no se vigila <code>{address}</code>.',
+  'wallet.not_found': '❌ <code>{address}</code> no se vigila en este chat.',
+  'wallet.unwatch_usage': 'Uso: <code>/unwatch_wallet &lt;address&gt;</code>',
+  'wallet.list_empty': '👛 Todavía no se vigila ninguna cartera en este chat.\n\nAñade una con <code>/watch_wallet &lt;address&gt; [chain] [label]</code>.',
+  'wallet.list_title': '👛 <b>Carteras vigiladas</b> ({count}/{limit})',
+  'wallet.list_footer': 'Deja de vigilar una con <code>/unwatch_wallet &lt;address&gt;</code>.',
+  'wallet.error': '❌ Error al actualizar las carteras vigiladas. Inténtalo de nuevo.',
+  'wallet.unavailable': '❌ La vigilancia de carteras no está disponible.',
+  'wallet.action.bought': 'compró',
+  'wallet.action.sold': 'vendió',
+  'wallet.action.minted': 'minteó',
+  'wallet.action.received': 'recibió',
+  'wallet.action.sent': 'envió',
+  'wallet.action.burned': 'quemó',
+
+  // Floor and volume alerts
+  'stats_alert.usage': `📈 <b>Alertas de suelo y volumen</b>
+
+<code>/stats_alert &lt;collection&gt; floor below 0.5</code>
+<code>/stats_alert &lt;collection&gt; floor above 2</code>
+<code>/stats_alert &lt;collection&gt; floor change 10% 1h</code>
+<code>/stats_alert &lt;collection&gt; volume above 100</code>
+
+La colección es la dirección del contrato, el slug o el nombre de un NFT seguido en este chat. Los importes van en la moneda de la colección (ETH, SOL, BTC) y el volumen cubre las últimas 24 horas. Una alerta de cambio compara con el valor de una ventana antes (1h por defecto, hasta 7d).`,
+  'stats_alert.invalid_rule': '❌ No se entiende la regla. Usa <code>floor|volume below|above &lt;importe&gt;</code> o <code>floor|volume change &lt;porcentaje&gt; [ventana]</code>.',
+  'stats_alert.not_tracked': '❌ <b>{collection}</b> no se sigue en este chat. Añádela antes con /add_token.',
+  'stats_alert.saved': '✅ Alerta #{id} guardada: <b>{collection}</b> {rule}.',
+  'stats_alert.limit': '❌ Este chat ya tiene {limit} alertas de suelo y volumen. Elimina una con /stats_alert_remove primero.',
+  'stats_alert.list_empty': '📈 Aún no hay alertas de suelo ni de volumen en este chat.\n\nAñade una con <code>/stats_alert &lt;collection&gt; floor below &lt;importe&gt;</code>.',
+  'stats_alert.list_title': '📈 <b>Alertas de suelo y volumen</b> ({count}/{limit})',
+  'stats_alert.list_footer': 'Elimina una con <code>/stats_alert_remove &lt;id&gt;</code>.',
+  'stats_alert.removed': '✅ Alerta #{id} eliminada.',
+  'stats_alert.not_found': '❌ La alerta #{id} no existe en este chat.',
+  'stats_alert.remove_usage': 'Uso: <code>/stats_alert_remove &lt;id&gt;</code>',
+  'stats_alert.error': '❌ Error al actualizar las alertas de suelo y volumen. Inténtalo de nuevo.',
+  'stats_alert.unavailable': '❌ Las alertas de suelo y volumen no están disponibles.',
+  'stats_alert.rule.below': '{metric} por debajo de {threshold}',
+  'stats_alert.rule.above': '{metric} por encima de {threshold}',
+  'stats_alert.rule.change': '{metric} varía un {threshold}% en {window}',
+  'stats_alert.metric.floor': 'Precio suelo',
+  'stats_alert.metric.volume': 'Volumen 24h',
+  'stats_alert.triggered.below': '{emoji} **{collection}**: {metric} cayó por debajo de {threshold}',
+  'stats_alert.triggered.above': '{emoji} **{collection}**: {metric} superó {threshold}',
+  'stats_alert.triggered.change': '{emoji} **{collection}**: {metric} varió {change} en {window}',
+  'stats_alert.previous': '{metric} hace {window}',
+  'stats_alert.sales': 'Ventas 24h',
+  'stats_alert.listed': 'En venta',
+
+  // Payments
+  'payment.type.trending': 'Boost de tendencia',
+  'payment.type.image': 'Imagen del NFT',
+  'payment.type.footer': 'Anuncio en el pie',
+  'payment.instructions': `{emoji} <b>Instrucciones de pago: {type}</b>
+
+<b>Red:</b> {network}
+<b>Importe:</b> {amount} {symbol}
+<b>Duración:</b> {duration}
+
+<b>Envía el pago a:</b>
+<code>{address}</code>
+
+<b>⚠️ Importante:</b>
+• Envía EXACTAMENTE {amount} {symbol}
+• Usa la red {network}
+• Después del pago, usa /validate para verificarlo
+• El pago caduca en 30 minutos
+
+¿Necesitas ayuda? Contacta con @YourSupportBot`,
+  'duration.hours_price': '{hours} horas - {price}',
+  'duration.days_price': '{days} días - {price}',
+  'duration.days': '{days} días',
+
+  // Tracked token lists
+  'tokens.none_on_chain': '🔍 ¡Todavía no has añadido NFT en {chain}!\n\nUsa /add_token para empezar a seguir colecciones NFT.',
+  'tokens.none': '🔍 ¡Todavía no has añadido ningún token!\n\nUsa /add_token para empezar a seguir colecciones NFT.',
+  'tokens.title_chain': '📊 <b>Tus NFT seguidos en {chain}</b>',
+  'tokens.title': '📊 <b>Tus NFT seguidos</b>',
+  'tokens.remove_button': '🗑️ Quitar {name}',
+
+  // Alerts
+  'alert.action.sale': '**Compra**',
+  'alert.action.listing': 'Listado',
+  'alert.action.offer': 'Oferta recibida',
+  'alert.action.mint': 'Mint',
+  'alert.action.transfer': 'Transferencia',
+  'alert.action.burn': 'Quema',
+  'alert.price.sale': 'Precio de compra',
+  'alert.price.listing': 'Precio de listado',
+  'alert.price.offer': 'Importe de la oferta',
+  'alert.price.mint': 'Precio de mint',
+  'alert.price.transfer': 'Valor',
+  'alert.price.burn': 'Valor',
+  'alert.trending': 'TENDENCIA',
+  'alert.candy_minted': '¡minteado!',
+  'alert.nft': 'NFT',
+  'alert.buyer': 'Comprador',
+  'alert.seller': 'Vendedor',
+  'alert.bidder': 'Ofertante',
+  'alert.from': 'De',
+  'alert.to': 'Para',
+  'alert.marketplace': 'Marketplace',
+  'alert.collection': 'Colección',
+  'alert.ca': 'CA',
+  'alert.chain': 'Red',
+  'alert.view_on': 'Ver en {name}',
+  'alert.snipe.action': 'listado bajo el suelo',
+  'alert.snipe.discount': 'Bajo el suelo',
+  'alert.snipe.floor': 'suelo {floor}',
+  'alert.snipe.buy': '🛒 Comprar en {name}',
+  'alert.rank': 'Rango',
+  'alert.rarest_traits': 'Rasgos más raros',
+  'alert.royalty': 'Regalía',
+  'alert.powered_by': 'Con la tecnología de {link}',
+  'alert.boost': 'IMPULSA TU NFT🟢',
+  'alert.sweep': 'Barrido',
+  'alert.items': 'Artículos',
+  'alert.total': 'Total',
+  'alert.priced': '{count} con precio',
+  'alert.avg_price': 'Precio medio',
+  'alert.buyers': 'Compradores',
+  'alert.wallets': '{count} wallets',
+  'alert.nfts': 'NFT',
+  'alert.more': '+{count} más',
+
+  // Digests
+  'digest.title.quiet': '🌙 **Resumen de las horas de silencio**',
+  'digest.title.digest': '📰 **Resumen de actividad**',
+  'digest.summary_one': '{count} evento durante {elapsed}',
+  'digest.summary_other': '{count} eventos durante {elapsed}',
+  'digest.hours': '{hours} h',
+  'digest.minutes': '{minutes} min',
+  'digest.sales': 'Ventas',
+  'digest.volume': 'Volumen',
+  'digest.top_sale': 'Mejor venta',
+  'digest.top_sale_item': '{item} por {price}',
+  'digest.floor': 'Floor',
+  'digest.more_collections': '+{count} colecciones más',
+  'digest.count.listing_one': '{count} listado',
+  'digest.count.listing_other': '{count} listados',
+  'digest.count.offer_one': '{count} oferta',
+  'digest.count.offer_other': '{count} ofertas',
+  'digest.count.mint_one': '{count} mint',
+  'digest.count.mint_other': '{count} mints',
+  'digest.count.transfer_one': '{count} transferencia',
+  'digest.count.transfer_other': '{count} transferencias',
+  'digest.count.burn_one': '{count} quema',
+  'digest.count.burn_other': '{count} quemas',
+
+  // Resume reminders and unavailable services
+  'resume.tx_hash': '⏳ Tienes un pago sin terminar. Responde con el hash de tu transacción para continuar, o escribe "cancel" para empezar de nuevo.',
+  'resume.payment': '⏳ Tienes un pago sin terminar. Si ya pagaste, pulsa "📝 Enviar hash de transacción" en las instrucciones de pago de arriba para continuar.',
+  'resume.step': '⏳ Tienes un paso sin terminar. Responde para continuar donde lo dejaste, o escribe "cancel" para empezar de nuevo.',
+  'service.validation_unavailable': '❌ El servicio de validación no está disponible.',
+  'service.image_unavailable': '❌ El sistema de pago de imágenes no está disponible.',
+  'service.footer_unavailable': '❌ El sistema de anuncios en el pie no está disponible.',
+  'service.chain_manager_unavailable': '❌ El gestor de cadenas no está disponible',
+  'tokens.load_error': '❌ Error al cargar tus NFTs. Inténtalo de nuevo.',
+  'channel_cmd.add_wrong_chat': '❌ Este comando solo funciona en canales o grupos. Primero añádeme a un canal y luego usa este comando allí.',
+  'channel_cmd.unknown_user': '❌ No se pudo identificar al usuario. Inténtalo de nuevo.',
+  'channel_cmd.user_error': '❌ Error al configurar el usuario. Inténtalo de nuevo.',
+  'channel_cmd.add_error': '❌ Error al añadir el canal. Inténtalo de nuevo.',
+  'channel_cmd.settings_wrong_chat': '❌ Este comando solo funciona en canales o grupos.',
+  'channel_cmd.settings_error': '❌ Error al obtener la configuración del canal. Inténtalo de nuevo.',
+  'chat_id.message': '🆔 *Información del chat*\n\n**ID del chat:** `{chatId}`\n**Tipo:** {chatType}\n**Título:** {chatTitle}\n\n*Usa este ID de chat para configurar las notificaciones en los ajustes del bot.*',
+  'chat_id.error': '❌ Error al obtener la información del chat.',
+
+  // Adding collections
+  'common.group': 'Grupo',
+  'common.main_menu': '🏠 Menú principal',
+  'common.back': '◀️ Atrás',
+  'common.cancel': '❌ Cancelar',
+  'common.session_expired': '❌ La sesión ha caducado. Empieza de nuevo.',
+  'add_token.adding_to': `🎯 <b>Añadiendo NFT a:</b> {group}
+
+Selecciona la blockchain:`,
+  'add_token.select_network': `🔗 <b>Selecciona la red blockchain</b>
+
+👥 <b>Añadiendo a: {group}</b>
+
+Elige la blockchain en la que existe tu colección NFT:`,
+  'add_token.switch_group': '🔄 Cambiar de grupo',
+  'add_token.back_to_context': '◀️ Volver a la selección de contexto',
+  'add_token.back_to_chains': '◀️ Volver a la selección de cadena',
+  'add_token.admins_only': '⚠️ Solo los administradores del grupo pueden añadir NFTs.',
+  'add_token.prompt_solana': `◎ ¡<b>Solana</b> seleccionada!
+
+📝 Introduce el símbolo de la colección:
+
+Ejemplo: <code>mad_lads</code>
+
+💡 La colección debe existir en el marketplace de Magic Eden.`,
+  'add_token.prompt_bitcoin': `₿ ¡<b>Bitcoin</b> seleccionado!
+
+📝 Introduce el nombre de la colección:
+
+Ejemplo: <code>bitcoin-puppets</code> o <code>NodeMonkes</code>
+
+💡 La colección debe existir en el marketplace de Magic Eden Ordinals.`,
+  'add_token.prompt_evm': `🔗 ¡<b>{chain}</b> seleccionada!
+
+📝 Introduce la dirección del contrato NFT que quieres seguir en {chain}:
+
+💡 Asegúrate de que el contrato existe en la red {chain}.`,
+  'chain.select_specific': '❌ Selecciona una red blockchain concreta para añadir tokens.',
+  'chain.invalid': '❌ La red blockchain seleccionada no es válida.',
+  'chain.no_selection': '❌ No hay ninguna selección de cadena en curso.',
+  'token.not_found': '❌ No se encontró la colección NFT.',
+  'channels_menu.add_help': `💡 <b>Añadir el bot a un canal</b>
+
+1. Añade este bot a tu canal como administrador
+2. Usa el comando <code>/add_channel</code> en el canal
+3. Configura las notificaciones con <code>/channel_settings</code>`,
+  'channels_menu.back': '◀️ Volver al menú de canales',
+  'chat_id.message_html': `🆔 <b>Información del chat</b>
+
+<b>ID del chat:</b> <code>{chatId}</code>
+<b>Tipo:</b> {chatType}
+<b>Título:</b> {chatTitle}
+
+<i>Usa este ID de chat para configurar las notificaciones en los ajustes del bot.</i>`,
+  'payment.selected_chain': 'la blockchain seleccionada',
+  'payment.submit_tx.image': `📝 <b>Enviar hash de transacción</b>
+
+Envíame el hash de tu transacción en {chain} del pago de la tarifa de imagen.
+
+<i>Ejemplo: 0xabc123456789def...</i>`,
+  'payment.submit_tx.image_token': `📝 <b>Enviar hash de transacción</b>
+
+🎯 <b>NFT:</b> {name} ({symbol})
+🔗 <b>Cadena:</b> {chain}
+
+Envíame el hash de la transacción del pago de la tarifa de imagen.
+
+<i>Ejemplo: 0xabc123456789def...</i>`,
+  'payment.submit_tx.footer': `📝 <b>Enviar hash de transacción</b>
+
+Envíame el hash de tu transacción en {chain} del pago del anuncio en el pie.
+
+<i>Ejemplo: 0xabc123456789def...</i>`,
+  'payment.submit_tx.trending': '📝 **Enviar hash de transacción**\n\nEnvía ahora el hash de tu transacción en {chain}.\n\nEl hash de la transacción debe:\n• Empezar por 0x\n• Tener 66 caracteres\n• Ser de una transacción enviada a: `{address}`\n\nEjemplo: `0x1234567890abcdef...`',
+
+  // Channel posts and webhooks
+  'payment.tx_cancelled': '✅ Envío de transacción cancelado.',
+  'channel_cmd.channel_only': '❌ Este comando solo funciona en canales o grupos.',
+  'channel_cmd.add_channel_only': '❌ Este comando solo funciona en canales o grupos. Primero añádeme a un canal y luego usa este comando allí.',
+  'channel_cmd.unknown_channel': 'Canal desconocido',
+  'channel_cmd.setup_error': '❌ Error al configurar el usuario. Inténtalo de nuevo.',
+  'channel_cmd.command_error': '❌ Se produjo un error al procesar el comando. Inténtalo de nuevo.',
+  'chat_id.message_plain': `📋 Información del chat:
+ID: {chatId}
+Tipo: {chatType}
+Título: {chatTitle}`,
+  'webhooks.unavailable': '❌ Los webhooks salientes no están disponibles.',
+  'webhooks.group_only': '❌ Los webhooks se gestionan por grupo. Ejecuta este comando en el grupo que sigue la colección.',
+  'webhooks.admins_only': '❌ Solo los administradores del grupo pueden gestionar webhooks.',
+  'webhooks.secret_dm': `🔑 <b>Secreto de firma del webhook #{id}</b> ({chat})
+
+<code>{secret}</code>
+
+Verifica cada petición calculando el HMAC-SHA256 de <code>&lt;X-MintTech-Timestamp&gt;.&lt;cuerpo sin procesar&gt;</code> con este secreto y comparándolo con la cabecera <code>X-MintTech-Signature</code> (<code>sha256=...</code>).`,
+  'webhooks.add_usage': `Uso: <code>/webhook_add &lt;contrato o colección&gt; &lt;url-https&gt; [sale,listing,mint,transfer]</code>
+
+Cada actividad de la colección que coincida se envía por POST a la URL como JSON firmado.`,
+  'webhooks.not_tracked': '❌ Esta colección no se sigue en este grupo. Añádela primero con /add_token.',
+  'webhooks.all_events': 'todos',
+  'webhooks.registered': `✅ Webhook #{id} registrado para {name}
+📨 Eventos: {events}`,
+  'webhooks.secret_sent': '🔑 Te he enviado el secreto de firma por mensaje privado.',
+  'webhooks.secret_not_sent': '⚠️ No he podido escribirte por privado. Inicia un chat conmigo y luego ejecuta aquí /webhook_secret {id} para recibir el secreto de firma.',
+  'webhooks.register_error': '❌ No se pudo registrar el webhook: {error}',
+  'webhooks.unknown_event_types': 'Tipo(s) de evento desconocido(s): {types} (se esperaba {expected})',
+  'webhooks.list_empty': 'No hay webhooks registrados en este grupo. Usa /webhook_add para añadir uno.',
+  'webhooks.list_title': '🔗 <b>Webhooks de este grupo</b>',
+  'webhooks.events': 'Eventos: {events}',
+  'webhooks.failures': ' · {count} fallo(s), último: {error}',
+  'webhooks.list_footer': 'Elimina con /webhook_remove &lt;id&gt; y rota el secreto con /webhook_secret &lt;id&gt;.',
+  'webhooks.load_error': '❌ Error al cargar los webhooks. Inténtalo de nuevo.',
+  'webhooks.remove_usage': 'Uso: /webhook_remove <id> (ver /webhooks)',
+  'webhooks.removed': '✅ Webhook #{id} eliminado.',
+  'webhooks.not_found': '❌ No se encontró el webhook #{id} en este grupo.',
+  'webhooks.remove_error': '❌ Error al eliminar el webhook. Inténtalo de nuevo.',
+  'webhooks.secret_usage': 'Uso: /webhook_secret <id> (ver /webhooks)',
+  'webhooks.secret_unchanged': '⚠️ No he podido escribirte por privado, así que el secreto no ha cambiado. Inicia un chat conmigo y vuelve a ejecutar /webhook_secret {id}.',
+  'webhooks.secret_rotated': '🔑 Te he enviado por privado el nuevo secreto de firma del webhook #{id}. El secreto anterior ya no funciona.',
+  'webhooks.secret_error': '❌ Error al rotar el secreto del webhook. Inténtalo de nuevo.',
+
+  // Adding, removing and boosting collections
+  'add_token.validating': '🔍 Validando y añadiendo en {chain}...',
+  'add_token.validating_solana': '◎ Validando NFT de Solana a través de Magic Eden...',
+  'add_token.private_unavailable': `❌ <b>El seguimiento privado no está disponible</b>
+
+Añade este bot a un grupo o canal y usa el botón <b>Setup inside BOT</b> para seguir NFTs.
+
+Aquí, por privado, puedes seguir viendo los NFTs que sigues en tus grupos.`,
+  'add_token.boost_button': 'IMPULSA TU NFT🟢',
+  'add_token.verified': '✅ Verificación: el token ya está en tu lista de seguimiento.',
+  'add_token.failed': `❌ {reason}
+
+Si crees que es un error, inténtalo de nuevo o contacta con soporte.`,
+  'add_token.error': '❌ Error al añadir el token. Comprueba la dirección del NFT e inténtalo de nuevo.',
+  'payment.no_pending': '❌ No hay ningún pago pendiente. Vuelve a iniciar el proceso de impulso.',
+  'payment.invalid_tx_hash': '❌ Formato de hash de transacción no válido. Envía un hash válido (empieza por 0x y tiene 64 caracteres).',
+  'payment.cancel_hint': 'O escribe "cancel" para cancelar.',
+  'payment.validating': '🔍 Validando tu transacción... Puede tardar unos instantes.',
+  'payment.trending_confirmed': '✅ **¡Pago confirmado!**\n\n🔥 **{name}** es tendencia durante {duration} horas.\n\n💰 Importe: {amount} ETH\n📝 Transacción: `{txHash}`\n🆔 ID de pago: {paymentId}\n\nTu colección aparecerá en la lista de tendencias y se promocionará en los canales. ¡Gracias por impulsar el ecosistema! 🚀',
+  'payment.trending_confirmed_one': '✅ **¡Pago confirmado!**\n\n🔥 **{name}** es tendencia durante {duration} hora.\n\n💰 Importe: {amount} ETH\n📝 Transacción: `{txHash}`\n🆔 ID de pago: {paymentId}\n\nTu colección aparecerá en la lista de tendencias y se promocionará en los canales. ¡Gracias por impulsar el ecosistema! 🚀',
+  'payment.trending_failed': '❌ **La validación del pago ha fallado**\n\n{error}\n\nComprueba que:\n• La transacción se envió a: `{address}`\n• Se envió el importe exacto: {amount} ETH\n• La transacción está confirmada en la blockchain\n\nPuedes intentarlo con otro hash de transacción o contactar con soporte.',
+  'payment.validation_error': '❌ Error al validar la transacción. Inténtalo de nuevo o contacta con soporte.',
+  'remove.user_not_found': '❌ Usuario no encontrado. Inicia primero el bot con /startminty',
+  'remove.not_found': '❌ NFT no encontrado.',
+  'remove.not_subscribed': '❌ No estás suscrito a este NFT en este chat.',
+  'remove.completion_error': '❌ Error al completar la eliminación del token. Inténtalo de nuevo.',
+  'remove.removed_completely': `✅ <b>Token eliminado correctamente</b>
+
+🗑️ <b>{name}</b> se ha dejado de seguir por completo.
+
+📮 Contrato: <code>{address}</code>`,
+  'remove.removed_from_chat': `✅ <b>Token eliminado correctamente</b>
+
+🗑️ <b>{name}</b> se ha eliminado de tu lista de seguimiento en {context}.
+
+📮 Contrato: <code>{address}</code>
+
+Ya no recibirás notificaciones de este NFT en este chat.`,
+  'remove.context_private': 'mensajes privados',
+  'remove.context_group': 'chat de grupo ({chatId})',
+  'remove.error': '❌ Error al eliminar el token. Inténtalo de nuevo.',
+  'tokens.unknown_collection': 'Colección desconocida',
+  'tokens.notification_updated': '¡Ajuste de notificaciones actualizado!',
+  'tokens.notification_error': '❌ Error al actualizar el ajuste de notificaciones',
+  'stats.loading': 'Cargando estadísticas...',
+  'stats.coming_soon': '📊 ¡Las estadísticas de tokens llegarán pronto!',
+  'stats.error': '❌ Error al cargar las estadísticas',
+  'trending.buy_button': '🚀 Comprar tendencia',
+  'trending.empty': `📊 *Ahora mismo no hay NFTs en tendencia*
+
+¡Sé el primero en impulsar tu colección NFT!`,
+  'trending.title': '🔥 *Colecciones NFT en tendencia*',
+  'trending.hours_left': '⏱️ Quedan {hours} h',
+  'trending.paid': '💰 Pagado: {amount} ETH',
+  'trending.stats_button': '📊 Estadísticas de {name}',
+  'trending.view': 'Ver',
+  'trending.boost_button': '🚀 Impulsa tu token',
+  'trending.load_error': '❌ Error al cargar los tokens en tendencia. Inténtalo de nuevo.',
+  'trending.instructions': `💳 <b>Instrucciones de pago</b>
+
+🔗 Cadena: <b>{chain}</b>
+🔥 Colección: {name}
+📮 Contrato: <code>{address}</code>
+⏱️ Duración: {duration} horas
+💰 Tarifa: {fee} {symbol}
+
+🏦 <b>Dirección de pago ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 Instrucciones de pago:
+1. Envía {fee} {symbol} a la dirección de pago de arriba
+2. Asegúrate de enviar desde la red {chain}
+3. Toca la dirección de arriba para copiarla
+4. Copia el hash de tu transacción después de la transferencia
+5. Envía el hash de la transacción con el botón de abajo
+
+✅ Proceso sencillo: basta con una transferencia normal de {symbol}, ¡sin llamadas complejas a contratos!
+⏰ El pago caduca en 30 minutos
+
+Tras completar la transacción, envía su hash abajo:`,
+  'payment.submit_tx_button': '📝 Enviar hash de transacción',
+  'trending.back_to_duration': '◀️ Volver a la duración',
+  'trending.instructions_error': '❌ Error al cargar las instrucciones de pago. Inténtalo de nuevo.',
+
+  // Group selection and image fees
+  'context.no_groups': `📭 <b>No hay grupos disponibles</b>
+
+Todavía no compartes ningún grupo con este bot, o no eres administrador en esos grupos.
+
+<b>Para empezar:</b>
+1️⃣ Añádeme al grupo de Telegram que quieras
+2️⃣ Asegúrate de ser administrador de ese grupo
+3️⃣ Ejecuta /startminty en el grupo para configurarlo
+4️⃣ Vuelve aquí para añadir NFTs
+
+💡 ¡Usa el botón de abajo para añadirme a un grupo!`,
+  'context.add_to_group': '➕ Añadir el bot a un grupo',
+  'common.page': 'Página {page}/{total}',
+  'context.select': `🎯 <b>¿Dónde quieres añadir este NFT?</b>
+
+Elige el chat:`,
+  'context.load_error': '❌ Error al cargar los chats. Inténtalo de nuevo.',
+  'images.select_title': '🖼️ <b>Selecciona el NFT para mostrar imágenes</b>',
+  'images.none_viewer': `📝 Este grupo todavía no sigue ningún NFT.
+
+ℹ️ Solo los administradores pueden añadir colecciones NFT.`,
+  'images.none_admin': `📝 ¡Primero tienes que añadir alguna colección NFT!
+
+Añade tus NFTs para seguirlos y activar la visualización de imágenes.`,
+  'images.select_prompt': 'Elige la colección NFT para la que quieres activar las imágenes reales:',
+  'images_menu.back': '◀️ Volver al menú de imágenes',
+  'tokens_menu.add_first': '➕ Añade tu primer NFT',
+  'token.not_found_retry': '❌ Token no encontrado. Inténtalo de nuevo.',
+  'token.selection_error': '❌ Error al procesar la selección del token. Inténtalo de nuevo.',
+  'images.duration_title': `🎨 <b>Tarifa de imagen - Elige la duración</b>
+
+🎯 <b>NFT seleccionado:</b> {name} ({symbol})
+🔗 <b>Blockchain:</b> {chain}
+
+Elige durante cuánto tiempo quieres mostrar las imágenes del NFT en lugar de la imagen de Candy Codex:`,
+  'duration.value_hint': '✨ ¡Las duraciones más largas salen más baratas por día!',
+  'duration.error': '❌ Error al mostrar las opciones de duración. Inténtalo de nuevo.',
+  'common.back_to_tokens': '◀️ Volver a la selección de token',
+  'images.already_active': '✅ La tarifa de imagen ya está activa para este contrato. Se están mostrando las imágenes reales del NFT.',
+  'images.instructions': `💰 <b>Instrucciones de pago de la tarifa de imagen</b>
+
+🎯 <b>NFT seleccionado:</b> {name} ({symbol})
+🔗 <b>Blockchain:</b> {chain}
+📮 <b>Contrato:</b> <code>{address}</code>
+📅 <b>Duración:</b> {duration}
+💸 <b>Tarifa:</b> {fee} {feeSymbol}
+
+🏦 <b>Contrato de pago ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Pasos del pago:</b>
+{steps}
+
+Tras realizar el pago, pulsa el botón de abajo para enviar el hash de la transacción.`,
+  'duration.back': '◀️ Volver a la selección de duración',
+  'payment.instructions_error': '❌ Error al generar las instrucciones de pago. Inténtalo de nuevo.',
+
+  // Payment verification
+  'verify.type.image': 'Tarifa de imagen',
+  'verify.type.trending': 'Tendencia',
+  'verify.payment_name.image': 'tarifa de imagen',
+  'verify.payment_name.trending': 'tendencia',
+  'verify.title': '{emoji} <b>Verificar pago de {type}</b>',
+  'verify.none_admin': `📝 ¡Primero tienes que añadir alguna colección NFT!
+
+Añade tus NFTs para seguirlos antes de verificar pagos.`,
+  'verify.select_prompt': 'Selecciona la colección NFT por la que hiciste el pago:',
+  'verify_menu.back': '◀️ Volver al menú de verificación',
+  'verify.submit_tx': `📝 <b>Enviar hash de transacción</b>
+
+{emoji} <b>Tipo de pago:</b> {type}
+🎯 <b>NFT:</b> {name} ({symbol})
+{chainEmoji} <b>Blockchain:</b> {chain}
+
+Envíame el hash de la transacción del pago de {payment}.
+
+<i>Ejemplo: 0xabc123456789def...</i>`,
+  'verify.footer_not_found': '❌ No se encontró el anuncio en el pie. Inténtalo de nuevo.',
+  'verify.submit_footer_tx': `📝 <b>Enviar hash de transacción</b>
+
+🔗 <b>Tipo de pago:</b> Anuncio en el pie
+💲 <b>Ticker:</b> {ticker}
+📎 <b>Enlace:</b> {link}
+
+Envíame el hash de la transacción del pago del anuncio en el pie.
+
+<i>Ejemplo: 0xabc123456789def...</i>`,
+  'verify.ticker_error': '❌ Error al procesar la selección del ticker. Inténtalo de nuevo.',
+  'verify.footer_ticker_prompt': `🔗 <b>Verificar pago del pie</b>
+
+Introduce el ticker que usaste al crear tu anuncio en el pie.
+
+💡 <b>Incluye el prefijo $ si lo usaste</b>
+
+<i>Ejemplos: $PEPE, $BAYC, $AZUKI</i>
+<i>O sin $: PEPE, BAYC, AZUKI</i>`,
+  'verify.ticker_input_error': '❌ Error al mostrar la entrada del ticker. Inténtalo de nuevo.',
+  'verify.footer_title': '🔗 <b>Verificar pagos del pie</b>',
+  'verify.no_footer_ads': `❌ <b>No se encontraron anuncios en el pie.</b>
+
+Todavía no has comprado ningún anuncio en el pie. Compra uno primero para verificar pagos.`,
+  'verify.buy_footer': '💰 Comprar anuncio en el pie',
+  'verify.select_footer': `Selecciona el anuncio en el pie que quieres verificar:
+
+💡 Elige entre los tickers que has comprado:`,
+  'verify.footer_load_error': '❌ Error al cargar los anuncios en el pie. Inténtalo de nuevo.',
+
+  // Tracked collections and trending purchases
+  'tokens.context_header': '👥 <b>{label}</b> ({count} tokens)',
+  'tokens.context_header_one': '👥 <b>{label}</b> ({count} token)',
+  'tokens.alerts_button': '🔔 Alertas: {name}',
+  'tokens.remove_from_button': '🗑️ Quitar {name}... de {context}',
+  'tokens.none_button': `🔍 ¡Todavía no has añadido ningún token!
+
+Usa el botón de abajo para empezar a seguir colecciones NFT.`,
+  'tokens.none_group': `🔍 Este grupo todavía no sigue ningún NFT.
+
+ℹ️ Solo los administradores pueden añadir colecciones NFT.`,
+  'tokens.title_all': '🎯 <b>Tus NFTs seguidos</b> ({total} en total en {count} chats)',
+  'tokens.title_all_one': '🎯 <b>Tus NFTs seguidos</b> ({total} en total en {count} chat)',
+  'tokens.title_total': '🎯 <b>Tus NFTs seguidos</b> ({total} en total)',
+  'tokens.title_group': `🎯 <b>NFTs seguidos en este grupo</b> ({total} en total)
+
+ℹ️ <i>Solo lectura: únicamente los administradores pueden gestionar colecciones</i>`,
+  'tokens.status_active': '🟢 Estado: activo',
+  'tokens.tracking_active': '📊 Seguimiento: ✅ activo',
+  'tokens_menu.add_more': '➕ Añadir más NFTs',
+  'tokens_menu.back': '◀️ Volver al menú de NFTs',
+  'tokens.none_add_first': `📝 ¡Primero tienes que añadir alguna colección NFT!
+
+Usa /add_token para seguir tu primera colección NFT.`,
+  'trending_menu.back': '◀️ Volver al menú de tendencias',
+  'trending.tier.premium': 'Premium',
+  'trending.tier.normal': 'Normal',
+  'trending.boost_title': `🚀 <b>Impulso de tendencia {tier}</b>
+
+Selecciona la colección NFT que quieres impulsar:`,
+  'trending.back_to_buy': '🔄 Volver al menú de compra de tendencias',
+  'trending.chain_title': `🚀 <b>Tendencia {tier} - {name}</b>
+
+🔗 Selecciona la red blockchain para el pago:`,
+  'trending.back_to_nfts': '◀️ Volver a la selección de NFT',
+  'trending.chain_error': '❌ Error al cargar las opciones de cadena. Inténtalo de nuevo.',
+  'trending.badge.premium': '⭐ PREMIUM',
+  'trending.badge.normal': '🔵 ESTÁNDAR',
+  'trending.group_link_prompt': '📎 <b>Enlace del grupo (opcional)</b>\n\n<b>Seleccionado:</b> {name}\n<b>Nivel:</b> {tier}\n\n¿Quieres incluir un enlace a un grupo de Telegram? Aparecerá como un ticker pulsable (💬 ${symbol}) en las notificaciones.\n\n<b>Formatos aceptados:</b>\n• Enlace completo: https://t.me/mygroup\n• Con @: @mygroup\n• Texto: mygroup\n\nEnvía ahora el enlace de tu grupo o pulsa Omitir para continuar sin enlace.',
+  'trending.skip_group_link': '⏭️ Omitir (sin enlace)',
+  'trending.group_link_error': '❌ Error al cargar la entrada del enlace del grupo. Inténtalo de nuevo.',
+  'trending.duration_title': `🚀 <b>Tendencia {tier} - {name}</b>
+
+🔗 Cadena: <b>{chain}</b> <i>(detectada automáticamente)</i>
+
+Selecciona la duración de la tendencia:`,
+  'trending.duration_button': '{hours} h - {price}',
+  'trending.change_chain': '🔄 Elegir otra cadena',
+  'trending.options_error': '❌ Error al cargar las opciones de tendencia. Inténtalo de nuevo.',
+
+  // Footer, image and payment validation
+  'common.too_many_pending': '❌ Tienes demasiadas operaciones pendientes. Completa o cancela las existentes antes de iniciar otras nuevas.',
+  'common.user_not_found': '❌ Usuario no encontrado. Usa /start primero.',
+  'validation.validating_address': '🔍 Validando la dirección del NFT...',
+  'validation.contract_failed': '❌ La validación del contrato falló: {error}',
+  'validation.validating_tx': '🔍 Validando tu transacción... Espera un momento.',
+  'validation.validating_image': '⏳ Validando tu transacción de la tarifa de imagen...',
+  'validation.validating_footer': '⏳ Validando el pago de tu anuncio de pie de página...',
+  'validation.validating_footer_tx': '⏳ Validando la transacción de tu anuncio de pie de página...',
+  'validation.payment_failed': `❌ La validación del pago falló: {error}
+
+Asegúrate de haber enviado el importe correcto al contrato de pago.`,
+  'validation.failed': '❌ La validación falló: {error}',
+  'validation.failed_markdown': `❌ **La validación falló**
+
+{error}`,
+  'footer_menu.back': '◀️ Volver al menú de pie de página',
+  'footer.payment': `💰 <b>Pago de anuncio de pie de página</b>
+
+🎨 <b>Colección:</b> {name}
+🎯 <b>Token:</b> {symbol}
+💸 <b>Tarifa:</b> {fee} {feeSymbol}
+⏰ <b>Duración:</b> {duration}
+📮 <b>Contrato:</b> <code>{address}</code>
+
+📋 <b>Pasos de pago:</b>
+{steps}`,
+  'footer.default_step': 'Envía el pago a la dirección del NFT',
+  'footer.payment_verified_details': `✅ <b>¡Pago verificado!</b>
+
+🎨 Colección: <b>{name}</b>
+💰 Importe: {amount}
+📅 Duración: <b>{duration}</b>
+
+🔗 <b>Enlace personalizado</b>
+
+Ahora envíame el enlace personalizado que quieres mostrar en los anuncios de pie de página.
+
+<i>Ejemplo: https://mytoken.com</i>`,
+  'footer.payment_verified': `✅ <b>¡Pago verificado!</b>
+
+🔗 <b>Enlace personalizado</b>
+
+Ahora envíame el enlace personalizado que quieres mostrar en los anuncios de pie de página.
+
+<i>Ejemplo: https://mytoken.com</i>`,
+  'footer.session_invalid': '❌ La sesión expiró o el pago no se validó. Empieza de nuevo.',
+  'footer.invalid_url': '❌ Formato de URL no válido. Proporciona una URL válida (p. ej., https://mytoken.com).',
+  'footer.creating': '⏳ Creando tu anuncio de pie de página...',
+  'footer.payment_validated': `✅ <b>¡Pago de pie de página validado!</b>
+
+🔗 <b>Ticker:</b> {ticker}
+💰 <b>Importe:</b> {amount} {symbol}
+📝 <b>Transacción:</b> <code>{txHash}</code>
+
+📢 ¡Tu anuncio de pie de página ya está activo!`,
+  'footer.validation_failed': '❌ La validación del pie de página falló: {error}',
+  'trending.group_link_invalid': '❌ Formato de enlace no válido. Envía un enlace de grupo de Telegram válido o escribe "cancel" para volver.',
+  'trending.group_link_saved': '✅ Enlace del grupo guardado: {link}',
+  'images.contract_instructions': `💰 <b>Instrucciones de pago de la tarifa de imagen</b>
+
+🎨 Colección: <b>{name}</b>
+📮 Contrato: <code>{address}</code>
+📅 Duración: <b>{duration}</b>
+💸 Tarifa: <b>{fee} {feeSymbol}</b>
+
+🏦 <b>Dirección de pago ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Pasos de pago:</b>
+{steps}
+
+Después de pagar, pulsa el botón de abajo para enviar el hash de tu transacción.`,
+  'images.payment_validated': `✅ <b>¡Pago de la tarifa de imagen validado!</b>
+
+🎨 Colección: <b>{name}</b>
+📮 Contrato: <code>{address}</code>
+💰 Importe: {amount} {symbol}
+📅 Duración: <b>{duration}</b>
+📝 Transacción: <code>{txHash}</code>
+👤 Pagador: <code>{payer}</code>
+
+🖼️ <b>¡Ahora se mostrarán las imágenes reales de los NFT de este contrato durante {duration}!</b>`,
+  'trending.payment_validated': '✅ **¡Pago validado correctamente!**\n\n🎯 ¡Trending de **{name}** activado!\n⏱️ Duración: {hours} horas\n💰 Importe: {amount} {symbol}\n🔗 TX: `{txHash}`\n\n¡Tu NFT ya está en tendencia! 🚀',
+  'verify.payment_name.footer': 'anuncio de pie de página',
+  'verify.tx_hash_required': `📝 <b>Se requiere el hash de la transacción</b>
+
+Ahora envíame el hash de la transacción de tu pago de {payment}.
+
+<i>Ejemplo: 0xabc123456789def...</i>`,
+  'verify.back_to_payments': '◀️ Volver a verificar pagos',
+  'verify.invalid_ticker': `❌ Formato de ticker no válido. Introduce un ticker válido.
+
+<i>Ejemplos: $PEPE, $BAYC, $AZUKI</i>
+
+💡 Usa el mismo ticker que usaste al crear tu anuncio de pie de página.`,
+  'verify.submit_ticker_tx': `📝 <b>Enviar hash de la transacción</b>
+
+🔗 <b>Ticker del pie de página:</b> {ticker}
+
+Envíame el hash de la transacción del pago del pie de página.
+
+<i>Ejemplo: 0xabc123456789def...</i>`,
+
+  // Chain lists and footer ad flow
+  'common.register_first': '❌ Regístrate primero usando /start',
+  'tokens.none_on_chain_add': `🔍 <b>No se encontraron NFTs en {chain}</b>
+
+¡Añade algunos NFTs para empezar a seguirlos en esta blockchain!`,
+  'tokens.title_chain_count': '🎯 <b>Tus tokens de {chain}</b> ({count})',
+  'tokens_menu.add_on_chain': '➕ Añadir NFT en esta cadena',
+  'tokens_menu.view_all_chains': '🌐 Ver todas las cadenas',
+  'tokens_menu.all_chains': '🌐 Todas las cadenas',
+  'images.duration_menu_title': `🎨 <b>Tarifa de imagen - Selecciona la duración</b>
+
+Elige durante cuánto tiempo quieres mostrar las imágenes de los NFT en lugar de la imagen de Candy Codex:`,
+  'duration.pricing_hint': '💡 El precio se mostrará después de elegir la cadena.',
+  'footer.duration_title': `📢 <b>Anuncio de pie de página - Selecciona la duración</b>
+
+{emoji} <b>Cadena:</b> {chain}
+
+Elige durante cuánto tiempo se mostrará tu anuncio:`,
+  'footer.duration_hint': '💡 ¡Tu anuncio aparecerá al final de todas las notificaciones de tokens!',
+  'images.chain_title': `🎨 <b>Pago de tarifa de imagen - Selecciona la cadena</b>
+
+📅 Duración: <b>{duration}</b>
+
+🔗 Selecciona la red blockchain:
+💡 El precio varía según la cadena y se mostrará en la pantalla de pago.`,
+  'footer.chain_title': `📢 <b>Pago de anuncio de pie de página - Selecciona la cadena</b>
+
+🔗 Selecciona la red blockchain para el pago de tu anuncio:
+
+💡 La duración y el precio se mostrarán en el siguiente paso.`,
+  'payment.chain_error': '❌ Error al mostrar la selección de cadena. Inténtalo de nuevo.',
+  'images.contract_title': '🎨 <b>Pago de tarifa de imagen - Introduce el contrato</b>',
+  'footer.contract_title': '📢 <b>Pago de anuncio de pie de página - Introduce el contrato</b>',
+  'payment.contract_prompt': `{title}
+
+📅 Duración: <b>{duration}</b>
+💰 Importe: <b>{amount}</b>
+🔗 Cadena: <b>{chain}</b>
+
+📝 Introduce la dirección del NFT:
+
+<i>Ejemplo: 0x1234567890abcdef...</i>`,
+  'payment.back_to_chain': '◀️ Volver a la selección de cadena',
+  'payment.back_to_contract': '◀️ Volver a introducir el contrato',
+  'payment.contract_input_error': '❌ Error al mostrar la entrada del contrato. Inténtalo de nuevo.',
+  'footer.link_title': '🔗 <b>Anuncio de pie de página - Enlace personalizado</b>',
+  'payment.chain_line': '🔗 Cadena: <b>{chain}</b>',
+  'footer.link_prompt': `Envíame el enlace personalizado que quieres que visiten los usuarios al pulsar tu anuncio.
+
+<i>Ejemplo: https://mytoken.com o https://twitter.com/myproject</i>`,
+  'footer.link_input_error': '❌ Error al mostrar la entrada del enlace. Inténtalo de nuevo.',
+  'footer.ticker_prompt': `💰 <b>Anuncio de pie de página - Ticker</b>
+
+Envíame el ticker que quieres mostrar en el pie de página.
+
+<i>Ejemplos: $CANDY, $PEPE, $MYTOKEN</i>
+
+⭐️ Tu ticker aparecerá así: <b>⭐️$YOURTICKER</b>`,
+  'footer.back_to_link': '◀️ Volver a introducir el enlace',
+  'footer.back_to_ticker': '◀️ Volver a introducir el ticker',
+  'footer.invalid_link': `❌ Formato de URL no válido. Proporciona una URL válida que empiece por http:// o https://.
+
+<i>Ejemplo: https://mytoken.com</i>`,
+  'footer.invalid_ticker': `❌ Formato de ticker no válido. Usa solo letras y números, máximo 10 caracteres. <b>Sin espacios.</b>
+
+<i>Ejemplos: $CANDY, $PEPE, $MYTOKEN</i>`,
+  'footer.contract_instructions': `📢 <b>Instrucciones de pago del anuncio de pie de página</b>
+
+🎨 Colección: <b>{name}</b>
+📮 Contrato: <code>{address}</code>
+🔗 Enlace personalizado: {link}
+⭐️ Ticker: <b>{ticker}</b>
+📅 Duración: <b>{duration}</b>
+💸 Tarifa: <b>{fee} {feeSymbol}</b>
+
+🏦 <b>Dirección de pago ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Pasos de pago:</b>
+{steps}
+
+Después de pagar, pulsa el botón de abajo para enviar el hash de tu transacción.`,
+  'footer.payment_instructions': `📢 <b>Instrucciones de pago del anuncio de pie de página</b>
+
+🔗 Enlace personalizado: {link}
+⭐️ Ticker: <b>{ticker}</b>
+📅 Duración: <b>{duration}</b>
+💸 Tarifa: <b>{amount}</b>
+{emoji} Cadena: <b>{chain}</b>
+
+🏦 <b>Dirección de pago ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Pasos de pago:</b>
+1. <b>ENVÍA EXACTAMENTE {amount}</b> a la dirección de pago de arriba
+2. Usa cualquier billetera de {chain} en la red {network}
+3. Toca la dirección de arriba para copiarla
+4. Espera la confirmación de la transacción
+5. Envía el hash de tu transacción abajo
+
+Después de pagar, pulsa el botón de abajo para enviar el hash de tu transacción.`
+};
@@ -0,0 +1,1076 @@
+// Russian
+module.exports = {
+  // Shared
+  'common.error': '❌ Произошла ошибка. Попробуйте ещё раз.',
+  'common.start_first': 'Сначала запустите бота командой /startminty',
+  'common.cancelled': '✅ Операция отменена.',
+  'common.coming_soon': 'Функция скоро появится!',
+  'common.request_error': '❌ Ошибка при обработке запроса',
+  'common.back_main': '◀️ В главное меню',
+  'common.back_to': '◀️ Назад: {menu}',
+  'common.previous': '⬅️ Назад',
+  'common.next': '➡️ Далее',
+  'common.unknown': 'Неизвестно',
+
+  // Welcome, help and main menu
+  'welcome.message': `🚀 <b>Добро пожаловать в Minty Rush!</b> 🚀
+
+Я помогаю отслеживать NFT-коллекции и присылаю уведомления в реальном времени о:
+• Новых минтах и переводах
+• Продажах и изменениях цен
+• Трендовых коллекциях
+• Ваших собственных NFT
+
+<b>Чтобы начать, выберите пункт меню:</b>`,
+  'help.message': `📋 <b>Команды Minty RushBot</b>
+
+🎯 <b>Управление NFT:</b>
+• /add_token - Добавить NFT-коллекцию для отслеживания
+• /remove_token - Удалить отслеживаемый NFT
+• /my_tokens - Ваши отслеживаемые NFT
+
+👛 <b>Отслеживание кошельков:</b>
+• /watch_wallet &lt;address&gt; [chain] [label] - Уведомления об NFT-активности кошелька
+• /unwatch_wallet &lt;address&gt; - Перестать отслеживать кошелёк
+• /wallets - Кошельки, отслеживаемые в этом чате
+
+📈 <b>Уведомления о флоре и объёме:</b>
+• /stats_alert &lt;collection&gt; floor below 0.5 - Уведомления о порогах флора или объёма за 24ч
+• /stats_alerts - Уведомления о флоре и объёме в этом чате
+• /stats_alert_remove &lt;id&gt; - Удалить уведомление
+
+💰 <b>Тренды и продвижение:</b>
+• /trending - Трендовые коллекции
+• /buy_trending - Продвинуть NFT в тренды
+• /validate &lt;txhash&gt; - Подтвердить оплату тренда
+• /buy_image &lt;contract&gt; - Оплатить показ настоящих изображений NFT
+• /validate_image &lt;contract&gt; &lt;txhash&gt; - Подтвердить оплату изображений
+• /buy_footer &lt;contract&gt; - Оплатить рекламу в подвале уведомлений
+• /validate_footer &lt;contract&gt; &lt;txhash&gt; &lt;link&gt; - Подтвердить рекламу в подвале
+
+📺 <b>Команды каналов:</b>
+• /add_channel - Добавить бота в канал
+• /channel_settings - Настроить уведомления канала
+
+🔗 <b>Вебхуки (администраторы группы):</b>
+• /webhook_add &lt;contract&gt; &lt;https-url&gt; - Отправлять активность на ваш адрес
+• /webhooks - Вебхуки этой группы
+• /webhook_remove &lt;id&gt; - Удалить вебхук
+• /webhook_secret &lt;id&gt; - Сменить секрет подписи
+
+⚙️ <b>Настройки уведомлений:</b>
+• /sweep_mode [aggregated|individual] - Одно сообщение на свип или на каждый предмет
+• /quiet_hours &lt;начало&gt; &lt;конец&gt; [часовой пояс]|off - Копить уведомления и присылать сводку после
+• /digest &lt;минуты&gt;|off - Присылать уведомления периодической сводкой
+• /templates - Изменить, просмотреть и сбросить шаблоны уведомлений
+• /language [en|es|ru|zh] - Язык меню и уведомлений
+
+• /startminty - Приветствие
+• /help - Эта справка
+
+Просто и удобно - продвигайте свои NFT без лишних хлопот! 🚀`,
+  'menu.manage_nfts': '📊 Управление NFT',
+  'menu.trending': '🔥 Тренды и буст',
+  'menu.images': '🖼️ Изображения NFT',
+  'menu.footer': '🔗 Реклама в подвале',
+  'menu.channels': '📺 Настройки каналов',
+  'menu.verify': '✅ Проверить оплату',
+  'menu.help': '❓ Помощь и контакты',
+  'menu.language': '🌐 Язык',
+  'contact.message': `📞 <b>Помощь и контакты</b>
+
+🌐 <b>Сайт:</b> https://www.candycodex.com
+
+📧 <b>Почта:</b> support@candycodex.com
+
+💬 <b>Telegram:</b> @CandyCodex
+
+<i>Нужна помощь? Пишите нам по любому из этих каналов!</i>`,
+
+  // Group setup
+  'group_start.message': `🎉 <b>Добро пожаловать в Minty Rush!</b>
+
+Чтобы настроить бота для <b>{group}</b>,
+выберите вариант ниже:`,
+  'group_start.this_group': 'этой группы',
+  'group_start.setup_chat': '💬 Настроить в чате',
+  'group_start.setup_bot': '🤖 Настроить в боте',
+  'start.not_admin': '⚠️ Вы больше не администратор этой группы.',
+  'start.invalid_setup': '❌ Ссылка для настройки недействительна или устарела. Запустите /startminty в группе ещё раз.',
+
+  // Top-level menus
+  'tokens_menu.admin': `📊 <b>Управление NFT</b>
+
+<b>Управляйте своими NFT-коллекциями:</b>`,
+  'tokens_menu.viewer': `📊 <b>NFT-коллекции</b>
+
+<b>Коллекции, отслеживаемые в этой группе:</b>
+
+ℹ️ <i>Добавлять и удалять коллекции могут только администраторы</i>`,
+  'tokens_menu.add': '➕ Добавить коллекцию',
+  'tokens_menu.view_mine': '👁️ Мои NFT',
+  'tokens_menu.remove': '🗑️ Удалить коллекцию',
+  'tokens_menu.view_tracked': '👁️ Отслеживаемые NFT',
+  'images_menu.message': `🖼️ <b>Изображения NFT</b>
+
+<b>Показывайте настоящие изображения NFT вместо картинки Candy Codex:</b>`,
+  'images_menu.pay': '💳 Включить изображения NFT',
+  'footer_menu.message': `🔗 <b>Реклама в подвале</b>
+
+<b>Рекламируйте свой проект в подвале уведомлений:</b>`,
+  'footer_menu.pay': '💳 Оплатить рекламу',
+  'channels_menu.empty': `📺 <b>Управление каналами</b>
+
+Вы ещё не добавили ни одного канала.
+
+<b>Как начать:</b>
+1️⃣ Добавьте меня в канал как администратора
+2️⃣ Выполните /add_channel в канале
+3️⃣ Вернитесь сюда, чтобы настроить его`,
+  'channels_menu.list': `📺 <b>Мои каналы</b>
+
+Выберите канал для настройки:`,
+  'verify_menu.message': `✅ <b>Проверка оплаты</b>
+
+Выберите тип платежа для проверки:
+
+`,
+  'verify_menu.trending': '🔍 Проверить тренд',
+  'verify_menu.image': '🖼️ Проверить изображения',
+  'verify_menu.footer': '🔗 Проверить рекламу',
+  'verify_menu.error': '❌ Не удалось загрузить варианты проверки. Попробуйте ещё раз.',
+  'trending_menu.message': `<b>🔥 Купить тренд</b>
+
+Выберите уровень:
+
+<b>🔵 Стандартный тренд</b>
+Дешевле, показывается в каналах стандартного уровня
+
+<b>⭐ Премиум тренд</b>
+Больше охват, показывается в каналах премиум-уровня`,
+  'trending_menu.buy_standard': '🔵 Купить стандартный тренд',
+  'trending_menu.buy_premium': '⭐ Купить премиум тренд',
+  'trending_menu.error': '❌ Не удалось загрузить меню трендов. Попробуйте ещё раз.',
+
+  // Channel settings
+  'channel.mode.all': '<b>Вся активность</b>\nУведомления в реальном времени обо ВСЕХ отслеживаемых NFT из любых групп',
+  'channel.mode.none': '<b>Без уведомлений</b>\nУведомления канала отключены',
+  'channel.mode.normal': '<b>Стандартный тренд</b>\nЗапланированные публикации промо стандартного уровня',
+  'channel.mode.premium': '<b>Премиум тренд</b>\nЗапланированные публикации промо премиум-уровня',
+  'channel.sweeps.aggregated': 'Одно сообщение на свип',
+  'channel.sweeps.individual': 'Одно сообщение на предмет',
+  'channel.digest_hint': '<i>Чтобы изменить, отправьте /quiet_hours или /digest в канале.</i>',
+  'channel.settings': `⚙️ <b>{title}</b>
+
+<b>Текущий режим уведомлений:</b>
+{mode}
+
+<b>Свипы:</b> {sweeps}
+<b>Язык:</b> {language}
+{digest}
+Нажмите кнопку ниже, чтобы переключить режим уведомлений:`,
+  'channel.change_mode': '{icon} Сменить режим',
+  'channel.sweeps_button.aggregated': '🧹 Свипы: сводно',
+  'channel.sweeps_button.individual': '🧹 Свипы: по одному',
+  'channel.language_button': '🌐 Язык: {language}',
+  'channel.add_nfts': '➕ Добавить NFT',
+  'channel.refresh': '🔄 Обновить',
+  'channel.back': '◀️ К каналам',
+
+  // Chat settings commands
+  'settings.unavailable': '❌ Настройки чата недоступны.',
+  'settings.channel_private_only': '❌ Настройки канала меняются через /channel_settings в личном чате со мной.',
+  'settings.admins_only': '❌ Менять настройки уведомлений могут только администраторы группы.',
+  'sweep.status': `🧹 <b>Уведомления о свипах:</b> {mode}
+
+<b>aggregated</b> - одно сообщение на свип: число предметов, сумма, средняя цена и коллаж
+<b>individual</b> - отдельное сообщение на каждый купленный предмет
+
+Изменить: <code>/sweep_mode aggregated</code> или <code>/sweep_mode individual</code>`,
+  'sweep.unknown_mode': '❌ Неизвестный режим "{mode}". Используйте: {modes}',
+  'sweep.saved.aggregated': '✅ Свипы будут публиковаться одним сообщением с коллажем.',
+  'sweep.saved.individual': '✅ Каждый предмет свипа будет публиковаться отдельно.',
+  'sweep.error': '❌ Не удалось изменить режим свипов. Попробуйте ещё раз.',
+  'quiet.summary': '🌙 <b>Тихие часы:</b> {quiet}\n📰 <b>Сводка:</b> {digest}',
+  'quiet.off': 'выключены',
+  'quiet.active_now': '<i>Сейчас тихие часы: уведомления копятся для сводки.</i>',
+  'quiet.help': `В тихие часы уведомления не отправляются, а после их окончания приходит одна сводка (продажи, объём, лучшая продажа, изменение флора).
+
+Включить: <code>/quiet_hours 23:00 07:00 Europe/Moscow</code> (часовой пояс необязателен, по умолчанию UTC)
+Выключить: <code>/quiet_hours off</code>`,
+  'quiet.turned_off': '✅ Тихие часы выключены. Накопленные уведомления скоро будут опубликованы.',
+  'quiet.invalid_times': '❌ Укажите время в формате 23:00 и 07:00, например /quiet_hours 23:00 07:00 Europe/Moscow',
+  'quiet.same_times': '❌ Начало и конец тихих часов должны различаться.',
+  'quiet.unknown_timezone': '❌ Неизвестный часовой пояс "{timezone}". Используйте название вроде UTC, Europe/Moscow или Asia/Almaty.',
+  'quiet.saved': '✅ Тихие часы сохранены.',
+  'quiet.error': '❌ Не удалось изменить тихие часы. Попробуйте ещё раз.',
+  'digest.every': 'каждые {minutes} мин',
+  'digest.off': 'выключена (уведомления сразу)',
+  'digest.help': `В режиме сводки уведомления копятся и публикуются одним сообщением через заданный интервал.
+
+Включить: <code>/digest 60</code> (минуты) или <code>/digest 4h</code>
+Вернуть мгновенные уведомления: <code>/digest off</code>`,
+  'digest.turned_off': '✅ Режим сводки выключен. Накопленные уведомления скоро будут опубликованы, дальше - сразу.',
+  'digest.invalid_interval': '❌ Укажите интервал от 5 минут до {maxHours} часов, например /digest 60 или /digest 4h',
+  'digest.saved': '✅ Режим сводки включён.',
+  'digest.error': '❌ Не удалось изменить режим сводки. Попробуйте ещё раз.',
+
+  // Alert templates
+  'templates.unavailable': '❌ Шаблоны сообщений недоступны.',
+  'templates.menu': `🧩 <b>Шаблоны уведомлений</b>
+
+Выберите, как выглядят уведомления в этом чате. В шаблонах используется HTML Telegram и подстановки вроде <code>{collection}</code> и <code>{price}</code>.
+
+{lines}
+
+Трендовые уведомления, свипы и сводки сохраняют свой формат.`,
+  'templates.custom': '✏️ свой',
+  'templates.default': 'стандартный',
+  'templates.editor': `🧩 Шаблон <b>{type}</b> ({state})
+
+<pre>{template}</pre>`,
+  'templates.state.custom': 'свой',
+  'templates.state.default': 'стандартный',
+  'templates.edit': '✏️ Изменить',
+  'templates.preview': '👁️ Просмотр',
+  'templates.reset': '♻️ Сбросить',
+  'templates.back': '◀️ К шаблонам',
+  'templates.caption_warning': '⚠️ С длинными названиями сообщение может превысить лимит подписи Telegram в 1024 символа.',
+  'templates.edit_prompt': `✏️ Отправьте новый шаблон <b>{type}</b>.
+
+Разрешённые теги: &lt;b&gt;, &lt;i&gt;, &lt;u&gt;, &lt;s&gt;, &lt;code&gt;, &lt;pre&gt;, &lt;a href="..."&gt;, &lt;blockquote&gt;, &lt;tg-spoiler&gt;. Строки, в которых все подстановки пусты, пропускаются.
+
+{placeholders}
+
+Напишите <code>cancel</code>, чтобы выйти.`,
+  'templates.wrong_chat': '❌ Откройте /templates заново в том чате, который хотите изменить.',
+  'templates.invalid': '❌ {reason}\n\nИсправьте шаблон и отправьте его снова или напишите cancel.',
+  'templates.error.empty': 'Шаблон пуст.',
+  'templates.error.too_long': 'Длина шаблона {length} символов; ограничение — {max}.',
+  'templates.error.unknown_placeholder': 'Неизвестный плейсхолдер {{name}}.',
+  'templates.error.malformed_tag': 'Неподдерживаемый или некорректный тег. Пишите символ "<" как &lt;.',
+  'templates.error.unsupported_tag': 'Telegram не поддерживает тег <{tag}>.',
+  'templates.error.wrong_close': '</{tag}> закрывает <{open}>.',
+  'templates.error.unopened_close': 'У </{tag}> нет открывающего тега.',
+  'templates.error.link_href': '<a> нужен атрибут href="...".',
+  'templates.error.attribute_placeholder': '{{name}} нельзя использовать внутри тега; используйте {item_url} или {explorer_url} в href.',
+  'templates.error.span_class': '<span> поддерживается только как <span class="tg-spoiler">.',
+  'templates.error.no_attributes': '<{tag}> не принимает атрибутов.',
+  'templates.error.unclosed': '<{tag}> не закрыт.',
+  'templates.error.ampersand': 'Пишите символ "&" как &amp;.',
+  'templates.saved': '✅ Шаблон {type} сохранён. Предпросмотр:',
+  'templates.load_error': '❌ Не удалось загрузить шаблоны. Попробуйте ещё раз.',
+  'templates.save_error': '❌ Не удалось сохранить шаблон. Попробуйте ещё раз.',
+
+  // Per-subscription alert filters
+  'filters.not_found': '❌ Подписка не найдена. Откройте /my_tokens ещё раз.',
+  'filters.title': '🔔 <b>Настройки уведомлений: {name}</b>',
+  'filters.none': 'Без фильтров: публикуются все продажи, листинги, офферы, минты и переводы.',
+  'filters.tap_hint': 'Нажмите на тип события, чтобы включить или выключить его.',
+  'filters.type.sale': 'Продажи',
+  'filters.type.listing': 'Листинги',
+  'filters.type.offer': 'Офферы',
+  'filters.type.mint': 'Минты',
+  'filters.type.transfer': 'Переводы',
+  'filters.min_price': '💲 Мин. цена: {value}',
+  'filters.marketplaces': '🏪 Маркетплейсы: {value}',
+  'filters.value.none': 'нет',
+  'filters.value.all': 'все',
+  'filters.value.off': 'выкл',
+  'filters.value.below_floor': 'ниже флора',
+  'filters.value.include': 'только {names}',
+  'filters.value.exclude': 'кроме {names}',
+  'filters.burns.hidden': '🔥 Сжигания: скрыты',
+  'filters.burns.shown': '🔥 Сжигания: видны',
+  'filters.snipe': '🎯 Режим снайпа: {value}',
+  'filters.traits': '🧬 Черты: {value}',
+  'filters.reset': '♻️ Сбросить фильтры',
+  'filters.back': '◀️ К моим NFT',
+  'filters.min_price_prompt': '💲 Отправьте минимальную цену для уведомлений о продажах, листингах и офферах:\n\n<code>0.5</code> - в нативной валюте\n<code>$250</code> - в USD\n<code>off</code> - без минимума\n\nНапишите <code>cancel</code>, чтобы выйти.',
+  'filters.marketplaces_prompt': '🏪 Отправьте маркетплейсы, которые нужно включить или исключить:\n\n<code>include opensea, magic eden</code>\n<code>exclude blur</code>\n<code>off</code> - все маркетплейсы\n\nНапишите <code>cancel</code>, чтобы выйти.',
+  'filters.snipe_prompt': '🎯 В режиме снайпа публикуются только листинги дешевле флора коллекции. Отправьте, насколько дешевле:\n\n<code>on</code> - любой листинг ниже флора\n<code>15%</code> - минимум на 15% ниже флора\n<code>off</code> - вернуть все уведомления\n\nНапишите <code>cancel</code>, чтобы выйти.',
+  'filters.traits_prompt': '🧬 Отправьте черты, по которым нужны уведомления. У предмета должна быть хотя бы одна из них:\n\n<code>Fur: Gold; Eyes: Laser</code> - тип и значение черты\n<code>1/1</code> - значение (или тип черты) любого типа\n<code>off</code> - все предметы\n\nЧерты берутся из снимка редкости коллекции. Предметы, которых в нём ещё нет, не публикуются.\n\nНапишите <code>cancel</code>, чтобы выйти.',
+  'filters.load_error': '❌ Не удалось загрузить настройки уведомлений. Попробуйте ещё раз.',
+  'filters.save_error': '❌ Не удалось сохранить фильтр. Попробуйте ещё раз.',
+  'filters.summary.events': 'События: {types}',
+  'filters.summary.min_price': 'Мин. цена: {value}',
+  'filters.summary.marketplaces': 'Маркетплейсы: {value}',
+  'filters.summary.burns': 'Сжигания скрыты',
+  'filters.summary.snipe_discount': 'Снайп-режим: листинги на {discount}%+ ниже флора',
+  'filters.summary.snipe_floor': 'Снайп-режим: листинги ниже флора',
+  'filters.summary.traits': 'Трейты: {traits}',
+  'filters.error.min_price': 'Отправьте число, например 0.5 (в нативной валюте) или $250 (в USD), либо "off".',
+  'filters.error.marketplace_mode': 'Начните с "include" или "exclude", например "exclude blur" или "include opensea, magic eden".',
+  'filters.error.marketplace_empty': 'Укажите хотя бы один маркетплейс.',
+  'filters.error.snipe': 'Отправьте "on" для любого листинга ниже флора, процент, например 15%, либо "off".',
+  'filters.error.traits': 'Отправьте трейты, например "Fur: Gold; Eyes: Laser", или значение, например "1/1", либо "off".',
+  'filters.error.too_many_traits': 'Отправьте не больше {max} трейтов.',
+  'filters.error.trait_too_long': 'Каждый трейт может быть не длиннее {max} символов.',
+
+  // Language
+  'language.menu': `🌐 <b>Язык</b>
+
+Сейчас: {language}
+
+Выберите язык меню и уведомлений в этом чате:`,
+  'language.saved': '✅ Язык изменён: {language}.',
+  'language.unknown': '❌ Неизвестный язык "{code}". Доступны: {codes}',
+  'language.error': '❌ Не удалось изменить язык. Попробуйте ещё раз.',
+
+  // Wallet watchlists
+  'wallet.usage': `👛 <b>Отслеживание кошелька</b>
+
+<code>/watch_wallet &lt;address&gt; [chain] [label]</code>
+
+Уведомление о каждом NFT, который кошелёк покупает, продаёт, минтит или переводит, в любой коллекции. EVM-адреса отслеживаются в Ethereum, если не указана сеть, например <code>/watch_wallet 0x… base Кит</code>. Адреса Solana и Bitcoin распознаются автоматически.`,
+  'wallet.invalid_address': '❌ Это не похоже на адрес EVM, Solana или Bitcoin.',
+  'wallet.chain_unavailable': '❌ Отслеживание кошельков недоступно в сети {chain}.',
+  'wallet.limit': '❌ В этом чате уже отслеживается {limit} кошельков. Сначала удалите один командой /unwatch_wallet.',
+  'wallet.saved': '✅ Отслеживаем <code>{address}</code> в сети {chain}.',
+  'wallet.updated': '✅ Метка <code>{address}</code> обновлена.',
+  'wallet.sync_failed': '⚠️ <code>{address}</code> сохранён, но вебхук {chain} пока не удалось обновить. Уведомления начнутся после синхронизации.',
+  'wallet.removed': '✅ <code>{address}</code> больше не отслеживается.',
+  'wallet.not_found': '❌ <code>{address}</code> не отслеживается в этом чате.',
+  'wallet.unwatch_usage': 'Использование: <code>/unwatch_wallet &lt;address&gt;</code>',
+  'wallet.list_empty': '👛 В этом чате пока не отслеживается ни один кошелёк.\n\nДобавьте кошелёк: <code>/watch_wallet &lt;address&gt; [chain] [label]</code>.',
+  'wallet.list_title': '👛 <b>Отслеживаемые кошельки</b> ({count}/{limit})',
+  'wallet.list_footer': 'Удалить кошелёк: <code>/unwatch_wallet &lt;address&gt;</code>.',
+  'wallet.error': '❌ Не удалось обновить список кошельков. Попробуйте ещё раз.',
+  'wallet.unavailable': '❌ Отслеживание кошельков недоступно.',
+  'wallet.action.bought': 'купил',
+  'wallet.action.sold': 'продал',
+  'wallet.action.minted': 'заминтил',
+  'wallet.action.received': 'получил',
+  'wallet.action.sent': 'отправил',
+  'wallet.action.burned': 'сжёг',
+
+  // Floor and volume alerts
+  'stats_alert.usage': `📈 <b>Уведомления о флоре и объёме</b>
+
+<code>/stats_alert &lt;collection&gt; floor below 0.5</code>
+<code>/stats_alert &lt;collection&gt; floor above 2</code>
+<code>/stats_alert &lt;collection&gt; floor change 10% 1h</code>
+<code>/stats_alert &lt;collection&gt; volume above 100</code>
+
+Коллекция — это адрес контракта, slug или название NFT, отслеживаемого в этом чате. Суммы указываются в валюте коллекции (ETH, SOL, BTC), объём — за последние 24 часа. Уведомление об изменении сравнивает с значением одно окно назад (по умолчанию 1h, до 7d).`,
+  'stats_alert.invalid_rule': '❌ Не удалось разобрать правило. Используйте <code>floor|volume below|above &lt;сумма&gt;</code> или <code>floor|volume change &lt;процент&gt; [окно]</code>.',
+  'stats_alert.not_tracked': '❌ <b>{collection}</b> не отслеживается в этом чате. Сначала добавьте её через /add_token.',
+  'stats_alert.saved': '✅ Уведомление #{id} сохранено: <b>{collection}</b> {rule}.',
+  'stats_alert.limit': '❌ В этом чате уже {limit} уведомлений о флоре и объёме. Сначала удалите одно через /stats_alert_remove.',
+  'stats_alert.list_empty': '📈 В этом чате пока нет уведомлений о флоре и объёме.\n\nДобавьте через <code>/stats_alert &lt;collection&gt; floor below &lt;сумма&gt;</code>.',
+  'stats_alert.list_title': '📈 <b>Уведомления о флоре и объёме</b> ({count}/{limit})',
+  'stats_alert.list_footer': 'Удалить: <code>/stats_alert_remove &lt;id&gt;</code>.',
+  'stats_alert.removed': '✅ Уведомление #{id} удалено.',
+  'stats_alert.not_found': '❌ Уведомления #{id} нет в этом чате.',
+  'stats_alert.remove_usage': 'Использование: <code>/stats_alert_remove &lt;id&gt;</code>',
+  'stats_alert.error': '❌ Ошибка при обновлении уведомлений о флоре и объёме. Попробуйте ещё раз.',
+  'stats_alert.unavailable': '❌ Уведомления о флоре и объёме недоступны.',
+  'stats_alert.rule.below': '{metric} ниже {threshold}',
+  'stats_alert.rule.above': '{metric} выше {threshold}',
+  'stats_alert.rule.change': '{metric} меняется на {threshold}% за {window}',
+  'stats_alert.metric.floor': 'Флор',
+  'stats_alert.metric.volume': 'Объём за 24ч',
+  'stats_alert.triggered.below': '{emoji} **{collection}**: {metric} опустился ниже {threshold}',
+  'stats_alert.triggered.above': '{emoji} **{collection}**: {metric} поднялся выше {threshold}',
+  'stats_alert.triggered.change': '{emoji} **{collection}**: {metric} изменился на {change} за {window}',
+  'stats_alert.previous': '{metric} {window} назад',
+  'stats_alert.sales': 'Продажи за 24ч',
+  'stats_alert.listed': 'Выставлено',
+
+  // Payments
+  'payment.type.trending': 'Продвижение в трендах',
+  'payment.type.image': 'Изображения NFT',
+  'payment.type.footer': 'Реклама в подвале',
+  'payment.instructions': `{emoji} <b>Инструкция по оплате: {type}</b>
+
+<b>Сеть:</b> {network}
+<b>Сумма:</b> {amount} {symbol}
+<b>Срок:</b> {duration}
+
+<b>Отправьте платёж на адрес:</b>
+<code>{address}</code>
+
+<b>⚠️ Важно:</b>
+• Отправьте РОВНО {amount} {symbol}
+• Используйте сеть {network}
+• После оплаты подтвердите её командой /validate
+• Платёж действителен 30 минут
+
+Нужна помощь? Пишите @YourSupportBot`,
+  'duration.hours_price': '{hours} ч - {price}',
+  'duration.days_price': '{days} дн. - {price}',
+  'duration.days': '{days} дн.',
+
+  // Tracked token lists
+  'tokens.none_on_chain': '🔍 Вы ещё не добавили NFT в сети {chain}!\n\nИспользуйте /add_token, чтобы начать отслеживать коллекции.',
+  'tokens.none': '🔍 Вы ещё не добавили ни одного токена!\n\nИспользуйте /add_token, чтобы начать отслеживать коллекции.',
+  'tokens.title_chain': '📊 <b>Ваши NFT в сети {chain}</b>',
+  'tokens.title': '📊 <b>Ваши отслеживаемые NFT</b>',
+  'tokens.remove_button': '🗑️ Удалить {name}',
+
+  // Alerts
+  'alert.action.sale': '**Покупка**',
+  'alert.action.listing': 'Листинг',
+  'alert.action.offer': 'Новый оффер',
+  'alert.action.mint': 'Минт',
+  'alert.action.transfer': 'Перевод',
+  'alert.action.burn': 'Сжигание',
+  'alert.price.sale': 'Цена покупки',
+  'alert.price.listing': 'Цена листинга',
+  'alert.price.offer': 'Сумма оффера',
+  'alert.price.mint': 'Цена минта',
+  'alert.price.transfer': 'Стоимость',
+  'alert.price.burn': 'Стоимость',
+  'alert.trending': 'В ТРЕНДЕ',
+  'alert.candy_minted': 'заминчен!',
+  'alert.nft': 'NFT',
+  'alert.buyer': 'Покупатель',
+  'alert.seller': 'Продавец',
+  'alert.bidder': 'Автор оффера',
+  'alert.from': 'От',
+  'alert.to': 'Кому',
+  'alert.marketplace': 'Маркетплейс',
+  'alert.collection': 'Коллекция',
+  'alert.ca': 'Контракт',
+  'alert.chain': 'Сеть',
+  'alert.view_on': 'Открыть на {name}',
+  'alert.snipe.action': 'выставлен ниже флора',
+  'alert.snipe.discount': 'Ниже флора',
+  'alert.snipe.floor': 'флор {floor}',
+  'alert.snipe.buy': '🛒 Купить на {name}',
+  'alert.rank': 'Ранг',
+  'alert.rarest_traits': 'Редчайшие черты',
+  'alert.royalty': 'Роялти',
+  'alert.powered_by': 'Работает на {link}',
+  'alert.boost': 'ПРОДВИНУТЬ NFT🟢',
+  'alert.sweep': 'Свип',
+  'alert.items': 'Предметов',
+  'alert.total': 'Итого',
+  'alert.priced': 'с ценой: {count}',
+  'alert.avg_price': 'Средняя цена',
+  'alert.buyers': 'Покупатели',
+  'alert.wallets': 'кошельков: {count}',
+  'alert.nfts': 'NFT',
+  'alert.more': 'и ещё {count}',
+
+  // Digests
+  'digest.title.quiet': '🌙 **Сводка за тихие часы**',
+  'digest.title.digest': '📰 **Сводка активности**',
+  'digest.summary_one': '{count} событие за {elapsed}',
+  'digest.summary_few': '{count} события за {elapsed}',
+  'digest.summary_many': '{count} событий за {elapsed}',
+  'digest.hours': '{hours} ч',
+  'digest.minutes': '{minutes} мин',
+  'digest.sales': 'Продажи',
+  'digest.volume': 'Объём',
+  'digest.top_sale': 'Лучшая продажа',
+  'digest.top_sale_item': '{item} за {price}',
+  'digest.floor': 'Флор',
+  'digest.more_collections': 'и ещё коллекций: {count}',
+  'digest.count.listing_one': '{count} листинг',
+  'digest.count.listing_few': '{count} листинга',
+  'digest.count.listing_many': '{count} листингов',
+  'digest.count.offer_one': '{count} оффер',
+  'digest.count.offer_few': '{count} оффера',
+  'digest.count.offer_many': '{count} офферов',
+  'digest.count.mint_one': '{count} минт',
+  'digest.count.mint_few': '{count} минта',
+  'digest.count.mint_many': '{count} минтов',
+  'digest.count.transfer_one': '{count} перевод',
+  'digest.count.transfer_few': '{count} перевода',
+  'digest.count.transfer_many': '{count} переводов',
+  'digest.count.burn_one': '{count} сжигание',
+  'digest.count.burn_few': '{count} сжигания',
+  'digest.count.burn_many': '{count} сжиганий',
+
+  // Resume reminders and unavailable services
+  'resume.tx_hash': '⏳ У вас есть незавершённый платёж. Ответьте хешем транзакции, чтобы продолжить, или напишите "cancel", чтобы начать заново.',
+  'resume.payment': '⏳ У вас есть незавершённый платёж. Если вы уже заплатили, нажмите "📝 Отправить хеш транзакции" в инструкциях по оплате выше, чтобы продолжить.',
+  'resume.step': '⏳ У вас есть незавершённый шаг. Ответьте, чтобы продолжить с того же места, или напишите "cancel", чтобы начать заново.',
+  'service.validation_unavailable': '❌ Сервис проверки недоступен.',
+  'service.image_unavailable': '❌ Оплата изображений недоступна.',
+  'service.footer_unavailable': '❌ Реклама в подвале недоступна.',
+  'service.chain_manager_unavailable': '❌ Менеджер сетей недоступен',
+  'tokens.load_error': '❌ Ошибка загрузки ваших NFT. Попробуйте ещё раз.',
+  'channel_cmd.add_wrong_chat': '❌ Эта команда работает только в каналах и группах. Сначала добавьте меня в канал, затем выполните команду там.',
+  'channel_cmd.unknown_user': '❌ Не удалось определить пользователя. Попробуйте ещё раз.',
+  'channel_cmd.user_error': '❌ Ошибка настройки пользователя. Попробуйте ещё раз.',
+  'channel_cmd.add_error': '❌ Ошибка добавления канала. Попробуйте ещё раз.',
+  'channel_cmd.settings_wrong_chat': '❌ Эта команда работает только в каналах и группах.',
+  'channel_cmd.settings_error': '❌ Ошибка получения настроек канала. Попробуйте ещё раз.',
+  'chat_id.message': '🆔 *Информация о чате*\n\n**ID чата:** `{chatId}`\n**Тип:** {chatType}\n**Название:** {chatTitle}\n\n*Используйте этот ID чата для настройки уведомлений в настройках бота.*',
+  'chat_id.error': '❌ Ошибка получения информации о чате.',
+
+  // Adding collections
+  'common.group': 'Группа',
+  'common.main_menu': '🏠 Главное меню',
+  'common.back': '◀️ Назад',
+  'common.cancel': '❌ Отмена',
+  'common.session_expired': '❌ Сессия истекла. Начните заново.',
+  'add_token.adding_to': `🎯 <b>Добавление NFT в:</b> {group}
+
+Выберите блокчейн:`,
+  'add_token.select_network': `🔗 <b>Выберите блокчейн</b>
+
+👥 <b>Добавление в: {group}</b>
+
+Выберите блокчейн, в котором находится ваша NFT-коллекция:`,
+  'add_token.switch_group': '🔄 Сменить группу',
+  'add_token.back_to_context': '◀️ Назад к выбору чата',
+  'add_token.back_to_chains': '◀️ Назад к выбору сети',
+  'add_token.admins_only': '⚠️ Только администраторы группы могут добавлять NFT.',
+  'add_token.prompt_solana': `◎ Выбрана сеть <b>Solana</b>!
+
+📝 Введите символ коллекции:
+
+Пример: <code>mad_lads</code>
+
+💡 Коллекция должна быть на маркетплейсе Magic Eden.`,
+  'add_token.prompt_bitcoin': `₿ Выбрана сеть <b>Bitcoin</b>!
+
+📝 Введите название коллекции:
+
+Пример: <code>bitcoin-puppets</code> или <code>NodeMonkes</code>
+
+💡 Коллекция должна быть на маркетплейсе Magic Eden Ordinals.`,
+  'add_token.prompt_evm': `🔗 Выбрана сеть <b>{chain}</b>!
+
+📝 Введите адрес NFT-контракта для отслеживания в {chain}:
+
+💡 Убедитесь, что контракт существует в сети {chain}.`,
+  'chain.select_specific': '❌ Для добавления токенов выберите конкретный блокчейн.',
+  'chain.invalid': '❌ Выбран неверный блокчейн.',
+  'chain.no_selection': '❌ Нет активного выбора сети.',
+  'token.not_found': '❌ NFT-коллекция не найдена.',
+  'channels_menu.add_help': `💡 <b>Добавление бота в канал</b>
+
+1. Добавьте этого бота в канал как администратора
+2. Выполните в канале команду <code>/add_channel</code>
+3. Настройте уведомления через <code>/channel_settings</code>`,
+  'channels_menu.back': '◀️ Назад к меню каналов',
+  'chat_id.message_html': `🆔 <b>Информация о чате</b>
+
+<b>ID чата:</b> <code>{chatId}</code>
+<b>Тип:</b> {chatType}
+<b>Название:</b> {chatTitle}
+
+<i>Используйте этот ID чата для настройки уведомлений в настройках бота.</i>`,
+  'payment.selected_chain': 'выбранном блокчейне',
+  'payment.submit_tx.image': `📝 <b>Отправка хеша транзакции</b>
+
+Отправьте мне хеш транзакции в {chain} для оплаты изображений.
+
+<i>Пример: 0xabc123456789def...</i>`,
+  'payment.submit_tx.image_token': `📝 <b>Отправка хеша транзакции</b>
+
+🎯 <b>NFT:</b> {name} ({symbol})
+🔗 <b>Сеть:</b> {chain}
+
+Отправьте мне хеш транзакции оплаты изображений.
+
+<i>Пример: 0xabc123456789def...</i>`,
+  'payment.submit_tx.footer': `📝 <b>Отправка хеша транзакции</b>
+
+Отправьте мне хеш транзакции в {chain} для оплаты рекламы в подвале.
+
+<i>Пример: 0xabc123456789def...</i>`,
+  'payment.submit_tx.trending': '📝 **Отправка хеша транзакции**\n\nОтправьте сейчас хеш транзакции в {chain}.\n\nХеш транзакции должен:\n• Начинаться с 0x\n• Состоять из 66 символов\n• Относиться к транзакции на адрес: `{address}`\n\nПример: `0x1234567890abcdef...`',
+
+  // Channel posts and webhooks
+  'payment.tx_cancelled': '✅ Отправка транзакции отменена.',
+  'channel_cmd.channel_only': '❌ Эта команда работает только в каналах и группах.',
+  'channel_cmd.add_channel_only': '❌ Эта команда работает только в каналах и группах. Сначала добавьте меня в канал, затем выполните команду там.',
+  'channel_cmd.unknown_channel': 'Неизвестный канал',
+  'channel_cmd.setup_error': '❌ Ошибка настройки пользователя. Попробуйте ещё раз.',
+  'channel_cmd.command_error': '❌ Ошибка при обработке команды. Попробуйте ещё раз.',
+  'chat_id.message_plain': `📋 Информация о чате:
+ID: {chatId}
+Тип: {chatType}
+Название: {chatTitle}`,
+  'webhooks.unavailable': '❌ Исходящие вебхуки недоступны.',
+  'webhooks.group_only': '❌ Вебхуки настраиваются для каждой группы. Выполните команду в группе, которая отслеживает коллекцию.',
+  'webhooks.admins_only': '❌ Только администраторы группы могут управлять вебхуками.',
+  'webhooks.secret_dm': `🔑 <b>Секрет подписи вебхука #{id}</b> ({chat})
+
+<code>{secret}</code>
+
+Проверяйте каждый запрос: вычислите HMAC-SHA256 от <code>&lt;X-MintTech-Timestamp&gt;.&lt;исходное тело&gt;</code> с этим секретом и сравните с заголовком <code>X-MintTech-Signature</code> (<code>sha256=...</code>).`,
+  'webhooks.add_usage': `Использование: <code>/webhook_add &lt;контракт или коллекция&gt; &lt;https-url&gt; [sale,listing,mint,transfer]</code>
+
+Каждое подходящее событие коллекции отправляется POST-запросом на URL в виде подписанного JSON.`,
+  'webhooks.not_tracked': '❌ Эта коллекция не отслеживается в группе. Сначала добавьте её через /add_token.',
+  'webhooks.all_events': 'все',
+  'webhooks.registered': `✅ Вебхук #{id} зарегистрирован для {name}
+📨 События: {events}`,
+  'webhooks.secret_sent': '🔑 Секрет подписи отправлен вам в личные сообщения.',
+  'webhooks.secret_not_sent': '⚠️ Не удалось написать вам в личные сообщения. Начните чат со мной, затем выполните здесь /webhook_secret {id}, чтобы получить секрет подписи.',
+  'webhooks.register_error': '❌ Не удалось зарегистрировать вебхук: {error}',
+  'webhooks.unknown_event_types': 'Неизвестные типы событий: {types} (ожидается {expected})',
+  'webhooks.list_empty': 'В этой группе нет вебхуков. Добавьте вебхук через /webhook_add.',
+  'webhooks.list_title': '🔗 <b>Вебхуки этой группы</b>',
+  'webhooks.events': 'События: {events}',
+  'webhooks.failures': ' · ошибок: {count}, последняя: {error}',
+  'webhooks.list_footer': 'Удаление: /webhook_remove &lt;id&gt;, смена секрета: /webhook_secret &lt;id&gt;.',
+  'webhooks.load_error': '❌ Ошибка загрузки вебхуков. Попробуйте ещё раз.',
+  'webhooks.remove_usage': 'Использование: /webhook_remove <id> (см. /webhooks)',
+  'webhooks.removed': '✅ Вебхук #{id} удалён.',
+  'webhooks.not_found': '❌ Вебхук #{id} не найден в этой группе.',
+  'webhooks.remove_error': '❌ Ошибка удаления вебхука. Попробуйте ещё раз.',
+  'webhooks.secret_usage': 'Использование: /webhook_secret <id> (см. /webhooks)',
+  'webhooks.secret_unchanged': '⚠️ Не удалось написать вам в личные сообщения, поэтому секрет не изменён. Начните чат со мной и снова выполните /webhook_secret {id}.',
+  'webhooks.secret_rotated': '🔑 Новый секрет подписи для вебхука #{id} отправлен в личные сообщения. Старый секрет больше не действует.',
+  'webhooks.secret_error': '❌ Ошибка смены секрета вебхука. Попробуйте ещё раз.',
+
+  // Adding, removing and boosting collections
+  'add_token.validating': '🔍 Проверка и добавление в {chain}...',
+  'add_token.validating_solana': '◎ Проверка Solana NFT через Magic Eden...',
+  'add_token.private_unavailable': `❌ <b>Отслеживание в личных сообщениях недоступно</b>
+
+Добавьте бота в группу или канал и нажмите кнопку <b>Setup inside BOT</b>, чтобы отслеживать NFT.
+
+Здесь, в личных сообщениях, можно смотреть NFT, отслеживаемые в ваших группах.`,
+  'add_token.boost_button': 'ПРОДВИНУТЬ NFT🟢',
+  'add_token.verified': '✅ Проверка: токен добавлен в ваш список отслеживания!',
+  'add_token.failed': `❌ {reason}
+
+Если это ошибка, попробуйте ещё раз или обратитесь в поддержку.`,
+  'add_token.error': '❌ Ошибка добавления токена. Проверьте адрес NFT и попробуйте ещё раз.',
+  'payment.no_pending': '❌ Ожидающий платёж не найден. Начните продвижение заново.',
+  'payment.invalid_tx_hash': '❌ Неверный формат хеша транзакции. Отправьте корректный хеш (начинается с 0x, 64 символа).',
+  'payment.cancel_hint': 'Или отправьте "cancel" для отмены.',
+  'payment.validating': '🔍 Проверка транзакции... Это может занять некоторое время.',
+  'payment.trending_confirmed': '✅ **Платёж подтверждён!**\n\n🔥 **{name}** в трендах на {duration} ч.!\n\n💰 Сумма: {amount} ETH\n📝 Транзакция: `{txHash}`\n🆔 ID платежа: {paymentId}\n\nВаша коллекция появится в списке трендов и будет продвигаться в каналах. Спасибо за поддержку экосистемы! 🚀',
+  'payment.trending_confirmed_one': '✅ **Платёж подтверждён!**\n\n🔥 **{name}** в трендах на {duration} ч.!\n\n💰 Сумма: {amount} ETH\n📝 Транзакция: `{txHash}`\n🆔 ID платежа: {paymentId}\n\nВаша коллекция появится в списке трендов и будет продвигаться в каналах. Спасибо за поддержку экосистемы! 🚀',
+  'payment.trending_failed': '❌ **Платёж не прошёл проверку**\n\n{error}\n\nПроверьте, что:\n• Транзакция отправлена на: `{address}`\n• Отправлена точная сумма: {amount} ETH\n• Транзакция подтверждена в блокчейне\n\nМожно попробовать другой хеш транзакции или обратиться в поддержку.',
+  'payment.validation_error': '❌ Ошибка проверки транзакции. Попробуйте ещё раз или обратитесь в поддержку.',
+  'remove.user_not_found': '❌ Пользователь не найден. Сначала запустите бота командой /startminty',
+  'remove.not_found': '❌ NFT не найден.',
+  'remove.not_subscribed': '❌ Вы не подписаны на этот NFT в этом чате.',
+  'remove.completion_error': '❌ Ошибка при удалении токена. Попробуйте ещё раз.',
+  'remove.removed_completely': `✅ <b>Токен удалён</b>
+
+🗑️ <b>{name}</b> полностью удалён из отслеживания.
+
+📮 Контракт: <code>{address}</code>`,
+  'remove.removed_from_chat': `✅ <b>Токен удалён</b>
+
+🗑️ <b>{name}</b> удалён из вашего списка отслеживания в {context}.
+
+📮 Контракт: <code>{address}</code>
+
+Вы больше не будете получать уведомления об этом NFT в этом чате.`,
+  'remove.context_private': 'личных сообщениях',
+  'remove.context_group': 'групповом чате ({chatId})',
+  'remove.error': '❌ Ошибка удаления токена. Попробуйте ещё раз.',
+  'tokens.unknown_collection': 'Неизвестная коллекция',
+  'tokens.notification_updated': 'Настройка уведомлений обновлена!',
+  'tokens.notification_error': '❌ Ошибка обновления настройки уведомлений',
+  'stats.loading': 'Загрузка статистики...',
+  'stats.coming_soon': '📊 Статистика токенов скоро появится!',
+  'stats.error': '❌ Ошибка загрузки статистики',
+  'trending.buy_button': '🚀 Купить тренд',
+  'trending.empty': `📊 *Сейчас нет NFT в трендах*
+
+Станьте первым, кто продвинет свою NFT-коллекцию!`,
+  'trending.title': '🔥 *NFT-коллекции в трендах*',
+  'trending.hours_left': '⏱️ Осталось {hours} ч',
+  'trending.paid': '💰 Оплачено: {amount} ETH',
+  'trending.stats_button': '📊 Статистика {name}',
+  'trending.view': 'Просмотр',
+  'trending.boost_button': '🚀 Продвинуть токен',
+  'trending.load_error': '❌ Ошибка загрузки трендовых токенов. Попробуйте ещё раз.',
+  'trending.instructions': `💳 <b>Инструкция по оплате</b>
+
+🔗 Сеть: <b>{chain}</b>
+🔥 Коллекция: {name}
+📮 Контракт: <code>{address}</code>
+⏱️ Длительность: {duration} ч
+💰 Стоимость: {fee} {symbol}
+
+🏦 <b>Адрес для оплаты ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 Как оплатить:
+1. Отправьте {fee} {symbol} на адрес выше
+2. Убедитесь, что отправляете из сети {chain}
+3. Нажмите на адрес выше, чтобы скопировать его
+4. После перевода скопируйте хеш транзакции
+5. Отправьте хеш транзакции кнопкой ниже
+
+✅ Всё просто: обычный перевод {symbol}, без сложных вызовов контракта!
+⏰ Платёж действителен 30 минут
+
+После успешной транзакции отправьте её хеш ниже:`,
+  'payment.submit_tx_button': '📝 Отправить хеш транзакции',
+  'trending.back_to_duration': '◀️ Назад к длительности',
+  'trending.instructions_error': '❌ Ошибка загрузки инструкции по оплате. Попробуйте ещё раз.',
+
+  // Group selection and image fees
+  'context.no_groups': `📭 <b>Нет доступных групп</b>
+
+У вас пока нет общих групп с этим ботом, или вы не администратор в этих группах.
+
+<b>С чего начать:</b>
+1️⃣ Добавьте меня в нужную группу Telegram
+2️⃣ Убедитесь, что вы администратор этой группы
+3️⃣ Выполните /startminty в группе для настройки
+4️⃣ Вернитесь сюда, чтобы добавить NFT
+
+💡 Нажмите кнопку ниже, чтобы добавить меня в группу!`,
+  'context.add_to_group': '➕ Добавить бота в группу',
+  'common.page': 'Стр. {page}/{total}',
+  'context.select': `🎯 <b>Куда добавить этот NFT?</b>
+
+Выберите чат:`,
+  'context.load_error': '❌ Ошибка загрузки чатов. Попробуйте ещё раз.',
+  'images.select_title': '🖼️ <b>Выберите NFT для показа изображений</b>',
+  'images.none_viewer': `📝 В этой группе пока не отслеживается ни один NFT!
+
+ℹ️ Добавлять NFT-коллекции могут только администраторы.`,
+  'images.none_admin': `📝 Сначала добавьте NFT-коллекции!
+
+Добавьте свои NFT, чтобы отслеживать их и включить показ изображений.`,
+  'images.select_prompt': 'Выберите NFT-коллекцию, для которой включить показ настоящих изображений:',
+  'images_menu.back': '◀️ Назад к меню изображений',
+  'tokens_menu.add_first': '➕ Добавить первый NFT',
+  'token.not_found_retry': '❌ Токен не найден. Попробуйте ещё раз.',
+  'token.selection_error': '❌ Ошибка обработки выбора токена. Попробуйте ещё раз.',
+  'images.duration_title': `🎨 <b>Оплата изображений — выберите срок</b>
+
+🎯 <b>Выбранный NFT:</b> {name} ({symbol})
+🔗 <b>Блокчейн:</b> {chain}
+
+Выберите, как долго показывать изображения NFT вместо картинки Candy Codex:`,
+  'duration.value_hint': '✨ Чем дольше срок, тем выгоднее цена за день!',
+  'duration.error': '❌ Ошибка показа вариантов срока. Попробуйте ещё раз.',
+  'common.back_to_tokens': '◀️ Назад к выбору токена',
+  'images.already_active': '✅ Оплата изображений для этого контракта уже активна. Показываются настоящие изображения NFT.',
+  'images.instructions': `💰 <b>Инструкция по оплате изображений</b>
+
+🎯 <b>Выбранный NFT:</b> {name} ({symbol})
+🔗 <b>Блокчейн:</b> {chain}
+📮 <b>Контракт:</b> <code>{address}</code>
+📅 <b>Срок:</b> {duration}
+💸 <b>Стоимость:</b> {fee} {feeSymbol}
+
+🏦 <b>Платёжный контракт ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Шаги оплаты:</b>
+{steps}
+
+После оплаты нажмите кнопку ниже, чтобы отправить хеш транзакции.`,
+  'duration.back': '◀️ Назад к выбору срока',
+  'payment.instructions_error': '❌ Ошибка формирования инструкции по оплате. Попробуйте ещё раз.',
+
+  // Payment verification
+  'verify.type.image': 'Оплата изображений',
+  'verify.type.trending': 'Тренд',
+  'verify.payment_name.image': 'оплаты изображений',
+  'verify.payment_name.trending': 'тренда',
+  'verify.title': '{emoji} <b>Проверка платежа: {type}</b>',
+  'verify.none_admin': `📝 Сначала добавьте NFT-коллекции!
+
+Добавьте свои NFT для отслеживания, прежде чем проверять платежи.`,
+  'verify.select_prompt': 'Выберите NFT-коллекцию, за которую вы платили:',
+  'verify_menu.back': '◀️ Назад к меню проверки',
+  'verify.submit_tx': `📝 <b>Отправка хеша транзакции</b>
+
+{emoji} <b>Тип платежа:</b> {type}
+🎯 <b>NFT:</b> {name} ({symbol})
+{chainEmoji} <b>Блокчейн:</b> {chain}
+
+Отправьте мне хеш транзакции {payment}.
+
+<i>Пример: 0xabc123456789def...</i>`,
+  'verify.footer_not_found': '❌ Реклама в подвале не найдена. Попробуйте ещё раз.',
+  'verify.submit_footer_tx': `📝 <b>Отправка хеша транзакции</b>
+
+🔗 <b>Тип платежа:</b> Реклама в подвале
+💲 <b>Тикер:</b> {ticker}
+📎 <b>Ссылка:</b> {link}
+
+Отправьте мне хеш транзакции оплаты рекламы в подвале.
+
+<i>Пример: 0xabc123456789def...</i>`,
+  'verify.ticker_error': '❌ Ошибка обработки выбора тикера. Попробуйте ещё раз.',
+  'verify.footer_ticker_prompt': `🔗 <b>Проверка оплаты подвала</b>
+
+Введите тикер, который вы указали при создании рекламы в подвале.
+
+💡 <b>Укажите префикс $, если использовали его</b>
+
+<i>Примеры: $PEPE, $BAYC, $AZUKI</i>
+<i>Или без $: PEPE, BAYC, AZUKI</i>`,
+  'verify.ticker_input_error': '❌ Ошибка показа ввода тикера. Попробуйте ещё раз.',
+  'verify.footer_title': '🔗 <b>Проверка оплаты подвала</b>',
+  'verify.no_footer_ads': `❌ <b>Реклама в подвале не найдена!</b>
+
+Вы ещё не покупали рекламу в подвале. Сначала купите её, чтобы проверять платежи.`,
+  'verify.buy_footer': '💰 Купить рекламу в подвале',
+  'verify.select_footer': `Выберите рекламу в подвале для проверки:
+
+💡 Выберите один из купленных тикеров:`,
+  'verify.footer_load_error': '❌ Ошибка загрузки рекламы в подвале. Попробуйте ещё раз.',
+
+  // Tracked collections and trending purchases
+  'tokens.context_header': '👥 <b>{label}</b> (токенов: {count})',
+  'tokens.context_header_one': '👥 <b>{label}</b> (токенов: {count})',
+  'tokens.alerts_button': '🔔 Оповещения: {name}',
+  'tokens.remove_from_button': '🗑️ Удалить {name}... из {context}',
+  'tokens.none_button': `🔍 Вы ещё не добавили ни одного токена!
+
+Нажмите кнопку ниже, чтобы начать отслеживать NFT-коллекции.`,
+  'tokens.none_group': `🔍 В этой группе пока не отслеживается ни один NFT!
+
+ℹ️ Добавлять NFT-коллекции могут только администраторы.`,
+  'tokens.title_all': '🎯 <b>Ваши отслеживаемые NFT</b> (всего {total}, чатов: {count})',
+  'tokens.title_all_one': '🎯 <b>Ваши отслеживаемые NFT</b> (всего {total}, чатов: {count})',
+  'tokens.title_total': '🎯 <b>Ваши отслеживаемые NFT</b> (всего {total})',
+  'tokens.title_group': `🎯 <b>NFT, отслеживаемые в группе</b> (всего {total})
+
+ℹ️ <i>Только просмотр — управлять коллекциями могут только администраторы</i>`,
+  'tokens.status_active': '🟢 Статус: активен',
+  'tokens.tracking_active': '📊 Отслеживание: ✅ активно',
+  'tokens_menu.add_more': '➕ Добавить ещё NFT',
+  'tokens_menu.back': '◀️ Назад к меню NFT',
+  'tokens.none_add_first': `📝 Сначала добавьте NFT-коллекции!
+
+Используйте /add_token, чтобы отслеживать первую NFT-коллекцию.`,
+  'trending_menu.back': '◀️ Назад к меню трендов',
+  'trending.tier.premium': 'Премиум',
+  'trending.tier.normal': 'Обычный',
+  'trending.boost_title': `🚀 <b>Продвижение в трендах: {tier}</b>
+
+Выберите NFT-коллекцию для продвижения:`,
+  'trending.back_to_buy': '🔄 Назад к покупке трендов',
+  'trending.chain_title': `🚀 <b>Тренд ({tier}) — {name}</b>
+
+🔗 Выберите блокчейн для оплаты:`,
+  'trending.back_to_nfts': '◀️ Назад к выбору NFT',
+  'trending.chain_error': '❌ Ошибка загрузки списка сетей. Попробуйте ещё раз.',
+  'trending.badge.premium': '⭐ ПРЕМИУМ',
+  'trending.badge.normal': '🔵 СТАНДАРТ',
+  'trending.group_link_prompt': '📎 <b>Ссылка на группу (необязательно)</b>\n\n<b>Выбрано:</b> {name}\n<b>Уровень:</b> {tier}\n\nДобавить ссылку на группу Telegram? Она появится в уведомлениях как кликабельный тикер (💬 ${symbol}).\n\n<b>Допустимые форматы:</b>\n• Полная ссылка: https://t.me/mygroup\n• С @: @mygroup\n• Просто текст: mygroup\n\nОтправьте ссылку на группу или нажмите «Пропустить», чтобы продолжить без неё.',
+  'trending.skip_group_link': '⏭️ Пропустить (без ссылки)',
+  'trending.group_link_error': '❌ Ошибка загрузки ввода ссылки на группу. Попробуйте ещё раз.',
+  'trending.duration_title': `🚀 <b>Тренд ({tier}) — {name}</b>
+
+🔗 Сеть: <b>{chain}</b> <i>(определена автоматически)</i>
+
+Выберите длительность:`,
+  'trending.duration_button': '{hours} ч - {price}',
+  'trending.change_chain': '🔄 Выбрать другую сеть',
+  'trending.options_error': '❌ Ошибка загрузки вариантов тренда. Попробуйте ещё раз.',
+
+  // Footer, image and payment validation
+  'common.too_many_pending': '❌ Слишком много незавершённых операций. Завершите или отмените текущие, прежде чем начинать новые.',
+  'common.user_not_found': '❌ Пользователь не найден. Сначала используйте /start.',
+  'validation.validating_address': '🔍 Проверка адреса NFT...',
+  'validation.contract_failed': '❌ Не удалось проверить контракт: {error}',
+  'validation.validating_tx': '🔍 Проверка транзакции... Пожалуйста, подождите.',
+  'validation.validating_image': '⏳ Проверка транзакции оплаты изображений...',
+  'validation.validating_footer': '⏳ Проверка оплаты рекламы в подвале...',
+  'validation.validating_footer_tx': '⏳ Проверка транзакции рекламы в подвале...',
+  'validation.payment_failed': `❌ Не удалось подтвердить оплату: {error}
+
+Убедитесь, что вы отправили правильную сумму на платёжный контракт.`,
+  'validation.failed': '❌ Проверка не пройдена: {error}',
+  'validation.failed_markdown': `❌ **Проверка не пройдена**
+
+{error}`,
+  'footer_menu.back': '◀️ Назад в меню подвала',
+  'footer.payment': `💰 <b>Оплата рекламы в подвале</b>
+
+🎨 <b>Коллекция:</b> {name}
+🎯 <b>Токен:</b> {symbol}
+💸 <b>Стоимость:</b> {fee} {feeSymbol}
+⏰ <b>Срок:</b> {duration}
+📮 <b>Контракт:</b> <code>{address}</code>
+
+📋 <b>Шаги оплаты:</b>
+{steps}`,
+  'footer.default_step': 'Отправьте оплату на адрес NFT',
+  'footer.payment_verified_details': `✅ <b>Оплата подтверждена!</b>
+
+🎨 Коллекция: <b>{name}</b>
+💰 Сумма: {amount}
+📅 Срок: <b>{duration}</b>
+
+🔗 <b>Своя ссылка</b>
+
+Теперь отправьте ссылку, которую нужно показывать в рекламе в подвале.
+
+<i>Пример: https://mytoken.com</i>`,
+  'footer.payment_verified': `✅ <b>Оплата подтверждена!</b>
+
+🔗 <b>Своя ссылка</b>
+
+Теперь отправьте ссылку, которую нужно показывать в рекламе в подвале.
+
+<i>Пример: https://mytoken.com</i>`,
+  'footer.session_invalid': '❌ Сессия истекла или оплата не подтверждена. Начните заново.',
+  'footer.invalid_url': '❌ Неверный формат URL. Укажите корректный URL (например, https://mytoken.com).',
+  'footer.creating': '⏳ Создание рекламы в подвале...',
+  'footer.payment_validated': `✅ <b>Оплата рекламы в подвале подтверждена!</b>
+
+🔗 <b>Тикер:</b> {ticker}
+💰 <b>Сумма:</b> {amount} {symbol}
+📝 <b>Транзакция:</b> <code>{txHash}</code>
+
+📢 Ваша реклама в подвале активна!`,
+  'footer.validation_failed': '❌ Не удалось подтвердить рекламу в подвале: {error}',
+  'trending.group_link_invalid': '❌ Неверный формат ссылки. Отправьте корректную ссылку на группу Telegram или введите "cancel", чтобы вернуться.',
+  'trending.group_link_saved': '✅ Ссылка на группу сохранена: {link}',
+  'images.contract_instructions': `💰 <b>Инструкция по оплате изображений</b>
+
+🎨 Коллекция: <b>{name}</b>
+📮 Контракт: <code>{address}</code>
+📅 Срок: <b>{duration}</b>
+💸 Стоимость: <b>{fee} {feeSymbol}</b>
+
+🏦 <b>Адрес для оплаты ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Шаги оплаты:</b>
+{steps}
+
+После оплаты нажмите кнопку ниже, чтобы отправить хеш транзакции.`,
+  'images.payment_validated': `✅ <b>Оплата изображений подтверждена!</b>
+
+🎨 Коллекция: <b>{name}</b>
+📮 Контракт: <code>{address}</code>
+💰 Сумма: {amount} {symbol}
+📅 Срок: <b>{duration}</b>
+📝 Транзакция: <code>{txHash}</code>
+👤 Плательщик: <code>{payer}</code>
+
+🖼️ <b>Настоящие изображения NFT этого контракта будут показываться (срок: {duration})!</b>`,
+  'trending.payment_validated': '✅ **Оплата успешно подтверждена!**\n\n🎯 Трендинг **{name}** активирован!\n⏱️ Срок (ч): {hours}\n💰 Сумма: {amount} {symbol}\n🔗 TX: `{txHash}`\n\nВаш NFT теперь в трендах! 🚀',
+  'verify.payment_name.footer': 'реклама в подвале',
+  'verify.tx_hash_required': `📝 <b>Нужен хеш транзакции</b>
+
+Теперь отправьте хеш транзакции оплаты ({payment}).
+
+<i>Пример: 0xabc123456789def...</i>`,
+  'verify.back_to_payments': '◀️ Назад к проверке платежей',
+  'verify.invalid_ticker': `❌ Неверный формат тикера. Введите корректный тикер.
+
+<i>Примеры: $PEPE, $BAYC, $AZUKI</i>
+
+💡 Используйте тот же тикер, что и при создании рекламы в подвале.`,
+  'verify.submit_ticker_tx': `📝 <b>Отправка хеша транзакции</b>
+
+🔗 <b>Тикер в подвале:</b> {ticker}
+
+Отправьте хеш транзакции оплаты рекламы в подвале.
+
+<i>Пример: 0xabc123456789def...</i>`,
+
+  // Chain lists and footer ad flow
+  'common.register_first': '❌ Сначала зарегистрируйтесь с помощью /start',
+  'tokens.none_on_chain_add': `🔍 <b>В сети {chain} нет NFT</b>
+
+Добавьте NFT, чтобы начать отслеживание в этом блокчейне!`,
+  'tokens.title_chain_count': '🎯 <b>Ваши токены в {chain}</b> ({count})',
+  'tokens_menu.add_on_chain': '➕ Добавить NFT в этой сети',
+  'tokens_menu.view_all_chains': '🌐 Все сети',
+  'tokens_menu.all_chains': '🌐 Все сети',
+  'images.duration_menu_title': `🎨 <b>Оплата изображений - выбор срока</b>
+
+Выберите, как долго показывать изображения NFT вместо изображения Candy Codex:`,
+  'duration.pricing_hint': '💡 Цена будет показана после выбора сети.',
+  'footer.duration_title': `📢 <b>Реклама в подвале - выбор срока</b>
+
+{emoji} <b>Сеть:</b> {chain}
+
+Выберите, как долго будет показываться ваша реклама:`,
+  'footer.duration_hint': '💡 Ваша реклама будет появляться внизу всех уведомлений о токенах!',
+  'images.chain_title': `🎨 <b>Оплата изображений - выбор сети</b>
+
+📅 Срок: <b>{duration}</b>
+
+🔗 Выберите блокчейн-сеть:
+💡 Цена зависит от сети и будет показана на экране оплаты.`,
+  'footer.chain_title': `📢 <b>Оплата рекламы в подвале - выбор сети</b>
+
+🔗 Выберите блокчейн-сеть для оплаты рекламы:
+
+💡 Срок и цена будут показаны на следующем шаге.`,
+  'payment.chain_error': '❌ Ошибка при выборе сети. Попробуйте ещё раз.',
+  'images.contract_title': '🎨 <b>Оплата изображений - ввод контракта</b>',
+  'footer.contract_title': '📢 <b>Оплата рекламы в подвале - ввод контракта</b>',
+  'payment.contract_prompt': `{title}
+
+📅 Срок: <b>{duration}</b>
+💰 Сумма: <b>{amount}</b>
+🔗 Сеть: <b>{chain}</b>
+
+📝 Введите адрес NFT:
+
+<i>Пример: 0x1234567890abcdef...</i>`,
+  'payment.back_to_chain': '◀️ Назад к выбору сети',
+  'payment.back_to_contract': '◀️ Назад к вводу контракта',
+  'payment.contract_input_error': '❌ Ошибка при открытии ввода контракта. Попробуйте ещё раз.',
+  'footer.link_title': '🔗 <b>Реклама в подвале - своя ссылка</b>',
+  'payment.chain_line': '🔗 Сеть: <b>{chain}</b>',
+  'footer.link_prompt': `Отправьте ссылку, по которой пользователи перейдут при нажатии на вашу рекламу.
+
+<i>Пример: https://mytoken.com или https://twitter.com/myproject</i>`,
+  'footer.link_input_error': '❌ Ошибка при открытии ввода ссылки. Попробуйте ещё раз.',
+  'footer.ticker_prompt': `💰 <b>Реклама в подвале - тикер</b>
+
+Отправьте тикер, который нужно показывать в подвале.
+
+<i>Примеры: $CANDY, $PEPE, $MYTOKEN</i>
+
+⭐️ Ваш тикер будет выглядеть так: <b>⭐️$YOURTICKER</b>`,
+  'footer.back_to_link': '◀️ Назад к вводу ссылки',
+  'footer.back_to_ticker': '◀️ Назад к вводу тикера',
+  'footer.invalid_link': `❌ Неверный формат URL. Укажите корректный URL, начинающийся с http:// или https://.
+
+<i>Пример: https://mytoken.com</i>`,
+  'footer.invalid_ticker': `❌ Неверный формат тикера. Используйте только буквы и цифры, не более 10 символов. <b>Без пробелов.</b>
+
+<i>Примеры: $CANDY, $PEPE, $MYTOKEN</i>`,
+  'footer.contract_instructions': `📢 <b>Инструкция по оплате рекламы в подвале</b>
+
+🎨 Коллекция: <b>{name}</b>
+📮 Контракт: <code>{address}</code>
+🔗 Своя ссылка: {link}
+⭐️ Тикер: <b>{ticker}</b>
+📅 Срок: <b>{duration}</b>
+💸 Стоимость: <b>{fee} {feeSymbol}</b>
+
+🏦 <b>Адрес для оплаты ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Шаги оплаты:</b>
+{steps}
+
+После оплаты нажмите кнопку ниже, чтобы отправить хеш транзакции.`,
+  'footer.payment_instructions': `📢 <b>Инструкция по оплате рекламы в подвале</b>
+
+🔗 Своя ссылка: {link}
+⭐️ Тикер: <b>{ticker}</b>
+📅 Срок: <b>{duration}</b>
+💸 Стоимость: <b>{amount}</b>
+{emoji} Сеть: <b>{chain}</b>
+
+🏦 <b>Адрес для оплаты ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>Шаги оплаты:</b>
+1. <b>ОТПРАВЬТЕ РОВНО {amount}</b> на адрес выше
+2. Используйте любой кошелёк {chain} в сети {network}
+3. Нажмите на адрес выше, чтобы скопировать его
+4. Дождитесь подтверждения транзакции
+5. Отправьте хеш транзакции ниже
+
+После оплаты нажмите кнопку ниже, чтобы отправить хеш транзакции.`
+};
@@ -0,0 +1,1064 @@
+// Chinese (Simplified)
+module.exports = {
+  // Shared
+  'common.error': '❌ 出错了，请重试。',
+  'common.start_first': '请先使用 /startminty 启动机器人',
+  'common.cancelled': '✅ 操作已取消。',
+  'common.coming_soon': '功能即将上线！',
+  'common.request_error': '❌ 处理请求时出错',
+  'common.back_main': '◀️ 返回主菜单',
+  'common.back_to': '◀️ 返回{menu}',
+  'common.previous': '⬅️ 上一页',
+  'common.next': '➡️ 下一页',
+  'common.unknown': '未知',
+
+  // Welcome, help and main menu
+  'welcome.message': `🚀 <b>欢迎使用 Minty Rush！</b> 🚀
+
+我可以帮你追踪 NFT 系列，并实时推送以下提醒：
+• 新铸造和转账
+• 成交和价格变动
+• 热门系列
+• 自定义 NFT 监控
+
+<b>请从下方菜单开始：</b>`,
+  'help.message': `📋 <b>Minty RushBot 命令</b>
+
+🎯 <b>NFT 管理：</b>
+• /add_token - 添加要追踪的 NFT 系列
+• /remove_token - 移除已追踪的 NFT
+• /my_tokens - 查看你追踪的 NFT
+
+👛 <b>钱包监控：</b>
+• /watch_wallet &lt;address&gt; [chain] [label] - 钱包 NFT 活动提醒
+• /unwatch_wallet &lt;address&gt; - 停止监控钱包
+• /wallets - 查看本聊天监控的钱包
+
+📈 <b>地板价与交易量提醒：</b>
+• /stats_alert &lt;collection&gt; floor below 0.5 - 地板价或 24 小时交易量阈值提醒
+• /stats_alerts - 查看本聊天的地板价与交易量提醒
+• /stats_alert_remove &lt;id&gt; - 删除提醒
+
+💰 <b>热门与推广：</b>
+• /trending - 查看热门系列
+• /buy_trending - 推广 NFT 上热门
+• /validate &lt;txhash&gt; - 验证热门推广付款
+• /buy_image &lt;contract&gt; - 支付真实 NFT 图片费用
+• /validate_image &lt;contract&gt; &lt;txhash&gt; - 验证图片费用
+• /buy_footer &lt;contract&gt; - 支付页脚广告费用
+• /validate_footer &lt;contract&gt; &lt;txhash&gt; &lt;link&gt; - 验证页脚广告
+
+📺 <b>频道命令：</b>
+• /add_channel - 将机器人添加到频道
+• /channel_settings - 配置频道提醒
+
+🔗 <b>Webhook（群组管理员）：</b>
+• /webhook_add &lt;contract&gt; &lt;https-url&gt; - 将活动推送到你的接口
+• /webhooks - 查看本群的 Webhook
+• /webhook_remove &lt;id&gt; - 删除 Webhook
+• /webhook_secret &lt;id&gt; - 更换签名密钥
+
+⚙️ <b>提醒设置：</b>
+• /sweep_mode [aggregated|individual] - 每次扫货一条消息或每件一条
+• /quiet_hours &lt;开始&gt; &lt;结束&gt; [时区]|off - 暂存提醒，结束后发送汇总
+• /digest &lt;分钟&gt;|off - 定期以汇总形式发送提醒
+• /templates - 编辑、预览和重置提醒模板
+• /language [en|es|ru|zh] - 菜单和提醒的语言
+
+• /startminty - 欢迎信息
+• /help - 显示本帮助
+
+简单专注，轻松推广你的 NFT！🚀`,
+  'menu.manage_nfts': '📊 管理 NFT',
+  'menu.trending': '🔥 热门与推广',
+  'menu.images': '🖼️ 显示 NFT 图片',
+  'menu.footer': '🔗 购买页脚广告',
+  'menu.channels': '📺 频道设置',
+  'menu.verify': '✅ 验证付款',
+  'menu.help': '❓ 帮助与联系',
+  'menu.language': '🌐 语言',
+  'contact.message': `📞 <b>帮助与联系</b>
+
+🌐 <b>网站：</b> https://www.candycodex.com
+
+📧 <b>邮箱：</b> support@candycodex.com
+
+💬 <b>Telegram：</b> @CandyCodex
+
+<i>需要帮助？欢迎通过以上任一方式联系我们！</i>`,
+
+  // Group setup
+  'group_start.message': `🎉 <b>欢迎使用 Minty Rush！</b>
+
+要为 <b>{group}</b> 配置机器人，
+请选择以下方式：`,
+  'group_start.this_group': '本群',
+  'group_start.setup_chat': '💬 在群聊中设置',
+  'group_start.setup_bot': '🤖 在机器人内设置',
+  'start.not_admin': '⚠️ 你已不再是该群的管理员。',
+  'start.invalid_setup': '❌ 设置链接无效或已过期。请在群内重新运行 /startminty。',
+
+  // Top-level menus
+  'tokens_menu.admin': `📊 <b>NFT 管理</b>
+
+<b>管理你的 NFT 系列：</b>`,
+  'tokens_menu.viewer': `📊 <b>NFT 系列</b>
+
+<b>查看本群追踪的 NFT 系列：</b>
+
+ℹ️ <i>只有管理员可以添加或移除系列</i>`,
+  'tokens_menu.add': '➕ 添加 NFT 系列',
+  'tokens_menu.view_mine': '👁️ 查看我的 NFT',
+  'tokens_menu.remove': '🗑️ 移除 NFT 系列',
+  'tokens_menu.view_tracked': '👁️ 查看已追踪的 NFT',
+  'images_menu.message': `🖼️ <b>NFT 图片显示</b>
+
+<b>用真实的 NFT 图片替代 Candy Codex 默认图片：</b>`,
+  'images_menu.pay': '💳 显示 NFT 图片',
+  'footer_menu.message': `🔗 <b>页脚广告</b>
+
+<b>在提醒页脚推广你的项目：</b>`,
+  'footer_menu.pay': '💳 购买页脚广告',
+  'channels_menu.empty': `📺 <b>频道管理</b>
+
+你还没有添加任何频道。
+
+<b>开始步骤：</b>
+1️⃣ 将我添加为频道管理员
+2️⃣ 在频道中运行 /add_channel
+3️⃣ 回到这里进行设置`,
+  'channels_menu.list': `📺 <b>我的频道</b>
+
+请选择要设置的频道：`,
+  'verify_menu.message': `✅ <b>验证你的付款</b>
+
+请选择要验证的付款类型：
+
+`,
+  'verify_menu.trending': '🔍 验证热门推广',
+  'verify_menu.image': '🖼️ 验证图片费用',
+  'verify_menu.footer': '🔗 验证页脚广告',
+  'verify_menu.error': '❌ 加载验证选项时出错，请重试。',
+  'trending_menu.message': `<b>🔥 购买热门推广</b>
+
+请选择推广等级：
+
+<b>🔵 标准热门</b>
+费用较低，在标准等级频道展示
+
+<b>⭐ 高级热门</b>
+曝光更高，在高级等级频道展示`,
+  'trending_menu.buy_standard': '🔵 购买标准热门',
+  'trending_menu.buy_premium': '⭐ 购买高级热门',
+  'trending_menu.error': '❌ 加载热门菜单时出错，请重试。',
+
+  // Channel settings
+  'channel.mode.all': '<b>全部活动</b>\n实时推送所有群组追踪的全部 NFT 提醒',
+  'channel.mode.none': '<b>无提醒</b>\n频道提醒已关闭',
+  'channel.mode.normal': '<b>标准热门</b>\n定时发布标准等级的推广内容',
+  'channel.mode.premium': '<b>高级热门</b>\n定时发布高级等级的推广内容',
+  'channel.sweeps.aggregated': '每次扫货一条消息',
+  'channel.sweeps.individual': '每件一条消息',
+  'channel.digest_hint': '<i>在频道中发送 /quiet_hours 或 /digest 进行修改。</i>',
+  'channel.settings': `⚙️ <b>{title}</b>
+
+<b>当前提醒模式：</b>
+{mode}
+
+<b>扫货：</b> {sweeps}
+<b>语言：</b> {language}
+{digest}
+点击下方按钮切换提醒模式：`,
+  'channel.change_mode': '{icon} 切换提醒模式',
+  'channel.sweeps_button.aggregated': '🧹 扫货：合并',
+  'channel.sweeps_button.individual': '🧹 扫货：逐件',
+  'channel.language_button': '🌐 语言：{language}',
+  'channel.add_nfts': '➕ 添加 NFT',
+  'channel.refresh': '🔄 刷新',
+  'channel.back': '◀️ 返回频道列表',
+
+  // Chat settings commands
+  'settings.unavailable': '❌ 聊天设置不可用。',
+  'settings.channel_private_only': '❌ 频道设置请在与我的私聊中通过 /channel_settings 修改。',
+  'settings.admins_only': '❌ 只有群管理员可以修改提醒设置。',
+  'sweep.status': `🧹 <b>扫货提醒：</b> {mode}
+
+<b>aggregated</b> - 每次扫货一条消息，包含件数、总价、均价和拼图
+<b>individual</b> - 每购买一件发送一条消息
+
+使用 <code>/sweep_mode aggregated</code> 或 <code>/sweep_mode individual</code> 修改`,
+  'sweep.unknown_mode': '❌ 未知模式 "{mode}"。可用：{modes}',
+  'sweep.saved.aggregated': '✅ 扫货将以一条带拼图的消息发送。',
+  'sweep.saved.individual': '✅ 扫货中的每一件都将单独发送。',
+  'sweep.error': '❌ 修改扫货模式时出错，请重试。',
+  'quiet.summary': '🌙 <b>免打扰时段：</b> {quiet}\n📰 <b>汇总：</b> {digest}',
+  'quiet.off': '关闭',
+  'quiet.active_now': '<i>当前处于免打扰时段：提醒将暂存并汇总发送。</i>',
+  'quiet.help': `免打扰时段内提醒会被暂存，结束时以一条汇总发送（成交、成交额、最高成交、地板价变化）。
+
+设置：<code>/quiet_hours 23:00 07:00 Asia/Shanghai</code>（时区可选，默认 UTC）
+关闭：<code>/quiet_hours off</code>`,
+  'quiet.turned_off': '✅ 免打扰时段已关闭。暂存的提醒将很快发送。',
+  'quiet.invalid_times': '❌ 请使用 23:00 和 07:00 这样的时间，例如 /quiet_hours 23:00 07:00 Asia/Shanghai',
+  'quiet.same_times': '❌ 免打扰时段的开始和结束时间不能相同。',
+  'quiet.unknown_timezone': '❌ 未知时区 "{timezone}"。请使用 UTC、Asia/Shanghai 或 Asia/Hong_Kong 这样的名称。',
+  'quiet.saved': '✅ 免打扰时段已保存。',
+  'quiet.error': '❌ 修改免打扰时段时出错，请重试。',
+  'digest.every': '每 {minutes} 分钟',
+  'digest.off': '关闭（实时提醒）',
+  'digest.help': `汇总模式下，提醒会被收集起来，按设定的间隔以一条汇总发送。
+
+设置：<code>/digest 60</code>（分钟）或 <code>/digest 4h</code>
+恢复实时提醒：<code>/digest off</code>`,
+  'digest.turned_off': '✅ 汇总模式已关闭。暂存的提醒将很快发送，之后恢复实时提醒。',
+  'digest.invalid_interval': '❌ 请使用 5 分钟到 {maxHours} 小时之间的间隔，例如 /digest 60 或 /digest 4h',
+  'digest.saved': '✅ 汇总模式已开启。',
+  'digest.error': '❌ 修改汇总模式时出错，请重试。',
+
+  // Alert templates
+  'templates.unavailable': '❌ 消息模板不可用。',
+  'templates.menu': `🧩 <b>提醒模板</b>
+
+选择本聊天中提醒的样式。模板使用 Telegram HTML 以及 <code>{collection}</code>、<code>{price}</code> 等占位符。
+
+{lines}
+
+热门提醒、扫货和汇总保持各自的格式。`,
+  'templates.custom': '✏️ 自定义',
+  'templates.default': '默认',
+  'templates.editor': `🧩 <b>{type}</b> 模板（{state}）
+
+<pre>{template}</pre>`,
+  'templates.state.custom': '自定义',
+  'templates.state.default': '默认',
+  'templates.edit': '✏️ 编辑',
+  'templates.preview': '👁️ 预览',
+  'templates.reset': '♻️ 恢复默认',
+  'templates.back': '◀️ 返回模板',
+  'templates.caption_warning': '⚠️ 名称较长时可能超过 Telegram 1024 字符的说明文字上限。',
+  'templates.edit_prompt': `✏️ 请发送新的 <b>{type}</b> 模板。
+
+允许的标签：&lt;b&gt;、&lt;i&gt;、&lt;u&gt;、&lt;s&gt;、&lt;code&gt;、&lt;pre&gt;、&lt;a href="..."&gt;、&lt;blockquote&gt;、&lt;tg-spoiler&gt;。占位符全部为空的行会被省略。
+
+{placeholders}
+
+输入 <code>cancel</code> 退出。`,
+  'templates.wrong_chat': '❌ 请在要修改的聊天中重新打开 /templates。',
+  'templates.invalid': '❌ {reason}\n\n请修改模板后重新发送，或输入 cancel。',
+  'templates.error.empty': '模板为空。',
+  'templates.error.too_long': '模板长度为 {length} 个字符，上限为 {max}。',
+  'templates.error.unknown_placeholder': '未知占位符 {{name}}。',
+  'templates.error.malformed_tag': '不支持或格式错误的标签。字面 "<" 请写作 &lt;。',
+  'templates.error.unsupported_tag': 'Telegram 不支持标签 <{tag}>。',
+  'templates.error.wrong_close': '</{tag}> 关闭了 <{open}>。',
+  'templates.error.unopened_close': '</{tag}> 没有对应的开始标签。',
+  'templates.error.link_href': '<a> 需要 href="..." 属性。',
+  'templates.error.attribute_placeholder': '{{name}} 不能在标签内使用；请在 href 中使用 {item_url} 或 {explorer_url}。',
+  'templates.error.span_class': '<span> 仅支持 <span class="tg-spoiler">。',
+  'templates.error.no_attributes': '<{tag}> 不接受属性。',
+  'templates.error.unclosed': '<{tag}> 未关闭。',
+  'templates.error.ampersand': '字面 "&" 请写作 &amp;。',
+  'templates.saved': '✅ {type} 模板已保存。预览：',
+  'templates.load_error': '❌ 加载模板时出错，请重试。',
+  'templates.save_error': '❌ 保存模板时出错，请重试。',
+
+  // Per-subscription alert filters
+  'filters.not_found': '❌ 未找到订阅。请重新打开 /my_tokens。',
+  'filters.title': '🔔 <b>提醒设置：{name}</b>',
+  'filters.none': '无过滤：所有成交、挂单、出价、铸造和转账都会推送。',
+  'filters.tap_hint': '点击事件类型以开启或关闭。',
+  'filters.type.sale': '成交',
+  'filters.type.listing': '挂单',
+  'filters.type.offer': '出价',
+  'filters.type.mint': '铸造',
+  'filters.type.transfer': '转账',
+  'filters.min_price': '💲 最低价格：{value}',
+  'filters.marketplaces': '🏪 交易市场：{value}',
+  'filters.value.none': '无',
+  'filters.value.all': '全部',
+  'filters.value.off': '关闭',
+  'filters.value.below_floor': '低于地板价',
+  'filters.value.include': '仅 {names}',
+  'filters.value.exclude': '排除 {names}',
+  'filters.burns.hidden': '🔥 销毁：隐藏',
+  'filters.burns.shown': '🔥 销毁：显示',
+  'filters.snipe': '🎯 捡漏模式：{value}',
+  'filters.traits': '🧬 特征：{value}',
+  'filters.reset': '♻️ 重置过滤',
+  'filters.back': '◀️ 返回我的 NFT',
+  'filters.min_price_prompt': '💲 请发送成交、挂单和出价提醒的最低价格：\n\n<code>0.5</code> - 以原生币计价\n<code>$250</code> - 以美元计价\n<code>off</code> - 不设最低价\n\n输入 <code>cancel</code> 退出。',
+  'filters.marketplaces_prompt': '🏪 请发送要包含或排除的交易市场：\n\n<code>include opensea, magic eden</code>\n<code>exclude blur</code>\n<code>off</code> - 所有交易市场\n\n输入 <code>cancel</code> 退出。',
+  'filters.snipe_prompt': '🎯 捡漏模式只推送价格低于系列地板价的挂单。请发送低于地板价的幅度：\n\n<code>on</code> - 任何低于地板价的挂单\n<code>15%</code> - 至少低于地板价 15%\n<code>off</code> - 恢复所有提醒\n\n输入 <code>cancel</code> 退出。',
+  'filters.traits_prompt': '🧬 请发送需要提醒的特征，物品只需具有其中之一：\n\n<code>Fur: Gold; Eyes: Laser</code> - 特征类型和值\n<code>1/1</code> - 任意类型的值（或特征类型）\n<code>off</code> - 所有物品\n\n特征来自系列的稀有度快照，尚未收录的物品不会推送。\n\n输入 <code>cancel</code> 退出。',
+  'filters.load_error': '❌ 加载提醒设置时出错，请重试。',
+  'filters.save_error': '❌ 保存过滤条件时出错，请重试。',
+  'filters.summary.events': '事件：{types}',
+  'filters.summary.min_price': '最低价格：{value}',
+  'filters.summary.marketplaces': '市场：{value}',
+  'filters.summary.burns': '已忽略销毁',
+  'filters.summary.snipe_discount': '狙击模式：低于地板价 {discount}% 以上的挂单',
+  'filters.summary.snipe_floor': '狙击模式：低于地板价的挂单',
+  'filters.summary.traits': '特征：{traits}',
+  'filters.error.min_price': '请发送数字，如 0.5（原生代币）或 $250（美元），或 "off"。',
+  'filters.error.marketplace_mode': '请以 "include" 或 "exclude" 开头，例如 "exclude blur" 或 "include opensea, magic eden"。',
+  'filters.error.marketplace_empty': '请至少指定一个市场。',
+  'filters.error.snipe': '发送 "on" 表示任何低于地板价的挂单，发送百分比如 15%，或 "off"。',
+  'filters.error.traits': '请发送特征，如 "Fur: Gold; Eyes: Laser"，或值如 "1/1"，或 "off"。',
+  'filters.error.too_many_traits': '最多发送 {max} 个特征。',
+  'filters.error.trait_too_long': '每个特征最多 {max} 个字符。',
+
+  // Language
+  'language.menu': `🌐 <b>语言</b>
+
+当前：{language}
+
+请选择本聊天中菜单和提醒的语言：`,
+  'language.saved': '✅ 语言已设置为 {language}。',
+  'language.unknown': '❌ 未知语言 "{code}"。可用：{codes}',
+  'language.error': '❌ 修改语言时出错，请重试。',
+
+  // Wallet watchlists
+  'wallet.usage': `👛 <b>监控钱包</b>
+
+<code>/watch_wallet &lt;address&gt; [chain] [label]</code>
+
+钱包在任何系列中买入、卖出、铸造或转移 NFT 时都会收到提醒。未指定链时，EVM 地址在 Ethereum 上监控，例如 <code>/watch_wallet 0x… base 巨鲸</code>。Solana 和 Bitcoin 地址会自动识别。`,
+  'wallet.invalid_address': '❌ 这不是有效的 EVM、Solana 或 Bitcoin 地址。',
+  'wallet.chain_unavailable': '❌ {chain} 暂不支持钱包监控。',
+  'wallet.limit': '❌ 本聊天已监控 {limit} 个钱包。请先用 /unwatch_wallet 移除一个。',
+  'wallet.saved': '✅ 正在监控 {chain} 上的 <code>{address}</code>。',
+  'wallet.updated': '✅ 已更新 <code>{address}</code> 的标签。',
+  'wallet.sync_failed': '⚠️ 已保存 <code>{address}</code>，但 {chain} 的 webhook 暂时无法更新。同步完成后开始提醒。',
+  'wallet.removed': '✅ 已停止监控 <code>{address}</code>。',
+  'wallet.not_found': '❌ 本聊天没有监控 <code>{address}</code>。',
+  'wallet.unwatch_usage': '用法：<code>/unwatch_wallet &lt;address&gt;</code>',
+  'wallet.list_empty': '👛 本聊天还没有监控任何钱包。\n\n使用 <code>/watch_wallet &lt;address&gt; [chain] [label]</code> 添加。',
+  'wallet.list_title': '👛 <b>监控的钱包</b>（{count}/{limit}）',
+  'wallet.list_footer': '使用 <code>/unwatch_wallet &lt;address&gt;</code> 停止监控。',
+  'wallet.error': '❌ 更新钱包监控列表时出错，请重试。',
+  'wallet.unavailable': '❌ 钱包监控不可用。',
+  'wallet.action.bought': '买入',
+  'wallet.action.sold': '卖出',
+  'wallet.action.minted': '铸造',
+  'wallet.action.received': '收到',
+  'wallet.action.sent': '转出',
+  'wallet.action.burned': '销毁',
+
+  // Floor and volume alerts
+  'stats_alert.usage': `📈 <b>地板价与交易量提醒</b>
+
+<code>/stats_alert &lt;collection&gt; floor below 0.5</code>
+<code>/stats_alert &lt;collection&gt; floor above 2</code>
+<code>/stats_alert &lt;collection&gt; floor change 10% 1h</code>
+<code>/stats_alert &lt;collection&gt; volume above 100</code>
+
+collection 可以是本聊天所追踪 NFT 的合约地址、slug 或名称。金额以该系列的货币计（ETH、SOL、BTC），交易量为最近 24 小时。变动提醒会与一个时间窗口之前的数值比较（默认 1h，最长 7d）。`,
+  'stats_alert.invalid_rule': '❌ 无法识别该规则。请使用 <code>floor|volume below|above &lt;金额&gt;</code> 或 <code>floor|volume change &lt;百分比&gt; [窗口]</code>。',
+  'stats_alert.not_tracked': '❌ 本聊天未追踪 <b>{collection}</b>，请先使用 /add_token 添加。',
+  'stats_alert.saved': '✅ 提醒 #{id} 已保存：<b>{collection}</b> {rule}。',
+  'stats_alert.limit': '❌ 本聊天已有 {limit} 条地板价与交易量提醒，请先用 /stats_alert_remove 删除一条。',
+  'stats_alert.list_empty': '📈 本聊天还没有地板价或交易量提醒。\n\n使用 <code>/stats_alert &lt;collection&gt; floor below &lt;金额&gt;</code> 添加。',
+  'stats_alert.list_title': '📈 <b>地板价与交易量提醒</b>（{count}/{limit}）',
+  'stats_alert.list_footer': '使用 <code>/stats_alert_remove &lt;id&gt;</code> 删除。',
+  'stats_alert.removed': '✅ 提醒 #{id} 已删除。',
+  'stats_alert.not_found': '❌ 本聊天不存在提醒 #{id}。',
+  'stats_alert.remove_usage': '用法：<code>/stats_alert_remove &lt;id&gt;</code>',
+  'stats_alert.error': '❌ 更新地板价与交易量提醒时出错，请重试。',
+  'stats_alert.unavailable': '❌ 地板价与交易量提醒不可用。',
+  'stats_alert.rule.below': '{metric}低于 {threshold}',
+  'stats_alert.rule.above': '{metric}高于 {threshold}',
+  'stats_alert.rule.change': '{metric}在 {window} 内变动 {threshold}%',
+  'stats_alert.metric.floor': '地板价',
+  'stats_alert.metric.volume': '24 小时交易量',
+  'stats_alert.triggered.below': '{emoji} **{collection}**：{metric}跌破 {threshold}',
+  'stats_alert.triggered.above': '{emoji} **{collection}**：{metric}突破 {threshold}',
+  'stats_alert.triggered.change': '{emoji} **{collection}**：{metric}在 {window} 内变动 {change}',
+  'stats_alert.previous': '{window}前{metric}',
+  'stats_alert.sales': '24 小时销量',
+  'stats_alert.listed': '挂单数',
+
+  // Payments
+  'payment.type.trending': '热门推广',
+  'payment.type.image': 'NFT 图片显示',
+  'payment.type.footer': '页脚广告',
+  'payment.instructions': `{emoji} <b>{type}付款说明</b>
+
+<b>网络：</b> {network}
+<b>金额：</b> {amount} {symbol}
+<b>时长：</b> {duration}
+
+<b>付款地址：</b>
+<code>{address}</code>
+
+<b>⚠️ 注意：</b>
+• 请准确发送 {amount} {symbol}
+• 使用 {network} 网络
+• 付款后使用 /validate 验证
+• 付款 30 分钟后过期
+
+需要帮助？联系 @YourSupportBot`,
+  'duration.hours_price': '{hours} 小时 - {price}',
+  'duration.days_price': '{days} 天 - {price}',
+  'duration.days': '{days} 天',
+
+  // Tracked token lists
+  'tokens.none_on_chain': '🔍 你还没有在 {chain} 上添加任何 NFT！\n\n使用 /add_token 开始追踪 NFT 系列。',
+  'tokens.none': '🔍 你还没有添加任何代币！\n\n使用 /add_token 开始追踪 NFT 系列。',
+  'tokens.title_chain': '📊 <b>你在 {chain} 上追踪的 NFT</b>',
+  'tokens.title': '📊 <b>你追踪的 NFT</b>',
+  'tokens.remove_button': '🗑️ 移除 {name}',
+
+  // Alerts
+  'alert.action.sale': '**买入**',
+  'alert.action.listing': '挂单',
+  'alert.action.offer': '收到出价',
+  'alert.action.mint': '铸造',
+  'alert.action.transfer': '转账',
+  'alert.action.burn': '销毁',
+  'alert.price.sale': '买入价',
+  'alert.price.listing': '挂单价',
+  'alert.price.offer': '出价金额',
+  'alert.price.mint': '铸造价',
+  'alert.price.transfer': '价值',
+  'alert.price.burn': '价值',
+  'alert.trending': '热门',
+  'alert.candy_minted': '已铸造！',
+  'alert.nft': 'NFT',
+  'alert.buyer': '买家',
+  'alert.seller': '卖家',
+  'alert.bidder': '出价人',
+  'alert.from': '发送方',
+  'alert.to': '接收方',
+  'alert.marketplace': '交易市场',
+  'alert.collection': '系列',
+  'alert.ca': '合约',
+  'alert.chain': '链',
+  'alert.view_on': '在 {name} 查看',
+  'alert.snipe.action': '低于地板价挂单',
+  'alert.snipe.discount': '低于地板价',
+  'alert.snipe.floor': '地板价 {floor}',
+  'alert.snipe.buy': '🛒 在 {name} 购买',
+  'alert.rank': '稀有度排名',
+  'alert.rarest_traits': '最稀有特征',
+  'alert.royalty': '版税',
+  'alert.powered_by': '由 {link} 提供支持',
+  'alert.boost': '推广你的 NFT🟢',
+  'alert.sweep': '扫货',
+  'alert.items': '件数',
+  'alert.total': '总价',
+  'alert.priced': '{count} 件有价格',
+  'alert.avg_price': '均价',
+  'alert.buyers': '买家',
+  'alert.wallets': '{count} 个钱包',
+  'alert.nfts': 'NFT',
+  'alert.more': '另有 {count} 件',
+
+  // Digests
+  'digest.title.quiet': '🌙 **免打扰时段汇总**',
+  'digest.title.digest': '📰 **活动汇总**',
+  'digest.summary_other': '过去 {elapsed} 共 {count} 个事件',
+  'digest.hours': '{hours} 小时',
+  'digest.minutes': '{minutes} 分钟',
+  'digest.sales': '成交',
+  'digest.volume': '成交额',
+  'digest.top_sale': '最高成交',
+  'digest.top_sale_item': '{item}，{price}',
+  'digest.floor': '地板价',
+  'digest.more_collections': '另有 {count} 个系列',
+  'digest.count.listing_other': '{count} 个挂单',
+  'digest.count.offer_other': '{count} 个出价',
+  'digest.count.mint_other': '{count} 次铸造',
+  'digest.count.transfer_other': '{count} 次转账',
+  'digest.count.burn_other': '{count} 次销毁',
+
+  // Resume reminders and unavailable services
+  'resume.tx_hash': '⏳ 你有一笔未完成的付款。回复你的交易哈希以继续，或输入 "cancel" 重新开始。',
+  'resume.payment': '⏳ 你有一笔未完成的付款。如果已经付款，请点击上方付款说明中的 "📝 提交交易哈希" 继续。',
+  'resume.step': '⏳ 你有一个未完成的步骤。回复即可从中断处继续，或输入 "cancel" 重新开始。',
+  'service.validation_unavailable': '❌ 验证服务不可用。',
+  'service.image_unavailable': '❌ 图片付费系统不可用。',
+  'service.footer_unavailable': '❌ 页脚广告系统不可用。',
+  'service.chain_manager_unavailable': '❌ 链管理器不可用',
+  'tokens.load_error': '❌ 加载你的 NFT 时出错，请重试。',
+  'channel_cmd.add_wrong_chat': '❌ 此命令只能在频道或群组中使用。请先把我添加到频道，然后在那里使用此命令。',
+  'channel_cmd.unknown_user': '❌ 无法识别用户，请重试。',
+  'channel_cmd.user_error': '❌ 设置用户时出错，请重试。',
+  'channel_cmd.add_error': '❌ 添加频道时出错，请重试。',
+  'channel_cmd.settings_wrong_chat': '❌ 此命令只能在频道或群组中使用。',
+  'channel_cmd.settings_error': '❌ 获取频道设置时出错，请重试。',
+  'chat_id.message': '🆔 *聊天信息*\n\n**聊天 ID:** `{chatId}`\n**类型:** {chatType}\n**名称:** {chatTitle}\n\n*使用此聊天 ID 在机器人设置中配置通知。*',
+  'chat_id.error': '❌ 获取聊天信息时出错。',
+
+  // Adding collections
+  'common.group': '群组',
+  'common.main_menu': '🏠 主菜单',
+  'common.back': '◀️ 返回',
+  'common.cancel': '❌ 取消',
+  'common.session_expired': '❌ 会话已过期，请重新开始。',
+  'add_token.adding_to': `🎯 <b>添加 NFT 到:</b> {group}
+
+请选择区块链:`,
+  'add_token.select_network': `🔗 <b>选择区块链网络</b>
+
+👥 <b>添加到: {group}</b>
+
+选择你的 NFT 系列所在的区块链:`,
+  'add_token.switch_group': '🔄 切换群组',
+  'add_token.back_to_context': '◀️ 返回选择聊天',
+  'add_token.back_to_chains': '◀️ 返回选择链',
+  'add_token.admins_only': '⚠️ 只有群组管理员可以添加 NFT 跟踪。',
+  'add_token.prompt_solana': `◎ 已选择 <b>Solana</b>！
+
+📝 请输入系列符号:
+
+示例: <code>mad_lads</code>
+
+💡 该系列必须在 Magic Eden 市场上存在。`,
+  'add_token.prompt_bitcoin': `₿ 已选择 <b>Bitcoin</b>！
+
+📝 请输入系列名称:
+
+示例: <code>bitcoin-puppets</code> 或 <code>NodeMonkes</code>
+
+💡 该系列必须在 Magic Eden Ordinals 市场上存在。`,
+  'add_token.prompt_evm': `🔗 已选择 <b>{chain}</b>！
+
+📝 请输入要在 {chain} 上跟踪的 NFT 合约地址:
+
+💡 请确认该合约存在于 {chain} 网络。`,
+  'chain.select_specific': '❌ 添加代币时请选择具体的区块链网络。',
+  'chain.invalid': '❌ 所选区块链网络无效。',
+  'chain.no_selection': '❌ 没有正在进行的链选择。',
+  'token.not_found': '❌ 未找到 NFT 系列。',
+  'channels_menu.add_help': `💡 <b>将机器人添加到频道</b>
+
+1. 将此机器人添加为频道管理员
+2. 在频道中使用命令 <code>/add_channel</code>
+3. 使用 <code>/channel_settings</code> 配置通知`,
+  'channels_menu.back': '◀️ 返回频道菜单',
+  'chat_id.message_html': `🆔 <b>聊天信息</b>
+
+<b>聊天 ID:</b> <code>{chatId}</code>
+<b>类型:</b> {chatType}
+<b>名称:</b> {chatTitle}
+
+<i>使用此聊天 ID 在机器人设置中配置通知。</i>`,
+  'payment.selected_chain': '所选区块链',
+  'payment.submit_tx.image': `📝 <b>提交交易哈希</b>
+
+请发送你在 {chain} 上支付图片费用的交易哈希。
+
+<i>示例: 0xabc123456789def...</i>`,
+  'payment.submit_tx.image_token': `📝 <b>提交交易哈希</b>
+
+🎯 <b>NFT:</b> {name} ({symbol})
+🔗 <b>链:</b> {chain}
+
+请发送你支付图片费用的交易哈希。
+
+<i>示例: 0xabc123456789def...</i>`,
+  'payment.submit_tx.footer': `📝 <b>提交交易哈希</b>
+
+请发送你在 {chain} 上支付页脚广告的交易哈希。
+
+<i>示例: 0xabc123456789def...</i>`,
+  'payment.submit_tx.trending': '📝 **提交交易哈希**\n\n请现在发送你在 {chain} 上的交易哈希。\n\n交易哈希应:\n• 以 0x 开头\n• 长度为 66 个字符\n• 来自发送到 `{address}` 的交易\n\n示例: `0x1234567890abcdef...`',
+
+  // Channel posts and webhooks
+  'payment.tx_cancelled': '✅ 已取消提交交易。',
+  'channel_cmd.channel_only': '❌ 此命令仅适用于频道或群组。',
+  'channel_cmd.add_channel_only': '❌ 此命令仅适用于频道或群组。请先将我添加到频道，然后在频道中使用此命令。',
+  'channel_cmd.unknown_channel': '未知频道',
+  'channel_cmd.setup_error': '❌ 设置用户时出错，请重试。',
+  'channel_cmd.command_error': '❌ 处理命令时出错，请重试。',
+  'chat_id.message_plain': `📋 聊天信息:
+ID: {chatId}
+类型: {chatType}
+名称: {chatTitle}`,
+  'webhooks.unavailable': '❌ 出站 Webhook 不可用。',
+  'webhooks.group_only': '❌ Webhook 按群组管理。请在跟踪该系列的群组中运行此命令。',
+  'webhooks.admins_only': '❌ 只有群组管理员可以管理 Webhook。',
+  'webhooks.secret_dm': `🔑 <b>Webhook #{id} 的签名密钥</b> ({chat})
+
+<code>{secret}</code>
+
+验证每个请求: 用此密钥计算 <code>&lt;X-MintTech-Timestamp&gt;.&lt;原始请求体&gt;</code> 的 HMAC-SHA256，并与 <code>X-MintTech-Signature</code> 请求头 (<code>sha256=...</code>) 比较。`,
+  'webhooks.add_usage': `用法: <code>/webhook_add &lt;合约或系列&gt; &lt;https-url&gt; [sale,listing,mint,transfer]</code>
+
+该系列的每个匹配活动都会以签名 JSON 的形式 POST 到该 URL。`,
+  'webhooks.not_tracked': '❌ 此群组未跟踪该系列。请先使用 /add_token 添加。',
+  'webhooks.all_events': '全部',
+  'webhooks.registered': `✅ 已为 {name} 注册 Webhook #{id}
+📨 事件: {events}`,
+  'webhooks.secret_sent': '🔑 签名密钥已通过私信发送给你。',
+  'webhooks.secret_not_sent': '⚠️ 无法给你发送私信。请先与我开始私聊，然后在此运行 /webhook_secret {id} 以接收签名密钥。',
+  'webhooks.register_error': '❌ 无法注册 Webhook: {error}',
+  'webhooks.unknown_event_types': '未知事件类型：{types}（应为 {expected}）',
+  'webhooks.list_empty': '此群组没有已注册的 Webhook。使用 /webhook_add 添加。',
+  'webhooks.list_title': '🔗 <b>此群组的 Webhook</b>',
+  'webhooks.events': '事件: {events}',
+  'webhooks.failures': ' · {count} 次失败，最近: {error}',
+  'webhooks.list_footer': '使用 /webhook_remove &lt;id&gt; 删除，使用 /webhook_secret &lt;id&gt; 更换密钥。',
+  'webhooks.load_error': '❌ 加载 Webhook 时出错，请重试。',
+  'webhooks.remove_usage': '用法: /webhook_remove <id> (见 /webhooks)',
+  'webhooks.removed': '✅ 已删除 Webhook #{id}。',
+  'webhooks.not_found': '❌ 此群组中未找到 Webhook #{id}。',
+  'webhooks.remove_error': '❌ 删除 Webhook 时出错，请重试。',
+  'webhooks.secret_usage': '用法: /webhook_secret <id> (见 /webhooks)',
+  'webhooks.secret_unchanged': '⚠️ 无法给你发送私信，因此密钥未更改。请先与我开始私聊，然后再次运行 /webhook_secret {id}。',
+  'webhooks.secret_rotated': '🔑 Webhook #{id} 的新签名密钥已私信发送。旧密钥已失效。',
+  'webhooks.secret_error': '❌ 更换 Webhook 密钥时出错，请重试。',
+
+  // Adding, removing and boosting collections
+  'add_token.validating': '🔍 正在 {chain} 上验证并添加...',
+  'add_token.validating_solana': '◎ 正在通过 Magic Eden 验证 Solana NFT...',
+  'add_token.private_unavailable': `❌ <b>不支持私聊跟踪</b>
+
+请将此机器人添加到群组或频道，并使用 <b>Setup inside BOT</b> 按钮跟踪 NFT。
+
+你仍然可以在私聊中查看群组里跟踪的 NFT。`,
+  'add_token.boost_button': '推广你的 NFT🟢',
+  'add_token.verified': '✅ 验证: 代币已加入你的跟踪列表！',
+  'add_token.failed': `❌ {reason}
+
+如果你认为这是错误，请重试或联系支持。`,
+  'add_token.error': '❌ 添加代币时出错。请检查 NFT 地址后重试。',
+  'payment.no_pending': '❌ 未找到待处理的付款。请重新开始推广流程。',
+  'payment.invalid_tx_hash': '❌ 交易哈希格式无效。请发送有效的交易哈希 (以 0x 开头，64 个字符)。',
+  'payment.cancel_hint': '或输入 "cancel" 取消。',
+  'payment.validating': '🔍 正在验证你的交易... 可能需要一点时间。',
+  'payment.trending_confirmed': '✅ **付款已确认！**\n\n🔥 **{name}** 将在接下来 {duration} 小时内上热门！\n\n💰 金额: {amount} ETH\n📝 交易: `{txHash}`\n🆔 付款 ID: {paymentId}\n\n你的系列将出现在热门列表中并在频道中推广。感谢你为生态做出的贡献！🚀',
+  'payment.trending_confirmed_one': '✅ **付款已确认！**\n\n🔥 **{name}** 将在接下来 {duration} 小时内上热门！\n\n💰 金额: {amount} ETH\n📝 交易: `{txHash}`\n🆔 付款 ID: {paymentId}\n\n你的系列将出现在热门列表中并在频道中推广。感谢你为生态做出的贡献！🚀',
+  'payment.trending_failed': '❌ **付款验证失败**\n\n{error}\n\n请检查:\n• 交易发送到: `{address}`\n• 发送了准确金额: {amount} ETH\n• 交易已在区块链上确认\n\n你可以使用其他交易哈希重试，或联系支持。',
+  'payment.validation_error': '❌ 验证交易时出错。请重试或联系支持。',
+  'remove.user_not_found': '❌ 未找到用户。请先使用 /startminty 启动机器人',
+  'remove.not_found': '❌ 未找到 NFT。',
+  'remove.not_subscribed': '❌ 你在此聊天中未订阅该 NFT。',
+  'remove.completion_error': '❌ 完成代币删除时出错，请重试。',
+  'remove.removed_completely': `✅ <b>代币已删除</b>
+
+🗑️ <b>{name}</b> 已完全停止跟踪。
+
+📮 合约: <code>{address}</code>`,
+  'remove.removed_from_chat': `✅ <b>代币已删除</b>
+
+🗑️ <b>{name}</b> 已从你在{context}的跟踪列表中删除。
+
+📮 合约: <code>{address}</code>
+
+你将不再在此聊天中收到该 NFT 的通知。`,
+  'remove.context_private': '私聊',
+  'remove.context_group': '群聊 ({chatId})',
+  'remove.error': '❌ 删除代币时出错，请重试。',
+  'tokens.unknown_collection': '未知系列',
+  'tokens.notification_updated': '通知设置已更新！',
+  'tokens.notification_error': '❌ 更新通知设置时出错',
+  'stats.loading': '正在加载统计...',
+  'stats.coming_soon': '📊 代币统计功能即将上线！',
+  'stats.error': '❌ 加载统计时出错',
+  'trending.buy_button': '🚀 购买热门',
+  'trending.empty': `📊 *当前没有热门 NFT*
+
+成为第一个推广 NFT 系列的人吧！`,
+  'trending.title': '🔥 *热门 NFT 系列*',
+  'trending.hours_left': '⏱️ 剩余 {hours} 小时',
+  'trending.paid': '💰 已付: {amount} ETH',
+  'trending.stats_button': '📊 {name} 统计',
+  'trending.view': '查看',
+  'trending.boost_button': '🚀 推广你的代币',
+  'trending.load_error': '❌ 加载热门代币时出错，请重试。',
+  'trending.instructions': `💳 <b>付款说明</b>
+
+🔗 链: <b>{chain}</b>
+🔥 系列: {name}
+📮 合约: <code>{address}</code>
+⏱️ 时长: {duration} 小时
+💰 费用: {fee} {symbol}
+
+🏦 <b>付款地址 ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 付款步骤:
+1. 向上方付款地址发送 {fee} {symbol}
+2. 确认从 {chain} 网络发送
+3. 点击上方地址即可复制
+4. 转账后复制交易哈希
+5. 使用下方按钮提交交易哈希
+
+✅ 流程简单: 只需普通 {symbol} 转账，无需复杂的合约调用！
+⏰ 付款将在 30 分钟后过期
+
+交易成功后，请在下方提交交易哈希:`,
+  'payment.submit_tx_button': '📝 提交交易哈希',
+  'trending.back_to_duration': '◀️ 返回时长',
+  'trending.instructions_error': '❌ 加载付款说明时出错，请重试。',
+
+  // Group selection and image fees
+  'context.no_groups': `📭 <b>没有可用的群组</b>
+
+你和此机器人还没有共同的群组，或者你不是这些群组的管理员。
+
+<b>开始步骤:</b>
+1️⃣ 将我添加到你想要的 Telegram 群组
+2️⃣ 确认你是该群组的管理员
+3️⃣ 在群组中运行 /startminty 完成设置
+4️⃣ 回到这里添加 NFT
+
+💡 使用下方按钮将我添加到群组！`,
+  'context.add_to_group': '➕ 将机器人添加到群组',
+  'common.page': '第 {page}/{total} 页',
+  'context.select': `🎯 <b>要将此 NFT 添加到哪里？</b>
+
+选择聊天:`,
+  'context.load_error': '❌ 加载聊天时出错，请重试。',
+  'images.select_title': '🖼️ <b>选择要显示图片的 NFT</b>',
+  'images.none_viewer': `📝 此群组还没有跟踪任何 NFT！
+
+ℹ️ 只有管理员可以添加要跟踪的 NFT 系列。`,
+  'images.none_admin': `📝 你需要先添加一些 NFT 系列！
+
+添加你的 NFT 以跟踪它们并启用图片显示。`,
+  'images.select_prompt': '选择要启用真实图片显示的 NFT 系列:',
+  'images_menu.back': '◀️ 返回图片菜单',
+  'tokens_menu.add_first': '➕ 添加你的第一个 NFT',
+  'token.not_found_retry': '❌ 未找到代币，请重试。',
+  'token.selection_error': '❌ 处理代币选择时出错，请重试。',
+  'images.duration_title': `🎨 <b>图片费用 - 选择时长</b>
+
+🎯 <b>所选 NFT:</b> {name} ({symbol})
+🔗 <b>区块链:</b> {chain}
+
+选择显示 NFT 图片 (替代 Candy Codex 图片) 的时长:`,
+  'duration.value_hint': '✨ 时长越长，每天越划算！',
+  'duration.error': '❌ 显示时长选项时出错，请重试。',
+  'common.back_to_tokens': '◀️ 返回选择代币',
+  'images.already_active': '✅ 此合约的图片费用已生效，正在显示真实 NFT 图片。',
+  'images.instructions': `💰 <b>图片费用付款说明</b>
+
+🎯 <b>所选 NFT:</b> {name} ({symbol})
+🔗 <b>区块链:</b> {chain}
+📮 <b>合约:</b> <code>{address}</code>
+📅 <b>时长:</b> {duration}
+💸 <b>费用:</b> {fee} {feeSymbol}
+
+🏦 <b>付款合约 ({shortAddress}):</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>付款步骤:</b>
+{steps}
+
+付款后，点击下方按钮提交交易哈希。`,
+  'duration.back': '◀️ 返回选择时长',
+  'payment.instructions_error': '❌ 生成付款说明时出错，请重试。',
+
+  // Payment verification
+  'verify.type.image': '图片费用',
+  'verify.type.trending': '热门',
+  'verify.payment_name.image': '图片费用',
+  'verify.payment_name.trending': '热门',
+  'verify.title': '{emoji} <b>验证{type}付款</b>',
+  'verify.none_admin': `📝 你需要先添加一些 NFT 系列！
+
+请先添加并跟踪你的 NFT，然后再验证付款。`,
+  'verify.select_prompt': '选择你为其付款的 NFT 系列:',
+  'verify_menu.back': '◀️ 返回验证菜单',
+  'verify.submit_tx': `📝 <b>提交交易哈希</b>
+
+{emoji} <b>付款类型:</b> {type}
+🎯 <b>NFT:</b> {name} ({symbol})
+{chainEmoji} <b>区块链:</b> {chain}
+
+请发送你支付{payment}的交易哈希。
+
+<i>示例: 0xabc123456789def...</i>`,
+  'verify.footer_not_found': '❌ 未找到页脚广告，请重试。',
+  'verify.submit_footer_tx': `📝 <b>提交交易哈希</b>
+
+🔗 <b>付款类型:</b> 页脚广告
+💲 <b>代码:</b> {ticker}
+📎 <b>链接:</b> {link}
+
+请发送你支付页脚广告的交易哈希。
+
+<i>示例: 0xabc123456789def...</i>`,
+  'verify.ticker_error': '❌ 处理代码选择时出错，请重试。',
+  'verify.footer_ticker_prompt': `🔗 <b>验证页脚付款</b>
+
+请输入你创建页脚广告时使用的代码。
+
+💡 <b>如果使用了 $ 前缀，请一并输入</b>
+
+<i>示例: $PEPE, $BAYC, $AZUKI</i>
+<i>或不带 $: PEPE, BAYC, AZUKI</i>`,
+  'verify.ticker_input_error': '❌ 显示代码输入时出错，请重试。',
+  'verify.footer_title': '🔗 <b>验证页脚付款</b>',
+  'verify.no_footer_ads': `❌ <b>未找到页脚广告！</b>
+
+你还没有购买页脚广告。请先购买页脚广告再验证付款。`,
+  'verify.buy_footer': '💰 购买页脚广告',
+  'verify.select_footer': `选择要验证的页脚广告:
+
+💡 从你购买的代码中选择:`,
+  'verify.footer_load_error': '❌ 加载页脚广告时出错，请重试。',
+
+  // Tracked collections and trending purchases
+  'tokens.context_header': '👥 <b>{label}</b> ({count} 个代币)',
+  'tokens.context_header_one': '👥 <b>{label}</b> ({count} 个代币)',
+  'tokens.alerts_button': '🔔 提醒: {name}',
+  'tokens.remove_from_button': '🗑️ 从 {context} 删除 {name}...',
+  'tokens.none_button': `🔍 你还没有添加任何代币！
+
+使用下方按钮开始跟踪 NFT 系列。`,
+  'tokens.none_group': `🔍 此群组还没有跟踪任何 NFT！
+
+ℹ️ 只有管理员可以添加要跟踪的 NFT 系列。`,
+  'tokens.title_all': '🎯 <b>你跟踪的 NFT</b> (共 {total} 个，分布在 {count} 个聊天中)',
+  'tokens.title_all_one': '🎯 <b>你跟踪的 NFT</b> (共 {total} 个，分布在 {count} 个聊天中)',
+  'tokens.title_total': '🎯 <b>你跟踪的 NFT</b> (共 {total} 个)',
+  'tokens.title_group': `🎯 <b>此群组跟踪的 NFT</b> (共 {total} 个)
+
+ℹ️ <i>只读视图 - 只有管理员可以管理系列</i>`,
+  'tokens.status_active': '🟢 状态: 活跃',
+  'tokens.tracking_active': '📊 跟踪: ✅ 已启用',
+  'tokens_menu.add_more': '➕ 添加更多 NFT',
+  'tokens_menu.back': '◀️ 返回 NFT 菜单',
+  'tokens.none_add_first': `📝 你需要先添加一些 NFT 系列！
+
+使用 /add_token 跟踪你的第一个 NFT 系列。`,
+  'trending_menu.back': '◀️ 返回热门菜单',
+  'trending.tier.premium': '高级',
+  'trending.tier.normal': '普通',
+  'trending.boost_title': `🚀 <b>{tier}热门推广</b>
+
+选择要推广的 NFT 系列:`,
+  'trending.back_to_buy': '🔄 返回购买热门菜单',
+  'trending.chain_title': `🚀 <b>{tier}热门 - {name}</b>
+
+🔗 选择付款所用的区块链网络:`,
+  'trending.back_to_nfts': '◀️ 返回选择 NFT',
+  'trending.chain_error': '❌ 加载链选项时出错，请重试。',
+  'trending.badge.premium': '⭐ 高级',
+  'trending.badge.normal': '🔵 标准',
+  'trending.group_link_prompt': '📎 <b>群组链接 (可选)</b>\n\n<b>已选择:</b> {name}\n<b>等级:</b> {tier}\n\n是否添加 Telegram 群组链接？该链接会在活动通知中显示为可点击的代码 (💬 ${symbol})。\n\n<b>支持的格式:</b>\n• 完整链接: https://t.me/mygroup\n• 带 @: @mygroup\n• 纯文本: mygroup\n\n现在发送群组链接，或点击跳过以不带链接继续。',
+  'trending.skip_group_link': '⏭️ 跳过 (不添加链接)',
+  'trending.group_link_error': '❌ 加载群组链接输入时出错，请重试。',
+  'trending.duration_title': `🚀 <b>{tier}热门 - {name}</b>
+
+🔗 链: <b>{chain}</b> <i>(自动识别)</i>
+
+选择热门时长:`,
+  'trending.duration_button': '{hours} 小时 - {price}',
+  'trending.change_chain': '🔄 选择其他链',
+  'trending.options_error': '❌ 加载热门选项时出错，请重试。',
+
+  // Footer, image and payment validation
+  'common.too_many_pending': '❌ 您的待处理操作过多。请先完成或取消现有操作，再开始新的操作。',
+  'common.user_not_found': '❌ 未找到用户。请先使用 /start。',
+  'validation.validating_address': '🔍 正在验证 NFT 地址...',
+  'validation.contract_failed': '❌ 合约验证失败：{error}',
+  'validation.validating_tx': '🔍 正在验证您的交易... 请稍候。',
+  'validation.validating_image': '⏳ 正在验证您的图片费用交易...',
+  'validation.validating_footer': '⏳ 正在验证您的页脚广告付款...',
+  'validation.validating_footer_tx': '⏳ 正在验证您的页脚广告交易...',
+  'validation.payment_failed': `❌ 付款验证失败：{error}
+
+请确认您向付款合约发送了正确的金额。`,
+  'validation.failed': '❌ 验证失败：{error}',
+  'validation.failed_markdown': `❌ **验证失败**
+
+{error}`,
+  'footer_menu.back': '◀️ 返回页脚菜单',
+  'footer.payment': `💰 <b>页脚广告付款</b>
+
+🎨 <b>收藏集：</b>{name}
+🎯 <b>代币：</b>{symbol}
+💸 <b>费用：</b>{fee} {feeSymbol}
+⏰ <b>时长：</b>{duration}
+📮 <b>合约：</b><code>{address}</code>
+
+📋 <b>付款步骤：</b>
+{steps}`,
+  'footer.default_step': '将付款发送到 NFT 地址',
+  'footer.payment_verified_details': `✅ <b>付款已验证！</b>
+
+🎨 收藏集：<b>{name}</b>
+💰 金额：{amount}
+📅 时长：<b>{duration}</b>
+
+🔗 <b>自定义链接</b>
+
+现在请发送您希望在页脚广告中显示的自定义链接。
+
+<i>示例：https://mytoken.com</i>`,
+  'footer.payment_verified': `✅ <b>付款已验证！</b>
+
+🔗 <b>自定义链接</b>
+
+现在请发送您希望在页脚广告中显示的自定义链接。
+
+<i>示例：https://mytoken.com</i>`,
+  'footer.session_invalid': '❌ 会话已过期或付款未验证。请重新开始。',
+  'footer.invalid_url': '❌ URL 格式无效。请提供有效的 URL（例如 https://mytoken.com）。',
+  'footer.creating': '⏳ 正在创建您的页脚广告...',
+  'footer.payment_validated': `✅ <b>页脚付款已验证！</b>
+
+🔗 <b>代码：</b>{ticker}
+💰 <b>金额：</b>{amount} {symbol}
+📝 <b>交易：</b><code>{txHash}</code>
+
+📢 您的页脚广告现已生效！`,
+  'footer.validation_failed': '❌ 页脚验证失败：{error}',
+  'trending.group_link_invalid': '❌ 链接格式无效。请发送有效的 Telegram 群组链接，或输入 "cancel" 返回。',
+  'trending.group_link_saved': '✅ 群组链接已保存：{link}',
+  'images.contract_instructions': `💰 <b>图片费用付款说明</b>
+
+🎨 收藏集：<b>{name}</b>
+📮 合约：<code>{address}</code>
+📅 时长：<b>{duration}</b>
+💸 费用：<b>{fee} {feeSymbol}</b>
+
+🏦 <b>付款地址（{shortAddress}）：</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>付款步骤：</b>
+{steps}
+
+付款完成后，点击下方按钮提交您的交易哈希。`,
+  'images.payment_validated': `✅ <b>图片费用付款已验证！</b>
+
+🎨 收藏集：<b>{name}</b>
+📮 合约：<code>{address}</code>
+💰 金额：{amount} {symbol}
+📅 时长：<b>{duration}</b>
+📝 交易：<code>{txHash}</code>
+👤 付款人：<code>{payer}</code>
+
+🖼️ <b>该合约的真实 NFT 图片将显示 {duration}！</b>`,
+  'trending.payment_validated': '✅ **付款验证成功！**\n\n🎯 **{name}** 热门推广已激活！\n⏱️ 时长：{hours} 小时\n💰 金额：{amount} {symbol}\n🔗 交易：`{txHash}`\n\n您的 NFT 现已进入热门！🚀',
+  'verify.payment_name.footer': '页脚广告',
+  'verify.tx_hash_required': `📝 <b>需要交易哈希</b>
+
+现在请发送您的{payment}付款的交易哈希。
+
+<i>示例：0xabc123456789def...</i>`,
+  'verify.back_to_payments': '◀️ 返回付款验证',
+  'verify.invalid_ticker': `❌ 代码格式无效。请输入有效的代码。
+
+<i>示例：$PEPE、$BAYC、$AZUKI</i>
+
+💡 请使用创建页脚广告时所用的代码。`,
+  'verify.submit_ticker_tx': `📝 <b>提交交易哈希</b>
+
+🔗 <b>页脚代码：</b>{ticker}
+
+请发送您页脚付款的交易哈希。
+
+<i>示例：0xabc123456789def...</i>`,
+
+  // Chain lists and footer ad flow
+  'common.register_first': '❌ 请先使用 /start 注册',
+  'tokens.none_on_chain_add': `🔍 <b>在 {chain} 上未找到 NFT</b>
+
+添加一些 NFT 以开始在此区块链上追踪！`,
+  'tokens.title_chain_count': '🎯 <b>您在 {chain} 上的代币</b>（{count}）',
+  'tokens_menu.add_on_chain': '➕ 在此链上添加 NFT',
+  'tokens_menu.view_all_chains': '🌐 查看所有链',
+  'tokens_menu.all_chains': '🌐 所有链',
+  'images.duration_menu_title': `🎨 <b>图片费用 - 选择时长</b>
+
+选择显示 NFT 图片（而非 Candy Codex 图片）的时长：`,
+  'duration.pricing_hint': '💡 选择链后将显示价格。',
+  'footer.duration_title': `📢 <b>页脚广告 - 选择时长</b>
+
+{emoji} <b>链：</b>{chain}
+
+选择您的页脚广告展示时长：`,
+  'footer.duration_hint': '💡 您的广告将显示在所有代币通知的底部！',
+  'images.chain_title': `🎨 <b>图片费用付款 - 选择链</b>
+
+📅 时长：<b>{duration}</b>
+
+🔗 选择区块链网络：
+💡 价格因链而异，将在付款页面显示。`,
+  'footer.chain_title': `📢 <b>页脚广告付款 - 选择链</b>
+
+🔗 选择用于支付页脚广告的区块链网络：
+
+💡 时长和价格将在下一步显示。`,
+  'payment.chain_error': '❌ 显示链选择时出错。请重试。',
+  'images.contract_title': '🎨 <b>图片费用付款 - 输入合约</b>',
+  'footer.contract_title': '📢 <b>页脚广告付款 - 输入合约</b>',
+  'payment.contract_prompt': `{title}
+
+📅 时长：<b>{duration}</b>
+💰 金额：<b>{amount}</b>
+🔗 链：<b>{chain}</b>
+
+📝 请输入 NFT 地址：
+
+<i>示例：0x1234567890abcdef...</i>`,
+  'payment.back_to_chain': '◀️ 返回链选择',
+  'payment.back_to_contract': '◀️ 返回合约输入',
+  'payment.contract_input_error': '❌ 显示合约输入时出错。请重试。',
+  'footer.link_title': '🔗 <b>页脚广告 - 自定义链接</b>',
+  'payment.chain_line': '🔗 链：<b>{chain}</b>',
+  'footer.link_prompt': `请发送用户点击您的页脚广告时要访问的自定义链接。
+
+<i>示例：https://mytoken.com 或 https://twitter.com/myproject</i>`,
+  'footer.link_input_error': '❌ 显示链接输入时出错。请重试。',
+  'footer.ticker_prompt': `💰 <b>页脚广告 - 代码</b>
+
+请发送您希望在页脚中显示的代码。
+
+<i>示例：$CANDY、$PEPE、$MYTOKEN</i>
+
+⭐️ 您的代码将显示为：<b>⭐️$YOURTICKER</b>`,
+  'footer.back_to_link': '◀️ 返回链接输入',
+  'footer.back_to_ticker': '◀️ 返回代码输入',
+  'footer.invalid_link': `❌ URL 格式无效。请提供以 http:// 或 https:// 开头的有效 URL。
+
+<i>示例：https://mytoken.com</i>`,
+  'footer.invalid_ticker': `❌ 代码格式无效。只能使用字母和数字，最多 10 个字符。<b>不允许空格。</b>
+
+<i>示例：$CANDY、$PEPE、$MYTOKEN</i>`,
+  'footer.contract_instructions': `📢 <b>页脚广告付款说明</b>
+
+🎨 收藏集：<b>{name}</b>
+📮 合约：<code>{address}</code>
+🔗 自定义链接：{link}
+⭐️ 代码：<b>{ticker}</b>
+📅 时长：<b>{duration}</b>
+💸 费用：<b>{fee} {feeSymbol}</b>
+
+🏦 <b>付款地址（{shortAddress}）：</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>付款步骤：</b>
+{steps}
+
+付款完成后，点击下方按钮提交您的交易哈希。`,
+  'footer.payment_instructions': `📢 <b>页脚广告付款说明</b>
+
+🔗 自定义链接：{link}
+⭐️ 代码：<b>{ticker}</b>
+📅 时长：<b>{duration}</b>
+💸 费用：<b>{amount}</b>
+{emoji} 链：<b>{chain}</b>
+
+🏦 <b>付款地址（{shortAddress}）：</b>
+
+<code>{paymentAddress}</code>
+
+📋 <b>付款步骤：</b>
+1. 向上方付款地址<b>准确发送 {amount}</b>
+2. 使用任意 {chain} 钱包，在 {network} 网络上付款
+3. 点击上方地址即可复制
+4. 等待交易确认
+5. 在下方提交您的交易哈希
+
+付款完成后，点击下方按钮提交您的交易哈希。`
+};
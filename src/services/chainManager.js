@@ -1,6 +1,97 @@
 const logger = require('./logger');
 const addresses = require('../config/addresses');
 
+const ZERO_ADDRESS = '0x0000000000000000000000000000000000000000';
+
+/**
+ * Tokens NFTs are paid with, per chain. The entry without an address is the native currency.
+ * coinGeckoId is the price source; tokens that share it with the native currency (WETH, the
+ * Blur pool) are valued 1:1 in it.
+ */
+const PAYMENT_TOKENS = {
+  ethereum: [
+    { symbol: 'ETH', address: null, decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'WETH', address: '0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2', decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'BETH', address: '0x0000000000a39bb272e79075ade125fd351887ac', decimals: 18, coinGeckoId: 'ethereum' }, // Blur pool
+    { symbol: 'USDC', address: '0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48', decimals: 6, coinGeckoId: 'usd-coin' },
+    { symbol: 'USDT', address: '0xdac17f958d2ee523a2206206994597c13d831ec7', decimals: 6, coinGeckoId: 'tether' },
+    { symbol: 'DAI', address: '0x6b175474e89094c44da98b954eedeac495271d0f', decimals: 18, coinGeckoId: 'dai' },
+    { symbol: 'APE', address: '0x4d224452801aced8b2f0aebe155379bb5d594381', decimals: 18, coinGeckoId: 'apecoin' },
+    { symbol: 'BLUR', address: '0x5283d291dbcf85356a21ba090e6db59121208b44', decimals: 18, coinGeckoId: 'blur' }
+  ],
+  arbitrum: [
+    { symbol: 'ETH', address: null, decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'WETH', address: '0x82af49447d8a07e3bd95bd0d56f35241523fbab1', decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'USDC', address: '0xaf88d065e77c8cc2239327c5edb3a432268e5831', decimals: 6, coinGeckoId: 'usd-coin' },
+    { symbol: 'ARB', address: '0x912ce59144191c1204e64559fe8253a0e49e6548', decimals: 18, coinGeckoId: 'arbitrum' }
+  ],
+  optimism: [
+    { symbol: 'ETH', address: null, decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'WETH', address: '0x4200000000000000000000000000000000000006', decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'USDC', address: '0x0b2c639c533813f4aa9d7837caf62653d097ff85', decimals: 6, coinGeckoId: 'usd-coin' },
+    { symbol: 'OP', address: '0x4200000000000000000000000000000000000042', decimals: 18, coinGeckoId: 'optimism' }
+  ],
+  base: [
+    { symbol: 'ETH', address: null, decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'WETH', address: '0x4200000000000000000000000000000000000006', decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'USDC', address: '0x833589fcd6edb6e08f4c7c32d4f71b54bda02913', decimals: 6, coinGeckoId: 'usd-coin' }
+  ],
+  avalanche: [
+    { symbol: 'AVAX', address: null, decimals: 18, coinGeckoId: 'avalanche-2' },
+    { symbol: 'WAVAX', address: '0xb31f66aa3c1e785363f0875a1b74e27b85fd66c7', decimals: 18, coinGeckoId: 'avalanche-2' },
+    { symbol: 'USDC', address: '0xb97ef9ef8734c71904d8002f8b6bc66dd9c48a6e', decimals: 6, coinGeckoId: 'usd-coin' }
+  ],
+  hyperblast: [
+    { symbol: 'ETH', address: null, decimals: 18, coinGeckoId: 'ethereum' }
+  ],
+  berachain: [
+    { symbol: 'BERA', address: null, decimals: 18, coinGeckoId: 'berachain-bera' },
+    { symbol: 'WBERA', address: '0x6969696969696969696969696969696969696969', decimals: 18, coinGeckoId: 'berachain-bera' }
+  ],
+  apechain: [
+    { symbol: 'APE', address: null, decimals: 18, coinGeckoId: 'apecoin' },
+    { symbol: 'WAPE', address: '0x48b62137edfa95a428d35c09e44256a739f6b557', decimals: 18, coinGeckoId: 'apecoin' }
+  ],
+  abstract: [
+    { symbol: 'ETH', address: null, decimals: 18, coinGeckoId: 'ethereum' },
+    { symbol: 'WETH', address: '0x3439153eb7af838ad19d56e1571fbd09333c2809', decimals: 18, coinGeckoId: 'ethereum' }
+  ],
+  ronin: [
+    { symbol: 'RON', address: null, decimals: 18, coinGeckoId: 'ronin' },
+    { symbol: 'WRON', address: '0xe514d9deb7966c8be0ca922de8a064264ea6bcd4', decimals: 18, coinGeckoId: 'ronin' },
+    { symbol: 'WETH', address: '0xc99a6a985ed2cac1ef41640596c5a5f9f4e19ef5', decimals: 18, coinGeckoId: 'ethereum' }
+  ],
+  sei: [
+    { symbol: 'SEI', address: null, decimals: 18, coinGeckoId: 'sei-network' }
+  ],
+  solana: [
+    { symbol: 'SOL', address: null, decimals: 9, coinGeckoId: 'solana' }
+  ],
+  bitcoin: [
+    { symbol: 'BTC', address: null, decimals: 8, coinGeckoId: 'bitcoin' }
+  ]
+};
+
+/**
+ * Registry entry of a payment token in a chain's list, by contract address or, without
+ * one, by symbol (the zero address stands for the native currency)
+ * @param {Array<Object>} tokens - PAYMENT_TOKENS entries of one chain
+ * @param {{address?: string|null, currency?: string}} payment
+ * @returns {Object|null}
+ */
+function findPaymentToken(tokens, { address = null, currency = null } = {}) {
+  const normalizedAddress = (address || '').toLowerCase();
+  if (normalizedAddress && normalizedAddress !== ZERO_ADDRESS) {
+    return tokens.find(token => token.address === normalizedAddress) || null;
+  }
+  if (normalizedAddress === ZERO_ADDRESS) {
+    return tokens.find(token => !token.address) || null;
+  }
+
+  const symbol = String(currency || '').toUpperCase();
+  return tokens.find(token => token.symbol === symbol) || null;
+}
+
 class ChainManager {
   constructor(database) {
     this.db = database;
@@ -39,6 +130,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'ethereum',
           rpcUrl: `https://eth-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'ETH_MAINNET', // Address activity webhooks for watched wallets
           paymentContract: addresses.ethereum.paymentContract
         },
         {
@@ -52,6 +144,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'arbitrum',
           rpcUrl: `https://arb-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'ARB_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -65,6 +158,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'optimism',
           rpcUrl: `https://opt-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'OPT_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -78,6 +172,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'avalanche',
           rpcUrl: `https://avax-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'AVAX_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -103,6 +198,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'bera_chain',
           rpcUrl: `https://berachain-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'BERACHAIN_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -116,6 +212,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'apechain',
           rpcUrl: `https://apechain-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'APECHAIN_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -129,6 +226,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'abstract',
           rpcUrl: `https://abstract-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'ABSTRACT_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -142,6 +240,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'base',
           rpcUrl: `https://base-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'BASE_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -155,6 +254,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'ronin',
           rpcUrl: `https://ronin-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'RONIN_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -168,6 +268,7 @@ class ChainManager {
           openSeaSupported: true,
           openSeaName: 'sei',
           rpcUrl: `https://sei-mainnet.g.alchemy.com/v2/${alchemyApiKey}`,
+          alchemyNetwork: 'SEI_MAINNET',
           paymentContract: '0x405792CbED87Fbb34afA505F768C8eDF8f9504E9'
         },
         {
@@ -205,7 +306,7 @@ class ChainManager {
 
       this.chains.clear();
       for (const config of chainConfigs) {
-        this.chains.set(config.name, config);
+        this.chains.set(config.name, { ...config, paymentTokens: PAYMENT_TOKENS[config.name] || [] });
       }
 
       logger.info(`Loaded ${this.chains.size} active chain configurations`);
@@ -453,6 +554,26 @@ class ChainManager {
     return chain ? chain.currencySymbol : 'ETH';
   }
 
+  // Payment token registry helpers
+  getPaymentTokens(chainName) {
+    const chain = this.getChain(chainName);
+    return chain ? chain.paymentTokens : [];
+  }
+
+  getNativePaymentToken(chainName) {
+    return this.getPaymentTokens(chainName).find(token => !token.address) || null;
+  }
+
+  /**
+   * Registry entry of a payment token, by contract address or, without one, by symbol
+   * @param {string} chainName
+   * @param {{address?: string|null, currency?: string}} payment - Address and/or symbol the source reported
+   * @returns {Object|null} { symbol, address, decimals, coinGeckoId }
+   */
+  findPaymentToken(chainName, payment = {}) {
+    return findPaymentToken(this.getPaymentTokens(chainName), payment);
+  }
+
   // Chain selection helpers for different contexts
   getChainsForPayments() {
     // Return all active chains that have payment contracts
@@ -504,4 +625,6 @@ class ChainManager {
   }
 }
 
-module.exports = ChainManager;
\ No newline at end of file
+module.exports = ChainManager;
+module.exports.PAYMENT_TOKENS = PAYMENT_TOKENS;
+module.exports.findPaymentToken = findPaymentToken;
\ No newline at end of file
@@ -1,11 +1,14 @@
 const logger = require('./logger');
 const crypto = require('crypto');
 const CollectionStatsService = require('./collectionStatsService');
+const TelegramSender = require('./telegramSender');
+const { PRIORITY } = TelegramSender;
 
 class ChannelService {
-  constructor(database, bot, trendingService, secureTrendingService = null) {
+  constructor(database, bot, trendingService, secureTrendingService = null, telegramSender = null) {
     this.db = database;
     this.bot = bot;
+    this.sender = telegramSender || new TelegramSender(bot);
     this.trending = trendingService;
     this.secureTrending = secureTrendingService;
     this.scheduledMessages = new Map();
@@ -64,7 +67,7 @@ I'll now send trending NFT alerts to this channel.
 Use /channel_settings to configure alerts.`;
 
       try {
-        await this.bot.telegram.sendMessage(
+        await this.sender.sendMessage(
           telegramChatId, 
           welcomeMessage, 
           { parse_mode: 'HTML' }
@@ -98,7 +101,7 @@ Use /channel_settings to configure alerts.`;
       if (result.changes > 0) {
 
         try {
-          await this.bot.telegram.sendMessage(
+          await this.sender.sendMessage(
             telegramChatId,
             '👋 MintTechBot has been deactivated for this channel. Goodbye!',
             { parse_mode: 'Markdown' }
@@ -214,7 +217,7 @@ Use /channel_settings to configure alerts.`;
 
       for (const channel of channels) {
         try {
-          await this.bot.telegram.sendMessage(
+          await this.sender.sendMessage(
             channel.telegram_chat_id,
             message,
             { 
@@ -223,8 +226,6 @@ Use /channel_settings to configure alerts.`;
             }
           );
           sent++;
-
-          await new Promise(resolve => setTimeout(resolve, 100));
         } catch (error) {
           failed++;
           logger.error(`Failed to send broadcast to channel ${channel.telegram_chat_id}:`, error);
@@ -360,8 +361,6 @@ Use /channel_settings to configure alerts.`;
             } else {
               totalFailed++;
             }
-            // Small delay to avoid rate limiting
-            await new Promise(resolve => setTimeout(resolve, 100));
           }
 
           logger.info(`Normal trending: ${totalSent} created/edited, ${totalSkipped} skipped, ${totalFailed} failed`);
@@ -393,8 +392,6 @@ Use /channel_settings to configure alerts.`;
               } else {
                 totalFailed++;
               }
-              // Small delay to avoid rate limiting
-              await new Promise(resolve => setTimeout(resolve, 100));
             }
 
             logger.info(`Premium trending complete`);
@@ -420,7 +417,7 @@ Use /channel_settings to configure alerts.`;
 
     for (const channel of channels) {
       try {
-        await this.bot.telegram.sendMessage(
+        await this.sender.sendMessage(
           channel.telegram_chat_id,
           message,
           {
@@ -429,7 +426,6 @@ Use /channel_settings to configure alerts.`;
           }
         );
         sent++;
-        await new Promise(resolve => setTimeout(resolve, 100));
       } catch (error) {
         failed++;
         logger.error(`Failed to send broadcast to channel ${channel.telegram_chat_id}:`, error);
@@ -566,7 +562,7 @@ Use /channel_settings to configure alerts.`;
       // Case 2: Have pinned message ID, try to edit it
       if (messageId) {
         try {
-          await this.bot.telegram.editMessageText(
+          await this.sender.editMessageText(
             chatId,
             parseInt(messageId),
             null,
@@ -579,7 +575,8 @@ Use /channel_settings to configure alerts.`;
                   [{ text: 'Candy Codex Telegram', url: 'https://t.me/CandyCodex' }]
                 ]
               }
-            }
+            },
+            { priority: PRIORITY.BACKGROUND }
           );
 
           // Update hash in database
@@ -606,7 +603,7 @@ Use /channel_settings to configure alerts.`;
       }
 
       // Case 3: No pinned message or edit failed, create new one
-      const sentMessage = await this.bot.telegram.sendMessage(
+      const sentMessage = await this.sender.sendMessage(
         chatId,
         message,
         {
@@ -617,14 +614,15 @@ Use /channel_settings to configure alerts.`;
               [{ text: 'Candy Codex Telegram', url: 'https://t.me/CandyCodex' }]
             ]
           }
-        }
+        },
+        { priority: PRIORITY.BACKGROUND }
       );
 
       // Try to pin the message
       try {
-        await this.bot.telegram.pinChatMessage(chatId, sentMessage.message_id, {
+        await this.sender.pinChatMessage(chatId, sentMessage.message_id, {
           disable_notification: true
-        });
+        }, { priority: PRIORITY.BACKGROUND });
 
         logger.info(`[${channel.channel_title}] Created and pinned new message ${sentMessage.message_id}`);
 
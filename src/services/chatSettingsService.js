@@ -0,0 +1,176 @@
+const logger = require('./logger');
+const { isSupportedLanguage } = require('../i18n');
+
+const CACHE_TTL_MS = 60 * 1000;
+
+const SWEEP_MODES = ['aggregated', 'individual'];
+const MINUTES_PER_DAY = 24 * 60;
+const MAX_DIGEST_INTERVAL_MINUTES = 7 * MINUTES_PER_DAY;
+
+// Values used for chats that never changed a setting
+const DEFAULT_SETTINGS = {
+  sweep_mode: 'aggregated',
+  // Quiet hours are minutes of the day in the chat's timezone, null when disabled
+  quiet_hours_start: null,
+  quiet_hours_end: null,
+  timezone: 'UTC',
+  digest_interval_minutes: null,
+  // Null until chosen: private chats then follow the user's Telegram language, others use English
+  language: null
+};
+
+function isMinuteOfDay(value) {
+  return value === null || (Number.isInteger(value) && value >= 0 && value < MINUTES_PER_DAY);
+}
+
+/**
+ * @param {string} timezone - IANA name, e.g. "Europe/Berlin"
+ * @returns {boolean} True if the runtime knows the timezone
+ */
+function isValidTimezone(timezone) {
+  if (typeof timezone !== 'string' || timezone === '') return false;
+  try {
+    new Intl.DateTimeFormat('en-US', { timeZone: timezone });
+    return true;
+  } catch (error) {
+    return false;
+  }
+}
+
+// Validators for the columns that may be updated
+const SETTING_VALIDATORS = {
+  sweep_mode: (value) => SWEEP_MODES.includes(value),
+  quiet_hours_start: isMinuteOfDay,
+  quiet_hours_end: isMinuteOfDay,
+  timezone: isValidTimezone,
+  digest_interval_minutes: (value) => value === null || (Number.isInteger(value) && value > 0 && value <= MAX_DIGEST_INTERVAL_MINUTES),
+  language: (value) => value === null || isSupportedLanguage(value)
+};
+
+/**
+ * Alert preferences of a chat (private chat, group or channel), stored in chat_settings.
+ *
+ * Chats without a row use DEFAULT_SETTINGS. Reads are cached briefly, since every alert
+ * looks up the settings of each chat it goes to.
+ */
+class ChatSettingsService {
+  constructor(database, options = {}) {
+    this.db = database;
+    this.cacheTtlMs = options.cacheTtlMs ?? CACHE_TTL_MS;
+    this.cache = new Map();
+  }
+
+  /**
+   * @param {string|number} chatId - Telegram chat ID
+   * @returns {Promise<Object>} Settings, defaults filled in
+   */
+  async get(chatId) {
+    const key = String(chatId);
+    const cached = this.cache.get(key);
+    if (cached && cached.expiresAt > Date.now()) {
+      return cached.settings;
+    }
+
+    let row = null;
+    try {
+      row = await this.db.get('SELECT * FROM chat_settings WHERE chat_id = $1', [key]);
+    } catch (error) {
+      // Alerts keep flowing with the defaults if the table can't be read
+      logger.error(`Error loading settings for chat ${key}:`, error.message);
+      return { chat_id: key, ...DEFAULT_SETTINGS };
+    }
+
+    const settings = { chat_id: key, ...DEFAULT_SETTINGS };
+    if (row) {
+      for (const column of Object.keys(DEFAULT_SETTINGS)) {
+        if (row[column] != null) settings[column] = row[column];
+      }
+    }
+
+    this.cache.set(key, { settings, expiresAt: Date.now() + this.cacheTtlMs });
+    return settings;
+  }
+
+  /**
+   * Change one or more settings of a chat
+   * @param {string|number} chatId - Telegram chat ID
+   * @param {Object} changes - Column/value pairs, e.g. { sweep_mode: 'individual' }
+   * @returns {Promise<Object>} Updated settings
+   */
+  async update(chatId, changes) {
+    const key = String(chatId);
+    const columns = Object.keys(changes);
+    if (columns.length === 0) {
+      return this.get(key);
+    }
+
+    for (const column of columns) {
+      const validate = SETTING_VALIDATORS[column];
+      if (!validate) {
+        throw new Error(`Unknown chat setting: ${column}`);
+      }
+      if (!validate(changes[column])) {
+        throw new Error(`Invalid value for ${column}: ${changes[column]}`);
+      }
+    }
+
+    const values = columns.map(column => changes[column]);
+    const placeholders = columns.map((column, index) => `$${index + 2}`);
+    const assignments = columns.map(column => `${column} = EXCLUDED.${column}`);
+
+    await this.db.query(
+      `INSERT INTO chat_settings (chat_id, ${columns.join(', ')}, updated_at)
+       VALUES ($1, ${placeholders.join(', ')}, NOW())
+       ON CONFLICT (chat_id) DO UPDATE SET
+         ${assignments.join(',\n         ')},
+         updated_at = NOW()`,
+      [key, ...values]
+    );
+
+    this.cache.delete(key);
+    logger.info(`Chat ${key} settings updated: ${columns.map(column => `${column}=${changes[column]}`).join(', ')}`);
+    return this.get(key);
+  }
+
+  async getSweepMode(chatId) {
+    return (await this.get(chatId)).sweep_mode;
+  }
+
+  async setSweepMode(chatId, mode) {
+    return this.update(chatId, { sweep_mode: mode });
+  }
+
+  /**
+   * Set or clear (start = null) the quiet hours of a chat
+   * @param {number|null} start - Minute of the day quiet hours begin
+   * @param {number|null} end - Minute of the day they end
+   * @param {string} [timezone] - IANA timezone, unchanged when omitted
+   */
+  async setQuietHours(chatId, start, end, timezone = null) {
+    const changes = start === null
+      ? { quiet_hours_start: null, quiet_hours_end: null }
+      : { quiet_hours_start: start, quiet_hours_end: end };
+    if (timezone) changes.timezone = timezone;
+    return this.update(chatId, changes);
+  }
+
+  /**
+   * @param {number|null} minutes - Digest interval, null for live alerts
+   */
+  async setDigestInterval(chatId, minutes) {
+    return this.update(chatId, { digest_interval_minutes: minutes });
+  }
+
+  /**
+   * @param {string|null} language - Supported language code, null to go back to the default
+   */
+  async setLanguage(chatId, language) {
+    return this.update(chatId, { language });
+  }
+}
+
+module.exports = ChatSettingsService;
+module.exports.SWEEP_MODES = SWEEP_MODES;
+module.exports.DEFAULT_SETTINGS = DEFAULT_SETTINGS;
+module.exports.MAX_DIGEST_INTERVAL_MINUTES = MAX_DIGEST_INTERVAL_MINUTES;
+module.exports.isValidTimezone = isValidTimezone;
@@ -4,10 +4,14 @@ const logger = require('./logger');
 /**
  * Service for fetching and caching OpenSea collection statistics
  * Handles API rate limiting, caching, and periodic updates
+ * Solana and Ordinals collections are read from Magic Eden when its services are passed in
  */
 class CollectionStatsService {
-  constructor(database) {
+  constructor(database, options = {}) {
     this.db = database;
+    this.magicEden = options.magicEden || null;
+    this.magicEdenOrdinals = options.magicEdenOrdinals || null;
+    this.chainManager = options.chainManager || null;
     this.apiKey = process.env.OPENSEA_API_KEY;
     this.baseUrl = 'https://api.opensea.io/api/v2';
 
@@ -15,6 +19,11 @@ class CollectionStatsService {
     this.cache = new Map();
     this.CACHE_TTL = 10 * 1000; // 10 seconds
 
+    // Floors for snipe mode: Map<chain:slug, {floor, timestamp}>. Kept a little longer so a
+    // new lowest listing is compared with the floor from before it was listed
+    this.floorCache = new Map();
+    this.FLOOR_CACHE_TTL = 60 * 1000; // 1 minute
+
     // Rate limiting: OpenSea allows ~4 requests/second
     this.requestQueue = [];
     this.isProcessingQueue = false;
@@ -169,6 +178,84 @@ class CollectionStatsService {
     });
   }
 
+  /**
+   * Current floor and volume of a tracked collection, in the collection's own currency.
+   * Magic Eden only reports lifetime volume, so volume_24h is left to the caller for
+   * Solana and Ordinals collections.
+   * @param {Object} token - Tracked token row
+   * @returns {Promise<Object|null>} { source, floor_price, volume_24h, volume_total, sales_24h, listed_count, currency }
+   */
+  async getCollectionSnapshot(token) {
+    const toNumber = (value) => {
+      const number = parseFloat(value);
+      return Number.isFinite(number) ? number : null;
+    };
+
+    if (token.chain_name === 'solana') {
+      if (!this.magicEden || !token.collection_slug) return null;
+      const stats = await this.magicEden.getCollectionStats(token.collection_slug);
+      return stats && {
+        source: 'magiceden',
+        floor_price: toNumber(stats.floorPrice),
+        volume_24h: null,
+        volume_total: toNumber(stats.volumeAll),
+        sales_24h: null,
+        listed_count: stats.listedCount,
+        currency: 'SOL'
+      };
+    }
+
+    if (token.chain_name === 'bitcoin') {
+      const symbol = token.collection_slug || token.contract_address;
+      if (!this.magicEdenOrdinals || !symbol) return null;
+      const stats = await this.magicEdenOrdinals.getCollectionStats(symbol);
+      return stats && {
+        source: 'magiceden_ordinals',
+        floor_price: toNumber(stats.floorPrice),
+        volume_24h: null,
+        volume_total: toNumber(stats.totalVolume),
+        sales_24h: null,
+        listed_count: stats.totalListed,
+        currency: 'BTC'
+      };
+    }
+
+    const stats = await this.getStats(token.collection_slug);
+    return stats && {
+      source: 'opensea',
+      floor_price: toNumber(stats.floor_price),
+      volume_24h: toNumber(stats.volume_24h),
+      volume_total: null,
+      sales_24h: stats.sales_24h ?? null,
+      listed_count: null,
+      currency: stats.floor_price_symbol || this.chainManager?.getCurrencySymbol(token.chain_name) || 'ETH'
+    };
+  }
+
+  /**
+   * Cached floor of a tracked collection (below-floor snipe mode)
+   * @param {Object} token - Tracked token row
+   * @returns {Promise<{price: number, currency: string}|null>} Null if the marketplace has no floor
+   */
+  async getFloorPrice(token) {
+    const key = `${token.chain_name || 'ethereum'}:${token.collection_slug || token.contract_address}`;
+    const cached = this.floorCache.get(key);
+    if (cached && (Date.now() - cached.timestamp < this.FLOOR_CACHE_TTL)) {
+      return cached.floor;
+    }
+
+    let floor = null;
+    try {
+      const snapshot = await this.getCollectionSnapshot(token);
+      floor = snapshot?.floor_price > 0 ? { price: snapshot.floor_price, currency: snapshot.currency } : null;
+    } catch (error) {
+      logger.warn(`[CollectionStats] Could not read the floor of ${key}: ${error.message}`);
+    }
+
+    this.floorCache.set(key, { floor, timestamp: Date.now() });
+    return floor;
+  }
+
   /**
    * Process request queue with rate limiting
    */
@@ -339,6 +426,7 @@ class CollectionStatsService {
    */
   clearCache() {
     this.cache.clear();
+    this.floorCache.clear();
     logger.info('[CollectionStats] Cache cleared');
   }
 }
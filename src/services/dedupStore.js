@@ -0,0 +1,164 @@
+const logger = require('./logger');
+
+const DEFAULT_TTL_HOURS = 24;
+const CLEANUP_INTERVAL_MS = 5 * 60 * 1000;
+
+/**
+ * Dedup keys in the processed_events table, shared by every instance using the database
+ */
+class DatabaseDedupBackend {
+  constructor(database) {
+    this.name = 'database';
+    this.db = database;
+  }
+
+  async claim(key, source, expiresAt) {
+    // Insert the key, or take over an expired row. No row comes back when another
+    // delivery (on this or another instance) already holds a live claim.
+    const result = await this.db.query(
+      `INSERT INTO processed_events (event_key, source, expires_at)
+       VALUES ($1, $2, $3)
+       ON CONFLICT (event_key) DO UPDATE SET
+         source = EXCLUDED.source,
+         expires_at = EXCLUDED.expires_at,
+         created_at = NOW()
+       WHERE processed_events.expires_at <= NOW()
+       RETURNING event_key`,
+      [key, source, expiresAt]
+    );
+    return result.rows.length > 0;
+  }
+
+  async has(key) {
+    const row = await this.db.get(
+      'SELECT event_key FROM processed_events WHERE event_key = $1 AND expires_at > $2',
+      [key, new Date()]
+    );
+    return !!row;
+  }
+
+  async cleanup() {
+    const result = await this.db.query('DELETE FROM processed_events WHERE expires_at <= $1', [new Date()]);
+    return result.rowCount;
+  }
+}
+
+/**
+ * Process-local dedup keys (single instance, lost on restart)
+ */
+class MemoryDedupBackend {
+  constructor() {
+    this.name = 'memory';
+    this.entries = new Map();
+  }
+
+  async claim(key, source, expiresAt) {
+    if (await this.has(key)) return false;
+    this.entries.set(key, expiresAt.getTime());
+    return true;
+  }
+
+  async has(key) {
+    const expiresAt = this.entries.get(key);
+    return expiresAt !== undefined && expiresAt > Date.now();
+  }
+
+  async cleanup() {
+    const now = Date.now();
+    let removedCount = 0;
+    for (const [key, expiresAt] of this.entries) {
+      if (expiresAt <= now) {
+        this.entries.delete(key);
+        removedCount++;
+      }
+    }
+    return removedCount;
+  }
+}
+
+/**
+ * Keyed store with expiry used to suppress duplicate sale/transfer alerts when
+ * the same event is delivered twice, after a restart, or to several replicas.
+ *
+ * Backend is chosen with DEDUP_BACKEND (`database` by default, or `memory`).
+ * If the database is unreachable, claims fall back to a local memory backend so
+ * alerts keep flowing (duplicates are then only suppressed per instance).
+ */
+class DedupStore {
+  constructor(backend, options = {}) {
+    this.backend = backend;
+    this.fallback = backend instanceof MemoryDedupBackend ? backend : new MemoryDedupBackend();
+    const ttlHours = options.ttlHours ?? (parseFloat(process.env.DEDUP_TTL_HOURS) || DEFAULT_TTL_HOURS);
+    this.ttlMs = ttlHours * 60 * 60 * 1000;
+    this.cleanupInterval = setInterval(() => this.cleanup(), CLEANUP_INTERVAL_MS);
+  }
+
+  /**
+   * Atomically record an event key
+   * @param {string} source - Event source (alchemy, opensea, helius, hiro, or chain for cross-source keys)
+   * @param {string} key - Unique event key
+   * @param {number} ttlMs - How long the key suppresses repeats
+   * @returns {Promise<boolean>} True if this caller claimed the event, false if it's a duplicate
+   */
+  async claim(source, key, ttlMs = this.ttlMs) {
+    if (!key) return true;
+    const expiresAt = new Date(Date.now() + ttlMs);
+
+    try {
+      return await this.backend.claim(key, source, expiresAt);
+    } catch (error) {
+      logger.error(`Dedup store (${this.backend.name}) unavailable, using local fallback:`, error.message);
+      return this.fallback.claim(key, source, expiresAt);
+    }
+  }
+
+  async isProcessed(key) {
+    if (!key) return false;
+    try {
+      return await this.backend.has(key);
+    } catch (error) {
+      logger.error(`Dedup store (${this.backend.name}) unavailable, using local fallback:`, error.message);
+      return this.fallback.has(key);
+    }
+  }
+
+  async cleanup() {
+    try {
+      const removedCount = await this.backend.cleanup();
+      if (this.fallback !== this.backend) {
+        await this.fallback.cleanup();
+      }
+      if (removedCount > 0) {
+        logger.debug(`Cleaned up ${removedCount} expired dedup entries`);
+      }
+    } catch (error) {
+      logger.error('Error cleaning up dedup entries:', error.message);
+    }
+  }
+
+  close() {
+    if (this.cleanupInterval) {
+      clearInterval(this.cleanupInterval);
+      this.cleanupInterval = null;
+    }
+  }
+}
+
+/**
+ * Create a dedup store for the configured backend
+ * @param {Object} database - Database instance (used by the database backend)
+ * @param {Object} env - Environment (defaults to process.env)
+ */
+function createDedupStore(database, env = process.env) {
+  const backendName = (env.DEDUP_BACKEND || 'database').toLowerCase();
+
+  if (backendName === 'memory') {
+    return new DedupStore(new MemoryDedupBackend());
+  }
+  if (backendName !== 'database') {
+    throw new Error(`Unsupported DEDUP_BACKEND "${env.DEDUP_BACKEND}" (expected database or memory)`);
+  }
+  return new DedupStore(new DatabaseDedupBackend(database));
+}
+
+module.exports = { DedupStore, DatabaseDedupBackend, MemoryDedupBackend, createDedupStore };
@@ -0,0 +1,268 @@
+const logger = require('./logger');
+
+const DEFAULT_POLL_INTERVAL_MS = 60 * 1000;
+
+class DigestClaimLostError extends Error {}
+
+/**
+ * Minute of the day (0-1439) at `date` in a timezone
+ */
+function getLocalMinutes(date, timezone) {
+  const parts = new Intl.DateTimeFormat('en-GB', {
+    timeZone: timezone || 'UTC',
+    hour: '2-digit',
+    minute: '2-digit',
+    hourCycle: 'h23'
+  }).formatToParts(date);
+  const hour = parseInt(parts.find(part => part.type === 'hour').value, 10);
+  const minute = parseInt(parts.find(part => part.type === 'minute').value, 10);
+  return hour * 60 + minute;
+}
+
+/**
+ * Whether a chat is inside its quiet hours. A window whose end is before its start
+ * runs past midnight (22:00-07:00).
+ * @param {Object} settings - Chat settings (quiet_hours_start, quiet_hours_end, timezone)
+ * @param {Date} now
+ * @returns {boolean}
+ */
+function isQuietTime(settings, now = new Date()) {
+  const start = settings.quiet_hours_start;
+  const end = settings.quiet_hours_end;
+  if (start == null || end == null || start === end) return false;
+
+  let minutes;
+  try {
+    minutes = getLocalMinutes(now, settings.timezone);
+  } catch (error) {
+    minutes = getLocalMinutes(now, 'UTC');
+  }
+  return start < end
+    ? minutes >= start && minutes < end
+    : minutes >= start || minutes < end;
+}
+
+/**
+ * Parse a time of day typed by a user: "22", "22:00", "7:30"
+ * @returns {number|null} Minute of the day, null when invalid
+ */
+function parseTimeOfDay(input) {
+  const match = String(input || '').trim().match(/^(\d{1,2})(?::(\d{2}))?$/);
+  if (!match) return null;
+  const hour = parseInt(match[1], 10);
+  const minute = match[2] ? parseInt(match[2], 10) : 0;
+  if (hour > 23 || minute > 59) return null;
+  return hour * 60 + minute;
+}
+
+/**
+ * Format a minute of the day as HH:MM
+ */
+function formatMinutes(minutes) {
+  return `${String(Math.floor(minutes / 60)).padStart(2, '0')}:${String(minutes % 60).padStart(2, '0')}`;
+}
+
+/**
+ * Holds the alerts of chats in quiet hours or digest mode and posts them as a summary.
+ *
+ * Held events are written to digest_events, so they survive restarts. Every poll looks
+ * at the chats with held events: events held during quiet hours are released as soon as
+ * the quiet hours end, digest events once the oldest one is `digest_interval_minutes`
+ * old. `onFlush(chatId, recipient, rows, context)` renders the summary and returns the
+ * outbox message (null when there is nothing left to post). The rows are deleted and the
+ * message queued in one transaction: a digest that fails to render stays held for the next
+ * poll, and when two instances flush the same chat only the one that deletes the rows posts.
+ */
+class DigestService {
+  constructor(database, { chatSettings, outbox, onFlush, getFloorPrice = null, pollIntervalMs } = {}) {
+    this.db = database;
+    this.chatSettings = chatSettings;
+    this.outbox = outbox;
+    this.onFlush = onFlush;
+    // Optional (token) => {price, currency}; the floor at the first held event is compared with the one at flush
+    this.getFloorPrice = getFloorPrice;
+    this.pollIntervalMs = pollIntervalMs ?? (parseInt(process.env.DIGEST_POLL_INTERVAL_MS, 10) || DEFAULT_POLL_INTERVAL_MS);
+    this.timer = null;
+    this.busy = null;
+    this.stats = { held: 0, digests: 0 };
+  }
+
+  /**
+   * Why alerts to a chat are held right now
+   * @param {string|number} chatId
+   * @returns {Promise<'quiet'|'digest'|null>} Null when alerts go out live
+   */
+  async getHoldReason(chatId, now = new Date()) {
+    const settings = await this.chatSettings.get(chatId);
+    if (isQuietTime(settings, now)) return 'quiet';
+    if (settings.digest_interval_minutes) return 'digest';
+    return null;
+  }
+
+  /**
+   * Hold one event for a chat's next digest
+   * @param {Object} target - Alert target ({chatId, recipient})
+   * @param {Object} token - Tracked token row
+   * @param {ActivityEvent} event
+   * @param {'quiet'|'digest'} reason
+   * @param {number|null} usdValue - Event price in USD, if known
+   */
+  async hold(target, token, event, reason, usdValue = null) {
+    const chatId = String(target.chatId);
+
+    let floor = null;
+    if (this.getFloorPrice) {
+      const existing = await this.db.get(
+        'SELECT id FROM digest_events WHERE chat_id = $1 AND token_id = $2 LIMIT 1',
+        [chatId, token.id]
+      );
+      if (!existing) {
+        floor = await this.getFloorPrice(token).catch(error => {
+          logger.debug(`No floor snapshot for ${token.token_name}: ${error.message}`);
+          return null;
+        });
+      }
+    }
+
+    await this.db.query(
+      `INSERT INTO digest_events (chat_id, token_id, recipient_type, recipient_id, reason, event_type, payload, usd_value, floor_price, floor_currency)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)`,
+      [
+        chatId,
+        token.id,
+        target.recipient?.type || null,
+        target.recipient?.id != null ? String(target.recipient.id) : null,
+        reason,
+        event.type,
+        JSON.stringify(event),
+        usdValue,
+        floor?.price ?? null,
+        floor?.currency ?? null
+      ]
+    );
+    this.stats.held++;
+    logger.debug(`🌙 ${event.type} ${event.tokenId} held for ${reason} digest of ${chatId}`);
+  }
+
+  start() {
+    if (this.timer) return;
+    this.timer = setInterval(() => this.tick(), this.pollIntervalMs);
+    logger.info(`🌙 Digest worker started (checking every ${this.pollIntervalMs / 1000}s)`);
+  }
+
+  /**
+   * Stop polling. Held events stay in the table for the next start.
+   */
+  async stop() {
+    clearInterval(this.timer);
+    this.timer = null;
+    if (this.busy) {
+      await this.busy;
+    }
+  }
+
+  async tick() {
+    if (this.busy) return;
+    this.busy = this.processDue()
+      .catch(error => logger.error('Error processing digests:', error))
+      .finally(() => { this.busy = null; });
+    await this.busy;
+  }
+
+  /**
+   * Post the digests of every chat whose hold is over
+   * @returns {Promise<number>} Number of digests posted
+   */
+  async processDue(now = new Date()) {
+    const chats = await this.db.all(`
+      SELECT chat_id, MIN(created_at) AS first_at,
+             SUM(CASE WHEN reason = 'quiet' THEN 1 ELSE 0 END) AS quiet_count
+      FROM digest_events
+      GROUP BY chat_id
+    `);
+
+    let posted = 0;
+    for (const chat of chats || []) {
+      const settings = await this.chatSettings.get(chat.chat_id);
+      if (isQuietTime(settings, now)) continue;
+
+      const heldFor = now.getTime() - new Date(chat.first_at).getTime();
+      const intervalMs = settings.digest_interval_minutes ? settings.digest_interval_minutes * 60 * 1000 : 0;
+      const quietEnded = parseInt(chat.quiet_count, 10) > 0;
+      if (!quietEnded && heldFor < intervalMs) continue;
+
+      const reason = quietEnded ? 'quiet' : 'digest';
+      if (await this.flushChat(chat.chat_id, reason)) posted++;
+    }
+    return posted;
+  }
+
+  /**
+   * Post a chat's held events now
+   * @param {string} chatId
+   * @param {'quiet'|'digest'} reason - What held them, for the summary header
+   * @returns {Promise<boolean>} True if a digest was posted
+   */
+  async flushChat(chatId, reason = 'digest') {
+    const rows = await this.db.all(
+      'SELECT * FROM digest_events WHERE chat_id = $1 ORDER BY id',
+      [String(chatId)]
+    );
+    if (!rows || rows.length === 0) return false;
+
+    const recipientRow = rows.find(row => row.recipient_type) || rows[0];
+    const recipient = recipientRow.recipient_type
+      ? { type: recipientRow.recipient_type, id: recipientRow.recipient_id }
+      : null;
+
+    let message;
+    try {
+      message = await this.onFlush(String(chatId), recipient, rows, { reason, since: new Date(rows[0].created_at) });
+    } catch (error) {
+      logger.error(`Error rendering the digest of ${chatId} (${rows.length} event(s) kept for the next try):`, error);
+      return false;
+    }
+
+    const ids = rows.map(row => row.id);
+    const placeholders = ids.map((id, index) => `$${index + 1}`).join(', ');
+    try {
+      await this.db.transaction(async (tx) => {
+        const result = await tx.query(`DELETE FROM digest_events WHERE id IN (${placeholders})`, ids);
+        if (result.rowCount !== ids.length) {
+          // Another instance flushed some of these rows first; undo the delete and leave them to it
+          throw new DigestClaimLostError();
+        }
+        if (message) {
+          await this.outbox.enqueue(message, tx);
+        }
+      });
+      if (!message) return false;
+    } catch (error) {
+      if (error instanceof DigestClaimLostError) {
+        logger.debug(`Digest of ${chatId} already flushed by another instance`);
+      } else {
+        logger.error(`Error queueing the digest of ${chatId} (${rows.length} event(s) kept for the next try):`, error);
+      }
+      return false;
+    }
+
+    this.stats.digests++;
+    logger.info(`🌙 Posted ${reason} digest of ${rows.length} event(s) to ${chatId}`);
+    return true;
+  }
+
+  async getStats() {
+    const row = await this.db.get('SELECT COUNT(*) AS pending, COUNT(DISTINCT chat_id) AS chats FROM digest_events');
+    return {
+      pending: parseInt(row?.pending, 10) || 0,
+      chats: parseInt(row?.chats, 10) || 0,
+      pollIntervalMs: this.pollIntervalMs,
+      ...this.stats
+    };
+  }
+}
+
+module.exports = DigestService;
+module.exports.isQuietTime = isQuietTime;
+module.exports.parseTimeOfDay = parseTimeOfDay;
+module.exports.formatMinutes = formatMinutes;
@@ -0,0 +1,594 @@
+const fs = require('fs');
+const path = require('path');
+const { ethers } = require('ethers');
+const logger = require('./logger');
+
+const DEFAULT_REGISTRY_FILE = path.join(__dirname, '..', 'config', 'marketplaces.json');
+// Address list key for contracts deployed at the same address on every EVM chain
+const ALL_CHAINS = '*';
+const ZERO_ADDRESS = '0x0000000000000000000000000000000000000000';
+// Blur bids are paid from the Blur pool (BETH)
+const BLUR_POOL_ADDRESS = '0x0000000000a39bb272e79075ade125fd351887ac';
+// Fee shares: basis points (Blur), millionths (X2Y2)
+const BPS = 10000n;
+const X2Y2_FEE_BASE = 1000000n;
+const X2Y2_INTENT_BUY = 3n;
+
+const TRANSFER_TOPIC = ethers.id('Transfer(address,address,uint256)');
+const TRANSFER_SINGLE_TOPIC = ethers.id('TransferSingle(address,address,address,uint256,uint256)');
+const abiCoder = ethers.AbiCoder.defaultAbiCoder();
+
+const MASK_88 = (1n << 88n) - 1n;
+const MASK_160 = (1n << 160n) - 1n;
+
+const BLUR_ORDER = 'tuple(address trader, uint8 side, address matchingPolicy, address collection, uint256 tokenId, ' +
+  'uint256 amount, address paymentToken, uint256 price, uint256 listingTime, uint256 expirationTime, ' +
+  'tuple(uint16 rate, address recipient)[] fees, uint256 salt, bytes extraParams)';
+const LOOKSRARE_V1_TAKER = '(bytes32 orderHash, uint256 orderNonce, address indexed taker, address indexed maker, ' +
+  'address indexed strategy, address currency, address collection, uint256 tokenId, uint256 amount, uint256 price)';
+const LOOKSRARE_V2_NONCE = 'tuple(bytes32 orderHash, uint256 orderNonce, bool isNonceInvalidated) nonceInvalidationParameters';
+const LOOKSRARE_V2_ORDER = 'uint256 strategyId, address currency, address collection, uint256[] itemIds, uint256[] amounts, ' +
+  'address[2] feeRecipients, uint256[3] feeAmounts';
+
+function lower(value) {
+  return value ? String(value).toLowerCase() : null;
+}
+
+function isZeroAddress(address) {
+  return !address || lower(address) === ZERO_ADDRESS;
+}
+
+function toAddress(value) {
+  return `0x${(value & MASK_160).toString(16).padStart(40, '0')}`;
+}
+
+function topicAddress(topic) {
+  return `0x${topic.slice(-40).toLowerCase()}`;
+}
+
+function sum(values) {
+  return values.reduce((total, value) => total + BigInt(value), 0n);
+}
+
+/**
+ * One sale per item of an order; the order's price and royalty are split evenly between them
+ * @param {Array<{collection: string|null, tokenId: *, seller?: string, buyer?: string}>} items
+ * @param {Object} order - { seller, buyer, currency, total, royalty, paidTo }
+ * @returns {Array<Object>} Decoded sales
+ */
+function splitSale(items, { seller = null, buyer = null, currency = null, total = null, royalty = null, paidTo = [] }) {
+  const count = BigInt(Math.max(items.length, 1));
+  return items.map(item => ({
+    collection: lower(item.collection),
+    tokenId: item.tokenId != null ? BigInt(item.tokenId).toString() : null,
+    seller: isZeroAddress(item.seller || seller) ? null : lower(item.seller || seller),
+    buyer: isZeroAddress(item.buyer || buyer) ? null : lower(item.buyer || buyer),
+    price: total != null && total > 0n ? { raw: total / count, address: isZeroAddress(currency) ? null : lower(currency) } : null,
+    royalty: royalty != null && total != null ? royalty / count : null,
+    paidTo
+  }));
+}
+
+// ==================== PROTOCOL DECODERS ====================
+
+function toSeaportItem(item, recipient) {
+  return {
+    itemType: Number(item.itemType),
+    token: lower(item.token),
+    identifier: item.identifier,
+    amount: BigInt(item.amount),
+    recipient: lower(recipient)
+  };
+}
+
+// Item types 2-5 are ERC-721/1155 (criteria items are resolved before the event)
+function isSeaportNft(item) {
+  return item.itemType >= 2;
+}
+
+/**
+ * Seaport OrderFulfilled. A listing offers the item and asks for payments (seller, marketplace
+ * fee, royalty); an accepted offer offers the payment and asks for the item plus the fees, which
+ * come out of the seller's proceeds. Royalty is every payment to someone who is neither the
+ * seller nor a registered fee recipient.
+ */
+function decodeSeaport({ args }, { feeRecipients }) {
+  const offerer = lower(args.offerer);
+  const fulfiller = lower(args.recipient);
+  const offer = args.offer.map(item => toSeaportItem(item, fulfiller));
+  const consideration = args.consideration.map(item => toSeaportItem(item, item.recipient));
+
+  const listed = offer.filter(isSeaportNft);
+  const isListing = listed.length > 0;
+  const nfts = isListing ? listed : consideration.filter(isSeaportNft);
+  const payments = (isListing ? consideration : offer).filter(item => !isSeaportNft(item));
+  if (nfts.length === 0 || payments.length === 0) return [];
+
+  const seller = isListing ? offerer : fulfiller;
+  const currency = payments[0].token;
+  const fees = consideration.filter(item => !isSeaportNft(item) && item.token === currency && item.recipient !== seller);
+  const royalties = fees.filter(item => !feeRecipients.has(item.recipient));
+
+  return splitSale(
+    nfts.map(nft => ({ collection: nft.token, tokenId: nft.identifier, buyer: nft.recipient })),
+    {
+      seller,
+      currency,
+      total: sum(payments.filter(item => item.token === currency).map(item => item.amount)),
+      royalty: sum(royalties.map(item => item.amount)),
+      paidTo: fees.map(item => item.recipient)
+    }
+  );
+}
+
+// Blur Exchange OrdersMatched: the sell order carries the price and the creator fees
+function decodeBlur({ args }) {
+  const { sell, buy } = args;
+  const total = BigInt(sell.price);
+  const feeRate = sum(sell.fees.map(fee => fee.rate));
+  return splitSale([{ collection: sell.collection, tokenId: sell.tokenId }], {
+    seller: sell.trader,
+    buyer: buy.trader,
+    currency: sell.paymentToken,
+    total,
+    royalty: total * feeRate / BPS,
+    paidTo: sell.fees.map(fee => lower(fee.recipient))
+  });
+}
+
+/**
+ * Blur marketplace v2 packed executions: tokenId << 168 | listingIndex << 160 | trader and
+ * side << 248 | price << 160 | collection. Only the maker (trader) is logged; the taker comes
+ * from the item's Transfer. Fees (maker or taker) are creator royalties, rate << 160 | recipient.
+ */
+function decodeBlurV2({ args }) {
+  const packedToken = BigInt(args.tokenIdListingIndexTrader);
+  const packedOrder = BigInt(args.collectionPriceSide);
+  const isBid = (packedOrder >> 248n) === 1n;
+  const total = (packedOrder >> 160n) & MASK_88;
+  const trader = toAddress(packedToken);
+  const feeRecipientRate = args.length > 3 ? BigInt(args[3]) : null;
+  const feeRate = feeRecipientRate != null ? (feeRecipientRate >> 160n) & 0xffffn : 0n;
+
+  return splitSale([{ collection: toAddress(packedOrder), tokenId: packedToken >> 168n }], {
+    seller: isBid ? null : trader,
+    buyer: isBid ? trader : null,
+    currency: isBid ? BLUR_POOL_ADDRESS : null,
+    total,
+    royalty: total * feeRate / BPS,
+    paidTo: feeRecipientRate != null ? [toAddress(feeRecipientRate)] : []
+  });
+}
+
+// LooksRare v1 TakerAsk/TakerBid; royalties are separate RoyaltyPayment logs of the same exchange
+function decodeLooksRare({ name, args }, { parsedLogs }) {
+  if (name === 'RoyaltyPayment') return [];
+
+  const isAsk = name === 'TakerAsk';
+  const royalties = parsedLogs.filter(parsed => parsed.name === 'RoyaltyPayment' &&
+    lower(parsed.args.collection) === lower(args.collection) &&
+    BigInt(parsed.args.tokenId) === BigInt(args.tokenId));
+
+  return splitSale([{ collection: args.collection, tokenId: args.tokenId }], {
+    seller: isAsk ? args.taker : args.maker,
+    buyer: isAsk ? args.maker : args.taker,
+    currency: args.currency,
+    total: BigInt(args.price),
+    royalty: sum(royalties.map(parsed => parsed.args.amount)),
+    paidTo: royalties.map(parsed => lower(parsed.args.royaltyRecipient))
+  });
+}
+
+// LooksRare v2: feeAmounts are [seller proceeds, creator fee, protocol fee], feeRecipients [seller, creator]
+function decodeLooksRareV2({ name, args }) {
+  const isAsk = name === 'TakerAsk';
+  return splitSale(args.itemIds.map(tokenId => ({ collection: args.collection, tokenId })), {
+    seller: isAsk ? args.askUser : args.feeRecipients[0],
+    buyer: isAsk ? args.bidUser : args.bidRecipient,
+    currency: args.currency,
+    total: sum(args.feeAmounts),
+    royalty: BigInt(args.feeAmounts[1]),
+    paidTo: [lower(args.feeRecipients[1])]
+  });
+}
+
+/**
+ * Items of an X2Y2 order: the item data is an ABI-encoded (token, tokenId[, amount])[] list.
+ * Collection offers leave the token ID blank in the order and fill it in through dataMask.
+ */
+function decodeX2Y2Items(data, dataMask, dataReplacement) {
+  let bytes = ethers.getBytes(data);
+  const mask = ethers.getBytes(dataMask || '0x');
+  const replacement = ethers.getBytes(dataReplacement || '0x');
+  if (replacement.length === bytes.length && mask.length === bytes.length) {
+    bytes = bytes.map((byte, index) => (mask[index] ? replacement[index] : byte));
+  }
+
+  for (const type of ['tuple(address token, uint256 tokenId)[]', 'tuple(address token, uint256 tokenId, uint256 amount)[]']) {
+    try {
+      const [pairs] = abiCoder.decode([type], bytes);
+      return pairs.map(pair => ({ collection: pair.token, tokenId: pair.tokenId }));
+    } catch (error) {
+      // Try the ERC-1155 layout next
+    }
+  }
+  return [];
+}
+
+// X2Y2 EvInventory: fees are shares in millionths; those not going to X2Y2 itself are royalties
+function decodeX2Y2({ args }, { feeRecipients }) {
+  const { item, detail } = args;
+  const items = decodeX2Y2Items(item.data, args.dataMask, detail.dataReplacement);
+  const isBuy = BigInt(args.intent) === X2Y2_INTENT_BUY;
+  const total = BigInt(detail.price);
+  const royalties = detail.fees.filter(fee => !feeRecipients.has(lower(fee.to)));
+
+  return splitSale(items, {
+    seller: isBuy ? args.taker : args.maker,
+    buyer: isBuy ? args.maker : args.taker,
+    currency: args.currency,
+    total,
+    royalty: total * sum(royalties.map(fee => fee.percentage)) / X2Y2_FEE_BASE,
+    paidTo: detail.fees.map(fee => lower(fee.to))
+  });
+}
+
+// Payment Processor v2 (Magic Eden EVM); royalties are paid without a log of their own
+function decodePaymentProcessor({ args }) {
+  return splitSale([{ collection: args.tokenAddress, tokenId: args.tokenId }], {
+    seller: args.seller,
+    buyer: isZeroAddress(args.beneficiary) ? args.buyer : args.beneficiary,
+    currency: args.paymentCoin,
+    total: BigInt(args.salePrice)
+  });
+}
+
+/**
+ * Sudoswap pool swaps. Pools are created by users, so any emitter counts. Items are the pool's
+ * NFT transfers in the transaction; v2 pools log the amount paid and the IDs, v1 pools neither.
+ * ERC-20 pools are recognised by a token transfer to or from the pool.
+ */
+function decodeSudoswap({ name, args }, { log, nftTransfers, tokenTransfers }) {
+  const pool = lower(log.address);
+  const soldToPool = name === 'SwapNFTInPair';
+  const ids = args.length > 1 ? args.ids.map(id => BigInt(id).toString()) : null;
+  const items = nftTransfers
+    .filter(transfer => (soldToPool ? transfer.to : transfer.from) === pool)
+    .filter(transfer => !ids || ids.includes(transfer.tokenId));
+
+  return splitSale(items, {
+    seller: soldToPool ? null : pool,
+    buyer: soldToPool ? pool : null,
+    currency: tokenTransfers.find(transfer => transfer.from === pool || transfer.to === pool)?.token || null,
+    total: args.length > 1 ? BigInt(args[0]) : null
+  });
+}
+
+/**
+ * Event decoders by registry protocol name. Each receives the parsed log and
+ * { log, parsedLogs, feeRecipients, nftTransfers, tokenTransfers } and returns decoded sales.
+ */
+const PROTOCOLS = {
+  seaport: {
+    events: [
+      'event OrderFulfilled(bytes32 orderHash, address indexed offerer, address indexed zone, address recipient, ' +
+        'tuple(uint8 itemType, address token, uint256 identifier, uint256 amount)[] offer, ' +
+        'tuple(uint8 itemType, address token, uint256 identifier, uint256 amount, address recipient)[] consideration)'
+    ],
+    decode: decodeSeaport
+  },
+  blur: {
+    events: [`event OrdersMatched(address indexed maker, address indexed taker, ${BLUR_ORDER} sell, bytes32 sellHash, ${BLUR_ORDER} buy, bytes32 buyHash)`],
+    decode: decodeBlur
+  },
+  blur_v2: {
+    events: [
+      'event Execution721Packed(bytes32 orderHash, uint256 tokenIdListingIndexTrader, uint256 collectionPriceSide)',
+      'event Execution721TakerFeePacked(bytes32 orderHash, uint256 tokenIdListingIndexTrader, uint256 collectionPriceSide, uint256 takerFeeRecipientRate)',
+      'event Execution721MakerFeePacked(bytes32 orderHash, uint256 tokenIdListingIndexTrader, uint256 collectionPriceSide, uint256 makerFeeRecipientRate)'
+    ],
+    decode: decodeBlurV2
+  },
+  looksrare: {
+    events: [
+      `event TakerAsk${LOOKSRARE_V1_TAKER}`,
+      `event TakerBid${LOOKSRARE_V1_TAKER}`,
+      'event RoyaltyPayment(address indexed collection, uint256 indexed tokenId, address indexed royaltyRecipient, address currency, uint256 amount)'
+    ],
+    decode: decodeLooksRare
+  },
+  looksrare_v2: {
+    events: [
+      `event TakerAsk(${LOOKSRARE_V2_NONCE}, address askUser, address bidUser, ${LOOKSRARE_V2_ORDER})`,
+      `event TakerBid(${LOOKSRARE_V2_NONCE}, address bidUser, address bidRecipient, ${LOOKSRARE_V2_ORDER})`
+    ],
+    decode: decodeLooksRareV2
+  },
+  x2y2: {
+    events: [
+      'event EvInventory(bytes32 indexed itemHash, address maker, address taker, uint256 orderSalt, uint256 settleSalt, ' +
+        'uint256 intent, uint256 delegateType, uint256 deadline, address currency, bytes dataMask, ' +
+        'tuple(uint256 price, bytes data) item, ' +
+        'tuple(uint8 op, uint256 orderIdx, uint256 itemIdx, uint256 price, bytes32 itemHash, address executionDelegate, ' +
+        'bytes dataReplacement, uint256 bidIncentivePct, uint256 aucMinIncrementPct, uint256 aucIncDurationSecs, ' +
+        'tuple(uint256 percentage, address to)[] fees) detail)'
+    ],
+    decode: decodeX2Y2
+  },
+  payment_processor: {
+    events: [
+      'event BuyListingERC721(address indexed buyer, address indexed seller, address indexed tokenAddress, address beneficiary, address paymentCoin, uint256 tokenId, uint256 salePrice)',
+      'event BuyListingERC1155(address indexed buyer, address indexed seller, address indexed tokenAddress, address beneficiary, address paymentCoin, uint256 tokenId, uint256 amount, uint256 salePrice)',
+      'event AcceptOfferERC721(address indexed seller, address indexed buyer, address indexed tokenAddress, address beneficiary, address paymentCoin, uint256 tokenId, uint256 salePrice)',
+      'event AcceptOfferERC1155(address indexed seller, address indexed buyer, address indexed tokenAddress, address beneficiary, address paymentCoin, uint256 tokenId, uint256 amount, uint256 salePrice)'
+    ],
+    decode: decodePaymentProcessor
+  },
+  sudoswap: {
+    events: [
+      'event SwapNFTInPair(uint256 amountOut, uint256[] ids)',
+      'event SwapNFTOutPair(uint256 amountIn, uint256[] ids)',
+      'event SwapNFTInPair()',
+      'event SwapNFTOutPair()'
+    ],
+    decode: decodeSudoswap
+  }
+};
+
+/**
+ * Decoder for an event declared in the registry file: "fields" names the event argument
+ * holding each sale field (collection, tokenId, seller, buyer, price, currency, royalty).
+ */
+function createEventDecoder({ signature, fields = {} }) {
+  const iface = new ethers.Interface([signature.startsWith('event ') ? signature : `event ${signature}`]);
+  const value = (parsed, field) => (fields[field] ? parsed.args[fields[field]] : null);
+  return {
+    iface,
+    decode: (parsed) => splitSale([{ collection: value(parsed, 'collection'), tokenId: value(parsed, 'tokenId') }], {
+      seller: value(parsed, 'seller'),
+      buyer: value(parsed, 'buyer'),
+      currency: value(parsed, 'currency'),
+      total: value(parsed, 'price') != null ? BigInt(value(parsed, 'price')) : null,
+      royalty: value(parsed, 'royalty') != null ? BigInt(value(parsed, 'royalty')) : null
+    })
+  };
+}
+
+function parseTransfers(logs) {
+  const nftTransfers = [];
+  const tokenTransfers = [];
+  for (const log of logs) {
+    const topics = log.topics || [];
+    try {
+      if (topics[0] === TRANSFER_TOPIC && topics.length === 4) {
+        nftTransfers.push({ collection: lower(log.address), from: topicAddress(topics[1]), to: topicAddress(topics[2]), tokenId: BigInt(topics[3]).toString() });
+      } else if (topics[0] === TRANSFER_TOPIC && topics.length === 3) {
+        tokenTransfers.push({ token: lower(log.address), from: topicAddress(topics[1]), to: topicAddress(topics[2]), amount: BigInt(log.data) });
+      } else if (topics[0] === TRANSFER_SINGLE_TOPIC && topics.length === 4) {
+        const [tokenId] = abiCoder.decode(['uint256', 'uint256'], log.data);
+        nftTransfers.push({ collection: lower(log.address), from: topicAddress(topics[2]), to: topicAddress(topics[3]), tokenId: tokenId.toString() });
+      }
+    } catch (error) {
+      // Non-standard event sharing the topic
+    }
+  }
+  return { nftTransfers, tokenTransfers };
+}
+
+function readRegistryFile(file) {
+  const data = JSON.parse(fs.readFileSync(file, 'utf8'));
+  const entries = Array.isArray(data) ? data : data.marketplaces;
+  if (!Array.isArray(entries)) {
+    throw new Error(`${file} has no "marketplaces" list`);
+  }
+  return entries;
+}
+
+/**
+ * Marketplace contracts per chain and how to read their sales from a transaction receipt.
+ * Entries come from src/config/marketplaces.json plus MARKETPLACE_REGISTRY_FILE, whose entries
+ * replace built-in ones with the same id:
+ *
+ *   { "id", "name", "protocol", "addresses": { "<chain>"|"*": [...] }, "feeRecipients": [...],
+ *     "anyEmitter": false, "events": [{ "signature", "fields": { "price": "<arg>", ... } }] }
+ *
+ * protocol picks one of the built-in decoders (PROTOCOLS); events declares further sale events
+ * without code. An entry with neither only attributes transactions that touch its contracts.
+ * feeRecipients are marketplace fee wallets: payments to them aren't royalties, and a Seaport
+ * order paying one is credited to that entry's marketplace.
+ */
+class MarketplaceRegistry {
+  constructor(entries = []) {
+    this.entries = [];
+    this.byAddress = new Map();
+    this.feeRecipients = new Set();
+    for (const entry of entries) {
+      try {
+        this.add(entry);
+      } catch (error) {
+        logger.error(`❌ Invalid marketplace registry entry ${entry?.id || entry?.name}:`, error.message);
+      }
+    }
+  }
+
+  /**
+   * Registry from the built-in file and MARKETPLACE_REGISTRY_FILE
+   * @param {Array<string>} files - Registry files, later ones overriding earlier entries by id
+   * @returns {MarketplaceRegistry}
+   */
+  static load(files = [DEFAULT_REGISTRY_FILE, process.env.MARKETPLACE_REGISTRY_FILE]) {
+    const entries = new Map();
+    for (const file of files.filter(Boolean)) {
+      try {
+        for (const entry of readRegistryFile(file)) {
+          entries.set(entry.id || entry.name, entry);
+        }
+      } catch (error) {
+        logger.error(`❌ Could not load the marketplace registry ${file}:`, error.message);
+      }
+    }
+    return new MarketplaceRegistry([...entries.values()]);
+  }
+
+  add(config) {
+    const protocol = config.protocol ? PROTOCOLS[config.protocol] : null;
+    if (config.protocol && !protocol) {
+      logger.warn(`Unknown marketplace protocol ${config.protocol} for ${config.id || config.name}, attributing by address only`);
+    }
+
+    const decoders = (config.events || []).map(createEventDecoder);
+    if (protocol) {
+      decoders.unshift({ iface: new ethers.Interface(protocol.events), decode: protocol.decode });
+    }
+
+    const topics = new Set();
+    for (const decoder of decoders) {
+      decoder.iface.forEachEvent(fragment => topics.add(fragment.topicHash));
+    }
+
+    const entry = {
+      id: config.id || config.name,
+      name: config.name || null,
+      protocol: protocol ? config.protocol : null,
+      anyEmitter: config.anyEmitter === true,
+      feeRecipients: new Set((config.feeRecipients || []).map(lower)),
+      decoders,
+      topics
+    };
+
+    for (const [chain, addresses] of Object.entries(config.addresses || {})) {
+      for (const address of addresses) {
+        this.byAddress.set(`${chain}:${lower(address)}`, entry);
+      }
+    }
+    for (const recipient of entry.feeRecipients) {
+      this.feeRecipients.add(recipient);
+    }
+    this.entries.push(entry);
+    return entry;
+  }
+
+  /**
+   * Registry entry of a marketplace contract
+   * @param {string} chain - Chain name
+   * @param {string} address - Contract address
+   * @returns {Object|null}
+   */
+  getMarketplace(chain, address) {
+    const normalized = lower(address);
+    if (!normalized) return null;
+    return this.byAddress.get(`${chain}:${normalized}`) || this.byAddress.get(`${ALL_CHAINS}:${normalized}`) || null;
+  }
+
+  isMarketplaceAddress(chain, address) {
+    return !!this.getMarketplace(chain, address);
+  }
+
+  getMarketplaceName(chain, address) {
+    return this.getMarketplace(chain, address)?.name || null;
+  }
+
+  /**
+   * Sales in a transaction receipt, one per item. Missing buyers and sellers are taken from the
+   * item's Transfer log. When several orders move the same item (Seaport matched orders), the
+   * one with the highest price is kept, as the counter order only carries the seller's proceeds.
+   * @param {string} chain - Chain name
+   * @param {{logs: Array<{address: string, topics: Array<string>, data: string}>}} receipt
+   * @returns {{sales: Array<Object>, marketplaces: Array<string>}} Sales ({ marketplace, collection,
+   *   tokenId, seller, buyer, price: { raw: bigint, address }|null, royalty: bigint|null }) and the
+   *   names of registered marketplaces whose contracts emitted a log
+   */
+  decodeReceipt(chain, receipt) {
+    const logs = receipt?.logs || [];
+    const { nftTransfers, tokenTransfers } = parseTransfers(logs);
+    const sales = new Map();
+    const marketplaces = new Set();
+
+    for (const log of logs) {
+      const entry = this.getMarketplace(chain, log.address);
+      if (entry?.name) marketplaces.add(entry.name);
+    }
+
+    for (const entry of this.entries) {
+      const entryLogs = logs.filter(log => entry.topics.has(log.topics?.[0]) &&
+        (entry.anyEmitter || this.getMarketplace(chain, log.address) === entry));
+
+      for (const decoder of entry.decoders) {
+        const parsedLogs = entryLogs
+          .map(log => ({ log, parsed: this.parseLog(decoder.iface, log) }))
+          .filter(({ parsed }) => parsed);
+
+        for (const { log, parsed } of parsedLogs) {
+          let decoded;
+          try {
+            decoded = decoder.decode(parsed, {
+              log,
+              parsedLogs: parsedLogs.map(item => item.parsed),
+              feeRecipients: this.feeRecipients,
+              nftTransfers,
+              tokenTransfers
+            });
+          } catch (error) {
+            logger.debug(`Could not decode ${entry.id} ${parsed.name} log: ${error.message}`);
+            continue;
+          }
+
+          for (const sale of decoded) {
+            this.addSale(sales, entry, sale, nftTransfers);
+          }
+        }
+      }
+    }
+
+    return { sales: [...sales.values()], marketplaces: [...marketplaces] };
+  }
+
+  parseLog(iface, log) {
+    try {
+      return iface.parseLog({ topics: log.topics, data: log.data });
+    } catch (error) {
+      return null;
+    }
+  }
+
+  addSale(sales, entry, sale, nftTransfers) {
+    if (sale.tokenId == null) return;
+
+    const transfer = nftTransfers.find(item => item.tokenId === sale.tokenId &&
+      (!sale.collection || item.collection === sale.collection));
+    const collection = sale.collection || transfer?.collection || null;
+    const operator = this.entries.find(other => other.protocol === entry.protocol && other !== entry &&
+      sale.paidTo.some(recipient => other.feeRecipients.has(recipient)));
+
+    const resolved = {
+      marketplace: (operator || entry).name,
+      collection,
+      tokenId: sale.tokenId,
+      seller: sale.seller || transfer?.from || null,
+      buyer: sale.buyer || transfer?.to || null,
+      price: sale.price,
+      royalty: sale.royalty
+    };
+
+    const key = `${collection || ''}:${sale.tokenId}`;
+    const existing = sales.get(key);
+    if (!existing || (resolved.price && (!existing.price || resolved.price.raw > existing.price.raw))) {
+      sales.set(key, resolved);
+    }
+  }
+
+  getStats() {
+    return {
+      marketplaces: this.entries.length,
+      decoders: this.entries.filter(entry => entry.decoders.length > 0).length,
+      addresses: this.byAddress.size
+    };
+  }
+}
+
+// Shared registry, also used by the activity event adapters to tell sales from transfers
+const marketplaceRegistry = MarketplaceRegistry.load();
+
+module.exports = MarketplaceRegistry;
+module.exports.marketplaceRegistry = marketplaceRegistry;
+module.exports.PROTOCOLS = PROTOCOLS;
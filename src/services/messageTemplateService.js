@@ -0,0 +1,342 @@
+const logger = require('./logger');
+const { t } = require('../i18n');
+
+const CACHE_TTL_MS = 60 * 1000;
+
+// Room is left for the footer inside Telegram's 1024-character caption limit
+const MAX_TEMPLATE_LENGTH = 800;
+const MAX_CAPTION_LENGTH = 1024;
+
+const TEMPLATE_EVENT_TYPES = ['sale', 'listing', 'offer', 'mint', 'transfer', 'burn'];
+
+// Placeholder name -> description shown in the bot
+const TEMPLATE_PLACEHOLDERS = {
+  emoji: 'Event emoji (💰🟢 sale, 📝 listing, ...)',
+  collection: 'Collection name',
+  token_id: 'Token ID',
+  nft: 'Item name, linked to its marketplace page',
+  price: 'Price with currency, e.g. 0.4200 ETH',
+  usd: 'USD value in brackets, e.g. ($1.1K)',
+  native: 'Value in the chain currency in brackets, for prices in other tokens, e.g. (≈0.7400 ETH)',
+  royalty: 'Creator royalty paid on a sale, e.g. 0.0210 ETH (5.0%)',
+  buyer: 'Buyer / recipient address (shortened)',
+  seller: 'Seller / sender address (shortened)',
+  marketplace: 'Marketplace name',
+  marketplace_link: 'Link "View on <marketplace>"',
+  explorer_link: 'Link "View on <explorer>"',
+  item_url: 'Marketplace page URL, for your own <a href="...">',
+  explorer_url: 'Transaction URL, for your own <a href="...">',
+  collection_id: 'Collection slug or contract address',
+  chain: 'Chain name',
+  rank: 'Rarity rank, e.g. #123 / 10,000 (sales and listings of ranked collections)',
+  rarest_traits: 'Rarest traits of the item, e.g. Background: Gold (0.4%)'
+};
+
+// Placeholders that may appear inside an attribute (href); the others render HTML
+const URL_PLACEHOLDERS = ['item_url', 'explorer_url'];
+
+// Telegram HTML tags allowed in templates
+const ALLOWED_TAGS = ['b', 'strong', 'i', 'em', 'u', 'ins', 's', 'strike', 'del', 'code', 'pre', 'a', 'tg-spoiler', 'span', 'blockquote'];
+
+/**
+ * Defaults in template form. They lay out the alert like the built-in renderer, which
+ * chats without a custom template keep using.
+ */
+const DEFAULT_TEMPLATES = {
+  sale: [
+    '{emoji} <b>{collection}</b> <b>Buy</b>',
+    '',
+    '💰 <b>Buy Price:</b> {price} {native} {usd}',
+    '👑 <b>Royalty:</b> {royalty}',
+    '🖼️ <b>NFT:</b> {nft}',
+    '🏆 <b>Rank:</b> {rank}',
+    '✨ <b>Rarest traits:</b> {rarest_traits}',
+    '👤 <b>Buyer:</b> <code>{buyer}</code>',
+    '📤 <b>Seller:</b> <code>{seller}</code>',
+    '🏪 <b>Marketplace:</b> {marketplace}',
+    '📮 <b>Collection:</b> <code>{collection_id}</code>',
+    '🔗 <b>Chain:</b> {chain}',
+    '{marketplace_link}',
+    '{explorer_link}'
+  ].join('\n'),
+  listing: [
+    '{emoji} <b>{collection}</b> Listed',
+    '',
+    '💰 <b>List Price:</b> {price} {native} {usd}',
+    '🖼️ <b>NFT:</b> {nft}',
+    '🏆 <b>Rank:</b> {rank}',
+    '✨ <b>Rarest traits:</b> {rarest_traits}',
+    '🏪 <b>Marketplace:</b> {marketplace}',
+    '📮 <b>Collection:</b> <code>{collection_id}</code>',
+    '🔗 <b>Chain:</b> {chain}',
+    '{marketplace_link}'
+  ].join('\n'),
+  offer: [
+    '{emoji} <b>{collection}</b> Offer Received',
+    '',
+    '💰 <b>Offer Amount:</b> {price} {native} {usd}',
+    '🖼️ <b>NFT:</b> {nft}',
+    '👤 <b>Bidder:</b> <code>{buyer}</code>',
+    '🏪 <b>Marketplace:</b> {marketplace}',
+    '📮 <b>Collection:</b> <code>{collection_id}</code>',
+    '🔗 <b>Chain:</b> {chain}',
+    '{marketplace_link}'
+  ].join('\n'),
+  mint: [
+    '{emoji} <b>{collection}</b> Mint',
+    '',
+    '💰 <b>Mint Price:</b> {price} {native} {usd}',
+    '🖼️ <b>NFT:</b> {nft}',
+    '📥 <b>To:</b> <code>{buyer}</code>',
+    '📮 <b>Collection:</b> <code>{collection_id}</code>',
+    '🔗 <b>Chain:</b> {chain}',
+    '{marketplace_link}',
+    '{explorer_link}'
+  ].join('\n'),
+  transfer: [
+    '{emoji} <b>{collection}</b> Transfer',
+    '',
+    '🖼️ <b>NFT:</b> {nft}',
+    '📤 <b>From:</b> <code>{seller}</code>',
+    '📥 <b>To:</b> <code>{buyer}</code>',
+    '📮 <b>Collection:</b> <code>{collection_id}</code>',
+    '🔗 <b>Chain:</b> {chain}',
+    '{marketplace_link}',
+    '{explorer_link}'
+  ].join('\n'),
+  burn: [
+    '{emoji} <b>{collection}</b> Burn',
+    '',
+    '🖼️ <b>NFT:</b> {nft}',
+    '📤 <b>From:</b> <code>{seller}</code>',
+    '📮 <b>Collection:</b> <code>{collection_id}</code>',
+    '🔗 <b>Chain:</b> {chain}',
+    '{marketplace_link}',
+    '{explorer_link}'
+  ].join('\n')
+};
+
+// Values used by the preview
+const SAMPLE_VALUES = {
+  emoji: '💰🟢',
+  collection: 'Sample Collection',
+  token_id: '1234',
+  nft: '<a href="https://opensea.io/">Sample #1234</a>',
+  price: '0.4200 ETH',
+  usd: '($1.1K)',
+  royalty: '0.0210 ETH (5.0%)',
+  buyer: '0x1a2b...9f0e',
+  seller: '0x7c8d...3b4a',
+  marketplace: 'OpenSea',
+  marketplace_link: '<a href="https://opensea.io/">View on OpenSea</a>',
+  explorer_link: '<a href="https://etherscan.io/">View on Etherscan</a>',
+  item_url: 'https://opensea.io/',
+  explorer_url: 'https://etherscan.io/',
+  collection_id: 'sample-collection',
+  chain: '🔷 Ethereum',
+  rank: '#123 / 10,000',
+  rarest_traits: 'Background: Gold (0.4%)'
+};
+
+/**
+ * Escape text for Telegram HTML, quotes included so it is safe inside attributes
+ */
+function escapeTemplateValue(text) {
+  return String(text ?? '')
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+/**
+ * Fill in a template. A line whose placeholders all come out empty is left out, so
+ * optional fields (seller, marketplace, ...) don't leave empty labels behind; an empty
+ * placeholder inside a line takes the space before it along.
+ * @param {string} template
+ * @param {Object} values - Placeholder name -> HTML-safe value
+ * @returns {string} Telegram HTML
+ */
+function renderTemplate(template, values) {
+  const lines = [];
+  for (const line of template.split('\n')) {
+    const names = [...line.matchAll(/\{([a-z_]+)\}/g)].map(match => match[1]);
+    if (names.length > 0 && names.every(name => !values[name])) {
+      continue;
+    }
+    lines.push(line.replace(/( ?)\{([a-z_]+)\}/g, (match, space, name) => (values[name] ? space + values[name] : '')).trimEnd());
+  }
+  return lines.join('\n').replace(/\n{3,}/g, '\n\n').trim();
+}
+
+/**
+ * Check a template: known placeholders, Telegram-supported HTML tags, properly
+ * nested and closed, no stray "<" or "&"
+ * @param {string} template
+ * @returns {{isValid: boolean, reason?: string, params?: Object}} `reason` is a catalog
+ *   key for t(lang, reason, params)
+ */
+function validateTemplate(template) {
+  if (!template || !template.trim()) {
+    return { isValid: false, reason: 'templates.error.empty' };
+  }
+  if (template.length > MAX_TEMPLATE_LENGTH) {
+    return { isValid: false, reason: 'templates.error.too_long', params: { length: template.length, max: MAX_TEMPLATE_LENGTH } };
+  }
+
+  const unknown = [...template.matchAll(/\{([^{}\s]*)\}/g)]
+    .map(match => match[1])
+    .filter(name => !TEMPLATE_PLACEHOLDERS[name]);
+  if (unknown.length > 0) {
+    return { isValid: false, reason: 'templates.error.unknown_placeholder', params: { name: unknown[0] } };
+  }
+
+  const stack = [];
+  const tagPattern = /<(\/?)([a-zA-Z][a-zA-Z0-9-]*)((?:\s+[a-zA-Z-]+="[^"<>]*")*)\s*>/g;
+  let position = 0;
+  let match;
+  while ((match = tagPattern.exec(template)) !== null) {
+    if (template.slice(position, match.index).includes('<')) {
+      return { isValid: false, reason: 'templates.error.malformed_tag' };
+    }
+    position = tagPattern.lastIndex;
+
+    const [, closing, rawName, attributes] = match;
+    const name = rawName.toLowerCase();
+    if (!ALLOWED_TAGS.includes(name)) {
+      return { isValid: false, reason: 'templates.error.unsupported_tag', params: { tag: name } };
+    }
+
+    if (closing) {
+      const open = stack.pop();
+      if (open !== name) {
+        return { isValid: false, reason: open ? 'templates.error.wrong_close' : 'templates.error.unopened_close', params: { tag: name, open } };
+      }
+      continue;
+    }
+
+    if (name === 'a' && !/\bhref="[^"]+"/.test(attributes)) {
+      return { isValid: false, reason: 'templates.error.link_href' };
+    }
+    const attributePlaceholder = [...attributes.matchAll(/\{([a-z_]+)\}/g)]
+      .find(placeholder => !URL_PLACEHOLDERS.includes(placeholder[1]));
+    if (attributePlaceholder) {
+      return { isValid: false, reason: 'templates.error.attribute_placeholder', params: { name: attributePlaceholder[1] } };
+    }
+    if (name === 'span' && !/\bclass="tg-spoiler"/.test(attributes)) {
+      return { isValid: false, reason: 'templates.error.span_class' };
+    }
+    if (name !== 'a' && name !== 'span' && name !== 'code' && attributes.trim()) {
+      return { isValid: false, reason: 'templates.error.no_attributes', params: { tag: name } };
+    }
+    stack.push(name);
+  }
+
+  if (template.slice(position).includes('<')) {
+    return { isValid: false, reason: 'templates.error.malformed_tag' };
+  }
+  if (stack.length > 0) {
+    return { isValid: false, reason: 'templates.error.unclosed', params: { tag: stack[stack.length - 1] } };
+  }
+  if (/&(?!(?:amp|lt|gt|quot|#\d+|#x[0-9a-fA-F]+);)/.test(template)) {
+    return { isValid: false, reason: 'templates.error.ampersand' };
+  }
+
+  return { isValid: true };
+}
+
+/**
+ * Custom alert templates of a chat (group or private chat), one per event type, stored
+ * in message_templates. Event types without a row use the built-in layout.
+ */
+class MessageTemplateService {
+  constructor(database, options = {}) {
+    this.db = database;
+    this.cacheTtlMs = options.cacheTtlMs ?? CACHE_TTL_MS;
+    this.cache = new Map();
+  }
+
+  /**
+   * @param {string|number} chatId - Telegram chat ID
+   * @returns {Promise<Object>} Event type -> custom template
+   */
+  async getTemplates(chatId) {
+    const key = String(chatId);
+    const cached = this.cache.get(key);
+    if (cached && cached.expiresAt > Date.now()) {
+      return cached.templates;
+    }
+
+    const templates = {};
+    try {
+      const rows = await this.db.all('SELECT event_type, template FROM message_templates WHERE chat_id = $1', [key]);
+      for (const row of rows || []) {
+        templates[row.event_type] = row.template;
+      }
+    } catch (error) {
+      // Alerts fall back to the built-in layout if the table can't be read
+      logger.error(`Error loading message templates for chat ${key}:`, error.message);
+      return templates;
+    }
+
+    this.cache.set(key, { templates, expiresAt: Date.now() + this.cacheTtlMs });
+    return templates;
+  }
+
+  /**
+   * @returns {Promise<string|null>} The chat's template for an event type, null for the built-in layout
+   */
+  async get(chatId, eventType) {
+    return (await this.getTemplates(chatId))[eventType] || null;
+  }
+
+  /**
+   * Save a custom template (validated)
+   * @param {string|number} chatId
+   * @param {string} eventType
+   * @param {string} template
+   * @param {string|number} updatedBy - Telegram user ID of the admin
+   */
+  async set(chatId, eventType, template, updatedBy = null) {
+    if (!TEMPLATE_EVENT_TYPES.includes(eventType)) {
+      throw new Error(`Unknown event type: ${eventType}`);
+    }
+    const validation = validateTemplate(template);
+    if (!validation.isValid) {
+      throw new Error(t('en', validation.reason, validation.params));
+    }
+
+    const key = String(chatId);
+    await this.db.query(
+      `INSERT INTO message_templates (chat_id, event_type, template, updated_by, updated_at)
+       VALUES ($1, $2, $3, $4, NOW())
+       ON CONFLICT (chat_id, event_type) DO UPDATE SET
+         template = EXCLUDED.template,
+         updated_by = EXCLUDED.updated_by,
+         updated_at = NOW()`,
+      [key, eventType, template, updatedBy != null ? String(updatedBy) : null]
+    );
+    this.cache.delete(key);
+    logger.info(`Chat ${key} ${eventType} template updated by ${updatedBy}`);
+  }
+
+  /**
+   * Go back to the built-in layout for one event type
+   */
+  async reset(chatId, eventType) {
+    const key = String(chatId);
+    await this.db.query('DELETE FROM message_templates WHERE chat_id = $1 AND event_type = $2', [key, eventType]);
+    this.cache.delete(key);
+    logger.info(`Chat ${key} ${eventType} template reset`);
+  }
+}
+
+module.exports = MessageTemplateService;
+module.exports.TEMPLATE_EVENT_TYPES = TEMPLATE_EVENT_TYPES;
+module.exports.TEMPLATE_PLACEHOLDERS = TEMPLATE_PLACEHOLDERS;
+module.exports.DEFAULT_TEMPLATES = DEFAULT_TEMPLATES;
+module.exports.SAMPLE_VALUES = SAMPLE_VALUES;
+module.exports.MAX_CAPTION_LENGTH = MAX_CAPTION_LENGTH;
+module.exports.escapeTemplateValue = escapeTemplateValue;
+module.exports.renderTemplate = renderTemplate;
+module.exports.validateTemplate = validateTemplate;
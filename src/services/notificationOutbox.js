@@ -0,0 +1,359 @@
+const fs = require('fs');
+const path = require('path');
+const logger = require('./logger');
+const TelegramSender = require('./telegramSender');
+
+const DEFAULT_IMAGE_PATH = path.join(__dirname, '../images/candyImage.jpg');
+const POLL_INTERVAL_MS = 2000;
+const BATCH_SIZE = 20;
+const BASE_RETRY_DELAY_MS = 5000;
+const MAX_RETRY_DELAY_MS = 15 * 60 * 1000;
+const LEASE_MS = 2 * 60 * 1000;
+const PRUNE_INTERVAL_MS = 60 * 60 * 1000;
+const SENT_RETENTION_DAYS = 7;
+const DEAD_RETENTION_DAYS = 30;
+// A 429 is rescheduled by the outbox itself so a long retry_after doesn't outlive the lease
+const SEND_OPTIONS = { priority: TelegramSender.PRIORITY.ALERT, maxRetries: 0 };
+
+/**
+ * Durable outbox for Telegram alerts
+ *
+ * Notify paths enqueue messages in notification_outbox and return immediately; the
+ * worker delivers them with exponential backoff. Messages to the same chat are sent
+ * strictly in order: a chat's next message waits until the previous one is sent or
+ * dead-lettered. Rows are claimed with a lease, so several instances can run workers
+ * against the same database.
+ */
+class NotificationOutbox {
+  constructor(database, bot, options = {}) {
+    this.db = database;
+    this.bot = bot;
+    // Shares the app-wide rate limiter when given one, so alerts and broadcasts draw from the same budget
+    this.sender = options.sender || (bot ? new TelegramSender(bot) : null);
+    this.maxAttempts = options.maxAttempts ?? (parseInt(process.env.OUTBOX_MAX_ATTEMPTS, 10) || 8);
+    this.pollIntervalMs = options.pollIntervalMs ?? POLL_INTERVAL_MS;
+    this.batchSize = options.batchSize ?? BATCH_SIZE;
+    this.timer = null;
+    this.running = false;
+    this.busy = null;
+    this.wakeRequested = false;
+    this.lastPrunedAt = 0;
+  }
+
+  /**
+   * Queue a message for delivery
+   * @param {Object} notification
+   * @param {string|number} notification.chatId - Target chat
+   * @param {string} notification.method - 'sendPhoto' or 'sendMessage'
+   * @param {string} notification.photo - Local image path (sendPhoto)
+   * @param {string} notification.text - Message text (sendMessage)
+   * @param {Object} notification.extra - Telegram options (caption, parse_mode, reply_markup)
+   * @param {Object} notification.recipient - { type: 'user'|'channel', id } used when the chat is unreachable
+   * @param {Array<string>} notification.cleanupFiles - Temp files to delete once delivered or dead-lettered
+   * @param {Object} db - Database or open transaction (db.transaction) to insert with
+   * @returns {Promise<{id: number}>}
+   */
+  async enqueue({ chatId, method = 'sendPhoto', photo = null, text = null, extra = {}, recipient = null, cleanupFiles = [] }, db = this.db) {
+    const result = await db.query(
+      `INSERT INTO notification_outbox (chat_id, method, payload, recipient_type, recipient_id, cleanup_files)
+       VALUES ($1, $2, $3, $4, $5, $6)
+       RETURNING id`,
+      [
+        String(chatId),
+        method,
+        JSON.stringify({ photo, text, extra }),
+        recipient?.type || null,
+        recipient?.id != null ? String(recipient.id) : null,
+        cleanupFiles.length > 0 ? JSON.stringify(cleanupFiles) : null
+      ]
+    );
+
+    const id = result.rows[0]?.id;
+    logger.debug(`📬 Queued ${method} #${id} for chat ${chatId}`);
+    this.wake();
+    return { id };
+  }
+
+  start() {
+    if (this.running) return;
+    this.running = true;
+    this.schedule(0);
+    logger.info('📬 Notification outbox worker started');
+  }
+
+  /**
+   * Stop polling and wait for the batch in flight
+   */
+  async stop() {
+    this.running = false;
+    clearTimeout(this.timer);
+    this.timer = null;
+    if (this.busy) {
+      await this.busy;
+    }
+  }
+
+  wake() {
+    if (!this.running) return;
+    if (this.busy) {
+      this.wakeRequested = true;
+    } else {
+      this.schedule(0);
+    }
+  }
+
+  schedule(delayMs) {
+    clearTimeout(this.timer);
+    this.timer = setTimeout(() => this.tick(), delayMs);
+  }
+
+  async tick() {
+    if (this.busy || !this.running) return;
+
+    this.busy = (async () => {
+      try {
+        let delivered;
+        do {
+          delivered = await this.processBatch();
+        } while (delivered >= this.batchSize && this.running);
+
+        if (Date.now() - this.lastPrunedAt > PRUNE_INTERVAL_MS) {
+          await this.prune();
+        }
+      } catch (error) {
+        logger.error('Notification outbox worker error:', error.message);
+      }
+    })();
+
+    await this.busy;
+    this.busy = null;
+
+    if (this.running) {
+      this.schedule(this.wakeRequested ? 0 : this.pollIntervalMs);
+      this.wakeRequested = false;
+    }
+  }
+
+  /**
+   * Claim and deliver the next due message of each chat
+   * @returns {Promise<number>} Number of messages claimed
+   */
+  async processBatch() {
+    // Give up leases held by a worker that died mid-delivery
+    await this.db.query(
+      `UPDATE notification_outbox
+       SET status = 'pending', locked_until = NULL
+       WHERE status = 'sending' AND locked_until < NOW()`
+    );
+
+    const due = await this.db.all(
+      `SELECT o.*
+       FROM notification_outbox o
+       WHERE o.status = 'pending'
+         AND o.next_attempt_at <= NOW()
+         AND NOT EXISTS (
+           SELECT 1 FROM notification_outbox earlier
+           WHERE earlier.chat_id = o.chat_id
+             AND earlier.status IN ('pending', 'sending')
+             AND earlier.id < o.id
+         )
+       ORDER BY o.id
+       LIMIT $1`,
+      [this.batchSize]
+    );
+
+    const claimed = [];
+    for (const row of due) {
+      const result = await this.db.query(
+        `UPDATE notification_outbox
+         SET status = 'sending', locked_until = $2
+         WHERE id = $1 AND status = 'pending'
+         RETURNING id`,
+        [row.id, new Date(Date.now() + LEASE_MS)]
+      );
+      if (result.rows.length > 0) {
+        claimed.push(row);
+      }
+    }
+
+    // One message per chat per batch, so chats are delivered in parallel without reordering
+    await Promise.all(claimed.map(row => this.deliver(row)));
+    return claimed.length;
+  }
+
+  async deliver(row) {
+    const attempts = parseInt(row.attempts, 10) + 1;
+
+    try {
+      const { photo, text, extra } = JSON.parse(row.payload);
+
+      if (row.method === 'sendPhoto') {
+        // A temp image may be gone after a restart or on another instance
+        const source = photo && fs.existsSync(photo) ? photo : DEFAULT_IMAGE_PATH;
+        await this.sender.sendPhoto(row.chat_id, { source }, extra, SEND_OPTIONS);
+      } else if (row.method === 'sendMessage') {
+        await this.sender.sendMessage(row.chat_id, text, extra, SEND_OPTIONS);
+      } else {
+        throw new Error(`Unsupported outbox method ${row.method}`);
+      }
+
+      await this.db.query(
+        `UPDATE notification_outbox
+         SET status = 'sent', attempts = $2, sent_at = NOW(), locked_until = NULL, last_error = NULL
+         WHERE id = $1`,
+        [row.id, attempts]
+      );
+      logger.info(`✅ Outbox #${row.id} delivered to chat ${row.chat_id}`);
+      this.cleanupFiles(row);
+    } catch (error) {
+      await this.handleFailure(row, attempts, error);
+    }
+  }
+
+  async handleFailure(row, attempts, error) {
+    const errorCode = error.response?.error_code;
+    const description = error.response?.description || error.message;
+
+    if (errorCode === 429) {
+      // Rate limited: wait as long as Telegram asks, without using up an attempt
+      const retryAfterMs = (error.response?.parameters?.retry_after || 5) * 1000;
+      await this.reschedule(row.id, row.attempts, retryAfterMs, description);
+      logger.warn(`⏳ Outbox #${row.id} rate limited for chat ${row.chat_id}, retrying in ${retryAfterMs / 1000}s`);
+      return;
+    }
+
+    if (errorCode === 403 || errorCode === 400) {
+      await this.deadLetter(row, attempts, description);
+      await this.handleUnreachableChat(row, errorCode);
+      return;
+    }
+
+    if (attempts >= this.maxAttempts) {
+      await this.deadLetter(row, attempts, description);
+      return;
+    }
+
+    const delayMs = this.getRetryDelay(attempts);
+    await this.reschedule(row.id, attempts, delayMs, description);
+    logger.warn(`🔁 Outbox #${row.id} to chat ${row.chat_id} failed (attempt ${attempts}/${this.maxAttempts}), retrying in ${Math.round(delayMs / 1000)}s: ${description}`);
+  }
+
+  getRetryDelay(attempts) {
+    const delay = Math.min(BASE_RETRY_DELAY_MS * 2 ** (attempts - 1), MAX_RETRY_DELAY_MS);
+    // Jitter so a burst of failures doesn't retry in lockstep
+    return Math.round(delay * (0.8 + Math.random() * 0.4));
+  }
+
+  async reschedule(id, attempts, delayMs, lastError) {
+    await this.db.query(
+      `UPDATE notification_outbox
+       SET status = 'pending', attempts = $2, next_attempt_at = $3, locked_until = NULL, last_error = $4
+       WHERE id = $1`,
+      [id, attempts, new Date(Date.now() + delayMs), lastError]
+    );
+  }
+
+  async deadLetter(row, attempts, lastError) {
+    await this.db.query(
+      `UPDATE notification_outbox
+       SET status = 'dead', attempts = $2, locked_until = NULL, last_error = $3
+       WHERE id = $1`,
+      [row.id, attempts, lastError]
+    );
+    logger.error(`💀 Outbox #${row.id} to chat ${row.chat_id} dead-lettered after ${attempts} attempt(s): ${lastError}`);
+    this.cleanupFiles(row);
+  }
+
+  /**
+   * Same handling as the inline senders had: users that blocked the bot (403) or whose
+   * chat is gone (400) are deactivated, channels only when the bot was removed (403)
+   */
+  async handleUnreachableChat(row, errorCode) {
+    try {
+      if (row.recipient_type === 'user' && row.recipient_id) {
+        await this.db.run('UPDATE users SET is_active = false WHERE telegram_id = $1', [row.recipient_id]);
+        logger.info(`Deactivated user ${row.recipient_id} due to delivery failure`);
+      } else if (row.recipient_type === 'channel' && errorCode === 403) {
+        await this.db.run('UPDATE channels SET is_active = false WHERE telegram_chat_id = $1', [row.chat_id]);
+        logger.info(`Deactivated channel ${row.chat_id} due to bot removal`);
+      }
+
+      if (errorCode === 403) {
+        // The bot can't post there anymore, so the rest of the chat's queue would fail too
+        await this.db.query(
+          `UPDATE notification_outbox
+           SET status = 'dead', last_error = $2
+           WHERE chat_id = $1 AND status = 'pending'`,
+          [row.chat_id, 'Chat unreachable (403)']
+        );
+      }
+    } catch (error) {
+      logger.error(`Error handling unreachable chat ${row.chat_id}:`, error.message);
+    }
+  }
+
+  cleanupFiles(row) {
+    if (!row.cleanup_files) return;
+
+    for (const file of JSON.parse(row.cleanup_files)) {
+      fs.promises.unlink(file)
+        .then(() => logger.debug(`🗑️ Cleaned up outbox image: ${file}`))
+        .catch(() => {});
+    }
+  }
+
+  async prune() {
+    this.lastPrunedAt = Date.now();
+    const sentBefore = new Date(Date.now() - SENT_RETENTION_DAYS * 24 * 60 * 60 * 1000);
+    const deadBefore = new Date(Date.now() - DEAD_RETENTION_DAYS * 24 * 60 * 60 * 1000);
+
+    const result = await this.db.query(
+      `DELETE FROM notification_outbox
+       WHERE (status = 'sent' AND sent_at < $1) OR (status = 'dead' AND created_at < $2)`,
+      [sentBefore, deadBefore]
+    );
+    if (result.rowCount > 0) {
+      logger.debug(`Pruned ${result.rowCount} old outbox entries`);
+    }
+  }
+
+  async getStats() {
+    const rows = await this.db.all('SELECT status, COUNT(*) AS count FROM notification_outbox GROUP BY status');
+    const stats = { pending: 0, sending: 0, sent: 0, dead: 0 };
+    rows.forEach(row => { stats[row.status] = parseInt(row.count, 10); });
+    return stats;
+  }
+
+  async getDeadLetters(limit = 20) {
+    return this.db.all(
+      `SELECT id, chat_id, method, recipient_type, recipient_id, attempts, last_error, created_at
+       FROM notification_outbox
+       WHERE status = 'dead'
+       ORDER BY id DESC
+       LIMIT $1`,
+      [limit]
+    );
+  }
+
+  /**
+   * Put dead-lettered messages back in the queue
+   * @param {number|null} id - A single message, or all dead letters if null
+   * @returns {Promise<number>} Number of messages requeued
+   */
+  async requeueDeadLetters(id = null) {
+    const result = id
+      ? await this.db.query(
+        `UPDATE notification_outbox SET status = 'pending', attempts = 0, next_attempt_at = NOW()
+         WHERE id = $1 AND status = 'dead'`,
+        [id]
+      )
+      : await this.db.query(
+        `UPDATE notification_outbox SET status = 'pending', attempts = 0, next_attempt_at = NOW()
+         WHERE status = 'dead'`
+      );
+    this.wake();
+    return result.rowCount;
+  }
+}
+
+module.exports = NotificationOutbox;
@@ -0,0 +1,521 @@
+const axios = require('axios');
+const crypto = require('crypto');
+const dns = require('dns');
+const https = require('https');
+const net = require('net');
+const logger = require('./logger');
+
+const EVENT_TYPES = ['sale', 'listing', 'mint', 'transfer'];
+const MAX_ENDPOINTS_PER_CHAT = 5;
+const POLL_INTERVAL_MS = 5000;
+const BATCH_SIZE = 20;
+const BASE_RETRY_DELAY_MS = 10 * 1000;
+const MAX_RETRY_DELAY_MS = 60 * 60 * 1000;
+const LEASE_MS = 2 * 60 * 1000;
+const PRUNE_INTERVAL_MS = 60 * 60 * 1000;
+const DELIVERED_RETENTION_DAYS = 7;
+const DEAD_RETENTION_DAYS = 30;
+const USER_AGENT = 'MintTechBot-Webhooks/1.0';
+
+/**
+ * Outbound webhooks for third-party integrations
+ *
+ * Group admins register HTTPS endpoints for a collection tracked in their group. Every
+ * normalized sale, listing, mint or transfer of that collection is queued per endpoint
+ * and POSTed as JSON, signed with the endpoint's secret:
+ *
+ *   X-MintTech-Signature: sha256=HMAC_SHA256(secret, "<X-MintTech-Timestamp>.<raw body>")
+ *
+ * Failed deliveries are retried with exponential backoff and dead-lettered after
+ * OUTBOUND_WEBHOOK_MAX_ATTEMPTS. An endpoint answering 410 Gone, or failing
+ * OUTBOUND_WEBHOOK_DISABLE_AFTER times in a row, is disabled. Hostnames are resolved again
+ * on every delivery and connections to private addresses are refused, so an endpoint can't
+ * be pointed at the internal network after it was registered.
+ */
+class OutboundWebhookService {
+  constructor(database, options = {}) {
+    this.db = database;
+    this.maxAttempts = options.maxAttempts ?? (parseInt(process.env.OUTBOUND_WEBHOOK_MAX_ATTEMPTS, 10) || 10);
+    this.disableAfter = options.disableAfter ?? (parseInt(process.env.OUTBOUND_WEBHOOK_DISABLE_AFTER, 10) || 50);
+    this.timeoutMs = options.timeoutMs ?? (parseInt(process.env.OUTBOUND_WEBHOOK_TIMEOUT_MS, 10) || 10000);
+    this.pollIntervalMs = options.pollIntervalMs ?? POLL_INTERVAL_MS;
+    this.batchSize = options.batchSize ?? BATCH_SIZE;
+    this.timer = null;
+    this.running = false;
+    this.busy = null;
+    this.wakeRequested = false;
+    this.lastPrunedAt = 0;
+    this.httpsAgent = new https.Agent({
+      lookup: (hostname, lookupOptions, callback) => this.lookupPublicAddress(hostname, lookupOptions, callback)
+    });
+  }
+
+  // ==================== ENDPOINT MANAGEMENT ====================
+
+  /**
+   * Check that an endpoint URL is a public HTTPS URL
+   * @param {string} url - Endpoint URL
+   * @returns {{isValid: boolean, reason?: string, url?: string}}
+   */
+  validateUrl(url) {
+    let parsed;
+    try {
+      parsed = new URL(url);
+    } catch (error) {
+      return { isValid: false, reason: 'Not a valid URL' };
+    }
+
+    if (parsed.protocol !== 'https:') {
+      return { isValid: false, reason: 'Only https:// endpoints are supported' };
+    }
+    if (parsed.username || parsed.password) {
+      return { isValid: false, reason: 'Credentials in the URL are not allowed' };
+    }
+
+    const hostname = parsed.hostname.replace(/^\[|\]$/g, '').toLowerCase();
+    if (hostname === 'localhost' || hostname.endsWith('.localhost') || hostname.endsWith('.local') || hostname.endsWith('.internal')) {
+      return { isValid: false, reason: 'Local hostnames are not allowed' };
+    }
+    if (net.isIP(hostname) && this.isPrivateAddress(hostname)) {
+      return { isValid: false, reason: 'Private IP addresses are not allowed' };
+    }
+
+    return { isValid: true, url: parsed.toString() };
+  }
+
+  isPrivateAddress(ip) {
+    if (net.isIPv4(ip)) {
+      const [a, b] = ip.split('.').map(Number);
+      return a === 10 || a === 127 || a === 0 ||
+        (a === 169 && b === 254) ||
+        (a === 172 && b >= 16 && b <= 31) ||
+        (a === 192 && b === 168) ||
+        (a === 100 && b >= 64 && b <= 127);
+    }
+    return ip === '::1' || ip === '::' || /^f[cd]/.test(ip) || ip.startsWith('fe80') || ip.startsWith('::ffff:');
+  }
+
+  /**
+   * dns.lookup for the delivery agent that fails when the host resolves to a private address
+   */
+  lookupPublicAddress(hostname, lookupOptions, callback) {
+    if (typeof lookupOptions === 'function') {
+      callback = lookupOptions;
+      lookupOptions = {};
+    } else if (typeof lookupOptions === 'number') {
+      lookupOptions = { family: lookupOptions };
+    }
+
+    dns.lookup(hostname, { ...lookupOptions, all: true }, (error, addresses) => {
+      if (error) return callback(error);
+
+      const blocked = addresses.find(({ address }) => this.isPrivateAddress(address.toLowerCase()));
+      if (blocked) {
+        const privateError = new Error(`${hostname} resolves to a private address (${blocked.address})`);
+        privateError.code = 'EPRIVATEADDRESS';
+        return callback(privateError);
+      }
+
+      if (lookupOptions.all) return callback(null, addresses);
+      callback(null, addresses[0].address, addresses[0].family);
+    });
+  }
+
+  /**
+   * Parse a comma-separated event type filter
+   * @param {string|null} input - e.g. "sale,listing"; empty means all types
+   * @returns {{isValid: boolean, eventTypes?: Array<string>|null, reason?: string, params?: Object}}
+   *   `reason` is a catalog key for t(lang, reason, params)
+   */
+  parseEventTypes(input) {
+    if (!input) return { isValid: true, eventTypes: null };
+
+    const eventTypes = [...new Set(input.toLowerCase().split(',').map(type => type.trim()).filter(Boolean))];
+    const unknown = eventTypes.filter(type => !EVENT_TYPES.includes(type));
+    if (unknown.length > 0) {
+      return { isValid: false, reason: 'webhooks.unknown_event_types', params: { types: unknown.join(', '), expected: EVENT_TYPES.join(', ') } };
+    }
+    return { isValid: true, eventTypes };
+  }
+
+  /**
+   * Find a collection tracked in a chat by contract address or collection slug
+   * @param {string} chatId - Group chat ID
+   * @param {string} identifier - Contract address or collection slug
+   * @returns {Promise<Object|null>} Tracked token
+   */
+  async findChatToken(chatId, identifier) {
+    return this.db.get(
+      `SELECT tt.*
+       FROM tracked_tokens tt
+       JOIN user_subscriptions us ON us.token_id = tt.id
+       WHERE us.chat_id = $1 AND tt.is_active = true
+         AND (LOWER(tt.contract_address) = LOWER($2) OR LOWER(tt.collection_slug) = LOWER($2))
+       LIMIT 1`,
+      [String(chatId), identifier]
+    );
+  }
+
+  /**
+   * Register an endpoint for a tracked collection
+   * @returns {Promise<{id: number, secret: string}>}
+   */
+  async register({ tokenId, chatId, userId = null, url, eventTypes = null }) {
+    const validation = this.validateUrl(url);
+    if (!validation.isValid) {
+      throw new Error(validation.reason);
+    }
+
+    const existing = await this.db.get(
+      'SELECT COUNT(*) as count FROM outbound_webhooks WHERE chat_id = $1 AND is_active = true',
+      [String(chatId)]
+    );
+    if ((parseInt(existing?.count) || 0) >= MAX_ENDPOINTS_PER_CHAT) {
+      throw new Error(`A group can register at most ${MAX_ENDPOINTS_PER_CHAT} endpoints`);
+    }
+
+    const secret = this.generateSecret();
+    const result = await this.db.query(
+      `INSERT INTO outbound_webhooks (token_id, chat_id, created_by_user_id, url, secret, event_types)
+       VALUES ($1, $2, $3, $4, $5, $6)
+       ON CONFLICT (token_id, chat_id, url) DO UPDATE SET
+         secret = EXCLUDED.secret,
+         event_types = EXCLUDED.event_types,
+         created_by_user_id = EXCLUDED.created_by_user_id,
+         is_active = true,
+         failure_count = 0
+       RETURNING id`,
+      [tokenId, String(chatId), userId, validation.url, secret, eventTypes ? eventTypes.join(',') : null]
+    );
+
+    const id = result.rows[0]?.id;
+    logger.info(`🔗 Outbound webhook #${id} registered for token ${tokenId} in chat ${chatId}`);
+    return { id, secret };
+  }
+
+  async listForChat(chatId) {
+    return this.db.all(
+      `SELECT ow.id, ow.url, ow.event_types, ow.is_active, ow.failure_count, ow.last_success_at,
+              ow.last_failure_at, ow.last_error, tt.token_name, tt.contract_address, tt.chain_name
+       FROM outbound_webhooks ow
+       JOIN tracked_tokens tt ON tt.id = ow.token_id
+       WHERE ow.chat_id = $1
+       ORDER BY ow.id`,
+      [String(chatId)]
+    );
+  }
+
+  async remove(id, chatId) {
+    const result = await this.db.query(
+      'DELETE FROM outbound_webhooks WHERE id = $1 AND chat_id = $2',
+      [id, String(chatId)]
+    );
+    return result.rowCount > 0;
+  }
+
+  async belongsToChat(id, chatId) {
+    const row = await this.db.get(
+      'SELECT id FROM outbound_webhooks WHERE id = $1 AND chat_id = $2',
+      [id, String(chatId)]
+    );
+    return !!row;
+  }
+
+  generateSecret() {
+    return crypto.randomBytes(32).toString('hex');
+  }
+
+  /**
+   * Replace an endpoint's signing secret (also re-enables it)
+   * @param {string} secret - From generateSecret, once it has reached the admin
+   * @returns {Promise<boolean>} False if the endpoint doesn't belong to the chat
+   */
+  async rotateSecret(id, chatId, secret) {
+    const result = await this.db.query(
+      `UPDATE outbound_webhooks SET secret = $3, is_active = true, failure_count = 0
+       WHERE id = $1 AND chat_id = $2`,
+      [id, String(chatId), secret]
+    );
+    return result.rowCount > 0;
+  }
+
+  // ==================== PUBLISHING ====================
+
+  /**
+   * Queue an event for every active endpoint of the collection
+   * @param {Object} token - Tracked token row
+   * @param {Object} event - Normalized event from WebhookHandlers.buildOutboundEvent
+   * @returns {Promise<number>} Number of deliveries queued
+   */
+  async publish(token, event) {
+    if (!event || !EVENT_TYPES.includes(event.type)) return 0;
+
+    const endpoints = await this.db.all(
+      'SELECT id, event_types FROM outbound_webhooks WHERE token_id = $1 AND is_active = true',
+      [token.id]
+    );
+    const targets = endpoints.filter(endpoint =>
+      !endpoint.event_types || endpoint.event_types.split(',').includes(event.type)
+    );
+    if (targets.length === 0) return 0;
+
+    const payload = JSON.stringify(event);
+    for (const endpoint of targets) {
+      await this.db.query(
+        `INSERT INTO outbound_webhook_deliveries (webhook_id, event_id, event_type, payload)
+         VALUES ($1, $2, $3, $4)`,
+        [endpoint.id, event.id, event.type, payload]
+      );
+    }
+
+    logger.debug(`🔗 Queued ${event.type} ${event.id} for ${targets.length} outbound webhook(s)`);
+    this.wake();
+    return targets.length;
+  }
+
+  /**
+   * Sign a request body
+   * @param {string} secret - Endpoint secret
+   * @param {string} timestamp - Unix seconds, sent as X-MintTech-Timestamp
+   * @param {string} body - Raw JSON body
+   * @returns {string} Signature header value
+   */
+  sign(secret, timestamp, body) {
+    const digest = crypto.createHmac('sha256', secret).update(`${timestamp}.${body}`).digest('hex');
+    return `sha256=${digest}`;
+  }
+
+  // ==================== DELIVERY WORKER ====================
+
+  start() {
+    if (this.running) return;
+    this.running = true;
+    this.schedule(0);
+    logger.info('🔗 Outbound webhook worker started');
+  }
+
+  async stop() {
+    this.running = false;
+    clearTimeout(this.timer);
+    this.timer = null;
+    if (this.busy) {
+      await this.busy;
+    }
+  }
+
+  wake() {
+    if (!this.running) return;
+    if (this.busy) {
+      this.wakeRequested = true;
+    } else {
+      this.schedule(0);
+    }
+  }
+
+  schedule(delayMs) {
+    clearTimeout(this.timer);
+    this.timer = setTimeout(() => this.tick(), delayMs);
+  }
+
+  async tick() {
+    if (this.busy || !this.running) return;
+
+    this.busy = (async () => {
+      try {
+        let claimed;
+        do {
+          claimed = await this.processBatch();
+        } while (claimed >= this.batchSize && this.running);
+
+        if (Date.now() - this.lastPrunedAt > PRUNE_INTERVAL_MS) {
+          await this.prune();
+        }
+      } catch (error) {
+        logger.error('Outbound webhook worker error:', error.message);
+      }
+    })();
+
+    await this.busy;
+    this.busy = null;
+
+    if (this.running) {
+      this.schedule(this.wakeRequested ? 0 : this.pollIntervalMs);
+      this.wakeRequested = false;
+    }
+  }
+
+  async processBatch() {
+    // Give up leases held by a worker that died mid-delivery
+    await this.db.query(
+      `UPDATE outbound_webhook_deliveries
+       SET status = 'pending', locked_until = NULL
+       WHERE status = 'sending' AND locked_until < NOW()`
+    );
+
+    const due = await this.db.all(
+      `SELECT d.*, ow.url, ow.secret, ow.is_active
+       FROM outbound_webhook_deliveries d
+       JOIN outbound_webhooks ow ON ow.id = d.webhook_id
+       WHERE d.status = 'pending' AND d.next_attempt_at <= NOW()
+       ORDER BY d.id
+       LIMIT $1`,
+      [this.batchSize]
+    );
+
+    const claimed = [];
+    for (const row of due) {
+      const result = await this.db.query(
+        `UPDATE outbound_webhook_deliveries
+         SET status = 'sending', locked_until = $2
+         WHERE id = $1 AND status = 'pending'
+         RETURNING id`,
+        [row.id, new Date(Date.now() + LEASE_MS)]
+      );
+      if (result.rows.length > 0) {
+        claimed.push(row);
+      }
+    }
+
+    await Promise.all(claimed.map(row => this.deliver(row)));
+    return claimed.length;
+  }
+
+  async deliver(row) {
+    const attempts = parseInt(row.attempts, 10) + 1;
+
+    if (!row.is_active) {
+      await this.deadLetter(row, attempts, null, 'Endpoint disabled');
+      return;
+    }
+
+    // Endpoints registered before a validation rule was added are checked again here
+    const validation = this.validateUrl(row.url);
+    if (!validation.isValid) {
+      await this.disableEndpoint(row.webhook_id, validation.reason);
+      await this.deadLetter(row, attempts, null, validation.reason);
+      return;
+    }
+
+    const timestamp = Math.floor(Date.now() / 1000).toString();
+    try {
+      const response = await axios.post(row.url, row.payload, {
+        timeout: this.timeoutMs,
+        maxRedirects: 0,
+        httpsAgent: this.httpsAgent,
+        validateStatus: () => true,
+        headers: {
+          'Content-Type': 'application/json',
+          'User-Agent': USER_AGENT,
+          'X-MintTech-Event': row.event_type,
+          'X-MintTech-Event-Id': row.event_id,
+          'X-MintTech-Delivery': String(row.id),
+          'X-MintTech-Timestamp': timestamp,
+          'X-MintTech-Signature': this.sign(row.secret, timestamp, row.payload)
+        }
+      });
+
+      if (response.status >= 200 && response.status < 300) {
+        await this.db.query(
+          `UPDATE outbound_webhook_deliveries
+           SET status = 'delivered', attempts = $2, last_status_code = $3, delivered_at = NOW(), locked_until = NULL, last_error = NULL
+           WHERE id = $1`,
+          [row.id, attempts, response.status]
+        );
+        await this.db.query(
+          'UPDATE outbound_webhooks SET failure_count = 0, last_success_at = NOW() WHERE id = $1',
+          [row.webhook_id]
+        );
+        logger.debug(`✅ Outbound webhook delivery #${row.id} accepted by ${row.url} (${response.status})`);
+        return;
+      }
+
+      if (response.status === 410) {
+        // The receiver asked us to stop
+        await this.disableEndpoint(row.webhook_id, 'Endpoint returned 410 Gone');
+        await this.deadLetter(row, attempts, response.status, 'Endpoint returned 410 Gone');
+        return;
+      }
+
+      await this.handleFailure(row, attempts, response.status, `HTTP ${response.status}`);
+    } catch (error) {
+      await this.handleFailure(row, attempts, null, error.code || error.message);
+    }
+  }
+
+  async handleFailure(row, attempts, statusCode, description) {
+    const result = await this.db.query(
+      `UPDATE outbound_webhooks
+       SET failure_count = failure_count + 1, last_failure_at = NOW(), last_error = $2
+       WHERE id = $1
+       RETURNING failure_count`,
+      [row.webhook_id, description]
+    );
+    const failureCount = parseInt(result.rows[0]?.failure_count, 10) || 0;
+
+    if (failureCount >= this.disableAfter) {
+      await this.disableEndpoint(row.webhook_id, `${failureCount} consecutive failures`);
+    }
+
+    if (attempts >= this.maxAttempts || failureCount >= this.disableAfter) {
+      await this.deadLetter(row, attempts, statusCode, description);
+      return;
+    }
+
+    const delayMs = this.getRetryDelay(attempts);
+    await this.db.query(
+      `UPDATE outbound_webhook_deliveries
+       SET status = 'pending', attempts = $2, last_status_code = $3, last_error = $4, next_attempt_at = $5, locked_until = NULL
+       WHERE id = $1`,
+      [row.id, attempts, statusCode, description, new Date(Date.now() + delayMs)]
+    );
+    logger.warn(`🔁 Outbound webhook delivery #${row.id} to ${row.url} failed (attempt ${attempts}/${this.maxAttempts}), retrying in ${Math.round(delayMs / 1000)}s: ${description}`);
+  }
+
+  getRetryDelay(attempts) {
+    const delay = Math.min(BASE_RETRY_DELAY_MS * 2 ** (attempts - 1), MAX_RETRY_DELAY_MS);
+    return Math.round(delay * (0.8 + Math.random() * 0.4));
+  }
+
+  async deadLetter(row, attempts, statusCode, lastError) {
+    await this.db.query(
+      `UPDATE outbound_webhook_deliveries
+       SET status = 'dead', attempts = $2, last_status_code = $3, last_error = $4, locked_until = NULL
+       WHERE id = $1`,
+      [row.id, attempts, statusCode, lastError]
+    );
+    logger.error(`💀 Outbound webhook delivery #${row.id} to ${row.url} dead-lettered after ${attempts} attempt(s): ${lastError}`);
+  }
+
+  async disableEndpoint(webhookId, reason) {
+    await this.db.query(
+      'UPDATE outbound_webhooks SET is_active = false, last_error = $2 WHERE id = $1',
+      [webhookId, reason]
+    );
+    logger.warn(`🚫 Outbound webhook #${webhookId} disabled: ${reason}`);
+  }
+
+  async prune() {
+    this.lastPrunedAt = Date.now();
+    const deliveredBefore = new Date(Date.now() - DELIVERED_RETENTION_DAYS * 24 * 60 * 60 * 1000);
+    const deadBefore = new Date(Date.now() - DEAD_RETENTION_DAYS * 24 * 60 * 60 * 1000);
+
+    const result = await this.db.query(
+      `DELETE FROM outbound_webhook_deliveries
+       WHERE (status = 'delivered' AND delivered_at < $1) OR (status = 'dead' AND created_at < $2)`,
+      [deliveredBefore, deadBefore]
+    );
+    if (result.rowCount > 0) {
+      logger.debug(`Pruned ${result.rowCount} old outbound webhook deliveries`);
+    }
+  }
+
+  async getStats() {
+    const rows = await this.db.all('SELECT status, COUNT(*) AS count FROM outbound_webhook_deliveries GROUP BY status');
+    const stats = { pending: 0, sending: 0, delivered: 0, dead: 0 };
+    rows.forEach(row => { stats[row.status] = parseInt(row.count, 10); });
+    return stats;
+  }
+}
+
+module.exports = OutboundWebhookService;
+module.exports.EVENT_TYPES = EVENT_TYPES;
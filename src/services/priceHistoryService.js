@@ -0,0 +1,302 @@
+const fs = require('fs');
+const path = require('path');
+const axios = require('axios');
+const logger = require('./logger');
+
+const HOUR_MS = 60 * 60 * 1000;
+// Hours fetched on each side of a missing candle, so nearby events find theirs stored
+const FETCH_WINDOW_HOURS = 24;
+// CoinGecko only has daily points for older dates; the last candle within a day still counts
+const MAX_CANDLE_AGE_HOURS = 24;
+const CRYPTOCOMPARE_LIMIT = 2000;
+const INSERT_BATCH_SIZE = 500;
+
+// CryptoCompare serves hourly OHLC by ticker; assets without one fall back to CoinGecko
+const CRYPTOCOMPARE_SYMBOLS = {
+  ethereum: 'ETH',
+  bitcoin: 'BTC',
+  solana: 'SOL',
+  'avalanche-2': 'AVAX',
+  apecoin: 'APE',
+  'usd-coin': 'USDC',
+  tether: 'USDT',
+  dai: 'DAI',
+  arbitrum: 'ARB',
+  optimism: 'OP',
+  blur: 'BLUR',
+  ronin: 'RON',
+  'sei-network': 'SEI',
+  'matic-network': 'MATIC',
+  binancecoin: 'BNB'
+};
+
+function toHour(time) {
+  const ms = new Date(time).getTime();
+  return Number.isFinite(ms) ? Math.floor(ms / HOUR_MS) * HOUR_MS : null;
+}
+
+/**
+ * Candle from a fixture: { time, open, high, low, close } or [time, open, high, low, close].
+ * Time is an ISO date, Unix seconds or milliseconds. A bare price is a flat candle.
+ */
+function parseFixtureCandle(entry) {
+  const [time, open, high, low, close] = Array.isArray(entry)
+    ? entry
+    : [entry.time ?? entry.hour, entry.open, entry.high, entry.low, entry.close ?? entry.price];
+  const hour = toHour(typeof time === 'number' && time < 1e12 ? time * 1000 : time);
+  const closePrice = Number(close ?? open);
+  if (hour == null || !(closePrice > 0)) return null;
+
+  const candle = { hour, open: Number(open ?? closePrice), high: Number(high ?? closePrice), low: Number(low ?? closePrice), close: closePrice };
+  return Object.values(candle).every(Number.isFinite) ? candle : null;
+}
+
+/**
+ * Hourly USD price history per asset, keyed by CoinGecko ID (the price source of the
+ * ChainManager payment token registry). Missing hours are fetched from CryptoCompare
+ * (hourly OHLC) or CoinGecko (price points, grouped by hour) and stored in price_candles.
+ *
+ * With PRICE_HISTORY_OFFLINE=true nothing is fetched and only stored candles are used;
+ * fixtures (PRICE_FIXTURES_DIR, scripts/priceFixtures.js) fill the store without network.
+ */
+class PriceHistoryService {
+  constructor(database) {
+    this.db = database;
+    this.offline = process.env.PRICE_HISTORY_OFFLINE === 'true';
+    this.fixturesDir = process.env.PRICE_FIXTURES_DIR || null;
+    this.fetching = new Map();
+    this.stats = { lookups: 0, misses: 0, fetchedCandles: 0, fixtureCandles: 0, fetchErrors: 0 };
+  }
+
+  /**
+   * Load the fixtures of PRICE_FIXTURES_DIR, if set
+   */
+  async start() {
+    if (!this.fixturesDir) return;
+    try {
+      await this.loadFixtureDir(this.fixturesDir);
+    } catch (error) {
+      logger.error(`❌ Could not load price fixtures from ${this.fixturesDir}:`, error);
+    }
+  }
+
+  /**
+   * USD price of an asset at a point in time: the close of its hourly candle, or of the
+   * latest candle in the day before when only daily data exists
+   * @param {string} asset - CoinGecko ID, e.g. ethereum
+   * @param {Date|string|number} time - Event time (block timestamp)
+   * @param {Object} options
+   * @param {boolean} options.wait - Wait for a missing candle to be fetched; without waiting,
+   *   the hours around it are fetched in the background for the next lookups
+   * @returns {Promise<number|null>} Null if no candle is stored or can be fetched
+   */
+  async getUsdRate(asset, time, { wait = true } = {}) {
+    const hour = toHour(time);
+    if (!asset || hour == null) return null;
+    this.stats.lookups++;
+
+    let candle = await this.findCandle(asset, hour);
+    if (!candle && !this.offline) {
+      const fetching = this.fetchAround(asset, hour);
+      if (wait) {
+        await fetching;
+        candle = await this.findCandle(asset, hour);
+      }
+    }
+    if (!candle) {
+      this.stats.misses++;
+      return null;
+    }
+    return Number(candle.close);
+  }
+
+  async findCandle(asset, hour) {
+    return this.db.get(
+      `SELECT hour, close FROM price_candles
+       WHERE asset = $1 AND hour <= $2 AND hour > $3
+       ORDER BY hour DESC LIMIT 1`,
+      [asset, new Date(hour), new Date(hour - MAX_CANDLE_AGE_HOURS * HOUR_MS)]
+    );
+  }
+
+  async fetchAround(asset, hour) {
+    const key = `${asset}:${hour}`;
+    if (!this.fetching.has(key)) {
+      const from = hour - FETCH_WINDOW_HOURS * HOUR_MS;
+      const to = Math.min(hour + FETCH_WINDOW_HOURS * HOUR_MS, toHour(Date.now()));
+      const run = this.fetchCandles(asset, from, to)
+        .then(({ candles, source }) => this.storeCandles(asset, candles, source))
+        .then(count => { this.stats.fetchedCandles += count; })
+        .catch(error => {
+          this.stats.fetchErrors++;
+          logger.warn(`Could not fetch ${asset} price history around ${new Date(hour).toISOString()}: ${error.message}`);
+        })
+        .finally(() => this.fetching.delete(key));
+      this.fetching.set(key, run);
+    }
+    return this.fetching.get(key);
+  }
+
+  /**
+   * Hourly candles of an asset from the price APIs
+   * @param {string} asset - CoinGecko ID
+   * @param {number} from - Start hour (ms)
+   * @param {number} to - End hour (ms), inclusive
+   * @returns {Promise<{candles: Array, source: string}>}
+   */
+  async fetchCandles(asset, from, to) {
+    const symbol = CRYPTOCOMPARE_SYMBOLS[asset];
+    if (symbol) {
+      try {
+        return { candles: await this.fetchCryptoCompareCandles(symbol, from, to), source: 'cryptocompare' };
+      } catch (error) {
+        logger.warn(`CryptoCompare history for ${symbol} failed, trying CoinGecko: ${error.message}`);
+      }
+    }
+    return { candles: await this.fetchCoinGeckoCandles(asset, from, to), source: 'coingecko' };
+  }
+
+  async fetchCryptoCompareCandles(symbol, from, to) {
+    const candles = [];
+    let toTs = Math.floor(to / 1000);
+    while (toTs * 1000 >= from) {
+      const limit = Math.min(CRYPTOCOMPARE_LIMIT, Math.ceil((toTs * 1000 - from) / HOUR_MS));
+      const response = await axios.get('https://min-api.cryptocompare.com/data/v2/histohour', {
+        params: { fsym: symbol, tsym: 'USD', limit: Math.max(1, limit), toTs },
+        timeout: 15000
+      });
+      if (response.data?.Response !== 'Success') {
+        throw new Error(response.data?.Message || 'Unexpected response');
+      }
+
+      const rows = response.data.Data?.Data || [];
+      for (const row of rows) {
+        if (row.close > 0 && row.time * 1000 >= from) {
+          candles.push({ hour: row.time * 1000, open: row.open, high: row.high, low: row.low, close: row.close });
+        }
+      }
+      if (rows.length === 0) break;
+      toTs = rows[0].time - 3600;
+    }
+    return candles;
+  }
+
+  async fetchCoinGeckoCandles(asset, from, to) {
+    const response = await axios.get(`https://api.coingecko.com/api/v3/coins/${asset}/market_chart/range`, {
+      params: { vs_currency: 'usd', from: Math.floor(from / 1000), to: Math.floor(to / 1000) + 3600 },
+      timeout: 15000
+    });
+
+    const byHour = new Map();
+    for (const [time, price] of response.data?.prices || []) {
+      const hour = toHour(time);
+      if (!(price > 0)) continue;
+      const candle = byHour.get(hour);
+      if (candle) {
+        candle.high = Math.max(candle.high, price);
+        candle.low = Math.min(candle.low, price);
+        candle.close = price;
+      } else {
+        byHour.set(hour, { hour, open: price, high: price, low: price, close: price });
+      }
+    }
+    return [...byHour.values()];
+  }
+
+  /**
+   * Insert or replace candles
+   * @returns {Promise<number>} Number of candles stored
+   */
+  async storeCandles(asset, candles, source) {
+    if (candles.length === 0) return 0;
+
+    await this.db.transaction(async (tx) => {
+      for (let start = 0; start < candles.length; start += INSERT_BATCH_SIZE) {
+        const batch = candles.slice(start, start + INSERT_BATCH_SIZE);
+        const params = [];
+        const rows = batch.map((candle, index) => {
+          params.push(asset, new Date(candle.hour), candle.open, candle.high, candle.low, candle.close, source);
+          const offset = index * 7;
+          return `(${Array.from({ length: 7 }, (_, column) => `$${offset + column + 1}`).join(', ')})`;
+        });
+        await tx.query(
+          `INSERT INTO price_candles (asset, hour, open, high, low, close, source) VALUES ${rows.join(', ')}
+           ON CONFLICT (asset, hour) DO UPDATE SET
+             open = EXCLUDED.open,
+             high = EXCLUDED.high,
+             low = EXCLUDED.low,
+             close = EXCLUDED.close,
+             source = EXCLUDED.source`,
+          params
+        );
+      }
+    });
+    return candles.length;
+  }
+
+  /**
+   * Store the candles of a fixture file: { asset, candles: [...] } or an array of those
+   * @param {string} file - Path to a JSON fixture
+   * @returns {Promise<number>} Number of candles stored
+   */
+  async loadFixtureFile(file) {
+    const fixtures = JSON.parse(fs.readFileSync(file, 'utf8'));
+    let count = 0;
+    for (const fixture of Array.isArray(fixtures) ? fixtures : [fixtures]) {
+      if (!fixture?.asset || !Array.isArray(fixture.candles)) {
+        throw new Error(`${file}: a fixture needs "asset" and "candles"`);
+      }
+      const candles = fixture.candles.map(parseFixtureCandle).filter(Boolean);
+      if (candles.length < fixture.candles.length) {
+        logger.warn(`${file}: skipped ${fixture.candles.length - candles.length} unreadable ${fixture.asset} candle(s)`);
+      }
+      count += await this.storeCandles(fixture.asset, candles, 'fixture');
+    }
+    this.stats.fixtureCandles += count;
+    return count;
+  }
+
+  /**
+   * Load every .json fixture in a directory
+   * @returns {Promise<number>} Number of candles stored
+   */
+  async loadFixtureDir(dir) {
+    const files = fs.readdirSync(dir).filter(file => file.endsWith('.json')).sort();
+    let count = 0;
+    for (const file of files) {
+      count += await this.loadFixtureFile(path.join(dir, file));
+    }
+    logger.info(`📈 Loaded ${count} price candles from ${files.length} fixture file(s) in ${dir}`);
+    return count;
+  }
+
+  /**
+   * Stored candles of an asset as a fixture, e.g. to use them offline elsewhere
+   * @returns {Promise<{asset: string, candles: Array}>}
+   */
+  async exportFixture(asset, from, to) {
+    const rows = await this.db.all(
+      `SELECT hour, open, high, low, close FROM price_candles
+       WHERE asset = $1 AND hour >= $2 AND hour <= $3
+       ORDER BY hour`,
+      [asset, new Date(from), new Date(to)]
+    );
+    return {
+      asset,
+      candles: rows.map(row => [new Date(row.hour).toISOString(), Number(row.open), Number(row.high), Number(row.low), Number(row.close)])
+    };
+  }
+
+  async getStats() {
+    const row = await this.db.get('SELECT COUNT(*) AS count, COUNT(DISTINCT asset) AS assets FROM price_candles');
+    return {
+      candles: parseInt(row?.count, 10) || 0,
+      assets: parseInt(row?.assets, 10) || 0,
+      offline: this.offline,
+      ...this.stats
+    };
+  }
+}
+
+module.exports = PriceHistoryService;
+module.exports.parseFixtureCandle = parseFixtureCandle;
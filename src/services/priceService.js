@@ -60,36 +60,24 @@ class PriceService {
         'matic': 'matic-network',
         'bnb': 'binancecoin',
         'sol': 'solana', // Solana
+        'btc': 'bitcoin',
         'avax': 'avalanche-2',
         'arb': 'arbitrum',
-        'op': 'optimism'
+        'op': 'optimism',
+        'ape': 'apecoin',
+        'blur': 'blur',
+        'ron': 'ronin',
+        'sei': 'sei-network',
+        'bera': 'berachain-bera'
       };
 
-      const coinId = tokenMap[tokenSymbol.toLowerCase()] || 'ethereum';
-
-      // Use CoinGecko API (free tier, no API key needed)
-      const response = await fetch(
-        `https://api.coingecko.com/api/v3/simple/price?ids=${coinId}&vs_currencies=usd`,
-        {
-          headers: {
-            'Accept': 'application/json',
-          },
-          timeout: 10000
-        }
-      );
-
-      if (!response.ok) {
-        throw new Error(`API response not ok: ${response.status}`);
-      }
-
-      const data = await response.json();
-      const price = data[coinId]?.usd;
-
-      if (!price) {
-        throw new Error(`No price data found for ${coinId}`);
+      // Unknown tokens have no price rather than the ETH price
+      const coinId = tokenMap[tokenSymbol.toLowerCase()];
+      if (!coinId) {
+        throw new Error(`No price source for ${tokenSymbol}`);
       }
 
-      return price;
+      return await this.fetchCoinGeckoPrice(coinId);
     } catch (error) {
       logger.error(`Failed to fetch price from CoinGecko for ${tokenSymbol}:`, error);
 
@@ -108,6 +96,59 @@ class PriceService {
     }
   }
 
+  /**
+   * USD price by CoinGecko ID, the price source of the ChainManager payment token registry
+   * @param {string} coinGeckoId
+   * @returns {Promise<number|null>}
+   */
+  async getCoinPrice(coinGeckoId) {
+    const cacheKey = `id:${coinGeckoId}`;
+    const cached = this.cachedPrices.get(cacheKey);
+    if (cached && Date.now() - cached.timestamp < this.cacheTimeout) {
+      return cached.price;
+    }
+
+    try {
+      const price = await this.fetchCoinGeckoPrice(coinGeckoId);
+      this.cachedPrices.set(cacheKey, { price, timestamp: Date.now() });
+      logger.info(`💰 Fetched fresh price for ${coinGeckoId}: $${price}`);
+      return price;
+    } catch (error) {
+      logger.error(`Failed to get price for ${coinGeckoId}:`, error);
+      if (cached) {
+        logger.warn(`Using expired cached price for ${coinGeckoId}: $${cached.price}`);
+        return cached.price;
+      }
+      return null;
+    }
+  }
+
+  async fetchCoinGeckoPrice(coinId) {
+    // Use CoinGecko API (free tier, no API key needed)
+    const response = await fetch(
+      `https://api.coingecko.com/api/v3/simple/price?ids=${coinId}&vs_currencies=usd`,
+      {
+        headers: {
+          'Accept': 'application/json',
+        },
+        timeout: 10000
+      }
+    );
+
+    if (!response.ok) {
+      throw new Error(`API response not ok: ${response.status}`);
+    }
+
+    const data = await response.json();
+    const price = data[coinId]?.usd;
+
+    if (!price) {
+      throw new Error(`No price data found for ${coinId}`);
+    }
+
+    return price;
+  }
+
   async fetchEthPriceFromAlternativeAPI() {
     try {
       // Fallback to CryptoCompare API
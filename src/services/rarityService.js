@@ -0,0 +1,386 @@
+const axios = require('axios');
+const logger = require('./logger');
+
+const DEFAULT_MAX_ITEMS = 20000;
+const DEFAULT_REFRESH_HOURS = 24;
+const INSERT_BATCH_SIZE = 500;
+const HIGHLIGHTED_TRAITS = 3;
+const ITEM_CACHE_TTL = 5 * 60 * 1000;
+const ITEM_CACHE_SIZE = 2000;
+const NONE_VALUE = 'None';
+
+const SOLANA_ADDRESS_PATTERN = /^[1-9A-HJ-NP-Za-km-z]{32,44}$/;
+
+/**
+ * Trait rarity and statistical rank of every item in a collection.
+ *
+ * Each item's score is the information content of its traits, -Σ log(share), where a
+ * trait type the item lacks counts as the value "None". This orders items the same way
+ * as the product of their trait frequencies (statistical rarity): the highest score is
+ * rank 1, and equal scores share a rank.
+ *
+ * @param {Array<{id: string, attributes: Array}>} items - Items with their metadata attributes
+ * @returns {Object|null} { itemCount, traitTypeCount, ranks: [{ id, rank, score, traits }] }, null without traits
+ */
+function computeRarity(items) {
+  const normalized = items.map(item => ({
+    id: item.id,
+    traits: (Array.isArray(item.attributes) ? item.attributes : [])
+      .filter(attr => attr && attr.trait_type != null && attr.value != null && attr.value !== '')
+      .map(attr => ({ type: String(attr.trait_type), value: String(attr.value) }))
+  }));
+
+  const traitTypes = new Set();
+  const counts = new Map();
+  const key = (type, value) => `${type}\u0000${value}`;
+  for (const item of normalized) {
+    for (const trait of item.traits) {
+      traitTypes.add(trait.type);
+      counts.set(key(trait.type, trait.value), (counts.get(key(trait.type, trait.value)) || 0) + 1);
+    }
+  }
+  if (traitTypes.size === 0 || normalized.length < 2) {
+    return null;
+  }
+
+  // Items without a trait type share its "None" value
+  const withType = new Map();
+  for (const item of normalized) {
+    for (const type of new Set(item.traits.map(trait => trait.type))) {
+      withType.set(type, (withType.get(type) || 0) + 1);
+    }
+  }
+
+  const total = normalized.length;
+  const scored = normalized.map(item => {
+    const traits = item.traits.map(trait => ({ ...trait, count: counts.get(key(trait.type, trait.value)) }));
+    const present = new Set(traits.map(trait => trait.type));
+    let score = traits.reduce((sum, trait) => sum - Math.log(trait.count / total), 0);
+    for (const type of traitTypes) {
+      if (!present.has(type)) {
+        score -= Math.log((total - withType.get(type)) / total);
+      }
+    }
+    traits.sort((a, b) => a.count - b.count);
+    return { id: item.id, score, traits };
+  });
+
+  scored.sort((a, b) => b.score - a.score);
+  let rank = 0;
+  scored.forEach((item, index) => {
+    if (index === 0 || item.score < scored[index - 1].score - 1e-9) {
+      rank = index + 1;
+    }
+    item.rank = rank;
+  });
+
+  return { itemCount: total, traitTypeCount: traitTypes.size, ranks: scored };
+}
+
+/**
+ * Rarity engine for tracked collections.
+ *
+ * A snapshot reads the metadata of every item (Alchemy NFT API for EVM chains, Helius DAS
+ * for Solana, Magic Eden for Ordinals), ranks them with computeRarity and replaces the
+ * collection's rows in nft_rarity. TokenTracker.updateTokenData refreshes snapshots that
+ * are older than RARITY_REFRESH_HOURS; collections above RARITY_MAX_ITEMS are skipped.
+ * The stored traits are also the trait index that subscription trait filters are checked against.
+ */
+class RarityService {
+  constructor(database, { chainManager = null, magicEden = null, magicEdenOrdinals = null, helius = null } = {}) {
+    this.db = database;
+    this.chainManager = chainManager;
+    this.magicEden = magicEden;
+    this.magicEdenOrdinals = magicEdenOrdinals;
+    this.helius = helius;
+    this.maxItems = parseInt(process.env.RARITY_MAX_ITEMS, 10) || DEFAULT_MAX_ITEMS;
+    this.refreshHours = parseFloat(process.env.RARITY_REFRESH_HOURS) || DEFAULT_REFRESH_HOURS;
+    this.refreshing = new Set();
+    // Snapshots run one at a time; the periodic token update asks for all of them at once
+    this.queue = Promise.resolve();
+    // Recently read nft_rarity rows: one event is looked up by every subscription and by the message
+    this.itemCache = new Map();
+    this.stats = { snapshots: 0, failed: 0, skipped: 0 };
+  }
+
+  /**
+   * Take a new snapshot when the stored one is missing or older than RARITY_REFRESH_HOURS
+   * @param {Object} token - Tracked token row
+   * @returns {Promise<boolean>} True if a snapshot was stored
+   */
+  async refreshIfStale(token) {
+    const row = await this.db.get('SELECT refreshed_at FROM collection_rarity WHERE token_id = $1', [token.id]);
+    if (row?.refreshed_at && Date.now() - new Date(row.refreshed_at).getTime() < this.refreshHours * 60 * 60 * 1000) {
+      return false;
+    }
+    return this.refresh(token);
+  }
+
+  /**
+   * Snapshot a collection's metadata and store the ranks of its items
+   * @param {Object} token - Tracked token row
+   * @returns {Promise<boolean>} True if a snapshot was stored
+   */
+  async refresh(token) {
+    if (this.refreshing.has(token.id)) return false;
+    this.refreshing.add(token.id);
+
+    const run = this.queue.then(() => this.takeSnapshot(token));
+    this.queue = run.catch(() => {});
+    return run;
+  }
+
+  async takeSnapshot(token) {
+    try {
+      const snapshot = await this.fetchItems(token);
+      if (!snapshot) {
+        this.stats.skipped++;
+        return false;
+      }
+
+      const rarity = computeRarity(snapshot.items);
+      if (!rarity) {
+        logger.info(`🏆 ${token.token_name} has no traits to rank (${snapshot.items.length} items)`);
+        this.stats.skipped++;
+        return false;
+      }
+
+      await this.store(token.id, snapshot.source, rarity);
+      this.stats.snapshots++;
+      logger.info(`🏆 Ranked ${rarity.itemCount} ${token.token_name} items by ${rarity.traitTypeCount} trait types (${snapshot.source})`);
+      return true;
+    } catch (error) {
+      this.stats.failed++;
+      logger.error(`❌ Rarity snapshot of ${token.token_name} (${token.chain_name}) failed:`, error);
+      return false;
+    } finally {
+      this.refreshing.delete(token.id);
+    }
+  }
+
+  async store(tokenId, source, rarity) {
+    await this.db.transaction(async (tx) => {
+      await tx.run('DELETE FROM nft_rarity WHERE token_id = $1', [tokenId]);
+
+      for (let start = 0; start < rarity.ranks.length; start += INSERT_BATCH_SIZE) {
+        const batch = rarity.ranks.slice(start, start + INSERT_BATCH_SIZE);
+        const params = [];
+        const rows = batch.map((item, index) => {
+          const traits = item.traits.map(trait => [trait.type, trait.value, trait.count]);
+          params.push(tokenId, item.id, item.rank, item.score, JSON.stringify(traits));
+          const offset = index * 5;
+          return `($${offset + 1}, $${offset + 2}, $${offset + 3}, $${offset + 4}, $${offset + 5})`;
+        });
+        await tx.query(
+          `INSERT INTO nft_rarity (token_id, nft_id, rank, score, traits) VALUES ${rows.join(', ')}
+           ON CONFLICT (token_id, nft_id) DO NOTHING`,
+          params
+        );
+      }
+
+      await tx.query(
+        `INSERT INTO collection_rarity (token_id, source, item_count, trait_type_count, refreshed_at)
+         VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (token_id) DO UPDATE SET
+           source = EXCLUDED.source,
+           item_count = EXCLUDED.item_count,
+           trait_type_count = EXCLUDED.trait_type_count,
+           refreshed_at = EXCLUDED.refreshed_at`,
+        [tokenId, source, rarity.itemCount, rarity.traitTypeCount, new Date()]
+      );
+    });
+
+    for (const key of this.itemCache.keys()) {
+      if (key.startsWith(`${tokenId}:`)) this.itemCache.delete(key);
+    }
+  }
+
+  /**
+   * Stored snapshot row of one item, cached for a few minutes
+   * @returns {Promise<{rank: number, itemCount: number, traits: Array<[string, string, number]>}|null>}
+   */
+  async getItem(tokenId, nftId) {
+    if (!tokenId || nftId == null) return null;
+
+    const key = `${tokenId}:${nftId}`;
+    const cached = this.itemCache.get(key);
+    if (cached && cached.expires > Date.now()) {
+      return cached.item;
+    }
+
+    const row = await this.db.get(
+      `SELECT r.rank, r.traits, c.item_count
+       FROM nft_rarity r
+       JOIN collection_rarity c ON c.token_id = r.token_id
+       WHERE r.token_id = $1 AND r.nft_id = $2`,
+      [tokenId, String(nftId)]
+    );
+
+    let item = null;
+    if (row) {
+      let traits = [];
+      try {
+        traits = JSON.parse(row.traits || '[]');
+      } catch (error) {
+        logger.warn(`Unreadable traits of ${nftId} (token ${tokenId})`);
+      }
+      item = { rank: row.rank, itemCount: row.item_count, traits };
+    }
+
+    if (this.itemCache.size >= ITEM_CACHE_SIZE) {
+      this.itemCache.delete(this.itemCache.keys().next().value);
+    }
+    this.itemCache.set(key, { item, expires: Date.now() + ITEM_CACHE_TTL });
+    return item;
+  }
+
+  /**
+   * Rank and rarest traits of one item
+   * @param {number} tokenId - tracked_tokens.id
+   * @param {string} nftId - Token ID, Solana mint address or inscription ID
+   * @returns {Promise<Object|null>} { rank, itemCount, traits: [{ type, value, share }] }, null if not ranked
+   */
+  async getItemRarity(tokenId, nftId) {
+    const item = await this.getItem(tokenId, nftId);
+    if (!item) return null;
+
+    return {
+      rank: item.rank,
+      itemCount: item.itemCount,
+      traits: item.traits.slice(0, HIGHLIGHTED_TRAITS).map(([type, value, count]) => ({
+        type,
+        value,
+        share: count / item.itemCount
+      }))
+    };
+  }
+
+  /**
+   * Traits of one item from the collection's snapshot, for trait filters
+   * @param {number} tokenId - tracked_tokens.id
+   * @param {string} nftId - Token ID, Solana mint address or inscription ID
+   * @returns {Promise<Array<{type: string, value: string}>|null>} Null if the item isn't in a snapshot
+   */
+  async getItemTraits(tokenId, nftId) {
+    const item = await this.getItem(tokenId, nftId);
+    return item ? item.traits.map(([type, value]) => ({ type, value })) : null;
+  }
+
+  /**
+   * Items of a collection with their attributes
+   * @returns {Promise<{source: string, items: Array}|null>} Null if the chain has no source or the collection is too large
+   */
+  async fetchItems(token) {
+    if (token.chain_name === 'solana') return this.fetchSolanaItems(token);
+    if (token.chain_name === 'bitcoin') return this.fetchOrdinalsItems(token);
+    return this.fetchEvmItems(token);
+  }
+
+  async fetchEvmItems(token) {
+    const rpcUrl = this.chainManager?.getRpcUrl(token.chain_name || 'ethereum');
+    if (!rpcUrl || !rpcUrl.includes('.g.alchemy.com/v2/')) {
+      logger.debug(`No Alchemy NFT API for ${token.chain_name}, skipping rarity of ${token.token_name}`);
+      return null;
+    }
+
+    const url = `${rpcUrl.replace('/v2/', '/nft/v3/')}/getNFTsForContract`;
+    const items = [];
+    let pageKey;
+    do {
+      const response = await axios.get(url, {
+        params: { contractAddress: token.contract_address, withMetadata: true, limit: 100, pageKey },
+        timeout: 30000
+      });
+      for (const nft of response.data?.nfts || []) {
+        items.push({ id: String(nft.tokenId), attributes: nft.raw?.metadata?.attributes });
+      }
+      pageKey = response.data?.pageKey;
+      if (items.length > this.maxItems) return this.tooLarge(token);
+    } while (pageKey);
+
+    return { source: 'alchemy', items };
+  }
+
+  async fetchSolanaItems(token) {
+    if (!this.helius) return null;
+
+    const collectionAddress = await this.resolveSolanaCollection(token);
+    if (!collectionAddress) {
+      logger.warn(`Could not find the collection address of ${token.token_name} for its rarity snapshot`);
+      return null;
+    }
+
+    const items = [];
+    for (let page = 1; ; page++) {
+      const assets = await this.helius.getAssetsByCollection(collectionAddress, page);
+      if (!assets) throw new Error(`Helius page ${page} of ${collectionAddress} failed`);
+      for (const asset of assets) {
+        items.push({ id: asset.id, attributes: asset.content?.metadata?.attributes });
+      }
+      if (items.length > this.maxItems) return this.tooLarge(token);
+      if (assets.length < 1000) break;
+    }
+
+    return { source: 'helius', items };
+  }
+
+  /**
+   * Verified collection address of a tracked Solana collection, read from the tracked
+   * mint or, for collections added by symbol, from one of its Magic Eden listings
+   */
+  async resolveSolanaCollection(token) {
+    const candidates = [];
+    if (SOLANA_ADDRESS_PATTERN.test(token.contract_address || '')) {
+      candidates.push(token.contract_address);
+    }
+    if (this.magicEden && token.collection_slug) {
+      const listings = await this.magicEden.getCollectionListings(token.collection_slug, 1);
+      if (listings[0]?.tokenMint) candidates.push(listings[0].tokenMint);
+    }
+
+    for (const mint of candidates) {
+      const collectionAddress = await this.helius.getCollectionAddress(mint);
+      if (collectionAddress) return collectionAddress;
+    }
+    // A collection NFT itself has no collection grouping
+    return candidates[0] === token.contract_address ? token.contract_address : null;
+  }
+
+  async fetchOrdinalsItems(token) {
+    const symbol = token.collection_slug || token.contract_address;
+    if (!this.magicEdenOrdinals || !symbol) return null;
+
+    const items = [];
+    for (let offset = 0; ; offset += 100) {
+      const tokens = await this.magicEdenOrdinals.getCollectionTokens(symbol, offset, 100);
+      if (!tokens) throw new Error(`Magic Eden page at ${offset} of ${symbol} failed`);
+      for (const inscription of tokens) {
+        items.push({ id: inscription.id, attributes: inscription.meta?.attributes });
+      }
+      if (items.length > this.maxItems) return this.tooLarge(token);
+      if (tokens.length < 100) break;
+    }
+
+    return { source: 'magiceden_ordinals', items };
+  }
+
+  tooLarge(token) {
+    logger.info(`🏆 ${token.token_name} has more than ${this.maxItems} items, skipping its rarity snapshot`);
+    return null;
+  }
+
+  async getStats() {
+    const row = await this.db.get('SELECT COUNT(*) AS count FROM collection_rarity');
+    return {
+      collections: parseInt(row?.count, 10) || 0,
+      refreshing: this.refreshing.size,
+      cachedItems: this.itemCache.size,
+      refreshHours: this.refreshHours,
+      maxItems: this.maxItems,
+      ...this.stats
+    };
+  }
+}
+
+module.exports = RarityService;
+module.exports.computeRarity = computeRarity;
@@ -0,0 +1,250 @@
+const { ethers } = require('ethers');
+const logger = require('./logger');
+const { marketplaceRegistry } = require('./marketplaceRegistry');
+
+// Items of one sweep share a receipt; it is fetched once and kept for a few minutes
+const RECEIPT_CACHE_TTL_MS = 5 * 60 * 1000;
+const RECEIPT_CACHE_SIZE = 500;
+const BLOCK_CACHE_SIZE = 500;
+const ERC20_METADATA_ABI = [
+  'function decimals() view returns (uint8)',
+  'function symbol() view returns (string)'
+];
+// Sources that stamp on-chain events with their delivery time instead of the block time
+const DELIVERY_TIME_SOURCES = ['alchemy', 'opensea'];
+// Event types a receipt can turn into (or confirm as) a sale
+const ATTRIBUTED_TYPES = ['sale', 'transfer'];
+const ZERO_ADDRESS = '0x0000000000000000000000000000000000000000';
+
+function isZeroAddress(address) {
+  return !address || address.toLowerCase() === ZERO_ADDRESS;
+}
+
+/**
+ * Reads EVM sales from transaction receipts with the marketplace registry: which marketplace
+ * filled the order, the price paid for each item and the royalty. Alchemy only reports the
+ * token movement (and the transaction value at best), so its events get all three; events from
+ * marketplace feeds keep their own price and marketplace and only gain the royalty.
+ */
+class SaleAttributionService {
+  constructor({ chainManager = null, registry = marketplaceRegistry } = {}) {
+    this.chainManager = chainManager;
+    this.registry = registry;
+    this.providers = new Map();
+    this.receipts = new Map();
+    this.pending = new Map();
+    this.blockTimes = new Map();
+    this.paymentTokens = new Map();
+    this.stats = { receipts: 0, cacheHits: 0, attributed: 0, blockTimes: 0, errors: 0 };
+  }
+
+  getProvider(chain) {
+    const config = this.chainManager?.getChain(chain);
+    if (!config?.rpcUrl || config.isSolana || config.isBitcoin) return null;
+
+    if (!this.providers.has(chain)) {
+      this.providers.set(chain, new ethers.JsonRpcProvider(config.rpcUrl));
+    }
+    return this.providers.get(chain);
+  }
+
+  /**
+   * Decoded sales of a transaction, cached per transaction
+   * @returns {Promise<{sales: Array<Object>, marketplaces: Array<string>, blockNumber: number}|null>} Null when the receipt can't be read
+   */
+  async getTransactionSales(chain, txHash) {
+    const key = `${chain}:${txHash.toLowerCase()}`;
+    const cached = this.receipts.get(key);
+    if (cached && cached.expiresAt > Date.now()) {
+      this.stats.cacheHits++;
+      return cached.result;
+    }
+    if (this.pending.has(key)) {
+      return this.pending.get(key);
+    }
+
+    const provider = this.getProvider(chain);
+    if (!provider) return null;
+
+    const request = (async () => {
+      try {
+        const receipt = await provider.getTransactionReceipt(txHash);
+        if (!receipt) return null;
+
+        this.stats.receipts++;
+        const result = { ...this.registry.decodeReceipt(chain, receipt), blockNumber: receipt.blockNumber };
+        this.cacheReceipt(key, result);
+        return result;
+      } catch (error) {
+        this.stats.errors++;
+        logger.warn(`Could not read the receipt of ${txHash} on ${chain}: ${error.message}`);
+        return null;
+      } finally {
+        this.pending.delete(key);
+      }
+    })();
+    this.pending.set(key, request);
+    return request;
+  }
+
+  cacheReceipt(key, result) {
+    if (this.receipts.size >= RECEIPT_CACHE_SIZE) {
+      this.receipts.delete(this.receipts.keys().next().value);
+    }
+    this.receipts.set(key, { result, expiresAt: Date.now() + RECEIPT_CACHE_TTL_MS });
+  }
+
+  /**
+   * Timestamp of a block, cached per block (the items of a sweep share it)
+   * @returns {Promise<string|null>} ISO time, null when the block can't be read
+   */
+  async getBlockTime(chain, blockNumber) {
+    const key = `${chain}:${blockNumber}`;
+    if (this.blockTimes.has(key)) {
+      return this.blockTimes.get(key);
+    }
+
+    const provider = this.getProvider(chain);
+    if (!provider) return null;
+
+    try {
+      const block = await provider.getBlock(blockNumber);
+      if (!block) return null;
+
+      this.stats.blockTimes++;
+      const time = new Date(Number(block.timestamp) * 1000).toISOString();
+      if (this.blockTimes.size >= BLOCK_CACHE_SIZE) {
+        this.blockTimes.delete(this.blockTimes.keys().next().value);
+      }
+      this.blockTimes.set(key, time);
+      return time;
+    } catch (error) {
+      this.stats.errors++;
+      logger.warn(`Could not read block ${blockNumber} on ${chain}: ${error.message}`);
+      return null;
+    }
+  }
+
+  /**
+   * Symbol and decimals of the token a sale was paid in: the chain's payment token registry
+   * first, then the ERC-20 contract itself, whose answer is cached per token.
+   * @param {string|null} address - Lowercase ERC-20 address, null for the native currency
+   * @returns {Promise<{symbol: string, decimals: number}|null>} Null when the token can't be read
+   */
+  async getPaymentToken(chain, address) {
+    if (!address) {
+      const native = this.chainManager?.getNativePaymentToken(chain);
+      return {
+        symbol: native?.symbol || this.chainManager?.getCurrencySymbol(chain) || 'ETH',
+        decimals: native?.decimals ?? 18
+      };
+    }
+
+    const known = this.chainManager?.findPaymentToken(chain, { address });
+    if (known) {
+      return { symbol: known.symbol, decimals: known.decimals };
+    }
+
+    const key = `${chain}:${address}`;
+    if (this.paymentTokens.has(key)) {
+      return this.paymentTokens.get(key);
+    }
+
+    const provider = this.getProvider(chain);
+    if (!provider) return null;
+
+    try {
+      const contract = new ethers.Contract(address, ERC20_METADATA_ABI, provider);
+      const [decimals, symbol] = await Promise.all([contract.decimals(), contract.symbol()]);
+      const token = { symbol, decimals: Number(decimals) };
+      this.paymentTokens.set(key, token);
+      return token;
+    } catch (error) {
+      this.stats.errors++;
+      logger.warn(`Could not read payment token ${address} on ${chain}: ${error.message}`);
+      return null;
+    }
+  }
+
+  /**
+   * Replace the delivery time Alchemy and OpenSea stamp on-chain events with by the block
+   * timestamp, so replays and late deliveries are valued at the price of their own hour.
+   * The block number comes from the event or, without one, from the transaction receipt.
+   * @param {ActivityEvent} event - Updated in place; keeps its time when the block can't be read
+   */
+  async stampBlockTime(event) {
+    if (!DELIVERY_TIME_SOURCES.includes(event.source) || !event.txHash) return;
+
+    let blockNumber = event.blockNumber;
+    if (blockNumber == null) {
+      const result = await this.getTransactionSales(event.chain, event.txHash);
+      blockNumber = result?.blockNumber ?? null;
+    }
+    if (blockNumber == null) return;
+
+    const time = await this.getBlockTime(event.chain, blockNumber);
+    if (time) {
+      event.occurredAt = time;
+    }
+  }
+
+  /**
+   * Complete an on-chain EVM event from its transaction receipt. A transfer paid for through
+   * a registered marketplace becomes a sale; Alchemy events take the decoded marketplace and
+   * item price (left unset when its token can't be read); every sale gets its royalty (raw
+   * amount in the price's token) when known.
+   * @param {ActivityEvent} event - Updated in place
+   * @returns {Promise<boolean>} True if the event matched a sale in the receipt
+   */
+  async attribute(event) {
+    if (!ATTRIBUTED_TYPES.includes(event.type) || !event.txHash || !event.contractAddress || event.tokenId == null) {
+      return false;
+    }
+
+    const result = await this.getTransactionSales(event.chain, event.txHash);
+    if (!result) return false;
+
+    const contractAddress = event.contractAddress.toLowerCase();
+    const sale = result.sales.find(item => item.tokenId === event.tokenId && item.collection === contractAddress);
+    if (!sale) {
+      if (!event.marketplace && result.marketplaces.length > 0) {
+        event.marketplace = result.marketplaces[0];
+      }
+      return false;
+    }
+
+    const fromFeed = event.source !== 'alchemy';
+    event.type = 'sale';
+    if (sale.marketplace && (!fromFeed || !event.marketplace)) {
+      event.marketplace = sale.marketplace;
+    }
+    if (sale.price && (!fromFeed || !event.price)) {
+      const paymentToken = await this.getPaymentToken(event.chain, sale.price.address);
+      event.price = paymentToken ? {
+        raw: sale.price.raw.toString(),
+        decimals: paymentToken.decimals,
+        currency: paymentToken.symbol,
+        address: sale.price.address,
+        usd: null,
+        native: null
+      } : null;
+    }
+    const priceToken = isZeroAddress(event.price?.address) ? null : event.price?.address.toLowerCase();
+    if (sale.royalty != null && sale.price && event.price && priceToken === sale.price.address) {
+      event.royalty = sale.royalty.toString();
+    }
+
+    this.stats.attributed++;
+    return true;
+  }
+
+  getStats() {
+    return {
+      ...this.registry.getStats(),
+      cachedReceipts: this.receipts.size,
+      ...this.stats
+    };
+  }
+}
+
+module.exports = SaleAttributionService;
@@ -1917,8 +1917,8 @@ class SecureTrendingService {
       const imageFeePayment = await this.db.get(
         `SELECT * FROM image_fee_payments
          WHERE LOWER(contract_address) = LOWER($1)
-         AND is_active = 1
-         AND end_time > datetime('now')
+         AND is_active = true
+         AND end_time > NOW()
          ORDER BY created_at DESC
          LIMIT 1`,
         [contractAddress]
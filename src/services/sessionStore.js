@@ -0,0 +1,230 @@
+const logger = require('./logger');
+
+const DEFAULT_TTL_HOURS = 24;
+const CLEANUP_INTERVAL_MS = 10 * 60 * 1000;
+
+// Payment amounts are kept as BigInt (wei/lamports), which JSON can't represent natively
+const serialize = (value) => JSON.stringify(value, (key, item) =>
+  (typeof item === 'bigint' ? { $bigint: item.toString() } : item));
+const deserialize = (text) => JSON.parse(text, (key, item) =>
+  (item && typeof item === 'object' && typeof item.$bigint === 'string' ? BigInt(item.$bigint) : item));
+
+/**
+ * Map that writes every change through to the bot_sessions table.
+ *
+ * Reads are served from memory; writes are queued so the database always ends up
+ * in the same order as the in-memory changes. Entries expire `ttlMs` after their
+ * last write. Values that can't be stored as JSON (e.g. timers) stay in memory only.
+ */
+class PersistentMap extends Map {
+  constructor(store, namespace, ttlMs) {
+    super();
+    this.store = store;
+    this.namespace = namespace;
+    this.ttlMs = ttlMs;
+    this.expiresAt = new Map();
+  }
+
+  isExpired(key) {
+    const expiresAt = this.expiresAt.get(key);
+    return expiresAt !== undefined && expiresAt <= Date.now();
+  }
+
+  get(key) {
+    if (this.isExpired(key)) {
+      this.delete(key);
+      return undefined;
+    }
+    return super.get(key);
+  }
+
+  has(key) {
+    if (this.isExpired(key)) {
+      this.delete(key);
+      return false;
+    }
+    return super.has(key);
+  }
+
+  set(key, value) {
+    super.set(key, value);
+
+    if (!SessionStore.isPersistable(value)) {
+      if (this.expiresAt.delete(key)) {
+        this.store.remove(this.namespace, key);
+      }
+      return this;
+    }
+
+    const expiresAt = Date.now() + this.ttlMs;
+    this.expiresAt.set(key, expiresAt);
+    this.store.save(this.namespace, key, value, new Date(expiresAt));
+    return this;
+  }
+
+  delete(key) {
+    const existed = super.delete(key);
+    if (this.expiresAt.delete(key)) {
+      this.store.remove(this.namespace, key);
+    }
+    return existed;
+  }
+
+  clear() {
+    super.clear();
+    this.expiresAt.clear();
+    this.store.removeNamespace(this.namespace);
+  }
+
+  /**
+   * Load a persisted entry without writing it back
+   */
+  restore(key, value, expiresAt) {
+    super.set(key, value);
+    this.expiresAt.set(key, expiresAt);
+  }
+
+  /**
+   * Drop expired entries from memory (the rows are purged by SessionStore.cleanup)
+   */
+  sweep() {
+    const now = Date.now();
+    for (const [key, expiresAt] of this.expiresAt) {
+      if (expiresAt <= now) {
+        super.delete(key);
+        this.expiresAt.delete(key);
+      }
+    }
+  }
+}
+
+/**
+ * Database-backed store for conversation state of multi-step bot flows
+ * (/buy_trending, /buy_footer, image fee payments, ...), so users can pick up
+ * where they left off after a restart or deploy.
+ */
+class SessionStore {
+  constructor(database, options = {}) {
+    this.db = database;
+    const ttlHours = options.ttlHours ?? (parseFloat(process.env.SESSION_TTL_HOURS) || DEFAULT_TTL_HOURS);
+    this.ttlMs = ttlHours * 60 * 60 * 1000;
+    this.maps = new Map();
+    this.loaded = new Map();
+    this.writeQueue = Promise.resolve();
+    this.cleanupInterval = null;
+  }
+
+  static isPersistable(value) {
+    if (value === null || ['string', 'number', 'boolean', 'bigint'].includes(typeof value)) {
+      return true;
+    }
+    if (Array.isArray(value)) {
+      return value.every(item => SessionStore.isPersistable(item));
+    }
+    if (typeof value === 'object' && Object.getPrototypeOf(value) === Object.prototype) {
+      return Object.values(value).every(item => item === undefined || SessionStore.isPersistable(item));
+    }
+    return false;
+  }
+
+  async initialize() {
+    await this.cleanup();
+
+    const rows = await this.db.all(
+      'SELECT namespace, session_key, value, expires_at FROM bot_sessions WHERE expires_at > $1',
+      [new Date()]
+    );
+
+    for (const row of rows) {
+      try {
+        const entries = this.loaded.get(row.namespace) || [];
+        entries.push([row.session_key, deserialize(row.value), new Date(row.expires_at).getTime()]);
+        this.loaded.set(row.namespace, entries);
+      } catch (error) {
+        logger.warn(`Skipping unreadable session ${row.namespace}/${row.session_key}: ${error.message}`);
+      }
+    }
+
+    this.cleanupInterval = setInterval(() => this.cleanup(), CLEANUP_INTERVAL_MS);
+    logger.info(`Session store initialized (${rows.length} active session entries restored)`);
+  }
+
+  /**
+   * Get the persisted map for a namespace, pre-filled with entries restored at startup
+   * @param {string} namespace - e.g. 'user_states', 'pending_payments'
+   * @returns {PersistentMap}
+   */
+  getMap(namespace) {
+    if (!this.maps.has(namespace)) {
+      const map = new PersistentMap(this, namespace, this.ttlMs);
+      for (const [key, value, expiresAt] of this.loaded.get(namespace) || []) {
+        map.restore(key, value, expiresAt);
+      }
+      this.loaded.delete(namespace);
+      this.maps.set(namespace, map);
+    }
+    return this.maps.get(namespace);
+  }
+
+  enqueue(description, work) {
+    this.writeQueue = this.writeQueue
+      .then(work)
+      .catch(error => logger.error(`Session store failed to ${description}:`, error.message));
+    return this.writeQueue;
+  }
+
+  save(namespace, key, value, expiresAt) {
+    return this.enqueue(`save ${namespace}/${key}`, () => this.db.query(
+      `INSERT INTO bot_sessions (namespace, session_key, value, expires_at, updated_at)
+       VALUES ($1, $2, $3, $4, NOW())
+       ON CONFLICT (namespace, session_key) DO UPDATE SET
+         value = EXCLUDED.value,
+         expires_at = EXCLUDED.expires_at,
+         updated_at = NOW()`,
+      [namespace, String(key), serialize(value), expiresAt]
+    ));
+  }
+
+  remove(namespace, key) {
+    return this.enqueue(`remove ${namespace}/${key}`, () => this.db.query(
+      'DELETE FROM bot_sessions WHERE namespace = $1 AND session_key = $2',
+      [namespace, String(key)]
+    ));
+  }
+
+  removeNamespace(namespace) {
+    return this.enqueue(`clear ${namespace}`, () => this.db.query(
+      'DELETE FROM bot_sessions WHERE namespace = $1',
+      [namespace]
+    ));
+  }
+
+  async cleanup() {
+    for (const map of this.maps.values()) {
+      map.sweep();
+    }
+
+    try {
+      const result = await this.db.query('DELETE FROM bot_sessions WHERE expires_at <= $1', [new Date()]);
+      if (result.rowCount > 0) {
+        logger.debug(`Removed ${result.rowCount} expired session entries`);
+      }
+    } catch (error) {
+      logger.error('Error cleaning up expired sessions:', error.message);
+    }
+  }
+
+  /**
+   * Stop the cleanup timer and wait for queued writes to reach the database
+   */
+  async close() {
+    if (this.cleanupInterval) {
+      clearInterval(this.cleanupInterval);
+      this.cleanupInterval = null;
+    }
+    await this.writeQueue;
+  }
+}
+
+module.exports = SessionStore;
+module.exports.PersistentMap = PersistentMap;
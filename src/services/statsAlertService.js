@@ -0,0 +1,303 @@
+const logger = require('./logger');
+
+const DEFAULT_POLL_INTERVAL_MS = 5 * 60 * 1000;
+const DEFAULT_HISTORY_DAYS = 30;
+const DEFAULT_RULE_LIMIT = 20;
+const DEFAULT_CHANGE_WINDOW_MINUTES = 60;
+const MAX_WINDOW_MINUTES = 7 * 24 * 60;
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+// Time series column compared by each metric
+const METRIC_COLUMNS = {
+  floor: 'floor_price',
+  volume: 'volume_24h'
+};
+const METRIC_ALIASES = { floor: 'floor', fp: 'floor', volume: 'volume', vol: 'volume' };
+const CONDITION_ALIASES = {
+  below: 'below', under: 'below', '<': 'below',
+  above: 'above', over: 'above', '>': 'above',
+  change: 'change', moves: 'change', move: 'change'
+};
+
+/**
+ * Parse a window typed by a user: "30m", "1h", "24h", "1d" (plain numbers are minutes)
+ * @returns {number|null} Minutes, null when invalid
+ */
+function parseWindow(input) {
+  const match = String(input || '').trim().toLowerCase().match(/^(\d+)(m|min|h|hr|d)?$/);
+  if (!match) return null;
+  const multiplier = { h: 60, hr: 60, d: 24 * 60 }[match[2]] || 1;
+  const minutes = parseInt(match[1], 10) * multiplier;
+  return minutes > 0 && minutes <= MAX_WINDOW_MINUTES ? minutes : null;
+}
+
+/**
+ * Format a window in minutes as "45m", "1h", "1d"
+ */
+function formatWindow(minutes) {
+  if (minutes % (24 * 60) === 0) return `${minutes / (24 * 60)}d`;
+  if (minutes % 60 === 0) return `${minutes / 60}h`;
+  return `${minutes}m`;
+}
+
+/**
+ * Parse the rule part of /stats_alert: "floor below 0.5", "floor change 10% 1h", "volume above 100"
+ * @param {Array<string>} args - Words after the collection
+ * @returns {Object|null} { metric, condition, threshold, windowMinutes }, null when invalid
+ */
+function parseStatsAlertRule(args) {
+  const [metricWord, conditionWord, valueWord, windowWord] = args.map(arg => String(arg).toLowerCase());
+  const metric = METRIC_ALIASES[metricWord];
+  const condition = CONDITION_ALIASES[conditionWord];
+  if (!metric || !condition || !valueWord) return null;
+
+  const threshold = parseFloat(valueWord.replace(/%$/, ''));
+  if (!Number.isFinite(threshold) || threshold <= 0) return null;
+
+  if (condition !== 'change') {
+    return windowWord ? null : { metric, condition, threshold, windowMinutes: null };
+  }
+  const windowMinutes = windowWord ? parseWindow(windowWord) : DEFAULT_CHANGE_WINDOW_MINUTES;
+  return windowMinutes ? { metric, condition, threshold, windowMinutes } : null;
+}
+
+/**
+ * Floor and volume alerts on tracked collections.
+ *
+ * Every poll takes a snapshot of each collection that has alert rules (OpenSea for EVM
+ * chains, Magic Eden for Solana and Ordinals) and appends it to collection_stats, which
+ * is pruned after STATS_HISTORY_DAYS. Magic Eden only reports lifetime volume, so the 24h
+ * volume of those collections is the difference to the snapshot from a day before.
+ *
+ * "below"/"above" rules fire when the value crosses the threshold and re-arm once it is
+ * back on the other side. "change" rules compare with the value one window ago and fire
+ * at most once per window. `onTrigger(rule, token, snapshot, details)` renders and queues
+ * the alert.
+ */
+class StatsAlertService {
+  constructor(database, { collectionStats, onTrigger, pollIntervalMs } = {}) {
+    this.db = database;
+    this.collectionStats = collectionStats;
+    this.onTrigger = onTrigger;
+    this.pollIntervalMs = pollIntervalMs ?? (parseInt(process.env.STATS_POLL_INTERVAL_MS, 10) || DEFAULT_POLL_INTERVAL_MS);
+    this.historyDays = parseInt(process.env.STATS_HISTORY_DAYS, 10) || DEFAULT_HISTORY_DAYS;
+    this.ruleLimit = parseInt(process.env.STATS_ALERT_LIMIT, 10) || DEFAULT_RULE_LIMIT;
+    this.timer = null;
+    this.busy = null;
+    this.stats = { snapshots: 0, triggered: 0 };
+  }
+
+  /**
+   * Add an alert rule for a tracked collection
+   * @param {Object} chat - { chatId, chatType, createdBy }
+   * @param {Object} token - Tracked token row
+   * @param {Object} rule - From parseStatsAlertRule
+   * @returns {Promise<Object>} { status: 'added', id } or { status: 'limit', limit }
+   */
+  async addRule({ chatId, chatType, createdBy }, token, { metric, condition, threshold, windowMinutes }) {
+    const key = String(chatId);
+    const countRow = await this.db.get('SELECT COUNT(*) AS count FROM stats_alerts WHERE chat_id = $1', [key]);
+    if ((parseInt(countRow?.count, 10) || 0) >= this.ruleLimit) {
+      return { status: 'limit', limit: this.ruleLimit };
+    }
+
+    const result = await this.db.run(
+      `INSERT INTO stats_alerts (chat_id, chat_type, token_id, metric, condition, threshold, window_minutes, created_by)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+       RETURNING id`,
+      [key, chatType || null, token.id, metric, condition, threshold, windowMinutes, createdBy != null ? String(createdBy) : null]
+    );
+    logger.info(`📊 Chat ${key} added ${metric} ${condition} ${threshold} alert for ${token.token_name}`);
+    return { status: 'added', id: result.id };
+  }
+
+  /**
+   * @returns {Promise<boolean>} True if the chat had a rule with this ID
+   */
+  async removeRule(chatId, ruleId) {
+    const result = await this.db.run('DELETE FROM stats_alerts WHERE id = $1 AND chat_id = $2', [ruleId, String(chatId)]);
+    return result.changes > 0;
+  }
+
+  async listRules(chatId) {
+    return this.db.all(
+      `SELECT sa.*, tt.token_name, tt.collection_slug, tt.chain_name
+       FROM stats_alerts sa
+       JOIN tracked_tokens tt ON tt.id = sa.token_id
+       WHERE sa.chat_id = $1
+       ORDER BY sa.id`,
+      [String(chatId)]
+    );
+  }
+
+  start() {
+    if (this.timer) return;
+    this.timer = setInterval(() => this.tick(), this.pollIntervalMs);
+    logger.info(`📊 Collection stats alerts started (polling every ${this.pollIntervalMs / 1000}s)`);
+  }
+
+  async stop() {
+    clearInterval(this.timer);
+    this.timer = null;
+    if (this.busy) {
+      await this.busy;
+    }
+  }
+
+  async tick() {
+    if (this.busy) return;
+    this.busy = this.pollAll()
+      .catch(error => logger.error('Error polling collection stats:', error))
+      .finally(() => { this.busy = null; });
+    await this.busy;
+  }
+
+  /**
+   * Snapshot every collection with alert rules and check its rules
+   * @returns {Promise<number>} Number of alerts triggered
+   */
+  async pollAll(now = new Date()) {
+    const tokens = await this.db.all(`
+      SELECT DISTINCT tt.*
+      FROM tracked_tokens tt
+      JOIN stats_alerts sa ON sa.token_id = tt.id
+      WHERE tt.is_active = true
+    `);
+
+    let triggered = 0;
+    for (const token of tokens || []) {
+      try {
+        const snapshot = await this.recordSnapshot(token, now);
+        if (snapshot) {
+          triggered += await this.evaluate(token, snapshot, now);
+        }
+      } catch (error) {
+        logger.error(`Error checking stats alerts for ${token.token_name}:`, error);
+      }
+    }
+
+    await this.db.run('DELETE FROM collection_stats WHERE recorded_at < $1', [new Date(now.getTime() - this.historyDays * DAY_MS)]);
+    return triggered;
+  }
+
+  /**
+   * Fetch and store the current stats of a collection
+   * @returns {Promise<Object|null>} Snapshot, null if the marketplace had no stats
+   */
+  async recordSnapshot(token, now = new Date()) {
+    const snapshot = await this.collectionStats.getCollectionSnapshot(token);
+    if (!snapshot || (snapshot.floor_price == null && snapshot.volume_24h == null && snapshot.volume_total == null)) {
+      logger.debug(`📊 No stats for ${token.token_name} (${token.chain_name})`);
+      return null;
+    }
+
+    if (snapshot.volume_24h == null && snapshot.volume_total != null) {
+      const dayAgo = await this.getValueAt(token.id, 'volume_total', new Date(now.getTime() - DAY_MS), DAY_MS / 12);
+      if (dayAgo != null) {
+        snapshot.volume_24h = Math.max(0, snapshot.volume_total - dayAgo);
+      }
+    }
+
+    await this.db.query(
+      `INSERT INTO collection_stats (token_id, source, floor_price, volume_24h, volume_total, sales_24h, listed_count, currency, recorded_at)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)`,
+      [token.id, snapshot.source, snapshot.floor_price, snapshot.volume_24h, snapshot.volume_total, snapshot.sales_24h, snapshot.listed_count, snapshot.currency, now]
+    );
+    this.stats.snapshots++;
+    return snapshot;
+  }
+
+  /**
+   * Latest stored value at or before a time
+   * @param {number} tokenId
+   * @param {string} column - collection_stats column
+   * @param {Date} at
+   * @param {number} maxAgeMs - How much older than `at` the value may be
+   * @returns {Promise<number|null>}
+   */
+  async getValueAt(tokenId, column, at, maxAgeMs) {
+    const row = await this.db.get(
+      `SELECT ${column} AS value FROM collection_stats
+       WHERE token_id = $1 AND recorded_at <= $2 AND recorded_at >= $3 AND ${column} IS NOT NULL
+       ORDER BY recorded_at DESC
+       LIMIT 1`,
+      [tokenId, at, new Date(at.getTime() - maxAgeMs)]
+    );
+    const value = parseFloat(row?.value);
+    return Number.isFinite(value) ? value : null;
+  }
+
+  /**
+   * Check the rules of a collection against a new snapshot
+   * @returns {Promise<number>} Number of alerts triggered
+   */
+  async evaluate(token, snapshot, now = new Date()) {
+    const rules = await this.db.all('SELECT * FROM stats_alerts WHERE token_id = $1', [token.id]);
+
+    let triggered = 0;
+    for (const rule of rules || []) {
+      const column = METRIC_COLUMNS[rule.metric];
+      const value = snapshot[column];
+      if (value == null) continue;
+      const threshold = parseFloat(rule.threshold);
+
+      if (rule.condition === 'change') {
+        const windowMs = rule.window_minutes * 60 * 1000;
+        if (rule.last_triggered_at && now.getTime() - new Date(rule.last_triggered_at).getTime() < windowMs) continue;
+
+        const previous = await this.getValueAt(token.id, column, new Date(now.getTime() - windowMs), windowMs);
+        if (!previous) continue;
+        const change = ((value - previous) / previous) * 100;
+        if (Math.abs(change) < threshold) continue;
+
+        // Only a delivered alert starts the cooldown; a failed one is retried on the next snapshot
+        if (await this.trigger(rule, token, snapshot, { previous, change })) {
+          await this.db.run('UPDATE stats_alerts SET last_triggered_at = $1 WHERE id = $2', [now, rule.id]);
+          triggered++;
+        }
+        continue;
+      }
+
+      const met = rule.condition === 'below' ? value < threshold : value > threshold;
+      const wasTriggered = !!rule.is_triggered;
+      if (met && !wasTriggered) {
+        if (await this.trigger(rule, token, snapshot, {})) {
+          await this.db.run('UPDATE stats_alerts SET is_triggered = true, last_triggered_at = $1 WHERE id = $2', [now, rule.id]);
+          triggered++;
+        }
+      } else if (!met && wasTriggered) {
+        // Back on the other side of the threshold: the next crossing alerts again
+        await this.db.run('UPDATE stats_alerts SET is_triggered = false WHERE id = $1', [rule.id]);
+      }
+    }
+    return triggered;
+  }
+
+  async trigger(rule, token, snapshot, details) {
+    try {
+      await this.onTrigger(rule, token, snapshot, details);
+      this.stats.triggered++;
+      logger.info(`📊 ${token.token_name} ${rule.metric} ${rule.condition} ${rule.threshold} alert triggered for ${rule.chat_id}`);
+      return true;
+    } catch (error) {
+      logger.error(`❌ Failed to queue stats alert ${rule.id} for ${rule.chat_id}:`, error);
+      return false;
+    }
+  }
+
+  async getStats() {
+    const row = await this.db.get('SELECT COUNT(*) AS rules, COUNT(DISTINCT token_id) AS collections FROM stats_alerts');
+    return {
+      rules: parseInt(row?.rules, 10) || 0,
+      collections: parseInt(row?.collections, 10) || 0,
+      pollIntervalMs: this.pollIntervalMs,
+      ...this.stats
+    };
+  }
+}
+
+module.exports = StatsAlertService;
+module.exports.parseStatsAlertRule = parseStatsAlertRule;
+module.exports.parseWindow = parseWindow;
+module.exports.formatWindow = formatWindow;
+module.exports.METRIC_COLUMNS = METRIC_COLUMNS;
@@ -0,0 +1,429 @@
+const { ethers } = require('ethers');
+const { PAYMENT_TOKENS, findPaymentToken } = require('./chainManager');
+const { t } = require('../i18n');
+
+/**
+ * Alert filters of a subscription (user_subscriptions row)
+ *
+ * A subscription without filters receives every alert of its token. Filters are stored
+ * in plain columns: alert_event_types (comma list), min_price + min_price_unit,
+ * marketplace_filter_mode + marketplace_filter (comma list), ignore_burns, snipe_discount
+ * and trait_filter ("Type: Value" entries separated by semicolons).
+ *
+ * Snipe mode narrows a subscription down to listings priced under the collection floor,
+ * by at least snipe_discount percent (0 = anything below the floor).
+ *
+ * Trait filters pass items that have any of the listed traits. An entry without a type
+ * ("1/1") matches a trait of any type with that value, or a trait type of that name.
+ * Items are looked up in the collection's rarity snapshot (RarityService).
+ */
+
+const FILTER_EVENT_TYPES = ['sale', 'listing', 'offer', 'mint', 'transfer'];
+const PRICE_UNITS = ['native', 'usd'];
+const MARKETPLACE_MODES = ['include', 'exclude'];
+
+// The minimum price only applies to these; their alerts without a known price are dropped
+const PRICED_EVENT_TYPES = ['sale', 'listing', 'offer'];
+
+const BURN_ADDRESSES = [
+  '0x0000000000000000000000000000000000000000',
+  '0x000000000000000000000000000000000000dead',
+  '1nc1nerator11111111111111111111111111111111'
+].map(address => address.toLowerCase());
+
+const DISABLE_WORDS = ['off', 'none', 'clear', 'all', '0'];
+
+const MAX_TRAIT_FILTERS = 20;
+const MAX_TRAIT_LENGTH = 100;
+
+// Wrapped native tokens are priced like the currency they wrap (WETH listings against an ETH floor)
+function normalizeCurrency(currency) {
+  const symbol = String(currency || '').toUpperCase();
+  return ['WETH', 'WBTC', 'WSOL', 'WAVAX', 'WAPE', 'WRON', 'WSEI', 'WBERA'].includes(symbol) ? symbol.slice(1) : symbol;
+}
+
+/**
+ * @typedef {Object} SubscriptionFilters
+ * @property {Array<string>|null} eventTypes - Allowed types (burns count as transfers), null = all
+ * @property {{amount: number, unit: 'native'|'usd'}|null} minPrice
+ * @property {{mode: 'include'|'exclude', names: Array<string>}|null} marketplaces
+ * @property {boolean} ignoreBurns - Drop burns and transfers to/from burn addresses
+ * @property {{discount: number}|null} snipe - Only listings at least `discount` percent under the floor
+ * @property {Array<{type: string|null, value: string}>|null} traits - Only items with one of these traits
+ */
+
+function splitList(value) {
+  return (value || '').split(',').map(item => item.trim()).filter(Boolean);
+}
+
+function normalizeMarketplace(name) {
+  return String(name || '').toLowerCase().replace(/[^a-z0-9]/g, '');
+}
+
+function normalizeTrait(text) {
+  return String(text || '').trim().replace(/\s+/g, ' ').toLowerCase();
+}
+
+// "Fur: Gold; 1/1" -> [{ type: 'Fur', value: 'Gold' }, { type: null, value: '1/1' }]
+function splitTraits(text) {
+  return String(text || '').split(/[;,\n]/).map(entry => entry.trim()).filter(Boolean).map(entry => {
+    const separator = entry.indexOf(':');
+    return separator === -1
+      ? { type: null, value: entry }
+      : { type: entry.slice(0, separator).trim() || null, value: entry.slice(separator + 1).trim() };
+  }).filter(trait => trait.value);
+}
+
+function formatTraitFilter(trait) {
+  return trait.type ? `${trait.type}: ${trait.value}` : trait.value;
+}
+
+/**
+ * Read the filters of a subscription row
+ * @param {Object} row - user_subscriptions columns
+ * @returns {SubscriptionFilters|null} Null when nothing is filtered
+ */
+function fromSubscriptionRow(row) {
+  if (!row) return null;
+
+  const eventTypes = splitList(row.alert_event_types).filter(type => FILTER_EVENT_TYPES.includes(type));
+  const minAmount = parseFloat(row.min_price);
+  const marketplaceNames = splitList(row.marketplace_filter);
+  const snipeDiscount = parseFloat(row.snipe_discount);
+  const traits = splitTraits(row.trait_filter);
+
+  const filters = {
+    eventTypes: row.alert_event_types != null && row.alert_event_types !== '' ? eventTypes : null,
+    minPrice: Number.isFinite(minAmount) && minAmount > 0
+      ? { amount: minAmount, unit: PRICE_UNITS.includes(row.min_price_unit) ? row.min_price_unit : 'native' }
+      : null,
+    marketplaces: MARKETPLACE_MODES.includes(row.marketplace_filter_mode) && marketplaceNames.length > 0
+      ? { mode: row.marketplace_filter_mode, names: marketplaceNames }
+      : null,
+    ignoreBurns: row.ignore_burns === true || row.ignore_burns === 1,
+    snipe: Number.isFinite(snipeDiscount) && snipeDiscount >= 0 ? { discount: snipeDiscount } : null,
+    traits: traits.length > 0 ? traits : null
+  };
+
+  return hasFilters(filters) ? filters : null;
+}
+
+function hasFilters(filters) {
+  return !!filters && (filters.eventTypes !== null || !!filters.minPrice || !!filters.marketplaces || filters.ignoreBurns || !!filters.snipe || !!filters.traits);
+}
+
+/**
+ * Column values for storing filters
+ * @param {SubscriptionFilters} filters
+ * @returns {Object} user_subscriptions column/value pairs
+ */
+function toSubscriptionColumns(filters) {
+  return {
+    // 'none' keeps an empty selection distinct from NULL (every type)
+    alert_event_types: filters.eventTypes ? (filters.eventTypes.join(',') || 'none') : null,
+    min_price: filters.minPrice ? String(filters.minPrice.amount) : null,
+    min_price_unit: filters.minPrice ? filters.minPrice.unit : null,
+    marketplace_filter_mode: filters.marketplaces ? filters.marketplaces.mode : null,
+    marketplace_filter: filters.marketplaces ? filters.marketplaces.names.join(',') : null,
+    ignore_burns: !!filters.ignoreBurns,
+    snipe_discount: filters.snipe ? filters.snipe.discount : null,
+    trait_filter: filters.traits ? filters.traits.map(formatTraitFilter).join('; ') : null
+  };
+}
+
+function getFilterEventType(event) {
+  return event.type === 'burn' ? 'transfer' : event.type;
+}
+
+function isBurnAddress(address) {
+  return !!address && BURN_ADDRESSES.includes(address.toLowerCase());
+}
+
+function isBurnEvent(event) {
+  if (event.type === 'burn') return true;
+  return event.type === 'transfer' && (isBurnAddress(event.seller) || isBurnAddress(event.buyer));
+}
+
+/**
+ * Whether a price is paid in the chain's native currency or a token valued 1:1 in it (WETH)
+ */
+function isNativeValued(event) {
+  const tokens = PAYMENT_TOKENS[event.chain] || [];
+  const nativeToken = tokens.find(token => !token.address);
+  const paymentToken = findPaymentToken(tokens, event.price);
+  return !!nativeToken && paymentToken?.coinGeckoId === nativeToken.coinGeckoId;
+}
+
+/**
+ * Value of an event's price in the chain's native currency. Prices in other tokens (USDC,
+ * APE on Ethereum) only have one once the handler worked out `price.native`.
+ * @returns {number|null}
+ */
+function getNativeAmount(event) {
+  const price = event.price;
+  if (price.native != null) return price.native;
+  if (!isNativeValued(event)) return null;
+  try {
+    return parseFloat(ethers.formatUnits(BigInt(price.raw), price.decimals));
+  } catch (error) {
+    return null;
+  }
+}
+
+/**
+ * How far a listing is priced under the floor
+ * @param {ActivityEvent} event - Listing
+ * @param {{price: number, currency: string}|null} floor - Current collection floor
+ * @returns {number|null} Discount in percent (negative above the floor), null if not comparable
+ */
+function getFloorDiscount(event, floor) {
+  if (!floor || !(floor.price > 0) || !event.price) return null;
+  if (normalizeCurrency(event.price.currency) !== normalizeCurrency(floor.currency)) return null;
+
+  const amount = getNativeAmount(event);
+  return amount == null ? null : ((floor.price - amount) / floor.price) * 100;
+}
+
+/**
+ * Whether an item has one of the selected traits
+ * @param {Array<{type: string|null, value: string}>} selected - Trait filter entries
+ * @param {Array<{type: string, value: string}>} itemTraits - Traits of the item
+ * @returns {boolean}
+ */
+function matchesTraits(selected, itemTraits) {
+  return selected.some(entry => {
+    const value = normalizeTrait(entry.value);
+    if (entry.type) {
+      const type = normalizeTrait(entry.type);
+      return itemTraits.some(trait => normalizeTrait(trait.type) === type && normalizeTrait(trait.value) === value);
+    }
+    return itemTraits.some(trait => normalizeTrait(trait.value) === value || normalizeTrait(trait.type) === value);
+  });
+}
+
+/**
+ * Check an event against a subscription's filters
+ * @param {SubscriptionFilters|null} filters
+ * @param {ActivityEvent} event
+ * @param {number|null} usdValue - Event price in USD (only needed for USD minimums)
+ * @param {{price: number, currency: string}|null} floor - Collection floor (only needed for snipe mode)
+ * @param {Array<{type: string, value: string}>|null} itemTraits - Traits of the item (only needed for trait filters)
+ * @returns {string|null} Why the event is filtered out, or null if it passes
+ */
+function getFilterRejection(filters, event, usdValue = null, floor = null, itemTraits = null) {
+  if (!filters) return null;
+
+  if (filters.snipe) {
+    if (event.type !== 'listing') {
+      return `snipe mode: ${event.type} is not a listing`;
+    }
+    const discount = getFloorDiscount(event, floor);
+    if (discount == null) {
+      return 'snipe mode: floor or price unknown';
+    }
+    if (discount <= 0 || discount < filters.snipe.discount) {
+      return `snipe mode: ${discount.toFixed(1)}% under the floor, ${filters.snipe.discount}% needed`;
+    }
+  }
+
+  if (filters.eventTypes && !filters.eventTypes.includes(getFilterEventType(event))) {
+    return `event type ${event.type} not selected`;
+  }
+
+  if (filters.ignoreBurns && isBurnEvent(event)) {
+    return 'burn';
+  }
+
+  if (filters.minPrice && PRICED_EVENT_TYPES.includes(event.type)) {
+    const amount = filters.minPrice.unit === 'usd'
+      ? usdValue
+      : (event.price ? getNativeAmount(event) : null);
+    if (amount == null) {
+      return 'price unknown';
+    }
+    if (amount < filters.minPrice.amount) {
+      return `price ${amount} below minimum ${filters.minPrice.amount} ${filters.minPrice.unit}`;
+    }
+  }
+
+  if (filters.marketplaces) {
+    const marketplace = normalizeMarketplace(event.marketplace);
+    const listed = !!marketplace && filters.marketplaces.names.some(name => normalizeMarketplace(name) === marketplace);
+    if (filters.marketplaces.mode === 'include' && !listed) {
+      return `marketplace ${event.marketplace || 'unknown'} not included`;
+    }
+    if (filters.marketplaces.mode === 'exclude' && listed) {
+      return `marketplace ${event.marketplace} excluded`;
+    }
+  }
+
+  if (filters.traits) {
+    if (!itemTraits) {
+      return 'traits unknown';
+    }
+    if (!matchesTraits(filters.traits, itemTraits)) {
+      return 'no selected trait';
+    }
+  }
+
+  return null;
+}
+
+/**
+ * Parse a minimum price typed by a user: "0.5", "0.5 eth", "$250", "250 usd" or "off".
+ * Like the other parsers, an invalid input gets a catalog key as `reason` and its
+ * values as `params`, for t(lang, reason, params).
+ * @returns {{isValid: boolean, minPrice?: Object|null, reason?: string, params?: Object}}
+ */
+function parseMinPrice(input) {
+  const text = String(input || '').trim().toLowerCase();
+  if (DISABLE_WORDS.includes(text)) {
+    return { isValid: true, minPrice: null };
+  }
+
+  const match = text.match(/^(\$)?\s*(\d+(?:\.\d+)?)\s*([a-z$]+)?$/);
+  if (!match) {
+    return { isValid: false, reason: 'filters.error.min_price' };
+  }
+
+  const amount = parseFloat(match[2]);
+  if (!(amount > 0)) {
+    return { isValid: true, minPrice: null };
+  }
+
+  const unit = match[1] || match[3] === 'usd' || match[3] === '$' ? 'usd' : 'native';
+  return { isValid: true, minPrice: { amount, unit } };
+}
+
+/**
+ * Parse a marketplace filter: "include opensea, blur", "exclude blur" or "off"
+ * @returns {{isValid: boolean, marketplaces?: Object|null, reason?: string, params?: Object}}
+ */
+function parseMarketplaceFilter(input) {
+  const text = String(input || '').trim();
+  if (DISABLE_WORDS.includes(text.toLowerCase())) {
+    return { isValid: true, marketplaces: null };
+  }
+
+  const match = text.match(/^(include|only|exclude|except)\s+(.+)$/i);
+  if (!match) {
+    return { isValid: false, reason: 'filters.error.marketplace_mode' };
+  }
+
+  const names = splitList(match[2]);
+  if (names.length === 0) {
+    return { isValid: false, reason: 'filters.error.marketplace_empty' };
+  }
+
+  const mode = ['include', 'only'].includes(match[1].toLowerCase()) ? 'include' : 'exclude';
+  return { isValid: true, marketplaces: { mode, names } };
+}
+
+/**
+ * Parse a snipe mode setting: "on" or "0" (any listing below the floor), "15" / "15%"
+ * (at least 15% under it) or "off"
+ * @returns {{isValid: boolean, snipe?: Object|null, reason?: string, params?: Object}}
+ */
+function parseSnipeDiscount(input) {
+  const text = String(input || '').trim().toLowerCase();
+  if (['on', 'floor', 'below'].includes(text)) {
+    return { isValid: true, snipe: { discount: 0 } };
+  }
+  if (text !== '0' && DISABLE_WORDS.includes(text)) {
+    return { isValid: true, snipe: null };
+  }
+
+  const match = text.match(/^(\d+(?:\.\d+)?)\s*%?$/);
+  const discount = match ? parseFloat(match[1]) : NaN;
+  if (!(discount >= 0 && discount < 100)) {
+    return { isValid: false, reason: 'filters.error.snipe' };
+  }
+  return { isValid: true, snipe: { discount } };
+}
+
+/**
+ * Parse a trait filter: "Fur: Gold; Eyes: Laser", "1/1" or "off". Entries are separated
+ * by semicolons, commas or new lines.
+ * @returns {{isValid: boolean, traits?: Array|null, reason?: string, params?: Object}}
+ */
+function parseTraitFilter(input) {
+  const text = String(input || '').trim();
+  if (DISABLE_WORDS.includes(text.toLowerCase())) {
+    return { isValid: true, traits: null };
+  }
+
+  const traits = splitTraits(text);
+  if (traits.length === 0) {
+    return { isValid: false, reason: 'filters.error.traits' };
+  }
+  if (traits.length > MAX_TRAIT_FILTERS) {
+    return { isValid: false, reason: 'filters.error.too_many_traits', params: { max: MAX_TRAIT_FILTERS } };
+  }
+  if (traits.some(trait => formatTraitFilter(trait).length > MAX_TRAIT_LENGTH)) {
+    return { isValid: false, reason: 'filters.error.trait_too_long', params: { max: MAX_TRAIT_LENGTH } };
+  }
+  return { isValid: true, traits };
+}
+
+/**
+ * Marketplace filter as shown to users: "include OpenSea, Blur" in their language
+ */
+function describeMarketplaces(marketplaces, lang = 'en') {
+  return t(lang, `filters.value.${marketplaces.mode}`, { names: marketplaces.names.join(', ') });
+}
+
+/**
+ * Short human-readable summary, one line per filter
+ * @param {SubscriptionFilters|null} filters
+ * @param {string} currencySymbol - Native currency of the token's chain
+ * @param {string} lang - Language of the chat
+ * @returns {Array<string>}
+ */
+function describeFilters(filters, currencySymbol = 'ETH', lang = 'en') {
+  if (!filters) return [];
+
+  const lines = [];
+  if (filters.eventTypes) {
+    const types = filters.eventTypes.length > 0
+      ? filters.eventTypes.map(type => t(lang, `filters.type.${type}`)).join(', ')
+      : t(lang, 'filters.value.none');
+    lines.push(t(lang, 'filters.summary.events', { types }));
+  }
+  if (filters.minPrice) {
+    const value = filters.minPrice.unit === 'usd' ? `$${filters.minPrice.amount}` : `${filters.minPrice.amount} ${currencySymbol}`;
+    lines.push(t(lang, 'filters.summary.min_price', { value }));
+  }
+  if (filters.marketplaces) {
+    lines.push(t(lang, 'filters.summary.marketplaces', { value: describeMarketplaces(filters.marketplaces, lang) }));
+  }
+  if (filters.ignoreBurns) {
+    lines.push(t(lang, 'filters.summary.burns'));
+  }
+  if (filters.snipe) {
+    lines.push(filters.snipe.discount > 0
+      ? t(lang, 'filters.summary.snipe_discount', { discount: filters.snipe.discount })
+      : t(lang, 'filters.summary.snipe_floor'));
+  }
+  if (filters.traits) {
+    lines.push(t(lang, 'filters.summary.traits', { traits: filters.traits.map(formatTraitFilter).join('; ') }));
+  }
+  return lines;
+}
+
+module.exports = {
+  FILTER_EVENT_TYPES,
+  PRICE_UNITS,
+  fromSubscriptionRow,
+  toSubscriptionColumns,
+  hasFilters,
+  getFilterRejection,
+  getFloorDiscount,
+  parseMinPrice,
+  parseSnipeDiscount,
+  parseMarketplaceFilter,
+  parseTraitFilter,
+  formatTraitFilter,
+  matchesTraits,
+  describeMarketplaces,
+  describeFilters
+};
@@ -0,0 +1,179 @@
+const logger = require('./logger');
+
+const DEFAULT_WINDOW_MS = 8000;
+const POLL_INTERVAL_MS = 1000;
+// A batch whose worker died mid-delivery is picked up again after this long
+const LEASE_MS = 60 * 1000;
+const BASE_RETRY_DELAY_MS = 5000;
+const MAX_RETRY_DELAY_MS = 5 * 60 * 1000;
+const MAX_ATTEMPTS = 5;
+
+/**
+ * Groups the sales of one collection made in the same transaction (sweeps, bulk buys).
+ *
+ * Every sale of a collection and transaction joins the same batch, and each one keeps the
+ * batch open for another `windowMs`. Once the transaction has been quiet for the whole
+ * window, `onFlush(token, events)` receives the whole batch (often just one event).
+ * Pending sales are kept in the pending_sweep_sales table, so they survive a restart and
+ * the sales a transaction's items bring to different replicas still form one batch. The
+ * worker leases every row of the transaction before delivering the batch, and no row of a
+ * leased transaction is delivered on its own. The rows are deleted once `onFlush` has
+ * queued the alerts; if it throws, the lease is released and the batch is retried with
+ * backoff, then dropped after MAX_ATTEMPTS. A batch left by a crashed worker is delivered
+ * again once the lease runs out.
+ */
+class SweepAggregator {
+  constructor(database, { onFlush, windowMs, pollIntervalMs = POLL_INTERVAL_MS } = {}) {
+    this.db = database;
+    this.onFlush = onFlush;
+    this.windowMs = windowMs ?? (parseInt(process.env.SWEEP_WINDOW_MS, 10) || DEFAULT_WINDOW_MS);
+    this.pollIntervalMs = pollIntervalMs;
+    this.timer = null;
+    this.busy = null;
+    this.stats = { batches: 0, sweeps: 0, largestSweep: 0 };
+  }
+
+  static getKey(token, event) {
+    return `${token.id}:${event.chain}:${event.txHash.toLowerCase()}`;
+  }
+
+  /**
+   * Add a sale to its transaction's batch
+   * @param {Object} token - Tracked token row
+   * @param {ActivityEvent} event - Sale with a transaction hash
+   */
+  async add(token, event) {
+    const key = SweepAggregator.getKey(token, event);
+    await this.db.query(
+      `INSERT INTO pending_sweep_sales (batch_key, token_id, payload, flush_at)
+       VALUES ($1, $2, $3, $4)`,
+      [key, token.id, JSON.stringify(event), new Date(Date.now() + this.windowMs)]
+    );
+    logger.debug(`🧹 Sale ${event.tokenId} added to batch ${key}`);
+  }
+
+  start() {
+    if (this.timer) return;
+    this.timer = setInterval(() => this.tick(), this.pollIntervalMs);
+    logger.info(`🧹 Sweep worker started (${this.windowMs / 1000}s window)`);
+  }
+
+  /**
+   * Stop polling. Pending sales stay in the table for the next start (or another replica).
+   */
+  async stop() {
+    clearInterval(this.timer);
+    this.timer = null;
+    if (this.busy) {
+      await this.busy;
+    }
+  }
+
+  async tick() {
+    if (this.busy) return;
+    this.busy = this.processDue()
+      .catch(error => logger.error('Error processing sweep batches:', error))
+      .finally(() => { this.busy = null; });
+    await this.busy;
+  }
+
+  /**
+   * Deliver every batch whose transaction has been quiet for the whole window
+   * @returns {Promise<number>} Number of batches delivered
+   */
+  async processDue(now = new Date()) {
+    const batches = await this.db.all(
+      `SELECT batch_key FROM pending_sweep_sales
+       GROUP BY batch_key
+       HAVING MAX(flush_at) <= $1
+         AND COUNT(CASE WHEN locked_until >= $1 THEN 1 END) = 0`,
+      [now]
+    );
+
+    let deliveredCount = 0;
+    for (const { batch_key: key } of batches) {
+      if (await this.flush(key)) deliveredCount++;
+    }
+    return deliveredCount;
+  }
+
+  /**
+   * Lease all of a transaction's sales and deliver them as one batch
+   * @returns {Promise<boolean>} False if the batch was empty, is still open, another worker
+   *   holds part of it, or its delivery failed
+   */
+  async flush(key) {
+    const now = new Date();
+    // Nothing is leased while a sale of the transaction is still in its window or leased
+    // elsewhere, so a late sale can't be split off into a batch of its own
+    const { rows } = await this.db.query(
+      `UPDATE pending_sweep_sales SET locked_until = $2, attempts = attempts + 1
+       WHERE batch_key = $1 AND (locked_until IS NULL OR locked_until < $3)
+         AND NOT EXISTS (
+           SELECT 1 FROM pending_sweep_sales other
+           WHERE other.batch_key = $1 AND (other.flush_at > $3 OR other.locked_until >= $3)
+         )
+       RETURNING id, token_id, payload, attempts`,
+      [key, new Date(now.getTime() + LEASE_MS), now]
+    );
+    if (rows.length === 0) return false;
+
+    const ids = rows.map(row => row.id);
+    try {
+      const token = await this.db.get('SELECT * FROM tracked_tokens WHERE id = $1', [rows[0].token_id]);
+      const events = rows.sort((a, b) => a.id - b.id).map(row => JSON.parse(row.payload));
+      if (token) {
+        await this.onFlush(token, events);
+        this.recordBatch(token, events);
+      }
+    } catch (error) {
+      await this.release(key, ids, Math.max(...rows.map(row => parseInt(row.attempts, 10))), error);
+      return false;
+    }
+
+    await this.deleteRows(ids);
+    return true;
+  }
+
+  /**
+   * Give a batch that failed to deliver back to the workers, or drop it after MAX_ATTEMPTS
+   */
+  async release(key, ids, attempts, error) {
+    if (attempts >= MAX_ATTEMPTS) {
+      logger.error(`💀 Sale batch ${key} dropped after ${attempts} attempt(s): ${error.message}`);
+      await this.deleteRows(ids);
+      return;
+    }
+
+    const delayMs = Math.min(BASE_RETRY_DELAY_MS * 2 ** (attempts - 1), MAX_RETRY_DELAY_MS);
+    logger.warn(`🔁 Sale batch ${key} failed (attempt ${attempts}/${MAX_ATTEMPTS}), retrying in ${delayMs / 1000}s: ${error.message}`);
+    await this.db.query(
+      `UPDATE pending_sweep_sales SET locked_until = NULL, flush_at = $1
+       WHERE id IN (${ids.map((_, index) => `$${index + 2}`).join(', ')})`,
+      [new Date(Date.now() + delayMs), ...ids]
+    );
+  }
+
+  async deleteRows(ids) {
+    await this.db.query(
+      `DELETE FROM pending_sweep_sales WHERE id IN (${ids.map((_, index) => `$${index + 1}`).join(', ')})`,
+      ids
+    );
+  }
+
+  recordBatch(token, events) {
+    this.stats.batches++;
+    if (events.length > 1) {
+      this.stats.sweeps++;
+      this.stats.largestSweep = Math.max(this.stats.largestSweep, events.length);
+      logger.info(`🧹 Sweep of ${events.length} ${token.token_name} items in ${events[0].txHash}`);
+    }
+  }
+
+  async getStats() {
+    const row = await this.db.get('SELECT COUNT(DISTINCT batch_key) AS pending FROM pending_sweep_sales');
+    return { pending: parseInt(row?.pending, 10) || 0, windowMs: this.windowMs, ...this.stats };
+  }
+}
+
+module.exports = SweepAggregator;
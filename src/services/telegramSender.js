@@ -0,0 +1,194 @@
+const logger = require('./logger');
+
+// Lower number = sent first
+const PRIORITY = {
+  ALERT: 0, // real-time sale/activity alerts
+  NORMAL: 1, // one-off messages (broadcasts, channel welcome messages)
+  BACKGROUND: 2 // trending refreshes and other periodic updates
+};
+
+const PRIVATE_CHAT_INTERVAL_MS = 1000;
+const GROUP_WINDOW_MS = 60 * 1000;
+const GLOBAL_WINDOW_MS = 1000;
+const MAX_RATE_LIMIT_RETRIES = 3;
+
+/**
+ * Central send layer for Telegram API calls
+ *
+ * Every outgoing message goes through one queue that enforces Telegram's limits:
+ * a global messages-per-second budget, one message per second per private chat and
+ * a per-minute budget per group/channel. A 429 response pauses the affected chat for
+ * the `retry_after` Telegram returns and the call is retried. When several calls are
+ * waiting, higher priority ones (sale alerts) go before trending refreshes.
+ */
+class TelegramSender {
+  constructor(bot, options = {}) {
+    this.bot = bot;
+    this.globalPerSecond = options.globalPerSecond ?? (parseInt(process.env.TELEGRAM_GLOBAL_RATE_PER_SECOND, 10) || 25);
+    this.groupPerMinute = options.groupPerMinute ?? (parseInt(process.env.TELEGRAM_GROUP_RATE_PER_MINUTE, 10) || 20);
+    this.queue = [];
+    this.sequence = 0;
+    this.globalSentAt = [];
+    this.chats = new Map();
+    this.timer = null;
+  }
+
+  sendMessage(chatId, text, extra = {}, options = {}) {
+    return this.schedule(chatId, () => this.bot.telegram.sendMessage(chatId, text, extra), options);
+  }
+
+  sendPhoto(chatId, photo, extra = {}, options = {}) {
+    return this.schedule(chatId, () => this.bot.telegram.sendPhoto(chatId, photo, extra), options);
+  }
+
+  editMessageText(chatId, messageId, inlineMessageId, text, extra = {}, options = {}) {
+    return this.schedule(chatId, () =>
+      this.bot.telegram.editMessageText(chatId, messageId, inlineMessageId, text, extra), options);
+  }
+
+  pinChatMessage(chatId, messageId, extra = {}, options = {}) {
+    return this.schedule(chatId, () => this.bot.telegram.pinChatMessage(chatId, messageId, extra), options);
+  }
+
+  /**
+   * Queue a Telegram API call for a chat
+   * @param {string|number} chatId - Target chat, used for per-chat limits
+   * @param {Function} run - Performs the API call
+   * @param {Object} options
+   * @param {number} options.priority - One of PRIORITY (default NORMAL)
+   * @param {number} options.maxRetries - Retries after a 429 before the error is thrown
+   * @returns {Promise<*>} Result of the API call
+   */
+  schedule(chatId, run, { priority = PRIORITY.NORMAL, maxRetries = MAX_RATE_LIMIT_RETRIES } = {}) {
+    return new Promise((resolve, reject) => {
+      this.queue.push({
+        chatId: String(chatId),
+        priority,
+        maxRetries,
+        sequence: this.sequence++,
+        run,
+        resolve,
+        reject,
+        retries: 0
+      });
+      this.pump();
+    });
+  }
+
+  getChat(chatId) {
+    if (!this.chats.has(chatId)) {
+      this.chats.set(chatId, { sentAt: [], blockedUntil: 0 });
+    }
+    return this.chats.get(chatId);
+  }
+
+  isGroupChat(chatId) {
+    // Groups, supergroups and channels have negative ids (or are addressed by @username)
+    return chatId.startsWith('-') || chatId.startsWith('@');
+  }
+
+  /**
+   * Earliest time a message may be sent to the chat
+   */
+  chatReadyAt(chatId, now) {
+    const chat = this.getChat(chatId);
+    chat.sentAt = chat.sentAt.filter(time => now - time < GROUP_WINDOW_MS);
+
+    let readyAt = chat.blockedUntil;
+    const last = chat.sentAt[chat.sentAt.length - 1];
+
+    if (this.isGroupChat(chatId)) {
+      if (chat.sentAt.length >= this.groupPerMinute) {
+        readyAt = Math.max(readyAt, chat.sentAt[chat.sentAt.length - this.groupPerMinute] + GROUP_WINDOW_MS);
+      }
+    } else if (last) {
+      readyAt = Math.max(readyAt, last + PRIVATE_CHAT_INTERVAL_MS);
+    }
+
+    return readyAt;
+  }
+
+  pump() {
+    if (this.timer) return;
+
+    while (this.queue.length > 0) {
+      const now = Date.now();
+      this.globalSentAt = this.globalSentAt.filter(time => now - time < GLOBAL_WINDOW_MS);
+
+      if (this.globalSentAt.length >= this.globalPerSecond) {
+        this.wakeAt(this.globalSentAt[0] + GLOBAL_WINDOW_MS);
+        return;
+      }
+
+      // Highest priority first, then oldest; per-chat order within a priority is kept
+      // because a chat that isn't ready skips all of its queued calls
+      this.queue.sort((a, b) => a.priority - b.priority || a.sequence - b.sequence);
+
+      let nextReadyAt = Infinity;
+      const readyIndex = this.queue.findIndex(job => {
+        const readyAt = this.chatReadyAt(job.chatId, now);
+        nextReadyAt = Math.min(nextReadyAt, readyAt);
+        return readyAt <= now;
+      });
+
+      if (readyIndex === -1) {
+        this.wakeAt(nextReadyAt);
+        return;
+      }
+
+      const [job] = this.queue.splice(readyIndex, 1);
+      this.globalSentAt.push(now);
+      this.getChat(job.chatId).sentAt.push(now);
+      this.execute(job);
+    }
+  }
+
+  wakeAt(time) {
+    this.timer = setTimeout(() => {
+      this.timer = null;
+      this.pump();
+    }, Math.max(time - Date.now(), 10));
+  }
+
+  async execute(job) {
+    try {
+      job.resolve(await job.run());
+    } catch (error) {
+      if (error.response?.error_code === 429) {
+        // Hold back everything else queued for this chat too
+        const retryAfterMs = (error.response.parameters?.retry_after || 1) * 1000;
+        this.getChat(job.chatId).blockedUntil = Date.now() + retryAfterMs;
+        if (job.retries >= job.maxRetries) {
+          job.reject(error);
+          this.pump();
+          return;
+        }
+        job.retries++;
+        logger.warn(`⏳ Telegram rate limit for chat ${job.chatId}, retrying in ${retryAfterMs / 1000}s (attempt ${job.retries}/${job.maxRetries})`);
+        this.queue.push(job);
+        this.pump();
+        return;
+      }
+      job.reject(error);
+    }
+  }
+
+  stop() {
+    if (this.timer) {
+      clearTimeout(this.timer);
+      this.timer = null;
+    }
+    const pending = this.queue.splice(0);
+    pending.forEach(job => job.reject(new Error('Telegram sender stopped')));
+  }
+
+  getStats() {
+    const byPriority = { alert: 0, normal: 0, background: 0 };
+    const names = Object.fromEntries(Object.entries(PRIORITY).map(([name, value]) => [value, name.toLowerCase()]));
+    this.queue.forEach(job => { byPriority[names[job.priority]]++; });
+    return { queued: this.queue.length, byPriority };
+  }
+}
+
+module.exports = TelegramSender;
+module.exports.PRIORITY = PRIORITY;
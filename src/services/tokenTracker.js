@@ -2,7 +2,7 @@ const logger = require('./logger');
 const CollectionResolver = require('./collectionResolver');
 
 class TokenTracker {
-  constructor(database, openSeaService, webhookHandlers = null, chainManager = null, magicEdenService = null, heliusService = null, magicEdenOrdinalsService = null, bitcoinPollerService = null) {
+  constructor(database, openSeaService, webhookHandlers = null, chainManager = null, magicEdenService = null, heliusService = null, magicEdenOrdinalsService = null, bitcoinPollerService = null, hiroOrdinalsService = null) {
     this.db = database;
     this.openSea = openSeaService;
     this.webhookHandlers = webhookHandlers; // Add webhook handlers reference
@@ -10,7 +10,8 @@ class TokenTracker {
     this.magicEden = magicEdenService; // Magic Eden service for Solana NFT validation
     this.helius = heliusService; // Helius service for Solana webhook management
     this.magicEdenOrdinals = magicEdenOrdinalsService; // Magic Eden service for Bitcoin Ordinals validation
-    this.bitcoinPoller = bitcoinPollerService; // Bitcoin Ordinals Poller service (replaces Hiro)
+    this.bitcoinPoller = bitcoinPollerService; // Bitcoin Ordinals Poller service (sales and listings)
+    this.hiroOrdinals = hiroOrdinalsService; // Hiro Chainhook service for Bitcoin Ordinals transfers
     this.trackingIntervals = new Map();
     this.openSeaSubscriptions = new Map(); // Track OpenSea collection subscriptions
     this.heliusWebhooks = new Map(); // Track Helius webhook subscriptions for Solana
@@ -61,6 +62,9 @@ class TokenTracker {
 
       await this.loadExistingTokens();
 
+      // Register (or drop) the chainhook predicates to match the tracked Bitcoin collections
+      await this.syncHiroPredicates();
+
       this.startPeriodicTasks();
       logger.info('Token tracker initialized successfully');
       return true;
@@ -477,6 +481,11 @@ class TokenTracker {
           this.trackingIntervals.delete(contractAddress);
           logger.info(`   ✅ Cleared tracking interval for ${contractAddress}`);
         }
+
+        // Drop the collection's chainhook predicate
+        if (token.chain_name === 'bitcoin') {
+          await this.syncHiroPredicates();
+        }
       } else {
         logger.info(`   ⚠️ Token ${contractAddress} still has active subscriptions, keeping all services active`);
       }
@@ -548,6 +557,19 @@ class TokenTracker {
       // Floor price tracking removed with Alchemy deprecation
       // OpenSea API can be used for floor price data if needed in the future
 
+      // Rarity snapshots older than RARITY_REFRESH_HOURS are retaken in the background
+      if (this.webhookHandlers?.rarity) {
+        const tokens = await this.db.all(
+          'SELECT * FROM tracked_tokens WHERE contract_address = $1 AND is_active = true',
+          [contractAddress]
+        );
+        for (const token of tokens || []) {
+          this.webhookHandlers.rarity.refreshIfStale(token).catch(error => {
+            logger.error(`Error refreshing rarity for ${contractAddress}:`, error);
+          });
+        }
+      }
+
 
       if (floorPrice) {
         await this.db.run(
@@ -613,10 +635,11 @@ class TokenTracker {
       };
 
 
+      const oneDayAgo = new Date(Date.now() - 24 * 60 * 60 * 1000).toISOString();
       const activityCount = await this.db.get(
         `SELECT COUNT(*) as count FROM nft_activities 
-         WHERE contract_address = $1 AND created_at > NOW() - INTERVAL '1 day'`,
-        [contractAddress]
+         WHERE contract_address = $1 AND created_at > $2`,
+        [contractAddress, oneDayAgo]
       );
 
       stats.activity_24h = activityCount ? activityCount.count : 0;
@@ -1445,6 +1468,7 @@ class TokenTracker {
           if (hasPremiumFeatures) {
             await this.db.run('UPDATE tracked_tokens SET is_active = true WHERE id = $1', [existingToken.id]);
             logger.info(`🔄 Reactivated inactive Bitcoin Ordinals collection: ${identifier}`);
+            await this.syncHiroPredicates();
           } else {
             // Delete and recreate - delete all foreign key references first
             await this.db.run('DELETE FROM pending_payments WHERE token_id = $1', [existingToken.id]);
@@ -1484,6 +1508,41 @@ class TokenTracker {
     }
   }
 
+  /**
+   * Keep the Hiro chainhook predicates in line with the tracked Bitcoin collections: one per
+   * active collection, plus one while a Bitcoin wallet is watched
+   * @returns {Promise<Object|null>} Sync result, or null when Hiro is not configured
+   */
+  async syncHiroPredicates() {
+    if (!this.hiroOrdinals || !this.hiroOrdinals.canManagePredicates()) {
+      return null;
+    }
+
+    try {
+      const collections = (await this.db.all(
+        'SELECT DISTINCT collection_slug FROM tracked_tokens WHERE chain_name = $1 AND collection_slug IS NOT NULL AND is_active = true',
+        ['bitcoin']
+      )).map(row => row.collection_slug);
+      const walletRow = await this.db.get(
+        'SELECT COUNT(*) as count FROM watched_wallets WHERE chain_name = $1',
+        ['bitcoin']
+      );
+      const watchedWallets = parseInt(walletRow?.count) || 0;
+
+      const result = await this.hiroOrdinals.syncPredicates({ collections, wallets: watchedWallets > 0 });
+      if (result.created?.length || result.deleted?.length) {
+        logger.info(`₿ Hiro chainhook predicates synced: ${result.created.length} registered, ${result.deleted.length} deleted (${collections.length} Bitcoin collection(s), ${watchedWallets} watched wallet(s))`);
+      }
+      if (!result.success) {
+        logger.warn(`₿ Hiro chainhook predicates out of sync: ${result.error || result.errors.join('; ')}`);
+      }
+      return result;
+    } catch (error) {
+      logger.error('Error syncing Hiro chainhook predicates:', error);
+      return { success: false, error: error.message };
+    }
+  }
+
   /**
    * Create a fresh Bitcoin Ordinals token record
    * @param {string} identifier - Bitcoin inscription ID or collection symbol
@@ -1570,6 +1629,9 @@ class TokenTracker {
         }
       }
 
+      // Inscription transfers arrive through the Hiro chainhook
+      await this.syncHiroPredicates();
+
       // Build success message
       let successMessage = `✅ *${validation.name || 'Bitcoin Ordinals Collection'}* added successfully!\n\n`;
       successMessage += `₿ *Chain:* Bitcoin\n`;
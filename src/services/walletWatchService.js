@@ -0,0 +1,396 @@
+const logger = require('./logger');
+
+const CACHE_TTL_MS = 60 * 1000;
+const DEFAULT_WALLET_LIMIT = 25;
+const MAX_LABEL_LENGTH = 32;
+
+// Helius delivers every transaction of a watched Solana wallet; NFT movements are picked out on receipt
+const HELIUS_TRANSACTION_TYPES = ['ANY'];
+const HELIUS_NETWORK = 'solana';
+
+const EVM_ADDRESS = /^0x[a-fA-F0-9]{40}$/;
+const BITCOIN_BECH32_ADDRESS = /^bc1[a-zA-HJ-NP-Z0-9]{25,87}$/i;
+const BITCOIN_LEGACY_ADDRESS = /^[13][1-9A-HJ-NP-Za-km-z]{25,33}$/;
+const SOLANA_ADDRESS = /^[1-9A-HJ-NP-Za-km-z]{32,44}$/;
+
+/**
+ * Work out the chain of a wallet address and normalize it for storage
+ * @param {string} address - Address as typed by the user
+ * @param {string|null} chainName - Chain named by the user; picks the EVM chain of 0x addresses
+ * @returns {{chain: string, address: string}|null} Null if the address is not valid (on that chain)
+ */
+function parseWalletAddress(address, chainName = null) {
+  const value = String(address || '').trim();
+  const evmChain = chainName && !['solana', 'bitcoin'].includes(chainName) ? chainName : 'ethereum';
+
+  let wallet = null;
+  if (EVM_ADDRESS.test(value)) {
+    wallet = { chain: evmChain, address: value.toLowerCase() };
+  } else if (chainName === 'solana' && SOLANA_ADDRESS.test(value)) {
+    // Short Solana addresses can look like legacy Bitcoin ones
+    wallet = { chain: 'solana', address: value };
+  } else if (BITCOIN_BECH32_ADDRESS.test(value)) {
+    // Bech32 is case-insensitive and Hiro reports it in lowercase
+    wallet = { chain: 'bitcoin', address: value.toLowerCase() };
+  } else if (BITCOIN_LEGACY_ADDRESS.test(value)) {
+    wallet = { chain: 'bitcoin', address: value };
+  } else if (SOLANA_ADDRESS.test(value)) {
+    wallet = { chain: 'solana', address: value };
+  }
+
+  return wallet && (!chainName || wallet.chain === chainName) ? wallet : null;
+}
+
+/**
+ * @param {string} chainName - Chain the address is on
+ * @param {string} address - Address from a webhook payload
+ * @returns {string} Address in the form stored in watched_wallets
+ */
+function normalizeWalletAddress(chainName, address) {
+  if (!address) return address;
+  if (chainName === 'solana') return address;
+  if (chainName === 'bitcoin') return BITCOIN_BECH32_ADDRESS.test(address) ? address.toLowerCase() : address;
+  return address.toLowerCase();
+}
+
+function sanitizeLabel(label) {
+  const cleaned = String(label || '').replace(/[*_`[\]<>]/g, '').replace(/\s+/g, ' ').trim();
+  return cleaned ? cleaned.slice(0, MAX_LABEL_LENGTH) : null;
+}
+
+/**
+ * Wallet watchlists: chats follow wallets and get an alert for every NFT the wallet buys,
+ * sells, mints or moves, in any collection.
+ *
+ * Activity is delivered by one provider webhook per network (Alchemy ADDRESS_ACTIVITY for
+ * EVM chains, a Helius enhanced webhook for Solana), whose address list is replaced with
+ * every watched address of that network whenever a watchlist changes. Bitcoin wallets have
+ * their own Hiro chainhook predicate; since Hiro only names the sender of a transfer by the
+ * output it spends, the outputs holding each watched wallet's inscriptions are kept in memory.
+ */
+class WalletWatchService {
+  constructor(database, options = {}) {
+    this.db = database;
+    this.chainManager = options.chainManager || null;
+    this.helius = options.helius || null;
+    this.alchemy = options.alchemy || null;
+    this.hiro = options.hiro || null;
+    this.onBitcoinWatchChange = options.onBitcoinWatchChange || null;
+    this.walletLimit = parseInt(process.env.WATCH_WALLET_LIMIT) || DEFAULT_WALLET_LIMIT;
+    this.webhookBaseUrl = process.env.WEBHOOK_URL ? process.env.WEBHOOK_URL.replace(/\/$/, '') : null;
+    this.cacheTtlMs = options.cacheTtlMs ?? CACHE_TTL_MS;
+    this.cache = new Map(); // chain -> { watchers: Map(address -> rows), expiresAt }
+    this.bitcoinOutputs = new Map(); // "<txid>:<vout>" -> watched Bitcoin address holding it
+  }
+
+  async initialize() {
+    const rows = await this.db.all('SELECT DISTINCT chain_name FROM watched_wallets');
+    for (const row of rows) {
+      const result = await this.syncChain(row.chain_name);
+      if (!result.success) {
+        logger.warn(`👛 Wallet webhook for ${row.chain_name} not synced: ${result.error}`);
+      }
+    }
+
+    const bitcoinWallets = await this.db.all(
+      'SELECT DISTINCT address FROM watched_wallets WHERE chain_name = $1',
+      ['bitcoin']
+    );
+    for (const wallet of bitcoinWallets) {
+      await this.loadBitcoinOutputs(wallet.address);
+    }
+
+    logger.info(`👛 Wallet watch service initialized (${rows.length} chain(s) with watched wallets)`);
+  }
+
+  /**
+   * @param {string} chainName - Chain name
+   * @returns {boolean} True if new wallets on this chain can be delivered
+   */
+  isChainAvailable(chainName) {
+    if (chainName === 'bitcoin') {
+      return !!this.hiro?.canManagePredicates();
+    }
+    if (!this.webhookBaseUrl) {
+      return false;
+    }
+    if (chainName === 'solana') {
+      return !!(this.helius?.apiKey && this.helius?.webhookAuthToken);
+    }
+    return !!(this.alchemy?.isConfigured() && this.chainManager?.getChain(chainName)?.alchemyNetwork);
+  }
+
+  /**
+   * Watch a wallet in a chat, or relabel it if it is already watched there
+   * @param {Object} chat - { chatId, chatType, addedBy }
+   * @param {Object} wallet - { chain, address } from parseWalletAddress
+   * @param {string|null} label - Optional label shown in alerts
+   * @returns {Promise<Object>} { status: 'added'|'updated'|'limit', synced }
+   */
+  async addWallet({ chatId, chatType, addedBy }, { chain, address }, label = null) {
+    const key = String(chatId);
+    const cleanLabel = sanitizeLabel(label);
+
+    const existing = await this.db.get(
+      'SELECT id FROM watched_wallets WHERE chat_id = $1 AND chain_name = $2 AND address = $3',
+      [key, chain, address]
+    );
+    if (existing) {
+      await this.db.query('UPDATE watched_wallets SET label = $1 WHERE id = $2', [cleanLabel, existing.id]);
+      this.cache.delete(chain);
+      return { status: 'updated', synced: true };
+    }
+
+    const countRow = await this.db.get('SELECT COUNT(*) as count FROM watched_wallets WHERE chat_id = $1', [key]);
+    if ((parseInt(countRow?.count) || 0) >= this.walletLimit) {
+      return { status: 'limit', limit: this.walletLimit };
+    }
+
+    await this.db.query(
+      `INSERT INTO watched_wallets (chat_id, chat_type, chain_name, address, label, added_by)
+       VALUES ($1, $2, $3, $4, $5, $6)`,
+      [key, chatType || null, chain, address, cleanLabel, addedBy != null ? String(addedBy) : null]
+    );
+    logger.info(`👛 Chat ${key} now watches ${chain} wallet ${address}`);
+
+    if (chain === 'bitcoin') {
+      await this.loadBitcoinOutputs(address);
+    }
+    const result = await this.syncChain(chain);
+    return { status: 'added', synced: result.success };
+  }
+
+  /**
+   * Stop watching a wallet in a chat
+   * @param {string|number} chatId - Telegram chat ID
+   * @param {string} address - Wallet address
+   * @returns {Promise<number>} Number of watches removed (one per chain)
+   */
+  async removeWallet(chatId, address) {
+    const key = String(chatId);
+    const rows = await this.db.all(
+      'SELECT id, chain_name, address FROM watched_wallets WHERE chat_id = $1 AND LOWER(address) = LOWER($2)',
+      [key, String(address).trim()]
+    );
+
+    for (const row of rows) {
+      await this.db.query('DELETE FROM watched_wallets WHERE id = $1', [row.id]);
+      logger.info(`👛 Chat ${key} stopped watching ${row.chain_name} wallet ${row.address}`);
+    }
+    for (const chain of new Set(rows.map(row => row.chain_name))) {
+      await this.syncChain(chain);
+    }
+    return rows.length;
+  }
+
+  async listWallets(chatId) {
+    return this.db.all(
+      'SELECT chain_name, address, label, created_at FROM watched_wallets WHERE chat_id = $1 ORDER BY created_at ASC, id ASC',
+      [String(chatId)]
+    );
+  }
+
+  async loadWatchers(chainName) {
+    const cached = this.cache.get(chainName);
+    if (cached && cached.expiresAt > Date.now()) {
+      return cached.watchers;
+    }
+
+    const watchers = new Map();
+    try {
+      const rows = await this.db.all('SELECT * FROM watched_wallets WHERE chain_name = $1', [chainName]);
+      for (const row of rows) {
+        if (!watchers.has(row.address)) watchers.set(row.address, []);
+        watchers.get(row.address).push(row);
+      }
+    } catch (error) {
+      logger.error(`Error loading watched ${chainName} wallets:`, error.message);
+      return watchers;
+    }
+
+    this.cache.set(chainName, { watchers, expiresAt: Date.now() + this.cacheTtlMs });
+    return watchers;
+  }
+
+  /**
+   * @param {string} chainName - Chain name
+   * @param {string} address - Address from a webhook payload
+   * @returns {Promise<Array<Object>>} watched_wallets rows for that address
+   */
+  async getWatchers(chainName, address) {
+    if (!address) return [];
+    const watchers = await this.loadWatchers(chainName);
+    return watchers.get(normalizeWalletAddress(chainName, address)) || [];
+  }
+
+  async hasWallets(chainName) {
+    return (await this.loadWatchers(chainName)).size > 0;
+  }
+
+  /**
+   * Point the provider webhook of a chain at its current watched addresses
+   * @param {string} chainName - Chain name
+   * @returns {Promise<Object>} { success, error }
+   */
+  async syncChain(chainName) {
+    this.cache.delete(chainName);
+
+    try {
+      if (chainName === 'bitcoin') {
+        if (this.onBitcoinWatchChange) {
+          const result = await this.onBitcoinWatchChange();
+          return { success: result?.success !== false, error: result?.error };
+        }
+        return { success: false, error: 'Hiro chainhooks not configured' };
+      }
+
+      if (!this.isChainAvailable(chainName)) {
+        return { success: false, error: `Wallet webhooks not configured for ${chainName}` };
+      }
+
+      const rows = await this.db.all(
+        'SELECT DISTINCT address FROM watched_wallets WHERE chain_name = $1',
+        [chainName]
+      );
+      const addresses = rows.map(row => row.address);
+
+      if (chainName === 'solana') {
+        const webhookURL = `${this.webhookBaseUrl}/webhook/helius`;
+        return await this.syncProviderWebhook('helius', HELIUS_NETWORK, addresses, {
+          create: () => this.helius.createWebhook(webhookURL, addresses, 'minttechbot-wallets', HELIUS_TRANSACTION_TYPES),
+          update: (webhookId) => this.helius.setWebhookAddresses(webhookId, webhookURL, addresses, HELIUS_TRANSACTION_TYPES),
+          remove: (webhookId) => this.helius.deleteWebhook(webhookId)
+        });
+      }
+
+      const network = this.chainManager.getChain(chainName).alchemyNetwork;
+      const webhookURL = `${this.webhookBaseUrl}/webhook/alchemy`;
+      return await this.syncProviderWebhook('alchemy', network, addresses, {
+        create: () => this.alchemy.createAddressWebhook(network, webhookURL, addresses),
+        update: (webhookId) => this.alchemy.replaceWebhookAddresses(webhookId, addresses),
+        remove: (webhookId) => this.alchemy.deleteWebhook(webhookId)
+      });
+    } catch (error) {
+      logger.error(`Error syncing wallet webhook for ${chainName}:`, error);
+      return { success: false, error: error.message };
+    }
+  }
+
+  /**
+   * Create, update or delete the webhook stored for a provider network
+   * @param {string} provider - alchemy or helius
+   * @param {string} network - Provider network name
+   * @param {Array<string>} addresses - Every watched address on the network
+   * @param {Object} api - { create(), update(webhookId), remove(webhookId) }
+   */
+  async syncProviderWebhook(provider, network, addresses, api) {
+    const existing = await this.db.get(
+      'SELECT webhook_id FROM wallet_webhooks WHERE provider = $1 AND network = $2',
+      [provider, network]
+    );
+
+    if (addresses.length === 0) {
+      if (existing) {
+        await api.remove(existing.webhook_id);
+        await this.db.query('DELETE FROM wallet_webhooks WHERE provider = $1 AND network = $2', [provider, network]);
+        logger.info(`👛 Removed ${provider} wallet webhook for ${network}, no wallets left`);
+      }
+      return { success: true };
+    }
+
+    if (existing) {
+      const result = await api.update(existing.webhook_id);
+      // A webhook deleted on the provider's side is created again below
+      if (result.success || !result.notFound) {
+        return result;
+      }
+    }
+
+    const created = await api.create();
+    if (!created.success) {
+      return created;
+    }
+
+    await this.db.query(
+      `INSERT INTO wallet_webhooks (provider, network, webhook_id, signing_key, updated_at)
+       VALUES ($1, $2, $3, $4, NOW())
+       ON CONFLICT (provider, network) DO UPDATE SET
+         webhook_id = EXCLUDED.webhook_id,
+         signing_key = EXCLUDED.signing_key,
+         updated_at = NOW()`,
+      [provider, network, created.webhookId, created.signingKey || null]
+    );
+    logger.info(`👛 ${provider} wallet webhook for ${network}: ${created.webhookId}`);
+    return created;
+  }
+
+  /**
+   * @param {string} webhookId - Alchemy webhook ID from a payload
+   * @returns {Promise<string|null>} Signing key of a wallet webhook
+   */
+  async getWebhookSigningKey(webhookId) {
+    if (!webhookId) return null;
+    const row = await this.db.get(
+      'SELECT signing_key FROM wallet_webhooks WHERE provider = $1 AND webhook_id = $2',
+      ['alchemy', webhookId]
+    );
+    return row?.signing_key || null;
+  }
+
+  async loadBitcoinOutputs(address) {
+    if (!this.hiro) return;
+    try {
+      const outputs = await this.hiro.getAddressInscriptionOutputs(address);
+      outputs.forEach(output => this.bitcoinOutputs.set(output, address));
+      logger.debug(`👛 Loaded ${outputs.length} inscription output(s) of ${address}`);
+    } catch (error) {
+      logger.warn(`Could not load inscriptions of ${address}: ${error.message}`);
+    }
+  }
+
+  /**
+   * Name the watched sender of a Hiro inscription transfer and keep the output map current
+   * @param {Object} transferData - Parsed by HiroOrdinalsService.parseInscriptionTransfer
+   * @returns {Promise<Object>} { sender, recipient } with watched addresses, or null
+   */
+  async resolveBitcoinTransfer(transferData) {
+    const sender = this.bitcoinOutputs.get(transferData.sender) || null;
+    if (sender) {
+      this.bitcoinOutputs.delete(transferData.sender);
+    }
+
+    const recipientWatchers = await this.getWatchers('bitcoin', transferData.recipient);
+    const recipient = recipientWatchers.length > 0 ? recipientWatchers[0].address : null;
+    if (recipient && transferData.recipient_output) {
+      this.bitcoinOutputs.set(transferData.recipient_output, recipient);
+    }
+
+    return { sender, recipient };
+  }
+
+  /**
+   * @param {Object} revealData - Parsed by HiroOrdinalsService.parseInscriptionReveal
+   * @returns {Promise<string|null>} Watched inscriber address
+   */
+  async recordBitcoinReveal(revealData) {
+    const watchers = await this.getWatchers('bitcoin', revealData.inscriber);
+    if (watchers.length === 0) return null;
+    if (revealData.output) {
+      this.bitcoinOutputs.set(revealData.output, watchers[0].address);
+    }
+    return watchers[0].address;
+  }
+
+  async getStats() {
+    const row = await this.db.get('SELECT COUNT(*) as count FROM watched_wallets');
+    return {
+      watchedWallets: parseInt(row?.count) || 0,
+      bitcoinOutputs: this.bitcoinOutputs.size,
+      walletLimit: this.walletLimit
+    };
+  }
+}
+
+module.exports = WalletWatchService;
+module.exports.parseWalletAddress = parseWalletAddress;
+module.exports.normalizeWalletAddress = normalizeWalletAddress;
@@ -0,0 +1,186 @@
+const logger = require('./logger');
+
+const POLL_INTERVAL_MS = 1000;
+const BATCH_SIZE = 20;
+// A payload whose worker died mid-processing is picked up again after this long
+const LEASE_MS = 5 * 60 * 1000;
+const BASE_RETRY_DELAY_MS = 5000;
+const MAX_RETRY_DELAY_MS = 10 * 60 * 1000;
+const DEFAULT_MAX_ATTEMPTS = 5;
+
+/**
+ * Durable inbox for incoming webhooks
+ *
+ * Webhook endpoints verify a delivery, store its payload in webhook_inbox and answer
+ * right away, so the provider never waits on receipts, price lookups or images. The worker
+ * hands payloads to the processor registered for their source, oldest first and one at a
+ * time. A payload whose processor throws is retried with backoff and dropped (logged to
+ * webhook_logs with the error) after WEBHOOK_INBOX_MAX_ATTEMPTS. Payloads are leased while
+ * they are processed, so one left by a crashed worker is processed again once the lease
+ * runs out, by this or another replica.
+ */
+class WebhookInbox {
+  constructor(database, { pollIntervalMs = POLL_INTERVAL_MS, maxAttempts } = {}) {
+    this.db = database;
+    this.pollIntervalMs = pollIntervalMs;
+    this.maxAttempts = maxAttempts ?? (parseInt(process.env.WEBHOOK_INBOX_MAX_ATTEMPTS, 10) || DEFAULT_MAX_ATTEMPTS);
+    this.processors = new Map();
+    this.timer = null;
+    this.busy = null;
+    this.wakeRequested = false;
+    this.stats = { processed: 0, retried: 0, dropped: 0 };
+  }
+
+  /**
+   * Set the function that processes the payloads of a source
+   * @param {string} source - alchemy, helius, hiro
+   * @param {Function} processor - async (payload) => void; throwing schedules a retry
+   */
+  register(source, processor) {
+    this.processors.set(source, processor);
+  }
+
+  /**
+   * Store a payload for processing
+   * @param {string} source - Registered source
+   * @param {Object} payload - Request body
+   * @returns {Promise<{id: number}>}
+   */
+  async enqueue(source, payload) {
+    const result = await this.db.query(
+      'INSERT INTO webhook_inbox (source, payload) VALUES ($1, $2) RETURNING id',
+      [source, JSON.stringify(payload)]
+    );
+    const id = result.rows[0]?.id;
+    logger.debug(`📥 Queued ${source} webhook #${id}`);
+    this.wake();
+    return { id };
+  }
+
+  start() {
+    if (this.timer) return;
+    this.timer = setInterval(() => this.tick(), this.pollIntervalMs);
+    logger.info('📥 Webhook inbox worker started');
+  }
+
+  /**
+   * Stop polling and wait for the payload in flight. Queued payloads stay in the table.
+   */
+  async stop() {
+    clearInterval(this.timer);
+    this.timer = null;
+    if (this.busy) {
+      await this.busy;
+    }
+  }
+
+  wake() {
+    if (!this.timer) return;
+    if (this.busy) {
+      this.wakeRequested = true;
+    } else {
+      setImmediate(() => this.tick());
+    }
+  }
+
+  async tick() {
+    if (this.busy) return;
+    this.busy = (async () => {
+      let count;
+      do {
+        this.wakeRequested = false;
+        count = await this.processDue();
+      } while ((this.wakeRequested || count >= BATCH_SIZE) && this.timer);
+    })()
+      .catch(error => logger.error('Error processing the webhook inbox:', error))
+      .finally(() => { this.busy = null; });
+    await this.busy;
+  }
+
+  /**
+   * Process the payloads that are due, oldest first, up to one batch
+   * @returns {Promise<number>} Number of payloads processed
+   */
+  async processDue() {
+    let count = 0;
+    let row;
+    while (count < BATCH_SIZE && (row = await this.claimNext())) {
+      await this.process(row);
+      count++;
+    }
+    return count;
+  }
+
+  /**
+   * Lease the oldest due payload of a registered source
+   * @returns {Promise<Object|null>} Null when nothing is due
+   */
+  async claimNext() {
+    const sources = [...this.processors.keys()];
+    if (sources.length === 0) return null;
+
+    const now = new Date();
+    const placeholders = sources.map((_, index) => `$${index + 2}`).join(', ');
+    const candidates = await this.db.all(
+      `SELECT id FROM webhook_inbox
+       WHERE next_attempt_at <= $1 AND (locked_until IS NULL OR locked_until < $1)
+         AND source IN (${placeholders})
+       ORDER BY id
+       LIMIT 5`,
+      [now, ...sources]
+    );
+
+    for (const { id } of candidates) {
+      const { rows } = await this.db.query(
+        `UPDATE webhook_inbox SET locked_until = $2, attempts = attempts + 1
+         WHERE id = $1 AND (locked_until IS NULL OR locked_until < $3)
+         RETURNING id, source, payload, attempts`,
+        [id, new Date(Date.now() + LEASE_MS), now]
+      );
+      if (rows.length > 0) return rows[0];
+    }
+    return null;
+  }
+
+  async process(row) {
+    let payload;
+    try {
+      payload = JSON.parse(row.payload);
+      await this.processors.get(row.source)(payload);
+    } catch (error) {
+      await this.handleFailure(row, payload, error);
+      return;
+    }
+
+    await this.db.query('DELETE FROM webhook_inbox WHERE id = $1', [row.id]);
+    this.stats.processed++;
+  }
+
+  async handleFailure(row, payload, error) {
+    const attempts = parseInt(row.attempts, 10);
+    if (attempts >= this.maxAttempts || payload === undefined) {
+      await this.db.query('DELETE FROM webhook_inbox WHERE id = $1', [row.id]);
+      this.stats.dropped++;
+      logger.error(`💀 ${row.source} webhook #${row.id} dropped after ${attempts} attempt(s): ${error.message}`);
+      await this.db.logWebhook(row.source, payload ?? row.payload, false, `Dropped after ${attempts} attempt(s): ${error.message}`)
+        .catch(logError => logger.error('Could not log the dropped webhook:', logError.message));
+      return;
+    }
+
+    const delayMs = Math.min(BASE_RETRY_DELAY_MS * 2 ** (attempts - 1), MAX_RETRY_DELAY_MS);
+    await this.db.query(
+      `UPDATE webhook_inbox SET locked_until = NULL, next_attempt_at = $2, last_error = $3
+       WHERE id = $1`,
+      [row.id, new Date(Date.now() + delayMs), error.message]
+    );
+    this.stats.retried++;
+    logger.warn(`🔁 ${row.source} webhook #${row.id} failed (attempt ${attempts}/${this.maxAttempts}), retrying in ${delayMs / 1000}s: ${error.message}`);
+  }
+
+  async getStats() {
+    const row = await this.db.get('SELECT COUNT(*) AS pending FROM webhook_inbox');
+    return { pending: parseInt(row?.pending, 10) || 0, ...this.stats };
+  }
+}
+
+module.exports = WebhookInbox;
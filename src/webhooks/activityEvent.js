@@ -0,0 +1,703 @@
+const crypto = require('crypto');
+const { marketplaceRegistry } = require('../services/marketplaceRegistry');
+
+/**
+ * Canonical activity event shared by every source (Alchemy, OpenSea Stream, Helius,
+ * Magic Eden Ordinals poller, Hiro Chainhook). Each source has a small adapter below that
+ * maps its payload into this shape; WebhookHandlers.routeActivityEvent() then logs,
+ * publishes, renders and sends it the same way regardless of where it came from.
+ *
+ * @typedef {Object} ActivityEvent
+ * @property {string} id - Stable chain-level identity (chain + tx/log or order reference + item + type)
+ * @property {string} source - alchemy, opensea, helius, magiceden_ordinals or hiro
+ * @property {string} chain - Chain name as used in tracked_tokens.chain_name
+ * @property {string} type - One of ACTIVITY_TYPES
+ * @property {{name: string|null, slug: string|null, contractAddress: string|null}} collection
+ * @property {string|null} contractAddress - Contract (EVM) the item belongs to
+ * @property {string|null} tokenId - Token ID, Solana mint address or inscription ID
+ * @property {string|null} nftName - Display name of the item when the source provides one
+ * @property {string|null} imageUrl - Item image when the source provides one
+ * @property {string|null} itemUrl - Marketplace page for the item
+ * @property {{raw: string, decimals: number, currency: string, address: string|null, usd: number|null, native: number|null}|null} price
+ *   Amount in the payment token's base units. address is the token contract (null for the native
+ *   currency); usd and native (value in the chain's native currency) are filled in by
+ *   WebhookHandlers.normalizeEventPrice when the source doesn't report them.
+ * @property {string|null} royalty - Creator royalty paid on a sale, in the price's base units
+ *   (read from the transaction receipt by SaleAttributionService)
+ * @property {string|null} seller - Seller, or sender for transfers/burns
+ * @property {string|null} buyer - Buyer (bidder for offers), or recipient for transfers/mints
+ * @property {string|null} marketplace - Marketplace display name
+ * @property {string|null} txHash - Transaction hash / signature / txid
+ * @property {number|null} blockNumber - Block number, slot or block height
+ * @property {number|null} logIndex - Log index within the transaction (EVM)
+ * @property {string|null} reference - Order hash or source activity ID for events without a transaction
+ * @property {string} occurredAt - ISO timestamp; the block time for priced on-chain events (sources
+ *   that send their delivery time get it from SaleAttributionService.stampBlockTime)
+ */
+
+const ACTIVITY_TYPES = ['sale', 'listing', 'offer', 'mint', 'transfer', 'burn'];
+
+// nft_activities.activity_type values; 'buy' predates the canonical model and is kept for sales
+const ACTIVITY_RECORD_TYPES = {
+  sale: 'buy',
+  listing: 'listing',
+  offer: 'offer',
+  mint: 'mint',
+  transfer: 'transfer',
+  burn: 'burn'
+};
+
+const ACTIVITY_LABELS = {
+  sale: { emoji: '💰🟢', action: '**Buy**', priceLabel: 'Buy Price' },
+  listing: { emoji: '📝', action: 'Listed', priceLabel: 'List Price' },
+  offer: { emoji: '💱', action: 'Offer Received', priceLabel: 'Offer Amount' },
+  mint: { emoji: '✨', action: 'Mint', priceLabel: 'Mint Price' },
+  transfer: { emoji: '🔄', action: 'Transfer', priceLabel: 'Value' },
+  burn: { emoji: '🔥', action: 'Burn', priceLabel: 'Value' }
+};
+
+const ZERO_ADDRESS = '0x0000000000000000000000000000000000000000';
+
+// OpenSea asset URLs use their own chain slugs; unsupported chains fall back to ethereum
+const OPENSEA_CHAIN_SLUGS = {
+  ethereum: 'ethereum',
+  arbitrum: 'arbitrum',
+  optimism: 'optimism',
+  base: 'base',
+  avalanche: 'avalanche',
+  berachain: 'bera_chain',
+  apechain: 'ape_chain',
+  abstract: 'abstract',
+  ronin: 'ronin',
+  sei: 'sei'
+};
+
+const EXPLORER_TX_URLS = {
+  ethereum: { name: 'Etherscan', url: 'https://etherscan.io/tx/' },
+  arbitrum: { name: 'Arbiscan', url: 'https://arbiscan.io/tx/' },
+  optimism: { name: 'Optimism Explorer', url: 'https://optimistic.etherscan.io/tx/' },
+  base: { name: 'Basescan', url: 'https://basescan.org/tx/' },
+  avalanche: { name: 'Snowtrace', url: 'https://snowtrace.io/tx/' },
+  berachain: { name: 'Berascan', url: 'https://berascan.com/tx/' },
+  apechain: { name: 'ApeScan', url: 'https://apescan.io/tx/' },
+  abstract: { name: 'Abscan', url: 'https://abscan.org/tx/' },
+  ronin: { name: 'Ronin Explorer', url: 'https://app.roninchain.com/tx/' },
+  sei: { name: 'Seitrace', url: 'https://seitrace.com/tx/' },
+  solana: { name: 'Solana Explorer', url: 'https://explorer.solana.com/tx/' },
+  bitcoin: { name: 'Bitcoin Explorer', url: 'https://mempool.space/tx/' }
+};
+
+const ORDINALS_TYPES = {
+  buying_broadcasted: 'sale',
+  sale: 'sale',
+  listing: 'listing',
+  list: 'listing',
+  transfer: 'transfer',
+  mint: 'mint'
+};
+
+// Marketplaces named in Helius enhanced transactions
+const HELIUS_SOURCES = {
+  MAGIC_EDEN: 'Magic Eden',
+  TENSOR: 'Tensor'
+};
+
+// Solana token standards that are NFTs (Helius tokenTransfers)
+const SOLANA_NFT_STANDARDS = ['NonFungible', 'ProgrammableNonFungible', 'NonFungibleEdition'];
+
+const ON_CHAIN_TYPES = ['sale', 'mint', 'transfer', 'burn'];
+const NON_EVM_CHAINS = ['solana', 'bitcoin'];
+
+const OPENSEA_TYPES = {
+  sold: 'sale',
+  listed: 'listing',
+  received_bid: 'offer',
+  received_offer: 'offer',
+  transferred: 'transfer'
+};
+
+function toIsoTime(value) {
+  if (value == null || value === '') return new Date().toISOString();
+  // Unix seconds (Helius, Ordinals block time) vs milliseconds / ISO strings
+  const date = new Date(typeof value === 'number' && value < 1e12 ? value * 1000 : value);
+  return isNaN(date.getTime()) ? new Date().toISOString() : date.toISOString();
+}
+
+function toNumberOrNull(value) {
+  if (value == null || value === '') return null;
+  const number = typeof value === 'string' && value.startsWith('0x') ? parseInt(value, 16) : Number(value);
+  return Number.isFinite(number) ? number : null;
+}
+
+/**
+ * Normalize an EVM token ID: hex topics/IDs become decimal strings
+ */
+function normalizeTokenId(tokenId) {
+  if (tokenId == null || tokenId === '') return null;
+  if (typeof tokenId === 'string' && tokenId.startsWith('0x')) {
+    try {
+      return BigInt(tokenId).toString();
+    } catch (error) {
+      return tokenId;
+    }
+  }
+  return String(tokenId);
+}
+
+/**
+ * Build a canonical event; adapters pass source fields, everything else is defaulted here
+ * @param {Object} fields - ActivityEvent fields
+ * @returns {ActivityEvent}
+ */
+function createActivityEvent(fields) {
+  if (!ACTIVITY_TYPES.includes(fields.type)) {
+    throw new Error(`Unknown activity type: ${fields.type}`);
+  }
+
+  const price = fields.price?.raw != null && String(fields.price.raw) !== '' ? {
+    raw: String(fields.price.raw),
+    decimals: fields.price.decimals ?? 18,
+    currency: fields.price.currency || 'ETH',
+    address: fields.price.address ? String(fields.price.address).toLowerCase() : null,
+    usd: fields.price.usd != null && Number.isFinite(Number(fields.price.usd)) ? Number(fields.price.usd) : null,
+    native: fields.price.native != null && Number.isFinite(Number(fields.price.native)) ? Number(fields.price.native) : null
+  } : null;
+
+  const event = {
+    source: fields.source,
+    chain: fields.chain || 'ethereum',
+    type: fields.type,
+    collection: {
+      name: fields.collection?.name || null,
+      slug: fields.collection?.slug || null,
+      contractAddress: fields.collection?.contractAddress || null
+    },
+    contractAddress: fields.contractAddress || fields.collection?.contractAddress || null,
+    tokenId: fields.tokenId != null ? String(fields.tokenId) : null,
+    nftName: fields.nftName || null,
+    imageUrl: fields.imageUrl || null,
+    itemUrl: fields.itemUrl || null,
+    price,
+    royalty: fields.royalty != null ? String(fields.royalty) : null,
+    seller: fields.seller || null,
+    buyer: fields.buyer || null,
+    marketplace: fields.marketplace || null,
+    txHash: fields.txHash || null,
+    blockNumber: toNumberOrNull(fields.blockNumber),
+    logIndex: toNumberOrNull(fields.logIndex),
+    reference: fields.reference || null,
+    occurredAt: toIsoTime(fields.occurredAt)
+  };
+
+  // Source is deliberately left out so the same on-chain event gets the same ID from every feed
+  const idSource = [
+    event.chain,
+    event.type,
+    (event.contractAddress || '').toLowerCase(),
+    event.tokenId || '',
+    event.txHash || event.reference || event.occurredAt,
+    event.logIndex ?? ''
+  ].join(':');
+  event.id = crypto.createHash('sha256').update(idSource).digest('hex').slice(0, 32);
+
+  return event;
+}
+
+/**
+ * Row for nft_activities (db.logNFTActivity)
+ * @param {ActivityEvent} event
+ * @returns {Object} Activity data
+ */
+function toActivityRecord(event) {
+  return {
+    contractAddress: event.contractAddress,
+    tokenId: event.tokenId,
+    activityType: ACTIVITY_RECORD_TYPES[event.type],
+    fromAddress: event.seller,
+    toAddress: event.buyer,
+    transactionHash: event.txHash,
+    blockNumber: event.blockNumber,
+    price: event.price?.raw || null,
+    marketplace: event.marketplace
+  };
+}
+
+/**
+ * Chain-level identity of an on-chain EVM token movement, the same whichever feed reports it.
+ * Type and log index are left out: Alchemy may see a sale as a plain transfer, and OpenSea
+ * doesn't send log indexes. Off-chain events (listings, offers) and non-EVM chains have no key.
+ * @param {ActivityEvent} event
+ * @returns {string|null} Dedup key
+ */
+function getChainEventKey(event) {
+  if (NON_EVM_CHAINS.includes(event.chain) || !ON_CHAIN_TYPES.includes(event.type)) return null;
+  if (!event.txHash || !event.contractAddress || event.tokenId == null) return null;
+  return `${event.chain}:${event.contractAddress.toLowerCase()}:${event.tokenId}:${event.txHash.toLowerCase()}`;
+}
+
+function isZeroAddress(address) {
+  const normalized = (address || '').toLowerCase();
+  return !normalized || normalized === ZERO_ADDRESS || normalized === '0x0';
+}
+
+function openSeaItemUrl(chain, contractAddress, tokenId) {
+  if (!contractAddress || tokenId == null) return null;
+  return `https://opensea.io/assets/${OPENSEA_CHAIN_SLUGS[chain] || 'ethereum'}/${contractAddress}/${tokenId}`;
+}
+
+// ==================== SOURCE ADAPTERS ====================
+
+/**
+ * Alchemy NFT_ACTIVITY entry
+ * @param {Object} activity - One entry of payload.event.activity
+ * @param {Object} token - Tracked token row
+ * @param {Object} options
+ * @param {string} options.currency - Native currency symbol of the token's chain
+ * @param {string} options.occurredAt - createdAt of the webhook payload (Alchemy sends no block time;
+ *   priced events get theirs from SaleAttributionService.stampBlockTime)
+ * @returns {ActivityEvent}
+ */
+function fromAlchemyActivity(activity, token, { currency = 'ETH', occurredAt = null } = {}) {
+  const from = activity.fromAddress?.toLowerCase() || null;
+  const to = activity.toAddress?.toLowerCase() || null;
+  const chain = token.chain_name || 'ethereum';
+  const marketplace = to ? marketplaceRegistry.getMarketplaceName(chain, to) : null;
+
+  let type = 'transfer';
+  if (isZeroAddress(from)) {
+    type = 'mint';
+  } else if (isZeroAddress(to)) {
+    type = 'burn';
+  } else if (marketplace || marketplaceRegistry.isMarketplaceAddress(chain, from) || marketplaceRegistry.isMarketplaceAddress(chain, to)) {
+    type = 'sale';
+  }
+
+  let rawPrice = null;
+  if (activity.value && parseFloat(activity.value) > 0) {
+    rawPrice = activity.value.toString();
+  } else if (activity.metadata?.value) {
+    rawPrice = activity.metadata.value.toString();
+  }
+
+  const tokenId = normalizeTokenId(
+    activity.tokenId || activity.erc721TokenId || activity.erc1155Metadata?.[0]?.tokenId || activity.log?.topics?.[3]
+  );
+
+  return createActivityEvent({
+    source: 'alchemy',
+    chain,
+    type,
+    collection: { name: token.token_name, slug: token.collection_slug, contractAddress: token.contract_address },
+    contractAddress: activity.contractAddress,
+    tokenId,
+    itemUrl: openSeaItemUrl(chain, activity.contractAddress, tokenId),
+    price: rawPrice ? { raw: rawPrice, decimals: 18, currency } : null,
+    seller: activity.fromAddress,
+    buyer: activity.toAddress,
+    marketplace,
+    txHash: activity.hash,
+    blockNumber: activity.blockNum,
+    logIndex: activity.log?.logIndex,
+    occurredAt
+  });
+}
+
+function isAlchemyNftTransfer(activity) {
+  return activity.erc721TokenId != null || activity.erc1155Metadata?.length > 0 ||
+    ['erc721', 'erc1155'].includes(activity.category);
+}
+
+/**
+ * Total of Alchemy value transfers in one currency (the first one found)
+ * @param {Array<Object>} payments - ADDRESS_ACTIVITY entries that move ETH or ERC20 tokens
+ * @param {string} currency - Native currency symbol
+ * @returns {{raw: string, decimals: number, currency: string, address: string|null}|null}
+ */
+function sumAlchemyPayments(payments, currency) {
+  let total = null;
+  for (const payment of payments) {
+    const isNative = payment.category === 'external' || payment.category === 'internal';
+    let raw;
+    try {
+      raw = BigInt(payment.rawContract?.rawValue);
+    } catch (error) {
+      continue;
+    }
+    if (raw <= 0n) continue;
+
+    const paymentCurrency = isNative ? currency : payment.asset || 'ERC20';
+    if (!total) {
+      total = {
+        raw,
+        decimals: isNative ? 18 : toNumberOrNull(payment.rawContract.decimals ?? payment.rawContract.decimal) ?? 18,
+        currency: paymentCurrency,
+        address: isNative ? null : payment.rawContract.address || null
+      };
+    } else if (total.currency === paymentCurrency) {
+      total.raw += raw;
+    }
+  }
+  return total ? { ...total, raw: total.raw.toString() } : null;
+}
+
+/**
+ * NFT movements in an Alchemy ADDRESS_ACTIVITY payload (wallet watchlists). A transfer
+ * paid for in the same transaction is a sale (or a priced mint); the price is what the
+ * receiving wallet paid or the sending wallet got, and is left out when the transaction
+ * moves several items.
+ * @param {Array<Object>} activities - payload.event.activity
+ * @param {Object} options
+ * @param {string} options.chain - Chain of the payload's network
+ * @param {string} options.currency - Native currency symbol of that chain
+ * @param {string} options.occurredAt - createdAt of the webhook payload
+ * @returns {Array<ActivityEvent>}
+ */
+function fromAlchemyAddressActivity(activities, { chain, currency = 'ETH', occurredAt = null }) {
+  const transactions = new Map();
+  for (const activity of activities) {
+    if (!activity.hash) continue;
+    if (!transactions.has(activity.hash)) transactions.set(activity.hash, []);
+    transactions.get(activity.hash).push(activity);
+  }
+
+  const events = [];
+  for (const [hash, entries] of transactions) {
+    const items = entries.filter(isAlchemyNftTransfer);
+    const payments = entries.filter(entry => !isAlchemyNftTransfer(entry));
+    const itemCount = items.reduce((count, item) => count + Math.max(1, item.erc1155Metadata?.length || 0), 0);
+
+    for (const item of items) {
+      const from = item.fromAddress?.toLowerCase() || null;
+      const to = item.toAddress?.toLowerCase() || null;
+      const contractAddress = item.rawContract?.address || item.contractAddress || null;
+      const price = itemCount === 1
+        ? sumAlchemyPayments(payments.filter(payment =>
+          payment.fromAddress?.toLowerCase() === to || payment.toAddress?.toLowerCase() === from), currency)
+        : null;
+      const marketplace = payments
+        .map(payment => marketplaceRegistry.getMarketplaceName(chain, payment.fromAddress) ||
+          marketplaceRegistry.getMarketplaceName(chain, payment.toAddress))
+        .find(Boolean) || null;
+
+      let type = 'transfer';
+      if (isZeroAddress(from)) {
+        type = 'mint';
+      } else if (isZeroAddress(to)) {
+        type = 'burn';
+      } else if (price || marketplace || marketplaceRegistry.isMarketplaceAddress(chain, from) || marketplaceRegistry.isMarketplaceAddress(chain, to)) {
+        type = 'sale';
+      }
+
+      const tokenIds = item.erc1155Metadata?.length > 0
+        ? item.erc1155Metadata.map(metadata => metadata.tokenId)
+        : [item.erc721TokenId || item.tokenId];
+      for (const rawTokenId of tokenIds) {
+        const tokenId = normalizeTokenId(rawTokenId);
+        events.push(createActivityEvent({
+          source: 'alchemy',
+          chain,
+          type,
+          collection: { name: item.asset || null, contractAddress },
+          contractAddress,
+          tokenId,
+          itemUrl: openSeaItemUrl(chain, contractAddress, tokenId),
+          price: type === 'transfer' || type === 'burn' ? null : price,
+          seller: item.fromAddress,
+          buyer: item.toAddress,
+          marketplace,
+          txHash: hash,
+          blockNumber: item.blockNum,
+          logIndex: item.log?.logIndex,
+          occurredAt
+        }));
+      }
+    }
+  }
+  return events;
+}
+
+/**
+ * OpenSea Stream event (already flattened by OpenSeaService.extractEventData)
+ * @param {string} eventType - sold, listed, received_bid, received_offer or transferred
+ * @param {Object} eventData - Extracted event data
+ * @param {Object} token - Tracked token row
+ * @returns {ActivityEvent|null} Null for event types that aren't activity (cancellations, metadata updates)
+ */
+function fromOpenSeaEvent(eventType, eventData, token) {
+  const type = OPENSEA_TYPES[eventType];
+  if (!type) return null;
+
+  const chain = token.chain_name || 'ethereum';
+  const contractAddress = eventData.contractAddress || token.contract_address;
+  let seller = eventData.fromAddress || eventData.makerAddress;
+  let buyer = eventData.toAddress || eventData.takerAddress;
+  if (type === 'offer') {
+    // The maker of an offer is the bidder
+    seller = null;
+    buyer = eventData.makerAddress;
+  }
+
+  return createActivityEvent({
+    source: 'opensea',
+    chain,
+    type,
+    collection: {
+      name: eventData.collectionName || token.token_name,
+      slug: eventData.collectionSlug || token.collection_slug,
+      contractAddress: token.contract_address
+    },
+    contractAddress,
+    tokenId: eventData.tokenId,
+    nftName: eventData.nftName,
+    imageUrl: eventData.nftImageUrl && eventData.nftImageUrl.startsWith('http') ? eventData.nftImageUrl : null,
+    itemUrl: eventData.tokenId
+      ? openSeaItemUrl(chain, contractAddress, eventData.tokenId)
+      : eventData.collectionSlug ? `https://opensea.io/collection/${eventData.collectionSlug}` : null,
+    price: eventData.price ? {
+      raw: eventData.price,
+      decimals: eventData.paymentTokenDecimals,
+      currency: eventData.paymentTokenSymbol,
+      address: eventData.paymentTokenAddress,
+      usd: eventData.priceUsd
+    } : null,
+    seller,
+    buyer,
+    marketplace: 'OpenSea',
+    txHash: eventData.transactionHash,
+    blockNumber: eventData.blockNumber,
+    reference: eventData.orderHash,
+    occurredAt: eventData.sentAt
+  });
+}
+
+/**
+ * Helius NFT_SALE (parsed by HeliusService.parseNFTSaleEvent)
+ * @param {Object} saleData - Parsed sale
+ * @param {Object} token - Tracked Solana token row
+ * @returns {ActivityEvent}
+ */
+function fromHeliusSale(saleData, token) {
+  const nft = saleData.nfts?.[0] || {};
+
+  return createActivityEvent({
+    source: 'helius',
+    chain: 'solana',
+    type: 'sale',
+    collection: { name: token.token_name, slug: token.collection_slug, contractAddress: token.contract_address },
+    contractAddress: token.contract_address,
+    tokenId: saleData.mintAddress,
+    nftName: nft.name,
+    imageUrl: nft.imageUri,
+    itemUrl: saleData.mintAddress ? `https://magiceden.io/item-details/${saleData.mintAddress}` : null,
+    price: saleData.amount ? { raw: saleData.amount, decimals: 9, currency: 'SOL' } : null,
+    seller: saleData.seller,
+    buyer: saleData.buyer,
+    marketplace: 'Magic Eden',
+    txHash: saleData.signature,
+    blockNumber: saleData.slot,
+    occurredAt: saleData.timestamp
+  });
+}
+
+/**
+ * Helius NFT_LISTING (parsed by HeliusService.parseNFTListingEvent)
+ * @param {Object} listingData - Parsed listing
+ * @param {Object} token - Tracked Solana token row
+ * @returns {ActivityEvent}
+ */
+function fromHeliusListing(listingData, token) {
+  const nft = listingData.nfts?.[0] || {};
+
+  return createActivityEvent({
+    source: 'helius',
+    chain: 'solana',
+    type: 'listing',
+    collection: { name: token.token_name, slug: token.collection_slug, contractAddress: token.contract_address },
+    contractAddress: token.contract_address,
+    tokenId: listingData.mintAddress,
+    nftName: nft.name,
+    imageUrl: nft.imageUri,
+    itemUrl: listingData.mintAddress ? `https://magiceden.io/item-details/${listingData.mintAddress}` : null,
+    price: listingData.amount ? { raw: listingData.amount, decimals: 9, currency: 'SOL' } : null,
+    seller: listingData.seller,
+    marketplace: HELIUS_SOURCES[listingData.source] || null,
+    txHash: listingData.signature,
+    blockNumber: listingData.slot,
+    occurredAt: listingData.timestamp
+  });
+}
+
+/**
+ * NFT movements in a Helius enhanced transaction (wallet watchlists): the sale or mint
+ * Helius parsed, otherwise every NFT token transfer. Collection details are not part of
+ * the payload and are filled in by the caller.
+ * @param {Object} transaction - Enhanced transaction from a Helius webhook
+ * @returns {Array<ActivityEvent>}
+ */
+function fromHeliusTransaction(transaction) {
+  const nftEvent = transaction.events?.nft;
+  const tokenTransfers = transaction.tokenTransfers || [];
+  const createItemEvent = (mint, fields) => createActivityEvent({
+    source: 'helius',
+    chain: 'solana',
+    tokenId: mint,
+    itemUrl: `https://magiceden.io/item-details/${mint}`,
+    txHash: transaction.signature,
+    blockNumber: transaction.slot,
+    occurredAt: transaction.timestamp,
+    ...fields
+  });
+
+  if (nftEvent && ['NFT_SALE', 'NFT_MINT'].includes(nftEvent.type)) {
+    const nfts = (nftEvent.nfts || []).filter(nft => nft.mint);
+    const type = nftEvent.type === 'NFT_SALE' ? 'sale' : 'mint';
+    return nfts.map(nft => createItemEvent(nft.mint, {
+      type,
+      price: nftEvent.amount && nfts.length === 1 ? { raw: nftEvent.amount, decimals: 9, currency: 'SOL' } : null,
+      seller: type === 'sale' ? nftEvent.seller : null,
+      buyer: nftEvent.buyer || tokenTransfers.find(transfer => transfer.mint === nft.mint)?.toUserAccount,
+      marketplace: type === 'sale' ? HELIUS_SOURCES[nftEvent.source] || null : null
+    }));
+  }
+
+  return tokenTransfers
+    .filter(transfer => transfer.mint && SOLANA_NFT_STANDARDS.includes(transfer.tokenStandard))
+    .map(transfer => createItemEvent(transfer.mint, {
+      type: transfer.toUserAccount ? 'transfer' : 'burn',
+      seller: transfer.fromUserAccount,
+      buyer: transfer.toUserAccount
+    }));
+}
+
+/**
+ * Magic Eden Ordinals activity (built by BitcoinOrdinalsPoller)
+ * @param {Object} eventData - Poller event data
+ * @param {Object} token - Tracked Bitcoin token row
+ * @returns {ActivityEvent|null} Null for activity kinds that aren't tracked
+ */
+function fromOrdinalsActivity(eventData, token) {
+  const type = ORDINALS_TYPES[(eventData.activityType || '').toLowerCase()];
+  if (!type) return null;
+
+  return createActivityEvent({
+    source: 'magiceden_ordinals',
+    chain: 'bitcoin',
+    type,
+    collection: {
+      name: eventData.collectionName || token.token_name,
+      slug: eventData.collectionSymbol || token.collection_slug,
+      contractAddress: token.contract_address
+    },
+    contractAddress: token.contract_address,
+    tokenId: eventData.inscriptionId,
+    itemUrl: eventData.inscriptionId ? `https://magiceden.io/ordinals/item-details/${eventData.inscriptionId}` : null,
+    price: eventData.priceRaw ? { raw: eventData.priceRaw, decimals: 8, currency: 'BTC' } : null,
+    seller: eventData.seller,
+    buyer: eventData.buyer,
+    marketplace: eventData.marketplace,
+    txHash: eventData.txId,
+    reference: eventData.activityId,
+    occurredAt: eventData.timestamp
+  });
+}
+
+/**
+ * Hiro Chainhook inscription transfer (parsed by HiroOrdinalsService.parseInscriptionTransfer)
+ * @param {Object} transferData - Parsed transfer
+ * @param {Object} token - Tracked Bitcoin token row
+ * @returns {ActivityEvent}
+ */
+function fromHiroTransfer(transferData, token) {
+  return createActivityEvent({
+    source: 'hiro',
+    chain: 'bitcoin',
+    type: 'transfer',
+    collection: { name: token.token_name, slug: transferData.collection_symbol || token.collection_slug, contractAddress: token.contract_address },
+    contractAddress: token.contract_address,
+    tokenId: transferData.inscription_id,
+    nftName: transferData.inscription_number != null ? `Inscription #${transferData.inscription_number}` : null,
+    itemUrl: `https://magiceden.io/ordinals/item-details/${transferData.inscription_id}`,
+    seller: transferData.sender,
+    buyer: transferData.recipient,
+    txHash: transferData.txid,
+    blockNumber: transferData.block_height,
+    occurredAt: transferData.timestamp
+  });
+}
+
+/**
+ * Hiro Chainhook inscription reveal (parsed by HiroOrdinalsService.parseInscriptionReveal)
+ * @param {Object} revealData - Parsed reveal
+ * @param {Object} token - Tracked Bitcoin token row, or the collection fields known for it
+ * @returns {ActivityEvent}
+ */
+function fromHiroReveal(revealData, token) {
+  return createActivityEvent({
+    source: 'hiro',
+    chain: 'bitcoin',
+    type: 'mint',
+    collection: { name: token.token_name, slug: token.collection_slug, contractAddress: token.contract_address },
+    contractAddress: token.contract_address,
+    tokenId: revealData.inscription_id,
+    nftName: revealData.inscription_number != null ? `Inscription #${revealData.inscription_number}` : null,
+    itemUrl: `https://magiceden.io/ordinals/item-details/${revealData.inscription_id}`,
+    buyer: revealData.inscriber,
+    txHash: revealData.txid,
+    blockNumber: revealData.block_height,
+    occurredAt: revealData.timestamp
+  });
+}
+
+// ==================== RENDERING HELPERS ====================
+
+/**
+ * Format an event price in its currency, e.g. "0.4200 ETH" or "1.20 mETH"
+ */
+function formatPrice(price) {
+  if (!price) return null;
+  const amount = parseFloat(price.raw) / Math.pow(10, price.decimals);
+  if (!Number.isFinite(amount) || amount <= 0) return null;
+
+  if (amount >= 1) {
+    return `${amount.toFixed(3)} ${price.currency}`;
+  } else if (amount >= 0.001) {
+    return `${amount.toFixed(4)} ${price.currency}`;
+  }
+  return `${(amount * 1000).toFixed(2)} m${price.currency}`;
+}
+
+/**
+ * Marketplace link for the item page, labelled by its host
+ */
+function getItemLink(event) {
+  if (!event.itemUrl) return null;
+  const hostNames = { 'opensea.io': 'OpenSea', 'magiceden.io': 'Magic Eden' };
+  const host = Object.keys(hostNames).find(name => event.itemUrl.includes(name));
+  return { name: host ? hostNames[host] : event.marketplace || 'Marketplace', url: event.itemUrl };
+}
+
+function getExplorerLink(chain, txHash) {
+  const explorer = EXPLORER_TX_URLS[chain];
+  if (!explorer || !txHash) return null;
+  return { name: explorer.name, url: `${explorer.url}${txHash}` };
+}
+
+module.exports = {
+  ACTIVITY_TYPES,
+  ACTIVITY_LABELS,
+  createActivityEvent,
+  toActivityRecord,
+  getChainEventKey,
+  fromAlchemyActivity,
+  fromAlchemyAddressActivity,
+  fromOpenSeaEvent,
+  fromHeliusSale,
+  fromHeliusListing,
+  fromHeliusTransaction,
+  fromOrdinalsActivity,
+  fromHiroTransfer,
+  fromHiroReveal,
+  formatPrice,
+  getItemLink,
+  getExplorerLink
+};
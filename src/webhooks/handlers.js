@@ -1,8 +1,74 @@
+const crypto = require('crypto');
 const logger = require('../services/logger');
 const axios = require('axios');
+const { ethers } = require('ethers');
+const { createDedupStore } = require('../services/dedupStore');
+const NotificationOutbox = require('../services/notificationOutbox');
+const ChatSettingsService = require('../services/chatSettingsService');
+const SweepAggregator = require('../services/sweepAggregator');
+const WebhookInbox = require('../services/webhookInbox');
+const DigestService = require('../services/digestService');
+const CollectionStatsService = require('../services/collectionStatsService');
+const StatsAlertService = require('../services/statsAlertService');
+const RarityService = require('../services/rarityService');
+const PriceHistoryService = require('../services/priceHistoryService');
+const SaleAttributionService = require('../services/saleAttributionService');
+const MessageTemplateService = require('../services/messageTemplateService');
+const { renderTemplate, escapeTemplateValue } = MessageTemplateService;
+const { fromSubscriptionRow, getFilterRejection, getFloorDiscount } = require('../services/subscriptionFilters');
+const { t, DEFAULT_LANGUAGE } = require('../i18n');
+const {
+  ACTIVITY_LABELS,
+  toActivityRecord,
+  getChainEventKey,
+  fromAlchemyActivity,
+  fromAlchemyAddressActivity,
+  fromOpenSeaEvent,
+  fromHeliusSale,
+  fromHeliusListing,
+  fromHeliusTransaction,
+  fromOrdinalsActivity,
+  fromHiroTransfer,
+  fromHiroReveal,
+  formatPrice,
+  getItemLink,
+  getExplorerLink
+} = require('./activityEvent');
+
+// Attempts at downloading a paid collection's item image before the default image is used
+const PAID_IMAGE_ATTEMPTS = 3;
+// Sweep messages: thumbnails in the collage and items linked in the caption
+const SWEEP_COLLAGE_ITEMS = 9;
+const SWEEP_LINKED_ITEMS = 5;
+// Collections listed in one digest message
+const DIGEST_MAX_COLLECTIONS = 10;
+// Events older than this are valued with the hourly price history only, never the live price
+const HISTORICAL_PRICE_AGE_MS = 60 * 60 * 1000;
+// Chains whose floor price doesn't come from OpenSea collection stats
+const FLOOR_PRICE_EXCLUDED_CHAINS = ['solana', 'bitcoin'];
+// What a watched wallet did, by its side of the event and the event type
+const WALLET_ACTIONS = {
+  buyer: { sale: 'bought', mint: 'minted', transfer: 'received' },
+  seller: { sale: 'sold', transfer: 'sent', burn: 'burned' }
+};
+
+function isHistoricalTime(occurredAt) {
+  return Date.now() - new Date(occurredAt).getTime() > HISTORICAL_PRICE_AGE_MS;
+}
+
+function boostButton(lang = DEFAULT_LANGUAGE) {
+  return {
+    inline_keyboard: [[
+      {
+        text: t(lang, 'alert.boost'),
+        callback_data: '/buy_trending'
+      }
+    ]]
+  };
+}
 
 class WebhookHandlers {
-  constructor(database, bot, trendingService = null, secureTrendingService = null, openSeaService = null, chainManager = null, magicEdenService = null, heliusService = null, magicEdenOrdinalsService = null, hiroOrdinalsService = null) {
+  constructor(database, bot, trendingService = null, secureTrendingService = null, openSeaService = null, chainManager = null, magicEdenService = null, heliusService = null, magicEdenOrdinalsService = null, hiroOrdinalsService = null, dedupStore = null, notificationOutbox = null, outboundWebhooks = null, chatSettings = null, messageTemplates = null, walletWatch = null) {
     this.db = database;
     this.bot = bot;
     this.trending = trendingService;
@@ -13,40 +79,157 @@ class WebhookHandlers {
     this.helius = heliusService;
     this.magicEdenOrdinals = magicEdenOrdinalsService;
     this.hiro = hiroOrdinalsService;
-    this.processedTransactions = new Map();
-    this.processedOpenSeaEvents = new Map(); // Track OpenSea events to prevent duplicates
-    this.processedHeliusEvents = new Map(); // Track Helius events to prevent duplicates
-    this.processedHiroEvents = new Map(); // Track Hiro Ordinals events to prevent duplicates
-    this.CACHE_EXPIRY_MS = 10 * 60 * 1000;
-    setInterval(() => {
-      this.cleanupExpiredTransactions();
-      this.cleanupExpiredOpenSeaEvents();
-      this.cleanupExpiredHeliusEvents();
-      this.cleanupExpiredHiroEvents();
-    }, 5 * 60 * 1000);
+    // Verified webhook payloads are stored and acknowledged, then processed by the inbox worker
+    this.inbox = new WebhookInbox(database);
+    this.inbox.register('alchemy', payload => this.processAlchemyWebhook(payload));
+    this.inbox.register('helius', transactions => this.processHeliusWebhook(transactions));
+    this.inbox.register('hiro', ({ target, event }) => this.processHiroChainhook(event, target));
+    // Persistent dedup keys for Alchemy, OpenSea, Helius and Hiro events, shared across restarts/replicas
+    this.dedup = dedupStore || createDedupStore(database);
+    // Alerts are queued and delivered by the outbox worker instead of being sent inline
+    this.outbox = notificationOutbox;
+    if (!this.outbox) {
+      this.outbox = new NotificationOutbox(database, bot);
+      this.outbox.start();
+    }
+    // Signed JSON copies of each activity for endpoints registered by group admins
+    this.outboundWebhooks = outboundWebhooks;
+    // Per-chat alert preferences (sweep mode, quiet hours, digests)
+    this.chatSettings = chatSettings || new ChatSettingsService(database);
+    // Custom alert layouts set by group admins
+    this.templates = messageTemplates || new MessageTemplateService(database);
+    // Wallets followed by chats across every collection
+    this.walletWatch = walletWatch;
+    // Sales are held for a few seconds so the items of one sweep go out as a single alert
+    this.sweeps = new SweepAggregator(database, { onFlush: (token, events) => this.deliverSaleBatch(token, events) });
+    // Floor prices for digest summaries and floor/volume alerts
+    this.collectionStats = new CollectionStatsService(database, {
+      magicEden: magicEdenService,
+      magicEdenOrdinals: magicEdenOrdinalsService,
+      chainManager
+    });
+    // Alerts to chats in quiet hours or digest mode are held and posted as one summary
+    this.digests = new DigestService(database, {
+      chatSettings: this.chatSettings,
+      outbox: this.outbox,
+      onFlush: (chatId, recipient, rows, context) => this.buildDigestNotification(chatId, recipient, rows, context),
+      getFloorPrice: (token) => this.getFloorPrice(token)
+    });
+    // Floor and volume thresholds set with /stats_alert, checked against polled collection stats
+    this.statsAlerts = new StatsAlertService(database, {
+      collectionStats: this.collectionStats,
+      onTrigger: (rule, token, snapshot, details) => this.deliverStatsAlert(rule, token, snapshot, details)
+    });
+    // Rarity ranks shown in sale and listing alerts; snapshots are refreshed by TokenTracker.updateTokenData
+    this.rarity = new RarityService(database, {
+      chainManager,
+      magicEden: magicEdenService,
+      magicEdenOrdinals: magicEdenOrdinalsService,
+      helius: heliusService
+    });
+    // Hourly USD candles, so events older than the live price are valued at their own time
+    this.priceHistory = new PriceHistoryService(database);
+    // Marketplace, item price and royalty of EVM sales, read from the receipt with the marketplace registry
+    this.saleAttribution = new SaleAttributionService({ chainManager });
+  }
+
+  /**
+   * Queue a photo alert in the notification outbox
+   * @param {string|number} chatId - Target chat
+   * @param {string} imagePath - Local image path
+   * @param {Object} extra - Telegram options (caption, parse_mode, reply_markup)
+   * @param {Object|null} recipient - { type: 'user'|'channel', id } for deactivation when the chat is unreachable
+   * @param {Array<string>} cleanupFiles - Temp images to delete once the message is delivered
+   */
+  async queuePhotoNotification(chatId, imagePath, extra, recipient = null, cleanupFiles = []) {
+    return this.outbox.enqueue({ chatId, method: 'sendPhoto', photo: imagePath, extra, recipient, cleanupFiles });
+  }
+
+  /**
+   * Build the payload posted to outbound webhooks
+   * @param {Object} token - Tracked token row
+   * @param {ActivityEvent} event - Canonical activity event
+   * @returns {Object} Outbound event
+   */
+  buildOutboundEvent(token, event) {
+    let amount = null;
+    if (event.price) {
+      try {
+        amount = ethers.formatUnits(BigInt(event.price.raw), event.price.decimals);
+      } catch (error) {
+        amount = null;
+      }
+    }
+
+    return {
+      id: event.id,
+      type: event.type,
+      source: event.source,
+      chain: event.chain,
+      occurred_at: event.occurredAt,
+      collection: {
+        name: token.token_name || null,
+        contract_address: token.contract_address,
+        slug: token.collection_slug || null
+      },
+      nft: {
+        contract_address: event.contractAddress || token.contract_address,
+        token_id: event.tokenId
+      },
+      from: event.seller,
+      to: event.buyer,
+      transaction_hash: event.txHash,
+      block_number: event.blockNumber,
+      marketplace: event.marketplace,
+      price: event.price ? {
+        amount,
+        raw: event.price.raw,
+        decimals: event.price.decimals,
+        currency: event.price.currency,
+        usd: event.price.usd
+      } : null,
+      royalty: event.price && event.royalty ? {
+        raw: event.royalty,
+        decimals: event.price.decimals,
+        currency: event.price.currency
+      } : null
+    };
+  }
+
+  /**
+   * Queue an activity for the collection's outbound webhooks; never fails the alert path
+   */
+  async publishOutboundEvent(token, event) {
+    if (!this.outboundWebhooks) return;
+
+    try {
+      await this.outboundWebhooks.publish(token, this.buildOutboundEvent(token, event));
+    } catch (error) {
+      logger.error(`Error publishing outbound webhook event for ${token.contract_address}:`, error.message);
+    }
   }
 
   async handleAlchemyWebhook(req, res) {
     try {
       const payload = req.body;
-      logger.info('Received Alchemy webhook:', JSON.stringify(payload, null, 2));
 
-      await this.db.logWebhook('alchemy', payload, false);
-      let processed = false;
-      if (payload.type === 'NFT_ACTIVITY') {
-        processed = await this.handleNFTActivity(payload);
-      } else if (payload.type === 'ADDRESS_ACTIVITY') {
-        processed = await this.handleAddressActivity(payload);
-      } else {
-        logger.warn(`Unknown webhook type: ${payload.type}`);
+      const verification = await this.verifyAlchemySignature(req);
+      if (!verification.valid) {
+        logger.warn(`⚠️ Rejected Alchemy webhook (webhook ${payload?.webhookId || 'unknown'}): ${verification.reason}`);
+        await this.db.logWebhook('alchemy', payload || {}, false, `Signature rejected: ${verification.reason}`);
+        return res.status(401).json({ error: 'Unauthorized' });
       }
 
-      await this.db.logWebhook('alchemy', payload, processed);
+      logger.info('Received Alchemy webhook:', JSON.stringify(payload, null, 2));
+
+      await this.db.logWebhook('alchemy', payload, false);
+      // Acknowledge once the payload is stored, so Alchemy never waits on receipts, prices or images
+      const { id } = await this.inbox.enqueue('alchemy', payload);
 
-      res.status(200).json({ 
-        success: true, 
-        processed: processed,
-        message: 'Webhook processed successfully' 
+      res.status(200).json({
+        success: true,
+        queued: id,
+        message: 'Webhook accepted'
       });
     } catch (error) {
       logger.error('Error handling Alchemy webhook:', error);
@@ -60,6 +243,95 @@ class WebhookHandlers {
     }
   }
 
+  /**
+   * Route a stored Alchemy payload (webhook inbox worker)
+   * @param {Object} payload - Request body of a verified delivery
+   */
+  async processAlchemyWebhook(payload) {
+    let processed = false;
+    if (payload.type === 'NFT_ACTIVITY') {
+      processed = await this.handleNFTActivity(payload);
+    } else if (payload.type === 'ADDRESS_ACTIVITY') {
+      processed = await this.handleAddressActivity(payload);
+    } else {
+      logger.warn(`Unknown webhook type: ${payload.type}`);
+    }
+
+    await this.db.logWebhook('alchemy', payload, processed);
+  }
+
+  /**
+   * Signing keys accepted for an Alchemy webhook: the key stored with its tracked tokens,
+   * the previous key while a rotation is within its grace period, the key of a wallet
+   * watchlist webhook, and any keys from ALCHEMY_WEBHOOK_SIGNING_KEYS (for webhooks not
+   * bound to a tracked token)
+   * @param {string} webhookId - Alchemy webhook ID from the payload
+   * @returns {Promise<Array<{key: string, label: string}>>}
+   */
+  async getAlchemySigningKeys(webhookId) {
+    const keys = [];
+
+    if (webhookId) {
+      const record = await this.db.getWebhookSigningKeys(webhookId);
+      if (record?.webhook_signing_key) {
+        keys.push({ key: record.webhook_signing_key, label: 'current' });
+      }
+
+      const graceMs = (parseFloat(process.env.ALCHEMY_KEY_ROTATION_GRACE_HOURS) || 24) * 60 * 60 * 1000;
+      const rotatedAt = record?.webhook_key_rotated_at ? new Date(record.webhook_key_rotated_at).getTime() : 0;
+      if (record?.webhook_previous_signing_key && Date.now() - rotatedAt <= graceMs) {
+        keys.push({ key: record.webhook_previous_signing_key, label: 'previous' });
+      }
+
+      const walletKey = this.walletWatch ? await this.walletWatch.getWebhookSigningKey(webhookId) : null;
+      if (walletKey) {
+        keys.push({ key: walletKey, label: 'wallets' });
+      }
+    }
+
+    (process.env.ALCHEMY_WEBHOOK_SIGNING_KEYS || '')
+      .split(',')
+      .map(key => key.trim())
+      .filter(Boolean)
+      .forEach(key => keys.push({ key, label: 'env' }));
+
+    return keys;
+  }
+
+  /**
+   * Verify the X-Alchemy-Signature header (hex HMAC-SHA256 of the raw request body)
+   * @param {Object} req - Express request with rawBody captured by the body parser
+   * @returns {Promise<{valid: boolean, reason?: string}>}
+   */
+  async verifyAlchemySignature(req) {
+    const signature = req.headers['x-alchemy-signature'];
+    if (!signature) {
+      return { valid: false, reason: 'Missing X-Alchemy-Signature header' };
+    }
+    if (!req.rawBody) {
+      return { valid: false, reason: 'Missing raw request body' };
+    }
+
+    const webhookId = req.body?.webhookId;
+    const keys = await this.getAlchemySigningKeys(webhookId);
+    if (keys.length === 0) {
+      return { valid: false, reason: `No signing key configured for webhook ${webhookId || 'unknown'}` };
+    }
+
+    const received = Buffer.from(String(signature).trim().toLowerCase(), 'utf8');
+    for (const { key, label } of keys) {
+      const expected = Buffer.from(crypto.createHmac('sha256', key).update(req.rawBody).digest('hex'), 'utf8');
+      if (expected.length === received.length && crypto.timingSafeEqual(expected, received)) {
+        if (label === 'previous') {
+          logger.info(`Alchemy webhook ${webhookId} signed with previous key (rotation in progress)`);
+        }
+        return { valid: true };
+      }
+    }
+
+    return { valid: false, reason: 'Invalid signature' };
+  }
+
   async handleNFTActivity(payload) {
     try {
       if (!payload.event || !payload.event.activity) {
@@ -75,7 +347,7 @@ class WebhookHandlers {
 
       for (const activity of activities) {
         try {
-          await this.processNFTActivity(activity);
+          await this.processNFTActivity(activity, payload.createdAt);
           processedCount++;
         } catch (error) {
           logger.error(`Error processing individual NFT activity:`, error);
@@ -90,110 +362,9 @@ class WebhookHandlers {
     }
   }
 
-  cleanupExpiredTransactions() {
-    const now = Date.now();
-    let removedCount = 0;
-    for (const [txHash, timestamp] of this.processedTransactions.entries()) {
-      if (now - timestamp > this.CACHE_EXPIRY_MS) {
-        this.processedTransactions.delete(txHash);
-        removedCount++;
-      }
-    }
-    if (removedCount > 0) {
-      logger.debug(`Cleaned up ${removedCount} expired transaction cache entries`);
-    }
-  }
-
-  cleanupExpiredOpenSeaEvents() {
-    const now = Date.now();
-    let removedCount = 0;
-    for (const [eventKey, timestamp] of this.processedOpenSeaEvents.entries()) {
-      if (now - timestamp > this.CACHE_EXPIRY_MS) {
-        this.processedOpenSeaEvents.delete(eventKey);
-        removedCount++;
-      }
-    }
-    if (removedCount > 0) {
-      logger.debug(`Cleaned up ${removedCount} expired OpenSea event cache entries`);
-    }
-  }
-
-  cleanupExpiredHeliusEvents() {
-    const now = Date.now();
-    let removedCount = 0;
-    for (const [eventKey, timestamp] of this.processedHeliusEvents.entries()) {
-      if (now - timestamp > this.CACHE_EXPIRY_MS) {
-        this.processedHeliusEvents.delete(eventKey);
-        removedCount++;
-      }
-    }
-    if (removedCount > 0) {
-      logger.debug(`Cleaned up ${removedCount} expired Helius event cache entries`);
-    }
-  }
-
-  cleanupExpiredHiroEvents() {
-    const now = Date.now();
-    let removedCount = 0;
-    for (const [eventKey, timestamp] of this.processedHiroEvents.entries()) {
-      if (now - timestamp > this.CACHE_EXPIRY_MS) {
-        this.processedHiroEvents.delete(eventKey);
-        removedCount++;
-      }
-    }
-    if (removedCount > 0) {
-      logger.debug(`Cleaned up ${removedCount} expired Hiro event cache entries`);
-    }
-  }
-
-  isTransactionProcessed(txHash) {
-    if (!txHash) return false;
-    const timestamp = this.processedTransactions.get(txHash);
-    if (!timestamp) return false;
-
-    const isValid = (Date.now() - timestamp) <= this.CACHE_EXPIRY_MS;
-    if (!isValid) {
-      this.processedTransactions.delete(txHash);
-      return false;
-    }
-    return true;
-  }
-
-  markTransactionProcessed(txHash) {
-    if (txHash) {
-      this.processedTransactions.set(txHash, Date.now());
-    }
-  }
-
-  async processNFTActivity(activity) {
+  async processNFTActivity(activity, occurredAt = null) {
     try {
       const contractAddress = activity.contractAddress;
-      const txHash = activity.hash;
-
-      const tokenId = activity.tokenId || 
-                     activity.token?.tokenId ||
-                     activity.erc721TokenId ||
-                     activity.log?.topics?.[3] ||
-                     this.extractTokenIdFromTx(activity.hash);
-
-      const activityType = this.determineActivityType(activity);
-
-      // Create a unique key for deduplication based on contract + token + action
-      const deduplicationKey = `${contractAddress}:${tokenId}:${activityType}:${txHash}`;
-      
-      logger.info(`Checking deduplication key: ${deduplicationKey}`);
-      if (this.isTransactionProcessed(deduplicationKey)) {
-        logger.info(`Activity ${deduplicationKey} already processed, skipping`);
-        return;
-      }
-
-      // Mark as being processed immediately to prevent race conditions
-      this.markTransactionProcessed(deduplicationKey);
-      logger.info(`Marked as processing: ${deduplicationKey}`);
-
-      logger.info(`Processing NFT activity: ${activityType} for ${contractAddress}:${tokenId}`);
-      logger.debug(`Full activity data:`, JSON.stringify(activity, null, 2));
-
 
       const token = await this.db.getTrackedToken(contractAddress);
       if (!token || !token.is_active) {
@@ -201,239 +372,686 @@ class WebhookHandlers {
         return;
       }
 
+      const event = fromAlchemyActivity(activity, token, {
+        currency: this.chainManager?.getCurrencySymbol(token.chain_name) || 'ETH',
+        occurredAt
+      });
 
-      const activityData = {
-        contractAddress: contractAddress,
-        tokenId: tokenId || null,
-        activityType: activityType,
-        fromAddress: activity.fromAddress || null,
-        toAddress: activity.toAddress || null,
-        transactionHash: activity.hash || null,
-        blockNumber: activity.blockNum || null,
-        price: this.extractPrice(activity) || '1000000000000000',
-        marketplace: this.extractMarketplace(activity) || null
-      };
-
-      await this.db.logNFTActivity(activityData);
-
-
-      await this.notifyUsers(token, activityData);
-
+      // Create a unique key for deduplication based on contract + token + action
+      const deduplicationKey = `${contractAddress}:${event.tokenId}:${event.type}:${event.txHash}`;
 
-      const shouldNotifyChannels = await this.shouldNotifyChannelsForToken(contractAddress);
-      if (shouldNotifyChannels.notify) {
-        logger.info(`📢 Notifying channels for ${token.token_name} (${shouldNotifyChannels.reason})`);
-        await this.notifyChannels(token, activityData, shouldNotifyChannels.channels, shouldNotifyChannels.isTrending);
-      } else {
-        logger.debug(`Token ${token.token_name} - no channels configured for notifications`);
+      logger.info(`Checking deduplication key: ${deduplicationKey}`);
+      // Claiming is atomic, so concurrent deliveries can't both get through
+      if (!await this.dedup.claim('alchemy', deduplicationKey)) {
+        logger.info(`Activity ${deduplicationKey} already processed, skipping`);
+        return;
       }
 
-      // Already marked as processed at the start
+      logger.info(`Processing NFT activity: ${event.type} for ${contractAddress}:${event.tokenId}`);
+      logger.debug(`Full activity data:`, JSON.stringify(activity, null, 2));
+
+      await this.routeActivityEvent(token, event);
     } catch (error) {
       logger.error('Error processing NFT activity:', error);
       throw error;
     }
   }
 
-  determineActivityType(activity) {
-    const fromAddress = activity.fromAddress?.toLowerCase();
-    const toAddress = activity.toAddress?.toLowerCase();
-
-    if (!fromAddress || fromAddress === '0x0000000000000000000000000000000000000000' || fromAddress === '0x0') {
-      return 'mint';
+  /**
+   * Single delivery path for every activity source: drop events another feed already
+   * delivered, complete the price, record the event, publish it to outbound webhooks, then
+   * alert subscribed chats and eligible channels. Sales wait in the sweep aggregator first.
+   * @param {Object} token - Tracked token row
+   * @param {ActivityEvent} event - Event built by one of the activityEvent adapters
+   * @param {Object} options
+   * @param {boolean} options.persist - Log to nft_activities (the Ordinals poller writes its own rows)
+   * @returns {Promise<boolean>} True if at least one alert was queued (or the sale was batched)
+   */
+  async routeActivityEvent(token, event, { persist = true } = {}) {
+    // Classify first: a transfer whose receipt shows a sale is claimed as that sale
+    await this.saleAttribution.attribute(event);
+    if (!await this.claimChainEvent(event)) {
+      logger.info(`⏭️ ${event.source} ${event.type} ${getChainEventKey(event)} already delivered by another source, skipping`);
+      return false;
     }
 
-    if (!toAddress || toAddress === '0x0000000000000000000000000000000000000000' || toAddress === '0x0') {
-      return 'burn';
+    if (event.price) {
+      await this.saleAttribution.stampBlockTime(event);
     }
+    await this.normalizeEventPrice(event);
 
-    const marketplace = this.extractMarketplace(activity);
-    if (marketplace) {
-      return 'buy';
+    if (persist) {
+      await this.db.logNFTActivity(toActivityRecord(event));
     }
 
-    if (this.isMarketplaceAddress(fromAddress) || this.isMarketplaceAddress(toAddress)) {
-      return 'buy';
+    await this.publishOutboundEvent(token, event);
+
+    if (event.type === 'sale' && event.txHash && this.sweeps.windowMs > 0) {
+      try {
+        await this.sweeps.add(token, event);
+        return true;
+      } catch (error) {
+        logger.error(`Could not hold sale ${event.tokenId} for its sweep window, sending it now:`, error.message);
+      }
     }
 
-    return 'transfer';
+    return this.notifyActivityEvent(token, event);
   }
 
-  isMarketplaceAddress(address) {
-    if (!address) return false;
-    const marketplaceAddresses = [
-
-      '0x00000000006c3852cbef3e08e8df289169ede581',
-      '0x00000000000001ad428e4906ae43d8f9852d0dd6',
-      '0x00000000000006c7676171937c444f6bde3d6282',
+  /**
+   * Claim an EVM token movement on its chain-level identity. Alchemy and OpenSea both report
+   * sales; whichever arrives first is delivered. Sales are claimed apart from other movements,
+   * so a sale is still announced after the same movement went out as a plain transfer (when
+   * its receipt couldn't be read), while a transfer arriving after its sale is dropped.
+   * @returns {Promise<boolean>} True if this event should be delivered
+   */
+  async claimChainEvent(event) {
+    const chainKey = getChainEventKey(event);
+    if (!chainKey) return true;
 
-      '0x000000000000ad05ccc4f10045630fb830b95127',
-      '0x29469395eaf6f95920e59f858042f0e28d98a20b',
+    if (event.type === 'sale') {
+      return this.dedup.claim('chain', `sale:${chainKey}`);
+    }
+    if (event.type === 'transfer' && await this.dedup.isProcessed(`sale:${chainKey}`)) {
+      return false;
+    }
+    return this.dedup.claim('chain', chainKey);
+  }
 
-      '0x59728544b08ab483533076417fbbb2fd0b17ce3a',
+  /**
+   * Alert subscribed chats and eligible channels about one event
+   * @returns {Promise<boolean>} True if at least one alert was queued
+   */
+  async notifyActivityEvent(token, event) {
+    const notifiedUsers = await this.notifySubscribers(token, event);
+
+    let notifiedChannels = false;
+    const shouldNotifyChannels = await this.shouldNotifyChannelsForToken(token.contract_address);
+    if (shouldNotifyChannels.notify) {
+      logger.info(`📢 Notifying channels for ${token.token_name} via ${event.source} ${event.type} (${shouldNotifyChannels.reason})`);
+      notifiedChannels = await this.notifyChannelsForEvent(token, event, shouldNotifyChannels.channels, shouldNotifyChannels.isTrending);
+    } else {
+      logger.debug(`Token ${token.token_name} - no channels configured for notifications`);
+    }
 
-      '0x2b2e8cda09bba9660dca5cb6233787738ad68329',
+    return notifiedUsers || notifiedChannels;
+  }
 
-      '0xcda72070e455bb31c7690a170224ce43623d0b6f',
+  async notifySubscribers(token, event) {
+    try {
+      const targets = await this.getSubscriberTargets(token);
+      if (targets.length === 0) {
+        logger.debug(`No users subscribed to token: ${token.contract_address}`);
+        return false;
+      }
 
-      '0x65b49f7aee40347f5a90b714be4ef086f3fe5e2c',
+      logger.info(`📤 Sending ${event.source} ${event.type} notification to ${targets.length} subscription(s) for ${token.token_name}`);
+      const successCount = await this.sendEventToTargets(token, event, targets);
 
-      '0x9757f2d2b135150bbeb65308d4a91804107cd8d6'
-    ];
-    return marketplaceAddresses.includes(address.toLowerCase());
+      logger.info(`📊 Notification summary: ${successCount}/${targets.length} notifications queued successfully`);
+      return successCount > 0;
+    } catch (error) {
+      logger.error('Error notifying users:', error);
+      return false;
+    }
   }
 
-  normalizeActivityType(category) {
-    const typeMap = {
-      'erc721': 'transfer',
-      'erc1155': 'transfer',
-      'external': 'external_transfer',
-      'internal': 'internal_transfer',
-      'token': 'transfer',
-      'sale': 'sale',
-      'mint': 'mint',
-      'burn': 'burn'
-    };
-
-    return typeMap[category?.toLowerCase()] || 'unknown';
+  /**
+   * Chats subscribed to a token. Private subscriptions go to the user's DM, group
+   * subscriptions to the group they were made in. Channels are left out: they only
+   * receive alerts through shouldNotifyChannelsForToken and its tier filtering.
+   * @param {Object} token - Tracked token row
+   * @returns {Promise<Array<{chatId: string, recipient: Object, trending: boolean, filters: Object|null}>>}
+   */
+  async getSubscriberTargets(token) {
+    const subscriptions = await this.db.all(`
+      SELECT u.telegram_id, u.username, us.notification_enabled, us.chat_id,
+             us.alert_event_types, us.min_price, us.min_price_unit,
+             us.marketplace_filter_mode, us.marketplace_filter, us.ignore_burns, us.snipe_discount,
+             us.trait_filter
+      FROM users u
+      JOIN user_subscriptions us ON u.id = us.user_id
+      WHERE us.token_id = $1
+        AND us.notification_enabled = true
+        AND u.is_active = true
+        AND us.chat_id NOT IN (SELECT telegram_chat_id FROM channels)
+    `, [token.id]);
+
+    return (subscriptions || []).map(subscription => ({
+      chatId: subscription.chat_id === 'private' ? subscription.telegram_id : subscription.chat_id,
+      recipient: { type: 'user', id: subscription.telegram_id },
+      trending: false,
+      filters: fromSubscriptionRow(subscription)
+    }));
   }
 
-  extractPrice(activity) {
+  /**
+   * Eligible channels as alert targets. A channel that tracks the token itself uses
+   * the filters of that subscription; all-activity channels get everything.
+   */
+  async getChannelTargets(token, channels, isTrending = false) {
+    const filtersByChat = new Map();
     try {
-
-      if (activity.value && parseFloat(activity.value) > 0) {
-        return activity.value.toString();
-      }
-      if (activity.metadata?.value) {
-        return activity.metadata.value.toString();
+      const subscriptions = await this.db.all(`
+        SELECT chat_id, alert_event_types, min_price, min_price_unit,
+               marketplace_filter_mode, marketplace_filter, ignore_burns, snipe_discount, trait_filter
+        FROM user_subscriptions
+        WHERE token_id = $1 AND chat_id IN (SELECT telegram_chat_id FROM channels)
+      `, [token.id]);
+      for (const subscription of subscriptions || []) {
+        if (!filtersByChat.has(subscription.chat_id)) {
+          filtersByChat.set(subscription.chat_id, fromSubscriptionRow(subscription));
+        }
       }
+    } catch (error) {
+      logger.warn(`Could not load channel filters for ${token.contract_address}: ${error.message}`);
+    }
 
+    return channels.map(channel => ({
+      chatId: channel.telegram_chat_id,
+      recipient: { type: 'channel', id: channel.telegram_chat_id },
+      trending: isTrending,
+      filters: filtersByChat.get(channel.telegram_chat_id) || null
+    }));
+  }
 
-      if (activity.log?.data) {
-
-
-      }
+  /**
+   * Check an event against a target's subscription filters
+   * @returns {Promise<boolean>} True if the target should get the alert
+   */
+  async passesFilters(target, event, token) {
+    if (!target.filters) return true;
+
+    const usdValue = target.filters.minPrice?.unit === 'usd' ? await this.resolveUsdValue(event) : null;
+    const floor = target.filters.snipe && event.type === 'listing' ? await this.getEventFloor(event) : null;
+    const itemTraits = target.filters.traits ? await this.getEventTraits(token, event) : null;
+    const rejection = getFilterRejection(target.filters, event, usdValue, floor, itemTraits);
+    if (rejection) {
+      logger.debug(`🔕 ${event.type} ${event.tokenId} filtered out for ${target.chatId}: ${rejection}`);
+      return false;
+    }
+    return true;
+  }
 
-      return null;
+  /**
+   * Traits of the event's item from the collection's rarity snapshot (RarityService, cached)
+   * @returns {Promise<Array<{type: string, value: string}>|null>} Null if the item isn't indexed
+   */
+  async getEventTraits(token, event) {
+    if (!token?.id || !event.tokenId) return null;
+    try {
+      return await this.rarity.getItemTraits(token.id, event.tokenId);
     } catch (error) {
-      logger.error('Error extracting price from activity:', error);
+      logger.warn(`Could not load the traits of ${event.tokenId}: ${error.message}`);
       return null;
     }
   }
 
-  extractMarketplace(activity) {
-    try {
-
-      if (activity.toAddress) {
-        const address = activity.toAddress.toLowerCase();
-
-        const marketplaces = {
-          '0x00000000006c3852cbef3e08e8df289169ede581': 'OpenSea',
-          '0x59728544b08ab483533076417fbbb2fd0b17ce3a': 'LooksRare',
-          '0x2b2e8cda09bba9660dca5cb6233787738ad68329': 'X2Y2'
-        };
+  /**
+   * Current floor of the collection an event belongs to (CollectionStatsService, cached)
+   * @returns {Promise<{price: number, currency: string}|null>}
+   */
+  async getEventFloor(event) {
+    return this.collectionStats.getFloorPrice({
+      chain_name: event.chain,
+      collection_slug: event.collection.slug,
+      contract_address: event.collection.contractAddress || event.contractAddress
+    });
+  }
 
-        return marketplaces[address] || null;
+  /**
+   * Queue one event's alert for each target. The message is rendered once per header
+   * variant or custom template and language, and the image fee is checked once.
+   * @returns {Promise<number>} Number of alerts queued
+   */
+  async sendEventToTargets(token, event, allTargets) {
+    const passing = [];
+    for (const target of allTargets) {
+      if (await this.passesFilters(target, event, token)) {
+        passing.push(target);
       }
-
-      return null;
-    } catch (error) {
-      logger.error('Error extracting marketplace from activity:', error);
-      return null;
     }
-  }
+    const targets = await this.holdForDigests(token, event, passing);
+    if (targets.length === 0) {
+      return 0;
+    }
 
-  async notifyUsers(token, activityData) {
-    try {
-      // Get users with their subscription context (chat_id)
-      const subscriptions = await this.db.all(`
-        SELECT u.telegram_id, u.username, us.notification_enabled, us.chat_id
-        FROM users u
-        JOIN user_subscriptions us ON u.id = us.user_id
-        WHERE us.token_id = $1 AND us.notification_enabled = true AND u.is_active = true
-      `, [token.id]);
+    const messages = {};
+    const media = await this.prepareEventMedia(token, event);
 
-      if (!subscriptions || subscriptions.length === 0) {
-        logger.debug(`No users subscribed to token: ${token.contract_address}`);
-        return false;
+    let queuedCount = 0;
+    let snipe;
+    for (const target of targets) {
+      const lang = await this.getChatLanguage(target.chatId);
+      const isSnipe = !!target.filters?.snipe && event.type === 'listing';
+      if (isSnipe && snipe === undefined) {
+        const floor = await this.getEventFloor(event);
+        snipe = floor ? { floor, discount: getFloorDiscount(event, floor) } : null;
       }
 
-      const message = await this.formatActivityMessage(token, activityData);
-      logger.info(`📤 Sending notification to ${subscriptions.length} subscription(s) for ${token.token_name}`);
+      // Snipe mode chats get the discount and a buy link, trending alerts keep their paid
+      // layout, other chats may have their own template
+      const template = isSnipe || target.trending ? null : await this.templates.get(target.chatId, event.type);
+      const layout = isSnipe ? 'snipe' : (target.trending ? 'trending' : 'default');
+      const variant = `${lang}:${template ? `template:${template}` : layout}`;
+      if (!messages[variant]) {
+        messages[variant] = template
+          ? { text: await this.formatTemplatedMessage(token, event, template, { lang }), parseMode: 'HTML', lang }
+          : { text: await this.formatActivityEventMessage(token, event, { trending: layout === 'trending', snipe: isSnipe ? snipe : null, lang }), parseMode: 'Markdown', lang };
+      }
 
-      // Send notifications to users in their specific subscribed contexts
-      let successCount = 0;
+      try {
+        await this.sendActivityNotification(target.chatId, messages[variant].text, media, target.recipient, messages[variant].parseMode, messages[variant].lang);
+        queuedCount++;
+        logger.info(`✅ Notification queued for ${target.recipient.type} ${target.chatId}`);
+      } catch (error) {
+        logger.error(`❌ Failed to queue notification to ${target.chatId}:`, error);
+      }
+    }
+    return queuedCount;
+  }
 
-      // Send to subscribed users in their correct context
-      for (const subscription of subscriptions) {
-        try {
-          // Determine target chat ID based on subscription context
-          let targetChatId;
-          if (subscription.chat_id === 'private') {
-            // For private chats, send to user's private chat
-            targetChatId = subscription.telegram_id;
-          } else {
-            // For group chats, send to the specific group where they subscribed
-            targetChatId = subscription.chat_id;
-          }
+  /**
+   * Deliver the sales of one transaction. A single sale is an ordinary alert; several
+   * make a sweep, sent as one message to chats in aggregated mode and item by item to
+   * chats that chose individual posts.
+   * @param {Object} token - Tracked token row
+   * @param {Array<ActivityEvent>} events - Sales of the same collection and transaction
+   * @returns {Promise<boolean>} True if at least one alert was queued
+   */
+  async deliverSaleBatch(token, events) {
+    if (events.length === 1) {
+      return this.notifyActivityEvent(token, events[0]);
+    }
 
-          await this.sendNotificationWithImage(targetChatId, message, token, activityData);
-          successCount++;
-          logger.info(`✅ Notification sent to ${subscription.chat_id === 'private' ? 'private chat' : 'group'} ${targetChatId} for user ${subscription.telegram_id}`);
-        } catch (error) {
-          logger.error(`❌ Failed to send notification to ${subscription.chat_id} for user ${subscription.telegram_id}:`, error);
-
-          if (error.response?.error_code === 403 || error.response?.error_code === 400) {
-            await this.db.run(
-              'UPDATE users SET is_active = false WHERE telegram_id = $1',
-              [subscription.telegram_id]
-            );
-            logger.info(`Deactivated user ${subscription.telegram_id} due to delivery failure`);
+    const targets = await this.getSubscriberTargets(token);
+    const shouldNotifyChannels = await this.shouldNotifyChannelsForToken(token.contract_address);
+    if (shouldNotifyChannels.notify) {
+      targets.push(...await this.getChannelTargets(token, shouldNotifyChannels.channels, shouldNotifyChannels.isTrending));
+    }
+    if (targets.length === 0) {
+      logger.debug(`No chats to notify about the ${token.token_name} sweep`);
+      return false;
+    }
+
+    // Chats holding their alerts get each sale of the sweep in their digest
+    const liveTargets = [];
+    for (const target of targets) {
+      if (await this.digests.getHoldReason(target.chatId)) {
+        for (const event of events) {
+          if (await this.passesFilters(target, event, token)) {
+            await this.holdForDigests(token, event, [target]);
           }
         }
+      } else {
+        liveTargets.push(target);
       }
+    }
 
-      logger.info(`📊 Notification summary: ${successCount}/${subscriptions.length} notifications sent successfully`);
-      return successCount > 0;
-    } catch (error) {
-      logger.error('Error notifying users:', error);
+    const aggregatedTargets = [];
+    const individualTargets = [];
+    for (const target of liveTargets) {
+      const sweepMode = await this.chatSettings.getSweepMode(target.chatId);
+      (sweepMode === 'individual' ? individualTargets : aggregatedTargets).push(target);
     }
-  }
 
-  // Check if token is trending in either service (secure service first)
-  async isTokenTrending(contractAddress) {
-    try {
-      // Check secure trending service first (preferred)
-      if (this.secureTrending) {
-        try {
-          const isTrendingSecure = await this.secureTrending.isTokenTrending(contractAddress);
-          if (isTrendingSecure) {
-            logger.info(`🔐 VERIFIED: Token ${contractAddress} has valid trending payment`);
-            return true;
-          }
-        } catch (error) {
-          logger.error('Error checking secure trending service:', error);
+    let queuedCount = 0;
+    if (aggregatedTargets.length > 0) {
+      // Filters can drop some items of the sweep, so chats are grouped by what is left for them
+      const groups = new Map();
+      for (const target of aggregatedTargets) {
+        const passing = [];
+        for (const event of events) {
+          if (await this.passesFilters(target, event, token)) passing.push(event);
         }
+        const key = passing.map(event => event.id).join(',');
+        if (!groups.has(key)) groups.set(key, { events: passing, targets: [] });
+        groups.get(key).targets.push({ ...target, filters: null });
       }
 
-      // Fall back to old trending service
-      if (this.trending) {
-        try {
-          const isTrendingOld = await this.trending.isTokenTrending(contractAddress);
-          if (isTrendingOld) {
-            logger.info(`🔐 VERIFIED: Token ${contractAddress} is trending via legacy service`);
-            return true;
-          }
-        } catch (error) {
-          logger.error('Error checking old trending service:', error);
+      for (const group of groups.values()) {
+        if (group.events.length > 1) {
+          queuedCount += await this.sendSweepToTargets(token, group.events, group.targets);
+        } else if (group.events.length === 1) {
+          queuedCount += await this.sendEventToTargets(token, group.events[0], group.targets);
         }
       }
-
-      logger.info(`🚫 NO PAYMENT: Token ${contractAddress} has no active trending payment`);
-      return false;
-    } catch (error) {
-      logger.error('Error in unified trending check:', error);
-      return false;
+    }
+    if (individualTargets.length > 0) {
+      for (const event of events) {
+        queuedCount += await this.sendEventToTargets(token, event, individualTargets);
+      }
+    }
+
+    logger.info(`🧹 ${token.token_name} sweep of ${events.length}: ${aggregatedTargets.length} aggregated, ${individualTargets.length} individual chat(s), ${queuedCount} alert(s) queued`);
+    return queuedCount > 0;
+  }
+
+  /**
+   * Language of a chat's alerts, chosen with /language
+   * @returns {Promise<string>} Language code
+   */
+  async getChatLanguage(chatId) {
+    const { language } = await this.chatSettings.get(chatId);
+    return language || DEFAULT_LANGUAGE;
+  }
+
+  /**
+   * Hold an event for the targets whose chat is in quiet hours or digest mode
+   * @returns {Promise<Array>} Targets that get the alert now
+   */
+  async holdForDigests(token, event, targets) {
+    const liveTargets = [];
+    let usdValue;
+    for (const target of targets) {
+      const reason = await this.digests.getHoldReason(target.chatId);
+      if (!reason) {
+        liveTargets.push(target);
+        continue;
+      }
+
+      try {
+        if (usdValue === undefined) {
+          usdValue = await this.resolveUsdValue(event);
+        }
+        await this.digests.hold(target, token, event, reason, usdValue);
+      } catch (error) {
+        logger.error(`❌ Failed to hold ${event.type} ${event.tokenId} for the digest of ${target.chatId}:`, error);
+      }
+    }
+    return liveTargets;
+  }
+
+  /**
+   * Floor price of a collection from OpenSea stats
+   * @returns {Promise<{price: number, currency: string}|null>}
+   */
+  async getFloorPrice(token) {
+    if (!token.collection_slug || FLOOR_PRICE_EXCLUDED_CHAINS.includes(token.chain_name)) {
+      return null;
+    }
+
+    const stats = await this.collectionStats.getStats(token.collection_slug);
+    const price = parseFloat(stats?.floor_price);
+    if (!Number.isFinite(price) || price <= 0) {
+      return null;
+    }
+    return {
+      price,
+      currency: stats.floor_price_symbol || this.chainManager?.getCurrencySymbol(token.chain_name) || 'ETH'
+    };
+  }
+
+  /**
+   * Render the events held for a chat as one digest message; DigestService queues it
+   * @param {string} chatId - Target chat
+   * @param {Object|null} recipient - { type, id } for deactivation when the chat is unreachable
+   * @param {Array<Object>} rows - digest_events rows, oldest first
+   * @param {Object} context - { reason: 'quiet'|'digest', since: Date }
+   * @returns {Promise<Object|null>} Outbox message, null when none of the collections is tracked anymore
+   */
+  async buildDigestNotification(chatId, recipient, rows, { reason, since }) {
+    // Several subscriptions of one chat hold the same event once each; it counts once
+    const seen = new Set();
+    const rowsByToken = new Map();
+    for (const row of rows) {
+      const eventId = JSON.parse(row.payload).id;
+      if (eventId && seen.has(eventId)) continue;
+      seen.add(eventId);
+      if (!rowsByToken.has(row.token_id)) {
+        rowsByToken.set(row.token_id, []);
+      }
+      rowsByToken.get(row.token_id).push(row);
+    }
+
+    const collections = [];
+    for (const [tokenId, tokenRows] of rowsByToken) {
+      const token = await this.db.get('SELECT * FROM tracked_tokens WHERE id = $1', [tokenId]);
+      if (!token) continue;
+
+      const startFloor = tokenRows.find(row => row.floor_price != null);
+      const currentFloor = startFloor ? await this.getFloorPrice(token).catch(() => null) : null;
+      collections.push({
+        token,
+        rows: tokenRows,
+        events: tokenRows.map(row => JSON.parse(row.payload)),
+        floor: startFloor && currentFloor
+          ? { from: parseFloat(startFloor.floor_price), to: currentFloor.price, currency: currentFloor.currency }
+          : null
+      });
+    }
+    if (collections.length === 0) {
+      return null;
+    }
+
+    const lang = await this.getChatLanguage(chatId);
+    const message = await this.formatDigestMessage(collections, { reason, since, lang });
+    return {
+      chatId,
+      method: 'sendMessage',
+      text: message,
+      extra: { parse_mode: 'Markdown', disable_web_page_preview: true, reply_markup: boostButton(lang) },
+      recipient
+    };
+  }
+
+  /**
+   * Post a triggered floor or volume alert to the chat that set it
+   * @param {Object} rule - stats_alerts row
+   * @param {Object} token - Tracked token row
+   * @param {Object} snapshot - Collection stats that triggered it
+   * @param {Object} details - { previous, change } for change rules
+   */
+  async deliverStatsAlert(rule, token, snapshot, details = {}) {
+    const lang = await this.getChatLanguage(rule.chat_id);
+    const message = this.formatStatsAlertMessage(rule, token, snapshot, details, lang);
+    const recipient = rule.chat_type === 'channel'
+      ? { type: 'channel', id: rule.chat_id }
+      : (rule.created_by ? { type: 'user', id: rule.created_by } : null);
+
+    await this.outbox.enqueue({
+      chatId: rule.chat_id,
+      method: 'sendMessage',
+      text: message,
+      extra: { parse_mode: 'Markdown', disable_web_page_preview: true, reply_markup: boostButton(lang) },
+      recipient
+    });
+  }
+
+  formatStatsAlertMessage(rule, token, snapshot, { previous = null, change = null } = {}, lang = DEFAULT_LANGUAGE) {
+    const { formatWindow } = StatsAlertService;
+    const collectionName = token.token_name || token.collection_slug || 'NFT Collection';
+    const metric = t(lang, `stats_alert.metric.${rule.metric}`);
+    const currency = snapshot.currency || this.chainManager?.getCurrencySymbol(token.chain_name) || '';
+    const amount = (value) => `${this.formatStatAmount(value)} ${currency}`.trim();
+
+    let message;
+    if (rule.condition === 'change') {
+      message = t(lang, 'stats_alert.triggered.change', {
+        emoji: change < 0 ? '📉' : '📈',
+        collection: collectionName,
+        metric,
+        change: `${change > 0 ? '+' : ''}${change.toFixed(1)}%`,
+        window: formatWindow(rule.window_minutes)
+      });
+    } else {
+      message = t(lang, `stats_alert.triggered.${rule.condition}`, {
+        emoji: rule.condition === 'below' ? '📉' : '📈',
+        collection: collectionName,
+        metric,
+        threshold: amount(parseFloat(rule.threshold))
+      });
+    }
+    message += '\n\n';
+
+    if (snapshot.floor_price != null) {
+      message += `💰 **${t(lang, 'stats_alert.metric.floor')}:** ${amount(snapshot.floor_price)}\n`;
+    }
+    if (previous != null) {
+      message += `⏮️ **${t(lang, 'stats_alert.previous', { metric, window: formatWindow(rule.window_minutes) })}:** ${amount(previous)}\n`;
+    }
+    if (snapshot.volume_24h != null) {
+      message += `📊 **${t(lang, 'stats_alert.metric.volume')}:** ${amount(snapshot.volume_24h)}\n`;
+    }
+    if (snapshot.sales_24h != null) {
+      message += `🛒 **${t(lang, 'stats_alert.sales')}:** ${snapshot.sales_24h}\n`;
+    }
+    if (snapshot.listed_count != null) {
+      message += `📋 **${t(lang, 'stats_alert.listed')}:** ${snapshot.listed_count}\n`;
+    }
+    if (token.collection_slug) {
+      message += `📮 **${t(lang, 'alert.collection')}:** \`${token.collection_slug}\`\n`;
+    }
+    message += `🔗 **${t(lang, 'alert.chain')}:** ${this.getChainLabel(token.chain_name)}\n`;
+
+    return this.appendFooter(message, { lang });
+  }
+
+  /**
+   * Collection stat amounts: four decimals below 10, two up to 1000, whole numbers above
+   */
+  formatStatAmount(value) {
+    const number = Number(value);
+    if (Math.abs(number) >= 1000) return Math.round(number).toLocaleString('en-US');
+    return number.toFixed(Math.abs(number) >= 10 ? 2 : 4);
+  }
+
+  /**
+   * Render a digest: per collection the sales, volume, top sale, floor change and
+   * a count of the other events
+   * @param {Array<{token: Object, rows: Array, events: Array<ActivityEvent>, floor: Object|null}>} collections
+   * @param {Object} options - { reason: 'quiet'|'digest', since: Date, lang }
+   * @returns {Promise<string>} Markdown message
+   */
+  async formatDigestMessage(collections, { reason, since, lang = DEFAULT_LANGUAGE }) {
+    const elapsedMinutes = Math.max(1, Math.round((Date.now() - since.getTime()) / 60000));
+    const hours = t(lang, 'digest.hours', { hours: Math.floor(elapsedMinutes / 60) });
+    const minutes = t(lang, 'digest.minutes', { minutes: elapsedMinutes % 60 });
+    const elapsed = elapsedMinutes >= 60
+      ? `${hours}${elapsedMinutes % 60 ? ` ${minutes}` : ''}`
+      : t(lang, 'digest.minutes', { minutes: elapsedMinutes });
+    const eventCount = collections.reduce((count, collection) => count + collection.events.length, 0);
+
+    let message = `${t(lang, `digest.title.${reason === 'quiet' ? 'quiet' : 'digest'}`)}\n`;
+    message += `_${t(lang, 'digest.summary', { count: eventCount, elapsed })}_\n`;
+
+    // Busiest collections first
+    const sorted = [...collections].sort((a, b) => b.events.length - a.events.length);
+    for (const collection of sorted.slice(0, DIGEST_MAX_COLLECTIONS)) {
+      message += `\n${await this.formatDigestSection(collection, lang)}`;
+    }
+    if (sorted.length > DIGEST_MAX_COLLECTIONS) {
+      message += `\n${t(lang, 'digest.more_collections', { count: sorted.length - DIGEST_MAX_COLLECTIONS })}\n`;
+    }
+
+    return this.appendFooter(message, { lang });
+  }
+
+  async formatDigestSection({ token, rows, events, floor }, lang = DEFAULT_LANGUAGE) {
+    const collectionName = events[0].collection?.name || token.token_name || 'NFT Collection';
+    let section = `**${collectionName}** · ${this.getChainLabel(token.chain_name)}\n`;
+
+    const saleRows = rows.filter(row => row.event_type === 'sale');
+    const sales = events.filter(event => event.type === 'sale');
+    if (sales.length > 0) {
+      const { total } = this.summarizeSweepPrices(sales);
+      section += `💰 **${t(lang, 'digest.sales')}:** ${sales.length}`;
+      if (total) {
+        section += ` · **${t(lang, 'digest.volume')}:** ${formatPrice(total)}`;
+        const usdValues = saleRows.map(row => row.usd_value).filter(value => value != null);
+        const usdVolume = usdValues.length === saleRows.length
+          ? usdValues.reduce((sum, value) => sum + parseFloat(value), 0)
+          : await this.resolveUsdValue({ price: total });
+        if (usdVolume) {
+          section += ` ($${this.formatUsdAmount(usdVolume)})`;
+        }
+      }
+      section += '\n';
+
+      const top = this.findTopSale(sales, saleRows);
+      if (top) {
+        const itemName = top.event.nftName || `#${this.shortenAddress(top.event.tokenId)}`;
+        const item = top.event.itemUrl ? `[${itemName}](${top.event.itemUrl})` : itemName;
+        section += `🏆 **${t(lang, 'digest.top_sale')}:** ${t(lang, 'digest.top_sale_item', { item, price: formatPrice(top.event.price) })}`;
+        if (top.usdValue) {
+          section += ` ($${this.formatUsdAmount(top.usdValue)})`;
+        }
+        section += '\n';
+      }
+    }
+
+    if (floor) {
+      const change = floor.from > 0 ? ((floor.to - floor.from) / floor.from) * 100 : 0;
+      const sign = change > 0 ? '+' : '';
+      section += `${change < 0 ? '📉' : '📈'} **${t(lang, 'digest.floor')}:** ${floor.from.toFixed(4)} → ${floor.to.toFixed(4)} ${floor.currency} (${sign}${change.toFixed(1)}%)\n`;
+    }
+
+    const otherCounts = ['listing', 'offer', 'mint', 'transfer', 'burn']
+      .map(type => ({ type, count: events.filter(event => event.type === type).length }))
+      .filter(({ count }) => count > 0)
+      .map(({ type, count }) => `${ACTIVITY_LABELS[type].emoji} ${t(lang, `digest.count.${type}`, { count })}`);
+    if (otherCounts.length > 0) {
+      section += `${otherCounts.join(' · ')}\n`;
+    }
+
+    return section;
+  }
+
+  /**
+   * Highest sale by USD value, or by price in the first priced currency when USD is missing
+   * @returns {{event: ActivityEvent, usdValue: number|null}|null}
+   */
+  findTopSale(sales, saleRows) {
+    const candidates = sales
+      .map((event, index) => ({ event, usdValue: saleRows[index]?.usd_value != null ? parseFloat(saleRows[index].usd_value) : null }))
+      .filter(candidate => candidate.event.price);
+    if (candidates.length === 0) return null;
+
+    if (candidates.every(candidate => candidate.usdValue != null)) {
+      return candidates.reduce((top, candidate) => candidate.usdValue > top.usdValue ? candidate : top);
+    }
+
+    const currency = candidates[0].event.price.currency;
+    const amount = (price) => parseFloat(price.raw) / Math.pow(10, price.decimals);
+    return candidates
+      .filter(candidate => candidate.event.price.currency === currency)
+      .reduce((top, candidate) => amount(candidate.event.price) > amount(top.event.price) ? candidate : top);
+  }
+
+  // Check if token is trending in either service (secure service first)
+  async isTokenTrending(contractAddress) {
+    try {
+      // Check secure trending service first (preferred)
+      if (this.secureTrending) {
+        try {
+          const isTrendingSecure = await this.secureTrending.isTokenTrending(contractAddress);
+          if (isTrendingSecure) {
+            logger.info(`🔐 VERIFIED: Token ${contractAddress} has valid trending payment`);
+            return true;
+          }
+        } catch (error) {
+          logger.error('Error checking secure trending service:', error);
+        }
+      }
+
+      // Fall back to old trending service
+      if (this.trending) {
+        try {
+          const isTrendingOld = await this.trending.isTokenTrending(contractAddress);
+          if (isTrendingOld) {
+            logger.info(`🔐 VERIFIED: Token ${contractAddress} is trending via legacy service`);
+            return true;
+          }
+        } catch (error) {
+          logger.error('Error checking old trending service:', error);
+        }
+      }
+
+      logger.info(`🚫 NO PAYMENT: Token ${contractAddress} has no active trending payment`);
+      return false;
+    } catch (error) {
+      logger.error('Error in unified trending check:', error);
+      return false;
     }
   }
 
@@ -646,1139 +1264,1168 @@ class WebhookHandlers {
     }
   }
 
-  async notifyChannels(token, activityData, channels = null, isTrending = false) {
+  async notifyChannelsForEvent(token, event, channels, isTrending = false) {
     try {
       // Authorization already performed by shouldNotifyChannelsForToken() before calling this method
 
-      if (!channels) {
-        channels = await this.db.all(`
-          SELECT * FROM channels
-          WHERE is_active = true AND show_trending = true
-        `);
-      }
-
-      if (channels.length === 0) {
+      if (!channels || channels.length === 0) {
         logger.debug('No channels provided for notifications');
-        return;
+        return false;
       }
 
-      const message = isTrending
-        ? await this.formatTrendingActivityMessage(token, activityData)
-        : await this.formatActivityMessage(token, activityData);
-      let notifiedCount = 0;
-      for (const channel of channels) {
-        try {
-          logger.info(`📤 SENDING to channel ${channel.channel_title}: ${token.token_name} (verified trending payment)`);
-          await this.sendNotificationWithImage(channel.telegram_chat_id, message, token, activityData);
-          notifiedCount++;
-          logger.info(`✅ Notification sent to channel ${channel.telegram_chat_id} (${channel.channel_title})`);
-        } catch (error) {
-          logger.error(`Failed to notify channel ${channel.telegram_chat_id}:`, error);
+      logger.info(`📤 SENDING to ${channels.length} channel(s): ${token.token_name} (${channels.map(c => c.channel_title).join(', ')})`);
+      const targets = await this.getChannelTargets(token, channels, isTrending);
+      const notifiedCount = await this.sendEventToTargets(token, event, targets);
 
-          if (error.response?.error_code === 403) {
-            await this.db.run(
-              'UPDATE channels SET is_active = false WHERE telegram_chat_id = $1',
-              [channel.telegram_chat_id]
-            );
-          }
-        }
-      }
-      logger.info(`Notified ${notifiedCount}/${channels.length} channels about ${token.token_name} activity`);
+      logger.info(`Notified ${notifiedCount}/${channels.length} channels about ${token.token_name} ${event.type}`);
+      return notifiedCount > 0;
     } catch (error) {
       logger.error('Error notifying channels:', error);
+      return false;
     }
   }
 
-  async formatActivityMessage(token, activityData) {
-    const tokenName = token.token_name || 'NFT Collection';
-    const tokenSymbol = token.token_symbol || 'TOKEN';
-
-    const isCandyCollection = tokenName.toLowerCase().includes('candy') ||
-                             tokenName.toLowerCase() === 'simplenft';
-    let message = '';
-    if (isCandyCollection) {
-
-      const rawTokenId = activityData.tokenId || 'Unknown';
-      const tokenId = this.formatTokenId(rawTokenId);
-      const ethAmount = this.formatEthAmount(activityData.price) || '0.001 ETH';
-      message = `🍭 **Candy #${tokenId}** minted! ${ethAmount}\n\n`;
+  /**
+   * Render the Markdown alert for an activity event, whatever its source
+   * @param {Object} token - Tracked token row
+   * @param {ActivityEvent} event - Canonical activity event
+   * @param {Object} options
+   * @param {boolean} options.trending - Use the trending header (paid trending channels)
+   * @param {string} options.lang - Language of the labels
+   * @returns {Promise<string>} Message
+   */
+  async formatActivityEventMessage(token, event, { trending = false, snipe = null, lang = DEFAULT_LANGUAGE } = {}) {
+    const collectionName = event.collection.name || token.token_name || 'NFT Collection';
+    const label = ACTIVITY_LABELS[event.type];
+    const action = t(lang, `alert.action.${event.type}`);
+    const price = formatPrice(event.price);
+    const isCandyCollection = collectionName.toLowerCase().includes('candy') ||
+                             collectionName.toLowerCase() === 'simplenft';
+
+    let message;
+    if (snipe) {
+      message = `🎯 **${collectionName}** ${t(lang, 'alert.snipe.action')}\n\n`;
+    } else if (trending) {
+      message = `🔥 **${t(lang, 'alert.trending')}:** ${collectionName} ${action}\n\n`;
+    } else if (isCandyCollection && event.type === 'mint') {
+      message = `🍭 **Candy #${event.tokenId || 'Unknown'}** ${t(lang, 'alert.candy_minted')} ${price || ''}\n\n`;
     } else {
-      const activityEmoji = this.getActivityEmoji(activityData.activityType);
-      message = `${activityEmoji} *${tokenName}* Activity\n\n`;
+      message = `${label.emoji} **${collectionName}** ${action}\n\n`;
     }
 
-    message += `🔹 **Action:** ${this.formatActivityType(activityData.activityType)}\n`;
-    const ethPrice = this.formatEthAmount(activityData.price) || '0.001 ETH';
-    message += `💰 **Amount:** ${ethPrice}\n`;
-    if (activityData.fromAddress && activityData.toAddress) {
-      message += `📤 **From:** \`${this.shortenAddress(activityData.fromAddress)}\`\n`;
-      message += `📥 **To:** \`${this.shortenAddress(activityData.toAddress)}\`\n`;
+    // Price first, for everything that has one
+    if (price && event.type !== 'transfer' && event.type !== 'burn') {
+      message += `💰 **${t(lang, `alert.price.${event.type}`)}:** ${price}`;
+      const nativeValue = this.formatNativeEquivalent(event);
+      if (nativeValue) {
+        message += ` (${nativeValue})`;
+      }
+      const usdValue = await this.resolveUsdValue(event);
+      if (usdValue) {
+        message += ` ($${this.formatUsdAmount(usdValue)})`;
+      }
+      message += '\n';
+    }
+    const royalty = this.formatRoyalty(event);
+    if (royalty) {
+      message += `👑 **${t(lang, 'alert.royalty')}:** ${royalty}\n`;
+    }
+    if (snipe) {
+      const floor = `${this.formatStatAmount(snipe.floor.price)} ${snipe.floor.currency}`;
+      message += `📉 **${t(lang, 'alert.snipe.discount')}:** ${snipe.discount.toFixed(1)}% (${t(lang, 'alert.snipe.floor', { floor })})\n`;
     }
-    message += `📮 **CA:** \`${this.shortenAddress(token.contract_address)}\`\n`;
 
-    // Add chain information
-    if (this.chainManager && token.chain_name) {
-      const chainConfig = this.chainManager.getChain(token.chain_name);
-      if (chainConfig) {
-        message += `🔗 **Chain:** ${chainConfig.emoji} ${chainConfig.displayName}\n`;
-      } else {
-        message += `🔗 **Chain:** ${token.chain_name}\n`;
-      }
+    if (event.tokenId) {
+      const itemName = event.nftName || `#${this.shortenAddress(event.tokenId)}`;
+      message += event.itemUrl
+        ? `🖼️ **${t(lang, 'alert.nft')}:** [${itemName}](${event.itemUrl})\n`
+        : `🖼️ **${t(lang, 'alert.nft')}:** ${itemName}\n`;
     }
+    message += await this.formatRarityLines(token, event, lang);
 
-    if (activityData.transactionHash) {
-      message += `🔗 **TX:** \`${this.shortenAddress(activityData.transactionHash)}\`\n`;
-      message += `[View on Etherscan](https://etherscan.io/tx/${activityData.transactionHash})`;
-    } else {
-      message += '\n';
+    // Parties: buyer/seller for trades, from/to for everything that moves without a price
+    if (event.type === 'sale') {
+      if (event.buyer) message += `👤 **${t(lang, 'alert.buyer')}:** \`${this.shortenAddress(event.buyer)}\`\n`;
+      if (event.seller) message += `📤 **${t(lang, 'alert.seller')}:** \`${this.shortenAddress(event.seller)}\`\n`;
+    } else if (event.type === 'offer') {
+      if (event.buyer) message += `👤 **${t(lang, 'alert.bidder')}:** \`${this.shortenAddress(event.buyer)}\`\n`;
+    } else if (event.type !== 'listing') {
+      if (event.seller && event.type !== 'mint') message += `📤 **${t(lang, 'alert.from')}:** \`${this.shortenAddress(event.seller)}\`\n`;
+      if (event.buyer && event.type !== 'burn') message += `📥 **${t(lang, 'alert.to')}:** \`${this.shortenAddress(event.buyer)}\`\n`;
     }
 
-    message += ` \nPowered by [Candy Codex](https://mint.candycodex.com/)`;
+    if (event.marketplace) {
+      message += `🏪 **${t(lang, 'alert.marketplace')}:** ${event.marketplace}\n`;
+    }
+    message += event.collection.slug
+      ? `📮 **${t(lang, 'alert.collection')}:** \`${event.collection.slug}\`\n`
+      : `📮 **${t(lang, 'alert.ca')}:** \`${this.shortenAddress(event.collection.contractAddress || token.contract_address)}\`\n`;
+    message += `🔗 **${t(lang, 'alert.chain')}:** ${this.getChainLabel(event.chain)}\n`;
 
-    // Add footer advertisements if available
-    if (this.secureTrending) {
-      try {
-        const footerAds = await this.secureTrending.getActiveFooterAds();
-        if (footerAds && footerAds.length > 0) {
-          const adLinks = footerAds.map(ad => {
-            const ticker = ad.ticker_symbol || ad.token_symbol || 'TOKEN';
-            return `[⭐️${ticker}](${ad.custom_link})`;
-          });
+    const itemLink = getItemLink(event);
+    if (itemLink) {
+      message += `[${t(lang, snipe ? 'alert.snipe.buy' : 'alert.view_on', { name: itemLink.name })}](${itemLink.url})\n`;
+    }
+    const explorerLink = getExplorerLink(event.chain, event.txHash);
+    if (explorerLink) {
+      message += `[${t(lang, 'alert.view_on', { name: explorerLink.name })}](${explorerLink.url})\n`;
+    }
 
-          // Add "BuyAdspot" if less than 3 slots are filled
-          if (adLinks.length < 3) {
-            adLinks.push('[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)');
-          }
+    return this.appendFooter(message, { lang });
+  }
 
-          message += `\n${adLinks.join(' ')}`;
-        } else {
-          message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-        }
-      } catch (error) {
-        // If footer ads fail, just show buy ad spot
-        message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-      }
-    } else {
-      message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-    }
+  /**
+   * Rank and rarest traits of a sold or listed item, when its collection has a rarity snapshot
+   * @returns {Promise<string>} Markdown lines, '' when unranked
+   */
+  async formatRarityLines(token, event, lang = DEFAULT_LANGUAGE) {
+    const rarity = await this.getEventRarity(token, event);
+    if (!rarity) return '';
 
-    return message;
+    let lines = `🏆 **${t(lang, 'alert.rank')}:** #${rarity.rank.toLocaleString('en-US')} / ${rarity.itemCount.toLocaleString('en-US')}\n`;
+    if (rarity.traits.length > 0) {
+      const plain = (text) => String(text).replace(/[*_`\[\]]/g, '');
+      lines += `✨ **${t(lang, 'alert.rarest_traits')}:** ${plain(this.formatRarestTraits(rarity))}\n`;
+    }
+    return lines;
   }
 
-  formatEthAmount(priceWei) {
-    if (!priceWei || parseFloat(priceWei) <= 0) {
+  /**
+   * Rarity of the item in a sale or listing
+   * @returns {Promise<Object|null>} RarityService.getItemRarity result, null for other events or unranked items
+   */
+  async getEventRarity(token, event) {
+    if (!['sale', 'listing'].includes(event.type) || !event.tokenId) return null;
+    try {
+      return await this.rarity.getItemRarity(token?.id, event.tokenId);
+    } catch (error) {
+      logger.warn(`Could not load the rarity of ${event.tokenId}: ${error.message}`);
       return null;
     }
-    const priceEth = parseFloat(priceWei) / 1e18;
-    if (priceEth >= 1) {
-      return `${priceEth.toFixed(3)} ETH`;
-    } else if (priceEth >= 0.001) {
-      return `${priceEth.toFixed(4)} ETH`;
-    } else {
-      return `${(priceEth * 1000).toFixed(2)} mETH`;
-    }
   }
 
-  async formatTrendingActivityMessage(token, activityData) {
-    let message = `🔥 **TRENDING:** ${token.token_name || 'NFT Collection'}\n\n`;
-    message += await this.formatActivityMessage(token, activityData);
-    return message;
+  /**
+   * Royalty paid on a sale with its share of the price, e.g. "0.0210 ETH (5.0%)"
+   * @returns {string|null} Null when unknown or nothing was paid
+   */
+  formatRoyalty(event) {
+    if (event.type !== 'sale' || !event.price || !event.royalty) return null;
+    const royalty = formatPrice({ ...event.price, raw: event.royalty });
+    if (!royalty) return null;
+
+    const share = Number(event.royalty) / Number(event.price.raw) * 100;
+    return Number.isFinite(share) ? `${royalty} (${share < 10 ? share.toFixed(1) : Math.round(share)}%)` : royalty;
   }
 
-  getActivityEmoji(activityType) {
-    const emojis = {
-      'transfer': '🔄',
-      'buy': '🛒',
-      'sale': '💸',
-      'mint': '✨',
-      'burn': '🔥',
-      'external_transfer': '📤',
-      'internal_transfer': '📥',
-      'unknown': '❓'
-    };
-    return emojis[activityType] || '❓';
-  }
-
-  formatActivityType(activityType) {
-    const types = {
-      'transfer': 'Transfer',
-      'buy': 'Buy',
-      'sale': 'Sale',
-      'mint': 'Mint',
-      'burn': 'Burn',
-      'external_transfer': 'External Transfer',
-      'internal_transfer': 'Internal Transfer',
-      'unknown': 'Unknown Activity'
-    };
-    return types[activityType] || 'Unknown Activity';
+  formatRarestTraits(rarity) {
+    return rarity.traits
+      .map(trait => `${trait.type}: ${trait.value} (${this.formatTraitShare(trait.share)})`)
+      .join(', ');
   }
 
-  shortenAddress(address) {
-    if (!address) return 'N/A';
-    if (address.length <= 10) return address;
-    return `${address.slice(0, 6)}...${address.slice(-4)}`;
+  formatTraitShare(share) {
+    const percent = share * 100;
+    if (percent < 0.1) return '<0.1%';
+    return `${percent < 10 ? percent.toFixed(1) : Math.round(percent)}%`;
   }
 
-  async handleAddressActivity(payload) {
-    try {
-      logger.info('Address activity webhook received:', JSON.stringify(payload, null, 2));
+  /**
+   * Render an alert with a chat's custom template
+   * @param {Object} token - Tracked token row
+   * @param {ActivityEvent} event
+   * @param {string} template - Validated Telegram HTML template
+   * @param {Object} options - { lang } for the link texts and footer
+   * @returns {Promise<string>} HTML message
+   */
+  async formatTemplatedMessage(token, event, template, { lang = DEFAULT_LANGUAGE } = {}) {
+    const values = await this.buildTemplateValues(token, event, lang);
+    return this.appendFooter(`${renderTemplate(template, values)}\n`, { html: true, lang });
+  }
 
-      if (!payload.event || !payload.event.activity) {
-        logger.warn('Invalid address activity payload structure');
-        return false;
-      }
+  /**
+   * Placeholder values of an event, escaped for Telegram HTML
+   * @returns {Promise<Object>} Placeholder name -> value ('' when unknown)
+   */
+  async buildTemplateValues(token, event, lang = DEFAULT_LANGUAGE) {
+    const price = event.type !== 'transfer' && event.type !== 'burn' ? formatPrice(event.price) : null;
+    const usdValue = price ? await this.resolveUsdValue(event) : null;
+    const nativeValue = price ? this.formatNativeEquivalent(event) : null;
+    const itemName = event.tokenId ? (event.nftName || `#${this.shortenAddress(event.tokenId)}`) : null;
+    const itemLink = getItemLink(event);
+    const explorerLink = getExplorerLink(event.chain, event.txHash);
+    const link = (url, text) => `<a href="${escapeTemplateValue(url)}">${escapeTemplateValue(text)}</a>`;
+    const rarity = await this.getEventRarity(token, event);
 
-      const activities = Array.isArray(payload.event.activity) 
-        ? payload.event.activity 
-        : [payload.event.activity];
+    return {
+      emoji: ACTIVITY_LABELS[event.type].emoji,
+      collection: escapeTemplateValue(event.collection.name || token.token_name || 'NFT Collection'),
+      token_id: escapeTemplateValue(event.tokenId || ''),
+      nft: itemName ? (event.itemUrl ? link(event.itemUrl, itemName) : escapeTemplateValue(itemName)) : '',
+      price: escapeTemplateValue(price || ''),
+      usd: usdValue ? `($${this.formatUsdAmount(usdValue)})` : '',
+      native: nativeValue ? `(${escapeTemplateValue(nativeValue)})` : '',
+      royalty: escapeTemplateValue(this.formatRoyalty(event) || ''),
+      buyer: event.buyer && event.type !== 'burn' ? escapeTemplateValue(this.shortenAddress(event.buyer)) : '',
+      seller: event.seller && event.type !== 'mint' ? escapeTemplateValue(this.shortenAddress(event.seller)) : '',
+      marketplace: escapeTemplateValue(event.marketplace || ''),
+      marketplace_link: itemLink ? link(itemLink.url, t(lang, 'alert.view_on', { name: itemLink.name })) : '',
+      explorer_link: explorerLink ? link(explorerLink.url, t(lang, 'alert.view_on', { name: explorerLink.name })) : '',
+      item_url: event.itemUrl ? escapeTemplateValue(event.itemUrl) : '',
+      explorer_url: explorerLink ? escapeTemplateValue(explorerLink.url) : '',
+      collection_id: escapeTemplateValue(event.collection.slug ||
+        this.shortenAddress(event.collection.contractAddress || token.contract_address)),
+      chain: escapeTemplateValue(this.getChainLabel(event.chain)),
+      rank: rarity ? `#${rarity.rank.toLocaleString('en-US')} / ${rarity.itemCount.toLocaleString('en-US')}` : '',
+      rarest_traits: rarity ? escapeTemplateValue(this.formatRarestTraits(rarity)) : ''
+    };
+  }
 
-      let processedCount = 0;
+  /**
+   * Render the Markdown alert for a sweep (several sales of one collection in one transaction)
+   * @param {Object} token - Tracked token row
+   * @param {Array<ActivityEvent>} events - The sweep's sales
+   * @param {Object} options
+   * @param {boolean} options.trending - Use the trending header (paid trending channels)
+   * @param {string} options.lang - Language of the labels
+   * @returns {Promise<string>} Message
+   */
+  async formatSweepMessage(token, events, { trending = false, lang = DEFAULT_LANGUAGE } = {}) {
+    const first = events[0];
+    const collectionName = first.collection.name || token.token_name || 'NFT Collection';
+    const { total, average, pricedCount } = this.summarizeSweepPrices(events);
 
-      for (const activity of activities) {
-        try {
-          // Address activities are handled manually via /validate command
-          // No automatic ETH transfer processing to prevent race conditions
-          logger.debug('Address activity received - manual validation required via /validate command');
-        } catch (error) {
-          logger.error(`Error processing individual address activity:`, error);
-        }
+    const sweep = t(lang, 'alert.sweep');
+    let message = trending
+      ? `🔥 **${t(lang, 'alert.trending')}:** ${collectionName} **${sweep}**\n\n`
+      : `🧹🟢 **${collectionName}** **${sweep}**\n\n`;
+
+    message += `🛒 **${t(lang, 'alert.items')}:** ${events.length}\n`;
+    if (total) {
+      message += `💰 **${t(lang, 'alert.total')}:** ${formatPrice(total)}`;
+      const nativeValue = this.formatNativeEquivalent({ chain: first.chain, price: total });
+      if (nativeValue) {
+        message += ` (${nativeValue})`;
       }
+      const usdValue = await this.resolveUsdValue({ price: total });
+      if (usdValue) {
+        message += ` ($${this.formatUsdAmount(usdValue)})`;
+      }
+      if (pricedCount < events.length) {
+        message += ` (${t(lang, 'alert.priced', { count: pricedCount })})`;
+      }
+      message += `\n📊 **${t(lang, 'alert.avg_price')}:** ${formatPrice(average)}\n`;
+    }
 
-      logger.info(`Processed ${processedCount}/${activities.length} address activities`);
-      return processedCount > 0;
-    } catch (error) {
-      logger.error('Error handling address activity:', error);
-      return false;
+    const buyers = [...new Set(events.map(event => event.buyer?.toLowerCase()).filter(Boolean))];
+    if (buyers.length === 1) {
+      message += `👤 **${t(lang, 'alert.buyer')}:** \`${this.shortenAddress(first.buyer)}\`\n`;
+    } else if (buyers.length > 1) {
+      message += `👤 **${t(lang, 'alert.buyers')}:** ${t(lang, 'alert.wallets', { count: buyers.length })}\n`;
     }
-  }
 
+    const itemLinks = events.slice(0, SWEEP_LINKED_ITEMS).map(event => {
+      const itemName = event.nftName || `#${this.shortenAddress(event.tokenId)}`;
+      return event.itemUrl ? `[${itemName}](${event.itemUrl})` : itemName;
+    });
+    if (events.length > SWEEP_LINKED_ITEMS) {
+      itemLinks.push(t(lang, 'alert.more', { count: events.length - SWEEP_LINKED_ITEMS }));
+    }
+    message += `🖼️ **${t(lang, 'alert.nfts')}:** ${itemLinks.join(', ')}\n`;
 
+    const marketplaces = [...new Set(events.map(event => event.marketplace).filter(Boolean))];
+    if (marketplaces.length > 0) {
+      message += `🏪 **${t(lang, 'alert.marketplace')}:** ${marketplaces.join(', ')}\n`;
+    }
+    message += first.collection.slug
+      ? `📮 **${t(lang, 'alert.collection')}:** \`${first.collection.slug}\`\n`
+      : `📮 **${t(lang, 'alert.ca')}:** \`${this.shortenAddress(first.collection.contractAddress || token.contract_address)}\`\n`;
+    message += `🔗 **${t(lang, 'alert.chain')}:** ${this.getChainLabel(first.chain)}\n`;
 
-  async handleHealthCheck(req, res) {
-    try {
-      const status = {
-        status: 'healthy',
-        timestamp: new Date().toISOString(),
-        database: 'unknown',
-        bot: 'unknown'
-      };
+    const explorerLink = getExplorerLink(first.chain, first.txHash);
+    if (explorerLink) {
+      message += `[${t(lang, 'alert.view_on', { name: explorerLink.name })}](${explorerLink.url})\n`;
+    }
 
+    return this.appendFooter(message, { lang });
+  }
 
-      try {
-        await this.db.get('SELECT 1');
-        status.database = 'connected';
-      } catch (error) {
-        status.database = 'disconnected';
-        status.status = 'unhealthy';
-      }
+  /**
+   * Total and average price of a sweep. Sales paid in another currency than the
+   * first priced one are left out of the sums.
+   * @param {Array<ActivityEvent>} events
+   * @returns {{total: Object|null, average: Object|null, pricedCount: number}}
+   */
+  summarizeSweepPrices(events) {
+    const reference = events.find(event => event.price)?.price;
+    if (!reference) {
+      return { total: null, average: null, pricedCount: 0 };
+    }
 
+    const priced = events.filter(event =>
+      event.price && event.price.currency === reference.currency && event.price.decimals === reference.decimals);
 
+    let totalRaw = 0n;
+    let totalUsd = 0;
+    let totalNative = 0;
+    for (const event of priced) {
       try {
-        await this.bot.telegram.getMe();
-        status.bot = 'connected';
+        totalRaw += BigInt(event.price.raw);
       } catch (error) {
-        status.bot = 'disconnected';
-        status.status = 'unhealthy';
+        logger.warn(`Unparseable price ${event.price.raw} in sweep ${event.txHash}`);
       }
-
-      res.status(status.status === 'healthy' ? 200 : 503).json(status);
-    } catch (error) {
-      logger.error('Error in health check:', error);
-      res.status(500).json({ 
-        status: 'error', 
-        message: error.message,
-        timestamp: new Date().toISOString()
-      });
+      totalUsd = totalUsd != null && event.price.usd != null ? totalUsd + event.price.usd : null;
+      totalNative = totalNative != null && event.price.native != null ? totalNative + event.price.native : null;
     }
+
+    const currency = { decimals: reference.decimals, currency: reference.currency, address: reference.address || null };
+    const total = { raw: totalRaw.toString(), ...currency, usd: totalUsd, native: totalNative };
+    const average = {
+      raw: (totalRaw / BigInt(priced.length)).toString(),
+      ...currency,
+      usd: totalUsd != null ? totalUsd / priced.length : null,
+      native: totalNative != null ? totalNative / priced.length : null
+    };
+    return { total, average, pricedCount: priced.length };
   }
 
-  async sendNotificationWithImage(chatId, message, token, activityData) {
-    const NFTMetadataService = require('../services/nftMetadataService');
-    const metadataService = new NFTMetadataService();
+  getChainLabel(chainName) {
+    const chainConfig = this.chainManager?.getChain(chainName);
+    return chainConfig ? `${chainConfig.emoji} ${chainConfig.displayName}` : chainName;
+  }
 
-    // Check if image fee is paid for this contract FIRST
-    const hasImageFee = this.secureTrending ? await this.secureTrending.isImageFeeActive(token.contract_address) : false;
-    logger.info(`🖼️ IMAGE FEE CHECK: ${token.contract_address} (${token.token_name}) - hasImageFee: ${hasImageFee}`);
+  /**
+   * USD value of an event price: the source's own figure, otherwise converted with PriceService
+   * @param {ActivityEvent} event
+   * @returns {Promise<number|null>}
+   */
+  async resolveUsdValue(event) {
+    if (!event.price) return null;
+    if (event.price.usd != null) return event.price.usd;
+    // Older events are valued by normalizeEventPrice from the price history or not at all
+    if (isHistoricalTime(event.occurredAt)) return null;
+
+    const priceService = this.secureTrending?.priceService;
+    if (!priceService) return null;
 
     try {
-      logger.info('Attempting to fetch NFT metadata with traits for notification');
-
-      let nftData;
-      let imagePath = null;
-
-      // Check if this is our MongsInspired contract
-      const mongsInspiredContract = process.env.MONGS_INSPIRED_CONTRACT_ADDRESS;
-      if (token.contract_address.toLowerCase() === mongsInspiredContract?.toLowerCase()) {
-        // Use the actual minted token ID from activity data
-        const actualTokenId = activityData?.tokenId;
-        if (actualTokenId) {
-          try {
-            nftData = await metadataService.getMongsInspiredToken(token.contract_address, actualTokenId);
-          } catch (error) {
-            logger.warn(`Failed to fetch MongsInspired token ${actualTokenId}, falling back to MONGS: ${error.message}`);
-            nftData = await metadataService.getRandomMongsToken();
-          }
-        } else {
-          logger.warn(`No token ID found in activity data for MongsInspired contract, falling back to MONGS`);
-          nftData = await metadataService.getRandomMongsToken();
-        }
-      } else {
-        // Fall back to MONGS mainnet for other tokens
-        nftData = await metadataService.getRandomMongsToken();
-      }
-
-      // Handle image processing based on payment status
-      let originalImagePath = null;
-      if (hasImageFee && nftData.metadata.image) {
-        // For PAID tokens: Retry until successful, never fallback
-        logger.info(`✅ IMAGE FEE PAID: Processing actual NFT image for ${token.token_name} - WILL RETRY UNTIL SUCCESS`);
-        const result = await this.retryImageProcessingForPaidToken(metadataService, nftData.metadata.image, nftData.tokenId, token.token_name);
-        originalImagePath = result.originalPath;
-        imagePath = result.resizedPath;
-      } else {
-        // For UNPAID tokens: Use default tracking image immediately
-        logger.info(`🚫 IMAGE FEE NOT PAID: Using default tracking image for ${token.token_name}`);
-        // Use pre-sized default tracking image for better performance
-        const path = require('path');
-        imagePath = path.join(__dirname, '../../src/bot/defaultTracking_300x300.jpg');
-      }
-
-      // Format the enhanced message with traits
-      const nftMessage = metadataService.formatMetadataForTelegram(nftData);
+      return await priceService.calculateUSDValue(event.price.raw, event.price.currency, event.price.decimals);
+    } catch (error) {
+      logger.warn(`Failed to get ${event.price.currency} price for USD conversion:`, error.message);
+      return null;
+    }
+  }
 
-      // Extract NFT name from the formatted message
-      const nftNameMatch = nftMessage.match(/🎨 \*\*(.*?)\*\*/);
-      const nftName = nftNameMatch ? nftNameMatch[1] : '';
+  /**
+   * Complete an event price from the ChainManager payment token registry: the token's symbol
+   * and decimals (feeds may report an ERC-20 amount with 18 decimals or a bare contract
+   * address), its USD value and its value in the chain's native currency. Prices in tokens
+   * outside the registry keep what the source reported and get only a symbol-based USD value.
+   * @param {ActivityEvent} event - Updated in place
+   */
+  async normalizeEventPrice(event) {
+    const price = event.price;
+    if (!price || !this.chainManager) return;
 
-      // Split the original message to insert NFT name after the activity title
-      const messageParts = message.split('\n\n');
-      const activityTitle = messageParts[0]; // "✨ MONGS Inspired Activity"
-      const restOfMessage = messageParts.slice(1).join('\n\n');
+    const paymentToken = this.chainManager.findPaymentToken(event.chain, price);
+    const nativeToken = this.chainManager.getNativePaymentToken(event.chain);
+    if (paymentToken) {
+      price.currency = paymentToken.symbol;
+      price.decimals = paymentToken.decimals;
+    }
 
-      const enhancedMessage = nftName
-        ? `${activityTitle}\n🎨 **${nftName}**\n\n${restOfMessage}\n\n${nftMessage.replace(/🎨 \*\*.*?\*\*\n\n/, '')}`
-        : `${message}\n\n${nftMessage}`;
+    const amount = parseFloat(price.raw) / Math.pow(10, price.decimals);
+    if (!Number.isFinite(amount)) return;
 
-      const boostButton = {
-        inline_keyboard: [[
-          {
-            text: 'BOOST YOUR NFT🟢',
-            callback_data: '/buy_trending'
-          }
-        ]]
-      };
+    try {
+      if (price.usd == null) {
+        const tokenUsd = paymentToken ? await this.getUsdRate(paymentToken.coinGeckoId, event.occurredAt) : null;
+        price.usd = tokenUsd != null ? amount * tokenUsd : await this.resolveUsdValue(event);
+      }
 
-      // ALWAYS send as photo - image processing is guaranteed to succeed
-      await this.bot.telegram.sendPhoto(
-        chatId,
-        { source: imagePath },
-        {
-          caption: enhancedMessage,
-          parse_mode: 'Markdown',
-          reply_markup: boostButton
+      if (price.native == null && nativeToken) {
+        if (paymentToken?.coinGeckoId === nativeToken.coinGeckoId) {
+          price.native = amount;
+        } else if (price.usd != null) {
+          const nativeUsd = await this.getUsdRate(nativeToken.coinGeckoId, event.occurredAt);
+          price.native = nativeUsd ? price.usd / nativeUsd : null;
         }
-      );
-
-      // Cleanup downloaded images after a delay (only for paid tokens with NFT metadata images)
-      const imagesToCleanup = hasImageFee ? [originalImagePath, imagePath].filter(Boolean) : []; // Only cleanup NFT metadata images for paid tokens
-      if (imagesToCleanup.length > 0) {
-        setTimeout(async () => {
-          for (const imageToCleanup of imagesToCleanup) {
-            try {
-              await require('fs').promises.unlink(imageToCleanup);
-              logger.info(`Cleaned up image: ${imageToCleanup}`);
-            } catch (error) {
-              logger.warn(`Failed to cleanup image ${imageToCleanup}: ${error.message}`);
-            }
-          }
-        }, 60000); // Cleanup after 1 minute
       }
-      logger.info(`Successfully sent notification with NFT metadata and traits`);
-      return;
+    } catch (error) {
+      logger.warn(`Could not value ${price.currency} on ${event.chain}: ${error.message}`);
+    }
+  }
 
-    } catch (imageError) {
-      // For PAID tokens: This should never happen due to retry logic
-      if (hasImageFee) {
-        logger.error(`🚨 CRITICAL: Paid token ${token.token_name} failed image processing after retries: ${imageError.message}`);
-        throw new Error(`Paid token image processing failed: ${imageError.message}`);
-      }
+  /**
+   * USD price of an asset when an event happened. Recent events use the live price; events
+   * older than an hour (backfills, replays, late deliveries) only a stored candle of the
+   * price history. A missing candle leaves the event unvalued instead of holding up its
+   * alert: the hours around it are fetched in the background for the events that follow.
+   * @param {string} asset - CoinGecko ID from the payment token registry
+   * @param {string} occurredAt - Event time (block timestamp)
+   * @returns {Promise<number|null>}
+   */
+  async getUsdRate(asset, occurredAt) {
+    if (isHistoricalTime(occurredAt)) {
+      return this.priceHistory.getUsdRate(asset, occurredAt, { wait: false });
+    }
 
-      // For UNPAID tokens: Fallback to default image with retry
-      logger.warn(`Unpaid token ${token.token_name} failed, retrying with default image: ${imageError.message}`);
+    const priceService = this.secureTrending?.priceService;
+    return priceService ? priceService.getCoinPrice(asset) : null;
+  }
 
-      try {
-        // Use pre-sized default tracking image for better performance
-        const path = require('path');
-        const defaultImagePath = path.join(__dirname, '../../src/bot/defaultTracking_300x300.jpg');
-        const boostButton = {
-          inline_keyboard: [[
-            {
-              text: 'BOOST YOUR NFT🟢',
-              callback_data: '/buy_trending'
-            }
-          ]]
-        };
+  /**
+   * Native value of a price in another token, e.g. "≈0.7400 ETH" for a USDC sale
+   * @param {{chain: string, price: Object|null}} event
+   * @returns {string|null} Null without a native value or when the token is valued 1:1 (WETH)
+   */
+  formatNativeEquivalent(event) {
+    const price = event.price;
+    if (price?.native == null || !this.chainManager) return null;
 
-        await this.bot.telegram.sendPhoto(
-          chatId,
-          { source: defaultImagePath },
-          {
-            caption: message,
-            parse_mode: 'Markdown',
-            reply_markup: boostButton
-          }
-        );
-        logger.info(`Sent notification with default image for unpaid token ${token.token_name}`);
-      } catch (fallbackError) {
-        logger.error(`🚨 CRITICAL: Even default image failed for ${token.token_name}: ${fallbackError.message}`);
-        throw new Error(`Complete image processing failure: ${fallbackError.message}`);
-      }
-    }
+    const nativeToken = this.chainManager.getNativePaymentToken(event.chain);
+    const paymentToken = this.chainManager.findPaymentToken(event.chain, price);
+    if (!nativeToken || paymentToken?.coinGeckoId === nativeToken.coinGeckoId) return null;
+    return `≈${this.formatStatAmount(price.native)} ${nativeToken.symbol}`;
   }
 
-  // Retry image processing for paid tokens - keeps trying until success
-  async retryImageProcessingForPaidToken(metadataService, imageUrl, tokenId, tokenName, maxRetries = 10) {
-    let attempt = 1;
+  formatUsdAmount(usdValue) {
+    const value = parseFloat(usdValue);
+    if (value >= 1000) {
+      return `${(value / 1000).toFixed(1)}K`;
+    } else if (value >= 1) {
+      return value.toFixed(2);
+    } else {
+      return value.toFixed(4);
+    }
+  }
 
-    // Add a unique processing key to prevent duplicate retries for the same notification
-    const processingKey = `${tokenName}:${tokenId}:${Date.now()}`;
-    logger.info(`🔒 STARTING PAID TOKEN PROCESSING: ${processingKey}`);
+  /**
+   * "Powered by" line plus footer advertisements (or the BuyAdspot link when slots are free)
+   * @param {string} message
+   * @param {Object} options
+   * @param {boolean} options.html - Links as Telegram HTML (custom templates) instead of Markdown
+   * @param {string} options.lang - Language of the "Powered by" line
+   */
+  async appendFooter(message, { html = false, lang = DEFAULT_LANGUAGE } = {}) {
+    const link = (text, url) => html
+      ? `<a href="${escapeTemplateValue(url)}">${escapeTemplateValue(text)}</a>`
+      : `[${text}](${url})`;
+    message += ` \n${t(lang, 'alert.powered_by', { link: link('Candy Codex', 'https://mint.candycodex.com/') })}`;
 
-    while (attempt <= maxRetries) {
+    let adLinks = [];
+    if (this.secureTrending) {
       try {
-        logger.info(`🔄 PAID TOKEN RETRY ${attempt}/${maxRetries}: Processing image for ${tokenName} (${processingKey})`);
-
-        const originalImagePath = await metadataService.downloadImage(imageUrl, tokenId);
-        if (originalImagePath) {
-          const resizedImagePath = await metadataService.resizeImage(originalImagePath, 300, 300);
-          if (resizedImagePath) {
-            logger.info(`✅ PAID TOKEN SUCCESS: Image processed on attempt ${attempt} for ${tokenName} (${processingKey})`);
-            return {
-              originalPath: originalImagePath,
-              resizedPath: resizedImagePath
-            };
-          }
-        }
-
-        logger.warn(`⚠️ PAID TOKEN ATTEMPT ${attempt} FAILED: Retrying in ${attempt * 2} seconds for ${tokenName} (${processingKey})`);
-        await new Promise(resolve => setTimeout(resolve, attempt * 2000)); // Exponential backoff
-        attempt++;
-
+        const footerAds = await this.secureTrending.getActiveFooterAds();
+        adLinks = (footerAds || []).map(ad => {
+          const ticker = ad.ticker_symbol || ad.token_symbol || 'TOKEN';
+          return link(`⭐️${ticker}`, ad.custom_link);
+        });
       } catch (error) {
-        logger.error(`❌ PAID TOKEN ATTEMPT ${attempt} ERROR for ${tokenName} (${processingKey}): ${error.message}`);
-
-        if (attempt === maxRetries) {
-          logger.error(`🚨 PAID TOKEN FINAL FAILURE: All ${maxRetries} attempts failed for ${tokenName} (${processingKey})`);
-          throw new Error(`Paid token image processing failed after ${maxRetries} attempts: ${error.message}`);
-        }
-
-        await new Promise(resolve => setTimeout(resolve, attempt * 2000));
-        attempt++;
+        // If footer ads fail, just show buy ad spot
+        adLinks = [];
       }
     }
 
-    throw new Error(`Paid token image processing exhausted all ${maxRetries} attempts`);
-  }
-
-  extractTokenIdFromTx(txHash) {
-
-
-    if (!txHash) return null;
-
-    const hashEnd = txHash.slice(-4);
-    const tokenId = parseInt(hashEnd, 16) % 1000;
-    return tokenId.toString();
-  }
-
-  formatTokenId(tokenId) {
-    if (!tokenId || tokenId === 'Unknown') {
-      return 'Unknown';
+    // Add "BuyAdspot" if less than 3 slots are filled
+    if (adLinks.length < 3) {
+      adLinks.push(link('BuyAdspot', 'https://t.me/MintTechBot?start=buy_footer'));
     }
 
-    if (typeof tokenId === 'string' && tokenId.startsWith('0x')) {
-      const decimal = parseInt(tokenId, 16);
-      return decimal.toString();
-    }
+    return `${message}\n${adLinks.join(' ')}`;
+  }
 
-    return tokenId.toString();
+  shortenAddress(address) {
+    if (!address) return 'N/A';
+    if (address.length <= 10) return address;
+    return `${address.slice(0, 6)}...${address.slice(-4)}`;
   }
 
-  // OpenSea Event Handling Methods
-  async handleOpenSeaEvent(eventType, eventData, rawEvent) {
+  /**
+   * Alchemy ADDRESS_ACTIVITY webhooks carry the activity of watched wallets
+   * @param {Object} payload - Webhook body
+   * @returns {Promise<boolean>} True if at least one alert was queued
+   */
+  async handleAddressActivity(payload) {
     try {
-      logger.info(`🌊 OPENSEA EVENT PROCESSING - ${eventType} for collection: ${eventData.collectionSlug}`);
-
-      // Create unique key for deduplication
-      const eventKey = this.createOpenSeaEventKey(eventType, eventData);
-
-      if (this.isOpenSeaEventProcessed(eventKey)) {
-        logger.info(`   ⏭️ Event ${eventKey} already processed, skipping`);
+      if (!payload.event || !payload.event.activity) {
+        logger.warn('Invalid address activity payload structure');
         return false;
       }
-
-      // Mark as being processed
-      this.markOpenSeaEventProcessed(eventKey);
-
-      // CRITICAL: Check if we have tracked tokens for this collection
-      const tokens = await this.db.getTokensForCollectionSlug(eventData.collectionSlug);
-      logger.info(`   📊 Collection ${eventData.collectionSlug} analysis:`);
-      logger.info(`      - Tokens returned by query: ${tokens?.length || 0}`);
-
-      if (!tokens || tokens.length === 0) {
-        logger.info(`   🛑 STOPPING EVENT PROCESSING - No tracked tokens found for collection: ${eventData.collectionSlug}`);
-        logger.info(`      - This collection should not receive events anymore`);
+      if (!this.walletWatch || !this.chainManager) {
+        logger.debug('Address activity received without wallet watching, skipping');
         return false;
       }
 
-      // Log details about returned tokens
-      logger.info(`      - Active tokens in collection:`, tokens.map(t => ({
-        contract: t.contract_address,
-        name: t.token_name,
-        is_active: t.is_active,
-        has_subscriptions: 'checking...'
-      })));
-
-      // Additional safety check: verify at least one token has active subscriptions
-      let hasAnyActiveSubscriptions = false;
-      for (const token of tokens) {
-        if (typeof this.db.hasAnyActiveSubscriptions === 'function') {
-          const tokenHasSubscriptions = await this.db.hasAnyActiveSubscriptions(token.id);
-          if (tokenHasSubscriptions) {
-            hasAnyActiveSubscriptions = true;
-            logger.info(`      - Token ${token.contract_address} has active subscriptions: YES`);
-          } else {
-            logger.info(`      - Token ${token.contract_address} has active subscriptions: NO`);
-          }
-        } else {
-          logger.info(`      - Token ${token.contract_address} subscription check skipped (method not available)`);
-        }
-      }
-
-      if (!hasAnyActiveSubscriptions) {
-        logger.info(`   🛑 STOPPING EVENT PROCESSING - Collection ${eventData.collectionSlug} has no tokens with active subscriptions`);
-        logger.info(`      - This indicates a database inconsistency or recent unsubscription`);
+      const chain = this.chainManager.getAllChains().find(config => config.alchemyNetwork === payload.event.network);
+      if (!chain) {
+        logger.warn(`Address activity for unknown network ${payload.event.network}`);
         return false;
       }
 
-      logger.info(`   ✅ Proceeding with event processing for ${tokens.length} tokens`);
+      const activities = Array.isArray(payload.event.activity)
+        ? payload.event.activity
+        : [payload.event.activity];
+      const events = fromAlchemyAddressActivity(activities, {
+        chain: chain.name,
+        currency: chain.currencySymbol,
+        occurredAt: payload.createdAt
+      });
 
-      // Process each tracked token in this collection
       let processedCount = 0;
-      for (const token of tokens) {
+      for (const event of events) {
         try {
-          await this.processOpenSeaEventForToken(eventType, eventData, token, rawEvent);
-          processedCount++;
+          if (await this.notifyWalletActivity(event)) processedCount++;
         } catch (error) {
-          logger.error(`Error processing OpenSea event for token ${token.contract_address}:`, error);
+          logger.error(`Error processing wallet activity ${event.txHash}:`, error);
         }
       }
 
-      logger.info(`Processed OpenSea ${eventType} event for ${processedCount} tokens`);
+      logger.info(`👛 Processed ${processedCount}/${events.length} NFT movement(s) of watched wallets on ${chain.name}`);
       return processedCount > 0;
     } catch (error) {
-      logger.error(`Error handling OpenSea ${eventType} event:`, error);
+      logger.error('Error handling address activity:', error);
       return false;
     }
   }
 
-  async processOpenSeaEventForToken(eventType, eventData, token, rawEvent) {
-    try {
-      // SAFEGUARD: Verify token still exists in database before processing
-      const tokenExists = await this.db.get('SELECT id FROM tracked_tokens WHERE id = $1 AND is_active = true', [token.id]);
-      if (!tokenExists) {
-        logger.warn(`🚫 Skipping OpenSea event processing - token ${token.contract_address} no longer exists in database`);
-        return;
-      }
+  /**
+   * Alert the chats watching the buyer or seller of an event, in any collection
+   * @param {ActivityEvent} event - Canonical activity event
+   * @returns {Promise<boolean>} True if at least one alert was queued
+   */
+  async notifyWalletActivity(event) {
+    // A transfer through a marketplace is reported to watchers as a sale
+    await this.saleAttribution.attribute(event);
 
-      // SAFEGUARD: Check if any users exist in database at all
-      const userCount = await this.db.get('SELECT COUNT(*) as count FROM users WHERE is_active = true');
-      if (!userCount || userCount.count === 0) {
-        logger.warn(`🚫 Skipping OpenSea event processing - no active users in database`);
-        return;
+    const parties = [];
+    for (const role of ['buyer', 'seller']) {
+      const action = WALLET_ACTIONS[role][event.type];
+      const watchers = action ? await this.walletWatch.getWatchers(event.chain, event[role]) : [];
+      if (watchers.length > 0) {
+        parties.push({ role, action, watchers });
       }
+    }
+    if (parties.length === 0) {
+      return false;
+    }
 
-      // OPTIMIZATION: Skip processing if token has no active subscriptions and no premium features
-      const hasActiveSubscriptions = typeof this.db.hasAnyActiveSubscriptions === 'function'
-        ? await this.db.hasAnyActiveSubscriptions(token.id)
-        : false;
-      const hasActivePremiumFeatures = await this.db.hasActivePremiumFeatures(token.contract_address);
+    if (event.price) {
+      await this.saleAttribution.stampBlockTime(event);
+    }
+    await this.normalizeEventPrice(event);
+    const token = await this.resolveWalletEventToken(event);
+    const media = await this.prepareEventMedia(token, event);
 
-      if (!hasActiveSubscriptions && !hasActivePremiumFeatures) {
-        logger.debug(`⏭️ Skipping OpenSea event processing - token ${token.contract_address} has no active subscriptions or premium features`);
-        return;
+    let queuedCount = 0;
+    for (const { role, action, watchers } of parties) {
+      const eventKey = `wallet:${event.chain}:${event.txHash}:${event.tokenId}:${role}:${watchers[0].address}`;
+      if (!await this.dedup.claim('wallet', eventKey)) {
+        logger.info(`⏭️ Wallet event ${eventKey} already processed, skipping`);
+        continue;
       }
 
-      // Convert OpenSea event to our internal activity format
-      const activityData = this.convertOpenSeaEventToActivity(eventType, eventData, token);
+      for (const wallet of watchers) {
+        const lang = await this.getChatLanguage(wallet.chat_id);
+        const message = await this.formatWalletActivityMessage(token, event, wallet, action, lang);
+        const recipient = wallet.chat_type === 'channel'
+          ? { type: 'channel', id: wallet.chat_id }
+          : (wallet.added_by ? { type: 'user', id: wallet.added_by } : null);
 
-      // Log the activity
-      await this.db.logNFTActivity(activityData);
+        try {
+          await this.sendActivityNotification(wallet.chat_id, message, media, recipient, 'Markdown', lang);
+          queuedCount++;
+        } catch (error) {
+          logger.error(`❌ Failed to queue wallet alert to ${wallet.chat_id}:`, error);
+        }
+      }
+    }
 
-      // Notify users subscribed to this specific token with rich OpenSea data
-      await this.notifyUsersOpenSea(token, eventType, eventData, activityData);
+    logger.info(`👛 ${event.chain} ${event.type} ${event.tokenId}: ${queuedCount} wallet alert(s) queued`);
+    return queuedCount > 0;
+  }
 
-      // Check if token should notify channels
-      const shouldNotifyChannels = await this.shouldNotifyChannelsForToken(token.contract_address);
-      if (shouldNotifyChannels.notify) {
-        logger.info(`📢 Notifying channels for ${token.token_name} via OpenSea event (${shouldNotifyChannels.reason})`);
-        await this.notifyChannelsOpenSea(token, eventType, eventData, activityData, shouldNotifyChannels.channels, shouldNotifyChannels.isTrending);
+  /**
+   * Collection details for a watched wallet's event: the tracked token when the collection
+   * is tracked, otherwise what the marketplace knows about the item
+   * @param {ActivityEvent} event - Event, whose collection fields are filled in
+   * @returns {Promise<Object>} Tracked token row, or the same fields for an untracked collection
+   */
+  async resolveWalletEventToken(event) {
+    let tracked = null;
+    try {
+      if (event.chain === 'solana' && this.magicEden) {
+        const item = await this.magicEden.getNFTMetadata(event.tokenId);
+        event.nftName = event.nftName || item?.name || null;
+        event.imageUrl = event.imageUrl || item?.image || null;
+        event.collection.slug = event.collection.slug || item?.collection || null;
+      } else if (event.chain === 'bitcoin' && this.hiro) {
+        // Watched wallets also hold untracked inscriptions, which only a metadata lookup can place
+        event.collection.slug = event.collection.slug || this.hiro.findIndexedCollection(event.tokenId) ||
+          await this.hiro.resolveCollectionSymbol(event.tokenId);
+      }
+
+      if (event.collection.slug && (event.chain === 'solana' || event.chain === 'bitcoin')) {
+        tracked = await this.db.get(
+          'SELECT * FROM tracked_tokens WHERE chain_name = $1 AND collection_slug = $2 AND is_active = true',
+          [event.chain, event.collection.slug]
+        );
+      } else if (event.contractAddress) {
+        tracked = await this.db.getTrackedToken(event.contractAddress, event.chain);
       }
-
-      logger.info(`Successfully processed OpenSea ${eventType} event for token ${token.contract_address}`);
     } catch (error) {
-      logger.error(`Error processing OpenSea event for token ${token.contract_address}:`, error);
-      throw error;
+      logger.warn(`Could not resolve the collection of ${event.chain} ${event.tokenId}: ${error.message}`);
     }
-  }
-
-  convertOpenSeaEventToActivity(eventType, eventData, token) {
-    // Map OpenSea event types to our activity types (excluding cancelled events)
-    const activityTypeMap = {
-      'listed': 'sale',
-      'sold': 'buy',
-      'transferred': 'transfer',
-      'metadata_updated': 'transfer',
-      'received_bid': 'sale',
-      'received_offer': 'sale'
-    };
 
+    if (tracked) {
+      event.collection.name = tracked.token_name || event.collection.name;
+      event.collection.slug = event.collection.slug || tracked.collection_slug || null;
+      return tracked;
+    }
     return {
-      contractAddress: eventData.contractAddress || token.contract_address,
-      tokenId: eventData.tokenId || null,
-      activityType: activityTypeMap[eventType] || 'transfer',
-      fromAddress: eventData.fromAddress || eventData.makerAddress,
-      toAddress: eventData.toAddress || eventData.takerAddress,
-      transactionHash: eventData.transactionHash || null,
-      blockNumber: eventData.blockNumber || null,
-      price: eventData.price || '1000000000000000', // Default to 0.001 ETH in wei
-      marketplace: 'OpenSea'
+      token_name: event.collection.name || event.collection.slug,
+      contract_address: event.contractAddress || event.collection.slug,
+      collection_slug: event.collection.slug,
+      chain_name: event.chain
     };
   }
 
-  createOpenSeaEventKey(eventType, eventData) {
-    // Create unique key for deduplication
-    const contractAddress = eventData.contractAddress || 'unknown';
-    const tokenId = eventData.tokenId || 'unknown';
-    const txHash = eventData.transactionHash || eventData.orderHash || 'unknown';
-    const timestamp = eventData.sentAt || new Date().toISOString();
-
-    return `opensea:${eventType}:${contractAddress}:${tokenId}:${txHash}:${timestamp}`;
+  /**
+   * Alert for a watched wallet: which wallet did what, then the usual event details
+   * @param {Object} wallet - watched_wallets row
+   * @param {string} action - Key of wallet.action.* (bought, sold, ...)
+   * @returns {Promise<string>} Markdown message
+   */
+  async formatWalletActivityMessage(token, event, wallet, action, lang = DEFAULT_LANGUAGE) {
+    const label = wallet.label || this.shortenAddress(wallet.address);
+    const header = `👛 **${label}** ${t(lang, `wallet.action.${action}`)}\n`;
+    return header + await this.formatActivityEventMessage(token, event, { lang });
   }
 
-  isOpenSeaEventProcessed(eventKey) {
-    const timestamp = this.processedOpenSeaEvents.get(eventKey);
-    if (!timestamp) return false;
-
-    const isValid = (Date.now() - timestamp) <= this.CACHE_EXPIRY_MS;
-    if (!isValid) {
-      this.processedOpenSeaEvents.delete(eventKey);
+  /**
+   * NFT movements of watched Solana wallets in a Helius transaction
+   * @param {Object} transaction - Enhanced transaction from Helius
+   * @returns {Promise<boolean>} True if at least one alert was queued
+   */
+  async handleHeliusWalletActivity(transaction) {
+    if (!await this.walletWatch.hasWallets('solana')) {
       return false;
     }
-    return true;
-  }
 
-  markOpenSeaEventProcessed(eventKey) {
-    this.processedOpenSeaEvents.set(eventKey, Date.now());
+    let notified = false;
+    for (const event of fromHeliusTransaction(transaction)) {
+      if (await this.notifyWalletActivity(event)) notified = true;
+    }
+    return notified;
   }
 
-  // Setup OpenSea event handlers for a collection
-  async setupOpenSeaHandlers(collectionSlug) {
+  async handleHealthCheck(req, res) {
     try {
-      if (!this.openSea) {
-        logger.warn('OpenSea service not available');
-        return null;
-      }
-
-      const eventHandlers = {
-        listed: (eventData, rawEvent) => this.handleOpenSeaEvent('listed', eventData, rawEvent),
-        sold: (eventData, rawEvent) => this.handleOpenSeaEvent('sold', eventData, rawEvent),
-        cancelled: (eventData, rawEvent) => this.handleOpenSeaEvent('cancelled', eventData, rawEvent),
-        received_bid: (eventData, rawEvent) => this.handleOpenSeaEvent('received_bid', eventData, rawEvent),
-        received_offer: (eventData, rawEvent) => this.handleOpenSeaEvent('received_offer', eventData, rawEvent),
-        default: (eventType, eventData, rawEvent) => this.handleOpenSeaEvent(eventType, eventData, rawEvent)
+      const status = {
+        status: 'healthy',
+        timestamp: new Date().toISOString(),
+        database: 'unknown',
+        bot: 'unknown'
       };
 
-      const subscription = await this.openSea.subscribeToCollection(collectionSlug, eventHandlers);
-      logger.info(`Set up OpenSea event handlers for collection: ${collectionSlug}`);
-      return subscription;
-    } catch (error) {
-      logger.error(`Failed to setup OpenSea handlers for collection ${collectionSlug}:`, error);
-      throw error;
-    }
-  }
 
-  // OpenSea-specific notification methods with rich data formatting
-  async notifyUsersOpenSea(token, eventType, eventData, activityData) {
-    try {
-      // SAFEGUARD: Verify token still exists in database
-      const tokenExists = await this.db.get('SELECT id FROM tracked_tokens WHERE id = $1 AND is_active = true', [token.id]);
-      if (!tokenExists) {
-        logger.warn(`🚫 Skipping notification - token ${token.contract_address} no longer exists in database`);
-        return false;
+      try {
+        await this.db.get('SELECT 1');
+        status.database = 'connected';
+      } catch (error) {
+        status.database = 'disconnected';
+        status.status = 'unhealthy';
       }
 
-      // SAFEGUARD: Check if any users exist in database at all
-      const userCount = await this.db.get('SELECT COUNT(*) as count FROM users WHERE is_active = true');
-      if (!userCount || userCount.count === 0) {
-        logger.warn(`🚫 Skipping notification - no active users in database`);
-        return false;
-      }
 
-      // OPTIMIZATION: Skip if no active subscriptions (avoids unnecessary query)
-      const hasActiveSubscriptions = typeof this.db.hasAnyActiveSubscriptions === 'function'
-        ? await this.db.hasAnyActiveSubscriptions(token.id)
-        : false;
-      if (!hasActiveSubscriptions) {
-        logger.debug(`⏭️ Skipping OpenSea notification - token ${token.contract_address} has no active subscriptions`);
-        return false;
+      try {
+        await this.bot.telegram.getMe();
+        status.bot = 'connected';
+      } catch (error) {
+        status.bot = 'disconnected';
+        status.status = 'unhealthy';
       }
 
-      // Get users with their subscription context (chat_id)
-      // EXCLUDE channels - they should only receive notifications via notifyChannelsOpenSea with tier filtering
-      const subscriptions = await this.db.all(`
-        SELECT u.telegram_id, u.username, us.notification_enabled, us.chat_id
-        FROM users u
-        JOIN user_subscriptions us ON u.id = us.user_id
-        WHERE us.token_id = $1
-          AND us.notification_enabled = true
-          AND u.is_active = true
-          AND us.chat_id NOT IN (SELECT telegram_chat_id FROM channels)
-      `, [token.id]);
-
-      if (!subscriptions || subscriptions.length === 0) {
-        logger.debug(`No users subscribed to token: ${token.contract_address}`);
-        return false;
-      }
-
-      const message = await this.formatOpenSeaActivityMessage(eventType, eventData, token);
-      logger.info(`📤 Sending OpenSea ${eventType} notification to ${subscriptions.length} subscription(s) for ${token.token_name}`);
-
-      // Send notifications to users in their specific subscribed contexts
-      let successCount = 0;
-
-      // Send to subscribed users in their correct context
-      for (const subscription of subscriptions) {
-        try {
-          // Determine target chat ID based on subscription context
-          let targetChatId;
-          if (subscription.chat_id === 'private') {
-            // For private chats, send to user's private chat
-            targetChatId = subscription.telegram_id;
-          } else {
-            // For group chats, send to the specific group where they subscribed
-            targetChatId = subscription.chat_id;
-          }
-
-          await this.sendOpenSeaNotificationWithImage(targetChatId, message, eventData);
-          successCount++;
-          logger.info(`✅ OpenSea notification sent to ${subscription.chat_id === 'private' ? 'private chat' : 'group'} ${targetChatId} for user ${subscription.telegram_id}`);
-        } catch (error) {
-          logger.error(`❌ Failed to send OpenSea notification to ${subscription.chat_id} for user ${subscription.telegram_id}:`, error);
-        }
-      }
-
-      logger.info(`📊 OpenSea notification summary: ${successCount}/${subscriptions.length} notifications sent successfully`);
-      return successCount > 0;
+      res.status(status.status === 'healthy' ? 200 : 503).json(status);
     } catch (error) {
-      logger.error('Error notifying users for OpenSea event:', error);
-      return false;
+      logger.error('Error in health check:', error);
+      res.status(500).json({ 
+        status: 'error', 
+        message: error.message,
+        timestamp: new Date().toISOString()
+      });
     }
   }
 
-  async notifyChannelsOpenSea(token, eventType, eventData, activityData, channels, isTrending = false) {
-    try {
-      // Authorization already performed by shouldNotifyChannelsForToken() before calling this method
-
-      if (!channels || channels.length === 0) {
-        return false;
-      }
-
-      const message = isTrending
-        ? await this.formatTrendingOpenSeaMessage(eventType, eventData, token)
-        : await this.formatOpenSeaActivityMessage(eventType, eventData, token);
+  /**
+   * Work out once per event which image its alerts carry. The item's own image is only
+   * used when the collection has paid the image fee; otherwise the default image is sent.
+   * @param {Object} token - Tracked token row
+   * @param {ActivityEvent} event - Canonical activity event
+   * @returns {Promise<{event: ActivityEvent, hasImageFee: boolean, imageUrl: string|null}>}
+   */
+  async prepareEventMedia(token, event) {
+    const hasImageFee = this.secureTrending ? await this.secureTrending.isImageFeeActive(token.contract_address) : false;
+    logger.info(`🖼️ IMAGE FEE CHECK: ${token.contract_address} (${token.token_name}) - hasImageFee: ${hasImageFee}`);
 
-      let notifiedCount = 0;
-      for (const channel of channels) {
-        try {
-          logger.info(`📤 SENDING OpenSea to channel ${channel.channel_title}: ${token.token_name} (verified trending payment)`);
-          // Use same field name as original: telegram_chat_id
-          await this.sendOpenSeaNotificationWithImage(channel.telegram_chat_id, message, eventData);
-          notifiedCount++;
-          logger.info(`✅ OpenSea notification sent to channel: ${channel.channel_title || channel.channel_name}`);
-        } catch (error) {
-          logger.error(`❌ Failed to send OpenSea notification to channel ${channel.channel_title || channel.channel_name}:`, error);
-
-          // Handle bot removal from channel (same as original)
-          if (error.response?.error_code === 403) {
-            await this.db.run(
-              'UPDATE channels SET is_active = false WHERE telegram_chat_id = $1',
-              [channel.telegram_chat_id]
-            );
-            logger.info(`Deactivated channel ${channel.telegram_chat_id} due to bot removal`);
-          }
-        }
+    let imageUrl = null;
+    if (hasImageFee) {
+      try {
+        imageUrl = await this.resolveEventImageUrl(event);
+      } catch (error) {
+        logger.warn(`Could not resolve image for ${event.source} ${event.type} ${event.tokenId}: ${error.message}`);
       }
-
-      logger.info(`📢 Notified ${notifiedCount}/${channels.length} channels for OpenSea ${eventType} event`);
-      return notifiedCount > 0;
-    } catch (error) {
-      logger.error('Error notifying channels for OpenSea event:', error);
-      return false;
     }
+
+    return { event, hasImageFee, imageUrl };
   }
 
-  async formatOpenSeaActivityMessage(eventType, eventData, token) {
-    const collectionName = eventData.collectionName || token.token_name || 'NFT Collection';
-    const nftName = eventData.nftName || `#${eventData.tokenId || 'Unknown'}`;
-    const tokenSymbol = token.token_symbol || 'TOKEN';
+  /**
+   * Find the item image for sources that don't include one in their payload
+   * @param {ActivityEvent} event
+   * @returns {Promise<string|null>} Image URL
+   */
+  async resolveEventImageUrl(event) {
+    if (event.imageUrl) return event.imageUrl;
+    if (event.chain === 'bitcoin') return this.resolveInscriptionImageUrl(event);
+    if (event.chain !== 'ethereum' || !event.contractAddress || !event.tokenId) return null;
 
-    // Get event-specific emoji and action
-    const eventInfo = this.getOpenSeaEventInfo(eventType);
+    // Alchemy events carry no metadata, read it from tokenURI
+    const NFTMetadataService = require('../services/nftMetadataService');
+    const metadataService = new NFTMetadataService();
+    const mongsInspiredContract = process.env.MONGS_INSPIRED_CONTRACT_ADDRESS;
+    const nftData = event.contractAddress.toLowerCase() === mongsInspiredContract?.toLowerCase()
+      ? await metadataService.getMongsInspiredToken(event.contractAddress, event.tokenId)
+      : await metadataService.fetchTokenMetadata(event.contractAddress, event.tokenId, true);
 
-    let message = `${eventInfo.emoji} **${collectionName}** ${eventInfo.action}\n\n`;
+    const image = nftData?.metadata?.image;
+    return image ? metadataService.resolveIPFS(image) : null;
+  }
 
-    // NFT details
-    message += `🖼️ **NFT:** ${nftName}\n`;
+  /**
+   * Inscription image from Magic Eden, falling back to the collection image
+   * @param {ActivityEvent} event - Bitcoin Ordinals event
+   * @returns {Promise<string|null>} Image URL
+   */
+  async resolveInscriptionImageUrl(event) {
+    if (!this.magicEdenOrdinals) return null;
 
-    // Token ID as clickable shortened link to OpenSea
-    if (eventData.tokenId && eventData.contractAddress) {
-      const openseaUrl = `https://opensea.io/assets/${token.chain_name || 'ethereum'}/${eventData.contractAddress}/${eventData.tokenId}`;
-      const shortContract = this.shortenAddress(eventData.contractAddress);
-      message += `🔢 **Token ID:** [${shortContract}/${eventData.tokenId}](${openseaUrl})\n`;
-    } else if (eventData.tokenId) {
-      message += `🔢 **Token ID:** ${eventData.tokenId}\n`;
+    let inscriptionImage = null;
+    try {
+      const inscriptionMetadata = event.tokenId ? await this.magicEdenOrdinals.getInscriptionMetadata(event.tokenId) : null;
+      if (inscriptionMetadata) {
+        // Try multiple possible image fields from Magic Eden API, higher quality versions first
+        inscriptionImage = inscriptionMetadata.contentURI ||
+                         inscriptionMetadata.imageURI ||
+                         inscriptionMetadata.content_url ||
+                         inscriptionMetadata.image ||
+                         inscriptionMetadata.meta?.high_res_img_url ||
+                         inscriptionMetadata.chain?.high_res_image ||
+                         null;
+      }
+    } catch (error) {
+      logger.warn(`₿ Could not fetch inscription metadata:`, error.message);
     }
 
-    // Price information (for relevant events)
-    if (eventData.price && eventType !== 'transferred') {
-      const priceFormatted = this.formatOpenSeaPrice(eventData.price, eventData.paymentTokenSymbol, eventData.paymentTokenDecimals);
-      message += `💰 **${eventInfo.priceLabel}:** ${priceFormatted}`;
-
-      // Add USD value if available
-      if (eventData.priceUsd) {
-        message += ` ($${this.formatUsdAmount(eventData.priceUsd)})`;
-      } else {
-        // Debug info when USD is missing
-        logger.warn(`💰 USD price missing for ${eventType} event:`, {
-          event_type: eventType,
-          has_price: !!eventData.price,
-          price_value: eventData.price,
-          payment_token_symbol: eventData.paymentTokenSymbol,
-          payment_token_usd_price: eventData.paymentTokenUsdPrice,
-          contract: eventData.contractAddress,
-          token_id: eventData.tokenId
-        });
+    if (inscriptionImage) {
+      // Ask the Magic Eden CDN for a larger rendition
+      if ((inscriptionImage.includes('img-cdn.magiceden.dev') || inscriptionImage.includes('ord-mirror.magiceden.dev')) &&
+          !inscriptionImage.includes('?')) {
+        inscriptionImage = `${inscriptionImage}?w=600`;
       }
-      message += '\n';
+      return inscriptionImage;
     }
 
-    // User addresses - removed to keep notifications short
-    // Keeping only transfer addresses as they are essential
-    if (eventType === 'transferred') {
-      if (eventData.fromAddress) {
-        message += `📤 **From:** \`${this.shortenAddress(eventData.fromAddress)}\`\n`;
-      }
-      if (eventData.toAddress) {
-        message += `📥 **To:** \`${this.shortenAddress(eventData.toAddress)}\`\n`;
+    try {
+      const collectionData = await this.magicEdenOrdinals.validateCollectionSymbol(event.collection.slug);
+      if (collectionData?.image) {
+        logger.info(`₿ Using collection image as fallback: ${collectionData.image}`);
+        return collectionData.image;
       }
+    } catch (error) {
+      logger.warn(`₿ Could not fetch collection image fallback:`, error.message);
     }
 
-    // Collection and marketplace info
-    message += `🏪 **Marketplace:** OpenSea\n`;
-    message += `📮 **Collection:** \`${eventData.collectionSlug || 'Unknown'}\`\n`;
+    return null;
+  }
 
-    // Add chain information for OpenSea events
-    if (this.chainManager && token.chain_name) {
-      const chainConfig = this.chainManager.getChain(token.chain_name);
-      if (chainConfig) {
-        message += `🔗 **Chain:** ${chainConfig.emoji} ${chainConfig.displayName}\n`;
-      } else {
-        message += `🔗 **Chain:** ${token.chain_name}\n`;
-      }
+  /**
+   * Queue one alert as a photo with the boost button
+   * @param {string|number} chatId - Target chat
+   * @param {string} message - Markdown caption
+   * @param {Object} media - Result of prepareEventMedia
+   * @param {Object|null} recipient - { type: 'user'|'channel', id }
+   */
+  async sendActivityNotification(chatId, message, media, recipient = null, parseMode = 'Markdown', lang = DEFAULT_LANGUAGE) {
+    // Each recipient gets its own copy, since the outbox deletes the file once that message is delivered
+    let imagePath = null;
+    if (media.hasImageFee && media.imageUrl) {
+      imagePath = await this.retryEventImageForPaidToken(media.event, media.imageUrl);
     }
-
-    // Transaction link - removed from sale and transfer events to keep notifications short
-    if (eventData.transactionHash && eventType !== 'sold' && eventType !== 'transferred') {
-      message += `🔗 **TX:** \`${this.shortenAddress(eventData.transactionHash)}\`\n`;
-      message += `[View on Etherscan](https://etherscan.io/tx/${eventData.transactionHash})\n`;
+    const cleanupFiles = imagePath ? [imagePath] : [];
+    if (!imagePath) {
+      imagePath = await this.resizeDefaultTrackingImage();
     }
 
-    // OpenSea link (using the exact format from OpenSea's item.permalink or manual construction)
-    if (eventData.contractAddress && eventData.tokenId) {
-      // Get chain name for OpenSea URL - map our chain names to OpenSea's format
-      const openSeaChainMap = {
-        'ethereum': 'ethereum',
-        'arbitrum': 'arbitrum',
-        'optimism': 'optimism',
-        'bsc': 'bsc',
-        'hyperblast': 'ethereum' // Fallback to ethereum since HyperEVM not supported on OpenSea
-      };
-      const chainForUrl = openSeaChainMap[token.chain_name] || 'ethereum';
-
-      // OpenSea URLs are: https://opensea.io/assets/{chain}/{contract}/{tokenId}
-      message += `[View on OpenSea](https://opensea.io/assets/${chainForUrl}/${eventData.contractAddress}/${eventData.tokenId})\n`;
-    } else if (eventData.collectionSlug) {
-      message += `[View Collection](https://opensea.io/collection/${eventData.collectionSlug})\n`;
-    }
+    // ALWAYS send as photo
+    await this.queuePhotoNotification(chatId, imagePath, {
+      caption: message,
+      parse_mode: parseMode,
+      reply_markup: boostButton(lang)
+    }, recipient, cleanupFiles);
+    logger.info(`✅ ${media.event.source} notification with image queued for ${chatId}`);
+  }
 
-    message += ` \nPowered by [Candy Codex](https://mint.candycodex.com/)`;
+  // Retry image processing for paid tokens before falling back to the default image
+  async retryEventImageForPaidToken(event, imageUrl, maxAttempts = PAID_IMAGE_ATTEMPTS) {
+    const fileKey = String(event.tokenId || 'unknown').slice(0, 16);
 
-    // Add footer advertisements if available
-    if (this.secureTrending) {
+    for (let attempt = 1; attempt <= maxAttempts; attempt++) {
       try {
-        const footerAds = await this.secureTrending.getActiveFooterAds();
-        if (footerAds && footerAds.length > 0) {
-          const adLinks = footerAds.map(ad => {
-            const ticker = ad.ticker_symbol || ad.token_symbol || 'TOKEN';
-            return `[⭐️${ticker}](${ad.custom_link})`;
-          });
-
-          // Add "BuyAdspot" if less than 3 slots are filled
-          if (adLinks.length < 3) {
-            adLinks.push('[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)');
-          }
-
-          message += `\n${adLinks.join(' ')}`;
-        } else {
-          message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
+        logger.info(`🔄 PAID IMAGE ATTEMPT ${attempt}/${maxAttempts}: Processing image for ${event.collection.name} ${event.tokenId}`);
+        const imagePath = event.chain === 'bitcoin'
+          ? await this.downloadAndResizeBitcoinImage(imageUrl, fileKey)
+          : await this.downloadAndResizeImage(imageUrl, fileKey);
+        if (imagePath) {
+          return imagePath;
         }
       } catch (error) {
-        message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
+        logger.warn(`⚠️ PAID IMAGE ATTEMPT ${attempt} ERROR for ${event.collection.name}: ${error.message}`);
+      }
+
+      if (attempt < maxAttempts) {
+        await new Promise(resolve => setTimeout(resolve, attempt * 2000));
       }
-    } else {
-      message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
     }
 
-    return message;
+    logger.error(`🚨 PAID IMAGE FAILURE: All ${maxAttempts} attempts failed for ${event.collection.name} ${event.tokenId}, using default image`);
+    return null;
   }
 
-  async formatTrendingOpenSeaMessage(eventType, eventData, token) {
-    const collectionName = eventData.collectionName || token.token_name || 'NFT Collection';
-    const eventInfo = this.getOpenSeaEventInfo(eventType);
-
-    let message = `🔥 **TRENDING:** ${collectionName} ${eventInfo.action}\n\n`;
+  /**
+   * Queue the sweep alert for each target, with a collage of the items when the
+   * collection paid the image fee and the default image otherwise
+   * @returns {Promise<number>} Number of alerts queued
+   */
+  async sendSweepToTargets(token, events, targets) {
+    const messages = {};
+    const collage = await this.prepareSweepCollage(token, events);
 
-    // Add rest of the message using the regular formatter
-    const regularMessage = await this.formatOpenSeaActivityMessage(eventType, eventData, token);
-    // Remove the first line and add to trending message
-    const lines = regularMessage.split('\n');
-    message += lines.slice(1).join('\n');
+    let queuedCount = 0;
+    for (const target of targets) {
+      const lang = await this.getChatLanguage(target.chatId);
+      const variant = `${lang}:${target.trending ? 'trending' : 'default'}`;
+      if (!messages[variant]) {
+        messages[variant] = await this.formatSweepMessage(token, events, { trending: target.trending, lang });
+      }
 
-    return message;
+      try {
+        await this.sendSweepNotification(target.chatId, messages[variant], collage, events[0], target.recipient, lang);
+        queuedCount++;
+      } catch (error) {
+        logger.error(`❌ Failed to queue sweep notification to ${target.chatId}:`, error);
+      }
+    }
+    return queuedCount;
   }
 
-  getOpenSeaEventInfo(eventType) {
-    const eventMap = {
-      'sold': {
-        emoji: '💰🟢',
-        action: '**Buy**',
-        priceLabel: 'Buy Price'
-      },
-      'listed': {
-        emoji: '📝',
-        action: 'Listed',
-        priceLabel: 'List Price'
-      },
-      'transferred': {
-        emoji: '🔄',
-        action: 'Transfer',
-        priceLabel: 'Value'
-      },
-      'received_bid': {
-        emoji: '🏷️',
-        action: 'Bid Received',
-        priceLabel: 'Bid Amount'
-      },
-      'received_offer': {
-        emoji: '💱',
-        action: 'Offer Received',
-        priceLabel: 'Offer Amount'
-      },
-      'metadata_updated': {
-        emoji: '📊',
-        action: 'Updated',
-        priceLabel: 'Value'
-      }
-    };
+  async sendSweepNotification(chatId, message, collage, event, recipient = null, lang = DEFAULT_LANGUAGE) {
+    let imagePath = null;
+    let cleanupFiles = [];
+    if (collage) {
+      // Each recipient gets its own file, since the outbox deletes it once that message is delivered
+      const fs = require('fs').promises;
+      const path = require('path');
+      const tempDir = path.join(__dirname, '../../temp_opensea_images');
+      await fs.mkdir(tempDir, { recursive: true });
+      imagePath = path.join(tempDir, `sweep_${String(event.txHash).slice(0, 16)}_${chatId}_${Date.now()}.jpg`);
+      await fs.writeFile(imagePath, collage);
+      cleanupFiles = [imagePath];
+    } else {
+      imagePath = await this.resizeDefaultTrackingImage();
+    }
 
-    return eventMap[eventType] || {
-      emoji: '🎯',
-      action: 'Activity',
-      priceLabel: 'Price'
-    };
+    await this.queuePhotoNotification(chatId, imagePath, {
+      caption: message,
+      parse_mode: 'Markdown',
+      reply_markup: boostButton(lang)
+    }, recipient, cleanupFiles);
+    logger.info(`✅ Sweep notification ${collage ? 'with collage ' : ''}queued for ${chatId}`);
   }
 
-  formatOpenSeaPrice(priceWei, tokenSymbol = 'ETH', decimals = 18) {
-    if (!priceWei || parseFloat(priceWei) <= 0) {
-      return '0 ETH';
+  /**
+   * Build the sweep collage (JPEG buffer) from the first items' images
+   * @returns {Promise<Buffer|null>} Null when the image fee isn't paid or no image could be fetched
+   */
+  async prepareSweepCollage(token, events) {
+    const hasImageFee = this.secureTrending ? await this.secureTrending.isImageFeeActive(token.contract_address) : false;
+    if (!hasImageFee) {
+      return null;
     }
 
-    const priceInToken = parseFloat(priceWei) / Math.pow(10, decimals);
+    const thumbnails = [];
+    for (const event of events.slice(0, SWEEP_COLLAGE_ITEMS)) {
+      try {
+        const imageUrl = await this.resolveEventImageUrl(event);
+        if (imageUrl) {
+          thumbnails.push(await this.fetchThumbnail(imageUrl));
+        }
+      } catch (error) {
+        logger.warn(`Could not fetch sweep thumbnail for ${event.tokenId}: ${error.message}`);
+      }
+    }
 
-    if (priceInToken >= 1) {
-      return `${priceInToken.toFixed(3)} ${tokenSymbol}`;
-    } else if (priceInToken >= 0.001) {
-      return `${priceInToken.toFixed(4)} ${tokenSymbol}`;
-    } else {
-      return `${(priceInToken * 1000).toFixed(2)} m${tokenSymbol}`;
+    if (thumbnails.length === 0) {
+      return null;
     }
-  }
 
-  formatUsdAmount(usdValue) {
-    const value = parseFloat(usdValue);
-    if (value >= 1000) {
-      return `${(value / 1000).toFixed(1)}K`;
-    } else if (value >= 1) {
-      return value.toFixed(2);
-    } else {
-      return value.toFixed(4);
+    try {
+      return await this.buildSweepCollage(thumbnails, events.length);
+    } catch (error) {
+      logger.error(`Could not build sweep collage for ${token.token_name}:`, error.message);
+      return null;
     }
   }
 
-  async sendOpenSeaNotificationWithImage(chatId, message, eventData) {
-    // SECURITY: Check if image fee is paid for this contract before showing actual NFT image
-    const hasImageFee = this.secureTrending ? await this.secureTrending.isImageFeeActive(eventData.contractAddress) : false;
-    logger.info(`🖼️ OPENSEA IMAGE FEE CHECK: ${eventData.contractAddress} - hasImageFee: ${hasImageFee}`);
-
-    try {
-      let processedImagePath = null;
+  async fetchThumbnail(imageUrl, size = 300) {
+    const axios = require('axios');
+    const sharp = require('sharp');
 
-      // Handle image processing based on payment status
-      if (hasImageFee && eventData.nftImageUrl && eventData.nftImageUrl.startsWith('http')) {
-        // For PAID tokens: Retry until successful, never fallback to default
-        logger.info(`✅ IMAGE FEE PAID: Processing OpenSea image for ${eventData.contractAddress} - WILL RETRY UNTIL SUCCESS`);
-        processedImagePath = await this.retryOpenSeaImageProcessingForPaidToken(eventData.nftImageUrl, eventData.tokenId || 'unknown', eventData.contractAddress);
-      } else {
-        // For UNPAID tokens: Use default tracking image immediately
-        logger.info(`🚫 IMAGE FEE NOT PAID: Using default tracking image for OpenSea notification`);
-        processedImagePath = await this.resizeDefaultTrackingImage();
+    const response = await axios.get(imageUrl, {
+      responseType: 'arraybuffer',
+      timeout: 10000,
+      headers: {
+        'User-Agent': 'Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36'
       }
+    });
+    return sharp(response.data).resize(size, size, { fit: 'cover', position: 'center' }).jpeg({ quality: 85 }).toBuffer();
+  }
 
-      const boostButton = {
-        inline_keyboard: [[
-          {
-            text: 'BOOST YOUR NFT🟢',
-            callback_data: '/buy_trending'
-          }
-        ]]
-      };
-
-      // ALWAYS send as photo - image processing is guaranteed to succeed
-      await this.bot.telegram.sendPhoto(chatId, { source: processedImagePath }, {
-        caption: message,
-        parse_mode: 'Markdown',
-        reply_markup: boostButton
-      });
-      logger.info(`✅ OpenSea notification with image sent successfully to ${chatId}`);
-
-      // Clean up the temporary file after sending (only for paid tokens with NFT metadata images)
-      if (hasImageFee && processedImagePath) {
-        this.cleanupTempImage(processedImagePath);
+  /**
+   * Lay thumbnails out on a grid (up to 3x3, 600px wide). When the sweep has more items
+   * than tiles, the last tile is dimmed and shows how many are left out.
+   * @param {Array<Buffer>} thumbnails - Square item images
+   * @param {number} itemCount - Items in the sweep
+   * @returns {Promise<Buffer>} JPEG
+   */
+  async buildSweepCollage(thumbnails, itemCount) {
+    const sharp = require('sharp');
+    const columns = thumbnails.length === 1 ? 1 : thumbnails.length <= 4 ? 2 : 3;
+    const rows = Math.ceil(thumbnails.length / columns);
+    const tileSize = Math.floor(600 / columns);
+    const gap = 4;
+
+    const composites = [];
+    for (let index = 0; index < thumbnails.length; index++) {
+      const left = (index % columns) * tileSize;
+      const top = Math.floor(index / columns) * tileSize;
+      const tile = await sharp(thumbnails[index]).resize(tileSize - gap, tileSize - gap, { fit: 'cover' }).toBuffer();
+      composites.push({ input: tile, left: left + gap / 2, top: top + gap / 2 });
+
+      // The dimmed tile counts as hidden too
+      const hiddenCount = itemCount - thumbnails.length + 1;
+      if (index === thumbnails.length - 1 && itemCount > thumbnails.length) {
+        const overlay = Buffer.from(
+          `<svg width="${tileSize - gap}" height="${tileSize - gap}">` +
+          `<rect width="100%" height="100%" fill="black" fill-opacity="0.6"/>` +
+          `<text x="50%" y="50%" font-family="sans-serif" font-size="${Math.floor(tileSize / 4)}" font-weight="bold" ` +
+          `fill="white" text-anchor="middle" dominant-baseline="central">+${hiddenCount}</text></svg>`
+        );
+        composites.push({ input: overlay, left: left + gap / 2, top: top + gap / 2 });
       }
+    }
 
-    } catch (error) {
-      // For PAID tokens: This should never happen due to retry logic
-      if (hasImageFee) {
-        logger.error(`🚨 CRITICAL: Paid OpenSea token ${eventData.contractAddress} failed image processing after retries: ${error.message}`);
-        throw new Error(`Paid OpenSea token image processing failed: ${error.message}`);
-      }
+    return sharp({
+      create: { width: columns * tileSize, height: rows * tileSize, channels: 3, background: '#111111' }
+    })
+      .composite(composites)
+      .jpeg({ quality: 85, progressive: true })
+      .toBuffer();
+  }
 
-      // For UNPAID tokens: Emergency fallback to default image
-      logger.warn(`Unpaid OpenSea token ${eventData.contractAddress} failed, emergency fallback to default image: ${error.message}`);
+  // OpenSea Event Handling Methods
+  async handleOpenSeaEvent(eventType, eventData, rawEvent) {
+    try {
+      logger.info(`🌊 OPENSEA EVENT PROCESSING - ${eventType} for collection: ${eventData.collectionSlug}`);
 
-      try {
-        const emergencyImagePath = await this.resizeDefaultTrackingImage();
-        const boostButton = {
-          inline_keyboard: [[
-            {
-              text: 'BOOST YOUR NFT🟢',
-              callback_data: '/buy_trending'
-            }
-          ]]
-        };
+      // Create unique key for deduplication
+      const eventKey = this.createOpenSeaEventKey(eventType, eventData);
 
-        await this.bot.telegram.sendPhoto(chatId, { source: emergencyImagePath }, {
-          caption: message,
-          parse_mode: 'Markdown',
-          reply_markup: boostButton
-        });
-        logger.info(`Sent OpenSea notification with emergency default image for unpaid token ${eventData.contractAddress}`);
-      } catch (emergencyError) {
-        logger.error(`🚨 CRITICAL: Even emergency default image failed for OpenSea ${eventData.contractAddress}: ${emergencyError.message}`);
-        throw new Error(`Complete OpenSea image processing failure: ${emergencyError.message}`);
+      if (!await this.dedup.claim('opensea', eventKey)) {
+        logger.info(`   ⏭️ Event ${eventKey} already processed, skipping`);
+        return false;
       }
-    }
-  }
 
-  // Retry OpenSea image processing for paid tokens - keeps trying until success
-  async retryOpenSeaImageProcessingForPaidToken(imageUrl, tokenId, contractAddress, maxRetries = 10) {
-    let attempt = 1;
+      // CRITICAL: Check if we have tracked tokens for this collection
+      const tokens = await this.db.getTokensForCollectionSlug(eventData.collectionSlug);
+      logger.info(`   📊 Collection ${eventData.collectionSlug} analysis:`);
+      logger.info(`      - Tokens returned by query: ${tokens?.length || 0}`);
 
-    // Add a unique processing key to prevent duplicate retries for the same notification
-    const processingKey = `opensea:${contractAddress}:${tokenId}:${Date.now()}`;
-    logger.info(`🔒 STARTING PAID OPENSEA PROCESSING: ${processingKey}`);
+      if (!tokens || tokens.length === 0) {
+        logger.info(`   🛑 STOPPING EVENT PROCESSING - No tracked tokens found for collection: ${eventData.collectionSlug}`);
+        logger.info(`      - This collection should not receive events anymore`);
+        return false;
+      }
 
-    while (attempt <= maxRetries) {
-      try {
-        logger.info(`🔄 PAID OPENSEA RETRY ${attempt}/${maxRetries}: Processing image for ${contractAddress} (${processingKey})`);
+      // Log details about returned tokens
+      logger.info(`      - Active tokens in collection:`, tokens.map(t => ({
+        contract: t.contract_address,
+        name: t.token_name,
+        is_active: t.is_active,
+        has_subscriptions: 'checking...'
+      })));
 
-        const processedImagePath = await this.downloadAndResizeOpenSeaImage(imageUrl, tokenId);
-        if (processedImagePath) {
-          logger.info(`✅ PAID OPENSEA SUCCESS: Image processed on attempt ${attempt} for ${contractAddress} (${processingKey})`);
-          return processedImagePath;
+      // Additional safety check: verify at least one token has active subscriptions
+      let hasAnyActiveSubscriptions = false;
+      for (const token of tokens) {
+        if (typeof this.db.hasAnyActiveSubscriptions === 'function') {
+          const tokenHasSubscriptions = await this.db.hasAnyActiveSubscriptions(token.id);
+          if (tokenHasSubscriptions) {
+            hasAnyActiveSubscriptions = true;
+            logger.info(`      - Token ${token.contract_address} has active subscriptions: YES`);
+          } else {
+            logger.info(`      - Token ${token.contract_address} has active subscriptions: NO`);
+          }
+        } else {
+          logger.info(`      - Token ${token.contract_address} subscription check skipped (method not available)`);
         }
+      }
 
-        logger.warn(`⚠️ PAID OPENSEA ATTEMPT ${attempt} FAILED: Retrying in ${attempt * 2} seconds for ${contractAddress} (${processingKey})`);
-        await new Promise(resolve => setTimeout(resolve, attempt * 2000)); // Exponential backoff
-        attempt++;
+      if (!hasAnyActiveSubscriptions) {
+        logger.info(`   🛑 STOPPING EVENT PROCESSING - Collection ${eventData.collectionSlug} has no tokens with active subscriptions`);
+        logger.info(`      - This indicates a database inconsistency or recent unsubscription`);
+        return false;
+      }
 
-      } catch (error) {
-        logger.error(`❌ PAID OPENSEA ATTEMPT ${attempt} ERROR for ${contractAddress} (${processingKey}): ${error.message}`);
+      logger.info(`   ✅ Proceeding with event processing for ${tokens.length} tokens`);
 
-        if (attempt === maxRetries) {
-          logger.error(`🚨 PAID OPENSEA FINAL FAILURE: All ${maxRetries} attempts failed for ${contractAddress} (${processingKey})`);
-          throw new Error(`Paid OpenSea token image processing failed after ${maxRetries} attempts: ${error.message}`);
+      // Process each tracked token in this collection
+      let processedCount = 0;
+      for (const token of tokens) {
+        try {
+          await this.processOpenSeaEventForToken(eventType, eventData, token, rawEvent);
+          processedCount++;
+        } catch (error) {
+          logger.error(`Error processing OpenSea event for token ${token.contract_address}:`, error);
         }
-
-        await new Promise(resolve => setTimeout(resolve, attempt * 2000));
-        attempt++;
       }
-    }
 
-    throw new Error(`Paid OpenSea token image processing exhausted all ${maxRetries} attempts`);
+      logger.info(`Processed OpenSea ${eventType} event for ${processedCount} tokens`);
+      return processedCount > 0;
+    } catch (error) {
+      logger.error(`Error handling OpenSea ${eventType} event:`, error);
+      return false;
+    }
   }
 
-  /**
-   * Download and resize an OpenSea image to 300x300
-   * @param {string} imageUrl - The OpenSea image URL
-   * @param {string} tokenId - Token ID for filename uniqueness
-   * @returns {string|null} Path to resized image file or null if failed
-   */
-  async downloadAndResizeOpenSeaImage(imageUrl, tokenId) {
-    const axios = require('axios');
-    const sharp = require('sharp');
-    const fs = require('fs').promises;
-    const path = require('path');
-
+  async processOpenSeaEventForToken(eventType, eventData, token, rawEvent) {
     try {
-      // Create temp directory if it doesn't exist
-      const tempDir = path.join(__dirname, '../../temp_opensea_images');
-      await fs.mkdir(tempDir, { recursive: true });
+      // SAFEGUARD: Verify token still exists in database before processing
+      const tokenExists = await this.db.get('SELECT id FROM tracked_tokens WHERE id = $1 AND is_active = true', [token.id]);
+      if (!tokenExists) {
+        logger.warn(`🚫 Skipping OpenSea event processing - token ${token.contract_address} no longer exists in database`);
+        return;
+      }
 
-      // Generate unique filename
-      const fileName = `opensea_${tokenId}_${Date.now()}.jpg`;
-      const tempPath = path.join(tempDir, fileName);
-      const resizedPath = path.join(tempDir, `resized_${fileName}`);
+      // SAFEGUARD: Check if any users exist in database at all
+      const userCount = await this.db.get('SELECT COUNT(*) as count FROM users WHERE is_active = true');
+      if (!userCount || userCount.count === 0) {
+        logger.warn(`🚫 Skipping OpenSea event processing - no active users in database`);
+        return;
+      }
 
-      // Download the image
-      logger.info(`📥 Downloading OpenSea image: ${imageUrl}`);
-      const response = await axios.get(imageUrl, {
-        responseType: 'arraybuffer',
-        timeout: 10000,
-        headers: {
-          'User-Agent': 'Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36'
-        }
-      });
+      // OPTIMIZATION: Skip processing if token has no active subscriptions and no premium features
+      const hasActiveSubscriptions = typeof this.db.hasAnyActiveSubscriptions === 'function'
+        ? await this.db.hasAnyActiveSubscriptions(token.id)
+        : false;
+      const hasActivePremiumFeatures = await this.db.hasActivePremiumFeatures(token.contract_address);
+
+      if (!hasActiveSubscriptions && !hasActivePremiumFeatures) {
+        logger.debug(`⏭️ Skipping OpenSea event processing - token ${token.contract_address} has no active subscriptions or premium features`);
+        return;
+      }
+
+      const event = fromOpenSeaEvent(eventType, eventData, token);
+      if (!event) {
+        logger.debug(`Ignoring OpenSea ${eventType} event for token ${token.contract_address}`);
+        return;
+      }
+
+      await this.routeActivityEvent(token, event);
+
+      logger.info(`Successfully processed OpenSea ${eventType} event for token ${token.contract_address}`);
+    } catch (error) {
+      logger.error(`Error processing OpenSea event for token ${token.contract_address}:`, error);
+      throw error;
+    }
+  }
+
+  createOpenSeaEventKey(eventType, eventData) {
+    // Create unique key for deduplication
+    const contractAddress = eventData.contractAddress || 'unknown';
+    const tokenId = eventData.tokenId || 'unknown';
+    const txHash = eventData.transactionHash || eventData.orderHash || 'unknown';
+    const timestamp = eventData.sentAt || new Date().toISOString();
+
+    return `opensea:${eventType}:${contractAddress}:${tokenId}:${txHash}:${timestamp}`;
+  }
+
+  // Setup OpenSea event handlers for a collection
+  async setupOpenSeaHandlers(collectionSlug) {
+    try {
+      if (!this.openSea) {
+        logger.warn('OpenSea service not available');
+        return null;
+      }
+
+      const eventHandlers = {
+        listed: (eventData, rawEvent) => this.handleOpenSeaEvent('listed', eventData, rawEvent),
+        sold: (eventData, rawEvent) => this.handleOpenSeaEvent('sold', eventData, rawEvent),
+        cancelled: (eventData, rawEvent) => this.handleOpenSeaEvent('cancelled', eventData, rawEvent),
+        received_bid: (eventData, rawEvent) => this.handleOpenSeaEvent('received_bid', eventData, rawEvent),
+        received_offer: (eventData, rawEvent) => this.handleOpenSeaEvent('received_offer', eventData, rawEvent),
+        default: (eventType, eventData, rawEvent) => this.handleOpenSeaEvent(eventType, eventData, rawEvent)
+      };
+
+      const subscription = await this.openSea.subscribeToCollection(collectionSlug, eventHandlers);
+      logger.info(`Set up OpenSea event handlers for collection: ${collectionSlug}`);
+      return subscription;
+    } catch (error) {
+      logger.error(`Failed to setup OpenSea handlers for collection ${collectionSlug}:`, error);
+      throw error;
+    }
+  }
+
+  /**
+   * Download and resize an item image to 300x300
+   * @param {string} imageUrl - Item image URL
+   * @param {string} tokenId - Token ID for filename uniqueness
+   * @returns {string|null} Path to resized image file or null if failed
+   */
+  async downloadAndResizeImage(imageUrl, tokenId) {
+    const axios = require('axios');
+    const sharp = require('sharp');
+    const fs = require('fs').promises;
+    const path = require('path');
+
+    try {
+      // Create temp directory if it doesn't exist
+      const tempDir = path.join(__dirname, '../../temp_opensea_images');
+      await fs.mkdir(tempDir, { recursive: true });
+
+      // Generate unique filename
+      const fileName = `nft_${tokenId}_${Date.now()}.jpg`;
+      const tempPath = path.join(tempDir, fileName);
+      const resizedPath = path.join(tempDir, `resized_${fileName}`);
+
+      // Download the image
+      logger.info(`📥 Downloading NFT image: ${imageUrl}`);
+      const response = await axios.get(imageUrl, {
+        responseType: 'arraybuffer',
+        timeout: 10000,
+        headers: {
+          'User-Agent': 'Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36'
+        }
+      });
 
       // Save downloaded image
       await fs.writeFile(tempPath, response.data);
@@ -1803,7 +2450,7 @@ class WebhookHandlers {
       return resizedPath;
 
     } catch (error) {
-      logger.error(`❌ Failed to download/resize OpenSea image: ${error.message}`);
+      logger.error(`❌ Failed to download/resize NFT image: ${error.message}`);
       return null;
     }
   }
@@ -1848,1020 +2495,55 @@ class WebhookHandlers {
         await sharp('./src/images/candyImage.jpg')
           .resize(300, 300, {
             fit: 'cover',
-            position: 'center'
-          })
-          .jpeg({
-            quality: 85,
-            progressive: true
-          })
-          .toFile(resizedPath);
-
-        logger.debug(`📸 Default tracking image resized to 300x300: ${resizedPath}`);
-        return resizedPath;
-      }
-    } catch (error) {
-      logger.error(`❌ Failed to get default tracking image: ${error.message}`);
-      return null;
-    }
-  }
-
-  /**
-   * Clean up temporary image file
-   * @param {string} imagePath - Path to image file to delete
-   */
-  async cleanupTempImage(imagePath) {
-    try {
-      const fs = require('fs').promises;
-      await fs.unlink(imagePath);
-      logger.debug(`🗑️ Cleaned up temp image: ${imagePath}`);
-    } catch (error) {
-      logger.debug(`⚠️ Failed to cleanup temp image: ${error.message}`);
-    }
-  }
-
-  // ==================== HELIUS WEBHOOK HANDLERS (SOLANA / MAGIC EDEN) ====================
-
-  /**
-   * Handle Helius webhook for Solana NFT sales on Magic Eden
-   * @param {Object} req - Express request object
-   * @param {Object} res - Express response object
-   */
-  async handleHeliusWebhook(req, res) {
-    try {
-      // Verify auth header
-      const authHeader = req.headers.authorization;
-      if (!this.helius || !this.helius.verifyWebhookAuth(authHeader)) {
-        logger.warn('⚠️ Unauthorized Helius webhook request');
-        return res.status(401).json({ error: 'Unauthorized' });
-      }
-
-      const transactions = req.body;
-      logger.info(`🌟 Received Helius webhook with ${transactions.length} transactions`);
-
-      await this.db.logWebhook('helius', transactions, false);
-      let processedCount = 0;
-
-      for (const transaction of transactions) {
-        try {
-          if (transaction.type === 'NFT_SALE') {
-            logger.info(`💰 Processing Helius NFT_SALE event: ${transaction.signature}`);
-            const processed = await this.handleHeliusNFTSale(transaction);
-            if (processed) processedCount++;
-          }
-        } catch (error) {
-          logger.error(`Error processing Helius transaction ${transaction.signature}:`, error);
-        }
-      }
-
-      await this.db.logWebhook('helius', transactions, processedCount > 0);
-      logger.info(`✅ Helius webhook processed: ${processedCount}/${transactions.length} events`);
-
-      res.status(200).json({
-        success: true,
-        processed: processedCount,
-        message: 'Webhook processed successfully'
-      });
-    } catch (error) {
-      logger.error('Error handling Helius webhook:', error);
-      if (req.body) {
-        await this.db.logWebhook('helius', req.body, false, error.message);
-      }
-      res.status(500).json({
-        success: false,
-        error: 'Internal server error'
-      });
-    }
-  }
-
-  /**
-   * Handle a single NFT sale event from Helius
-   * @param {Object} transaction - The transaction object from Helius
-   * @returns {Promise<boolean>} True if processed successfully
-   */
-  async handleHeliusNFTSale(transaction) {
-    try {
-      if (!this.helius) {
-        logger.warn('Helius service not available');
-        return false;
-      }
-
-      // Parse the NFT sale event
-      const saleData = this.helius.parseNFTSaleEvent(transaction);
-      if (!saleData) {
-        logger.warn('Failed to parse Helius NFT sale event');
-        return false;
-      }
-
-      // Create deduplication key
-      const eventKey = `helius:${saleData.signature}:${saleData.mintAddress}`;
-      if (this.isHeliusEventProcessed(eventKey)) {
-        logger.info(`⏭️ Helius event ${eventKey} already processed, skipping`);
-        return false;
-      }
-
-      // Mark as being processed
-      this.markHeliusEventProcessed(eventKey);
-
-      logger.info(`🌟 HELIUS NFT SALE - Mint: ${saleData.mintAddress}, Price: ${saleData.amountSol} SOL`);
-
-      // For Solana NFTs, we need to find the collection symbol from the mint
-      // First, try to get collection info from Magic Eden
-      let collectionSymbol = null;
-      if (this.magicEden) {
-        try {
-          const nftInfo = await this.magicEden.getNFTMetadata(saleData.mintAddress);
-          collectionSymbol = nftInfo?.collection || nftInfo?.collectionSymbol;
-          if (collectionSymbol) {
-            logger.info(`📦 Found collection: ${collectionSymbol} for mint ${saleData.mintAddress}`);
-          }
-        } catch (error) {
-          logger.debug(`Could not fetch collection for mint ${saleData.mintAddress}: ${error.message}`);
-        }
-      }
-
-      // Find tracked tokens by collection symbol for Solana
-      let token = null;
-      if (collectionSymbol) {
-        // Query by collection_slug for Solana tokens
-        const tokens = await this.db.all(
-          'SELECT * FROM tracked_tokens WHERE chain_name = $1 AND collection_slug = $2 AND is_active = true',
-          ['solana', collectionSymbol]
-        );
-        if (tokens && tokens.length > 0) {
-          token = tokens[0];
-          logger.info(`📊 Found tracked Solana collection: ${token.token_name} (${collectionSymbol})`);
-        }
-      }
-
-      if (!token) {
-        logger.debug(`Collection ${collectionSymbol || 'unknown'} for mint ${saleData.mintAddress} not tracked, skipping`);
-        return false;
-      }
-
-      // Log activity to database
-      const activityData = {
-        contractAddress: saleData.mintAddress,
-        tokenId: null, // Solana uses mint addresses
-        activityType: 'buy',
-        fromAddress: saleData.seller,
-        toAddress: saleData.buyer,
-        transactionHash: saleData.signature,
-        blockNumber: saleData.slot,
-        price: saleData.amount.toString(), // Store lamports
-        marketplace: 'Magic Eden'
-      };
-
-      await this.db.logNFTActivity(activityData);
-
-      // Notify subscribed users
-      await this.notifyUsersMagicEden(token, saleData, activityData);
-
-      // Check if token should notify channels
-      const shouldNotifyChannels = await this.shouldNotifyChannelsForToken(token.contract_address);
-      if (shouldNotifyChannels.notify) {
-        logger.info(`📢 Notifying channels for ${token.token_name} via Magic Eden sale (${shouldNotifyChannels.reason})`);
-        await this.notifyChannelsMagicEden(token, saleData, activityData, shouldNotifyChannels.channels, shouldNotifyChannels.isTrending);
-      }
-
-      logger.info(`✅ Successfully processed Helius NFT sale for ${token.token_name}`);
-      return true;
-
-    } catch (error) {
-      logger.error('Error handling Helius NFT sale:', error);
-      return false;
-    }
-  }
-
-  /**
-   * Check if Helius event has been processed
-   * @param {string} eventKey - Unique event key
-   * @returns {boolean} True if already processed
-   */
-  isHeliusEventProcessed(eventKey) {
-    const timestamp = this.processedHeliusEvents.get(eventKey);
-    if (!timestamp) return false;
-
-    const isValid = (Date.now() - timestamp) <= this.CACHE_EXPIRY_MS;
-    if (!isValid) {
-      this.processedHeliusEvents.delete(eventKey);
-      return false;
-    }
-    return true;
-  }
-
-  /**
-   * Mark Helius event as processed
-   * @param {string} eventKey - Unique event key
-   */
-  markHeliusEventProcessed(eventKey) {
-    this.processedHeliusEvents.set(eventKey, Date.now());
-  }
-
-  /**
-   * Notify users about Magic Eden sale
-   * @param {Object} token - Token data from database
-   * @param {Object} saleData - Parsed sale data from Helius
-   * @param {Object} activityData - Activity data for database
-   * @returns {Promise<boolean>} True if notifications sent successfully
-   */
-  async notifyUsersMagicEden(token, saleData, activityData) {
-    try {
-      // Get users with their subscription context (chat_id)
-      const subscriptions = await this.db.all(`
-        SELECT u.telegram_id, u.username, us.notification_enabled, us.chat_id
-        FROM users u
-        JOIN user_subscriptions us ON u.id = us.user_id
-        WHERE us.token_id = $1 AND us.notification_enabled = true AND u.is_active = true
-      `, [token.id]);
-
-      if (!subscriptions || subscriptions.length === 0) {
-        logger.debug(`No users subscribed to Solana token: ${token.contract_address}`);
-        return false;
-      }
-
-      const message = await this.formatMagicEdenSaleMessage(token, saleData);
-      logger.info(`📤 Sending Magic Eden sale notification to ${subscriptions.length} subscription(s) for ${token.token_name}`);
-
-      let successCount = 0;
-
-      for (const subscription of subscriptions) {
-        try {
-          let targetChatId;
-          if (subscription.chat_id === 'private') {
-            targetChatId = subscription.telegram_id;
-          } else {
-            targetChatId = subscription.chat_id;
-          }
-
-          await this.sendMagicEdenNotificationWithImage(targetChatId, message, saleData, token);
-          successCount++;
-          logger.info(`✅ Magic Eden notification sent to ${subscription.chat_id === 'private' ? 'private chat' : 'group'} ${targetChatId}`);
-        } catch (error) {
-          logger.error(`❌ Failed to send Magic Eden notification to ${subscription.chat_id}:`, error);
-
-          if (error.response?.error_code === 403 || error.response?.error_code === 400) {
-            await this.db.run(
-              'UPDATE users SET is_active = false WHERE telegram_id = $1',
-              [subscription.telegram_id]
-            );
-            logger.info(`Deactivated user ${subscription.telegram_id} due to delivery failure`);
-          }
-        }
-      }
-
-      logger.info(`📊 Magic Eden notification summary: ${successCount}/${subscriptions.length} notifications sent`);
-      return successCount > 0;
-    } catch (error) {
-      logger.error('Error notifying users for Magic Eden sale:', error);
-      return false;
-    }
-  }
-
-  /**
-   * Notify channels about Magic Eden sale
-   * @param {Object} token - Token data
-   * @param {Object} saleData - Sale data
-   * @param {Object} activityData - Activity data
-   * @param {Array} channels - Channels to notify
-   * @param {boolean} isTrending - Whether token is trending
-   * @returns {Promise<boolean>} True if notifications sent
-   */
-  async notifyChannelsMagicEden(token, saleData, activityData, channels, isTrending = false) {
-    try {
-      // Authorization already performed by shouldNotifyChannelsForToken() before calling this method
-
-      if (!channels || channels.length === 0) {
-        return false;
-      }
-
-      const message = isTrending
-        ? await this.formatTrendingMagicEdenMessage(token, saleData)
-        : await this.formatMagicEdenSaleMessage(token, saleData);
-
-      let notifiedCount = 0;
-      for (const channel of channels) {
-        try {
-          logger.info(`📤 SENDING Magic Eden to channel ${channel.channel_title}: ${token.token_name}`);
-          await this.sendMagicEdenNotificationWithImage(channel.telegram_chat_id, message, saleData, token);
-          notifiedCount++;
-          logger.info(`✅ Magic Eden notification sent to channel: ${channel.channel_title}`);
-        } catch (error) {
-          logger.error(`❌ Failed to send Magic Eden notification to channel ${channel.channel_title}:`, error);
-
-          if (error.response?.error_code === 403) {
-            await this.db.run(
-              'UPDATE channels SET is_active = false WHERE telegram_chat_id = $1',
-              [channel.telegram_chat_id]
-            );
-          }
-        }
-      }
-
-      logger.info(`📢 Notified ${notifiedCount}/${channels.length} channels for Magic Eden sale`);
-      return notifiedCount > 0;
-    } catch (error) {
-      logger.error('Error notifying channels for Magic Eden sale:', error);
-      return false;
-    }
-  }
-
-  /**
-   * Format Magic Eden sale message for Telegram
-   * @param {Object} token - Token data
-   * @param {Object} saleData - Sale data from Helius
-   * @returns {Promise<string>} Formatted message
-   */
-  async formatMagicEdenSaleMessage(token, saleData) {
-    const nftName = token.token_name || 'Solana NFT';
-
-    let message = `💰🟢 **${nftName}** **BUY!**\n\n`;
-    message += `💰 **Price:** ${saleData.amountSol} SOL`;
-
-    // Add USD value if PriceService is available
-    if (this.secureTrending && this.secureTrending.priceService) {
-      try {
-        const solPrice = await this.secureTrending.priceService.getTokenPrice('SOL');
-        if (solPrice) {
-          const usdValue = parseFloat(saleData.amountSol) * solPrice;
-          message += ` ($${usdValue.toFixed(2)})`;
-        }
-      } catch (error) {
-        logger.warn('Failed to get SOL price for USD conversion:', error);
-      }
-    }
-
-    message += '\n';
-
-    // Add NFT Mint address as clickable link
-    if (saleData.mintAddress) {
-      const shortMint = this.shortenAddress(saleData.mintAddress);
-      message += `🖼️ **NFT:** [${shortMint}](https://magiceden.io/item-details/${saleData.mintAddress})\n`;
-    }
-
-    message += `👤 **Buyer:** \`${this.shortenAddress(saleData.buyer)}\`\n`;
-    message += `📤 **Seller:** \`${this.shortenAddress(saleData.seller)}\`\n`;
-    message += `🏪 **Marketplace:** Magic Eden\n`;
-    message += `🔗 **Chain:** ◎ Solana\n`;
-
-    // Add Magic Eden link
-    if (saleData.mintAddress) {
-      message += `[View on Magic Eden](https://magiceden.io/item-details/${saleData.mintAddress})\n`;
-    }
-    message += `[View on Solana Explorer](https://explorer.solana.com/tx/${saleData.signature})\n`;
-
-    message += ` \nPowered by [Candy Codex](https://mint.candycodex.com/)`;
-
-    // Add footer advertisements
-    if (this.secureTrending) {
-      try {
-        const footerAds = await this.secureTrending.getActiveFooterAds();
-        if (footerAds && footerAds.length > 0) {
-          const adLinks = footerAds.map(ad => {
-            const ticker = ad.ticker_symbol || ad.token_symbol || 'TOKEN';
-            return `[⭐️${ticker}](${ad.custom_link})`;
-          });
-
-          if (adLinks.length < 3) {
-            adLinks.push('[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)');
-          }
-
-          message += `\n${adLinks.join(' ')}`;
-        } else {
-          message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-        }
-      } catch (error) {
-        message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-      }
-    } else {
-      message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-    }
-
-    return message;
-  }
-
-  /**
-   * Format trending Magic Eden sale message
-   * @param {Object} token - Token data
-   * @param {Object} saleData - Sale data
-   * @returns {Promise<string>} Formatted message
-   */
-  async formatTrendingMagicEdenMessage(token, saleData) {
-    let message = `🔥 **TRENDING:** ${token.token_name || 'Solana NFT'}\n\n`;
-    const regularMessage = await this.formatMagicEdenSaleMessage(token, saleData);
-    const lines = regularMessage.split('\n');
-    message += lines.slice(1).join('\n');
-    return message;
-  }
-
-  /**
-   * Send Magic Eden notification with image
-   * @param {string} chatId - Telegram chat ID
-   * @param {string} message - Message text
-   * @param {Object} saleData - Sale data
-   * @param {Object} token - Token data
-   */
-  async sendMagicEdenNotificationWithImage(chatId, message, saleData, token) {
-    // Check if image fee is paid for this contract
-    const hasImageFee = this.secureTrending ? await this.secureTrending.isImageFeeActive(token.contract_address) : false;
-    logger.info(`🖼️ MAGIC EDEN IMAGE FEE CHECK: ${token.contract_address} - hasImageFee: ${hasImageFee}`);
-
-    const boostButton = {
-      inline_keyboard: [[
-        {
-          text: 'BOOST YOUR NFT🟢',
-          callback_data: '/buy_trending'
-        }
-      ]]
-    };
-
-    try {
-      let imagePath = null;
-
-      // Only fetch actual NFT image if fee is PAID
-      if (hasImageFee && saleData.nfts && saleData.nfts[0]?.imageUri) {
-        logger.info(`✅ IMAGE FEE PAID: Processing Magic Eden NFT image`);
-        try {
-          imagePath = await this.downloadAndResizeSolanaImage(saleData.nfts[0].imageUri, saleData.mintAddress);
-        } catch (error) {
-          logger.warn(`Failed to process Solana NFT image: ${error.message}`);
-          imagePath = null;
-        }
-      } else if (!hasImageFee) {
-        logger.info(`🚫 IMAGE FEE NOT PAID: Using default tracking image for Magic Eden NFT`);
-      }
-
-      // Always use default tracking image if no paid image or download failed
-      if (!imagePath) {
-        const path = require('path');
-        imagePath = path.join(__dirname, '../images/candyImage.jpg');
-        logger.info(`📷 Using default tracking image: ${imagePath}`);
-      }
-
-      // ALWAYS send as photo (like EVM chains)
-      await this.bot.telegram.sendPhoto(chatId, { source: imagePath }, {
-        caption: message,
-        parse_mode: 'Markdown',
-        reply_markup: boostButton
-      });
-
-      logger.info(`✅ Magic Eden notification with image sent successfully to ${chatId}`);
-
-    } catch (error) {
-      logger.error(`Error sending Magic Eden notification with image:`, error);
-      throw error;
-    }
-  }
-
-  /**
-   * Download and resize Solana NFT image
-   * @param {string} imageUrl - NFT image URL
-   * @param {string} mintAddress - Solana mint address
-   * @returns {Promise<string>} Path to resized image
-   */
-  async downloadAndResizeSolanaImage(imageUrl, mintAddress) {
-    const axios = require('axios');
-    const sharp = require('sharp');
-    const fs = require('fs').promises;
-    const path = require('path');
-
-    try {
-      const tempDir = path.join(__dirname, '../../temp_solana_images');
-      await fs.mkdir(tempDir, { recursive: true });
-
-      const fileName = `solana_${mintAddress.slice(0, 8)}_${Date.now()}.jpg`;
-      const tempPath = path.join(tempDir, fileName);
-      const resizedPath = path.join(tempDir, `resized_${fileName}`);
-
-      // Download the image
-      logger.info(`📥 Downloading Solana NFT image: ${imageUrl}`);
-      const response = await axios.get(imageUrl, {
-        responseType: 'arraybuffer',
-        timeout: 10000,
-        headers: {
-          'User-Agent': 'Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36'
-        }
-      });
-
-      await fs.writeFile(tempPath, response.data);
-      logger.info(`💾 Downloaded Solana image saved: ${tempPath}`);
-
-      // Resize to 300x300
-      await sharp(tempPath)
-        .resize(300, 300, {
-          fit: 'cover',
-          position: 'center'
-        })
-        .jpeg({
-          quality: 85,
-          progressive: true
-        })
-        .toFile(resizedPath);
-
-      // Clean up original
-      await fs.unlink(tempPath).catch(() => {});
-
-      logger.info(`🖼️ Solana image resized to 300x300: ${resizedPath}`);
-      return resizedPath;
-
-    } catch (error) {
-      logger.error(`❌ Failed to download/resize Solana image: ${error.message}`);
-      throw error;
-    }
-  }
-
-  // ==================== BITCOIN ORDINALS POLLING HANDLERS (MAGIC EDEN) ====================
-
-  /**
-   * Handle Bitcoin Ordinals activity from Magic Eden API poller
-   * @param {Object} eventData - Activity data from BitcoinOrdinalsPoller
-   * @returns {Promise<boolean>} True if processed successfully
-   */
-  async handleBitcoinOrdinalsActivity(eventData) {
-    try {
-      logger.info(`₿ Processing Bitcoin Ordinals activity: ${eventData.activityType} for ${eventData.collectionSymbol}`);
-
-      // Find tracked token by collection symbol
-      const token = await this.db.get(
-        'SELECT * FROM tracked_tokens WHERE collection_slug = $1 AND chain_name = $2 AND is_active = true',
-        [eventData.collectionSymbol, 'bitcoin']
-      );
-
-      if (!token) {
-        logger.debug(`Collection ${eventData.collectionSymbol} not tracked, skipping activity`);
-        return false;
-      }
-
-      // Get all subscriptions for this token
-      const subscriptions = await this.db.all(
-        `SELECT us.*, u.telegram_id
-         FROM user_subscriptions us
-         JOIN users u ON us.user_id = u.id
-         WHERE us.token_id = $1 AND us.notification_enabled = true`,
-        [token.id]
-      );
-
-      if (subscriptions.length === 0) {
-        logger.debug(`No active subscriptions for ${eventData.collectionName}, skipping`);
-        return false;
-      }
-
-      logger.info(`₿ Sending Bitcoin Ordinals notifications to ${subscriptions.length} subscriptions`);
-
-      // Fetch inscription metadata to get the specific inscription image
-      let inscriptionImage = null;
-      let inscriptionMetadata = null;
-      let useCollectionFallback = false;
-
-      try {
-        if (this.magicEdenOrdinals && eventData.inscriptionId) {
-          inscriptionMetadata = await this.magicEdenOrdinals.getInscriptionMetadata(eventData.inscriptionId);
-          if (inscriptionMetadata) {
-            logger.debug(`₿ Inscription metadata fields:`, Object.keys(inscriptionMetadata));
-
-            // Try multiple possible image fields from Magic Eden API
-            // Check for higher quality versions first
-            inscriptionImage = inscriptionMetadata.contentURI ||
-                             inscriptionMetadata.imageURI ||
-                             inscriptionMetadata.content_url ||
-                             inscriptionMetadata.image ||
-                             inscriptionMetadata.meta?.high_res_img_url ||
-                             inscriptionMetadata.chain?.high_res_image ||
-                             null;
-
-            if (inscriptionImage) {
-              logger.info(`₿ Found inscription image URL: ${inscriptionImage}`);
-
-              // If it's a Magic Eden CDN URL, try to get a larger size
-              if (inscriptionImage.includes('img-cdn.magiceden.dev') || inscriptionImage.includes('ord-mirror.magiceden.dev')) {
-                // Try to replace size parameters or add width parameter
-                if (!inscriptionImage.includes('?')) {
-                  inscriptionImage = `${inscriptionImage}?w=600`;
-                  logger.info(`₿ Added size parameter to ME CDN: ${inscriptionImage}`);
-                }
-              }
-            } else {
-              logger.warn(`₿ No image found in inscription metadata. Available fields: ${JSON.stringify(Object.keys(inscriptionMetadata))}`);
-              useCollectionFallback = true;
-            }
-          } else {
-            logger.warn(`₿ No inscription metadata returned for ${eventData.inscriptionId}`);
-            useCollectionFallback = true;
-          }
-        }
-      } catch (imageError) {
-        logger.warn(`₿ Could not fetch inscription metadata:`, imageError.message);
-        useCollectionFallback = true;
-      }
-
-      // Fallback to collection image if no inscription image found
-      if (!inscriptionImage && useCollectionFallback) {
-        try {
-          if (this.magicEdenOrdinals) {
-            const collectionData = await this.magicEdenOrdinals.validateCollectionSymbol(eventData.collectionSymbol);
-            if (collectionData && collectionData.image) {
-              inscriptionImage = collectionData.image;
-              logger.info(`₿ Using collection image as fallback: ${inscriptionImage}`);
-            }
-          }
-        } catch (fallbackError) {
-          logger.warn(`₿ Could not fetch collection image fallback:`, fallbackError.message);
-        }
-      }
-
-      // Build notification message in EVM format
-      const activityEmoji = this.getBitcoinActivityEmoji(eventData.activityType);
-      const activityAction = this.formatBitcoinActivityAction(eventData.activityType);
-
-      // Add green emoji for buys (buying_broadcasted)
-      const greenEmoji = eventData.activityType === 'buying_broadcasted' ? '🟢' : '';
-
-      let message = `${activityEmoji}${greenEmoji} **${eventData.collectionName}** ${activityAction}\n\n`;
-
-      // Price information FIRST (only for sales and listings) - matching EVM format
-      logger.debug(`₿ Price data - activityType: ${eventData.activityType}, price: ${eventData.price}, priceRaw: ${eventData.priceRaw}`);
-
-      if (eventData.price && eventData.activityType !== 'transfer') {
-        // Get BTC price in USD and format as "0.3 BTC $6,939"
-        const priceWithUSD = await this.formatBTCPriceWithUSD(eventData.price, eventData.priceRaw);
-        const priceLabel = eventData.activityType === 'buying_broadcasted' ? 'Price' : 'List Price';
-        message += `💰 **${priceLabel}:** ${priceWithUSD}\n`;
-      } else if (eventData.activityType !== 'transfer') {
-        logger.warn(`₿ No price data available for ${eventData.activityType} event. Price: ${eventData.price}, PriceRaw: ${eventData.priceRaw}`);
-      }
-
-      // Inscription details (equivalent to NFT details in EVM)
-      if (eventData.inscriptionId) {
-        // Show truncated inscription ID as a clickable link to Magic Eden
-        const truncatedId = eventData.inscriptionId.length > 16
-          ? `${eventData.inscriptionId.substring(0, 8)}...${eventData.inscriptionId.substring(eventData.inscriptionId.length - 4)}`
-          : eventData.inscriptionId;
-        message += `🖼️ **Inscription:** [${truncatedId}](https://magiceden.io/ordinals/item-details/${eventData.inscriptionId})\n`;
-      }
-
-      // Addresses (only show for transfers, like EVM)
-      if (eventData.activityType === 'transfer') {
-        if (eventData.seller) {
-          message += `📤 **From:** \`${eventData.seller.substring(0, 12)}...\`\n`;
-        }
-        if (eventData.buyer) {
-          message += `📥 **To:** \`${eventData.buyer.substring(0, 12)}...\`\n`;
-        }
-      }
-
-      // Marketplace and collection info
-      message += `🏪 **Marketplace:** ${eventData.marketplace}\n`;
-      message += `📮 **Collection:** \`${eventData.collectionSymbol}\`\n`;
-      message += `🔗 **Chain:** ₿ Bitcoin\n`;
-
-      // Add Magic Eden link
-      if (eventData.inscriptionId) {
-        message += `[View on Magic Eden](https://magiceden.io/ordinals/item-details/${eventData.inscriptionId})\n`;
-      }
-
-      // Add footer - Powered by Candy Codex
-      message += `\nPowered by [Candy Codex](https://mint.candycodex.com/)`;
-
-      // Add footer advertisements
-      if (this.secureTrending) {
-        try {
-          const footerAds = await this.secureTrending.getActiveFooterAds();
-          if (footerAds && footerAds.length > 0) {
-            const adLinks = footerAds.map(ad => {
-              const ticker = ad.ticker_symbol || ad.token_symbol || 'TOKEN';
-              return `[⭐️${ticker}](${ad.custom_link})`;
-            });
-
-            if (adLinks.length < 3) {
-              adLinks.push('[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)');
-            }
-
-            message += `\n${adLinks.join(' ')}`;
-          } else {
-            message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-          }
-        } catch (error) {
-          message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-        }
-      } else {
-        message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-      }
-
-      // Check if image fee is paid for this contract
-      const hasImageFee = this.secureTrending ? await this.secureTrending.isImageFeeActive(token.contract_address) : false;
-      logger.info(`🖼️ BITCOIN ORDINALS IMAGE FEE CHECK: ${token.contract_address} - hasImageFee: ${hasImageFee}`);
-
-      const boostButton = {
-        inline_keyboard: [[
-          {
-            text: 'BOOST YOUR NFT🟢',
-            callback_data: '/buy_trending'
-          }
-        ]]
-      };
-
-      // Send notifications to all subscribed users
-      for (const subscription of subscriptions) {
-        try {
-          let imagePath = null;
-
-          // Only fetch actual inscription image if fee is PAID
-          if (hasImageFee && inscriptionImage) {
-            logger.info(`   ✅ IMAGE FEE PAID: Attempting to fetch Bitcoin inscription image`);
-            try {
-              imagePath = await this.downloadAndResizeBitcoinImage(inscriptionImage, eventData.inscriptionId);
-            } catch (imageError) {
-              logger.warn(`   ⚠️ Inscription image download failed: ${imageError.message}`);
-              imagePath = null;
-            }
-          } else if (!hasImageFee) {
-            logger.info(`   🚫 IMAGE FEE NOT PAID: Using default tracking image`);
-          }
-
-          // Always use default tracking image if no paid image or download failed
-          if (!imagePath) {
-            const path = require('path');
-            imagePath = path.join(__dirname, '../images/candyImage.jpg');
-          }
-
-          // ALWAYS send as photo (like EVM chains)
-          await this.bot.telegram.sendPhoto(subscription.chat_id, { source: imagePath }, {
-            caption: message,
-            parse_mode: 'Markdown',
-            reply_markup: boostButton
-          });
-
-          logger.info(`   ✅ Sent with image to chat ${subscription.chat_id}`);
-
-          // Clean up downloaded inscription image (not default image)
-          if (hasImageFee && imagePath && !imagePath.includes('candyImage.jpg')) {
-            setTimeout(async () => {
-              try {
-                const fs = require('fs').promises;
-                await fs.unlink(imagePath).catch(() => {});
-              } catch (e) {
-                // Ignore cleanup errors
-              }
-            }, 5000);
-          }
-        } catch (error) {
-          logger.error(`   ❌ Failed to send to chat ${subscription.chat_id}:`, error.message);
-        }
-      }
-
-      return true;
-
-    } catch (error) {
-      logger.error(`Error handling Bitcoin Ordinals activity:`, error);
-      return false;
-    }
-  }
-
-  /**
-   * Download and resize Bitcoin Ordinals collection image
-   * @param {string} imageUrl - Image URL from Magic Eden
-   * @param {string} collectionSymbol - Collection symbol for filename
-   * @returns {Promise<string>} Path to resized image
-   */
-  async downloadAndResizeBitcoinImage(imageUrl, collectionSymbol) {
-    const fs = require('fs').promises;
-    const path = require('path');
-    const sharp = require('sharp');
-
-    try {
-      const tempDir = '/tmp';
-      const tempPath = path.join(tempDir, `bitcoin_ordinals_${collectionSymbol}_${Date.now()}_original.jpg`);
-      const resizedPath = path.join(tempDir, `bitcoin_ordinals_${collectionSymbol}_${Date.now()}_300x300.jpg`);
-
-      // Download image
-      const response = await axios.get(imageUrl, {
-        responseType: 'arraybuffer',
-        timeout: 10000,
-        maxContentLength: 50 * 1024 * 1024 // 50MB max
-      });
-
-      await fs.writeFile(tempPath, response.data);
-
-      // Get image metadata
-      const metadata = await sharp(tempPath).metadata();
-      logger.debug(`📊 Original Bitcoin inscription image: ${metadata.width}x${metadata.height}, format: ${metadata.format}`);
-
-      // Process image - resize to 300x300 with high quality settings
-      // For Bitcoin Ordinals: often pixel art or detailed artwork that needs sharp rendering
-      const sharpInstance = sharp(tempPath);
-
-      // If image is smaller than 300x300, upscale it with nearest neighbor (best for pixel art)
-      // If larger, downscale with lanczos3 (best for detailed images)
-      const resizeOptions = {
-        width: 300,
-        height: 300,
-        fit: 'cover',
-        position: 'center'
-      };
-
-      // Use nearest neighbor for small images (pixel art) to keep sharp pixels
-      if (metadata.width < 300 || metadata.height < 300) {
-        resizeOptions.kernel = 'nearest';
-        logger.debug(`📐 Using nearest neighbor for small image (${metadata.width}x${metadata.height})`);
-      } else {
-        resizeOptions.kernel = 'lanczos3';
-        logger.debug(`📐 Using lanczos3 for large image (${metadata.width}x${metadata.height})`);
-      }
-
-      // Create orange background (Bitcoin theme color)
-      const orangeBackground = Buffer.from(
-        '<svg width="300" height="300"><rect width="300" height="300" fill="#FF8C00"/></svg>'
-      );
-
-      // First resize the inscription image
-      const resizedInscription = await sharpInstance
-        .resize(resizeOptions)
-        .toBuffer();
-
-      // Composite inscription on orange background
-      await sharp(orangeBackground)
-        .composite([{
-          input: resizedInscription,
-          gravity: 'center'
-        }])
-        .jpeg({
-          quality: 92,
-          progressive: true,
-          mozjpeg: true
-        })
-        .toFile(resizedPath);
-
-      // Clean up original
-      await fs.unlink(tempPath).catch(() => {});
-
-      logger.info(`🖼️ Bitcoin Ordinals image processed with orange background: ${resizedPath} (300x300)`);
-      return resizedPath;
-
-    } catch (error) {
-      logger.error(`❌ Failed to download/resize Bitcoin Ordinals image: ${error.message}`);
-      throw error;
-    }
-  }
-
-  /**
-   * Get emoji for Bitcoin activity type
-   * @param {string} activityType - Activity type from Magic Eden
-   * @returns {string} Emoji
-   */
-  getBitcoinActivityEmoji(activityType) {
-    const emojiMap = {
-      'buying_broadcasted': '💰',
-      'sale': '💸',
-      'listing': '🏷️',
-      'transfer': '🔄',
-      'mint': '✨'
-    };
-    return emojiMap[activityType.toLowerCase()] || '₿';
-  }
-
-  /**
-   * Format Bitcoin activity type for display
-   * @param {string} activityType - Activity type from Magic Eden
-   * @returns {string} Formatted activity type
-   */
-  formatBitcoinActivityType(activityType) {
-    const typeMap = {
-      'buying_broadcasted': 'Buy Broadcasted',
-      'sale': 'Sale',
-      'listing': 'New Listing',
-      'transfer': 'Transfer',
-      'mint': 'Mint'
-    };
-    return typeMap[activityType.toLowerCase()] || activityType;
-  }
-
-  /**
-   * Format Bitcoin activity action for message header (like EVM format)
-   * @param {string} activityType - Activity type from Magic Eden
-   * @returns {string} Action text (e.g., "was sold", "was listed")
-   */
-  formatBitcoinActivityAction(activityType) {
-    const actionMap = {
-      'buying_broadcasted': 'was sold',
-      'sale': 'was sold',
-      'listing': 'was listed',
-      'list': 'was listed',
-      'transfer': 'was transferred',
-      'mint': 'was minted'
-    };
-    return actionMap[activityType.toLowerCase()] || 'activity';
-  }
-
-  /**
-   * Format BTC price with USD equivalent
-   * @param {string} btcPriceFormatted - Formatted BTC price string (e.g., "0.3 BTC")
-   * @param {number} satoshis - Price in satoshis
-   * @returns {Promise<string>} Formatted price with USD (e.g., "0.3 BTC $6939")
-   */
-  async formatBTCPriceWithUSD(btcPriceFormatted, satoshis) {
-    try {
-      if (!satoshis || satoshis === 0) {
-        return btcPriceFormatted || '0 BTC';
-      }
-
-      // Convert satoshis to BTC
-      const btcAmount = satoshis / 100000000;
-
-      // Fetch current BTC price in USD
-      const btcPriceUSD = await this.getBTCPriceUSD();
-
-      if (btcPriceUSD) {
-        const usdAmount = btcAmount * btcPriceUSD;
-        // Format USD with commas and no decimals for cleaner look
-        const formattedUSD = Math.round(usdAmount).toLocaleString('en-US');
-        return `${btcPriceFormatted} $${formattedUSD}`;
-      }
-
-      // Fallback if USD price fetch fails
-      return btcPriceFormatted;
-    } catch (error) {
-      logger.warn('Failed to format BTC price with USD:', error.message);
-      return btcPriceFormatted;
-    }
-  }
-
-  /**
-   * Get current BTC price in USD
-   * @returns {Promise<number|null>} BTC price in USD or null if fetch fails
-   */
-  async getBTCPriceUSD() {
-    try {
-      const axios = require('axios');
-      // Use CoinGecko free API (no auth required)
-      const response = await axios.get('https://api.coingecko.com/api/v3/simple/price', {
-        params: {
-          ids: 'bitcoin',
-          vs_currencies: 'usd'
-        },
-        timeout: 5000
-      });
+            position: 'center'
+          })
+          .jpeg({
+            quality: 85,
+            progressive: true
+          })
+          .toFile(resizedPath);
 
-      const btcPrice = response.data?.bitcoin?.usd;
-      if (btcPrice) {
-        logger.debug(`₿ Current BTC price: $${btcPrice.toLocaleString('en-US')}`);
-        return btcPrice;
+        logger.debug(`📸 Default tracking image resized to 300x300: ${resizedPath}`);
+        return resizedPath;
       }
-
-      return null;
     } catch (error) {
-      logger.warn('Failed to fetch BTC price from CoinGecko:', error.message);
+      logger.error(`❌ Failed to get default tracking image: ${error.message}`);
       return null;
     }
   }
 
-  // ==================== HIRO ORDINALS WEBHOOK HANDLERS (DEPRECATED - KEPT FOR REFERENCE) ====================
+  // ==================== HELIUS WEBHOOK HANDLERS (SOLANA / MAGIC EDEN) ====================
 
   /**
-   * Handle Hiro Chainhook webhook for Bitcoin Ordinals transfers
-   * @deprecated This handler is deprecated and removed - using Magic Eden API polling instead
+   * Handle Helius webhook for Solana NFT sales on Magic Eden
    * @param {Object} req - Express request object
    * @param {Object} res - Express response object
    */
-  async handleHiroChainhook(req, res) {
+  async handleHeliusWebhook(req, res) {
     try {
       // Verify auth header
       const authHeader = req.headers.authorization;
-      if (!this.hiro || !this.hiro.verifyChainhookAuth(authHeader)) {
-        logger.warn('⚠️ Unauthorized Hiro Chainhook webhook request');
+      if (!this.helius || !this.helius.verifyWebhookAuth(authHeader)) {
+        logger.warn('⚠️ Unauthorized Helius webhook request');
         return res.status(401).json({ error: 'Unauthorized' });
       }
 
-      const event = req.body;
-      logger.info(`₿ Received Hiro Chainhook event: ${event.apply?.[0]?.type || 'unknown'}`);
-
-      await this.db.logWebhook('hiro', event, false);
-      let processed = false;
-
-      // Process inscription transfer events
-      if (event.apply && Array.isArray(event.apply)) {
-        for (const applyItem of event.apply) {
-          try {
-            if (applyItem.type === 'transaction' || applyItem.type === 'ordinal_operation') {
-              const transferProcessed = await this.handleInscriptionTransfer(applyItem);
-              if (transferProcessed) processed = true;
-            }
-          } catch (error) {
-            logger.error(`Error processing Hiro event:`, error);
-          }
-        }
-      }
+      const transactions = req.body;
+      logger.info(`🌟 Received Helius webhook with ${transactions.length} transactions`);
 
-      await this.db.logWebhook('hiro', event, processed);
-      logger.info(`✅ Hiro Chainhook processed: ${processed ? 'success' : 'skipped'}`);
+      await this.db.logWebhook('helius', transactions, false);
+      // Acknowledge once the payload is stored; the inbox worker processes it
+      const { id } = await this.inbox.enqueue('helius', transactions);
 
       res.status(200).json({
         success: true,
-        processed: processed,
-        message: 'Chainhook processed successfully'
+        queued: id,
+        message: 'Webhook accepted'
       });
     } catch (error) {
-      logger.error('Error handling Hiro Chainhook:', error);
+      logger.error('Error handling Helius webhook:', error);
       if (req.body) {
-        await this.db.logWebhook('hiro', req.body, false, error.message);
+        await this.db.logWebhook('helius', req.body, false, error.message);
       }
       res.status(500).json({
         success: false,
@@ -2871,348 +2553,386 @@ class WebhookHandlers {
   }
 
   /**
-   * Handle a single inscription transfer event from Hiro Chainhook
-   * @param {Object} event - The event object from Hiro
+   * Route the transactions of a stored Helius payload (webhook inbox worker)
+   * @param {Array<Object>} transactions - Request body of a verified delivery
+   */
+  async processHeliusWebhook(transactions) {
+    let processedCount = 0;
+
+    for (const transaction of transactions) {
+      try {
+        let processed = false;
+        if (transaction.type === 'NFT_SALE') {
+          logger.info(`💰 Processing Helius NFT_SALE event: ${transaction.signature}`);
+          processed = await this.handleHeliusNFTSale(transaction);
+        } else if (transaction.type === 'NFT_LISTING') {
+          logger.info(`📝 Processing Helius NFT_LISTING event: ${transaction.signature}`);
+          processed = await this.handleHeliusNFTListing(transaction);
+        }
+        // The wallet watchlist webhook delivers every transaction of the watched wallets
+        if (this.walletWatch) {
+          processed = await this.handleHeliusWalletActivity(transaction) || processed;
+        }
+        if (processed) processedCount++;
+      } catch (error) {
+        logger.error(`Error processing Helius transaction ${transaction.signature}:`, error);
+      }
+    }
+
+    await this.db.logWebhook('helius', transactions, processedCount > 0);
+    logger.info(`✅ Helius webhook processed: ${processedCount}/${transactions.length} events`);
+  }
+
+  /**
+   * Handle a single NFT sale event from Helius
+   * @param {Object} transaction - The transaction object from Helius
    * @returns {Promise<boolean>} True if processed successfully
    */
-  async handleInscriptionTransfer(event) {
+  async handleHeliusNFTSale(transaction) {
     try {
-      if (!this.hiro) {
-        logger.warn('Hiro service not available');
+      if (!this.helius) {
+        logger.warn('Helius service not available');
         return false;
       }
 
-      // Parse the inscription transfer event
-      const transferData = this.hiro.parseInscriptionTransfer(event);
-      if (!transferData) {
-        logger.warn('Failed to parse Hiro inscription transfer event');
+      // Parse the NFT sale event
+      const saleData = this.helius.parseNFTSaleEvent(transaction);
+      if (!saleData) {
+        logger.warn('Failed to parse Helius NFT sale event');
         return false;
       }
 
       // Create deduplication key
-      const eventKey = `hiro:${transferData.txid}:${transferData.inscription_id}`;
-      if (this.isHiroEventProcessed(eventKey)) {
-        logger.info(`⏭️ Hiro event ${eventKey} already processed, skipping`);
+      const eventKey = `helius:${saleData.signature}:${saleData.mintAddress}`;
+      if (!await this.dedup.claim('helius', eventKey)) {
+        logger.info(`⏭️ Helius event ${eventKey} already processed, skipping`);
         return false;
       }
 
-      // Mark as being processed
-      this.markHiroEventProcessed(eventKey);
-
-      logger.info(`₿ HIRO INSCRIPTION TRANSFER - ID: ${transferData.inscription_id}`);
-
-      // Find tracked tokens by collection symbol for Bitcoin
-      let token = null;
-      if (transferData.collection_symbol) {
-        const tokens = await this.db.all(
-          'SELECT * FROM tracked_tokens WHERE chain_name = $1 AND collection_slug = $2 AND is_active = true',
-          ['bitcoin', transferData.collection_symbol]
-        );
-        if (tokens && tokens.length > 0) {
-          token = tokens[0];
-          logger.info(`📊 Found tracked Bitcoin Ordinals collection: ${token.token_name} (${transferData.collection_symbol})`);
-        }
-      }
+      logger.info(`🌟 HELIUS NFT SALE - Mint: ${saleData.mintAddress}, Price: ${saleData.amountSol} SOL`);
 
+      const token = await this.findSolanaTokenForMint(saleData.mintAddress);
       if (!token) {
-        logger.debug(`Collection ${transferData.collection_symbol || 'unknown'} for inscription ${transferData.inscription_id} not tracked, skipping`);
         return false;
       }
 
-      // Log activity to database
-      const activityData = {
-        contractAddress: transferData.inscription_id,
-        tokenId: transferData.inscription_number || null,
-        activityType: 'transfer',
-        fromAddress: transferData.sender,
-        toAddress: transferData.recipient,
-        transactionHash: transferData.txid,
-        blockNumber: transferData.block_height,
-        price: null, // Ordinals transfers don't have on-chain price data
-        marketplace: 'Bitcoin Network'
-      };
-
-      await this.db.logNFTActivity(activityData);
-
-      // Notify subscribed users
-      await this.notifyUsersOrdinals(token, transferData, activityData);
-
-      // Check if token should notify channels
-      const shouldNotifyChannels = await this.shouldNotifyChannelsForToken(token.contract_address);
-      if (shouldNotifyChannels.notify) {
-        logger.info(`📢 Notifying channels for ${token.token_name} via Ordinals transfer (${shouldNotifyChannels.reason})`);
-        await this.notifyChannelsOrdinals(token, transferData, activityData, shouldNotifyChannels.channels, shouldNotifyChannels.isTrending);
-      }
+      await this.routeActivityEvent(token, fromHeliusSale(saleData, token));
 
-      logger.info(`✅ Successfully processed Hiro inscription transfer for ${token.token_name}`);
+      logger.info(`✅ Successfully processed Helius NFT sale for ${token.token_name}`);
       return true;
 
     } catch (error) {
-      logger.error('Error handling Hiro inscription transfer:', error);
+      logger.error('Error handling Helius NFT sale:', error);
       return false;
     }
   }
 
   /**
-   * Check if Hiro event has been processed
-   * @param {string} eventKey - Unique event key
-   * @returns {boolean} True if already processed
+   * Handle a single NFT listing event from Helius. Listings of tracked collections are
+   * routed like any other listing; they matter most to chats in below-floor snipe mode.
+   * @param {Object} transaction - The transaction object from Helius
+   * @returns {Promise<boolean>} True if processed successfully
    */
-  isHiroEventProcessed(eventKey) {
-    const timestamp = this.processedHiroEvents.get(eventKey);
-    if (!timestamp) return false;
+  async handleHeliusNFTListing(transaction) {
+    try {
+      const listingData = this.helius?.parseNFTListingEvent(transaction);
+      if (!listingData?.mintAddress) {
+        logger.warn('Failed to parse Helius NFT listing event');
+        return false;
+      }
+
+      const eventKey = `helius:${listingData.signature}:${listingData.mintAddress}`;
+      if (!await this.dedup.claim('helius', eventKey)) {
+        logger.info(`⏭️ Helius event ${eventKey} already processed, skipping`);
+        return false;
+      }
+
+      logger.info(`🌟 HELIUS NFT LISTING - Mint: ${listingData.mintAddress}, Price: ${listingData.amountSol} SOL`);
+
+      const token = await this.findSolanaTokenForMint(listingData.mintAddress);
+      if (!token) {
+        return false;
+      }
 
-    const isValid = (Date.now() - timestamp) <= this.CACHE_EXPIRY_MS;
-    if (!isValid) {
-      this.processedHiroEvents.delete(eventKey);
+      await this.routeActivityEvent(token, fromHeliusListing(listingData, token));
+      return true;
+    } catch (error) {
+      logger.error('Error handling Helius NFT listing:', error);
       return false;
     }
-    return true;
   }
 
   /**
-   * Mark Hiro event as processed
-   * @param {string} eventKey - Unique event key
+   * Tracked Solana collection of a mint. Helius doesn't name the collection, so its
+   * symbol is looked up on Magic Eden.
+   * @param {string} mintAddress
+   * @returns {Promise<Object|null>} Tracked token row, null if the collection isn't tracked
    */
-  markHiroEventProcessed(eventKey) {
-    this.processedHiroEvents.set(eventKey, Date.now());
+  async findSolanaTokenForMint(mintAddress) {
+    let collectionSymbol = null;
+    if (this.magicEden) {
+      try {
+        const nftInfo = await this.magicEden.getNFTMetadata(mintAddress);
+        collectionSymbol = nftInfo?.collection || nftInfo?.collectionSymbol;
+        if (collectionSymbol) {
+          logger.info(`📦 Found collection: ${collectionSymbol} for mint ${mintAddress}`);
+        }
+      } catch (error) {
+        logger.debug(`Could not fetch collection for mint ${mintAddress}: ${error.message}`);
+      }
+    }
+
+    let token = null;
+    if (collectionSymbol) {
+      const tokens = await this.db.all(
+        'SELECT * FROM tracked_tokens WHERE chain_name = $1 AND collection_slug = $2 AND is_active = true',
+        ['solana', collectionSymbol]
+      );
+      token = tokens?.[0] || null;
+      if (token) {
+        logger.info(`📊 Found tracked Solana collection: ${token.token_name} (${collectionSymbol})`);
+      }
+    }
+
+    if (!token) {
+      logger.debug(`Collection ${collectionSymbol || 'unknown'} for mint ${mintAddress} not tracked, skipping`);
+    }
+    return token;
   }
 
+  // ==================== BITCOIN ORDINALS POLLING HANDLERS (MAGIC EDEN) ====================
+
   /**
-   * Notify users about Bitcoin Ordinals transfer
-   * @param {Object} token - Token data from database
-   * @param {Object} transferData - Parsed transfer data from Hiro
-   * @param {Object} activityData - Activity data for database
-   * @returns {Promise<boolean>} True if notifications sent successfully
+   * Handle Bitcoin Ordinals activity from Magic Eden API poller
+   * @param {Object} eventData - Activity data from BitcoinOrdinalsPoller
+   * @returns {Promise<boolean>} True if any alert was queued
    */
-  async notifyUsersOrdinals(token, transferData, activityData) {
+  async handleBitcoinOrdinalsActivity(eventData) {
     try {
-      // Get users with their subscription context (chat_id)
-      const subscriptions = await this.db.all(`
-        SELECT u.telegram_id, u.username, us.notification_enabled, us.chat_id
-        FROM users u
-        JOIN user_subscriptions us ON u.id = us.user_id
-        WHERE us.token_id = $1 AND us.notification_enabled = true AND u.is_active = true
-      `, [token.id]);
+      logger.info(`₿ Processing Bitcoin Ordinals activity: ${eventData.activityType} for ${eventData.collectionSymbol}`);
 
-      if (!subscriptions || subscriptions.length === 0) {
-        logger.debug(`No users subscribed to Bitcoin Ordinals token: ${token.contract_address}`);
+      // Find tracked token by collection symbol
+      const token = await this.db.get(
+        'SELECT * FROM tracked_tokens WHERE collection_slug = $1 AND chain_name = $2 AND is_active = true',
+        [eventData.collectionSymbol, 'bitcoin']
+      );
+
+      if (!token) {
+        logger.debug(`Collection ${eventData.collectionSymbol} not tracked, skipping activity`);
         return false;
       }
 
-      const message = await this.formatOrdinalsTransferMessage(token, transferData);
-      logger.info(`📤 Sending Bitcoin Ordinals transfer notification to ${subscriptions.length} subscription(s) for ${token.token_name}`);
-
-      let successCount = 0;
-
-      for (const subscription of subscriptions) {
-        try {
-          let targetChatId;
-          if (subscription.chat_id === 'private') {
-            targetChatId = subscription.telegram_id;
-          } else {
-            targetChatId = subscription.chat_id;
-          }
-
-          await this.sendOrdinalsNotificationWithImage(targetChatId, message, transferData, token);
-          successCount++;
-          logger.info(`✅ Bitcoin Ordinals notification sent to ${subscription.chat_id === 'private' ? 'private chat' : 'group'} ${targetChatId}`);
-        } catch (error) {
-          logger.error(`❌ Failed to send Bitcoin Ordinals notification to ${subscription.chat_id}:`, error);
-
-          if (error.response?.error_code === 403 || error.response?.error_code === 400) {
-            await this.db.run(
-              'UPDATE users SET is_active = false WHERE telegram_id = $1',
-              [subscription.telegram_id]
-            );
-            logger.info(`Deactivated user ${subscription.telegram_id} due to delivery failure`);
-          }
-        }
+      const event = fromOrdinalsActivity(eventData, token);
+      if (!event) {
+        logger.debug(`Ignoring Ordinals ${eventData.activityType} activity for ${eventData.collectionSymbol}`);
+        return false;
       }
 
-      logger.info(`📊 Bitcoin Ordinals notification summary: ${successCount}/${subscriptions.length} notifications sent`);
-      return successCount > 0;
+      // BitcoinOrdinalsPoller has already written the nft_activities row
+      return await this.routeActivityEvent(token, event, { persist: false });
     } catch (error) {
-      logger.error('Error notifying users for Bitcoin Ordinals transfer:', error);
+      logger.error(`Error handling Bitcoin Ordinals activity:`, error);
       return false;
     }
   }
 
+  // ==================== HIRO ORDINALS WEBHOOK HANDLERS ====================
+
   /**
-   * Notify channels about Bitcoin Ordinals transfer
-   * @param {Object} token - Token data
-   * @param {Object} transferData - Transfer data
-   * @param {Object} activityData - Activity data
-   * @param {Array} channels - Channels to notify
-   * @param {boolean} isTrending - Whether token is trending
-   * @returns {Promise<boolean>} True if notifications sent
+   * Handle Hiro Chainhook webhook for Bitcoin Ordinals transfers
+   * Sales and listings still come from BitcoinOrdinalsPoller; the chainhook adds inscription transfers.
+   * @param {Object} req - Express request object
+   * @param {Object} res - Express response object
    */
-  async notifyChannelsOrdinals(token, transferData, activityData, channels, isTrending = false) {
+  async handleHiroChainhook(req, res) {
     try {
-      // Authorization already performed by shouldNotifyChannelsForToken() before calling this method
-
-      if (!channels || channels.length === 0) {
-        return false;
+      // Verify bearer token
+      const authHeader = req.headers.authorization;
+      if (!this.hiro || !this.hiro.verifyChainhookAuth(authHeader)) {
+        logger.warn('⚠️ Unauthorized Hiro Chainhook webhook request');
+        return res.status(401).json({ error: 'Unauthorized' });
       }
 
-      const message = isTrending
-        ? await this.formatTrendingOrdinalsMessage(token, transferData)
-        : await this.formatOrdinalsTransferMessage(token, transferData);
+      const event = req.body;
+      // Each predicate posts with the collection (or the watched wallets) it was registered for
+      const target = {
+        collection: typeof req.query?.collection === 'string' ? req.query.collection : null,
+        wallets: req.query?.scope === 'wallets'
+      };
+      logger.info(`₿ Received Hiro Chainhook event for ${target.wallets ? 'watched wallets' : target.collection || 'all collections'}`);
 
-      let notifiedCount = 0;
-      for (const channel of channels) {
-        try {
-          logger.info(`📤 SENDING Bitcoin Ordinals to channel ${channel.channel_title}: ${token.token_name}`);
-          await this.sendOrdinalsNotificationWithImage(channel.telegram_chat_id, message, transferData, token);
-          notifiedCount++;
-          logger.info(`✅ Bitcoin Ordinals notification sent to channel: ${channel.channel_title}`);
-        } catch (error) {
-          logger.error(`❌ Failed to send Bitcoin Ordinals notification to channel ${channel.channel_title}:`, error);
+      await this.db.logWebhook('hiro', event, false);
 
-          if (error.response?.error_code === 403) {
-            await this.db.run(
-              'UPDATE channels SET is_active = false WHERE telegram_chat_id = $1',
-              [channel.telegram_chat_id]
-            );
-          }
-        }
-      }
+      // A block can carry thousands of feed transfers; store the payload and acknowledge
+      // before matching them so Hiro doesn't time out
+      const { id } = await this.inbox.enqueue('hiro', { target, event });
 
-      logger.info(`📢 Notified ${notifiedCount}/${channels.length} channels for Bitcoin Ordinals transfer`);
-      return notifiedCount > 0;
+      res.status(200).json({
+        success: true,
+        queued: id,
+        message: 'Chainhook accepted'
+      });
     } catch (error) {
-      logger.error('Error notifying channels for Bitcoin Ordinals transfer:', error);
-      return false;
+      logger.error('Error handling Hiro Chainhook:', error);
+      if (req.body) {
+        await this.db.logWebhook('hiro', req.body, false, error.message);
+      }
+      res.status(500).json({
+        success: false,
+        error: 'Internal server error'
+      });
     }
   }
 
   /**
-   * Format Bitcoin Ordinals transfer message for Telegram
-   * @param {Object} token - Token data
-   * @param {Object} transferData - Transfer data from Hiro
-   * @returns {Promise<string>} Formatted message
+   * Match a chainhook payload's transfers against the predicate's collection or the watched wallets
+   * @param {Object} event - Chainhook request body
+   * @param {Object} target - { collection, wallets } from the predicate URL; payloads of a
+   *   predicate without either (registered by hand or by an older version) are matched against both
    */
-  async formatOrdinalsTransferMessage(token, transferData) {
-    const collectionName = token.token_name || 'Bitcoin Ordinals';
-    const shortInscriptionId = this.shortenAddress(transferData.inscription_id);
+  async processHiroChainhook(event, target = {}) {
+    if (!this.hiro) {
+      logger.warn('Hiro service not available, skipping queued chainhook payload');
+      return;
+    }
 
-    let message = `🔄 **${collectionName}** Transfer\n\n`;
-    message += `🖼️ **Inscription:** #${transferData.inscription_number || 'Unknown'}\n`;
-    message += `🆔 **Inscription ID:** [${shortInscriptionId}](https://magiceden.io/ordinals/item-details/${transferData.inscription_id})\n`;
-    message += `📤 **From:** \`${this.shortenAddress(transferData.sender)}\`\n`;
-    message += `📥 **To:** \`${this.shortenAddress(transferData.recipient)}\`\n`;
-    message += `🏪 **Marketplace:** Magic Eden Ordinals\n`;
-    message += `🔗 **Chain:** ₿ Bitcoin\n`;
+    const transfers = this.hiro.extractInscriptionTransfers(event);
+    const matchCollections = !target.wallets;
+    const matchWallets = target.wallets || !target.collection;
+    let processedCount = 0;
+
+    // Only index collections when there is something to match against
+    const trackedCollections = matchCollections && transfers.length > 0
+      ? (await this.db.all(
+        'SELECT DISTINCT collection_slug FROM tracked_tokens WHERE chain_name = $1 AND collection_slug IS NOT NULL AND is_active = true',
+        ['bitcoin']
+      )).map(row => row.collection_slug)
+      : [];
+    // A collection removed after the payload was queued is no longer matched
+    const collections = target.collection
+      ? trackedCollections.filter(symbol => symbol === target.collection)
+      : trackedCollections;
+
+    if (collections.length > 0) {
+      await this.hiro.refreshCollectionIndex(trackedCollections);
+      for (const transfer of transfers) {
+        try {
+          const processed = await this.handleInscriptionTransfer(transfer, collections);
+          if (processed) processedCount++;
+        } catch (error) {
+          logger.error(`Error processing Hiro event:`, error);
+        }
+      }
+    }
 
-    // Add Magic Eden link
-    if (transferData.inscription_id) {
-      message += `[View on Magic Eden](https://magiceden.io/ordinals/item-details/${transferData.inscription_id})\n`;
+    if (matchWallets && this.walletWatch && await this.walletWatch.hasWallets('bitcoin')) {
+      processedCount += await this.handleBitcoinWalletActivity(event, transfers);
     }
-    message += `[View on Bitcoin Explorer](https://mempool.space/tx/${transferData.txid})\n`;
 
-    message += `\nPowered by [Candy Codex](https://mint.candycodex.com/)`;
+    await this.db.logWebhook('hiro', event, processedCount > 0);
+    logger.info(`✅ Hiro Chainhook processed: ${processedCount}/${transfers.length} transfers`);
+  }
 
-    // Add footer advertisements
-    if (this.secureTrending) {
+  /**
+   * Inscriptions moved or revealed by watched Bitcoin wallets, in any collection
+   * @param {Object} payload - Chainhook request body
+   * @param {Array<Object>} transfers - From HiroOrdinalsService.extractInscriptionTransfers
+   * @returns {Promise<number>} Number of events alerted
+   */
+  async handleBitcoinWalletActivity(payload, transfers) {
+    let processedCount = 0;
+
+    for (const transfer of transfers) {
       try {
-        const footerAds = await this.secureTrending.getActiveFooterAds();
-        if (footerAds && footerAds.length > 0) {
-          const adLinks = footerAds.map(ad => {
-            const ticker = ad.ticker_symbol || ad.token_symbol || 'TOKEN';
-            return `[⭐️${ticker}](${ad.custom_link})`;
-          });
+        const transferData = this.hiro.parseInscriptionTransfer(transfer);
+        if (!transferData) continue;
 
-          if (adLinks.length < 3) {
-            adLinks.push('[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)');
-          }
+        const { sender, recipient } = await this.walletWatch.resolveBitcoinTransfer(transferData);
+        if (!sender && !recipient) continue;
 
-          message += `\n${adLinks.join(' ')}`;
-        } else {
-          message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
-        }
+        const event = fromHiroTransfer({ ...transferData, sender: sender || transferData.sender }, {});
+        if (await this.notifyWalletActivity(event)) processedCount++;
       } catch (error) {
-        message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
+        logger.error('Error processing Hiro transfer for watched wallets:', error);
       }
-    } else {
-      message += `\n[BuyAdspot](https://t.me/MintTechBot?start=buy_footer)`;
     }
 
-    return message;
-  }
+    for (const reveal of this.hiro.extractInscriptionReveals(payload)) {
+      try {
+        const revealData = this.hiro.parseInscriptionReveal(reveal);
+        if (!revealData || !await this.walletWatch.recordBitcoinReveal(revealData)) continue;
 
-  /**
-   * Format trending Bitcoin Ordinals transfer message
-   * @param {Object} token - Token data
-   * @param {Object} transferData - Transfer data
-   * @returns {Promise<string>} Formatted message
-   */
-  async formatTrendingOrdinalsMessage(token, transferData) {
-    let message = `🔥 **TRENDING:** ${token.token_name || 'Bitcoin Ordinals'}\n\n`;
-    const regularMessage = await this.formatOrdinalsTransferMessage(token, transferData);
-    const lines = regularMessage.split('\n');
-    message += lines.slice(1).join('\n');
-    return message;
+        if (await this.notifyWalletActivity(fromHiroReveal(revealData, {}))) processedCount++;
+      } catch (error) {
+        logger.error('Error processing Hiro reveal for watched wallets:', error);
+      }
+    }
+
+    return processedCount;
   }
 
   /**
-   * Send Bitcoin Ordinals notification with image
-   * @param {string} chatId - Telegram chat ID
-   * @param {string} message - Message text
-   * @param {Object} transferData - Transfer data
-   * @param {Object} token - Token data
+   * Handle a single inscription transfer event from Hiro Chainhook
+   * @param {Object} event - Transfer from HiroOrdinalsService.extractInscriptionTransfers
+   * @param {Array<string>|null} collections - Only match inscriptions of these collections
+   * @returns {Promise<boolean>} True if processed successfully
    */
-  async sendOrdinalsNotificationWithImage(chatId, message, transferData, token) {
-    // Check if image fee is paid for this contract
-    const hasImageFee = this.secureTrending ? await this.secureTrending.isImageFeeActive(token.contract_address) : false;
-    logger.info(`🖼️ BITCOIN ORDINALS IMAGE FEE CHECK: ${token.contract_address} - hasImageFee: ${hasImageFee}`);
+  async handleInscriptionTransfer(event, collections = null) {
+    try {
+      if (!this.hiro) {
+        logger.warn('Hiro service not available');
+        return false;
+      }
 
-    const boostButton = {
-      inline_keyboard: [[
-        {
-          text: 'BOOST YOUR NFT🟢',
-          callback_data: '/buy_trending'
-        }
-      ]]
-    };
+      // Parse the inscription transfer event
+      const transferData = this.hiro.parseInscriptionTransfer(event);
+      if (!transferData) {
+        logger.debug('Skipping unparseable Hiro inscription transfer event');
+        return false;
+      }
 
-    try {
-      let imagePath = null;
+      // The inscription feed covers every collection; match it against the tracked collections' inscriptions
+      if (!transferData.collection_symbol) {
+        transferData.collection_symbol = this.hiro.findIndexedCollection(transferData.inscription_id, collections);
+      } else if (collections && !collections.includes(transferData.collection_symbol)) {
+        return false;
+      }
 
-      // Only fetch actual inscription image if fee is PAID
-      if (hasImageFee && this.magicEdenOrdinals) {
-        logger.info(`✅ IMAGE FEE PAID: Attempting to fetch Bitcoin Ordinals inscription image`);
-        try {
-          const inscriptionMetadata = await this.magicEdenOrdinals.getInscriptionMetadata(transferData.inscription_id);
-          if (inscriptionMetadata && inscriptionMetadata.content_url) {
-            imagePath = await this.downloadAndResizeBitcoinImage(inscriptionMetadata.content_url, transferData.inscription_id);
-          }
-        } catch (error) {
-          logger.warn(`Failed to process Bitcoin Ordinals inscription image: ${error.message}`);
-          imagePath = null;
+      // Find tracked tokens by collection symbol for Bitcoin
+      let token = null;
+      if (transferData.collection_symbol) {
+        const tokens = await this.db.all(
+          'SELECT * FROM tracked_tokens WHERE chain_name = $1 AND collection_slug = $2 AND is_active = true',
+          ['bitcoin', transferData.collection_symbol]
+        );
+        if (tokens && tokens.length > 0) {
+          token = tokens[0];
+          logger.info(`📊 Found tracked Bitcoin Ordinals collection: ${token.token_name} (${transferData.collection_symbol})`);
         }
-      } else if (!hasImageFee) {
-        logger.info(`🚫 IMAGE FEE NOT PAID: Using default tracking image for Bitcoin Ordinals`);
       }
 
-      // Always use default tracking image if no paid image or download failed
-      if (!imagePath) {
-        const path = require('path');
-        imagePath = path.join(__dirname, '../images/candyImage.jpg');
-        logger.info(`📷 Using default tracking image: ${imagePath}`);
+      if (!token) {
+        logger.debug(`Collection ${transferData.collection_symbol || 'unknown'} for inscription ${transferData.inscription_id} not tracked, skipping`);
+        return false;
       }
 
-      // ALWAYS send as photo (like EVM chains)
-      await this.bot.telegram.sendPhoto(chatId, { source: imagePath }, {
-        caption: message,
-        parse_mode: 'Markdown',
-        reply_markup: boostButton
-      });
+      // Claimed only for tracked collections, so the feed doesn't fill the dedup store
+      const eventKey = `hiro:${transferData.txid}:${transferData.inscription_id}`;
+      if (!await this.dedup.claim('hiro', eventKey)) {
+        logger.info(`⏭️ Hiro event ${eventKey} already processed, skipping`);
+        return false;
+      }
 
-      logger.info(`✅ Bitcoin Ordinals notification with image sent successfully to ${chatId}`);
+      logger.info(`₿ HIRO INSCRIPTION TRANSFER - ID: ${transferData.inscription_id}`);
+
+      await this.routeActivityEvent(token, fromHiroTransfer(transferData, token));
+
+      logger.info(`✅ Successfully processed Hiro inscription transfer for ${token.token_name}`);
+      return true;
 
     } catch (error) {
-      logger.error(`Error sending Bitcoin Ordinals notification with image:`, error);
-      throw error;
+      logger.error('Error handling Hiro inscription transfer:', error);
+      return false;
     }
   }
 
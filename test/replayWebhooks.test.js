@@ -0,0 +1,105 @@
+const { test } = require('node:test');
+const assert = require('node:assert');
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
+
+const Database = require('../src/database/db');
+const { SqliteAdapter } = require('../src/database/adapters');
+const ChainManager = require('../src/services/chainManager');
+const { parseArgs, loadLogs, replayLogs } = require('../scripts/replayWebhooks');
+
+const CONTRACT = '0x1111111111111111111111111111111111111111';
+const SEAPORT = '0x0000000000000068f116a894984e2db1123eb395';
+
+// An Alchemy delivery with a mint and a marketplace sale of a tracked collection
+const ALCHEMY_PAYLOAD = {
+  webhookId: 'wh_replay_test',
+  type: 'NFT_ACTIVITY',
+  createdAt: '2025-01-10T12:00:00.000Z',
+  event: {
+    network: 'ETH_MAINNET',
+    activity: [
+      {
+        fromAddress: '0x0000000000000000000000000000000000000000',
+        toAddress: '0x2222222222222222222222222222222222222222',
+        contractAddress: CONTRACT,
+        erc721TokenId: '0x1',
+        category: 'erc721',
+        hash: '0x' + 'a'.repeat(64),
+        blockNum: '0x1'
+      },
+      {
+        fromAddress: '0x2222222222222222222222222222222222222222',
+        toAddress: SEAPORT,
+        contractAddress: CONTRACT,
+        erc721TokenId: '0x2',
+        category: 'erc721',
+        value: '1000000000000000000',
+        hash: '0x' + 'b'.repeat(64),
+        blockNum: '0x2'
+      }
+    ]
+  }
+};
+
+async function createDatabase() {
+  const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'replay-'));
+  const db = new Database({ adapter: new SqliteAdapter(path.join(dir, 'replay.sqlite')), autoMigrate: true });
+  await db.initialize();
+
+  const user = await db.createUser('1001', 'collector', 'Collector');
+  const token = await db.addTrackedToken(CONTRACT, { name: 'Replay Test', symbol: 'RPT', tokenType: 'ERC721', totalSupply: '100' }, user.id, 'wh_replay_test', 'replay-test');
+  await db.subscribeUserToToken(user.id, token.id, 'private');
+  await db.logWebhook('alchemy', ALCHEMY_PAYLOAD, false, 'Simulated outage');
+  return { db, dir };
+}
+
+async function snapshotTables(db) {
+  const tables = await db.all(`SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name`);
+  const snapshot = {};
+  for (const { name } of tables) {
+    snapshot[name] = await db.all(`SELECT * FROM ${name} ORDER BY rowid`);
+  }
+  return snapshot;
+}
+
+for (const args of [[], ['--respect-dedup']]) {
+  test(`dry-run replay ${args.join(' ') || 'with its own dedup'} leaves every table unchanged`, async () => {
+    const { db, dir } = await createDatabase();
+    try {
+      const options = parseArgs(args);
+      const entries = await loadLogs(db, options);
+      assert.strictEqual(entries.length, 1);
+
+      const chainManager = new ChainManager(db);
+      await chainManager.initialize();
+
+      const before = await snapshotTables(db);
+      const { replayed, rendered } = await replayLogs(db, entries, options, { chainManager }, () => {});
+      const after = await snapshotTables(db);
+
+      assert.strictEqual(replayed, 1);
+      assert.ok(rendered.length > 0, 'the replayed events are rendered');
+      assert.deepStrictEqual(after, before);
+    } finally {
+      await db.close();
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+}
+
+test('dry-run replay refuses writes to the database', async () => {
+  const { db, dir } = await createDatabase();
+  try {
+    const options = parseArgs(['--dry-run']);
+    await replayLogs(db, [], options, { chainManager: null }, () => {});
+
+    await assert.rejects(db.query('DELETE FROM webhook_logs'), /Dry run: refusing to write/);
+    await assert.rejects(db.transaction(tx => tx.run('UPDATE users SET username = $1', ['changed'])), /Dry run: refusing to write/);
+    assert.strictEqual((await db.get('SELECT COUNT(*) AS count FROM webhook_logs')).count, 1);
+  } finally {
+    await db.close();
+    fs.rmSync(dir, { recursive: true, force: true });
+  }
+});